@@ -0,0 +1,231 @@
+//! Extracts prop tables from `#[component]` functions for the props panel
+//!
+//! Parses the component crates' sources with `syn` and writes
+//! `$OUT_DIR/component_props.rs`, a `COMPONENTS` table included by
+//! `src/props.rs`. Only `pub` components are listed.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use quote::ToTokens;
+use syn::{Attribute, Expr, FnArg, Item, Lit, Meta, Pat, Type};
+
+/// Crates whose components are documented, relative to this manifest
+const SOURCE_DIRS: &[&str] = &["../ui-components/src", "../wallet-leptos/src"];
+
+struct Prop {
+    name: String,
+    ty: String,
+    default: Option<String>,
+    optional: bool,
+    doc: String,
+}
+
+struct Component {
+    name: String,
+    module: String,
+    doc: String,
+    props: Vec<Prop>,
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let mut components = Vec::new();
+
+    for dir in SOURCE_DIRS {
+        let dir = manifest_dir.join(dir);
+        println!("cargo:rerun-if-changed={}", dir.display());
+        let mut files = Vec::new();
+        collect_rs_files(&dir, &mut files);
+        files.sort();
+        for file in files {
+            println!("cargo:rerun-if-changed={}", file.display());
+            let module = file
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            let Ok(source) = fs::read_to_string(&file) else {
+                continue;
+            };
+            // Files that don't parse (mid-edit) are skipped rather than failing the build
+            let Ok(parsed) = syn::parse_file(&source) else {
+                println!("cargo:warning=props: could not parse {}", file.display());
+                continue;
+            };
+            components.extend(
+                parsed
+                    .items
+                    .iter()
+                    .filter_map(|item| component(item, &module)),
+            );
+        }
+    }
+
+    let out = PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("component_props.rs");
+    fs::write(out, render(&components)).expect("write component_props.rs");
+}
+
+fn collect_rs_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.is_dir() {
+            collect_rs_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+fn component(item: &Item, module: &str) -> Option<Component> {
+    let Item::Fn(func) = item else {
+        return None;
+    };
+    let is_component = func.attrs.iter().any(|a| a.path().is_ident("component"));
+    if !is_component || !matches!(func.vis, syn::Visibility::Public(_)) {
+        return None;
+    }
+
+    let props = func
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| {
+            let FnArg::Typed(arg) = arg else {
+                return None;
+            };
+            let Pat::Ident(ident) = arg.pat.as_ref() else {
+                return None;
+            };
+            let (optional, default) = prop_options(&arg.attrs, &arg.ty);
+            Some(Prop {
+                name: ident.ident.to_string(),
+                ty: type_string(&arg.ty),
+                default,
+                optional,
+                doc: doc_string(&arg.attrs),
+            })
+        })
+        .collect();
+
+    Some(Component {
+        name: func.sig.ident.to_string(),
+        module: module.to_string(),
+        doc: doc_string(&func.attrs),
+        props,
+    })
+}
+
+/// Whether a prop may be omitted, and its default as written
+fn prop_options(attrs: &[Attribute], ty: &Type) -> (bool, Option<String>) {
+    let mut optional = false;
+    let mut default = None;
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("prop")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            let key = meta
+                .path
+                .get_ident()
+                .map(|i| i.to_string())
+                .unwrap_or_default();
+            match key.as_str() {
+                "optional" | "optional_no_strip" => optional = true,
+                "default" => {
+                    optional = true;
+                    let value: Expr = meta.value()?.parse()?;
+                    default = Some(expr_string(&value));
+                }
+                _ => {
+                    // `into`, `name = ..` and friends: consume any value
+                    if meta.input.peek(syn::Token![=]) {
+                        let _: Expr = meta.value()?.parse()?;
+                    }
+                }
+            }
+            Ok(())
+        });
+    }
+
+    if optional && default.is_none() {
+        default = Some(if type_string(ty).starts_with("Option<") {
+            "None".to_string()
+        } else {
+            "Default::default()".to_string()
+        });
+    }
+    (optional, default)
+}
+
+/// `///` comment lines, trimmed
+fn doc_string(attrs: &[Attribute]) -> String {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|a| a.path().is_ident("doc"))
+        .filter_map(|a| match &a.meta {
+            Meta::NameValue(nv) => match &nv.value {
+                Expr::Lit(lit) => match &lit.lit {
+                    Lit::Str(s) => Some(s.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    lines.join("\n").trim().to_string()
+}
+
+fn type_string(ty: &Type) -> String {
+    tidy_tokens(&ty.to_token_stream().to_string())
+}
+
+fn expr_string(expr: &Expr) -> String {
+    tidy_tokens(&expr.to_token_stream().to_string())
+}
+
+/// Undo the token printer's spacing (`Signal < Vec < u8 > >` -> `Signal<Vec<u8>>`)
+fn tidy_tokens(tokens: &str) -> String {
+    let mut out = tokens
+        .replace(" < ", "<")
+        .replace("< ", "<")
+        .replace(" <", "<")
+        .replace(" >", ">")
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" ::", "::")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+        .replace(" (", "(")
+        .replace("( ", "(")
+        .replace(" )", ")")
+        .replace(" .", ".")
+        .replace(". ", ".")
+        .replace(" !", "!");
+    // `dyn Fn(A)-> B` reads better with spaced arrows
+    out = out.replace("->", " -> ").replace("  ", " ");
+    out.trim().to_string()
+}
+
+fn render(components: &[Component]) -> String {
+    let mut out = String::from("// Generated by build.rs - do not edit\n\n");
+    out.push_str("pub static COMPONENTS: &[ComponentDoc] = &[\n");
+    for c in components {
+        let _ = writeln!(
+            out,
+            "    ComponentDoc {{\n        name: {:?},\n        module: {:?},\n        doc: {:?},\n        props: &[",
+            c.name, c.module, c.doc
+        );
+        for p in &c.props {
+            let _ = writeln!(
+                out,
+                "            PropDoc {{ name: {:?}, ty: {:?}, default: {:?}, optional: {:?}, doc: {:?} }},",
+                p.name, p.ty, p.default, p.optional, p.doc
+            );
+        }
+        out.push_str("        ],\n    },\n");
+    }
+    out.push_str("];\n");
+    out
+}
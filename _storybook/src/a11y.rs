@@ -0,0 +1,493 @@
+//! Accessibility audit panel
+//!
+//! Runs a handful of automated checks against the rendered story and lists
+//! what it finds. This is a smoke test, not a substitute for a screen reader
+//! pass - it catches the mistakes that are easy to make and easy to fix:
+//!
+//! - `img-alt` - images without an `alt` attribute
+//! - `control-label` - form controls with no accessible name
+//! - `button-name` - buttons and links with no text or label
+//! - `role-valid` - unknown ARIA roles
+//! - `focusable` - widgets with interactive roles that can't be tabbed to,
+//!   and positive `tabindex` values that break tab order
+//! - `contrast` - text below WCAG AA contrast (4.5:1, 3:1 for large text)
+
+use crate::registry::StoryDef;
+use leptos::prelude::*;
+use std::time::Duration;
+use ui_core::color::{contrast_ratio, parse_css_color, Rgb};
+use wasm_bindgen::JsCast;
+
+/// Root the audit runs against
+const AUDIT_ROOT: &str = ".story-frame";
+
+/// Delay after a story switch before auditing, so async content can settle
+const AUDIT_DELAY: Duration = Duration::from_millis(400);
+
+/// ARIA roles that expect keyboard interaction
+const INTERACTIVE_ROLES: &[&str] = &[
+    "button", "checkbox", "link", "menuitem", "option", "radio", "slider", "switch", "tab",
+];
+
+/// ARIA 1.2 roles (abstract roles excluded)
+const KNOWN_ROLES: &[&str] = &[
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "blockquote",
+    "button",
+    "caption",
+    "cell",
+    "checkbox",
+    "code",
+    "columnheader",
+    "combobox",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "deletion",
+    "dialog",
+    "document",
+    "emphasis",
+    "feed",
+    "figure",
+    "form",
+    "generic",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "insertion",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "marquee",
+    "math",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "meter",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "paragraph",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "strong",
+    "subscript",
+    "superscript",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "time",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+/// How serious a finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    fn class(&self) -> &'static str {
+        match self {
+            Severity::Error => "a11y-panel__severity--error",
+            Severity::Warning => "a11y-panel__severity--warning",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// A single audit finding
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub rule: &'static str,
+    pub severity: Severity,
+    /// Short description of the offending element (`tag#id.class`)
+    pub element: String,
+    pub message: String,
+}
+
+impl Violation {
+    fn new(rule: &'static str, severity: Severity, el: &web_sys::Element, message: String) -> Self {
+        Self {
+            rule,
+            severity,
+            element: describe(el),
+            message,
+        }
+    }
+}
+
+/// Run every check against `root` and its descendants
+pub fn audit(root: &web_sys::Element) -> Vec<Violation> {
+    let mut found = Vec::new();
+    for el in elements(root, "*") {
+        check_img_alt(&el, &mut found);
+        check_control_label(root, &el, &mut found);
+        check_button_name(&el, &mut found);
+        check_role(&el, &mut found);
+        check_focusable(&el, &mut found);
+        check_contrast(&el, &mut found);
+    }
+    found
+}
+
+fn check_img_alt(el: &web_sys::Element, found: &mut Vec<Violation>) {
+    if el.tag_name() == "IMG" && !el.has_attribute("alt") {
+        found.push(Violation::new(
+            "img-alt",
+            Severity::Error,
+            el,
+            "Image has no alt attribute (use alt=\"\" if decorative)".into(),
+        ));
+    }
+}
+
+fn check_control_label(root: &web_sys::Element, el: &web_sys::Element, found: &mut Vec<Violation>) {
+    let is_control = match el.tag_name().as_str() {
+        "INPUT" => !matches!(
+            el.get_attribute("type").as_deref(),
+            Some("hidden" | "submit" | "button" | "reset")
+        ),
+        "SELECT" | "TEXTAREA" => true,
+        _ => false,
+    };
+    if !is_control || has_aria_name(el) || el.closest("label").ok().flatten().is_some() {
+        return;
+    }
+    let id = el.id();
+    if !id.is_empty()
+        && root
+            .query_selector(&format!("label[for=\"{id}\"]"))
+            .ok()
+            .flatten()
+            .is_some()
+    {
+        return;
+    }
+
+    let hint = if el.has_attribute("placeholder") {
+        " - a placeholder is not a label"
+    } else {
+        ""
+    };
+    found.push(Violation::new(
+        "control-label",
+        Severity::Error,
+        el,
+        format!("Form control has no label{hint}"),
+    ));
+}
+
+fn check_button_name(el: &web_sys::Element, found: &mut Vec<Violation>) {
+    let is_button = match el.tag_name().as_str() {
+        "BUTTON" => true,
+        "A" => el.has_attribute("href"),
+        _ => el.get_attribute("role").as_deref() == Some("button"),
+    };
+    if !is_button || has_aria_name(el) {
+        return;
+    }
+    let has_text = el.text_content().is_some_and(|t| !t.trim().is_empty());
+    let has_img_alt = elements(el, "img[alt]").iter().any(|img| {
+        img.get_attribute("alt")
+            .is_some_and(|a| !a.trim().is_empty())
+    });
+    if !has_text && !has_img_alt {
+        found.push(Violation::new(
+            "button-name",
+            Severity::Error,
+            el,
+            "Button or link has no text or aria-label".into(),
+        ));
+    }
+}
+
+fn check_role(el: &web_sys::Element, found: &mut Vec<Violation>) {
+    let Some(role) = el.get_attribute("role") else {
+        return;
+    };
+    // role can list fallbacks; the first recognised one wins
+    if !role.split_whitespace().any(|r| KNOWN_ROLES.contains(&r)) {
+        found.push(Violation::new(
+            "role-valid",
+            Severity::Warning,
+            el,
+            format!("Unknown ARIA role \"{role}\""),
+        ));
+    }
+}
+
+fn check_focusable(el: &web_sys::Element, found: &mut Vec<Violation>) {
+    let tabindex = el
+        .get_attribute("tabindex")
+        .and_then(|t| t.trim().parse::<i32>().ok());
+
+    if let Some(t) = tabindex.filter(|t| *t > 0) {
+        found.push(Violation::new(
+            "focusable",
+            Severity::Warning,
+            el,
+            format!("tabindex=\"{t}\" overrides natural tab order"),
+        ));
+    }
+
+    let Some(role) = el.get_attribute("role") else {
+        return;
+    };
+    let natively_focusable = match el.tag_name().as_str() {
+        "BUTTON" | "INPUT" | "SELECT" | "TEXTAREA" => true,
+        "A" => el.has_attribute("href"),
+        _ => false,
+    };
+    if INTERACTIVE_ROLES.contains(&role.as_str()) && !natively_focusable && tabindex.is_none() {
+        found.push(Violation::new(
+            "focusable",
+            Severity::Error,
+            el,
+            format!("role=\"{role}\" element is not keyboard focusable (add tabindex=\"0\")"),
+        ));
+    }
+}
+
+fn check_contrast(el: &web_sys::Element, found: &mut Vec<Violation>) {
+    if !has_own_text(el) {
+        return;
+    }
+    let Some(style) = computed_style(el) else {
+        return;
+    };
+    let prop = |name: &str| style.get_property_value(name).unwrap_or_default();
+    if prop("display") == "none" || prop("visibility") == "hidden" {
+        return;
+    }
+
+    let Some((fg, fg_alpha)) = parse_css_color(&prop("color")) else {
+        return;
+    };
+    let Some(bg) = effective_background(el) else {
+        // Image or gradient behind the text - can't judge
+        return;
+    };
+    let fg = blend(fg, fg_alpha, bg);
+
+    let font_px: f64 = prop("font-size")
+        .trim_end_matches("px")
+        .parse()
+        .unwrap_or(16.0);
+    let bold = prop("font-weight").parse::<u32>().unwrap_or(400) >= 700;
+    let large = font_px >= 24.0 || (bold && font_px >= 18.66);
+    let required = if large { 3.0 } else { 4.5 };
+
+    let ratio = contrast_ratio(fg, bg);
+    if ratio < required {
+        found.push(Violation::new(
+            "contrast",
+            Severity::Error,
+            el,
+            format!("Text contrast {ratio:.2}:1 is below {required}:1"),
+        ));
+    }
+}
+
+// ============================================================================
+// DOM helpers
+// ============================================================================
+
+fn elements(root: &web_sys::Element, selector: &str) -> Vec<web_sys::Element> {
+    let Ok(list) = root.query_selector_all(selector) else {
+        return Vec::new();
+    };
+    (0..list.length())
+        .filter_map(|i| list.get(i)?.dyn_into::<web_sys::Element>().ok())
+        .collect()
+}
+
+fn computed_style(el: &web_sys::Element) -> Option<web_sys::CssStyleDeclaration> {
+    web_sys::window()?.get_computed_style(el).ok().flatten()
+}
+
+fn has_aria_name(el: &web_sys::Element) -> bool {
+    ["aria-label", "aria-labelledby", "title"]
+        .iter()
+        .any(|attr| el.get_attribute(attr).is_some_and(|v| !v.trim().is_empty()))
+}
+
+/// Whether the element has non-whitespace text directly inside it
+fn has_own_text(el: &web_sys::Element) -> bool {
+    let children = el.child_nodes();
+    (0..children.length()).any(|i| {
+        children.get(i).is_some_and(|n| {
+            n.node_type() == web_sys::Node::TEXT_NODE
+                && n.text_content().is_some_and(|t| !t.trim().is_empty())
+        })
+    })
+}
+
+/// First opaque background behind an element, compositing translucent layers
+///
+/// Returns `None` when a background image is in the way.
+fn effective_background(el: &web_sys::Element) -> Option<Rgb> {
+    let mut layers = Vec::new();
+    let mut current = Some(el.clone());
+    while let Some(node) = current {
+        let style = computed_style(&node)?;
+        if style.get_property_value("background-image").ok()? != "none" {
+            return None;
+        }
+        if let Some((rgb, alpha)) =
+            parse_css_color(&style.get_property_value("background-color").ok()?)
+        {
+            if alpha > 0.0 {
+                layers.push((rgb, alpha));
+                if alpha >= 1.0 {
+                    break;
+                }
+            }
+        }
+        current = node.parent_element();
+    }
+
+    // Nothing opaque found - the page default is white
+    let mut bg = (255, 255, 255);
+    for (rgb, alpha) in layers.into_iter().rev() {
+        bg = blend(rgb, alpha, bg);
+    }
+    Some(bg)
+}
+
+/// Composite `fg` at `alpha` over an opaque `bg`
+fn blend(fg: Rgb, alpha: f64, bg: Rgb) -> Rgb {
+    let mix = |f: u8, b: u8| (f as f64 * alpha + b as f64 * (1.0 - alpha)).round() as u8;
+    (mix(fg.0, bg.0), mix(fg.1, bg.1), mix(fg.2, bg.2))
+}
+
+/// `tag#id.class` summary for listing
+fn describe(el: &web_sys::Element) -> String {
+    let mut out = el.tag_name().to_lowercase();
+    let id = el.id();
+    if !id.is_empty() {
+        out.push('#');
+        out.push_str(&id);
+    }
+    if let Some(class) = el.class_name().split_whitespace().next() {
+        out.push('.');
+        out.push_str(class);
+    }
+    out
+}
+
+// ============================================================================
+// Panel
+// ============================================================================
+
+/// Collapsible panel listing audit results for the current story
+#[component]
+pub fn A11yPanel(story: Signal<StoryDef>) -> impl IntoView {
+    let results = RwSignal::new(None::<Vec<Violation>>);
+    let expanded = RwSignal::new(false);
+
+    let run = move || {
+        let root = document().query_selector(AUDIT_ROOT).ok().flatten();
+        results.set(root.map(|r| audit(&r)));
+    };
+
+    // Re-audit shortly after each story switch
+    Effect::new(move |_| {
+        story.track();
+        results.set(None);
+        set_timeout(run, AUDIT_DELAY);
+    });
+
+    let summary = move || match results.get() {
+        None => "Accessibility: running...".to_string(),
+        Some(r) if r.is_empty() => "Accessibility: no issues found".to_string(),
+        Some(r) => {
+            let errors = r.iter().filter(|v| v.severity == Severity::Error).count();
+            let warnings = r.len() - errors;
+            format!("Accessibility: {errors} errors, {warnings} warnings")
+        }
+    };
+
+    view! {
+        <section class="a11y-panel">
+            <div class="a11y-panel__header">
+                <button
+                    class="a11y-panel__toggle"
+                    aria-expanded=move || expanded.get().to_string()
+                    on:click=move |_| expanded.update(|e| *e = !*e)
+                >
+                    {move || if expanded.get() { "▾ " } else { "▸ " }}
+                    {summary}
+                </button>
+                <button class="a11y-panel__rerun" on:click=move |_| run()>
+                    "Re-run"
+                </button>
+            </div>
+            <Show when=move || expanded.get() fallback=|| ()>
+                <ul class="a11y-panel__list">
+                    {move || {
+                        results
+                            .get()
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|v| {
+                                view! {
+                                    <li class="a11y-panel__item">
+                                        <span class=format!(
+                                            "a11y-panel__severity {}",
+                                            v.severity.class(),
+                                        )>{v.severity.label()}</span>
+                                        <code class="a11y-panel__rule">{v.rule}</code>
+                                        <code class="a11y-panel__element">{v.element}</code>
+                                        <span class="a11y-panel__message">{v.message}</span>
+                                    </li>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </ul>
+            </Show>
+        </section>
+    }
+}
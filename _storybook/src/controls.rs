@@ -0,0 +1,355 @@
+//! Interactive story controls ("knobs")
+//!
+//! Stories declare typed controls with [`use_controls`]; the storybook renders
+//! them in a side panel and the returned signals update the demo live. Values
+//! are mirrored into the URL query (`?ctl.<name>=<value>`) so a specific
+//! configuration can be shared by copying the address bar.
+//!
+//! Controls are scoped to the story that declared them and are removed when
+//! it unmounts.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let controls = use_controls();
+//! let label = controls.text("label", "New");
+//! let variant = controls.select("variant", &["solid", "outline", "subtle"], "solid");
+//! let color = controls.color("color", "#4a9eff");
+//!
+//! view! {
+//!     {move || view! { <Badge label=label.get() color=color.get() /> }}
+//! }
+//! ```
+
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+/// Query parameter prefix for control values
+const URL_PREFIX: &str = "ctl.";
+
+/// Kind of input rendered for a control
+#[derive(Debug, Clone, PartialEq)]
+pub enum ControlKind {
+    Bool,
+    Select(&'static [&'static str]),
+    Number { min: f64, max: f64, step: f64 },
+    Color,
+    Text,
+}
+
+/// A control declared by the current story
+#[derive(Debug, Clone, PartialEq)]
+pub struct ControlDef {
+    pub name: &'static str,
+    pub kind: ControlKind,
+    pub default: String,
+}
+
+/// Registry of controls for the active story
+///
+/// Provided once by the storybook shell; stories reach it via [`use_controls`].
+#[derive(Clone, Copy)]
+pub struct Controls {
+    defs: RwSignal<Vec<ControlDef>>,
+    values: RwSignal<HashMap<&'static str, String>>,
+}
+
+impl Controls {
+    pub fn new() -> Self {
+        Self {
+            defs: RwSignal::new(Vec::new()),
+            values: RwSignal::new(HashMap::new()),
+        }
+    }
+
+    /// Controls currently declared (tracked)
+    pub fn defs(&self) -> Vec<ControlDef> {
+        self.defs.get()
+    }
+
+    /// Raw value of a control (tracked)
+    pub fn raw(&self, name: &str) -> Option<String> {
+        self.values.with(|v| v.get(name).cloned())
+    }
+
+    /// Update a control from the panel
+    pub fn set_raw(&self, name: &'static str, value: String) {
+        self.values.update(|v| {
+            v.insert(name, value);
+        });
+        self.sync_url();
+    }
+
+    /// Restore every control to its declared default
+    pub fn reset(&self) {
+        let defaults: HashMap<_, _> = self
+            .defs
+            .with_untracked(|defs| defs.iter().map(|d| (d.name, d.default.clone())).collect());
+        self.values.set(defaults);
+        self.sync_url();
+    }
+
+    /// Boolean toggle
+    pub fn bool(&self, name: &'static str, default: bool) -> Signal<bool> {
+        self.register(name, ControlKind::Bool, default.to_string());
+        let values = self.values;
+        Signal::derive(move || values.with(|v| v.get(name).map(|s| s == "true").unwrap_or(default)))
+    }
+
+    /// One of a fixed set of options
+    ///
+    /// A URL value that isn't one of `options` falls back to `default`.
+    pub fn select(
+        &self,
+        name: &'static str,
+        options: &'static [&'static str],
+        default: &'static str,
+    ) -> Signal<String> {
+        self.register(name, ControlKind::Select(options), default.to_string());
+        let values = self.values;
+        Signal::derive(move || {
+            values.with(|v| {
+                v.get(name)
+                    .filter(|s| options.contains(&s.as_str()))
+                    .cloned()
+                    .unwrap_or_else(|| default.to_string())
+            })
+        })
+    }
+
+    /// Number within a range, rendered as a slider
+    pub fn number(
+        &self,
+        name: &'static str,
+        default: f64,
+        min: f64,
+        max: f64,
+        step: f64,
+    ) -> Signal<f64> {
+        self.register(
+            name,
+            ControlKind::Number { min, max, step },
+            default.to_string(),
+        );
+        let values = self.values;
+        Signal::derive(move || {
+            values.with(|v| {
+                v.get(name)
+                    .and_then(|s| s.parse::<f64>().ok())
+                    .map(|n| n.clamp(min, max))
+                    .unwrap_or(default)
+            })
+        })
+    }
+
+    /// CSS color, rendered as a color picker
+    pub fn color(&self, name: &'static str, default: &'static str) -> Signal<String> {
+        self.string_control(name, ControlKind::Color, default)
+    }
+
+    /// Free text
+    pub fn text(&self, name: &'static str, default: &'static str) -> Signal<String> {
+        self.string_control(name, ControlKind::Text, default)
+    }
+
+    fn string_control(
+        &self,
+        name: &'static str,
+        kind: ControlKind,
+        default: &'static str,
+    ) -> Signal<String> {
+        self.register(name, kind, default.to_string());
+        let values = self.values;
+        Signal::derive(move || {
+            values.with(|v| v.get(name).cloned().unwrap_or_else(|| default.to_string()))
+        })
+    }
+
+    /// Declare a control, seeding its value from the URL
+    fn register(&self, name: &'static str, kind: ControlKind, default: String) {
+        let initial = url_value(name).unwrap_or_else(|| default.clone());
+        self.defs.update(|defs| {
+            defs.retain(|d| d.name != name);
+            defs.push(ControlDef {
+                name,
+                kind,
+                default,
+            });
+        });
+        self.values.update(|v| {
+            v.insert(name, initial);
+        });
+
+        let controls = *self;
+        on_cleanup(move || controls.unregister(name));
+    }
+
+    fn unregister(&self, name: &'static str) {
+        self.defs.update(|defs| defs.retain(|d| d.name != name));
+        self.values.update(|v| {
+            v.remove(name);
+        });
+        self.sync_url();
+    }
+
+    /// Rewrite the `ctl.*` query parameters to match the current values
+    fn sync_url(&self) {
+        let Some((location, history)) = window_location() else {
+            return;
+        };
+        let Ok(params) =
+            web_sys::UrlSearchParams::new_with_str(&location.search().unwrap_or_default())
+        else {
+            return;
+        };
+
+        // Drop stale control params, keep anything else (e.g. the story id)
+        let keys: Vec<String> = js_sys::Array::from(&params.keys())
+            .iter()
+            .filter_map(|k| k.as_string())
+            .filter(|k| k.starts_with(URL_PREFIX))
+            .collect();
+        for key in keys {
+            params.delete(&key);
+        }
+
+        self.values.with_untracked(|values| {
+            self.defs.with_untracked(|defs| {
+                // Defaults are left out to keep shared links short
+                for def in defs {
+                    if let Some(value) = values.get(def.name).filter(|v| **v != def.default) {
+                        params.set(&format!("{URL_PREFIX}{}", def.name), value);
+                    }
+                }
+            })
+        });
+
+        let query = String::from(params.to_string());
+        let pathname = location.pathname().unwrap_or_default();
+        let hash = location.hash().unwrap_or_default();
+        let url = if query.is_empty() {
+            format!("{pathname}{hash}")
+        } else {
+            format!("{pathname}?{query}{hash}")
+        };
+        let _ = history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&url));
+    }
+}
+
+impl Default for Controls {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get the storybook's control registry
+pub fn use_controls() -> Controls {
+    expect_context::<Controls>()
+}
+
+fn window_location() -> Option<(web_sys::Location, web_sys::History)> {
+    let window = web_sys::window()?;
+    Some((window.location(), window.history().ok()?))
+}
+
+/// Read a control value from the page URL
+fn url_value(name: &str) -> Option<String> {
+    let (location, _) = window_location()?;
+    let params = web_sys::UrlSearchParams::new_with_str(&location.search().ok()?).ok()?;
+    params.get(&format!("{URL_PREFIX}{name}"))
+}
+
+/// Side panel listing the active story's controls
+#[component]
+pub fn ControlsPanel() -> impl IntoView {
+    let controls = use_controls();
+    let has_controls = move || controls.defs.with(|d| !d.is_empty());
+
+    view! {
+        <Show when=has_controls fallback=|| ()>
+            <aside class="storybook-controls">
+                <div class="storybook-controls__header">
+                    <h2>"Controls"</h2>
+                    <button class="storybook-controls__reset" on:click=move |_| controls.reset()>
+                        "Reset"
+                    </button>
+                </div>
+                <For
+                    each=move || controls.defs()
+                    key=|def| def.name
+                    let:def
+                >
+                    <ControlRow def=def />
+                </For>
+            </aside>
+        </Show>
+    }
+}
+
+#[component]
+fn ControlRow(def: ControlDef) -> impl IntoView {
+    let controls = use_controls();
+    let name = def.name;
+    let value = move || controls.raw(name).unwrap_or_default();
+
+    let input = match def.kind {
+        ControlKind::Bool => view! {
+            <input
+                type="checkbox"
+                prop:checked=move || value() == "true"
+                on:change=move |ev| controls.set_raw(name, event_target_checked(&ev).to_string())
+            />
+        }
+        .into_any(),
+        ControlKind::Select(options) => view! {
+            <select
+                class="demo-select"
+                prop:value=value
+                on:change=move |ev| controls.set_raw(name, event_target_value(&ev))
+            >
+                {options
+                    .iter()
+                    .map(|opt| view! { <option value=*opt>{*opt}</option> })
+                    .collect_view()}
+            </select>
+        }
+        .into_any(),
+        ControlKind::Number { min, max, step } => view! {
+            <input
+                type="range"
+                min=min.to_string()
+                max=max.to_string()
+                step=step.to_string()
+                prop:value=value
+                on:input=move |ev| controls.set_raw(name, event_target_value(&ev))
+            />
+            <span class="storybook-controls__number">{value}</span>
+        }
+        .into_any(),
+        ControlKind::Color => view! {
+            <input
+                type="color"
+                prop:value=value
+                on:input=move |ev| controls.set_raw(name, event_target_value(&ev))
+            />
+            <span class="storybook-controls__number">{value}</span>
+        }
+        .into_any(),
+        ControlKind::Text => view! {
+            <input
+                class="demo-input"
+                type="text"
+                prop:value=value
+                on:input=move |ev| controls.set_raw(name, event_target_value(&ev))
+            />
+        }
+        .into_any(),
+    };
+
+    view! {
+        <label class="storybook-controls__row">
+            <span class="storybook-controls__name">{name}</span>
+            <span class="storybook-controls__input">{input}</span>
+        </label>
+    }
+}
@@ -0,0 +1,239 @@
+//! Mock wallet sandbox
+//!
+//! Injects wallet-core's [`MockWallet`] into `window.cardano` so wallet
+//! stories are demoable without a browser extension. The fixture is chosen
+//! from the toolbar, saved with the other storybook settings, and installed
+//! at startup so auto-reconnect finds it.
+//!
+//! The mock takes the Eternl slot; a real Eternl extension is hidden while a
+//! fixture is active and restored when it's turned off.
+
+use crate::toolbar::SETTINGS_NAMESPACE;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use ui_core::settings::Settings;
+use wallet_core::{install_mock_wallet, uninstall_mock_wallet, MockWallet, WalletInfo};
+
+/// Settings key for the selected fixture
+pub const MOCK_WALLET_KEY: &str = "mock_wallet";
+
+/// Black Flag Pirates policy, so gallery images resolve
+const PIRATE_POLICY: &str = "b3dab69f7e6100849434fb1781e34bd12a916557f6231b8d2629b6f6";
+
+/// Assets per UTxO when spreading a collection across outputs
+const ASSETS_PER_UTXO: usize = 60;
+
+/// Mainnet enterprise address (header 0x61) with a made-up key hash
+const ADDRESS_HEX: &str = "61d1b2c3a4f5e6d7c8b9a0f1e2d3c4b5a6978877665544332211aabbcc";
+
+const LOVELACE_PER_ADA: u64 = 1_000_000;
+
+/// Wallet contents the mock reports
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MockFixture {
+    /// No mock; real extensions only
+    #[default]
+    Off,
+    /// A dozen NFTs and some ADA
+    FewNfts,
+    /// Thousands of NFTs across many UTxOs
+    ManyNfts,
+    /// Connected but empty
+    ZeroBalance,
+    /// A dozen NFTs, with every third call failing
+    ErrorProne,
+}
+
+impl MockFixture {
+    pub fn all() -> &'static [MockFixture] {
+        &[
+            MockFixture::Off,
+            MockFixture::FewNfts,
+            MockFixture::ManyNfts,
+            MockFixture::ZeroBalance,
+            MockFixture::ErrorProne,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MockFixture::Off => "Off",
+            MockFixture::FewNfts => "Few NFTs",
+            MockFixture::ManyNfts => "Thousands of NFTs",
+            MockFixture::ZeroBalance => "Zero balance",
+            MockFixture::ErrorProne => "Error-prone",
+        }
+    }
+
+    /// Value for the toolbar `<select>`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MockFixture::Off => "off",
+            MockFixture::FewNfts => "few_nfts",
+            MockFixture::ManyNfts => "many_nfts",
+            MockFixture::ZeroBalance => "zero_balance",
+            MockFixture::ErrorProne => "error_prone",
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|f| f.as_str() == value)
+            .unwrap_or_default()
+    }
+
+    /// The wallet for this fixture, `None` when off
+    fn wallet(&self) -> Option<MockWallet> {
+        let wallet = match self {
+            MockFixture::Off => return None,
+            MockFixture::FewNfts => pirate_wallet(12, 250),
+            MockFixture::ManyNfts => pirate_wallet(3_000, 1_200),
+            MockFixture::ZeroBalance => MockWallet::new(),
+            MockFixture::ErrorProne => pirate_wallet(12, 250).failing_every(3),
+        };
+        Some(wallet.with_wallets(vec![WalletInfo {
+            api_name: "eternl".into(),
+            name: "Mock Wallet".into(),
+            icon: None,
+        }]))
+    }
+}
+
+/// Install `fixture`, replacing any mock already installed
+pub fn apply(fixture: MockFixture) {
+    match fixture.wallet() {
+        Some(wallet) => {
+            if let Err(e) = install_mock_wallet(Rc::new(wallet)) {
+                tracing::warn!("Failed to install mock wallet: {e}");
+            }
+        }
+        None => uninstall_mock_wallet(),
+    }
+}
+
+/// Install the fixture saved from the toolbar
+pub fn apply_saved() {
+    let settings = Settings::new(SETTINGS_NAMESPACE);
+    apply(settings.get(MOCK_WALLET_KEY).unwrap_or_default());
+}
+
+/// Mainnet wallet holding pirates #1..=`count` and `ada` ADA
+fn pirate_wallet(count: u32, ada: u64) -> MockWallet {
+    let names: Vec<Vec<u8>> = (1..=count)
+        .map(|n| format!("Pirate{n}").into_bytes())
+        .collect();
+    let policy = decode_hex(PIRATE_POLICY);
+    let address = decode_hex(ADDRESS_HEX);
+
+    let chunks: Vec<_> = names.chunks(ASSETS_PER_UTXO).collect();
+    // Spread the ADA over the UTxOs; the first takes the remainder
+    let lovelace = ada * LOVELACE_PER_ADA;
+    let share = lovelace / chunks.len() as u64;
+    let utxos = chunks
+        .iter()
+        .enumerate()
+        .map(|(i, names)| {
+            let coin = if i == 0 {
+                lovelace - share * (chunks.len() as u64 - 1)
+            } else {
+                share
+            };
+            let mut cbor = Cbor::default();
+            cbor.array(2);
+            // Input: made-up tx hash numbered by output
+            cbor.array(2);
+            let mut tx_hash = [0xab; 32];
+            tx_hash[..4].copy_from_slice(&(i as u32).to_be_bytes());
+            cbor.bytes(&tx_hash);
+            cbor.uint(0);
+            // Output: legacy [address, value]
+            cbor.array(2);
+            cbor.bytes(&address);
+            cbor.value(coin, &policy, names);
+            cbor.into_hex()
+        })
+        .collect();
+
+    let mut balance = Cbor::default();
+    balance.value(lovelace, &policy, &names);
+
+    MockWallet::new()
+        .with_network_id(1)
+        .with_addresses(vec![ADDRESS_HEX.to_string()])
+        .with_balance(balance.into_hex())
+        .with_utxos(utxos)
+}
+
+/// Just enough CBOR to build Values and UTxOs
+#[derive(Default)]
+struct Cbor(Vec<u8>);
+
+impl Cbor {
+    fn head(&mut self, major: u8, n: u64) {
+        let major = major << 5;
+        match n {
+            0..=23 => self.0.push(major | n as u8),
+            24..=0xff => self.0.extend([major | 24, n as u8]),
+            0x100..=0xffff => {
+                self.0.push(major | 25);
+                self.0.extend((n as u16).to_be_bytes());
+            }
+            0x1_0000..=0xffff_ffff => {
+                self.0.push(major | 26);
+                self.0.extend((n as u32).to_be_bytes());
+            }
+            _ => {
+                self.0.push(major | 27);
+                self.0.extend(n.to_be_bytes());
+            }
+        }
+    }
+
+    fn uint(&mut self, n: u64) {
+        self.head(0, n);
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.head(2, bytes.len() as u64);
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn array(&mut self, len: usize) {
+        self.head(4, len as u64);
+    }
+
+    fn map(&mut self, len: usize) {
+        self.head(5, len as u64);
+    }
+
+    /// `coin` or `[coin, {policy: {name: 1}}]`
+    fn value(&mut self, coin: u64, policy: &[u8], names: &[Vec<u8>]) {
+        if names.is_empty() {
+            self.uint(coin);
+            return;
+        }
+        self.array(2);
+        self.uint(coin);
+        self.map(1);
+        self.bytes(policy);
+        self.map(names.len());
+        for name in names {
+            self.bytes(name);
+            self.uint(1);
+        }
+    }
+
+    fn into_hex(self) -> String {
+        self.0.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .filter_map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
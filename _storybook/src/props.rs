@@ -0,0 +1,146 @@
+//! Props documentation panel
+//!
+//! Shows the prop tables of the components a story covers, so prop names,
+//! types and defaults don't need a trip to the source. The tables are
+//! generated at build time by `build.rs` from the `#[component]` signatures
+//! and their `///` comments.
+//!
+//! A story covers the public components defined in the module matching its
+//! id (`color-swatch` -> `color_swatch.rs`), plus any component whose name
+//! matches the id (`page-header` -> `PageHeader`).
+
+use crate::registry::StoryDef;
+use leptos::prelude::*;
+
+/// A documented `#[component]`
+pub struct ComponentDoc {
+    /// Component name
+    pub name: &'static str,
+    /// Source file stem
+    pub module: &'static str,
+    /// `///` comment on the component function
+    pub doc: &'static str,
+    /// Props in declaration order
+    pub props: &'static [PropDoc],
+}
+
+/// A documented component prop
+pub struct PropDoc {
+    /// Prop name
+    pub name: &'static str,
+    /// Declared type
+    pub ty: &'static str,
+    /// Default as written, for optional props
+    pub default: Option<&'static str>,
+    /// Whether the prop may be omitted
+    pub optional: bool,
+    /// `///` comment on the prop
+    pub doc: &'static str,
+}
+
+include!(concat!(env!("OUT_DIR"), "/component_props.rs"));
+
+/// `PageHeader` -> `page-header`
+fn kebab_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i > 0 {
+                out.push('-');
+            }
+            out.extend(ch.to_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// Components documented for a story
+pub fn for_story(story: &StoryDef) -> Vec<&'static ComponentDoc> {
+    let module = story.id.replace('-', "_");
+    COMPONENTS
+        .iter()
+        .filter(|c| c.module == module || kebab_case(c.name) == story.id)
+        .collect()
+}
+
+/// Collapsible prop tables for the current story
+#[component]
+pub fn PropsPanel(story: Signal<StoryDef>) -> impl IntoView {
+    let expanded = RwSignal::new(true);
+
+    move || {
+        let components = story.with(for_story);
+        if components.is_empty() {
+            return None;
+        }
+
+        let summary = match components.as_slice() {
+            [one] => format!("Props: {}", one.name),
+            many => format!("Props: {} components", many.len()),
+        };
+
+        Some(view! {
+            <section class="props-panel">
+                <div class="props-panel__header">
+                    <button
+                        class="props-panel__toggle"
+                        aria-expanded=move || expanded.get().to_string()
+                        on:click=move |_| expanded.update(|e| *e = !*e)
+                    >
+                        {move || if expanded.get() { "▾ " } else { "▸ " }}
+                        {summary}
+                    </button>
+                </div>
+                <Show when=move || expanded.get()>
+                    {components.iter().map(|&c| view! { <ComponentProps component=c /> }).collect_view()}
+                </Show>
+            </section>
+        })
+    }
+}
+
+/// Prop table for one component
+#[component]
+fn ComponentProps(component: &'static ComponentDoc) -> impl IntoView {
+    view! {
+        <div class="props-panel__component">
+            <h4 class="props-panel__name">{format!("<{} />", component.name)}</h4>
+            {(!component.doc.is_empty()).then(|| view! {
+                <p class="props-panel__doc">{component.doc}</p>
+            })}
+            {if component.props.is_empty() {
+                view! { <p class="props-panel__empty">"No props"</p> }.into_any()
+            } else {
+                view! {
+                    <table class="props-panel__table">
+                        <thead>
+                            <tr>
+                                <th>"Prop"</th>
+                                <th>"Type"</th>
+                                <th>"Default"</th>
+                                <th>"Description"</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {component.props.iter().map(|prop| view! {
+                                <tr>
+                                    <td>
+                                        <code>{prop.name}</code>
+                                        {(!prop.optional).then(|| view! {
+                                            <span class="props-panel__required" title="Required">"*"</span>
+                                        })}
+                                    </td>
+                                    <td><code class="props-panel__type">{prop.ty}</code></td>
+                                    <td>{prop.default.map(|d| view! { <code>{d}</code> })}</td>
+                                    <td class="props-panel__description">{prop.doc}</td>
+                                </tr>
+                            }).collect_view()}
+                        </tbody>
+                    </table>
+                }.into_any()
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,128 @@
+//! Story registry
+//!
+//! Each story module declares its entries as [`StoryDef`] consts next to the
+//! story component (`STORY` for one story, `STORIES` for several), and the
+//! module's parent lists them in sidebar order. Ids are stable and used for
+//! deep links (`?story=<id>`) and snapshot file names, so don't rename them.
+//!
+//! ## Adding a story
+//!
+//! ```ignore
+//! // stories/components/my_widget.rs
+//! pub const STORY: StoryDef = StoryDef {
+//!     id: "my-widget",
+//!     label: "My Widget",
+//!     category: "Data Display",
+//!     keywords: &["gizmo", "thing"],
+//!     render: || view! { <MyWidgetStory /> }.into_any(),
+//! };
+//!
+//! // stories/components/mod.rs - add `my_widget::STORY` to `STORIES`
+//! ```
+
+use leptos::prelude::*;
+
+/// A single story in the storybook
+#[derive(Clone, Copy)]
+pub struct StoryDef {
+    /// Stable kebab-case identifier used in URLs
+    pub id: &'static str,
+    /// Sidebar label
+    pub label: &'static str,
+    /// Sidebar section
+    pub category: &'static str,
+    /// Extra search terms (component aliases, prop names, ...)
+    pub keywords: &'static [&'static str],
+    /// Renders the story
+    pub render: fn() -> AnyView,
+}
+
+impl PartialEq for StoryDef {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for StoryDef {}
+
+impl std::fmt::Debug for StoryDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StoryDef").field(&self.id).finish()
+    }
+}
+
+impl StoryDef {
+    /// Relevance of this story for a search query, `None` if it doesn't match
+    ///
+    /// Every whitespace-separated term must match the label, id, category or
+    /// a keyword. Label matches are weighted highest.
+    pub fn search_score(&self, query: &str) -> Option<u32> {
+        query.split_whitespace().try_fold(0, |total, term| {
+            let best = [
+                fuzzy_score(term, self.label).map(|s| s * 2),
+                fuzzy_score(term, self.id),
+                fuzzy_score(term, self.category),
+            ]
+            .into_iter()
+            .chain(self.keywords.iter().map(|k| fuzzy_score(term, k)))
+            .flatten()
+            .max()?;
+            Some(total + best)
+        })
+    }
+}
+
+/// All stories in sidebar order
+pub fn all() -> Vec<StoryDef> {
+    crate::stories::all()
+}
+
+/// Look up a story by id
+pub fn find(id: &str) -> Option<StoryDef> {
+    all().into_iter().find(|s| s.id == id)
+}
+
+/// Stories matching `query`, best match first
+///
+/// An empty query returns every story in sidebar order.
+pub fn search(query: &str) -> Vec<StoryDef> {
+    let mut scored: Vec<(u32, StoryDef)> = all()
+        .into_iter()
+        .filter_map(|s| s.search_score(query).map(|score| (score, s)))
+        .collect();
+    // Stable sort keeps sidebar order for ties
+    scored.sort_by(|a, b| b.0.cmp(&a.0));
+    scored.into_iter().map(|(_, s)| s).collect()
+}
+
+/// Case-insensitive subsequence match
+///
+/// Consecutive characters and matches at the start of a word score higher.
+fn fuzzy_score(query: &str, text: &str) -> Option<u32> {
+    let mut query = query.chars().flat_map(char::to_lowercase).peekable();
+    let mut score = 0;
+    let mut prev: Option<char> = None;
+    let mut prev_matched = false;
+
+    for ch in text.chars().flat_map(char::to_lowercase) {
+        let Some(&want) = query.peek() else {
+            break;
+        };
+        if ch == want {
+            query.next();
+            score += 1;
+            if prev_matched {
+                score += 2;
+            }
+            if prev.is_none_or(|p| matches!(p, ' ' | '-' | '_' | '/')) {
+                score += 3;
+            }
+            prev_matched = true;
+        } else {
+            prev_matched = false;
+        }
+        prev = Some(ch);
+    }
+
+    query.peek().is_none().then_some(score)
+}
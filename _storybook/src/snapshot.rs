@@ -0,0 +1,119 @@
+//! Visual regression snapshot mode
+//!
+//! Loading the storybook with `?snapshot` mounts a bare, fixed-width canvas
+//! instead of the interactive shell: no sidebar, no toolbar, animations off,
+//! controls at their defaults. A screenshot harness drives it through
+//! `window.__storybookSnapshot`:
+//!
+//! - `stories()` - array of `{ index, id, label, category }`
+//! - `show(index)` - renders that story; the returned Promise resolves once it
+//!   has painted, and rejects for an unknown index
+//!
+//! ## Playwright example
+//!
+//! ```js
+//! await page.goto(`${base}/?snapshot`);
+//! await page.waitForFunction(() => window.__storybookSnapshot);
+//! const stories = await page.evaluate(() => window.__storybookSnapshot.stories());
+//! for (const s of stories) {
+//!     await page.evaluate((i) => window.__storybookSnapshot.show(i), s.index);
+//!     await page.waitForLoadState("networkidle");
+//!     await expect(page.locator(".snapshot-frame")).toHaveScreenshot(`${s.id}.png`);
+//! }
+//! ```
+
+use crate::controls::Controls;
+use crate::registry::{self, StoryDef};
+use crate::StoryContent;
+use js_sys::{Array, Object, Promise, Reflect};
+use leptos::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+
+/// Query parameter that switches the storybook into snapshot mode
+const SNAPSHOT_PARAM: &str = "snapshot";
+
+/// Global the harness talks to
+const HOOK_NAME: &str = "__storybookSnapshot";
+
+/// Whether the page was loaded in snapshot mode
+pub fn is_requested() -> bool {
+    web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .and_then(|search| web_sys::UrlSearchParams::new_with_str(&search).ok())
+        .is_some_and(|params| params.has(SNAPSHOT_PARAM))
+}
+
+/// Bare story canvas for screenshot capture
+#[component]
+pub fn SnapshotApp() -> impl IntoView {
+    let (story, set_story) = signal(registry::all()[0]);
+    provide_context(Controls::new());
+
+    if let Err(e) = install_hook(set_story) {
+        tracing::error!("Failed to install snapshot hook: {e:?}");
+    }
+
+    view! {
+        <style>{ui_components::STYLES}</style>
+        <div
+            class="story-frame story-frame--reduced-motion snapshot-frame"
+            data-theme="dark"
+            data-story=move || story.get().id
+        >
+            <StoryContent story=story.into() />
+        </div>
+    }
+}
+
+/// Expose `window.__storybookSnapshot` for the harness
+fn install_hook(set_story: WriteSignal<StoryDef>) -> Result<(), JsValue> {
+    let window = web_sys::window().ok_or("no window")?;
+    let hook = Object::new();
+
+    let stories = Closure::<dyn Fn() -> Array>::new(|| {
+        registry::all()
+            .into_iter()
+            .enumerate()
+            .map(|(index, story)| {
+                let entry = Object::new();
+                let _ = Reflect::set(&entry, &"index".into(), &(index as u32).into());
+                let _ = Reflect::set(&entry, &"id".into(), &story.id.into());
+                let _ = Reflect::set(&entry, &"label".into(), &story.label.into());
+                let _ = Reflect::set(&entry, &"category".into(), &story.category.into());
+                JsValue::from(entry)
+            })
+            .collect()
+    });
+
+    let show = Closure::<dyn Fn(u32) -> Promise>::new(move |index: u32| {
+        let Some(story) = registry::all().get(index as usize).copied() else {
+            return Promise::reject(&format!("No story at index {index}").into());
+        };
+        set_story.set(story);
+        future_to_promise(async move {
+            // Two frames: one for Leptos to flush, one for the browser to paint
+            next_frame().await?;
+            next_frame().await?;
+            Ok(story.id.into())
+        })
+    });
+
+    Reflect::set(&hook, &"stories".into(), stories.as_ref())?;
+    Reflect::set(&hook, &"show".into(), show.as_ref())?;
+    Reflect::set(&window, &HOOK_NAME.into(), &hook)?;
+
+    // The hook lives for the lifetime of the page
+    stories.forget();
+    show.forget();
+    Ok(())
+}
+
+/// Resolve on the next animation frame
+async fn next_frame() -> Result<JsValue, JsValue> {
+    let window = web_sys::window().ok_or("no window")?;
+    let promise = Promise::new(&mut |resolve, _reject| {
+        let _ = window.request_animation_frame(&resolve);
+    });
+    JsFuture::from(promise).await
+}
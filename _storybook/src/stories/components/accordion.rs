@@ -1,10 +1,19 @@
 //! Accordion component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::{resolve_iiif_image, AttributeCard};
 use cardano_assets::AssetId;
 use leptos::prelude::*;
 use ui_components::{Accordion, AccordionItem};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "accordion",
+    label: "Accordion",
+    category: "Layout",
+    keywords: &["collapse", "expand", "disclosure"],
+    render: || view! { <AccordionStory /> }.into_any(),
+};
+
 // Black Flag Pirates policy ID
 const BFP_POLICY: &str = "b3dab69f7e6100849434fb1781e34bd12a916557f6231b8d2629b6f6";
 
@@ -1,9 +1,18 @@
 //! Alert component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{Alert, AlertVariant};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "alert",
+    label: "Alert",
+    category: "Feedback",
+    keywords: &["banner", "notice", "error", "warning"],
+    render: || view! { <AlertStory /> }.into_any(),
+};
+
 #[component]
 pub fn AlertStory() -> impl IntoView {
     let (show_dismissible, set_show_dismissible) = signal(true);
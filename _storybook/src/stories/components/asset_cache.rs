@@ -1,9 +1,18 @@
 //! Asset Cache component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{AssetCache, AssetCard, PreloadAsset};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "asset-cache",
+    label: "Asset Cache",
+    category: "Data Display",
+    keywords: &["preload", "image", "iiif"],
+    render: || view! { <AssetCacheStory /> }.into_any(),
+};
+
 /// Sample Pirate assets for testing
 const TEST_ASSETS: &[(&str, &str)] = &[
     (
@@ -39,10 +48,7 @@ pub fn AssetCacheStory() -> impl IntoView {
 
     let test_assets: Vec<PreloadAsset> = TEST_ASSETS
         .iter()
-        .map(|(policy_id, asset_name_hex)| PreloadAsset {
-            policy_id: policy_id.to_string(),
-            asset_name_hex: asset_name_hex.to_string(),
-        })
+        .map(|(policy_id, asset_name_hex)| PreloadAsset::new(*policy_id, *asset_name_hex))
         .collect();
 
     view! {
@@ -152,7 +158,7 @@ pub fn AssetCacheStory() -> impl IntoView {
                         <AttributeCard
                             name="2. Store"
                             values="Blob URLs"
-                            description="Images are stored in a global cache as blob URLs, keeping them in memory."
+                            description="Images are stored in a global cache as blob URLs, and persisted to Cache Storage so repeat visits skip the network."
                         />
                         <AttributeCard
                             name="3. Use"
@@ -171,7 +177,12 @@ pub fn AssetCacheStory() -> impl IntoView {
                         <AttributeCard
                             name="assets"
                             values="Signal<Vec<PreloadAsset>>"
-                            description="List of assets to preload. Each has policy_id and asset_name_hex."
+                            description="List of assets to preload. Each has policy_id, asset_name_hex and a priority hint."
+                        />
+                        <AttributeCard
+                            name="max_concurrent"
+                            values="Option<usize>"
+                            description="Maximum simultaneous fetches (default 6). Queued assets are fetched highest priority first."
                         />
                         <AttributeCard
                             name="on_progress"
@@ -190,16 +201,15 @@ pub fn AssetCacheStory() -> impl IntoView {
             // Code example
             <div class="story-section">
                 <h3>"Usage"</h3>
-                <pre class="code-block">{r##"use ui_components::{AssetCache, AssetCard, PreloadAsset};
+                <pre class="code-block">{r##"use ui_components::{AssetCache, AssetCard, PreloadAsset, PreloadPriority};
 
 let (assets, set_assets) = create_signal(vec![]);
 
 // Start preloading
 set_assets.set(vec![
-    PreloadAsset {
-        policy_id: "abc...".to_string(),
-        asset_name_hex: "def...".to_string(),
-    },
+    // Visible cards first
+    PreloadAsset::new("abc...", "def...").with_priority(PreloadPriority::High),
+    PreloadAsset::new("abc...", "123..."),
     // ...more assets
 ]);
 
@@ -238,8 +248,15 @@ if let Some(blob_url) = image_cache::get_cached_url(&image_url) {
     // Use the blob URL directly
 }
 
-// Manually preload an image (async)
-let blob_url = image_cache::preload_image(url).await?;"##}</pre>
+// Manually preload an image (async) - checks memory, then disk, then network
+let blob_url = image_cache::preload_image(url).await?;
+
+// Cap the persistent (Cache Storage) tier - least recently used images are evicted
+image_cache::set_persistent_limit(50 * 1024 * 1024);
+let bytes = image_cache::persistent_cache_bytes();
+
+// Wipe memory and disk tiers (e.g. from an admin/settings screen)
+image_cache::clear_cache().await?;"##}</pre>
             </div>
         </div>
     }
@@ -1,9 +1,20 @@
 //! Asset Card component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use cardano_assets::AssetId;
 use leptos::prelude::*;
-use ui_components::{children_fn, AssetCard, AssetModal, Badge, CardSize, StatPill};
+use ui_components::{
+    children_fn, shorten_middle, AssetCard, AssetModal, Badge, CardSize, StatPill,
+};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "asset-card",
+    label: "Asset Card",
+    category: "Data Display",
+    keywords: &["nft", "iiif", "thumbnail"],
+    render: || view! { <AssetCardStory /> }.into_any(),
+};
 
 #[component]
 pub fn AssetCardStory() -> impl IntoView {
@@ -144,12 +155,7 @@ pub fn AssetCardStory() -> impl IntoView {
                                 if id.is_empty() {
                                     String::new()
                                 } else {
-                                    let truncated = if id.len() > 20 {
-                                        format!("{}...{}", &id[..10], &id[id.len()-10..])
-                                    } else {
-                                        id
-                                    };
-                                    format!("ID: {truncated}")
+                                    format!("ID: {}", shorten_middle(&id, 10, 10))
                                 }
                             }}
                         </span>
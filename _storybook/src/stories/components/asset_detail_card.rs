@@ -3,11 +3,21 @@
 use crate::api::pfp_city::{
     fetch_asset_details, fetch_collection_assets, AssetDetails, CollectionAsset, KNOWN_COLLECTIONS,
 };
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use std::collections::HashMap;
 use ui_components::{
     generate_iiif_url, AssetCard, AssetDetailCard, AssetGrid, CardSize, IiifSize, Modal,
+    RoyaltyInfo,
+};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "asset-detail-card",
+    label: "Asset Detail Card",
+    category: "Data Display",
+    keywords: &["nft", "traits", "metadata"],
+    render: || view! { <AssetDetailCardStory /> }.into_any(),
 };
 
 #[component]
@@ -46,6 +56,15 @@ pub fn AssetDetailCardStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Provenance Example
+            <div class="story-section">
+                <h3>"Metadata & Provenance"</h3>
+                <p class="story-description">"Attributes read from raw CIP-25 metadata, with collection, policy, mint tx and CIP-27 royalty rows. The raw JSON sits behind a toggle."</p>
+                <div class="story-canvas">
+                    <ProvenanceExample />
+                </div>
+            </div>
+
             // Props Reference
             <div class="story-section">
                 <h3>"Props"</h3>
@@ -81,6 +100,26 @@ pub fn AssetDetailCardStory() -> impl IntoView {
                             values="Signal<Option<u32>>"
                             description="Rarity ranking (lower = rarer)"
                         />
+                        <AttributeCard
+                            name="metadata"
+                            values="Signal<serde_json::Value>"
+                            description="Raw CIP-25 asset metadata - attributes fallback when traits is empty, and a raw JSON viewer"
+                        />
+                        <AttributeCard
+                            name="collection_name"
+                            values="Signal<String>"
+                            description="Collection display name"
+                        />
+                        <AttributeCard
+                            name="mint_tx"
+                            values="Signal<String>"
+                            description="Mint transaction hash, linked via ExplorerLink"
+                        />
+                        <AttributeCard
+                            name="royalty"
+                            values="Signal<Option<RoyaltyInfo>>"
+                            description="CIP-27 royalty terms (RoyaltyInfo::from_cip27)"
+                        />
                         <AttributeCard
                             name="accent_color"
                             values="Signal<String>"
@@ -500,3 +539,33 @@ fn StaticDetailExample() -> impl IntoView {
         />
     }
 }
+
+/// Metadata-driven example with provenance rows
+#[component]
+fn ProvenanceExample() -> impl IntoView {
+    let metadata = serde_json::json!({
+        "name": "Pirate #189",
+        "image": "ipfs://QmPirate189",
+        "mediaType": "image/png",
+        "attributes": {
+            "Background": "Ocean Storm",
+            "Hat": "Captain's Tricorn",
+            "Weapon": "Flintlock Pistol"
+        }
+    });
+    let royalty = RoyaltyInfo::from_cip27(&serde_json::json!({
+        "rate": "0.05",
+        "addr": "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x"
+    }));
+
+    view! {
+        <AssetDetailCard
+            asset_id="b3dab69f7e6100849434fb1781e34bd12a916557f6231b8d2629b6f6506972617465313839".to_string()
+            name="Pirate #189".to_string()
+            metadata=metadata
+            collection_name="Black Flag Pirates".to_string()
+            mint_tx="8f3c1e0a5b2d4c6e9f7a1b3d5c7e9f0a2b4d6c8e0f1a3b5d7c9e1f3a5b7d9c1e".to_string()
+            royalty=royalty
+        />
+    }
+}
@@ -1,10 +1,19 @@
 //! Asset Grid component story
 
 use crate::api::pfp_city::{fetch_collection_assets, CollectionAsset, KNOWN_COLLECTIONS};
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{AssetCard, AssetGrid, CardSize, ImageCard, StatPill};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "asset-grid",
+    label: "Asset Grid",
+    category: "Data Display",
+    keywords: &["nft", "gallery", "list"],
+    render: || view! { <AssetGridStory /> }.into_any(),
+};
+
 // Sample Black Flag pirate asset IDs for demos
 const SAMPLE_ASSETS: &[(&str, &str)] = &[
     (
@@ -1,10 +1,19 @@
 //! Asset Picker component story
 
 use crate::api::pfp_city::{fetch_collection_assets, KNOWN_COLLECTIONS};
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{AssetCard, AssetPicker, Badge, BadgeSize, CardSize, PickerAsset};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "asset-picker",
+    label: "Asset Picker",
+    category: "Data Display",
+    keywords: &["nft", "select", "choose"],
+    render: || view! { <AssetPickerStory /> }.into_any(),
+};
+
 #[component]
 pub fn AssetPickerStory() -> impl IntoView {
     let (show_basic, set_show_basic) = signal(false);
@@ -1,11 +1,29 @@
 //! Badge component story
 
+use crate::controls::use_controls;
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::{Badge, BadgeVariant};
+use ui_components::{
+    Badge, BadgeAnchor, BadgePosition, BadgeSize, BadgeVariant, CountBadge, UserAvatar,
+};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "badge",
+    label: "Badge",
+    category: "Data Display",
+    keywords: &["tag", "label", "chip", "count", "notification", "dot"],
+    render: || view! { <BadgeStory /> }.into_any(),
+};
 
 #[component]
 pub fn BadgeStory() -> impl IntoView {
+    let controls = use_controls();
+    let label = controls.text("label", "Featured");
+    let variant = controls.select("variant", &["solid", "outline", "subtle"], "solid");
+    let size = controls.select("size", &["xs", "sm", "md"], "md");
+    let color = controls.color("color", "#4a9eff");
+
     view! {
         <div>
             <div class="story-header">
@@ -13,6 +31,28 @@ pub fn BadgeStory() -> impl IntoView {
                 <p>"A small label for categorization, status, or tagging. Supports solid, outline, and subtle variants."</p>
             </div>
 
+            // Playground (driven by the controls panel)
+            <div class="story-section">
+                <h3>"Playground"</h3>
+                <div class="story-canvas">
+                    {move || {
+                        let variant = match variant.get().as_str() {
+                            "outline" => BadgeVariant::Outline,
+                            "subtle" => BadgeVariant::Subtle,
+                            _ => BadgeVariant::Solid,
+                        };
+                        let size = match size.get().as_str() {
+                            "xs" => BadgeSize::Xs,
+                            "sm" => BadgeSize::Sm,
+                            _ => BadgeSize::Md,
+                        };
+                        view! {
+                            <Badge label=label.get() color=color.get() variant=variant size=size />
+                        }
+                    }}
+                </div>
+            </div>
+
             // Basic examples
             <div class="story-section">
                 <h3>"Basic Examples"</h3>
@@ -89,6 +129,39 @@ pub fn BadgeStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Counts and dots
+            <div class="story-section">
+                <h3>"Counts & Dots"</h3>
+                <div class="story-canvas">
+                    <div style="display: flex; flex-direction: column; gap: 1.5rem;">
+                        <div style="display: flex; align-items: center; gap: 0.75rem;">
+                            <span>"Inline:"</span>
+                            <CountBadge count=Signal::stored(3) />
+                            <CountBadge count=Signal::stored(42) />
+                            <CountBadge count=Signal::stored(250) />
+                            <CountBadge count=Signal::stored(12) max=9 />
+                            <CountBadge count=Signal::stored(0) show_zero=true color="#6c757d" />
+                            <CountBadge dot=true />
+                        </div>
+                        <div style="display: flex; align-items: center; gap: 2rem;">
+                            <span>"Anchored:"</span>
+                            <BadgeAnchor count=Signal::stored(7)>
+                                <span style="font-size: 1.75rem;">"🔔"</span>
+                            </BadgeAnchor>
+                            <BadgeAnchor count=Signal::stored(128) pulse=true>
+                                <span style="font-size: 1.75rem;">"✉️"</span>
+                            </BadgeAnchor>
+                            <BadgeAnchor dot=true color="#4caf50" position=BadgePosition::BottomRight>
+                                <UserAvatar fallback="A" />
+                            </BadgeAnchor>
+                            <BadgeAnchor dot=true pulse=true position=BadgePosition::TopLeft>
+                                <span style="font-size: 1.75rem;">"💬"</span>
+                            </BadgeAnchor>
+                        </div>
+                    </div>
+                </div>
+            </div>
+
             // Props section
             <div class="story-section">
                 <h3>"Props"</h3>
@@ -109,6 +182,26 @@ pub fn BadgeStory() -> impl IntoView {
                             values="BadgeVariant (Solid|Outline|Subtle)"
                             description="Visual style - default is Solid"
                         />
+                        <AttributeCard
+                            name="count (CountBadge, BadgeAnchor)"
+                            values="Signal<u32> (optional)"
+                            description="Number to show, hidden at zero unless show_zero"
+                        />
+                        <AttributeCard
+                            name="max"
+                            values="u32 (default 99)"
+                            description="Larger counts show as \"99+\""
+                        />
+                        <AttributeCard
+                            name="dot / pulse"
+                            values="bool"
+                            description="Dot-only indicator and attention pulse"
+                        />
+                        <AttributeCard
+                            name="position (BadgeAnchor)"
+                            values="TopRight|TopLeft|BottomRight|BottomLeft"
+                            description="Corner of the wrapped content to pin the badge to"
+                        />
                     </div>
                 </div>
             </div>
@@ -139,6 +232,20 @@ view! {
         label="Archived"
         variant=BadgeVariant::Subtle
     />
+}
+
+// Unread count pinned to an icon
+view! {
+    <BadgeAnchor count=unread max=9 pulse=has_new>
+        <BellIcon />
+    </BadgeAnchor>
+}
+
+// Presence dot on an avatar
+view! {
+    <BadgeAnchor dot=true color="#4caf50" position=BadgePosition::BottomRight>
+        <UserAvatar fallback="A" />
+    </BadgeAnchor>
 }"##}</pre>
             </div>
         </div>
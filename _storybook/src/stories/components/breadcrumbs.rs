@@ -0,0 +1,150 @@
+//! Breadcrumbs component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{Breadcrumb, Breadcrumbs};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "breadcrumbs",
+    label: "Breadcrumbs",
+    category: "Layout",
+    keywords: &["navigation", "trail", "path", "crumbs"],
+    render: || view! { <BreadcrumbsStory /> }.into_any(),
+};
+
+fn admin_trail() -> Vec<Breadcrumb> {
+    vec![
+        Breadcrumb::link("Admin", "#admin"),
+        Breadcrumb::link("Projects", "#admin/projects"),
+        Breadcrumb::link("Spacebudz", "#admin/projects/spacebudz"),
+        Breadcrumb::link("Policies", "#admin/projects/spacebudz/policies"),
+        Breadcrumb::link("Policy 3", "#admin/projects/spacebudz/policies/3"),
+        Breadcrumb::new("Assets"),
+    ]
+}
+
+#[component]
+pub fn BreadcrumbsStory() -> impl IntoView {
+    let (route, set_route) = signal("#admin/projects/spacebudz/policies/3/assets".to_string());
+    let (width, set_width) = signal(100u32);
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Breadcrumbs"</h2>
+                <p>"A navigation trail for multi-level pages. Middle crumbs fold into an ellipsis menu when the trail is too long or doesn't fit, and the markup carries schema.org BreadcrumbList microdata."</p>
+            </div>
+
+            // Basic
+            <div class="story-section">
+                <h3>"Basic"</h3>
+                <div class="story-canvas">
+                    <Breadcrumbs items=vec![
+                        Breadcrumb::link("Raffles", "#raffles"),
+                        Breadcrumb::new("Raffle #12"),
+                    ] />
+                </div>
+            </div>
+
+            // Max visible
+            <div class="story-section">
+                <h3>"Collapsed Middle"</h3>
+                <p class="story-description">"max_visible=3 keeps the root and the last two crumbs; the rest open from the ellipsis."</p>
+                <div class="story-canvas">
+                    <Breadcrumbs items=admin_trail() max_visible=3 />
+                </div>
+            </div>
+
+            // Overflow
+            <div class="story-section">
+                <h3>"Collapse on Overflow"</h3>
+                <p class="story-description">"Shrink the container: once the full trail stops fitting it collapses, and expands again when there's room."</p>
+                <div class="story-canvas">
+                    <input
+                        type="range"
+                        min="20"
+                        max="100"
+                        prop:value=move || width.get()
+                        on:input=move |ev| {
+                            if let Ok(w) = event_target_value(&ev).parse() {
+                                set_width.set(w);
+                            }
+                        }
+                    />
+                    <div style=move || format!("width: {}%; margin-top: 1rem; border: 1px dashed #444; padding: 0.5rem;", width.get())>
+                        <Breadcrumbs items=admin_trail() />
+                    </div>
+                </div>
+            </div>
+
+            // Routing
+            <div class="story-section">
+                <h3>"Route Integration"</h3>
+                <p class="story-description">"on_navigate receives the href of plain clicks instead of following the link; ctrl/cmd-click still opens a new tab."</p>
+                <div class="story-canvas">
+                    <Breadcrumbs
+                        items=admin_trail()
+                        max_visible=4
+                        on_navigate=move |href: String| set_route.set(href)
+                    />
+                    <p style="margin-top: 0.5rem; color: #888; font-size: 0.875rem;">
+                        "Route: " {move || route.get()}
+                    </p>
+                </div>
+            </div>
+
+            // Props
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="items"
+                            values="Signal<Vec<Breadcrumb>>"
+                            description="The trail; the last crumb is the current page"
+                        />
+                        <AttributeCard
+                            name="max_visible"
+                            values="usize (optional)"
+                            description="Collapse middle crumbs past this many"
+                        />
+                        <AttributeCard
+                            name="collapse_on_overflow"
+                            values="bool (default: true)"
+                            description="Collapse when the trail doesn't fit its container"
+                        />
+                        <AttributeCard
+                            name="on_navigate"
+                            values="Callback<String> (optional)"
+                            description="Called with the href of a plain click, for client-side routing"
+                        />
+                        <AttributeCard
+                            name="class"
+                            values="String (optional)"
+                            description="Additional CSS classes"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            // Code example
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r##"use ui_components::{Breadcrumb, Breadcrumbs};
+
+view! {
+    <Breadcrumbs
+        items=vec![
+            Breadcrumb::link("Admin", "/admin"),
+            Breadcrumb::link("Projects", "/admin/projects"),
+            Breadcrumb::new("Spacebudz"),
+        ]
+        max_visible=3
+        on_navigate=move |href: String| router.push(&href)
+    />
+}"##}</pre>
+            </div>
+        </div>
+    }
+}
@@ -1,9 +1,18 @@
 //! Button component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{Button, ButtonSize, ButtonVariant};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "button",
+    label: "Button",
+    category: "Forms",
+    keywords: &["action", "click", "cta"],
+    render: || view! { <ButtonStory /> }.into_any(),
+};
+
 #[component]
 pub fn ButtonStory() -> impl IntoView {
     let (click_count, set_click_count) = signal(0u32);
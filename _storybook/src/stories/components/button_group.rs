@@ -1,9 +1,18 @@
 //! ButtonGroup component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{Button, ButtonGroup, ButtonVariant};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "button-group",
+    label: "Button Group",
+    category: "Forms",
+    keywords: &["toggle", "segmented", "toolbar"],
+    render: || view! { <ButtonGroupStory /> }.into_any(),
+};
+
 #[component]
 pub fn ButtonGroupStory() -> impl IntoView {
     let (selected, set_selected) = signal("option1".to_string());
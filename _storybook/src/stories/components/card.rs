@@ -1,16 +1,28 @@
 //! Card component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::Card;
+use ui_components::{Button, Card, CardMediaPlacement};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "card",
+    label: "Card",
+    category: "Layout",
+    keywords: &["panel", "container", "box", "media", "feature", "link"],
+    render: || view! { <CardStory /> }.into_any(),
+};
 
 #[component]
 pub fn CardStory() -> impl IntoView {
+    let clicks = RwSignal::new(0u32);
+    let loading = RwSignal::new(true);
+
     view! {
         <div>
             <div class="story-header">
                 <h2>"Card"</h2>
-                <p>"A generic card container with optional accent bar. Use for grouping related content with consistent styling."</p>
+                <p>"A generic card container with optional accent bar, media slot and clickable variants. Use for grouping related content with consistent styling."</p>
             </div>
 
             // Examples section
@@ -57,6 +69,68 @@ pub fn CardStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Media
+            <div class="story-section">
+                <h3>"Media"</h3>
+                <div class="story-canvas">
+                    <div style="display: grid; grid-template-columns: repeat(2, 1fr); gap: 1rem;">
+                        <Card media="https://picsum.photos/seed/card-top/480/270" hoverable=true>
+                            <h4 style="margin: 0 0 0.5rem 0;">"Top media"</h4>
+                            <p style="margin: 0; color: #aaa;">"Full-width 16:9 image above the content."</p>
+                        </Card>
+                        <Card
+                            media="https://picsum.photos/seed/card-side/300/300"
+                            media_placement=CardMediaPlacement::Side
+                            hoverable=true
+                        >
+                            <h4 style="margin: 0 0 0.5rem 0;">"Side media"</h4>
+                            <p style="margin: 0; color: #aaa;">"Image beside the content; stacks on narrow screens."</p>
+                        </Card>
+                    </div>
+                </div>
+            </div>
+
+            // Clickable
+            <div class="story-section">
+                <h3>"Clickable Cards"</h3>
+                <div class="story-canvas">
+                    <div style="display: grid; grid-template-columns: repeat(2, 1fr); gap: 1rem;">
+                        <Card href="#card" hoverable=true accent_color="#FFD700">
+                            <h4 style="margin: 0 0 0.5rem 0;">"Link card"</h4>
+                            <p style="margin: 0; color: #aaa;">"The whole card is an anchor - focus it with Tab."</p>
+                        </Card>
+                        <Card
+                            on_click=move |_| clicks.update(|c| *c += 1)
+                            label="Increment counter"
+                            hoverable=true
+                        >
+                            <h4 style="margin: 0 0 0.5rem 0;">"Button card"</h4>
+                            <p style="margin: 0; color: #aaa;">
+                                {move || format!("Clicked {} times (Enter/Space work too)", clicks.get())}
+                            </p>
+                        </Card>
+                    </div>
+                </div>
+            </div>
+
+            // Loading
+            <div class="story-section">
+                <h3>"Loading Skeleton"</h3>
+                <div class="story-canvas">
+                    <div style="margin-bottom: 1rem;">
+                        <Button on_click=move |_| loading.update(|l| *l = !*l)>
+                            {move || if loading.get() { "Show Content" } else { "Show Skeleton" }}
+                        </Button>
+                    </div>
+                    <div style="max-width: 320px;">
+                        <Card media="https://picsum.photos/seed/card-loading/480/270" loading=loading>
+                            <h4 style="margin: 0 0 0.5rem 0;">"Loaded content"</h4>
+                            <p style="margin: 0; color: #aaa;">"Replaces the skeleton once data arrives."</p>
+                        </Card>
+                    </div>
+                </div>
+            </div>
+
             // Props section
             <div class="story-section">
                 <h3>"Props"</h3>
@@ -72,6 +146,46 @@ pub fn CardStory() -> impl IntoView {
                             values="String (CSS color, optional)"
                             description="Color for the top accent bar. If not provided, no accent is shown."
                         />
+                        <AttributeCard
+                            name="media"
+                            values="String (image URL, optional)"
+                            description="Image shown in the media slot"
+                        />
+                        <AttributeCard
+                            name="media_alt"
+                            values="String (optional)"
+                            description="Alt text for the media image; leave empty for decorative images"
+                        />
+                        <AttributeCard
+                            name="media_placement"
+                            values="Top | Side"
+                            description="Where the media sits. Side stacks above the content on narrow screens."
+                        />
+                        <AttributeCard
+                            name="hoverable"
+                            values="bool"
+                            description="Lift the card with a shadow on hover"
+                        />
+                        <AttributeCard
+                            name="href"
+                            values="String (optional)"
+                            description="Render the whole card as a link"
+                        />
+                        <AttributeCard
+                            name="on_click"
+                            values="Callback<()> (optional)"
+                            description="Render the whole card as a button (role, tabindex, Enter/Space)"
+                        />
+                        <AttributeCard
+                            name="label"
+                            values="String (optional)"
+                            description="Accessible name for a clickable card"
+                        />
+                        <AttributeCard
+                            name="loading"
+                            values="Signal<bool> (optional)"
+                            description="Show skeleton placeholders instead of media and body"
+                        />
                         <AttributeCard
                             name="children"
                             values="Children"
@@ -84,7 +198,7 @@ pub fn CardStory() -> impl IntoView {
             // Code example
             <div class="story-section">
                 <h3>"Usage"</h3>
-                <pre class="code-block">{r##"use ui_components::Card;
+                <pre class="code-block">{r##"use ui_components::{Card, CardMediaPlacement};
 
 // Basic card
 view! {
@@ -106,6 +220,26 @@ view! {
     <Card class="my-special-card">
         <p>"Styled with custom CSS"</p>
     </Card>
+}
+
+// Feature card: side image, whole card links
+view! {
+    <Card
+        media="/img/staking.png"
+        media_placement=CardMediaPlacement::Side
+        href="/staking"
+        hoverable=true
+    >
+        <h3>"Staking"</h3>
+        <p>"Delegate to earn rewards"</p>
+    </Card>
+}
+
+// Skeleton while loading
+view! {
+    <Card media=image_url loading=is_loading>
+        <p>{description}</p>
+    </Card>
 }"##}</pre>
             </div>
         </div>
@@ -1,16 +1,35 @@
 //! ColorSwatch component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::{ColorSwatch, SwatchSize};
+use ui_components::{ColorSwatch, PalettePicker, SwatchSize};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "color-swatch",
+    label: "Color Swatch",
+    category: "Data Display",
+    keywords: &["colour", "palette", "hex", "picker"],
+    render: || view! { <ColorSwatchStory /> }.into_any(),
+};
 
 #[component]
 pub fn ColorSwatchStory() -> impl IntoView {
+    let (color, set_color) = signal("#3b82f6".to_string());
+    let (brand, set_brand) = signal("#ffc107".to_string());
+    let brand_palette = vec![
+        "#ffc107".to_string(),
+        "#ff9800".to_string(),
+        "#0d0d1a".to_string(),
+        "#1a1a2e".to_string(),
+        "#e0e0e0".to_string(),
+    ];
+
     view! {
         <div>
             <div class="story-header">
                 <h2>"ColorSwatch"</h2>
-                <p>"A small color sample display for showing tier colors, role colors, etc."</p>
+                <p>"A small color sample display for showing tier colors, role colors, etc., plus a PalettePicker for choosing one."</p>
             </div>
 
             // Basic examples
@@ -79,6 +98,42 @@ pub fn ColorSwatchStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Palette picker
+            <div class="story-section">
+                <h3>"Palette Picker"</h3>
+                <p class="story-description">"A swatch grid with selection state, a custom hex/native color input and recently used colors persisted in settings."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; gap: 2rem; flex-wrap: wrap; align-items: flex-start;">
+                        <div style="max-width: 260px;">
+                            <PalettePicker
+                                value=color
+                                on_change=Callback::new(move |c| set_color.set(c))
+                            />
+                        </div>
+                        {move || view! { <ColorSwatch color=color.get() label="Selected" size=SwatchSize::Lg show_hex=true /> }}
+                    </div>
+                </div>
+            </div>
+
+            // Custom palette
+            <div class="story-section">
+                <h3>"Custom Palette"</h3>
+                <p class="story-description">"Brand colors only, large swatches, recent colors kept under their own key."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; gap: 2rem; flex-wrap: wrap; align-items: flex-start;">
+                        <PalettePicker
+                            value=brand
+                            on_change=Callback::new(move |c| set_brand.set(c))
+                            palette=brand_palette
+                            size=SwatchSize::Lg
+                            recent_key="brand-recent"
+                            max_recent=5
+                        />
+                        {move || view! { <ColorSwatch color=brand.get() label="Accent" size=SwatchSize::Lg show_hex=true /> }}
+                    </div>
+                </div>
+            </div>
+
             // Props section
             <div class="story-section">
                 <h3>"Props"</h3>
@@ -108,10 +163,54 @@ pub fn ColorSwatchStory() -> impl IntoView {
                 </div>
             </div>
 
+            // PalettePicker props
+            <div class="story-section">
+                <h3>"PalettePicker Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="value"
+                            values="Signal<String>"
+                            description="Currently selected color; matched case-insensitively against swatches"
+                        />
+                        <AttributeCard
+                            name="on_change"
+                            values="Callback<String>"
+                            description="Called with the chosen color as lowercase #rrggbb"
+                        />
+                        <AttributeCard
+                            name="palette"
+                            values="Vec<String> (optional)"
+                            description="Preset colors - defaults to DEFAULT_PALETTE"
+                        />
+                        <AttributeCard
+                            name="allow_custom"
+                            values="bool (default: true)"
+                            description="Show the hex field and native color input"
+                        />
+                        <AttributeCard
+                            name="recent_key"
+                            values="&'static str (default: \"recent\")"
+                            description="Settings key recent colors persist under; give separate pickers separate keys"
+                        />
+                        <AttributeCard
+                            name="max_recent"
+                            values="usize (default: 8)"
+                            description="Recent colors remembered - 0 hides the row"
+                        />
+                        <AttributeCard
+                            name="size"
+                            values="SwatchSize (Sm|Md|Lg)"
+                            description="Swatch size - default is Md"
+                        />
+                    </div>
+                </div>
+            </div>
+
             // Code example
             <div class="story-section">
                 <h3>"Usage"</h3>
-                <pre class="code-block">{r##"use ui_components::{ColorSwatch, SwatchSize};
+                <pre class="code-block">{r##"use ui_components::{ColorSwatch, PalettePicker, SwatchSize};
 
 // Simple swatch
 view! { <ColorSwatch color="#FFD700" /> }
@@ -127,6 +226,16 @@ view! {
         size=SwatchSize::Lg
         show_hex=true
     />
+}
+
+// Palette picker
+let (color, set_color) = signal("#3b82f6".to_string());
+view! {
+    <PalettePicker
+        value=color
+        on_change=Callback::new(move |c| set_color.set(c))
+        recent_key="theme-accent"
+    />
 }"##}</pre>
             </div>
         </div>
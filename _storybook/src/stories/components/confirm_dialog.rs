@@ -0,0 +1,196 @@
+//! Confirm Dialog component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{
+    use_confirm, Button, ButtonVariant, ConfirmDialog, ConfirmOptions, ConfirmProvider,
+};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "confirm-dialog",
+    label: "Confirm Dialog",
+    category: "Feedback",
+    keywords: &["confirm", "alert", "dialog", "destructive", "prompt"],
+    render: || view! { <ConfirmDialogStory /> }.into_any(),
+};
+
+#[component]
+pub fn ConfirmDialogStory() -> impl IntoView {
+    let (show_static, set_show_static) = signal(false);
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Confirm Dialog"</h2>
+                <p>"Promise-style confirmations: await use_confirm().confirm(options) for the user's answer instead of calling window.confirm."</p>
+            </div>
+
+            // Async API
+            <div class="story-section">
+                <h3>"use_confirm"</h3>
+                <p style="color: #888; margin-bottom: 1rem;">"Each button awaits the dialog and logs the answer."</p>
+                <div class="story-canvas">
+                    <ConfirmProvider>
+                        <ConfirmDemo />
+                    </ConfirmProvider>
+                </div>
+            </div>
+
+            // Controlled component
+            <div class="story-section">
+                <h3>"ConfirmDialog"</h3>
+                <p style="color: #888; margin-bottom: 1rem;">"The dialog on its own, shown while mounted."</p>
+                <div class="story-canvas">
+                    <Button
+                        variant=ButtonVariant::Secondary
+                        on_click=Callback::new(move |_| set_show_static.set(true))
+                    >
+                        "Open Dialog"
+                    </Button>
+                    <Show when=move || show_static.get()>
+                        <ConfirmDialog
+                            options=ConfirmOptions::new("Leave the lobby?")
+                                .body("You can rejoin with the same invite link.")
+                                .confirm_label("Leave")
+                            on_answer=Callback::new(move |_| set_show_static.set(false))
+                        />
+                    </Show>
+                </div>
+            </div>
+
+            // Props section
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <h4 style="margin: 0 0 0.5rem; color: #e0e0e0;">"ConfirmOptions"</h4>
+                    <div class="story-grid" style="margin-bottom: 1.5rem;">
+                        <AttributeCard
+                            name="title"
+                            values="String"
+                            description="Question asked"
+                        />
+                        <AttributeCard
+                            name="body"
+                            values="Option<String>"
+                            description="Explanation below the title"
+                        />
+                        <AttributeCard
+                            name="confirm_label"
+                            values="Option<String>"
+                            description="Confirm button text (default: OK)"
+                        />
+                        <AttributeCard
+                            name="cancel_label"
+                            values="Option<String>"
+                            description="Cancel button text (default: Cancel)"
+                        />
+                        <AttributeCard
+                            name="destructive"
+                            values="bool"
+                            description="Danger styling; focus starts on cancel"
+                        />
+                    </div>
+                    <h4 style="margin: 0 0 0.5rem; color: #e0e0e0;">"ConfirmDialog"</h4>
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="options"
+                            values="ConfirmOptions"
+                            description="Title, body, labels and styling"
+                        />
+                        <AttributeCard
+                            name="alert"
+                            values="bool"
+                            description="Only show the confirm button"
+                        />
+                        <AttributeCard
+                            name="on_answer"
+                            values="Callback<bool>"
+                            description="true on confirm, false on cancel, Escape or backdrop click"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            // Code example
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r##"use ui_components::{use_confirm, ConfirmOptions, ConfirmProvider};
+
+// Once, at the app root
+view! {
+    <ConfirmProvider>
+        <App />
+    </ConfirmProvider>
+}
+
+// Anywhere below it
+let dialog = use_confirm();
+spawn_local(async move {
+    let confirmed = dialog
+        .confirm(
+            ConfirmOptions::new("Disconnect wallet?")
+                .confirm_label("Disconnect")
+                .destructive(),
+        )
+        .await;
+    if confirmed {
+        disconnect();
+    }
+});
+
+// Acknowledge-only
+dialog.alert(ConfirmOptions::new("Game saved")).await;"##}</pre>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn ConfirmDemo() -> impl IntoView {
+    let dialog = use_confirm();
+    let (answer, set_answer) = signal(String::from("(no answer yet)"));
+
+    let ask = move |options: ConfirmOptions| {
+        wasm_bindgen_futures::spawn_local(async move {
+            let title = options.title.clone();
+            let confirmed = dialog.confirm(options).await;
+            set_answer.set(format!("{title} \u{2192} {confirmed}"));
+        });
+    };
+
+    view! {
+        <div style="display: flex; gap: 0.5rem; flex-wrap: wrap; margin-bottom: 1rem;">
+            <Button on_click=Callback::new(move |_| ask(ConfirmOptions::new("Save changes?")))>
+                "Confirm"
+            </Button>
+            <Button
+                variant=ButtonVariant::Danger
+                on_click=Callback::new(move |_| {
+                    ask(
+                        ConfirmOptions::new("Reset game?")
+                            .body("Scores and progress for everyone will be lost.")
+                            .confirm_label("Reset")
+                            .destructive(),
+                    )
+                })
+            >
+                "Reset Game"
+            </Button>
+            <Button
+                variant=ButtonVariant::Secondary
+                on_click=Callback::new(move |_| {
+                    wasm_bindgen_futures::spawn_local(async move {
+                        dialog
+                            .alert(ConfirmOptions::new("Game saved").body("Your progress is stored on this device."))
+                            .await;
+                        set_answer.set("Alert dismissed".into());
+                    });
+                })
+            >
+                "Alert"
+            </Button>
+        </div>
+        <p style="color: #888;">"Last answer: "<code>{answer}</code></p>
+    }
+}
@@ -1,13 +1,28 @@
 //! Connection Status component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{ConnectionState, ConnectionStatus};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "connection-status",
+    label: "Connection Status",
+    category: "Data Display",
+    keywords: &["websocket", "online", "reconnect"],
+    render: || view! { <ConnectionStatusStory /> }.into_any(),
+};
+
 #[component]
 pub fn ConnectionStatusStory() -> impl IntoView {
     let (status, set_status) = signal(ConnectionState::Disconnected);
     let (reconnect_count, set_reconnect_count) = signal(0u32);
+    let (latency, set_latency) = signal(Some(42u32));
+    let (last_disconnect, set_last_disconnect) = signal(None::<String>);
+    let reconnecting = ConnectionState::Reconnecting {
+        attempt: 1,
+        retry_at_ms: 0,
+    };
 
     view! {
         <div>
@@ -23,6 +38,7 @@ pub fn ConnectionStatusStory() -> impl IntoView {
                     <div class="story-inline">
                         <ConnectionStatus status=ConnectionState::Connected />
                         <ConnectionStatus status=ConnectionState::Connecting />
+                        <ConnectionStatus status=reconnecting />
                         <ConnectionStatus status=ConnectionState::Disconnected />
                         <ConnectionStatus status=ConnectionState::Error />
                     </div>
@@ -46,6 +62,15 @@ pub fn ConnectionStatusStory() -> impl IntoView {
                         >
                             "Set Connecting"
                         </button>
+                        <button
+                            class="demo-btn demo-btn--warning"
+                            on:click=move |_| {
+                                set_last_disconnect.set(Some("Connection lost (1006)".to_string()));
+                                set_status.set(ConnectionState::reconnecting(1, 5000));
+                            }
+                        >
+                            "Set Reconnecting (5s)"
+                        </button>
                         <button
                             class="demo-btn"
                             on:click=move |_| set_status.set(ConnectionState::Disconnected)
@@ -59,10 +84,26 @@ pub fn ConnectionStatusStory() -> impl IntoView {
                             "Set Error"
                         </button>
                     </div>
+                    <div class="story-inline" style="margin-top: 1rem;">
+                        <button
+                            class="demo-btn"
+                            on:click=move |_| set_latency.set(Some(42))
+                        >
+                            "Latency 42 ms"
+                        </button>
+                        <button
+                            class="demo-btn demo-btn--danger"
+                            on:click=move |_| set_latency.set(Some(480))
+                        >
+                            "Latency 480 ms"
+                        </button>
+                    </div>
                     <div class="story-inline" style="margin-top: 1rem;">
                         <ConnectionStatus
                             status=status
                             on_reconnect=move |()| set_reconnect_count.update(|c| *c += 1)
+                            latency_ms=latency
+                            last_disconnect=last_disconnect
                         />
                         <span class="status-indicator status-indicator--connected">
                             {move || format!("Reconnect clicked: {} times", reconnect_count.get())}
@@ -79,7 +120,7 @@ pub fn ConnectionStatusStory() -> impl IntoView {
                         <AttributeCard
                             name="status"
                             values="ConnectionState"
-                            description="Connection state to display: Connected, Connecting, Disconnected, Error"
+                            description="Connection state to display: Connected, Connecting, Reconnecting, Disconnected, Error"
                         />
                         <AttributeCard
                             name="show_text"
@@ -91,6 +132,16 @@ pub fn ConnectionStatusStory() -> impl IntoView {
                             values="Callback<()>"
                             description="Called when user clicks to reconnect (only on Disconnected/Error)"
                         />
+                        <AttributeCard
+                            name="latency_ms"
+                            values="Signal<Option<u32>>"
+                            description="Heartbeat round-trip time, shown while connected (flagged slow from 300 ms)"
+                        />
+                        <AttributeCard
+                            name="last_disconnect"
+                            values="Signal<Option<String>>"
+                            description="Reason for the last disconnect, shown as a tooltip (e.g. CloseInfo::to_string())"
+                        />
                     </div>
                 </div>
             </div>
@@ -118,6 +169,23 @@ view! {
     />
 }
 
+// Countdown, latency and disconnect reason from a ui-flow connection
+let config = ReconnectConfig::default();
+let (latency, set_latency) = signal(None::<u32>);
+let (last_disconnect, set_last_disconnect) = signal(None::<String>);
+FlowConnection::builder()
+    .on_status(move |s| set_status.set(match s {
+        FlowStatus::Reconnecting { attempt } => {
+            ConnectionState::reconnecting(attempt, config.delay_for(attempt))
+        }
+        // ...
+    }))
+    .on_latency(move |ms| set_latency.set(Some(ms)))
+    .on_close(move |info| set_last_disconnect.set(Some(info.to_string())));
+view! {
+    <ConnectionStatus status=status latency_ms=latency last_disconnect=last_disconnect />
+}
+
 // Without text label
 view! {
     <ConnectionStatus
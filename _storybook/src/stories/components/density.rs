@@ -0,0 +1,172 @@
+//! Density component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{
+    Button, ButtonSize, ButtonVariant, Density, DensityProvider, InfoGrid, InfoRow, PlayerList,
+    PlayerListEntry, Select, SelectOption,
+};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "density",
+    label: "Density",
+    category: "Layout",
+    keywords: &["compact", "comfortable", "spacing", "admin", "dashboard"],
+    render: || view! { <DensityStory /> }.into_any(),
+};
+
+fn sample_players() -> Vec<PlayerListEntry> {
+    vec![
+        PlayerListEntry {
+            user_id: "jack".into(),
+            name: "Captain Jack".into(),
+            score: 4,
+            host: true,
+            ..Default::default()
+        },
+        PlayerListEntry {
+            user_id: "anne".into(),
+            name: "Anne Bonny".into(),
+            score: 3,
+            ..Default::default()
+        },
+    ]
+}
+
+#[component]
+pub fn DensityStory() -> impl IntoView {
+    let (selected, set_selected) = signal("galleon".to_string());
+    let options = Signal::derive(|| {
+        vec![
+            SelectOption::new("galleon", "Galleon"),
+            SelectOption::new("sloop", "Sloop"),
+            SelectOption::new("brig", "Brig"),
+        ]
+    });
+    let (density, set_density) = signal(Density::Compact);
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Density"</h2>
+                <p>"Compact, comfortable or spacious spacing for everything inside a DensityProvider. Supported by Button, Select, InfoGrid and PlayerList."</p>
+            </div>
+
+            // Side by side
+            <div class="story-section">
+                <h3>"All Densities"</h3>
+                <div class="story-canvas">
+                    <div style="display: grid; grid-template-columns: repeat(3, minmax(0, 1fr)); gap: 1.5rem; align-items: start;">
+                        {Density::ALL
+                            .into_iter()
+                            .map(|d| {
+                                view! {
+                                    <div style="display: flex; flex-direction: column; gap: 0.75rem;">
+                                        <h4 style="margin: 0; color: #e0e0e0;">{d.label()}</h4>
+                                        <DensityProvider density=d>
+                                            <div style="display: flex; gap: 0.5rem;">
+                                                <Button size=ButtonSize::Sm>"Save"</Button>
+                                                <Button variant=ButtonVariant::Secondary>"Cancel"</Button>
+                                            </div>
+                                            <Select
+                                                value=selected
+                                                options=options
+                                                on_change=Callback::new(move |v| set_selected.set(v))
+                                            />
+                                            <InfoGrid>
+                                                <InfoRow label="Class" value="Galleon" />
+                                                <InfoRow label="Cannons" value="32" />
+                                                <InfoRow label="Crew" value="120" muted=true />
+                                            </InfoGrid>
+                                            <PlayerList players=sample_players() title="Crew" />
+                                        </DensityProvider>
+                                    </div>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                </div>
+            </div>
+
+            // Reactive density
+            <div class="story-section">
+                <h3>"Switching Density"</h3>
+                <p style="color: #888; margin-bottom: 1rem;">"The provider takes a signal, so a persisted user setting can drive it."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; gap: 0.5rem; margin-bottom: 1rem;">
+                        {Density::ALL
+                            .into_iter()
+                            .map(|d| {
+                                view! {
+                                    <Button
+                                        size=ButtonSize::Sm
+                                        variant=ButtonVariant::Secondary
+                                        on_click=Callback::new(move |_| set_density.set(d))
+                                    >
+                                        {d.label()}
+                                    </Button>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                    <div style="max-width: 400px;">
+                        <DensityProvider density=density>
+                            <InfoGrid>
+                                <InfoRow label="Policy" value="Spacebudz" />
+                                <InfoRow label="Assets" value="10,000" />
+                                <InfoRow label="Holders" value="3,412" />
+                            </InfoGrid>
+                        </DensityProvider>
+                    </div>
+                </div>
+            </div>
+
+            // Props section
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <h4 style="margin: 0 0 0.5rem; color: #e0e0e0;">"DensityProvider"</h4>
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="density"
+                            values="Signal<Density>"
+                            description="Compact, Comfortable (default) or Spacious"
+                        />
+                        <AttributeCard
+                            name="children"
+                            values="Children"
+                            description="Content laid out at this density"
+                        />
+                    </div>
+                    <p style="color: #888; margin: 1rem 0 0;">
+                        "Use "<code>"provide_density"</code>" to set an app-wide density without a wrapper, and "
+                        <code>"use_density"</code>" to read the nearest one."
+                    </p>
+                </div>
+            </div>
+
+            // Code example
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r##"use ui_components::{provide_density, Density, DensityProvider, InfoGrid, InfoRow};
+
+// App-wide, from a persisted setting
+let (density, set_density) = signal(Density::Comfortable);
+provide_density(density);
+
+// One section only
+view! {
+    <DensityProvider density=Density::Compact>
+        <InfoGrid>
+            <InfoRow label="Assets" value="10,000" />
+        </InfoGrid>
+    </DensityProvider>
+}
+
+// Plain markup can opt in too
+view! { <div data-density="compact">...</div> }"##}</pre>
+            </div>
+        </div>
+    }
+}
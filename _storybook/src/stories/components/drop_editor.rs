@@ -1,9 +1,21 @@
 //! DropEditor component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use asset_intents::{AssetId, Drop};
 use leptos::prelude::*;
-use ui_components::{CardSize, DropEditor};
+use ui_components::{
+    CardSize, Condition, DropEditor, DropKind, DropRecord, DropSchema, FieldSpec, FieldValue,
+    SchemaDropEditor, SelectOption,
+};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "drop-editor",
+    label: "Drop Editor",
+    category: "Editors",
+    keywords: &["drag", "drop", "upload"],
+    render: || view! { <DropEditorStory /> }.into_any(),
+};
 
 /// Sample asset IDs for the story
 fn sample_asset_id() -> AssetId {
@@ -27,6 +39,70 @@ fn sample_asset_id_3() -> AssetId {
     )
 }
 
+/// Campaign rewards that aren't `Drop`s: points and Discord roles
+fn campaign_schema() -> DropSchema {
+    DropSchema::new()
+        .kind(
+            DropKind::new("points", "Points")
+                .icon("★")
+                .summary("{points} pts")
+                .field(
+                    FieldSpec::integer("points", "Points")
+                        .required()
+                        .min(1.0)
+                        .max(10_000.0)
+                        .default_value(FieldValue::Integer(100)),
+                ),
+        )
+        .kind(
+            DropKind::new("role", "Discord Role")
+                .icon("@")
+                .summary("{role}[ ({days}d)]")
+                .field(
+                    FieldSpec::choice(
+                        "role",
+                        "Role",
+                        vec![
+                            SelectOption::new("og", "OG"),
+                            SelectOption::new("holder", "Holder"),
+                            SelectOption::new("whale", "Whale"),
+                        ],
+                    )
+                    .required(),
+                )
+                .field(FieldSpec::toggle("expires", "Expires"))
+                .field(
+                    FieldSpec::integer("days", "Days")
+                        .required()
+                        .min(1.0)
+                        .max(365.0)
+                        .hint("Role is removed after this many days")
+                        .visible_when(Condition::is_set("expires")),
+                ),
+        )
+}
+
+/// Standard tip/CNFT schema limited to a few tokens
+fn token_choice_schema() -> DropSchema {
+    let mut schema = DropSchema::standard();
+    if let Some(tip) = schema.kinds.iter_mut().find(|k| k.id == "tip") {
+        tip.fields.retain(|f| f.key != "token");
+        tip.fields.push(
+            FieldSpec::choice(
+                "token",
+                "Token",
+                vec![
+                    SelectOption::new("ADA", "ADA"),
+                    SelectOption::new("SNEK", "SNEK"),
+                ],
+            )
+            .required()
+            .default_value(FieldValue::Text("ADA".to_string())),
+        );
+    }
+    schema
+}
+
 #[component]
 pub fn DropEditorStory() -> impl IntoView {
     // Basic interactive example
@@ -55,6 +131,13 @@ pub fn DropEditorStory() -> impl IntoView {
         Drop::tip("ADA", 25.0),
     ]);
 
+    // Custom schema examples
+    let (records, set_records) = signal(vec![DropRecord {
+        kind: "points".to_string(),
+        values: [("points".to_string(), FieldValue::Integer(250))].into(),
+    }]);
+    let (token_drops, set_token_drops) = signal(vec![Drop::tip("SNEK", 1000.0)]);
+
     view! {
         <div>
             <div class="story-header">
@@ -140,6 +223,43 @@ pub fn DropEditorStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Custom schema
+            <div class="story-section">
+                <h3>"Custom Schema"</h3>
+                <p style="color: #888; margin-bottom: 1rem; font-size: 0.875rem;">
+                    "SchemaDropEditor builds the add form from a DropSchema and emits validated DropRecords. The Days field only appears when Expires is checked."
+                </p>
+                <div class="story-canvas">
+                    <SchemaDropEditor
+                        schema=campaign_schema()
+                        records=records
+                        on_change=move |new_records| set_records.set(new_records)
+                        add_label="Add Reward"
+                    />
+                </div>
+                <div style="margin-top: 1rem; padding: 0.75rem; background: #1a1a2e; border-radius: 4px;">
+                    <p style="font-size: 0.75rem; color: #888; margin-bottom: 0.5rem;">"Structured output:"</p>
+                    <pre style="font-size: 0.75rem; color: #888; margin: 0; white-space: pre-wrap;">
+                        {move || serde_json::to_string_pretty(&records.get()).unwrap_or_default()}
+                    </pre>
+                </div>
+            </div>
+
+            // Customised standard schema
+            <div class="story-section">
+                <h3>"Restricted Tokens"</h3>
+                <p style="color: #888; margin-bottom: 1rem; font-size: 0.875rem;">
+                    "DropEditor with a customised standard schema: tips are limited to a token list."
+                </p>
+                <div class="story-canvas">
+                    <DropEditor
+                        drops=token_drops
+                        on_change=move |new_drops| set_token_drops.set(new_drops)
+                        schema=token_choice_schema()
+                    />
+                </div>
+            </div>
+
             // Features
             <div class="story-section">
                 <h3>"Features"</h3>
@@ -165,6 +285,10 @@ pub fn DropEditorStory() -> impl IntoView {
                             <strong style="color: #fff;">"Modal for adding"</strong>
                             " - Click + button to add tips or CNFTs via modal"
                         </li>
+                        <li style="margin-bottom: 0.5rem;">
+                            <strong style="color: #fff;">"Schema-driven fields"</strong>
+                            " - Field types, constraints and conditional visibility from a DropSchema"
+                        </li>
                         <li>
                             <strong style="color: #fff;">"Hover to remove"</strong>
                             " - Remove button appears on hover"
@@ -198,6 +322,11 @@ pub fn DropEditorStory() -> impl IntoView {
                             values="CardSize (Xs|Sm|Md|Lg)"
                             description="Card size for drop items. Default: Sm (120px)"
                         />
+                        <AttributeCard
+                            name="schema"
+                            values="DropSchema (optional)"
+                            description="Customised tip/CNFT schema. Default: DropSchema::standard()"
+                        />
                     </div>
                 </div>
             </div>
@@ -1,8 +1,17 @@
 //! EmptyState component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::EmptyState;
+use ui_components::{EmptyState, EmptyStateVariant};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "empty-state",
+    label: "Empty State",
+    category: "Data Display",
+    keywords: &["no results", "placeholder", "blank", "offline", "error"],
+    render: || view! { <EmptyStateStory /> }.into_any(),
+};
 
 #[component]
 pub fn EmptyStateStory() -> impl IntoView {
@@ -10,7 +19,7 @@ pub fn EmptyStateStory() -> impl IntoView {
         <div>
             <div class="story-header">
                 <h2>"Empty State"</h2>
-                <p>"A placeholder for when there's no content to display. Supports icon and message."</p>
+                <p>"A placeholder for when there's no content to display. Supports presets, an illustration slot and action buttons."</p>
             </div>
 
             // Basic examples
@@ -31,6 +40,60 @@ pub fn EmptyStateStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Variant presets
+            <div class="story-section">
+                <h3>"Variants"</h3>
+                <div class="story-canvas">
+                    <div style="display: grid; grid-template-columns: repeat(2, 1fr); gap: 1rem;">
+                        <div style="background: #1a1a2e; padding: 1rem; border-radius: 8px;">
+                            <EmptyState
+                                variant=EmptyStateVariant::NoResults
+                                primary_action="Clear filters"
+                                on_primary=move |_| {}
+                            />
+                        </div>
+                        <div style="background: #1a1a2e; padding: 1rem; border-radius: 8px;">
+                            <EmptyState
+                                variant=EmptyStateVariant::Error
+                                primary_action="Retry"
+                                on_primary=move |_| {}
+                                secondary_action="Go back"
+                                on_secondary=move |_| {}
+                            />
+                        </div>
+                        <div style="background: #1a1a2e; padding: 1rem; border-radius: 8px;">
+                            <EmptyState
+                                variant=EmptyStateVariant::NoWallet
+                                primary_action="Connect Wallet"
+                                on_primary=move |_| {}
+                            />
+                        </div>
+                        <div style="background: #1a1a2e; padding: 1rem; border-radius: 8px;">
+                            <EmptyState variant=EmptyStateVariant::Offline />
+                        </div>
+                    </div>
+                </div>
+            </div>
+
+            // Illustration slot
+            <div class="story-section">
+                <h3>"With Illustration"</h3>
+                <div class="story-canvas">
+                    <div style="background: #1a1a2e; padding: 1rem; border-radius: 8px;">
+                        <EmptyState
+                            title="Your fleet is empty"
+                            message="Hire a crew and launch your first ship."
+                            illustration=|| view! {
+                                <svg viewBox="0 0 120 80" width="120" height="80">
+                                    <path d="M10 60 L110 60 L95 75 L25 75 Z" fill="#3a3a5e" />
+                                    <path d="M60 10 L60 58 L90 50 Z" fill="#4a9eff" />
+                                </svg>
+                            }
+                        />
+                    </div>
+                </div>
+            </div>
+
             // Various icons
             <div class="story-section">
                 <h3>"With Icons"</h3>
@@ -69,9 +132,19 @@ pub fn EmptyStateStory() -> impl IntoView {
                 <h3>"Props"</h3>
                 <div class="story-canvas">
                     <div class="story-grid">
+                        <AttributeCard
+                            name="variant"
+                            values="NoResults | Error | NoWallet | Offline"
+                            description="Preset supplying icon, title and message"
+                        />
+                        <AttributeCard
+                            name="title"
+                            values="String (optional)"
+                            description="Heading above the message"
+                        />
                         <AttributeCard
                             name="message"
-                            values="String"
+                            values="String (optional)"
                             description="The main message to display"
                         />
                         <AttributeCard
@@ -79,6 +152,21 @@ pub fn EmptyStateStory() -> impl IntoView {
                             values="String (optional)"
                             description="Emoji or text icon shown above the message"
                         />
+                        <AttributeCard
+                            name="illustration"
+                            values="Children (optional)"
+                            description="Image or SVG shown instead of the icon"
+                        />
+                        <AttributeCard
+                            name="primary_action / on_primary"
+                            values="String, Callback<()>"
+                            description="Primary button label and click handler"
+                        />
+                        <AttributeCard
+                            name="secondary_action / on_secondary"
+                            values="String, Callback<()>"
+                            description="Secondary button label and click handler"
+                        />
                         <AttributeCard
                             name="action"
                             values="Children (optional)"
@@ -91,7 +179,7 @@ pub fn EmptyStateStory() -> impl IntoView {
             // Code example
             <div class="story-section">
                 <h3>"Usage"</h3>
-                <pre class="code-block">{r##"use ui_components::EmptyState;
+                <pre class="code-block">{r##"use ui_components::{EmptyState, EmptyStateVariant};
 
 // Simple empty state
 view! {
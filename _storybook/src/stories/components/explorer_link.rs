@@ -0,0 +1,167 @@
+//! ExplorerLink component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{Explorer, ExplorerKind, ExplorerLink, ExplorerNetwork};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "explorer-link",
+    label: "Explorer Link",
+    category: "Data Display",
+    keywords: &[
+        "cardanoscan",
+        "cexplorer",
+        "pool.pm",
+        "address",
+        "tx",
+        "copy",
+    ],
+    render: || view! { <ExplorerLinkStory /> }.into_any(),
+};
+
+const ADDRESS: &str = "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x";
+const TX_HASH: &str = "8a1b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f809";
+const POLICY_ID: &str = "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373";
+const UNIT: &str = "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373504154415445";
+
+#[component]
+pub fn ExplorerLinkStory() -> impl IntoView {
+    let (explorer, set_explorer) = signal(Explorer::Cardanoscan);
+    let (network, set_network) = signal(ExplorerNetwork::Mainnet);
+
+    let select_style = "padding: 0.25rem 0.5rem; background: #2a2a4e; border: 1px solid #3a3a5e; border-radius: 4px; color: #e0e0e0;";
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Explorer Link"</h2>
+                <p>"Shortened address, transaction, asset and policy values linking to Cardanoscan, cexplorer or pool.pm, with a copy button."</p>
+            </div>
+
+            // Explorers & networks
+            <div class="story-section">
+                <h3>"Explorers & Networks"</h3>
+                <div class="story-canvas">
+                    <div style="display: flex; gap: 1rem; margin-bottom: 1rem;">
+                        <select
+                            style=select_style
+                            on:change=move |ev| set_explorer.set(match event_target_value(&ev).as_str() {
+                                "cexplorer" => Explorer::Cexplorer,
+                                "poolpm" => Explorer::PoolPm,
+                                _ => Explorer::Cardanoscan,
+                            })
+                        >
+                            <option value="cardanoscan">"Cardanoscan"</option>
+                            <option value="cexplorer">"cexplorer"</option>
+                            <option value="poolpm">"pool.pm"</option>
+                        </select>
+                        <select
+                            style=select_style
+                            on:change=move |ev| set_network.set(match event_target_value(&ev).as_str() {
+                                "preprod" => ExplorerNetwork::Preprod,
+                                "preview" => ExplorerNetwork::Preview,
+                                _ => ExplorerNetwork::Mainnet,
+                            })
+                        >
+                            <option value="mainnet">"Mainnet"</option>
+                            <option value="preprod">"Preprod"</option>
+                            <option value="preview">"Preview"</option>
+                        </select>
+                    </div>
+                    {move || {
+                        let (explorer, network) = (explorer.get(), network.get());
+                        view! {
+                            <div style="display: grid; grid-template-columns: auto 1fr; gap: 0.5rem 1rem; align-items: center;">
+                                <span>"Address"</span>
+                                <ExplorerLink kind=ExplorerKind::Address value=ADDRESS explorer=explorer network=network />
+                                <span>"Transaction"</span>
+                                <ExplorerLink kind=ExplorerKind::Tx value=TX_HASH explorer=explorer network=network />
+                                <span>"Asset"</span>
+                                <ExplorerLink kind=ExplorerKind::Asset value=UNIT explorer=explorer network=network />
+                                <span>"Policy"</span>
+                                <ExplorerLink kind=ExplorerKind::Policy value=POLICY_ID explorer=explorer network=network />
+                            </div>
+                        }
+                    }}
+                    <p style="margin-top: 1rem; color: #888; font-size: 0.875rem;">
+                        "pool.pm only indexes mainnet, so its links fall back to plain text on test networks."
+                    </p>
+                </div>
+            </div>
+
+            // Display options
+            <div class="story-section">
+                <h3>"Display Options"</h3>
+                <div class="story-canvas">
+                    <div style="display: flex; flex-direction: column; gap: 0.5rem;">
+                        <ExplorerLink kind=ExplorerKind::Address value=ADDRESS head=16 tail=10 />
+                        <ExplorerLink kind=ExplorerKind::Tx value=TX_HASH label="View transaction" />
+                        <ExplorerLink kind=ExplorerKind::Policy value=POLICY_ID copyable=false />
+                    </div>
+                </div>
+            </div>
+
+            // Props section
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="kind"
+                            values="ExplorerKind (Address|Tx|Asset|Policy)"
+                            description="What the value identifies"
+                        />
+                        <AttributeCard
+                            name="value"
+                            values="String"
+                            description="Address, tx hash, asset unit or asset1 fingerprint, or policy ID"
+                        />
+                        <AttributeCard
+                            name="explorer"
+                            values="Explorer (Cardanoscan|Cexplorer|PoolPm)"
+                            description="Override the explorer from provide_explorer_config"
+                        />
+                        <AttributeCard
+                            name="network"
+                            values="ExplorerNetwork (Mainnet|Preprod|Preview)"
+                            description="Override the network from provide_explorer_config"
+                        />
+                        <AttributeCard
+                            name="label"
+                            values="String (optional)"
+                            description="Display text instead of the shortened value"
+                        />
+                        <AttributeCard
+                            name="head / tail"
+                            values="usize (default: 8 / 6)"
+                            description="Characters kept either side of the ellipsis"
+                        />
+                        <AttributeCard
+                            name="copyable"
+                            values="bool (default: true)"
+                            description="Show a copy-to-clipboard button"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            // Code example
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r##"use ui_components::{
+    provide_explorer_config, Explorer, ExplorerConfig, ExplorerKind, ExplorerLink,
+    ExplorerNetwork,
+};
+
+// Once at the app root
+provide_explorer_config(ExplorerConfig::new(Explorer::Cexplorer, ExplorerNetwork::Preprod));
+
+view! {
+    <ExplorerLink kind=ExplorerKind::Address value=address />
+    <ExplorerLink kind=ExplorerKind::Tx value=tx_hash label="View transaction" />
+}"##}</pre>
+            </div>
+        </div>
+    }
+}
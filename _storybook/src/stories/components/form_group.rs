@@ -1,9 +1,18 @@
 //! FormGroup component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{FormGroup, TextInput, Textarea};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "form-group",
+    label: "Form Group",
+    category: "Forms",
+    keywords: &["label", "field", "validation"],
+    render: || view! { <FormGroupStory /> }.into_any(),
+};
+
 #[component]
 pub fn FormGroupStory() -> impl IntoView {
     let (name, set_name) = signal(String::new());
@@ -1,8 +1,17 @@
 //! PageHeader component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::PageHeader;
+use ui_components::{Breadcrumb, Button, ButtonSize, ButtonVariant, PageHeader};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "page-header",
+    label: "Page Header",
+    category: "Layout",
+    keywords: &["title", "heading", "breadcrumb"],
+    render: || view! { <HeaderStory /> }.into_any(),
+};
 
 #[component]
 pub fn HeaderStory() -> impl IntoView {
@@ -10,7 +19,7 @@ pub fn HeaderStory() -> impl IntoView {
         <div>
             <div class="story-header">
                 <h2>"Page Header"</h2>
-                <p>"A page header component with title and optional subtitle."</p>
+                <p>"A page header with breadcrumbs, title, subtitle and left/right action slots. Optionally sticky, shrinking on scroll, and collapsing its actions into a menu on narrow widths."</p>
             </div>
 
             // Basic examples
@@ -52,6 +61,70 @@ pub fn HeaderStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Breadcrumbs and slots
+            <div class="story-section">
+                <h3>"Breadcrumbs & Action Slots"</h3>
+                <div class="story-canvas">
+                    <PageHeader
+                        title="Raffle #12"
+                        subtitle="Ends in 2 days"
+                        breadcrumbs=vec![
+                            Breadcrumb::link("Home", "#"),
+                            Breadcrumb::link("Raffles", "#"),
+                            Breadcrumb::new("Raffle #12"),
+                        ]
+                        left_actions=|| view! {
+                            <Button variant=ButtonVariant::Secondary size=ButtonSize::Sm>"←"</Button>
+                        }
+                        actions=|| view! {
+                            <Button variant=ButtonVariant::Secondary size=ButtonSize::Sm>"Edit"</Button>
+                            <Button size=ButtonSize::Sm>"Draw Winner"</Button>
+                        }
+                    />
+                </div>
+            </div>
+
+            // Responsive collapse
+            <div class="story-section">
+                <h3>"Responsive Collapse"</h3>
+                <p style="color: #888; margin-bottom: 1rem; font-size: 0.875rem;">
+                    "With collapse_below=520, actions fold into a ⋯ menu when the header is narrower than 520px. Resize the container to try it."
+                </p>
+                <div class="story-canvas" style="resize: horizontal; overflow: auto; max-width: 100%; width: 400px;">
+                    <PageHeader
+                        title="Fleet Management"
+                        subtitle="Configure and deploy your ships"
+                        collapse_below=520
+                        actions=|| view! {
+                            <Button variant=ButtonVariant::Secondary size=ButtonSize::Sm>"Import"</Button>
+                            <Button variant=ButtonVariant::Secondary size=ButtonSize::Sm>"Export"</Button>
+                            <Button size=ButtonSize::Sm>"New Ship"</Button>
+                        }
+                    />
+                </div>
+            </div>
+
+            // Sticky + shrink
+            <div class="story-section">
+                <h3>"Sticky & Shrink on Scroll"</h3>
+                <p style="color: #888; margin-bottom: 1rem; font-size: 0.875rem;">
+                    "sticky keeps the header at the top of its scroll container; shrink_on_scroll compacts it once the window scrolls past 48px."
+                </p>
+                <div class="story-canvas">
+                    <PageHeader
+                        title="Leaderboard"
+                        subtitle="Top players this season"
+                        breadcrumbs=vec![Breadcrumb::link("Games", "#"), Breadcrumb::new("Leaderboard")]
+                        sticky=true
+                        shrink_on_scroll=true
+                        actions=|| view! { <Button size=ButtonSize::Sm>"Refresh"</Button> }
+                    />
+                    <div style="height: 600px; color: #888; font-size: 0.875rem;">
+                        "Scroll the page to see the header shrink."
+                    </div>
+                </div>
+            </div>
+
             // Props section
             <div class="story-section">
                 <h3>"Props"</h3>
@@ -72,6 +145,31 @@ pub fn HeaderStory() -> impl IntoView {
                             values="Children (optional)"
                             description="Action buttons or other content aligned to the right"
                         />
+                        <AttributeCard
+                            name="left_actions"
+                            values="Children (optional)"
+                            description="Content before the title, e.g. a back button"
+                        />
+                        <AttributeCard
+                            name="breadcrumbs"
+                            values="Vec<Breadcrumb> (optional)"
+                            description="Trail above the title; the last crumb is the current page"
+                        />
+                        <AttributeCard
+                            name="collapse_below"
+                            values="u32 (optional)"
+                            description="Header width in px below which actions fold into a menu"
+                        />
+                        <AttributeCard
+                            name="sticky"
+                            values="bool"
+                            description="Stick to the top while scrolling. Default: false"
+                        />
+                        <AttributeCard
+                            name="shrink_on_scroll"
+                            values="bool"
+                            description="Compact the header once the window scrolls. Default: false"
+                        />
                     </div>
                 </div>
             </div>
@@ -103,6 +201,22 @@ view! {
             <button class="btn btn--primary">"Add User"</button>
         }
     />
+}
+
+// Widget bar: breadcrumbs, back button, sticky, collapsing actions
+view! {
+    <PageHeader
+        title="Raffle #12"
+        breadcrumbs=vec![
+            Breadcrumb::link("Raffles", "/raffles"),
+            Breadcrumb::new("Raffle #12"),
+        ]
+        left_actions=|| view! { <Button on_click=go_back>"←"</Button> }
+        actions=|| view! { <Button on_click=draw>"Draw"</Button> }
+        collapse_below=560
+        sticky=true
+        shrink_on_scroll=true
+    />
 }"##}</pre>
             </div>
         </div>
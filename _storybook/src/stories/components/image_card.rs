@@ -1,8 +1,19 @@
 //! Image Card component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::{CardSize, ImageCard};
+use ui_components::{AspectRatio, CardSize, ImageCard, ImageFit};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "image-card",
+    label: "Image Card",
+    category: "Data Display",
+    keywords: &["picture", "thumbnail", "nft"],
+    render: || view! { <ImageCardStory /> }.into_any(),
+};
+
+const PIRATE_URL: &str = "https://iiif.hodlcroft.com/iiif/3/b3dab69f7e6100849434fb1781e34bd12a916557f6231b8d2629b6f6:506972617465363636/full/400,/0/default.jpg";
 
 #[component]
 pub fn ImageCardStory() -> impl IntoView {
@@ -80,6 +91,58 @@ pub fn ImageCardStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Aspect & Fit section
+            <div class="story-section">
+                <h3>"Aspect Ratio & Fit"</h3>
+                <p class="story-description">"Square art in a 16:9 frame: cover crops it, contain letterboxes it, and blur_backdrop fills the bars with a blurred copy."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; align-items: flex-start; gap: 1rem; flex-wrap: wrap;">
+                        <ImageCard
+                            image_url=PIRATE_URL
+                            name="16:9 cover"
+                            size=CardSize::Md
+                            show_name=true
+                            aspect=AspectRatio::SixteenNine
+                        />
+                        <ImageCard
+                            image_url=PIRATE_URL
+                            name="16:9 contain"
+                            size=CardSize::Md
+                            show_name=true
+                            aspect=AspectRatio::SixteenNine
+                            fit=ImageFit::Contain
+                        />
+                        <ImageCard
+                            image_url=PIRATE_URL
+                            name="16:9 contain + blur"
+                            size=CardSize::Md
+                            show_name=true
+                            aspect=AspectRatio::SixteenNine
+                            fit=ImageFit::Contain
+                            blur_backdrop=true
+                        />
+                    </div>
+                    <div style="display: flex; align-items: flex-start; gap: 1rem; margin-top: 1rem; flex-wrap: wrap;">
+                        <ImageCard
+                            image_url=PIRATE_URL
+                            name="4:3 contain + blur"
+                            size=CardSize::Md
+                            show_name=true
+                            aspect=AspectRatio::FourThree
+                            fit=ImageFit::Contain
+                            blur_backdrop=true
+                        />
+                        <ImageCard
+                            image_url=PIRATE_URL
+                            name="Natural"
+                            size=CardSize::Md
+                            show_name=true
+                            aspect=AspectRatio::Natural
+                        />
+                    </div>
+                </div>
+            </div>
+
             // Interactive Demo section
             <div class="story-section">
                 <h3>"Interactive Demo"</h3>
@@ -137,6 +200,26 @@ pub fn ImageCardStory() -> impl IntoView {
                             values="bool"
                             description="If true, disables hover effects and clicks"
                         />
+                        <AttributeCard
+                            name="aspect"
+                            values="Square | FourThree | SixteenNine | Natural"
+                            description="Frame shape (default: Square)"
+                        />
+                        <AttributeCard
+                            name="fit"
+                            values="Cover | Contain | Fill | ScaleDown"
+                            description="How the image fills the frame (default: Cover)"
+                        />
+                        <AttributeCard
+                            name="blur_backdrop"
+                            values="bool"
+                            description="Fill letterbox space with a blurred copy of the image"
+                        />
+                        <AttributeCard
+                            name="srcset / sizes"
+                            values="String"
+                            description="Responsive candidates; sizes defaults to the card width. AssetCard fills srcset from IIIF."
+                        />
                     </div>
                 </div>
             </div>
@@ -1,9 +1,18 @@
 //! InfoGrid component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{Badge, InfoGrid, InfoRow};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "info-grid",
+    label: "Info Grid",
+    category: "Data Display",
+    keywords: &["key value", "details", "table"],
+    render: || view! { <InfoGridStory /> }.into_any(),
+};
+
 #[component]
 pub fn InfoGridStory() -> impl IntoView {
     view! {
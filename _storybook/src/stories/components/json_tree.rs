@@ -0,0 +1,105 @@
+//! JsonTree component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use serde_json::{json, Value};
+use ui_components::JsonTree;
+
+pub const STORY: StoryDef = StoryDef {
+    id: "json-tree",
+    label: "JSON Tree",
+    category: "Data Display",
+    keywords: &["json", "metadata", "debug", "inspector", "raw"],
+    render: || view! { <JsonTreeStory /> }.into_any(),
+};
+
+fn sample_metadata() -> Value {
+    json!({
+        "721": {
+            "b3dab69f7e6100849434fb1781e34bd12a916557f6231b8d2629b6f6": {
+                "Pirate189": {
+                    "name": "Pirate #189",
+                    "image": "ipfs://QmXyZ...",
+                    "mediaType": "image/png",
+                    "attributes": {
+                        "Hat": "Tricorn",
+                        "Weapon": "Cutlass",
+                        "Parrot": true,
+                        "Rank": 42
+                    },
+                    "files": [
+                        {"name": "Pirate #189", "mediaType": "image/png", "src": "ipfs://QmXyZ..."}
+                    ],
+                    "twitter": null
+                }
+            }
+        }
+    })
+}
+
+fn large_array() -> Value {
+    Value::Array(
+        (0..250)
+            .map(|i| json!({"id": i, "owner": format!("addr1q{i:04}...")}))
+            .collect(),
+    )
+}
+
+#[component]
+pub fn JsonTreeStory() -> impl IntoView {
+    let metadata = Signal::stored(sample_metadata());
+    let holders = Signal::stored(large_array());
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"JsonTree"</h2>
+                <p>"A collapsible JSON viewer with syntax coloring, search and copy-path, for raw metadata, debug panels and admin tooling."</p>
+            </div>
+
+            // Metadata
+            <div class="story-section">
+                <h3>"CIP-25 Metadata"</h3>
+                <p class="story-description">"Fully expanded. Search for \"tricorn\" to highlight the match; hover a row to copy its path."</p>
+                <div class="story-canvas">
+                    <JsonTree value=metadata expand_depth=usize::MAX />
+                </div>
+            </div>
+
+            // Large arrays
+            <div class="story-section">
+                <h3>"Large Arrays"</h3>
+                <p class="story-description">"250 entries, shown 50 at a time. Children render only when a node is opened."</p>
+                <div class="story-canvas">
+                    <JsonTree value=holders page_size=50 searchable=false />
+                </div>
+            </div>
+
+            // Props
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard name="value" values="Signal<Value>" description="The JSON to display" />
+                        <AttributeCard name="expand_depth" values="usize" description="Levels open on first render (default 1)" />
+                        <AttributeCard name="page_size" values="usize" description="Children shown per node before \"Show more\" (default 100)" />
+                        <AttributeCard name="searchable" values="bool" description="Show the search box (default true)" />
+                        <AttributeCard name="class" values="String" description="Additional CSS classes" />
+                    </div>
+                </div>
+            </div>
+
+            // Usage
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r#"use ui_components::JsonTree;
+
+<JsonTree value=metadata />
+
+// Fully expanded, no search box
+<JsonTree value=payload expand_depth=usize::MAX searchable=false />"#}</pre>
+            </div>
+        </div>
+    }
+}
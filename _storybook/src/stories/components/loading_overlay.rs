@@ -1,12 +1,23 @@
 //! Loading Overlay component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{Button, ButtonVariant, LoadingOverlay, Spinner, SpinnerSize};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "loading-overlay",
+    label: "Loading Overlay",
+    category: "Feedback",
+    keywords: &["spinner", "busy", "progress"],
+    render: || view! { <LoadingOverlayStory /> }.into_any(),
+};
+
 #[component]
 pub fn LoadingOverlayStory() -> impl IntoView {
     let (show_overlay, set_show_overlay) = signal(false);
+    let (show_scoped, set_show_scoped) = signal(true);
+    let (progress, set_progress) = signal(0.35_f32);
 
     // Auto-hide overlay after 2 seconds when shown
     Effect::new(move || {
@@ -43,6 +54,44 @@ pub fn LoadingOverlayStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Scoped overlay with progress and cancel
+            <div class="story-section">
+                <h3>"Scoped Progress with Cancel"</h3>
+                <p class="story-description">"A blurred overlay covering only its content, with determinate progress and a cancel button."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; gap: 0.5rem; margin-bottom: 1rem;">
+                        <Button
+                            variant=ButtonVariant::Primary
+                            on_click=move |()| set_show_scoped.set(true)
+                        >
+                            "Show"
+                        </Button>
+                        <Button
+                            variant=ButtonVariant::Secondary
+                            on_click=move |()| set_progress.update(|p| *p = (*p + 0.15).min(1.0))
+                        >
+                            "Advance"
+                        </Button>
+                    </div>
+                    <LoadingOverlay
+                        loading=show_scoped
+                        message="Loading assets..."
+                        progress=progress
+                        on_cancel=Callback::new(move |()| {
+                            set_show_scoped.set(false);
+                            set_progress.set(0.35);
+                        })
+                        blur=true
+                        scoped=true
+                    >
+                        <div style="height: 200px; padding: 1rem; background: rgba(255,255,255,0.05); border-radius: 6px;">
+                            <p>"Panel content stays in place behind the overlay."</p>
+                            <p>"The rest of the page remains usable."</p>
+                        </div>
+                    </LoadingOverlay>
+                </div>
+            </div>
+
             // Inline spinners
             <div class="story-section">
                 <h3>"Inline Spinners"</h3>
@@ -114,6 +163,31 @@ pub fn LoadingOverlayStory() -> impl IntoView {
                             values="String (optional)"
                             description="Loading message to display. Defaults to 'Loading...'"
                         />
+                        <AttributeCard
+                            name="progress"
+                            values="Signal<f32> (optional)"
+                            description="Progress from 0.0 to 1.0. Shows a progress bar instead of the spinner"
+                        />
+                        <AttributeCard
+                            name="on_cancel"
+                            values="Callback<()> (optional)"
+                            description="Shows a cancel button that runs this callback"
+                        />
+                        <AttributeCard
+                            name="cancel_label"
+                            values="String (optional)"
+                            description="Cancel button text. Defaults to 'Cancel'"
+                        />
+                        <AttributeCard
+                            name="blur"
+                            values="bool"
+                            description="Blur the content behind a translucent overlay"
+                        />
+                        <AttributeCard
+                            name="scoped"
+                            values="bool"
+                            description="Cover only the wrapped children instead of the full screen"
+                        />
                         <AttributeCard
                             name="children"
                             values="Children (optional)"
@@ -152,6 +226,20 @@ view! {
     />
 }
 
+// Cancellable progress scoped to a panel
+view! {
+    <LoadingOverlay
+        loading=preloading
+        message="Loading assets..."
+        progress=preload_progress
+        on_cancel=Callback::new(move |()| set_preloading.set(false))
+        blur=true
+        scoped=true
+    >
+        <AssetGrid />
+    </LoadingOverlay>
+}
+
 // Inline spinner
 view! {
     <div style="display: flex; align-items: center; gap: 0.5rem;">
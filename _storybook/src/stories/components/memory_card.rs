@@ -1,9 +1,18 @@
 //! Memory Card component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::MemoryCard;
 
+pub const STORY: StoryDef = StoryDef {
+    id: "memory-card",
+    label: "Memory Card",
+    category: "Data Display",
+    keywords: &["flip", "game", "pairs"],
+    render: || view! { <MemoryCardStory /> }.into_any(),
+};
+
 #[component]
 pub fn MemoryCardStory() -> impl IntoView {
     let (click_count, set_click_count) = signal(0u32);
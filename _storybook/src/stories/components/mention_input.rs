@@ -0,0 +1,169 @@
+//! MentionInput component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{Mention, MentionInput, MentionMessage, MentionText, MentionUser};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "mention-input",
+    label: "Mention Input",
+    category: "Forms",
+    keywords: &["mention", "autocomplete", "chat", "ping", "@"],
+    render: || view! { <MentionInputStory /> }.into_any(),
+};
+
+fn demo_users() -> Vec<MentionUser> {
+    [
+        ("u1", "Alice"),
+        ("u2", "Alfred"),
+        ("u3", "Bob"),
+        ("u4", "Carol Danvers"),
+        ("u5", "Malory"),
+    ]
+    .into_iter()
+    .map(|(user_id, name)| MentionUser {
+        user_id: user_id.to_string(),
+        name: name.to_string(),
+    })
+    .collect()
+}
+
+#[component]
+pub fn MentionInputStory() -> impl IntoView {
+    let (draft, set_draft) = signal(MentionMessage::default());
+    let (sent, set_sent) = signal(Vec::<MentionMessage>::new());
+    let users = Signal::derive(demo_users);
+    let (me, _) = signal("u3".to_string());
+
+    let example_text = "Thanks @Bob, and @Carol Danvers too!".to_string();
+    let example_mentions = vec![
+        Mention {
+            user_id: "u3".to_string(),
+            start: 7,
+            end: 11,
+        },
+        Mention {
+            user_id: "u4".to_string(),
+            start: 17,
+            end: 31,
+        },
+    ];
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"MentionInput"</h2>
+                <p>"A textarea with @-mention autocomplete. Picked users are sent as structured mentions (user ID and text range), and MentionText highlights them in the message list."</p>
+            </div>
+
+            <div class="story-section">
+                <h3>"Interactive Example"</h3>
+                <p class="story-description">"Type @ and part of a name. Arrow keys move through suggestions, Enter or Tab picks one, Escape closes them. Enter sends, Shift+Enter adds a newline. You are Bob here."</p>
+                <div class="story-canvas">
+                    <div style="max-width: 400px; display: flex; flex-direction: column; gap: 1rem; padding-top: 10rem;">
+                        <div style="display: flex; flex-direction: column; gap: 0.5rem;">
+                            <For
+                                each=move || sent.get().into_iter().enumerate()
+                                key=|(i, _)| *i
+                                children=move |(_, message)| view! {
+                                    <div>
+                                        <MentionText
+                                            text=message.text
+                                            mentions=message.mentions
+                                            current_user_id=me
+                                        />
+                                    </div>
+                                }
+                            />
+                        </div>
+                        <MentionInput
+                            value=Signal::derive(move || draft.with(|d| d.text.clone()))
+                            on_change=move |m: MentionMessage| set_draft.set(m)
+                            users=users
+                            on_submit=move |m: MentionMessage| {
+                                set_sent.update(|sent| sent.push(m));
+                                set_draft.set(MentionMessage::default());
+                            }
+                            placeholder="Say something to @someone..."
+                        />
+                        <p style="color: #888; font-size: 0.875rem;">
+                            {move || {
+                                let ids: Vec<String> = draft.with(|d| {
+                                    d.mentions.iter().map(|m| m.user_id.clone()).collect()
+                                });
+                                format!("Mentions: {ids:?}")
+                            }}
+                        </p>
+                    </div>
+                </div>
+            </div>
+
+            <div class="story-section">
+                <h3>"Highlighted Mentions"</h3>
+                <p class="story-description">"Mentions of the current user stand out from the rest."</p>
+                <div class="story-canvas">
+                    <MentionText text=example_text mentions=example_mentions current_user_id=me />
+                </div>
+            </div>
+
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="value"
+                            values="Signal<String>"
+                            description="Current text"
+                        />
+                        <AttributeCard
+                            name="on_change"
+                            values="Callback<MentionMessage>"
+                            description="The text and the mentions in it, after every edit"
+                        />
+                        <AttributeCard
+                            name="users"
+                            values="Signal<Vec<MentionUser>>"
+                            description="Who can be mentioned; mention_users builds this from presence"
+                        />
+                        <AttributeCard
+                            name="on_submit"
+                            values="Callback<MentionMessage> (optional)"
+                            description="Called on Enter; without it Enter adds a newline"
+                        />
+                        <AttributeCard
+                            name="rows"
+                            values="u32 (default: 1)"
+                            description="Visible rows"
+                        />
+                        <AttributeCard
+                            name="placeholder / disabled / class"
+                            values="optional"
+                            description="As on Textarea"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r#"use ui_components::{mention_users, MentionInput, MentionMessage, MentionText};
+
+let (draft, set_draft) = signal(MentionMessage::default());
+let users = Signal::derive(move || mention_users(&presence.get(), &my_id));
+
+view! {
+    <MentionInput
+        value=Signal::derive(move || draft.with(|d| d.text.clone()))
+        on_change=move |m: MentionMessage| set_draft.set(m)
+        users=users
+        on_submit=move |m: MentionMessage| send(m.text, m.mentions)
+    />
+}
+
+// In the message list
+view! { <MentionText text=msg.text mentions=msg.mentions current_user_id=my_id /> }"#}</pre>
+            </div>
+        </div>
+    }
+}
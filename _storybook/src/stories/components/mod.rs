@@ -8,66 +8,108 @@ mod asset_detail_card;
 mod asset_grid;
 mod asset_picker;
 mod badge;
+mod breadcrumbs;
 mod button;
 mod button_group;
 mod card;
 mod color_swatch;
+mod confirm_dialog;
 mod connection_status;
+mod density;
 mod drop_editor;
 mod empty_state;
+mod explorer_link;
 mod form_group;
 mod header;
 mod image_card;
 mod info_grid;
+mod json_tree;
 mod loading_overlay;
 mod memory_card;
+mod mention_input;
 mod modal;
 mod modal_stack;
+mod otp_input;
+mod phase_transition;
 mod player_card;
+mod player_list;
+mod product_tour;
 mod progress_bar;
+mod progress_ring;
 mod rating;
+mod require_role;
 mod role_dots;
 mod select;
 mod skeleton;
 mod stat_pill;
+mod sticky_action_bar;
+mod system_banner;
 mod tabs;
 mod text_input;
 mod textarea;
+mod truncated_text;
 mod use_draggable;
 mod user_avatar;
 
-pub use accordion::*;
-pub use alert::*;
-pub use asset_cache::*;
-pub use asset_card::*;
-pub use asset_detail_card::*;
-pub use asset_grid::*;
-pub use asset_picker::*;
-pub use badge::*;
-pub use button::*;
-pub use button_group::*;
-pub use card::*;
-pub use color_swatch::*;
-pub use connection_status::*;
-pub use drop_editor::*;
-pub use empty_state::*;
-pub use form_group::*;
-pub use header::*;
-pub use image_card::*;
-pub use info_grid::*;
-pub use loading_overlay::*;
-pub use memory_card::*;
-pub use modal::*;
-pub use modal_stack::*;
-pub use player_card::*;
-pub use progress_bar::*;
-pub use rating::*;
-pub use role_dots::*;
-pub use select::*;
-pub use skeleton::*;
-pub use stat_pill::*;
-pub use tabs::*;
-pub use text_input::*;
-pub use textarea::*;
-pub use use_draggable::*;
-pub use user_avatar::*;
+use crate::registry::StoryDef;
+
+/// Component stories in sidebar order
+pub const STORIES: &[StoryDef] = &[
+    // Layout
+    accordion::STORY,
+    card::STORY,
+    modal::STORY,
+    modal_stack::STORY,
+    tabs::STORY,
+    header::STORY,
+    breadcrumbs::STORY,
+    density::STORY,
+    // Feedback
+    loading_overlay::STORY,
+    phase_transition::STORY,
+    skeleton::STORY,
+    alert::STORY,
+    confirm_dialog::STORY,
+    system_banner::STORY,
+    product_tour::STORY,
+    // User
+    user_avatar::STORY,
+    role_dots::STORY,
+    require_role::STORY,
+    player_card::STORY,
+    player_list::STORY,
+    // Data Display
+    image_card::STORY,
+    asset_card::STORY,
+    asset_grid::STORY,
+    asset_picker::STORY,
+    asset_detail_card::STORY,
+    asset_cache::STORY,
+    connection_status::STORY,
+    memory_card::STORY,
+    stat_pill::STORY,
+    badge::STORY,
+    empty_state::STORY,
+    explorer_link::STORY,
+    truncated_text::STORY,
+    progress_bar::STORY,
+    progress_ring::STORY,
+    info_grid::STORY,
+    json_tree::STORY,
+    color_swatch::STORY,
+    rating::STORY,
+    // Forms
+    button::STORY,
+    button_group::STORY,
+    select::STORY,
+    text_input::STORY,
+    otp_input::STORY,
+    textarea::STORY,
+    mention_input::STORY,
+    form_group::STORY,
+    sticky_action_bar::STORY,
+    // Editors
+    drop_editor::STORY,
+    // Hooks
+    use_draggable::STORY,
+];
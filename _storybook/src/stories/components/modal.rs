@@ -1,9 +1,18 @@
 //! Modal component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::Modal;
 
+pub const STORY: StoryDef = StoryDef {
+    id: "modal",
+    label: "Modal",
+    category: "Layout",
+    keywords: &["dialog", "popup", "overlay"],
+    render: || view! { <ModalStory /> }.into_any(),
+};
+
 #[component]
 pub fn ModalStory() -> impl IntoView {
     let (show_basic, set_show_basic) = signal(false);
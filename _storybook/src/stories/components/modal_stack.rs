@@ -1,8 +1,19 @@
 //! ModalStack component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::{Button, ButtonVariant, Modal, ModalStack, ModalStackContext};
+use ui_components::{
+    use_modal_route, Button, ButtonVariant, Modal, ModalStack, ModalStackContext, ModalStackRoute,
+};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "modal-stack",
+    label: "Modal Stack",
+    category: "Layout",
+    keywords: &["dialog", "nested", "overlay"],
+    render: || view! { <ModalStackStory /> }.into_any(),
+};
 
 /// View enum for the demo modal
 #[derive(Clone, PartialEq)]
@@ -72,6 +83,25 @@ fn demo_content(view: DemoView, ctx: ModalStackContext<DemoView>) -> AnyView {
     }
 }
 
+fn demo_route_segment(view: &DemoView) -> String {
+    match view {
+        DemoView::Main => "main",
+        DemoView::Settings => "settings",
+        DemoView::Profile => "profile",
+        DemoView::EditName => "edit-name",
+    }
+    .to_string()
+}
+
+fn demo_view_from_segment(segment: &str) -> Option<DemoView> {
+    match segment {
+        "settings" => Some(DemoView::Settings),
+        "profile" => Some(DemoView::Profile),
+        "edit-name" => Some(DemoView::EditName),
+        _ => None,
+    }
+}
+
 fn nested_title(view: &NestedView) -> String {
     match view {
         NestedView::Step1 => "Step 1".to_string(),
@@ -229,6 +259,7 @@ pub fn ModalStackStory() -> impl IntoView {
     let (show_nested, set_show_nested) = signal(false);
     let (show_preserved, set_show_preserved) = signal(false);
     let (show_context_aware, set_show_context_aware) = signal(false);
+    let demo_route = use_modal_route("demo");
 
     view! {
         <div>
@@ -266,7 +297,17 @@ pub fn ModalStackStory() -> impl IntoView {
                         >
                             "Context-Aware Modal"
                         </button>
+                        <button
+                            class="btn btn--primary"
+                            on:click=move |_| demo_route.open("")
+                        >
+                            "Route-Aware (#demo=...)"
+                        </button>
                     </div>
+                    <p class="story-description">
+                        "The route-aware demo mirrors its views into the URL hash. "
+                        "Navigate a few levels, then refresh or use the browser back button."
+                    </p>
                 </div>
             </div>
 
@@ -306,6 +347,16 @@ pub fn ModalStackStory() -> impl IntoView {
                 view_content=context_aware_content
             />
 
+            // Route-Aware Demo
+            <ModalStack
+                open=Signal::derive(move || demo_route.is_open())
+                initial_view=DemoView::Main
+                on_close=Callback::new(move |()| demo_route.close())
+                view_title=demo_title
+                view_content=demo_content
+                route=ModalStackRoute::new(demo_route, demo_route_segment, demo_view_from_segment)
+            />
+
             // Key Differences section
             <div class="story-section">
                 <h3>"Key Differences from Modal"</h3>
@@ -379,6 +430,11 @@ pub fn ModalStackStory() -> impl IntoView {
                             values="bool (default: false)"
                             description="Remove body padding for full-bleed content"
                         />
+                        <AttributeCard
+                            name="route"
+                            values="ModalStackRoute<V> (optional)"
+                            description="Mirror the view stack into a URL hash parameter; restores on refresh and pops on back"
+                        />
                     </div>
                 </div>
             </div>
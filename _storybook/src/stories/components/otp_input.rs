@@ -0,0 +1,135 @@
+//! OtpInput component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{OtpCharset, OtpInput};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "otp-input",
+    label: "OTP Input",
+    category: "Forms",
+    keywords: &["code", "pin", "verification", "join", "segmented"],
+    render: || view! { <OtpInputStory /> }.into_any(),
+};
+
+/// Code the verification demo accepts
+const DEMO_CODE: &str = "123456";
+
+#[component]
+pub fn OtpInputStory() -> impl IntoView {
+    let (code, set_code) = signal(String::new());
+    let (wrong, set_wrong) = signal(false);
+    let (verified, set_verified) = signal(false);
+    let (room, set_room) = signal(String::new());
+    let (disabled_code, _) = signal("4821".to_string());
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"OtpInput"</h2>
+                <p>"A segmented one-time-code input for verification codes and room join codes. Typing advances, Backspace steps back, and pasting a code fills every box."</p>
+            </div>
+
+            // Verification
+            <div class="story-section">
+                <h3>"Verification Code"</h3>
+                <p class="story-description">"Numeric, with a numeric keyboard on mobile. Enter anything but 123456 to see the error shake."</p>
+                <div class="story-canvas">
+                    <OtpInput
+                        value=code
+                        on_change=Callback::new(move |c: String| {
+                            set_wrong.set(false);
+                            set_verified.set(false);
+                            set_code.set(c);
+                        })
+                        on_complete=Callback::new(move |c: String| {
+                            if c == DEMO_CODE {
+                                set_verified.set(true);
+                            } else {
+                                set_wrong.set(true);
+                            }
+                        })
+                        error=wrong
+                        label="Verification code"
+                    />
+                    <p style="margin-top: 0.5rem; color: #888; font-size: 0.875rem;">
+                        {move || if verified.get() { "Verified!".to_string() } else { format!("Value: {}", code.get()) }}
+                    </p>
+                </div>
+            </div>
+
+            // Room code
+            <div class="story-section">
+                <h3>"Room Join Code"</h3>
+                <p class="story-description">"Alphanumeric, five characters, uppercased as typed."</p>
+                <div class="story-canvas">
+                    <OtpInput
+                        value=room
+                        on_change=Callback::new(move |c| set_room.set(c))
+                        length=5
+                        charset=OtpCharset::Alphanumeric
+                        label="Room code"
+                    />
+                </div>
+            </div>
+
+            // Disabled
+            <div class="story-section">
+                <h3>"Disabled"</h3>
+                <div class="story-canvas">
+                    <OtpInput
+                        value=disabled_code
+                        on_change=Callback::new(|_| {})
+                        length=4
+                        disabled=true
+                    />
+                </div>
+            </div>
+
+            // Props
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard name="value" values="Signal<String>" description="Current code" />
+                        <AttributeCard name="on_change" values="Callback<String>" description="Called with the code after every edit" />
+                        <AttributeCard name="on_complete" values="Callback<String>" description="Called when every box is filled" />
+                        <AttributeCard name="length" values="usize" description="Number of boxes (default 6)" />
+                        <AttributeCard name="charset" values="Numeric | Alphanumeric" description="Accepted characters; letters are uppercased" />
+                        <AttributeCard name="label" values="String" description="Optional label" />
+                        <AttributeCard name="error" values="Signal<bool>" description="Error state; shakes when it turns on" />
+                        <AttributeCard name="disabled" values="Signal<bool>" description="Disable every box" />
+                        <AttributeCard name="autofocus" values="bool" description="Focus the first empty box on mount" />
+                    </div>
+                </div>
+            </div>
+
+            // Usage
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r#"use ui_components::{OtpCharset, OtpInput};
+
+let (code, set_code) = signal(String::new());
+let (wrong, set_wrong) = signal(false);
+
+view! {
+    <OtpInput
+        value=code
+        on_change=move |c| { set_wrong.set(false); set_code.set(c) }
+        on_complete=move |c| verify(c)
+        error=wrong
+    />
+
+    // Room join code
+    <OtpInput
+        value=room_code
+        on_change=move |c| set_room_code.set(c)
+        length=5
+        charset=OtpCharset::Alphanumeric
+    />
+}"#}</pre>
+            </div>
+        </div>
+    }
+}
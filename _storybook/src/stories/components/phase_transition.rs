@@ -0,0 +1,123 @@
+//! Phase Transition component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use std::time::Duration;
+use ui_components::{Button, ButtonVariant, PhaseOverlay, PhaseTransition};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "phase-transition",
+    label: "Phase Transition",
+    category: "Feedback",
+    keywords: &["countdown", "winner", "confetti", "game", "overlay"],
+    render: || view! { <PhaseTransitionStory /> }.into_any(),
+};
+
+#[component]
+pub fn PhaseTransitionStory() -> impl IntoView {
+    let (overlay, set_overlay) = signal(None::<PhaseOverlay>);
+
+    // Count 3, 2, 1 then announce the start
+    let start_countdown = move || {
+        for (i, seconds) in [3u8, 2, 1].into_iter().enumerate() {
+            set_timeout(
+                move || set_overlay.set(Some(PhaseOverlay::Countdown { seconds })),
+                Duration::from_secs(i as u64),
+            );
+        }
+        set_timeout(
+            move || set_overlay.set(Some(PhaseOverlay::announcement("Go!"))),
+            Duration::from_secs(3),
+        );
+    };
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Phase Transition"</h2>
+                <p>"Full-screen animated overlays between game phases: countdowns, announcements and a winner screen with confetti."</p>
+            </div>
+
+            <div class="story-section">
+                <h3>"Overlays"</h3>
+                <p class="story-description">"Announcements hide after 2.5 seconds or on click. Countdowns stay until the overlay changes."</p>
+                <div class="story-canvas">
+                    <div class="story-inline">
+                        <Button variant=ButtonVariant::Primary on_click=move |()| start_countdown()>
+                            "Countdown"
+                        </Button>
+                        <Button
+                            variant=ButtonVariant::Secondary
+                            on_click=move |()| {
+                                set_overlay.set(Some(
+                                    PhaseOverlay::announcement("All players ready")
+                                        .with_subtitle("Loading complete"),
+                                ))
+                            }
+                        >
+                            "All Players Ready"
+                        </Button>
+                        <Button
+                            variant=ButtonVariant::Secondary
+                            on_click=move |()| {
+                                set_overlay.set(Some(
+                                    PhaseOverlay::winner("Captain Flint wins!")
+                                        .with_subtitle("12 pairs found"),
+                                ))
+                            }
+                        >
+                            "Winner"
+                        </Button>
+                    </div>
+                    <PhaseTransition
+                        overlay=overlay
+                        on_dismiss=move |()| set_overlay.set(None)
+                    />
+                </div>
+            </div>
+
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="overlay"
+                            values="Signal<Option<PhaseOverlay>>"
+                            description="Overlay for the current phase: Countdown, Announcement or Winner"
+                        />
+                        <AttributeCard
+                            name="duration_ms"
+                            values="u32"
+                            description="How long announcements stay up (default: 2500, 0 = until the overlay changes)"
+                        />
+                        <AttributeCard
+                            name="on_dismiss"
+                            values="Callback<()>"
+                            description="Called when the user clicks an announcement away"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r#"use ui_components::{PhaseOverlay, PhaseTransition};
+
+// Map your game phase to an overlay
+let overlay = Signal::derive(move || match game_state.get().phase {
+    GamePhase::Starting { countdown } => {
+        Some(PhaseOverlay::Countdown { seconds: countdown })
+    }
+    GamePhase::Playing => Some(PhaseOverlay::announcement("All players ready")),
+    GamePhase::Finished { .. } => {
+        Some(PhaseOverlay::winner("You win!").with_subtitle("12 pairs"))
+    }
+    _ => None,
+});
+
+view! { <PhaseTransition overlay=overlay /> }"#}</pre>
+            </div>
+        </div>
+    }
+}
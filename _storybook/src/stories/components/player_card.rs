@@ -1,8 +1,17 @@
 //! Player Card component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::{Button, ButtonVariant, PlayerCard};
+use ui_components::{Button, ButtonSize, ButtonVariant, PlayerCard, PresenceStatus};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "player-card",
+    label: "Player Card",
+    category: "User",
+    keywords: &["profile", "game", "participant"],
+    render: || view! { <PlayerCardStory /> }.into_any(),
+};
 
 // Sample Discord avatar URLs
 const AVATAR_1: &str = "https://cdn.discordapp.com/guilds/1283465958945456149/users/179744071361757184/avatars/7e67374c51a831be5f10516a3df195f8.png";
@@ -19,6 +28,26 @@ const TRIBE_2: &str =
 
 #[component]
 pub fn PlayerCardStory() -> impl IntoView {
+    let status = RwSignal::new(Some(PresenceStatus::Active));
+    let streak = RwSignal::new(3_u32);
+    let remaining = RwSignal::new(0.7_f32);
+    let host = RwSignal::new(true);
+    let crown = RwSignal::new(false);
+
+    let cycle_status = move |()| {
+        status.update(|s| {
+            *s = match s {
+                Some(PresenceStatus::Active) => Some(PresenceStatus::Idle),
+                Some(PresenceStatus::Idle) => Some(PresenceStatus::Away),
+                Some(PresenceStatus::Away) => None,
+                None => Some(PresenceStatus::Active),
+            }
+        });
+    };
+    let tick_timer = move |()| {
+        remaining.update(|r| *r = if *r <= 0.1 { 1.0 } else { *r - 0.15 });
+    };
+
     view! {
         <div>
             <div class="story-header">
@@ -134,6 +163,49 @@ pub fn PlayerCardStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Game chrome
+            <div class="story-section">
+                <h3>"Game Chrome"</h3>
+                <p class="story-description">"Presence dot, streak flame, turn-timer ring and host/crown badges, all driven by signals. The same chrome is used by PlayerList."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; gap: 1.5rem; align-items: flex-start; flex-wrap: wrap;">
+                        <div style="width: 280px;">
+                            <PlayerCard
+                                name="Captain Jack"
+                                subtitle="The Black Pearl"
+                                avatar_url=AVATAR_1
+                                hero_url=TRIBE_1
+                                status=status
+                                streak=streak
+                                turn_progress=remaining
+                                host=host
+                                crown=crown
+                            />
+                        </div>
+                        <div style="display: flex; flex-direction: column; gap: 0.5rem;">
+                            <Button size=ButtonSize::Sm variant=ButtonVariant::Secondary on_click=cycle_status>
+                                "Cycle status"
+                            </Button>
+                            <Button size=ButtonSize::Sm variant=ButtonVariant::Secondary on_click=move |()| streak.update(|s| *s += 1)>
+                                "Match (+1 streak)"
+                            </Button>
+                            <Button size=ButtonSize::Sm variant=ButtonVariant::Secondary on_click=move |()| streak.set(0)>
+                                "Miss (reset streak)"
+                            </Button>
+                            <Button size=ButtonSize::Sm variant=ButtonVariant::Secondary on_click=tick_timer>
+                                "Tick turn timer"
+                            </Button>
+                            <Button size=ButtonSize::Sm variant=ButtonVariant::Secondary on_click=move |()| host.update(|h| *h = !*h)>
+                                "Toggle host"
+                            </Button>
+                            <Button size=ButtonSize::Sm variant=ButtonVariant::Secondary on_click=move |()| crown.update(|c| *c = !*c)>
+                                "Toggle crown"
+                            </Button>
+                        </div>
+                    </div>
+                </div>
+            </div>
+
             // Props section
             <div class="story-section">
                 <h3>"Props"</h3>
@@ -174,6 +246,26 @@ pub fn PlayerCardStory() -> impl IntoView {
                             values="Callback<()> (optional)"
                             description="Click handler for the card. Makes card interactive."
                         />
+                        <AttributeCard
+                            name="status"
+                            values="Signal<Option<PresenceStatus>> (optional)"
+                            description="Presence dot on the avatar. None renders as offline; omit to hide."
+                        />
+                        <AttributeCard
+                            name="streak"
+                            values="Signal<u32> (optional)"
+                            description="Streak flame beside the name, shown from 2 and intensifying at 4 and 6"
+                        />
+                        <AttributeCard
+                            name="turn_progress"
+                            values="Signal<f32> (optional)"
+                            description="Turn time remaining (1.0 to 0.0) drawn as a ring; turns red below 25%"
+                        />
+                        <AttributeCard
+                            name="host / crown"
+                            values="Signal<bool> (optional)"
+                            description="Host badge below and crown above the avatar"
+                        />
                         <AttributeCard
                             name="children"
                             values="Children (optional)"
@@ -207,6 +299,18 @@ view! {
     </PlayerCard>
 }
 
+// Game chrome driven by signals
+view! {
+    <PlayerCard
+        name="Captain Jack"
+        avatar_url="https://..."
+        status=presence        // Signal<Option<PresenceStatus>>
+        streak=streak          // Signal<u32>
+        turn_progress=remaining // Signal<f32>
+        host=is_host
+    />
+}
+
 // Minimal card
 view! {
     <PlayerCard name="Unknown Pirate" />
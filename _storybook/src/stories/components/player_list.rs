@@ -0,0 +1,225 @@
+//! Player List component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{
+    Button, ButtonSize, ButtonVariant, PlayerList, PlayerListEntry, PresenceStatus,
+};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "player-list",
+    label: "Player List",
+    category: "User",
+    keywords: &["scoreboard", "game", "turn", "presence", "streak"],
+    render: || view! { <PlayerListStory /> }.into_any(),
+};
+
+// Sample Discord avatar URLs
+const AVATAR_1: &str = "https://cdn.discordapp.com/guilds/1283465958945456149/users/179744071361757184/avatars/7e67374c51a831be5f10516a3df195f8.png";
+const AVATAR_2: &str =
+    "https://cdn.discordapp.com/avatars/806487443955384381/e68cb992f315a06ebf7d9c0963ca511c.png";
+const AVATAR_3: &str =
+    "https://cdn.discordapp.com/avatars/142538195202998272/f625b4e5b163d06bf49b657435958853.png";
+
+fn sample_players() -> Vec<PlayerListEntry> {
+    vec![
+        PlayerListEntry {
+            user_id: "jack".into(),
+            name: "Captain Jack".into(),
+            avatar_url: Some(AVATAR_1.into()),
+            score: 4,
+            streak: 2,
+            presence: Some(PresenceStatus::Active),
+            host: true,
+            ..Default::default()
+        },
+        PlayerListEntry {
+            user_id: "anne".into(),
+            name: "Anne Bonny".into(),
+            avatar_url: Some(AVATAR_2.into()),
+            score: 3,
+            presence: Some(PresenceStatus::Idle),
+            ..Default::default()
+        },
+        PlayerListEntry {
+            user_id: "calico".into(),
+            name: "Calico Jack".into(),
+            avatar_url: Some(AVATAR_3.into()),
+            score: 1,
+            presence: Some(PresenceStatus::Away),
+            ..Default::default()
+        },
+        PlayerListEntry {
+            user_id: "bot_1".into(),
+            name: "Mary Read".into(),
+            score: 2,
+            presence: Some(PresenceStatus::Active),
+            bot: true,
+            ..Default::default()
+        },
+        PlayerListEntry {
+            user_id: "ghost".into(),
+            name: "Davy Jones".into(),
+            spectating: true,
+            ..Default::default()
+        },
+    ]
+}
+
+#[component]
+pub fn PlayerListStory() -> impl IntoView {
+    let players = RwSignal::new(sample_players());
+    let order = ["jack", "anne", "calico", "bot_1"];
+    let turn = RwSignal::new(0_usize);
+    let remaining = RwSignal::new(1.0_f32);
+
+    let current_turn = Signal::derive(move || Some(order[turn.get()].to_string()));
+
+    let scored = move |()| {
+        let id = order[turn.get_untracked()];
+        players.update(|list| {
+            if let Some(p) = list.iter_mut().find(|p| p.user_id == id) {
+                p.score += 1;
+                p.streak += 1;
+            }
+        });
+        remaining.set(1.0);
+    };
+    let missed = move |()| {
+        let id = order[turn.get_untracked()];
+        players.update(|list| {
+            if let Some(p) = list.iter_mut().find(|p| p.user_id == id) {
+                p.streak = 0;
+            }
+        });
+        turn.update(|t| *t = (*t + 1) % order.len());
+        remaining.set(1.0);
+    };
+    let tick_timer = move |()| {
+        remaining.update(|r| *r = (*r - 0.15).max(0.0));
+    };
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Player List"</h2>
+                <p>"A game scoreboard sharing PlayerCard's chrome: turn highlight and timer ring, presence dots, streak flames, host badge and a crown for the leader."</p>
+            </div>
+
+            // Interactive scoreboard
+            <div class="story-section">
+                <h3>"Scoreboard"</h3>
+                <p class="story-description">"Score to extend the current player's streak, miss to pass the turn. The unique leader wears the crown."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; gap: 1.5rem; align-items: flex-start; flex-wrap: wrap;">
+                        <div style="width: 320px;">
+                            <PlayerList
+                                players=players
+                                current_turn=current_turn
+                                current_user_id="anne".to_string()
+                                turn_progress=remaining
+                                track_presence=true
+                                crown_leader=true
+                                title="Players"
+                            />
+                        </div>
+                        <div style="display: flex; flex-direction: column; gap: 0.5rem;">
+                            <Button size=ButtonSize::Sm variant=ButtonVariant::Primary on_click=scored>
+                                "Match"
+                            </Button>
+                            <Button size=ButtonSize::Sm variant=ButtonVariant::Secondary on_click=missed>
+                                "Miss"
+                            </Button>
+                            <Button size=ButtonSize::Sm variant=ButtonVariant::Secondary on_click=tick_timer>
+                                "Tick turn timer"
+                            </Button>
+                        </div>
+                    </div>
+                </div>
+            </div>
+
+            // Minimal
+            <div class="story-section">
+                <h3>"Minimal"</h3>
+                <p class="story-description">"Without presence tracking, turn or crown - just names and scores."</p>
+                <div class="story-canvas">
+                    <div style="width: 320px;">
+                        <PlayerList players=Signal::derive(sample_players) />
+                    </div>
+                </div>
+            </div>
+
+            // Props section
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="players"
+                            values="Signal<Vec<PlayerListEntry>>"
+                            description="Rows in display order: id, name, avatar, score, streak, presence, spectating, host, bot"
+                        />
+                        <AttributeCard
+                            name="current_turn"
+                            values="Signal<Option<String>> (optional)"
+                            description="user_id whose turn it is; highlights the row"
+                        />
+                        <AttributeCard
+                            name="current_user_id"
+                            values="Signal<String> (optional)"
+                            description="Local user's id, marked \"(you)\""
+                        />
+                        <AttributeCard
+                            name="turn_progress"
+                            values="Signal<f32> (optional)"
+                            description="Turn time remaining, drawn as a ring on the current player's avatar"
+                        />
+                        <AttributeCard
+                            name="track_presence"
+                            values="bool"
+                            description="Show presence dots; players without presence show as offline"
+                        />
+                        <AttributeCard
+                            name="crown_leader"
+                            values="bool"
+                            description="Crown the player with the unique highest score"
+                        />
+                        <AttributeCard
+                            name="title"
+                            values="String (optional)"
+                            description="Heading above the list"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            // Code example
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r##"use ui_components::{PlayerList, PlayerListEntry};
+
+let players = Signal::derive(move || {
+    state.get().players.values().map(|p| PlayerListEntry {
+        user_id: p.user_id.clone(),
+        name: p.user_name.clone(),
+        score: p.score,
+        presence: presence_of(&p.user_id),
+        ..Default::default()
+    }).collect()
+});
+
+view! {
+    <PlayerList
+        players=players
+        current_turn=current_turn
+        current_user_id=my_id
+        track_presence=true
+        crown_leader=true
+        title="Players"
+    />
+}"##}</pre>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,177 @@
+//! ProductTour component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{
+    is_tour_completed, reset_tour, Button, ButtonSize, ButtonVariant, ProductTour, StatPill,
+    TourAnchor, TourPlacement, TourStep,
+};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "product-tour",
+    label: "Product Tour",
+    category: "Feedback",
+    keywords: &[
+        "onboarding",
+        "walkthrough",
+        "coachmark",
+        "spotlight",
+        "tutorial",
+    ],
+    render: || view! { <ProductTourStory /> }.into_any(),
+};
+
+const TOUR_ID: &str = "storybook-demo";
+
+fn steps() -> Vec<TourStep> {
+    vec![
+        TourStep::new(
+            "balance",
+            "Your balance",
+            "Tickets are paid from this balance. Top it up before a round starts.",
+        ),
+        TourStep::new(
+            "buy",
+            "Buy tickets",
+            "Each ticket is one entry. Buy as many as you like until the draw.",
+        )
+        .placement(TourPlacement::Right),
+        TourStep::new(
+            "history",
+            "Past rounds",
+            "Winners and prizes from earlier rounds are listed here.",
+        )
+        .placement(TourPlacement::Top),
+        TourStep::new(
+            "missing",
+            "Anywhere else",
+            "Steps whose anchor isn't on the page show a centered popover.",
+        ),
+    ]
+}
+
+#[component]
+pub fn ProductTourStory() -> impl IntoView {
+    let open = RwSignal::new(false);
+    let completed = RwSignal::new(is_tour_completed(TOUR_ID));
+    let outcome = RwSignal::new(None::<&'static str>);
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Product Tour"</h2>
+                <p>"Onboarding walkthrough that spotlights registered anchors one at a time, with next/back/skip controls. Completion is remembered in Settings so the tour only opens by itself once."</p>
+            </div>
+
+            // Demo
+            <div class="story-section">
+                <h3>"Walkthrough"</h3>
+                <div class="story-canvas">
+                    <ProductTour
+                        id=TOUR_ID
+                        steps=steps()
+                        open=open
+                        on_finish=move |done: bool| {
+                            completed.set(true);
+                            outcome.set(Some(if done { "Completed" } else { "Skipped" }));
+                        }
+                    >
+                        <div style="display: flex; gap: 0.5rem; margin-bottom: 1.5rem;">
+                            <Button on_click=move |_| open.set(true)>"Start Tour"</Button>
+                            <Button
+                                variant=ButtonVariant::Secondary
+                                on_click=move |_| {
+                                    reset_tour(TOUR_ID);
+                                    completed.set(false);
+                                    outcome.set(None);
+                                }
+                            >
+                                "Reset Completion"
+                            </Button>
+                        </div>
+                        <div class="story-inline">
+                            <TourAnchor id="balance">
+                                <StatPill value="1,250 ADA" icon="₳" />
+                            </TourAnchor>
+                            <TourAnchor id="buy">
+                                <Button size=ButtonSize::Lg>"Buy Tickets"</Button>
+                            </TourAnchor>
+                        </div>
+                        <TourAnchor id="history">
+                            <div style="margin-top: 12rem; padding: 1rem; border: 1px dashed #3a3a5e; border-radius: 8px; color: #a0a0b0;">
+                                "Round history"
+                            </div>
+                        </TourAnchor>
+                    </ProductTour>
+                    <p style="margin-top: 1rem; color: #a0a0b0; font-size: 0.85rem;">
+                        {move || format!(
+                            "Completed flag: {}{}",
+                            completed.get(),
+                            outcome.get().map(|o| format!(" ({o})")).unwrap_or_default(),
+                        )}
+                    </p>
+                </div>
+            </div>
+
+            // Props section
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="id"
+                            values="String"
+                            description="Tour id; completion is persisted under it"
+                        />
+                        <AttributeCard
+                            name="steps"
+                            values="Vec<TourStep>"
+                            description="Steps in order - anchor id, title, body, placement"
+                        />
+                        <AttributeCard
+                            name="open"
+                            values="RwSignal<bool> (optional)"
+                            description="External control; without it the tour opens unless completed"
+                        />
+                        <AttributeCard
+                            name="on_finish"
+                            values="Callback<bool> (optional)"
+                            description="true when completed, false when skipped"
+                        />
+                        <AttributeCard
+                            name="placement"
+                            values="TourPlacement (Top|Bottom|Left|Right)"
+                            description="TourStep field - preferred popover side, flipped when out of room"
+                        />
+                        <AttributeCard
+                            name="TourAnchor"
+                            values="id, class (optional)"
+                            description="Wraps the element a step points at"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            // Usage
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r##"use ui_components::{ProductTour, TourAnchor, TourPlacement, TourStep};
+
+view! {
+    <ProductTour
+        id="raffle-onboarding"
+        steps=vec![
+            TourStep::new("wallet", "Connect a wallet", "Tickets are bought with ADA."),
+            TourStep::new("prizes", "Prizes", "Everything up for grabs.")
+                .placement(TourPlacement::Right),
+        ]
+    >
+        <TourAnchor id="wallet"><WalletButton /></TourAnchor>
+        <TourAnchor id="prizes"><PrizeList /></TourAnchor>
+    </ProductTour>
+}"##}</pre>
+            </div>
+        </div>
+    }
+}
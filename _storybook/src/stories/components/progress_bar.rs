@@ -1,9 +1,18 @@
 //! ProgressBar component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::ProgressBar;
 
+pub const STORY: StoryDef = StoryDef {
+    id: "progress-bar",
+    label: "Progress Bar",
+    category: "Data Display",
+    keywords: &["meter", "percent", "loading"],
+    render: || view! { <ProgressBarStory /> }.into_any(),
+};
+
 #[component]
 pub fn ProgressBarStory() -> impl IntoView {
     let (progress, set_progress) = signal(0.65f32);
@@ -0,0 +1,161 @@
+//! ProgressRing component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{ProgressRing, RingThreshold};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "progress-ring",
+    label: "Progress Ring",
+    category: "Data Display",
+    keywords: &["circular", "timer", "countdown", "turn", "upload"],
+    render: || view! { <ProgressRingStory /> }.into_any(),
+};
+
+const TURN_MS: u64 = 15_000;
+
+fn turn_thresholds() -> Vec<RingThreshold> {
+    vec![
+        RingThreshold::new(0.5, "#ff9800"),
+        RingThreshold::new(0.2, "#f44336"),
+    ]
+}
+
+#[component]
+pub fn ProgressRingStory() -> impl IntoView {
+    let (progress, set_progress) = signal(0.4f32);
+    let deadline = RwSignal::new(None::<u64>);
+    let expired = RwSignal::new(0u32);
+    let start_turn = move |_| deadline.set(Some(js_sys::Date::now() as u64 + TURN_MS));
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Progress Ring"</h2>
+                <p>"Circular progress for uploads, or a countdown ring bound to a deadline for turn timers. Thresholds recolor the ring as it drains."</p>
+            </div>
+
+            // Determinate
+            <div class="story-section">
+                <h3>"Determinate"</h3>
+                <div class="story-canvas">
+                    <div class="story-inline">
+                        <ProgressRing value=Signal::derive(|| 0.0) show_value=true />
+                        <ProgressRing value=Signal::derive(|| 0.25) show_value=true />
+                        <ProgressRing value=Signal::derive(|| 0.75) show_value=true />
+                        <ProgressRing value=Signal::derive(|| 1.0) show_value=true />
+                        <ProgressRing value=progress size=72 stroke_width=6.0 show_value=true />
+                    </div>
+                    <div style="display: flex; gap: 0.5rem; margin-top: 1rem;">
+                        <button class="btn btn--secondary btn--sm" on:click=move |_| set_progress.update(|p| *p = (*p - 0.1).max(0.0))>"-10%"</button>
+                        <button class="btn btn--secondary btn--sm" on:click=move |_| set_progress.update(|p| *p = (*p + 0.1).min(1.0))>"+10%"</button>
+                    </div>
+                </div>
+            </div>
+
+            // Countdown
+            <div class="story-section">
+                <h3>"Turn Timer"</h3>
+                <div class="story-canvas">
+                    <div class="story-inline">
+                        <ProgressRing
+                            deadline=deadline
+                            duration_ms=TURN_MS
+                            thresholds=turn_thresholds()
+                            size=64
+                            show_value=true
+                            on_expire=move |_| expired.update(|n| *n += 1)
+                        />
+                        <ProgressRing
+                            deadline=deadline
+                            duration_ms=TURN_MS
+                            thresholds=turn_thresholds()
+                            size=32
+                            stroke_width=3.0
+                        />
+                    </div>
+                    <div style="display: flex; gap: 0.5rem; margin-top: 1rem; align-items: center;">
+                        <button class="btn btn--secondary btn--sm" on:click=start_turn>"Start 15s Turn"</button>
+                        <button class="btn btn--secondary btn--sm" on:click=move |_| deadline.set(None)>"Stop"</button>
+                        <span style="color: #a0a0b0; font-size: 0.85rem;">
+                            {move || format!("Expired {} times", expired.get())}
+                        </span>
+                    </div>
+                </div>
+            </div>
+
+            // Props section
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="value"
+                            values="Signal<f32> (optional)"
+                            description="Determinate progress from 0.0 to 1.0"
+                        />
+                        <AttributeCard
+                            name="deadline"
+                            values="Signal<Option<u64>> (optional)"
+                            description="Unix ms the countdown ends at; None = not running"
+                        />
+                        <AttributeCard
+                            name="duration_ms"
+                            values="u64"
+                            description="Full countdown length; the ring is full this long before the deadline"
+                        />
+                        <AttributeCard
+                            name="thresholds"
+                            values="Vec<RingThreshold>"
+                            description="Ring color once the fraction drops below each threshold"
+                        />
+                        <AttributeCard
+                            name="size / stroke_width"
+                            values="u32 (48) / f32 (4.0)"
+                            description="Diameter and ring thickness in px"
+                        />
+                        <AttributeCard
+                            name="show_value"
+                            values="bool"
+                            description="Percentage or seconds left in the center"
+                        />
+                        <AttributeCard
+                            name="on_expire"
+                            values="Callback<()> (optional)"
+                            description="Called once when a countdown reaches its deadline"
+                        />
+                        <AttributeCard
+                            name="label"
+                            values="String (optional)"
+                            description="Accessible name (defaults to Progress / Time remaining)"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            // Usage
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r##"use ui_components::{ProgressRing, RingThreshold};
+
+// Upload progress
+view! { <ProgressRing value=upload_progress show_value=true /> }
+
+// Turn timer
+view! {
+    <ProgressRing
+        deadline=turn_deadline
+        duration_ms=30_000
+        thresholds=vec![
+            RingThreshold::new(0.5, "#ff9800"),
+            RingThreshold::new(0.2, "#f44336"),
+        ]
+        show_value=true
+        on_expire=move |_| end_turn()
+    />
+}"##}</pre>
+            </div>
+        </div>
+    }
+}
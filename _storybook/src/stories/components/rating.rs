@@ -1,12 +1,22 @@
 //! Rating component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::{Rating, RatingSize};
+use ui_components::{format_rating, Rating, RatingIcons, RatingPrecision, RatingSize};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "rating",
+    label: "Rating",
+    category: "Data Display",
+    keywords: &["stars", "score", "review", "half", "fractional"],
+    render: || view! { <RatingStory /> }.into_any(),
+};
 
 #[component]
 pub fn RatingStory() -> impl IntoView {
-    let (interactive_value, set_interactive_value) = signal(3u32);
+    let (interactive_value, set_interactive_value) = signal(3.0);
+    let (selected_value, set_selected_value) = signal(2.5);
 
     view! {
         <div>
@@ -22,15 +32,15 @@ pub fn RatingStory() -> impl IntoView {
                     <div style="display: flex; flex-direction: column; gap: 1rem;">
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 100px; color: #888;">"1 of 5:"</span>
-                            <Rating value=Signal::derive(|| 1u32) max=5 />
+                            <Rating value=Signal::derive(|| 1.0) max=5 />
                         </div>
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 100px; color: #888;">"3 of 5:"</span>
-                            <Rating value=Signal::derive(|| 3u32) max=5 />
+                            <Rating value=Signal::derive(|| 3.0) max=5 />
                         </div>
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 100px; color: #888;">"5 of 5:"</span>
-                            <Rating value=Signal::derive(|| 5u32) max=5 />
+                            <Rating value=Signal::derive(|| 5.0) max=5 />
                         </div>
                     </div>
                 </div>
@@ -43,15 +53,15 @@ pub fn RatingStory() -> impl IntoView {
                     <div style="display: flex; flex-direction: column; gap: 1rem;">
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 80px; color: #888;">"Stars:"</span>
-                            <Rating value=Signal::derive(|| 3u32) max=5 icon="⭐" empty_icon="☆" />
+                            <Rating value=Signal::derive(|| 3.0) max=5 icon="⭐" empty_icon="☆" />
                         </div>
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 80px; color: #888;">"Hearts:"</span>
-                            <Rating value=Signal::derive(|| 4u32) max=5 icon="❤️" empty_icon="🤍" />
+                            <Rating value=Signal::derive(|| 4.0) max=5 icon="❤️" empty_icon="🤍" />
                         </div>
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 80px; color: #888;">"Circles:"</span>
-                            <Rating value=Signal::derive(|| 2u32) max=5 icon="●" empty_icon="○" color="#FFD700" />
+                            <Rating value=Signal::derive(|| 2.0) max=5 icon="●" empty_icon="○" color="#FFD700" />
                         </div>
                     </div>
                 </div>
@@ -64,15 +74,15 @@ pub fn RatingStory() -> impl IntoView {
                     <div style="display: flex; flex-direction: column; gap: 0.75rem;">
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 100px; color: #888;">"Easy:"</span>
-                            <Rating value=Signal::derive(|| 1u32) max=5 icon="💀" />
+                            <Rating value=Signal::derive(|| 1.0) max=5 icon="💀" />
                         </div>
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 100px; color: #888;">"Medium:"</span>
-                            <Rating value=Signal::derive(|| 3u32) max=5 icon="💀" />
+                            <Rating value=Signal::derive(|| 3.0) max=5 icon="💀" />
                         </div>
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 100px; color: #888;">"Hard:"</span>
-                            <Rating value=Signal::derive(|| 5u32) max=5 icon="💀" />
+                            <Rating value=Signal::derive(|| 5.0) max=5 icon="💀" />
                         </div>
                     </div>
                 </div>
@@ -85,15 +95,15 @@ pub fn RatingStory() -> impl IntoView {
                     <div style="display: flex; flex-direction: column; gap: 1rem;">
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 80px; color: #888;">"Small:"</span>
-                            <Rating value=Signal::derive(|| 3u32) max=5 icon="⭐" empty_icon="☆" size=RatingSize::Sm />
+                            <Rating value=Signal::derive(|| 3.0) max=5 icon="⭐" empty_icon="☆" size=RatingSize::Sm />
                         </div>
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 80px; color: #888;">"Medium:"</span>
-                            <Rating value=Signal::derive(|| 3u32) max=5 icon="⭐" empty_icon="☆" size=RatingSize::Md />
+                            <Rating value=Signal::derive(|| 3.0) max=5 icon="⭐" empty_icon="☆" size=RatingSize::Md />
                         </div>
                         <div style="display: flex; align-items: center; gap: 1rem;">
                             <span style="width: 80px; color: #888;">"Large:"</span>
-                            <Rating value=Signal::derive(|| 3u32) max=5 icon="⭐" empty_icon="☆" size=RatingSize::Lg />
+                            <Rating value=Signal::derive(|| 3.0) max=5 icon="⭐" empty_icon="☆" size=RatingSize::Lg />
                         </div>
                     </div>
                 </div>
@@ -104,9 +114,9 @@ pub fn RatingStory() -> impl IntoView {
                 <h3>"Custom Colors"</h3>
                 <div class="story-canvas">
                     <div style="display: flex; flex-direction: column; gap: 1rem;">
-                        <Rating value=Signal::derive(|| 4u32) max=5 icon="●" empty_icon="○" color="#FFD700" />
-                        <Rating value=Signal::derive(|| 3u32) max=5 icon="●" empty_icon="○" color="#28a745" />
-                        <Rating value=Signal::derive(|| 2u32) max=5 icon="●" empty_icon="○" color="#dc3545" />
+                        <Rating value=Signal::derive(|| 4.0) max=5 icon="●" empty_icon="○" color="#FFD700" />
+                        <Rating value=Signal::derive(|| 3.0) max=5 icon="●" empty_icon="○" color="#28a745" />
+                        <Rating value=Signal::derive(|| 2.0) max=5 icon="●" empty_icon="○" color="#dc3545" />
                     </div>
                 </div>
             </div>
@@ -121,7 +131,7 @@ pub fn RatingStory() -> impl IntoView {
                             {(1..=5).map(|n| view! {
                                 <button
                                     style="padding: 0.25rem 0.75rem; background: #2a2a4e; border: 1px solid #3a3a5e; border-radius: 4px; color: #e0e0e0; cursor: pointer;"
-                                    on:click=move |_| set_interactive_value.set(n)
+                                    on:click=move |_| set_interactive_value.set(n as f64)
                                 >
                                     {n}
                                 </button>
@@ -131,6 +141,50 @@ pub fn RatingStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Fractional display
+            <div class="story-section">
+                <h3>"Fractional Values"</h3>
+                <p style="color: #888; margin-bottom: 1rem;">"Aggregate scores are rounded to the chosen precision. Readonly ratings show the exact value as a tooltip."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; flex-direction: column; gap: 1rem;">
+                        <div style="display: flex; align-items: center; gap: 1rem;">
+                            <span style="width: 100px; color: #888;">"Full (4.3):"</span>
+                            <Rating value=4.3 icons=RatingIcons::stars() readonly=true />
+                        </div>
+                        <div style="display: flex; align-items: center; gap: 1rem;">
+                            <span style="width: 100px; color: #888;">"Half (4.3):"</span>
+                            <Rating value=4.3 icons=RatingIcons::stars() precision=RatingPrecision::Half readonly=true />
+                        </div>
+                        <div style="display: flex; align-items: center; gap: 1rem;">
+                            <span style="width: 100px; color: #888;">"Quarter (4.3):"</span>
+                            <Rating value=4.3 icons=RatingIcons::stars() precision=RatingPrecision::Quarter readonly=true />
+                        </div>
+                        <div style="display: flex; align-items: center; gap: 1rem;">
+                            <span style="width: 100px; color: #888;">"Skulls (2.5):"</span>
+                            <Rating value=2.5 icon="💀" precision=RatingPrecision::Half readonly=true />
+                        </div>
+                    </div>
+                </div>
+            </div>
+
+            // Selectable
+            <div class="story-section">
+                <h3>"Selectable"</h3>
+                <p style="color: #888; margin-bottom: 1rem;">"With on_change the rating previews on hover and emits the value under the pointer. Arrow keys step by the precision."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; align-items: center; gap: 1rem;">
+                        <Rating
+                            value=selected_value
+                            icons=RatingIcons::hearts()
+                            precision=RatingPrecision::Half
+                            size=RatingSize::Lg
+                            on_change=Callback::new(move |v| set_selected_value.set(v))
+                        />
+                        <span style="color: #888;">{move || format!("Selected: {}", format_rating(selected_value.get()))}</span>
+                    </div>
+                </div>
+            </div>
+
             // Props section
             <div class="story-section">
                 <h3>"Props"</h3>
@@ -138,8 +192,13 @@ pub fn RatingStory() -> impl IntoView {
                     <div class="story-grid">
                         <AttributeCard
                             name="value"
-                            values="Signal<u32>"
-                            description="Current rating value"
+                            values="Signal<f64>"
+                            description="Current rating value (fractional values supported)"
+                        />
+                        <AttributeCard
+                            name="precision"
+                            values="RatingPrecision (Full|Half|Quarter)"
+                            description="Display and selection step - default is Full"
                         />
                         <AttributeCard
                             name="max"
@@ -156,6 +215,11 @@ pub fn RatingStory() -> impl IntoView {
                             values="String (optional)"
                             description="Empty icon (if not set, only filled shown)"
                         />
+                        <AttributeCard
+                            name="icons"
+                            values="RatingIcons (optional)"
+                            description="Icon set preset (stars, hearts, circles or custom); overrides icon/empty_icon"
+                        />
                         <AttributeCard
                             name="size"
                             values="RatingSize (Sm|Md|Lg)"
@@ -166,6 +230,16 @@ pub fn RatingStory() -> impl IntoView {
                             values="String (optional)"
                             description="Icon color (CSS color value)"
                         />
+                        <AttributeCard
+                            name="on_change"
+                            values="Callback<f64> (optional)"
+                            description="Makes the rating selectable with hover preview"
+                        />
+                        <AttributeCard
+                            name="readonly"
+                            values="bool (default: false)"
+                            description="Disable selection and show the value as a tooltip"
+                        />
                     </div>
                 </div>
             </div>
@@ -173,12 +247,12 @@ pub fn RatingStory() -> impl IntoView {
             // Code example
             <div class="story-section">
                 <h3>"Usage"</h3>
-                <pre class="code-block">{r##"use ui_components::{Rating, RatingSize};
+                <pre class="code-block">{r##"use ui_components::{Rating, RatingIcons, RatingPrecision, RatingSize};
 
 // Star rating (3 out of 5)
 view! {
     <Rating
-        value=Signal::derive(|| 3u32)
+        value=Signal::derive(|| 3.0)
         max=5
         icon="⭐"
         empty_icon="☆"
@@ -194,6 +268,16 @@ view! {
     />
 }
 
+// Aggregate score in quarter stars
+view! {
+    <Rating
+        value=4.3
+        icons=RatingIcons::stars()
+        precision=RatingPrecision::Quarter
+        readonly=true
+    />
+}
+
 // Custom colored dots
 view! {
     <Rating
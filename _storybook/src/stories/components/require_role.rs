@@ -0,0 +1,133 @@
+//! RequireRole component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{provide_auth, RequireRole};
+use ui_core::AuthState;
+
+pub const STORY: StoryDef = StoryDef {
+    id: "require-role",
+    label: "Require Role",
+    category: "User",
+    keywords: &["permission", "auth", "admin", "gate", "access"],
+    render: || view! { <RequireRoleStory /> }.into_any(),
+};
+
+/// Auth states to switch between
+const STATES: &[(&str, &str)] = &[
+    ("admin", "Signed in as admin"),
+    ("anonymous", "Signed out"),
+    ("authenticating", "Signing in"),
+    ("expired", "Session expired"),
+];
+
+fn auth_state(key: &str) -> AuthState {
+    match key {
+        "admin" => AuthState::mock_authenticated("123456789", "987654321"),
+        "authenticating" => AuthState::Authenticating,
+        "expired" => AuthState::TokenExpired,
+        _ => AuthState::Anonymous,
+    }
+}
+
+#[component]
+pub fn RequireRoleStory() -> impl IntoView {
+    let (state, set_state) = signal("admin".to_string());
+    provide_auth(Signal::derive(move || auth_state(&state.get())));
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Require Role"</h2>
+                <p>"Renders its children only for users holding a role, with a fallback or an inline prompt for everyone else."</p>
+            </div>
+
+            <div class="story-section">
+                <h3>"Auth State"</h3>
+                <p class="story-description">"The dev user is a platform admin, so it holds \"admin\" but not \"moderator\"."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; gap: 0.5rem; flex-wrap: wrap;">
+                        {STATES.iter().map(|&(key, label)| {
+                            view! {
+                                <button
+                                    class=move || if state.get() == key { "btn btn--primary" } else { "btn btn--outline" }
+                                    on:click=move |_| set_state.set(key.to_string())
+                                >
+                                    {label}
+                                </button>
+                            }
+                        }).collect_view()}
+                    </div>
+                </div>
+            </div>
+
+            <div class="story-section">
+                <h3>"Inline Prompt"</h3>
+                <div class="story-canvas">
+                    <div style="display: grid; gap: 1rem;">
+                        <RequireRole role="admin">
+                            <p>"Admin panel: visible to admins only."</p>
+                        </RequireRole>
+                        <RequireRole role="moderator">
+                            <p>"Moderation queue: visible to moderators only."</p>
+                        </RequireRole>
+                    </div>
+                </div>
+            </div>
+
+            <div class="story-section">
+                <h3>"Custom Fallback"</h3>
+                <div class="story-canvas">
+                    <RequireRole
+                        role="moderator"
+                        fallback=|| view! { <p class="text-muted">"Read only: ask a moderator to make changes."</p> }
+                    >
+                        <button class="btn btn--primary">"Edit Rules"</button>
+                    </RequireRole>
+                </div>
+            </div>
+
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="role"
+                            values="String"
+                            description="Role the user must hold (platform admins implicitly hold \"admin\")"
+                        />
+                        <AttributeCard
+                            name="fallback"
+                            values="ViewFn (optional)"
+                            description="Rendered instead of children when access is denied"
+                        />
+                        <AttributeCard
+                            name="prompt"
+                            values="bool (default: true)"
+                            description="Show the sign-in / insufficient role prompt when there's no fallback"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r#"use ui_components::{provide_auth, RequireRole};
+
+// Once at the root, with the loader's auth state
+provide_auth(result.auth);
+
+view! {
+    <RequireRole role="admin">
+        <AdminPanel />
+    </RequireRole>
+
+    <RequireRole role="moderator" fallback=|| view! { <p>"Read only"</p> }>
+        <EditButton />
+    </RequireRole>
+}"#}</pre>
+            </div>
+        </div>
+    }
+}
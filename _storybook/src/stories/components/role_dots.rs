@@ -1,10 +1,19 @@
 //! Role Dots component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::{resolve_iiif_image, AttributeCard};
 use cardano_assets::AssetId;
 use leptos::prelude::*;
 use ui_components::{children_fn, AssetCard, CardSize, RoleDot, RoleDots};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "role-dots",
+    label: "Role Dots",
+    category: "User",
+    keywords: &["roles", "indicator", "crew"],
+    render: || view! { <RoleDotsStory /> }.into_any(),
+};
+
 // Black Flag Pirates policy ID
 const BFP_POLICY: &str = "b3dab69f7e6100849434fb1781e34bd12a916557f6231b8d2629b6f6";
 
@@ -1,9 +1,18 @@
 //! Select component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{Select, SelectOption};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "select",
+    label: "Select",
+    category: "Forms",
+    keywords: &["dropdown", "options", "picker"],
+    render: || view! { <SelectStory /> }.into_any(),
+};
+
 #[component]
 pub fn SelectStory() -> impl IntoView {
     let (selected_string, set_selected_string) = signal("".to_string());
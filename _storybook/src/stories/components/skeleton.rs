@@ -1,9 +1,18 @@
 //! Skeleton component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{Skeleton, SkeletonVariant};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "skeleton",
+    label: "Skeleton",
+    category: "Feedback",
+    keywords: &["placeholder", "shimmer", "loading"],
+    render: || view! { <SkeletonStory /> }.into_any(),
+};
+
 #[component]
 pub fn SkeletonStory() -> impl IntoView {
     view! {
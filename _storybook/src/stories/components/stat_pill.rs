@@ -1,9 +1,18 @@
 //! StatPill component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{StatPill, StatPillColor, StatPillSize};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "stat-pill",
+    label: "Stat Pill",
+    category: "Data Display",
+    keywords: &["metric", "value", "chip"],
+    render: || view! { <StatPillStory /> }.into_any(),
+};
+
 #[component]
 pub fn StatPillStory() -> impl IntoView {
     let (dynamic_value, set_dynamic_value) = signal(150u32);
@@ -0,0 +1,151 @@
+//! StickyActionBar component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{FormGroup, StickyActionBar, TextInput, Textarea};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "sticky-action-bar",
+    label: "Sticky Action Bar",
+    category: "Forms",
+    keywords: &["save", "discard", "unsaved", "dirty", "form"],
+    render: || view! { <StickyActionBarStory /> }.into_any(),
+};
+
+#[derive(Clone, PartialEq)]
+struct Settings {
+    name: String,
+    description: String,
+}
+
+#[component]
+pub fn StickyActionBarStory() -> impl IntoView {
+    let initial = Settings {
+        name: "Weekly Raffle".to_string(),
+        description: "One winner drawn every Friday.".to_string(),
+    };
+    let saved = RwSignal::new(initial.clone());
+    let draft = RwSignal::new(initial);
+    let (saving, set_saving) = signal(false);
+
+    let dirty = Signal::derive(move || draft.get() != saved.get());
+    let invalid = Signal::derive(move || draft.with(|d| d.name.trim().is_empty()));
+
+    // Pretend the save takes a moment
+    let on_save = move |_| {
+        set_saving.set(true);
+        set_timeout(
+            move || {
+                saved.set(draft.get_untracked());
+                set_saving.set(false);
+            },
+            std::time::Duration::from_millis(1200),
+        );
+    };
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Sticky Action Bar"</h2>
+                <p>"A save/discard bar pinned to the bottom of the viewport while a form has unsaved changes, guarding against leaving the page with them."</p>
+            </div>
+
+            <div class="story-section">
+                <h3>"Interactive Example"</h3>
+                <p class="story-description">"Edit a field to bring up the bar. While there are unsaved changes, reloading the page asks first, and so does following the link below."</p>
+                <div class="story-canvas">
+                    <div style="max-width: 350px; display: flex; flex-direction: column; gap: 1rem;">
+                        <FormGroup label="Name" required=true>
+                            <TextInput
+                                value=Signal::derive(move || draft.with(|d| d.name.clone()))
+                                on_change=Callback::new(move |v| draft.update(|d| d.name = v))
+                            />
+                        </FormGroup>
+                        <FormGroup label="Description">
+                            <Textarea
+                                value=Signal::derive(move || draft.with(|d| d.description.clone()))
+                                on_change=Callback::new(move |v| draft.update(|d| d.description = v))
+                                rows=3
+                            />
+                        </FormGroup>
+                        <a href="?story=form-group">"Go to the Form Group story"</a>
+                    </div>
+                </div>
+            </div>
+
+            <StickyActionBar
+                dirty=dirty
+                saving=saving
+                disabled=invalid
+                on_save=on_save
+                on_discard=move |_| draft.set(saved.get_untracked())
+            />
+
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="dirty"
+                            values="Signal<bool>"
+                            description="Whether there are unsaved changes; the bar is shown only while true"
+                        />
+                        <AttributeCard
+                            name="on_save"
+                            values="Callback<()>"
+                            description="Save the changes"
+                        />
+                        <AttributeCard
+                            name="on_discard"
+                            values="Callback<()> (optional)"
+                            description="Throw the changes away; no discard button without it"
+                        />
+                        <AttributeCard
+                            name="saving"
+                            values="Signal<bool>"
+                            description="Save in progress: shows a spinner and disables the buttons"
+                        />
+                        <AttributeCard
+                            name="disabled"
+                            values="Signal<bool>"
+                            description="Disable save, e.g. while the form is invalid"
+                        />
+                        <AttributeCard
+                            name="message / save_label / discard_label"
+                            values="String (optional)"
+                            description="Override the default text"
+                        />
+                        <AttributeCard
+                            name="guard"
+                            values="bool (default: true)"
+                            description="Ask before leaving the page with unsaved changes"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r#"use ui_components::{StickyActionBar, use_unsaved_changes_guard};
+
+let saved = RwSignal::new(load_drops());
+let draft = RwSignal::new(saved.get_untracked());
+let dirty = Signal::derive(move || draft.get() != saved.get());
+
+view! {
+    <DropEditor drops=draft on_change=move |d| draft.set(d) />
+    <StickyActionBar
+        dirty=dirty
+        saving=saving
+        on_save=move |_| save(draft.get())
+        on_discard=move |_| draft.set(saved.get())
+    />
+}
+
+// Or just the guard, for editors with their own save button
+use_unsaved_changes_guard(dirty);"#}</pre>
+            </div>
+        </div>
+    }
+}
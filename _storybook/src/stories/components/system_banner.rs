@@ -0,0 +1,156 @@
+//! SystemBanner component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{BannerVariant, SystemAnnouncement, SystemBanner};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "system-banner",
+    label: "System Banner",
+    category: "Feedback",
+    keywords: &[
+        "maintenance",
+        "announcement",
+        "downtime",
+        "countdown",
+        "banner",
+    ],
+    render: || view! { <SystemBannerStory /> }.into_any(),
+};
+
+fn now_ms() -> u64 {
+    js_sys::Date::now() as u64
+}
+
+#[component]
+pub fn SystemBannerStory() -> impl IntoView {
+    // Dismissals persist per id, so each "Show again" uses a fresh id
+    let round = RwSignal::new(0u32);
+    let dismissible = Signal::derive(move || {
+        Some(SystemAnnouncement::info(
+            format!("story-dismiss-{}", round.get()),
+            "Dismiss me - the id is remembered, so I stay hidden after a reload.",
+        ))
+    });
+
+    let started = now_ms();
+    let countdown = Signal::derive(move || {
+        Some(
+            SystemAnnouncement::maintenance(
+                "story-countdown",
+                "Scheduled maintenance - trading will be **paused** briefly.",
+            )
+            .starts_at(started + 90_000)
+            .until(started + 3_690_000)
+            .dismissible(false),
+        )
+    });
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"System Banner"</h2>
+                <p>"Full-width announcement banner for maintenance windows and news, driven by flow commands or a config endpoint."</p>
+            </div>
+
+            // Variants
+            <div class="story-section">
+                <h3>"Variants"</h3>
+                <div class="story-canvas">
+                    <div style="display: flex; flex-direction: column; gap: 1rem;">
+                        <SystemBanner announcement=Signal::derive(|| Some(
+                            SystemAnnouncement::info("story-info", "New season starts **Friday** - see the [roadmap](https://defrag.au).")
+                                .dismissible(false)
+                        )) />
+                        <SystemBanner announcement=Signal::derive(|| Some(
+                            SystemAnnouncement::new("story-warning", BannerVariant::Warning, "Blockfrost is degraded; balances may be *delayed*.")
+                                .dismissible(false)
+                        )) />
+                        <SystemBanner announcement=Signal::derive(|| Some(
+                            SystemAnnouncement::maintenance("story-maintenance", "Deploying `v2.4` - expect a brief reconnect.")
+                                .dismissible(false)
+                        )) />
+                    </div>
+                </div>
+            </div>
+
+            // Countdown
+            <div class="story-section">
+                <h3>"Countdown"</h3>
+                <div class="story-canvas">
+                    <SystemBanner announcement=countdown />
+                </div>
+            </div>
+
+            // Dismissible
+            <div class="story-section">
+                <h3>"Dismissible"</h3>
+                <div class="story-canvas">
+                    <div style="display: flex; flex-direction: column; gap: 1rem;">
+                        <SystemBanner announcement=dismissible />
+                        <button
+                            style="align-self: flex-start; padding: 0.5rem 1rem; background: #2a2a4e; border: 1px solid #3a3a5e; border-radius: 4px; color: #e0e0e0; cursor: pointer;"
+                            on:click=move |_| round.update(|r| *r += 1)
+                        >
+                            "Show Again"
+                        </button>
+                    </div>
+                </div>
+            </div>
+
+            // Props section
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="announcement"
+                            values="Signal<Option<SystemAnnouncement>>"
+                            description="Announcement to show - None hides the banner"
+                        />
+                        <AttributeCard
+                            name="on_dismiss"
+                            values="Callback<String> (optional)"
+                            description="Called with the announcement id when dismissed"
+                        />
+                        <AttributeCard
+                            name="variant"
+                            values="BannerVariant (Info|Warning|Maintenance)"
+                            description="SystemAnnouncement field - visual style"
+                        />
+                        <AttributeCard
+                            name="starts_at / until"
+                            values="Option<u64> (unix ms)"
+                            description="SystemAnnouncement fields - countdown target and expiry"
+                        />
+                        <AttributeCard
+                            name="message"
+                            values="String"
+                            description="Inline markdown: **bold**, *italic*, `code`, [link](https://...)"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            // Code example
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r##"use ui_components::{SystemAnnouncement, SystemBanner};
+use ui_flow::{run_default_command, ClientCommand};
+
+let announcement = RwSignal::new(None::<SystemAnnouncement>);
+
+let builder = FlowConnection::builder().on_command(move |command| match command {
+    ClientCommand::ShowMaintenanceBanner { message, until } => announcement.set(Some(
+        SystemAnnouncement::maintenance("maintenance", message).until(until),
+    )),
+    ClientCommand::HideMaintenanceBanner => announcement.set(None),
+    other => run_default_command(&other),
+});
+
+view! { <SystemBanner announcement=announcement /> }"##}</pre>
+            </div>
+        </div>
+    }
+}
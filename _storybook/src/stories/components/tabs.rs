@@ -1,8 +1,17 @@
 //! Tabs component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::{TabPanel, Tabs};
+use ui_components::{TabItem, TabPanel, Tabs, TabsOrientation};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "tabs",
+    label: "Tabs",
+    category: "Layout",
+    keywords: &["tab", "navigation", "panels", "vertical", "settings"],
+    render: || view! { <TabsStory /> }.into_any(),
+};
 
 #[component]
 pub fn TabsStory() -> impl IntoView {
@@ -71,6 +80,70 @@ pub fn TabsStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Rich tabs
+            <div class="story-section">
+                <h3>"Icons, Badges & Disabled Tabs"</h3>
+                <p style="color: #888; margin-bottom: 1rem;">"Use items with TabItem for icons, count badges and disabled tabs. Plain tabs and items can be mixed."</p>
+                <div class="story-canvas">
+                    {
+                        let (tab, set_tab) = signal("home".to_string());
+                        let unread = RwSignal::new(3u32);
+                        view! {
+                            <Tabs
+                                active=tab
+                                on_change=Callback::new(move |id| set_tab.set(id))
+                                items=vec![
+                                    TabItem::new("home", "Home").with_icon("🏠"),
+                                    TabItem::new("inbox", "Inbox").with_icon("✉").with_badge(unread),
+                                    TabItem::new("archive", "Archive").with_icon("🗄").disabled(true),
+                                ]
+                            >
+                                <TabPanel value="home">"Home content"</TabPanel>
+                                <TabPanel value="inbox">
+                                    <button class="btn btn--secondary btn--sm" on:click=move |_| unread.set(0)>"Mark all read"</button>
+                                    <button class="btn btn--secondary btn--sm" on:click=move |_| unread.update(|n| *n += 1)>"New message"</button>
+                                </TabPanel>
+                                <TabPanel value="archive">"Archive content"</TabPanel>
+                            </Tabs>
+                        }
+                    }
+                </div>
+            </div>
+
+            // Vertical orientation
+            <div class="story-section">
+                <h3>"Vertical Orientation"</h3>
+                <p style="color: #888; margin-bottom: 1rem;">"Vertical tabs suit settings panels and admin layouts."</p>
+                <div class="story-canvas">
+                    {
+                        let (tab, set_tab) = signal("profile".to_string());
+                        let billing_locked = RwSignal::new(true);
+                        view! {
+                            <Tabs
+                                active=tab
+                                on_change=Callback::new(move |id| set_tab.set(id))
+                                orientation=TabsOrientation::Vertical
+                                items=vec![
+                                    TabItem::new("profile", "Profile").with_icon("👤"),
+                                    TabItem::new("notifications", "Notifications").with_icon("🔔").with_badge(12u32),
+                                    TabItem::new("security", "Security").with_icon("🔒"),
+                                    TabItem::new("billing", "Billing").with_icon("💳").disabled(billing_locked),
+                                ]
+                            >
+                                <TabPanel value="profile">"Profile settings"</TabPanel>
+                                <TabPanel value="notifications">"Notification preferences"</TabPanel>
+                                <TabPanel value="security">
+                                    <button class="btn btn--secondary btn--sm" on:click=move |_| billing_locked.update(|l| *l = !*l)>
+                                        {move || if billing_locked.get() { "Unlock Billing" } else { "Lock Billing" }}
+                                    </button>
+                                </TabPanel>
+                                <TabPanel value="billing">"Billing details"</TabPanel>
+                            </Tabs>
+                        }
+                    }
+                </div>
+            </div>
+
             // Props section
             <div class="story-section">
                 <h3>"Props - Tabs"</h3>
@@ -91,6 +164,16 @@ pub fn TabsStory() -> impl IntoView {
                             values="Vec<(String, String)>"
                             description="Tab definitions as (value, label) tuples"
                         />
+                        <AttributeCard
+                            name="items"
+                            values="Vec<TabItem>"
+                            description="Rich tab definitions with icon, badge and disabled state"
+                        />
+                        <AttributeCard
+                            name="orientation"
+                            values="Horizontal (default) | Vertical"
+                            description="Tab list layout"
+                        />
                         <AttributeCard
                             name="children"
                             values="Children"
@@ -1,8 +1,17 @@
 //! TextInput component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
-use ui_components::{InputType, TextInput};
+use ui_components::{use_debounced_signal, InputType, Spinner, SpinnerSize, TextInput};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "text-input",
+    label: "Text Input",
+    category: "Forms",
+    keywords: &["field", "textbox", "input"],
+    render: || view! { <TextInputStory /> }.into_any(),
+};
 
 #[component]
 pub fn TextInputStory() -> impl IntoView {
@@ -11,6 +20,11 @@ pub fn TextInputStory() -> impl IntoView {
     let (password, set_password) = signal(String::new());
     let (search, set_search) = signal(String::new());
     let (disabled_value, _) = signal("Cannot edit this".to_string());
+    let (query, set_query) = signal(String::new());
+    let debounced_query = use_debounced_signal(query, 400);
+    let (amount, set_amount) = signal("25".to_string());
+    let (filter, set_filter) = signal("leviathan".to_string());
+    let (lookup, set_lookup) = signal("$handle".to_string());
 
     view! {
         <div>
@@ -82,6 +96,71 @@ pub fn TextInputStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Adornments
+            <div class="story-section">
+                <h3>"Adornments"</h3>
+                <p class="story-description">"prefix and suffix slots sit inside the field border - icons, units, clear buttons or a loading spinner."</p>
+                <div class="story-canvas">
+                    <div style="display: flex; flex-direction: column; gap: 1rem; max-width: 300px;">
+                        <TextInput
+                            value=amount
+                            on_change=Callback::new(move |v| set_amount.set(v))
+                            label="Amount"
+                            input_type=InputType::Number
+                            prefix=|| "₳"
+                            suffix=|| "ADA"
+                        />
+                        <TextInput
+                            value=filter
+                            on_change=Callback::new(move |v| set_filter.set(v))
+                            label="Filter"
+                            input_type=InputType::Search
+                            placeholder="Search assets..."
+                            prefix=|| "🔍"
+                            suffix=move || view! {
+                                <button
+                                    type="button"
+                                    aria-label="Clear"
+                                    on:click=move |_| set_filter.set(String::new())
+                                >
+                                    "✕"
+                                </button>
+                            }
+                        />
+                        <TextInput
+                            value=lookup
+                            on_change=Callback::new(move |v| set_lookup.set(v))
+                            label="Loading"
+                            placeholder="Searching..."
+                            suffix=|| view! { <Spinner size=SpinnerSize::Sm /> }
+                        />
+                    </div>
+                </div>
+            </div>
+
+            // Debounced
+            <div class="story-section">
+                <h3>"Debounced Value"</h3>
+                <p class="story-description">"use_debounced_signal follows the input but only updates 400ms after typing stops - use it to drive searches instead of firing on every keystroke."</p>
+                <div class="story-canvas">
+                    <div style="max-width: 300px;">
+                        <TextInput
+                            value=query
+                            on_change=Callback::new(move |v| set_query.set(v))
+                            label="Search"
+                            input_type=InputType::Search
+                            placeholder="Type quickly..."
+                        />
+                        <p style="margin-top: 0.5rem; color: #888; font-size: 0.875rem;">
+                            "Live: " {move || query.get()}
+                        </p>
+                        <p style="color: #888; font-size: 0.875rem;">
+                            "Debounced: " {move || debounced_query.get()}
+                        </p>
+                    </div>
+                </div>
+            </div>
+
             // Props section
             <div class="story-section">
                 <h3>"Props"</h3>
@@ -112,6 +191,16 @@ pub fn TextInputStory() -> impl IntoView {
                             values="Signal<bool> (optional)"
                             description="Whether input is disabled"
                         />
+                        <AttributeCard
+                            name="prefix"
+                            values="Children (optional)"
+                            description="Leading adornment inside the field (icon, currency)"
+                        />
+                        <AttributeCard
+                            name="suffix"
+                            values="Children (optional)"
+                            description="Trailing adornment inside the field (unit, clear button, spinner)"
+                        />
                         <AttributeCard
                             name="input_type"
                             values="InputType (Text|Email|Password|Number|Search|Tel|Url)"
@@ -1,9 +1,18 @@
 //! Textarea component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::Textarea;
 
+pub const STORY: StoryDef = StoryDef {
+    id: "textarea",
+    label: "Textarea",
+    category: "Forms",
+    keywords: &["multiline", "field", "input"],
+    render: || view! { <TextareaStory /> }.into_any(),
+};
+
 #[component]
 pub fn TextareaStory() -> impl IntoView {
     let (bio, set_bio) = signal(String::new());
@@ -0,0 +1,143 @@
+//! TruncatedText component story
+
+use crate::registry::StoryDef;
+use crate::stories::helpers::AttributeCard;
+use leptos::prelude::*;
+use ui_components::{TruncateMode, TruncatedText};
+
+pub const STORY: StoryDef = StoryDef {
+    id: "truncated-text",
+    label: "Truncated Text",
+    category: "Data Display",
+    keywords: &[
+        "ellipsis", "truncate", "address", "hash", "clamp", "tooltip",
+    ],
+    render: || view! { <TruncatedTextStory /> }.into_any(),
+};
+
+const ADDRESS: &str = "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x";
+const TX_HASH: &str = "8a1b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e7f809";
+const DESCRIPTION: &str = "A weathered pirate captain who has sailed every sea charted and a few that were not. Known for a crooked grin, a compass that points to whatever its owner wants most, and an uncanny talent for escaping tight spots at the very last moment.";
+
+#[component]
+pub fn TruncatedTextStory() -> impl IntoView {
+    let (width, set_width) = signal(240u32);
+
+    view! {
+        <div>
+            <div class="story-header">
+                <h2>"Truncated Text"</h2>
+                <p>"Shortens long values with an end or middle ellipsis, or clamps them to a few lines. Hover a shortened value to see it in full."</p>
+            </div>
+
+            // Middle ellipsis
+            <div class="story-section">
+                <h3>"Middle Ellipsis"</h3>
+                <p class="story-description">"Addresses and hashes keep both ends, which is what people compare."</p>
+                <div class="story-canvas">
+                    <div style="display: grid; grid-template-columns: auto 1fr; gap: 0.5rem 1rem; align-items: center;">
+                        <span>"Address"</span>
+                        <TruncatedText value=ADDRESS mode=TruncateMode::Middle />
+                        <span>"Transaction"</span>
+                        <TruncatedText value=TX_HASH mode=TruncateMode::Middle head=12 tail=8 />
+                        <span>"Short value"</span>
+                        <TruncatedText value="addr1qx2" mode=TruncateMode::Middle />
+                    </div>
+                </div>
+            </div>
+
+            // End ellipsis
+            <div class="story-section">
+                <h3>"End Ellipsis"</h3>
+                <div class="story-canvas">
+                    <label style="display: flex; gap: 0.5rem; align-items: center; margin-bottom: 1rem;">
+                        "Container width"
+                        <input
+                            type="range"
+                            min="80"
+                            max="600"
+                            prop:value=move || width.get().to_string()
+                            on:input=move |ev| {
+                                if let Ok(w) = event_target_value(&ev).parse() {
+                                    set_width.set(w);
+                                }
+                            }
+                        />
+                        {move || format!("{}px", width.get())}
+                    </label>
+                    <div style=move || format!("width: {}px; border: 1px dashed #3a3a5e; padding: 0.5rem;", width.get())>
+                        <TruncatedText value=DESCRIPTION />
+                    </div>
+                    <p style="margin-top: 1rem;">
+                        <TruncatedText value="Captain Jack Sparrow of the Black Pearl" max_chars=20 />
+                    </p>
+                </div>
+            </div>
+
+            // Line clamp
+            <div class="story-section">
+                <h3>"Line Clamp"</h3>
+                <div class="story-canvas">
+                    <div style="max-width: 360px;">
+                        <TruncatedText value=DESCRIPTION lines=2 />
+                    </div>
+                </div>
+            </div>
+
+            // Props section
+            <div class="story-section">
+                <h3>"Props"</h3>
+                <div class="story-canvas">
+                    <div class="story-grid">
+                        <AttributeCard
+                            name="value"
+                            values="Signal<String>"
+                            description="Full text"
+                        />
+                        <AttributeCard
+                            name="mode"
+                            values="TruncateMode (End|Middle)"
+                            description="Where the ellipsis goes (default: End)"
+                        />
+                        <AttributeCard
+                            name="max_chars"
+                            values="usize (optional)"
+                            description="Character limit for End; without it the container width decides"
+                        />
+                        <AttributeCard
+                            name="head / tail"
+                            values="usize (default: 8 / 6)"
+                            description="Characters kept either side in Middle mode"
+                        />
+                        <AttributeCard
+                            name="lines"
+                            values="u32 (optional)"
+                            description="Clamp End text to this many lines"
+                        />
+                        <AttributeCard
+                            name="tooltip"
+                            values="bool (default: true)"
+                            description="Show the full value on hover when truncated"
+                        />
+                    </div>
+                </div>
+            </div>
+
+            // Code example
+            <div class="story-section">
+                <h3>"Usage"</h3>
+                <pre class="code-block">{r#"use ui_components::{shorten_middle, TruncateMode, TruncatedText};
+
+view! {
+    <TruncatedText value=address mode=TruncateMode::Middle />
+    <TruncatedText value=tx_hash mode=TruncateMode::Middle head=12 tail=8 />
+    <TruncatedText value=description lines=3 />
+    <TruncatedText value=name max_chars=24 />
+}
+
+// Plain strings, e.g. for logs
+let short = shorten_middle(&address, 10, 10);"#}</pre>
+            </div>
+        </div>
+    }
+}
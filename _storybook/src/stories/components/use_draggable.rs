@@ -1,8 +1,19 @@
 //! use_draggable hook and DraggableStack component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
+use crate::toolbar::SETTINGS_NAMESPACE;
 use leptos::prelude::*;
-use ui_components::{use_draggable, DraggableStack, Reorder, StackDirection};
+use ui_components::{use_draggable, use_persistent_order, DraggableStack, Reorder, StackDirection};
+use ui_core::settings::Settings;
+
+pub const STORY: StoryDef = StoryDef {
+    id: "use-draggable",
+    label: "use_draggable",
+    category: "Hooks",
+    keywords: &["drag", "reorder", "sortable", "persist"],
+    render: || view! { <UseDraggableStory /> }.into_any(),
+};
 
 #[component]
 pub fn UseDraggableStory() -> impl IntoView {
@@ -234,6 +245,39 @@ pub fn UseDraggableStory() -> impl IntoView {
                 </div>
             </div>
 
+            // Persisted order
+            <div class="story-section">
+                <h3>"Persisted Order"</h3>
+                <p style="color: #888; margin-bottom: 1rem; font-size: 0.875rem;">
+                    "use_persistent_order saves the order in Settings and reapplies it to the incoming list. Reorder, then reload the page."
+                </p>
+                <div class="story-canvas">
+                    {
+                        let cards = Signal::derive(|| vec!["Balance", "Activity", "Listings", "Offers"]);
+                        let settings = Settings::new(SETTINGS_NAMESPACE);
+                        let order = use_persistent_order(&settings, "story_card_order", cards, |card: &&str| card.to_string());
+
+                        view! {
+                            <DraggableStack
+                                items=order.signal()
+                                on_reorder=move |reorder: Reorder| order.reorder(reorder)
+                                key_fn=|card| *card
+                                direction=StackDirection::Horizontal
+                                gap="0.5rem"
+                                render_item=move |card, _idx, _drag_state| view! {
+                                    <div style="padding: 1rem 1.25rem; background: #252538; border-radius: 8px; color: #fff;">
+                                        {card}
+                                    </div>
+                                }
+                            />
+                            <button class="btn btn-secondary" style="margin-top: 1rem;" on:click=move |_| order.reset()>
+                                "Reset Order"
+                            </button>
+                        }
+                    }
+                </div>
+            </div>
+
             // API section
             <div class="story-section">
                 <h3>"use_draggable Hook Return Value"</h3>
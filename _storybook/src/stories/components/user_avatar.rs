@@ -1,9 +1,18 @@
 //! User Avatar component story
 
+use crate::registry::StoryDef;
 use crate::stories::helpers::AttributeCard;
 use leptos::prelude::*;
 use ui_components::{AvatarSize, UserAvatar};
 
+pub const STORY: StoryDef = StoryDef {
+    id: "user-avatar",
+    label: "User Avatar",
+    category: "User",
+    keywords: &["profile", "picture", "pfp"],
+    render: || view! { <UserAvatarStory /> }.into_any(),
+};
+
 // Sample Discord avatar URLs
 const AVATAR_1: &str = "https://cdn.discordapp.com/guilds/1283465958945456149/users/179744071361757184/avatars/7e67374c51a831be5f10516a3df195f8.png";
 const AVATAR_2: &str =
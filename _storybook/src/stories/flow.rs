@@ -1,8 +1,33 @@
 //! UI Flow stories - overview, FlowState trait, operations
 
 use super::helpers::{ConfigOptionCard, FlowConceptCard, LoaderStepCard};
+use crate::registry::StoryDef;
 use leptos::prelude::*;
 
+pub const STORIES: &[StoryDef] = &[
+    StoryDef {
+        id: "flow-overview",
+        label: "Overview",
+        category: "UI Flow",
+        keywords: &["realtime", "websocket", "sync"],
+        render: || view! { <FlowOverviewStory /> }.into_any(),
+    },
+    StoryDef {
+        id: "flow-state",
+        label: "FlowState Trait",
+        category: "UI Flow",
+        keywords: &["state", "reducer", "trait"],
+        render: || view! { <FlowStateStory /> }.into_any(),
+    },
+    StoryDef {
+        id: "flow-operations",
+        label: "Operations",
+        category: "UI Flow",
+        keywords: &["ops", "actions", "optimistic"],
+        render: || view! { <FlowOperationsStory /> }.into_any(),
+    },
+];
+
 // ============================================================================
 // Flow Overview Story
 // ============================================================================
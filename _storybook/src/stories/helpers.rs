@@ -2,13 +2,12 @@
 
 use cardano_assets::AssetId;
 use leptos::prelude::*;
+use ui_core::urls::endpoints;
 
 // ============================================================================
 // IIIF Image URL Helper
 // ============================================================================
 
-const IIIF_BASE_URL: &str = "https://iiif.hodlcroft.com/iiif/3";
-
 /// Generate an IIIF image URL for an asset
 ///
 /// # Arguments
@@ -21,10 +20,7 @@ const IIIF_BASE_URL: &str = "https://iiif.hodlcroft.com/iiif/3";
 /// // Returns: https://iiif.hodlcroft.com/iiif/3/{policy_id}:{asset_name_hex}/full/256,/0/default.jpg
 /// ```
 pub fn resolve_iiif_image(asset_id: &AssetId, size: u16) -> String {
-    format!(
-        "{IIIF_BASE_URL}/{}:{}/full/{size},/0/default.jpg",
-        asset_id.policy_id, asset_id.asset_name_hex
-    )
+    endpoints().iiif_parts(&asset_id.policy_id, &asset_id.asset_name_hex, size)
 }
 
 /// Render an attribute documentation card
@@ -1,8 +1,26 @@
 //! UI Loader stories - loading states and configuration
 
 use super::helpers::{ConfigOptionCard, LoaderErrorCard, LoaderStepCard};
+use crate::registry::StoryDef;
 use leptos::prelude::*;
 
+pub const STORIES: &[StoryDef] = &[
+    StoryDef {
+        id: "loading-states",
+        label: "Loading States",
+        category: "UI Loader",
+        keywords: &["spinner", "error", "auth"],
+        render: || view! { <LoadingStatesStory /> }.into_any(),
+    },
+    StoryDef {
+        id: "loader-config",
+        label: "Loader Config",
+        category: "UI Loader",
+        keywords: &["config", "widget", "bootstrap"],
+        render: || view! { <LoaderConfigStory /> }.into_any(),
+    },
+];
+
 // ============================================================================
 // Loading States Story
 // ============================================================================
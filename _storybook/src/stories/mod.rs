@@ -8,9 +8,17 @@ mod toast;
 mod wallet;
 mod welcome;
 
-pub use components::*;
-pub use flow::*;
-pub use loader::*;
-pub use toast::*;
-pub use wallet::*;
-pub use welcome::*;
+use crate::registry::StoryDef;
+
+/// Every story in sidebar order
+pub fn all() -> Vec<StoryDef> {
+    [
+        &[welcome::STORY][..],
+        components::STORIES,
+        wallet::STORIES,
+        flow::STORIES,
+        loader::STORIES,
+        toast::STORIES,
+    ]
+    .concat()
+}
@@ -1,8 +1,26 @@
 //! UI Toast stories - toast types and usage
 
 use super::helpers::{ToastFnCard, ToastKindCard, TraitMethodCard};
+use crate::registry::StoryDef;
 use leptos::prelude::*;
 
+pub const STORIES: &[StoryDef] = &[
+    StoryDef {
+        id: "toast-types",
+        label: "Toast Types",
+        category: "UI Toast",
+        keywords: &["notification", "success", "error"],
+        render: || view! { <ToastTypesStory /> }.into_any(),
+    },
+    StoryDef {
+        id: "toast-usage",
+        label: "Toast Usage",
+        category: "UI Toast",
+        keywords: &["notification", "context", "snackbar"],
+        render: || view! { <ToastUsageStory /> }.into_any(),
+    },
+];
+
 // ============================================================================
 // Toast Types Story
 // ============================================================================
@@ -1,16 +1,73 @@
 //! Wallet stories - wallet providers, detection, connection flow, balance, and NFT display
 
+use crate::registry::StoryDef;
 use cardano_assets::AssetId;
 use leptos::prelude::*;
-use ui_components::{AssetModal, WalletNftGallery};
+use std::collections::HashSet;
+use ui_components::{
+    shorten_end, shorten_middle, AssetModal, ExplorerKind, ExplorerLink, ExplorerNetwork,
+    WalletNftGallery,
+};
 use wallet_core::{
     detect_wallets, detect_wallets_with_info, ConnectionState, Network, WalletApi, WalletInfo,
     WalletProvider,
 };
-use wallet_leptos::{use_wallet, WalletProvider as WalletProviderComponent};
+use wallet_leptos::{use_wallet, RequireWallet, WalletProvider as WalletProviderComponent};
 use wallet_pallas::{decode_balance, PolicyGroup, WalletBalance};
 use wasm_bindgen_futures::spawn_local;
 
+pub const STORIES: &[StoryDef] = &[
+    StoryDef {
+        id: "wallet-providers",
+        label: "Wallet Providers",
+        category: "Wallet Core",
+        keywords: &["cip-30", "eternl", "nami", "lace"],
+        render: || view! { <WalletProvidersStory /> }.into_any(),
+    },
+    StoryDef {
+        id: "wallet-connection-states",
+        label: "Connection States",
+        category: "Wallet Core",
+        keywords: &["connected", "disconnected", "status"],
+        render: || view! { <ConnectionStatesStory /> }.into_any(),
+    },
+    StoryDef {
+        id: "wallet-detection",
+        label: "Live Detection",
+        category: "Wallet Core",
+        keywords: &["detect", "installed", "extension"],
+        render: || view! { <WalletDetectionStory /> }.into_any(),
+    },
+    StoryDef {
+        id: "wallet-connection",
+        label: "Connection Flow",
+        category: "Wallet Core",
+        keywords: &["connect", "enable", "cip-30"],
+        render: || view! { <WalletConnectionStory /> }.into_any(),
+    },
+    StoryDef {
+        id: "wallet-balance",
+        label: "Balance & Assets",
+        category: "Wallet Core",
+        keywords: &["ada", "lovelace", "tokens"],
+        render: || view! { <WalletBalanceStory /> }.into_any(),
+    },
+    StoryDef {
+        id: "wallet-nfts",
+        label: "NFT Gallery",
+        category: "Wallet Core",
+        keywords: &["nft", "gallery", "policy"],
+        render: || view! { <WalletNftsStory /> }.into_any(),
+    },
+    StoryDef {
+        id: "wallet-leptos",
+        label: "Leptos Context",
+        category: "Wallet Core",
+        keywords: &["context", "signal", "provider"],
+        render: || view! { <WalletLeptosStory /> }.into_any(),
+    },
+];
+
 // ============================================================================
 // Wallet Providers Story (Static Reference)
 // ============================================================================
@@ -148,6 +205,15 @@ match state {
     }
 }
 
+/// Map the wallet's network onto explorer URLs
+fn explorer_network(network: Network) -> ExplorerNetwork {
+    match network {
+        Network::Mainnet => ExplorerNetwork::Mainnet,
+        Network::Preprod => ExplorerNetwork::Preprod,
+        Network::Preview => ExplorerNetwork::Preview,
+    }
+}
+
 #[component]
 fn ConnectionCard(state: ConnectionState) -> impl IntoView {
     let (status_class, status_text) = match &state {
@@ -178,7 +244,13 @@ fn ConnectionCard(state: ConnectionState) -> impl IntoView {
                                 </div>
                                 <div class="wallet-card__row">
                                     <span class="wallet-card__label">"Address"</span>
-                                    <span class="wallet-card__value">{address}</span>
+                                    <span class="wallet-card__value">
+                                        <ExplorerLink
+                                            kind=ExplorerKind::Address
+                                            value=address
+                                            network=explorer_network(network)
+                                        />
+                                    </span>
                                 </div>
                                 <div class="wallet-card__row">
                                     <span class="wallet-card__label">"Network"</span>
@@ -393,16 +465,10 @@ pub fn WalletConnectionStory() -> impl IntoView {
                             // Get change address
                             match api.change_address().await {
                                 Ok(address) => {
-                                    let short_addr = if address.len() > 20 {
-                                        format!(
-                                            "{}...{}",
-                                            &address[..10],
-                                            &address[address.len() - 10..]
-                                        )
-                                    } else {
-                                        address.clone()
-                                    };
-                                    add_log(format!("Address: {short_addr}"));
+                                    add_log(format!(
+                                        "Address: {}",
+                                        shorten_middle(&address, 10, 10)
+                                    ));
 
                                     set_connection_state.set(ConnectionState::Connected {
                                         provider,
@@ -752,20 +818,20 @@ fn BalanceDisplay(balance: WalletBalance) -> impl IntoView {
                     <div class="balance-display__tokens-list">
                         {tokens.into_iter().take(20).map(|token| {
                             let display_name = token.asset_name.clone()
-                                .unwrap_or_else(|| {
-                                    if token.asset_name_hex.len() > 16 {
-                                        format!("{}...", &token.asset_name_hex[..16])
-                                    } else {
-                                        token.asset_name_hex.clone()
-                                    }
-                                });
-                            let short_policy = format!("{}...", &token.policy_id[..8]);
+                                .unwrap_or_else(|| shorten_end(&token.asset_name_hex, 19));
 
                             view! {
                                 <div class="token-row">
                                     <div class="token-row__info">
                                         <span class="token-row__name">{display_name}</span>
-                                        <span class="token-row__policy">{short_policy}</span>
+                                        <span class="token-row__policy">
+                                            <ExplorerLink
+                                                kind=ExplorerKind::Policy
+                                                value=token.policy_id.clone()
+                                                head=8
+                                                tail=4
+                                            />
+                                        </span>
                                     </div>
                                     <span class="token-row__quantity">{format!("{}", token.quantity)}</span>
                                 </div>
@@ -793,6 +859,9 @@ pub fn WalletNftsStory() -> impl IntoView {
     // Modal state for asset preview
     let (modal_asset, set_modal_asset) = signal(Option::<AssetId>::None);
 
+    // Multi-select state, shared with the gallery
+    let selection = RwSignal::new(HashSet::<String>::new());
+
     // Detect wallets on mount
     Effect::new(move |_| {
         let wallets = detect_wallets();
@@ -837,7 +906,7 @@ pub fn WalletNftsStory() -> impl IntoView {
         <div>
             <div class="story-header">
                 <h2>"Wallet NFTs"</h2>
-                <p>"Display wallet NFTs grouped by policy using the WalletNftGallery component."</p>
+                <p>"Display wallet NFTs grouped by policy using the WalletNftGallery component, with search, collection filter, sort and multi-select."</p>
             </div>
 
             <div class="story-section">
@@ -894,7 +963,12 @@ pub fn WalletNftsStory() -> impl IntoView {
                                     set_modal_asset.set(Some(asset_id));
                                 }
                             })
+                            selectable=true
+                            selection=selection
                         />
+                        <p style="color: #888; margin-top: 0.5rem;">
+                            "Selection signal: " {move || selection.with(HashSet::len)} " asset(s)"
+                        </p>
                     </div>
                 </div>
             </div>
@@ -922,7 +996,10 @@ let groups: Vec<PolicyGroup> = balance.nft_policy_groups();
 // State for modal
 let (selected, set_selected) = signal(Option::<AssetId>::None);
 
-// Gallery with click handler
+// Multi-select: selected asset ids
+let picked = RwSignal::new(HashSet::<String>::new());
+
+// Gallery with click handler, search/filter/sort toolbar and multi-select
 <WalletNftGallery
     groups=Signal::derive(move || groups.clone())
     loading=Signal::derive(move || is_loading.get())
@@ -931,6 +1008,11 @@ let (selected, set_selected) = signal(Option::<AssetId>::None);
             set_selected.set(Some(id));
         }
     })
+    selectable=true
+    selection=picked
+    // Optional: folder titles and "Recently acquired" sorting
+    collection_names=names
+    acquired_at=acquired_times
 />
 
 // Render modal when asset is selected
@@ -967,6 +1049,30 @@ pub fn WalletLeptosStory() -> impl IntoView {
                 </div>
             </div>
 
+            <div class="story-section">
+                <h3>"Wallet-Gated Content"</h3>
+                <p class="story-description">"RequireWallet renders its children only while connected, otherwise a connect prompt for the detected wallets. Try the mock wallet from the toolbar."</p>
+                <div class="story-canvas">
+                    <WalletProviderComponent>
+                        <RequireWallet>
+                            <RequireWalletDemo />
+                        </RequireWallet>
+                    </WalletProviderComponent>
+                </div>
+                <pre class="code-block">{r#"use wallet_leptos::RequireWallet;
+
+view! {
+    <RequireWallet>
+        <MyGallery />
+    </RequireWallet>
+
+    // Custom fallback instead of the connect prompt
+    <RequireWallet fallback=|| view! { <p>"Connect a wallet to vote"</p> }>
+        <VoteForm />
+    </RequireWallet>
+}"#}</pre>
+            </div>
+
             <div class="story-section">
                 <h3>"Usage"</h3>
                 <pre class="code-block">{r#"use wallet_leptos::{WalletProvider, use_wallet, WalletProviderEnum};
@@ -1018,6 +1124,26 @@ fn MyApp() -> impl IntoView {
     }
 }
 
+/// Content behind `RequireWallet`
+#[component]
+fn RequireWalletDemo() -> impl IntoView {
+    let wallet = use_wallet();
+    let address = move || {
+        wallet
+            .address
+            .get()
+            .map(|a| shorten_middle(&a, 12, 8))
+            .unwrap_or_default()
+    };
+
+    view! {
+        <div class="wallet-leptos-demo__section">
+            <h4>"Connected"</h4>
+            <p>"Only connected wallets see this. Address: " <code>{address}</code></p>
+        </div>
+    }
+}
+
 /// Demo component showing wallet-leptos in action
 #[component]
 fn WalletLeptosDemo() -> impl IntoView {
@@ -1085,11 +1211,7 @@ fn WalletLeptosDemo() -> impl IntoView {
                 let wallet_inner = wallet_for_info.clone();
                 wallet_for_info.is_connected().then(move || {
                     let address = wallet_inner.address.get().unwrap_or_default();
-                    let short_addr = if address.len() > 20 {
-                        format!("{}...{}", &address[..10], &address[address.len()-10..])
-                    } else {
-                        address
-                    };
+                    let network = wallet_inner.network.get().map(explorer_network).unwrap_or_default();
 
                     let wallet_fetch = wallet_inner.clone();
                     let wallet_disconnect = wallet_inner.clone();
@@ -1100,20 +1222,13 @@ fn WalletLeptosDemo() -> impl IntoView {
                             <div class="wallet-leptos-demo__info">
                                 <div class="wallet-leptos-demo__row">
                                     <span class="label">"Address:"</span>
-                                    <code>{short_addr}</code>
+                                    <ExplorerLink kind=ExplorerKind::Address value=address network=network />
                                 </div>
-                                {wallet_inner.stake_address.get().map(|sa| {
-                                    let short_stake = if sa.len() > 24 {
-                                        format!("{}...{}", &sa[..12], &sa[sa.len()-8..])
-                                    } else {
-                                        sa.clone()
-                                    };
-                                    view! {
-                                        <div class="wallet-leptos-demo__row">
-                                            <span class="label">"Stake:"</span>
-                                            <code>{short_stake}</code>
-                                        </div>
-                                    }
+                                {wallet_inner.stake_address.get().map(|sa| view! {
+                                    <div class="wallet-leptos-demo__row">
+                                        <span class="label">"Stake:"</span>
+                                        <ExplorerLink kind=ExplorerKind::Address value=sa network=network />
+                                    </div>
                                 })}
                                 {wallet_inner.network.get().map(|n| view! {
                                     <div class="wallet-leptos-demo__row">
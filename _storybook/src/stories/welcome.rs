@@ -1,7 +1,16 @@
 //! Welcome story - landing page for the storybook
 
+use crate::registry::StoryDef;
 use leptos::prelude::*;
 
+pub const STORY: StoryDef = StoryDef {
+    id: "welcome",
+    label: "Welcome",
+    category: "Getting Started",
+    keywords: &["intro", "overview", "start"],
+    render: || view! { <WelcomeStory /> }.into_any(),
+};
+
 #[component]
 pub fn WelcomeStory() -> impl IntoView {
     view! {
@@ -0,0 +1,177 @@
+//! Story viewport and theme toolbar
+//!
+//! Wraps the story canvas in a frame that can be constrained to common device
+//! widths, switched between dark and light canvas tokens, and forced into
+//! reduced motion, and swaps in a mock wallet (see [`crate::mock_wallet`]).
+//! Choices persist across reloads via [`ui_core::Settings`].
+
+use crate::mock_wallet::{MockFixture, MOCK_WALLET_KEY};
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use ui_components::use_setting;
+use ui_core::settings::{keys, Settings};
+
+/// Settings namespace for storybook preferences
+pub const SETTINGS_NAMESPACE: &str = "storybook";
+
+/// Settings key for the selected viewport
+const VIEWPORT_KEY: &str = "viewport";
+
+/// Width the story frame is constrained to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Viewport {
+    Mobile,
+    Tablet,
+    #[default]
+    Desktop,
+}
+
+impl Viewport {
+    pub fn all() -> &'static [Viewport] {
+        &[Viewport::Mobile, Viewport::Tablet, Viewport::Desktop]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Viewport::Mobile => "Mobile",
+            Viewport::Tablet => "Tablet",
+            Viewport::Desktop => "Desktop",
+        }
+    }
+
+    /// Frame width in CSS pixels, `None` for full width
+    pub fn width(&self) -> Option<u32> {
+        match self {
+            Viewport::Mobile => Some(375),
+            Viewport::Tablet => Some(768),
+            Viewport::Desktop => None,
+        }
+    }
+}
+
+/// Canvas colour scheme
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl Theme {
+    pub fn all() -> &'static [Theme] {
+        &[Theme::Dark, Theme::Light]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+        }
+    }
+
+    /// Value for the frame's `data-theme` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+        }
+    }
+}
+
+/// Toolbar plus the framed story canvas
+#[component]
+pub fn StoryFrame(children: Children) -> impl IntoView {
+    let settings = Settings::new(SETTINGS_NAMESPACE);
+    let viewport = use_setting(&settings, VIEWPORT_KEY, Viewport::default());
+    let theme = use_setting(&settings, keys::THEME, Theme::default());
+    let reduced_motion = use_setting(&settings, keys::REDUCED_MOTION, false);
+    let mock_wallet = use_setting(&settings, MOCK_WALLET_KEY, MockFixture::default());
+
+    // Wallet contexts connect on mount, so start over with the new wallet
+    let on_mock_change = move |ev: leptos::ev::Event| {
+        mock_wallet.set(MockFixture::parse(&event_target_value(&ev)));
+        let _ = window().location().reload();
+    };
+
+    let frame_style = move || {
+        viewport
+            .get()
+            .width()
+            .map(|w| format!("max-width: {w}px"))
+            .unwrap_or_default()
+    };
+
+    view! {
+        <div class="story-frame-toolbar">
+            <div class="story-frame-toolbar__group" role="group" aria-label="Viewport">
+                {Viewport::all()
+                    .iter()
+                    .map(|vp| {
+                        let vp = *vp;
+                        view! {
+                            <button
+                                class="story-frame-toolbar__btn"
+                                class:active=move || viewport.get() == vp
+                                title=vp.width().map(|w| format!("{w}px")).unwrap_or_else(|| "Full width".into())
+                                on:click=move |_| viewport.set(vp)
+                            >
+                                {vp.label()}
+                            </button>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+            <div class="story-frame-toolbar__group" role="group" aria-label="Theme">
+                {Theme::all()
+                    .iter()
+                    .map(|t| {
+                        let t = *t;
+                        view! {
+                            <button
+                                class="story-frame-toolbar__btn"
+                                class:active=move || theme.get() == t
+                                on:click=move |_| theme.set(t)
+                            >
+                                {t.label()}
+                            </button>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+            <label class="story-frame-toolbar__toggle">
+                <input
+                    type="checkbox"
+                    prop:checked=move || reduced_motion.get()
+                    on:change=move |ev| reduced_motion.set(event_target_checked(&ev))
+                />
+                "Reduced motion"
+            </label>
+            <label class="story-frame-toolbar__toggle" title="Inject a mock CIP-30 wallet into window.cardano">
+                "Mock wallet"
+                <select class="story-frame-toolbar__select" on:change=on_mock_change>
+                    {MockFixture::all()
+                        .iter()
+                        .map(|f| {
+                            let f = *f;
+                            view! {
+                                <option value=f.as_str() selected=move || mock_wallet.get() == f>
+                                    {f.label()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </label>
+        </div>
+        <div
+            class="story-frame"
+            class:story-frame--constrained=move || viewport.get().width().is_some()
+            class:story-frame--reduced-motion=move || reduced_motion.get()
+            data-theme=move || theme.get().as_str()
+            style=frame_style
+        >
+            {children()}
+        </div>
+    }
+}
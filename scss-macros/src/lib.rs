@@ -20,6 +20,23 @@
 //! - Errors surface as compile errors with file/line info
 //! - Compressed output for smaller bundle sizes
 //!
+//! ## `scss_dir!` macro
+//!
+//! Compiles every SCSS entry point in a directory into one bundle, so adding
+//! a stylesheet doesn't mean editing an index file.
+//!
+//! ```ignore
+//! use scss_macros::scss_dir;
+//!
+//! const STYLES: &str = scss_dir!("src/styles");
+//! ```
+//!
+//! - Entry points are the directory's `.scss` files, in file name order
+//! - A `mod.scss` index goes first, so it fixes the cascade order of the
+//!   files it `@use`s
+//! - Partials (`_name.scss`) are only compiled where an entry point `@use`s them
+//! - Editing any `.scss` file in the directory triggers a rebuild
+//!
 //! ## `scss_inline!` macro
 //!
 //! Compiles inline SCSS strings at compile time. Useful for small component styles.
@@ -40,7 +57,7 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use syn::{parse_macro_input, LitStr};
 
 /// Compile an SCSS file to CSS at compile time.
@@ -113,6 +130,123 @@ pub fn scss(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
+/// Compile all SCSS entry points in a directory to one CSS bundle at compile time.
+///
+/// The path is relative to the crate's `Cargo.toml` directory. Each `.scss`
+/// file not starting with `_` is `@use`d in file name order, so every
+/// module's CSS appears once however many files load it. Subdirectories are
+/// not searched.
+///
+/// A `mod.scss` index is `@use`d first: the files it loads keep its order,
+/// and the remaining entry points follow. The index stays importable from
+/// other SCSS as before.
+///
+/// Every `.scss` file in the directory, partials included, is embedded with
+/// `include_bytes!` so Cargo rebuilds when one changes. Cargo doesn't watch
+/// the directory itself: a newly added file is picked up on the next
+/// rebuild of the crate.
+///
+/// # Example
+///
+/// ```ignore
+/// use scss_macros::scss_dir;
+///
+/// const STYLES: &str = scss_dir!("src/styles");
+/// ```
+///
+/// # Errors
+///
+/// - Compile error if the directory can't be read or has no entry points
+/// - Compile error if the SCSS is invalid
+#[proc_macro]
+pub fn scss_dir(input: TokenStream) -> TokenStream {
+    let path_lit = parse_macro_input!(input as LitStr);
+    let relative_path = path_lit.value();
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    let dir = PathBuf::from(&manifest_dir).join(&relative_path);
+
+    let files = match scss_files(&dir) {
+        Ok(files) => files,
+        Err(e) => {
+            let err_msg = format!("Failed to read SCSS directory '{}': {}", dir.display(), e);
+            return syn::Error::new_spanned(&path_lit, err_msg)
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let bundle: String = files
+        .iter()
+        .filter_map(|path| entry_point(path))
+        .map(|name| format!("@use \"{name}\";\n"))
+        .collect();
+    if bundle.is_empty() {
+        let err_msg = format!("No SCSS entry points found in {}", dir.display());
+        return syn::Error::new_spanned(&path_lit, err_msg)
+            .to_compile_error()
+            .into();
+    }
+
+    let options = grass::Options::default()
+        .style(grass::OutputStyle::Compressed)
+        .load_path(&manifest_dir)
+        .load_path(&dir);
+
+    let css = match grass::from_string(bundle, &options) {
+        Ok(css) => css,
+        Err(e) => {
+            let err_msg = format!("SCSS compilation error in '{}': {}", relative_path, e);
+            return syn::Error::new_spanned(&path_lit, err_msg)
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    // Embedding the sources makes Cargo track them for rebuilds
+    let tracked = files.iter().map(|path| path.display().to_string());
+
+    let expanded = quote! {
+        {
+            #(const _: &[u8] = include_bytes!(#tracked);)*
+            #css
+        }
+    };
+
+    expanded.into()
+}
+
+/// Index file whose order wins over file name order
+const INDEX: &str = "mod";
+
+/// `.scss` files directly inside `dir`, the index first, then by file name
+fn scss_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "scss") {
+            files.push(path);
+        }
+    }
+    sort_entry_points(&mut files);
+    Ok(files)
+}
+
+fn sort_entry_points(files: &mut [PathBuf]) {
+    let is_index = |path: &PathBuf| path.file_stem() == Some(INDEX.as_ref());
+    files.sort_by(|a, b| {
+        is_index(b)
+            .cmp(&is_index(a))
+            .then_with(|| a.file_name().cmp(&b.file_name()))
+    });
+}
+
+/// Module name to `@use` for an entry point, or `None` for a partial
+fn entry_point(path: &Path) -> Option<&str> {
+    let name = path.file_stem()?.to_str()?;
+    (!name.starts_with('_')).then_some(name)
+}
+
 /// Compile inline SCSS to CSS at compile time.
 ///
 /// Useful for small amounts of component-specific styles that don't warrant
@@ -159,3 +293,25 @@ pub fn scss_inline(input: TokenStream) -> TokenStream {
 
     expanded.into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_points_skip_partials() {
+        assert_eq!(entry_point(Path::new("styles/button.scss")), Some("button"));
+        assert_eq!(entry_point(Path::new("styles/_variables.scss")), None);
+    }
+
+    #[test]
+    fn test_index_sorts_first() {
+        let mut files: Vec<PathBuf> = ["button.scss", "mod.scss", "_tokens.scss", "alert.scss"]
+            .iter()
+            .map(|name| Path::new("styles").join(name))
+            .collect();
+        sort_entry_points(&mut files);
+        let names: Vec<_> = files.iter().filter_map(|path| entry_point(path)).collect();
+        assert_eq!(names, ["mod", "alert", "button"]);
+    }
+}
@@ -27,6 +27,7 @@
 //! </Alert>
 //! ```
 
+use crate::i18n::use_locale;
 use leptos::prelude::*;
 
 /// Alert visual variants
@@ -80,6 +81,7 @@ pub fn Alert(
     /// Alert content
     children: Children,
 ) -> impl IntoView {
+    let locale = use_locale();
     let (visible, set_visible) = signal(true);
 
     let variant_class = format!("ui-alert--{}", variant.class_suffix());
@@ -120,7 +122,7 @@ pub fn Alert(
                     <button
                         class="ui-alert__close"
                         on:click=handle_dismiss
-                        aria-label="Dismiss"
+                        aria-label=move || locale.t_or("alert.dismiss", "Dismiss")
                     >
                         "×"
                     </button>
@@ -3,15 +3,27 @@
 //! A non-visual component that preloads NFT asset images in the background.
 //! Images are stored in a global cache and can be retrieved by other components.
 //!
+//! Preloading runs through a bounded pool (6 requests in flight by default) so
+//! large decks don't stall the browser's connection pool. Assets are fetched in
+//! priority order, queued work is cancelled when the asset list changes, and an
+//! image that fails at thumbnail size is retried at the alternate IIIF size.
+//!
 //! ## Props
 //!
-//! - `assets` - List of (policy_id, asset_name_hex) tuples to preload
+//! - `assets` - List of assets to preload (see [`PreloadAsset`])
 //! - `on_ready` - Callback when all images have been preloaded (receives loaded, failed counts)
 //! - `on_progress` - Optional callback as each image loads (receives loaded, total counts)
+//! - `max_concurrent` - Optional cap on simultaneous fetches (default 6)
 //!
 //! ## Usage
 //!
 //! ```ignore
+//! let assets = vec![
+//!     // Cards on screen first
+//!     PreloadAsset::new(policy_id, visible_name).with_priority(PreloadPriority::High),
+//!     PreloadAsset::new(policy_id, offscreen_name),
+//! ];
+//!
 //! <AssetCache
 //!     assets=assets_to_preload
 //!     on_ready=move |(loaded, failed)| {
@@ -20,17 +32,39 @@
 //! />
 //! ```
 
+use crate::asset_card::IiifSize;
 use crate::image_cache;
 use leptos::prelude::*;
+use send_wrapper::SendWrapper;
 use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::rc::Rc;
+use ui_core::urls::endpoints;
 use wasm_bindgen_futures::spawn_local;
 
-/// Asset to preload - contains policy_id and asset_name_hex
+/// Default number of images fetched at once
+pub const DEFAULT_MAX_CONCURRENT: usize = 6;
+
+/// IIIF sizes tried in order when preloading an asset
+const FALLBACK_SIZES: [IiifSize; 2] = [IiifSize::Thumb, IiifSize::Large];
+
+/// Fetch priority hint for a preloaded asset
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PreloadPriority {
+    /// Fetched after everything else (e.g. off-screen or later rounds)
+    Low,
+    #[default]
+    Normal,
+    /// Fetched first (e.g. cards currently visible)
+    High,
+}
+
+/// Asset to preload - contains policy_id, asset_name_hex and a priority hint
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PreloadAsset {
     pub policy_id: String,
     pub asset_name_hex: String,
+    pub priority: PreloadPriority,
 }
 
 impl PreloadAsset {
@@ -38,15 +72,136 @@ impl PreloadAsset {
         Self {
             policy_id: policy_id.into(),
             asset_name_hex: asset_name_hex.into(),
+            priority: PreloadPriority::default(),
         }
     }
 
+    /// Set the fetch priority
+    pub fn with_priority(mut self, priority: PreloadPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
     /// Build the IIIF thumbnail URL (400px thumb)
+    ///
+    /// This is the cache key, whichever size was actually fetched.
     pub fn to_url(&self) -> String {
-        format!(
-            "https://iiif.hodlcroft.com/iiif/3/{}:{}/full/400,/0/default.jpg",
-            self.policy_id, self.asset_name_hex
-        )
+        self.to_url_with_size(IiifSize::Thumb)
+    }
+
+    /// Build the IIIF URL at a specific size
+    pub fn to_url_with_size(&self, size: IiifSize) -> String {
+        endpoints().iiif_parts(&self.policy_id, &self.asset_name_hex, size.pixels())
+    }
+
+    /// URLs to try in order, primary size first
+    fn candidate_urls(&self) -> Vec<String> {
+        FALLBACK_SIZES
+            .iter()
+            .map(|size| self.to_url_with_size(*size))
+            .collect()
+    }
+}
+
+/// Order assets for fetching: highest priority first, list order within a
+/// priority, already-cached URLs removed and duplicates merged at their
+/// highest priority
+fn build_queue(
+    assets: &[PreloadAsset],
+    is_cached: impl Fn(&str) -> bool,
+) -> VecDeque<PreloadAsset> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut queue: Vec<PreloadAsset> = Vec::new();
+    for asset in assets {
+        let url = asset.to_url();
+        if let Some(&index) = seen.get(&url) {
+            let queued = &mut queue[index];
+            queued.priority = queued.priority.max(asset.priority);
+        } else if !is_cached(&url) {
+            seen.insert(url, queue.len());
+            queue.push(asset.clone());
+        }
+    }
+    // Stable sort keeps list order within each priority
+    queue.sort_by(|a, b| b.priority.cmp(&a.priority));
+    queue.into()
+}
+
+/// Shared state for one preload run
+#[derive(Default)]
+struct PreloadRun {
+    /// Bumped whenever the asset list changes; stale workers exit
+    generation: u64,
+    /// Cache keys of the current asset list, to ignore identical updates
+    urls: Vec<String>,
+    queue: VecDeque<PreloadAsset>,
+    total: u32,
+    loaded: u32,
+    failed: u32,
+}
+
+/// Fetch an asset, falling back to alternate IIIF sizes
+///
+/// A fallback hit is stored under the primary URL so cards find it.
+async fn preload_with_fallback(asset: &PreloadAsset) -> bool {
+    let primary = asset.to_url();
+    for (attempt, url) in asset.candidate_urls().into_iter().enumerate() {
+        match image_cache::preload_image(url.clone()).await {
+            Ok(blob_url) => {
+                tracing::debug!("Cached image: {url}");
+                if attempt > 0 {
+                    image_cache::cache_blob_url(primary, blob_url);
+                }
+                return true;
+            }
+            Err(e) => tracing::warn!("Failed to cache image {url}: {e:?}"),
+        }
+    }
+    false
+}
+
+/// Pull assets off the queue until it's empty or the run is superseded
+async fn run_worker(
+    run: Rc<RefCell<PreloadRun>>,
+    generation: u64,
+    on_ready: Callback<(u32, u32)>,
+    on_progress: Option<Callback<(u32, u32)>>,
+) {
+    loop {
+        let next = {
+            let mut state = run.borrow_mut();
+            if state.generation != generation {
+                return;
+            }
+            state.queue.pop_front()
+        };
+        let Some(asset) = next else {
+            return;
+        };
+
+        let success = preload_with_fallback(&asset).await;
+
+        let (loaded, failed, total) = {
+            let mut state = run.borrow_mut();
+            if state.generation != generation {
+                return;
+            }
+            if success {
+                state.loaded += 1;
+            } else {
+                state.failed += 1;
+            }
+            (state.loaded, state.failed, state.total)
+        };
+
+        if let Some(cb) = &on_progress {
+            cb.run((loaded, total));
+        }
+
+        if loaded + failed >= total {
+            tracing::info!("Image cache ready: {loaded} loaded, {failed} failed");
+            on_ready.run((loaded, failed));
+        }
     }
 }
 
@@ -62,87 +217,130 @@ pub fn AssetCache(
     /// Optional progress callback (loaded_count, total_count)
     #[prop(into, optional)]
     on_progress: Option<Callback<(u32, u32)>>,
+    /// Maximum simultaneous image fetches
+    #[prop(optional)]
+    max_concurrent: Option<usize>,
 ) -> impl IntoView {
-    // Track assets we've already started loading to avoid duplicates
-    let loading_started = Rc::new(RefCell::new(Vec::<String>::new()));
+    let max_concurrent = max_concurrent.unwrap_or(DEFAULT_MAX_CONCURRENT).max(1);
+    let run = Rc::new(RefCell::new(PreloadRun::default()));
 
-    Effect::new(move |_| {
-        let current_assets = assets.get();
+    Effect::new({
+        let run = Rc::clone(&run);
+        move |_| {
+            let current_assets = assets.get();
 
-        if current_assets.is_empty() {
-            on_ready.run((0, 0));
-            return;
-        }
+            if current_assets.is_empty() {
+                let mut state = run.borrow_mut();
+                state.generation += 1;
+                state.urls.clear();
+                state.queue.clear();
+                drop(state);
+                on_ready.run((0, 0));
+                return;
+            }
 
-        // Filter out assets we've already started loading
-        let urls: Vec<String> = current_assets
-            .iter()
-            .map(|a| a.to_url())
-            .filter(|url| {
-                let mut started = loading_started.borrow_mut();
-                if started.contains(url) {
-                    false
-                } else {
-                    started.push(url.clone());
-                    true
-                }
-            })
-            .collect();
+            // Same list re-emitted - let the current run finish
+            let urls: Vec<String> = current_assets.iter().map(|a| a.to_url()).collect();
+            if run.borrow().urls == urls {
+                return;
+            }
 
-        if urls.is_empty() {
-            return;
-        }
+            let queue = build_queue(&current_assets, |url| {
+                image_cache::get_cached_url(url).is_some()
+            });
+            let unique = urls.iter().collect::<HashSet<_>>().len() as u32;
+            let pending = queue.len();
 
-        let total = urls.len() as u32;
-        tracing::info!("Preloading {} images into cache", total);
-
-        let loaded = Rc::new(RefCell::new(0u32));
-        let failed = Rc::new(RefCell::new(0u32));
-
-        for url in urls {
-            let loaded = Rc::clone(&loaded);
-            let failed = Rc::clone(&failed);
-            let on_ready = on_ready;
-            let on_progress = on_progress;
-
-            spawn_local(async move {
-                let success = match image_cache::preload_image(url.clone()).await {
-                    Ok(_blob_url) => {
-                        tracing::debug!("Cached image: {}", url);
-                        true
-                    }
-                    Err(e) => {
-                        tracing::warn!("Failed to cache image {}: {:?}", url, e);
-                        false
-                    }
-                };
-
-                if success {
-                    *loaded.borrow_mut() += 1;
-                } else {
-                    *failed.borrow_mut() += 1;
-                }
+            // Replace the previous run - its queued assets are dropped and
+            // its in-flight fetches finish without being counted
+            let generation = {
+                let mut state = run.borrow_mut();
+                state.generation += 1;
+                state.urls = urls;
+                state.queue = queue;
+                state.total = unique;
+                state.loaded = unique - pending as u32;
+                state.failed = 0;
+                state.generation
+            };
 
-                let current_loaded = *loaded.borrow();
-                let current_failed = *failed.borrow();
+            if pending == 0 {
+                tracing::info!("Image cache ready: all {unique} images already cached");
+                on_ready.run((unique, 0));
+                return;
+            }
 
-                // Emit progress
-                if let Some(cb) = &on_progress {
-                    cb.run((current_loaded, total));
-                }
+            tracing::info!(
+                "Preloading {pending} images into cache ({} at a time)",
+                max_concurrent.min(pending)
+            );
 
-                // Check if all done
-                if current_loaded + current_failed >= total {
-                    tracing::info!(
-                        "Image cache ready: {} loaded, {} failed",
-                        current_loaded,
-                        current_failed
-                    );
-                    on_ready.run((current_loaded, current_failed));
-                }
-            });
+            for _ in 0..max_concurrent.min(pending) {
+                spawn_local(run_worker(
+                    Rc::clone(&run),
+                    generation,
+                    on_ready,
+                    on_progress,
+                ));
+            }
         }
     });
 
+    // Stop workers when the component unmounts
+    let run = SendWrapper::new(run);
+    on_cleanup(move || {
+        run.borrow_mut().generation += 1;
+    });
+
     // Non-visual component - renders nothing
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(name: &str, priority: PreloadPriority) -> PreloadAsset {
+        PreloadAsset::new("a".repeat(56), name).with_priority(priority)
+    }
+
+    fn names(queue: &VecDeque<PreloadAsset>) -> Vec<&str> {
+        queue.iter().map(|a| a.asset_name_hex.as_str()).collect()
+    }
+
+    #[test]
+    fn test_queue_orders_by_priority_then_list_order() {
+        let assets = vec![
+            asset("01", PreloadPriority::Low),
+            asset("02", PreloadPriority::Normal),
+            asset("03", PreloadPriority::High),
+            asset("04", PreloadPriority::Normal),
+            asset("05", PreloadPriority::High),
+        ];
+        let queue = build_queue(&assets, |_| false);
+        assert_eq!(names(&queue), vec!["03", "05", "02", "04", "01"]);
+    }
+
+    #[test]
+    fn test_queue_skips_duplicates_and_cached() {
+        let cached = asset("02", PreloadPriority::Normal).to_url();
+        let assets = vec![
+            asset("01", PreloadPriority::Normal),
+            asset("02", PreloadPriority::Normal),
+            asset("01", PreloadPriority::High),
+            asset("03", PreloadPriority::Normal),
+        ];
+        let queue = build_queue(&assets, |url| url == cached);
+        assert_eq!(names(&queue), vec!["01", "03"]);
+        // The later High entry raises the duplicate's priority
+        assert_eq!(queue[0].priority, PreloadPriority::High);
+    }
+
+    #[test]
+    fn test_candidate_urls_start_with_primary() {
+        let a = asset("01", PreloadPriority::Normal);
+        let urls = a.candidate_urls();
+        assert_eq!(urls.len(), 2);
+        assert_eq!(urls[0], a.to_url());
+        assert!(urls[1].contains(&IiifSize::Large.pixels().to_string()));
+    }
+}
@@ -8,12 +8,16 @@
 //!
 //! The component generates IIIF URLs from the asset ID:
 //! - Asset ID format: `{policy_id}{asset_name_hex}` (56+ chars)
-//! - Generated URL: `https://iiif.hodlcroft.com/iiif/3/{policy_id}:{asset_name}/full/{size},/0/default.jpg`
+//! - Generated URL: `{iiif_base}/{policy_id}:{asset_name}/full/{size},/0/default.jpg`
+//!   (base from [`ui_core::urls::endpoints`], `https://iiif.hodlcroft.com/iiif/3` by default)
 //!
 //! The IIIF image size is automatically selected based on card size:
 //! - xs, sm, md, lg (≤400px): uses 400px IIIF image (cached, fast)
 //! - xl (>400px): uses 1686px IIIF image (high resolution)
 //!
+//! Both sizes are also offered as a `srcset`, so high-density screens and
+//! auto-sized cards pick the sharper image when they need it.
+//!
 //! ## Overlay Slots
 //!
 //! The card supports overlay content at four corners and a footer:
@@ -30,8 +34,11 @@
 //! - `size` - Card size: Xs, Sm, Md, Lg, Xl
 //! - `name` - Display name of the asset
 //! - `accent_color` - Optional accent/tier color for top bar
+//! - `tint_from_art` - If true and no `accent_color` is set, derive the accent
+//!   from the image's dominant color and tint overlays and footer to match
 //! - `is_static` - If true, card is non-interactive
 //! - `show_name` - If true, show name overlay
+//! - `aspect` / `fit` / `blur_backdrop` - Framing, passed to `ImageCard`
 //! - `on_click` - Callback when card is clicked, receives asset_id
 //! - `on_load` - Callback when image has loaded
 //! - `top_left`, `top_right`, `bottom_left`, `bottom_right` - Overlay slot content
@@ -59,12 +66,12 @@
 //! />
 //! ```
 
-use crate::image_card::{CardSize, ImageCard};
+use crate::image_card::{AspectRatio, CardSize, ImageCard, ImageFit};
+use crate::use_fetch::use_fetch_with;
 use leptos::children::ChildrenFn;
 use leptos::prelude::*;
-
-/// IIIF base URL for image lookups
-const IIIF_BASE_URL: &str = "https://iiif.hodlcroft.com/iiif/3";
+use ui_core::color::{contrast_text_for, dominant_colors, rgb_to_hex, Rgb};
+use ui_core::urls::endpoints;
 
 /// IIIF image size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -77,6 +84,11 @@ pub enum IiifSize {
 }
 
 impl IiifSize {
+    /// All sizes, smallest first
+    pub fn all() -> &'static [IiifSize] {
+        &[IiifSize::Thumb, IiifSize::Large]
+    }
+
     /// Get the IIIF size parameter value
     pub fn pixels(&self) -> u16 {
         match self {
@@ -101,24 +113,44 @@ impl IiifSize {
 /// Policy ID is always 56 hex chars, asset name is the remainder.
 ///
 /// Returns None if asset_id is too short to contain a valid policy ID.
+/// The IIIF base comes from the installed [`ui_core::urls::Endpoints`].
 pub fn generate_iiif_url(asset_id: &str, size: IiifSize) -> Option<String> {
-    // Policy ID is 56 hex characters (28 bytes)
-    if asset_id.len() < 56 {
-        return None;
-    }
+    endpoints().iiif(asset_id, size.pixels())
+}
 
-    let policy_id = &asset_id[..56];
-    let asset_name = &asset_id[56..];
+/// Generate a `srcset` offering every [`IiifSize`] of an asset
+///
+/// Returns None if the asset ID is invalid, as for [`generate_iiif_url`].
+pub fn iiif_srcset(asset_id: &str) -> Option<String> {
+    let candidates = IiifSize::all()
+        .iter()
+        .map(|size| {
+            generate_iiif_url(asset_id, *size).map(|url| format!("{url} {}w", size.pixels()))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(candidates.join(", "))
+}
 
-    if asset_name.is_empty() {
-        return None;
-    }
+/// Dominant color of an image, for art-derived accents
+///
+/// `image_url` is tracked; nothing is sampled while it returns `None`. The
+/// signal stays `None` until the palette is ready, or if sampling fails
+/// (e.g. the image host sends no CORS headers).
+pub fn use_art_accent(image_url: impl Fn() -> Option<String> + 'static) -> Signal<Option<Rgb>> {
+    let palette = use_fetch_with(
+        image_url,
+        |url| async move { dominant_colors(&url, 1).await },
+    );
+    Signal::derive(move || palette.data().and_then(|colors| colors.first().copied()))
+}
 
-    // IIIF format: {policy_id}:{asset_name_hex}
-    Some(format!(
-        "{IIIF_BASE_URL}/{policy_id}:{asset_name}/full/{},/0/default.jpg",
-        size.pixels()
-    ))
+/// Inline style exposing `--{prefix}-accent` and a readable `--{prefix}-accent-text`
+pub(crate) fn accent_style(prefix: &str, color: Rgb) -> String {
+    format!(
+        "--{prefix}-accent: {}; --{prefix}-accent-text: {}",
+        rgb_to_hex(color),
+        contrast_text_for(color)
+    )
 }
 
 /// Asset card component - wraps ImageCard with IIIF URL generation and overlay slots
@@ -139,12 +171,24 @@ pub fn AssetCard(
     /// Accent color for top bar
     #[prop(into, optional)]
     accent_color: Option<Signal<String>>,
+    /// Derive the accent from the image's dominant color when `accent_color` is unset
+    #[prop(optional)]
+    tint_from_art: bool,
     /// If true, card is non-interactive
     #[prop(optional)]
     is_static: bool,
     /// If true, show name overlay
     #[prop(optional)]
     show_name: bool,
+    /// Frame shape
+    #[prop(optional)]
+    aspect: AspectRatio,
+    /// How the image fills the frame
+    #[prop(optional)]
+    fit: ImageFit,
+    /// Fill letterbox space with a blurred copy of the image
+    #[prop(optional)]
+    blur_backdrop: bool,
     /// Click callback - receives asset_id
     #[prop(into, optional)]
     on_click: Option<Callback<String>>,
@@ -208,6 +252,31 @@ pub fn AssetCard(
         String::new()
     });
 
+    // Art-derived accent, only sampled when no explicit accent is given
+    let art_accent = use_art_accent(move || {
+        let explicit = accent_color.as_ref().is_some_and(|c| !c.get().is_empty());
+        let url = resolved_url.get();
+        (tint_from_art && !explicit && !url.is_empty()).then_some(url)
+    });
+    let tint_style = move || {
+        art_accent
+            .get()
+            .map(|color| accent_style("asset-card", color))
+            .unwrap_or_default()
+    };
+
+    // Responsive candidates, only for IIIF images
+    let srcset: Memo<String> = Memo::new(move |_| {
+        let direct = image_url.as_ref().is_some_and(|u| !u.get().is_empty());
+        if direct {
+            return String::new();
+        }
+        asset_id_for_url
+            .as_ref()
+            .and_then(|id| iiif_srcset(&id.get()))
+            .unwrap_or_default()
+    });
+
     let handle_click = move |()| {
         if let Some(cb) = on_click {
             let id = asset_id_for_click
@@ -222,8 +291,14 @@ pub fn AssetCard(
     let name_memo: Memo<String> =
         Memo::new(move |_| name.as_ref().map(|n| n.get()).unwrap_or_default());
 
-    let accent_memo: Memo<String> =
-        Memo::new(move |_| accent_color.as_ref().map(|c| c.get()).unwrap_or_default());
+    let accent_memo: Memo<String> = Memo::new(move |_| {
+        accent_color
+            .as_ref()
+            .map(|c| c.get())
+            .filter(|c| !c.is_empty())
+            .or_else(|| art_accent.get().map(rgb_to_hex))
+            .unwrap_or_default()
+    });
 
     // Wrap the on_load callback to forward through
     let handle_load = move |()| {
@@ -243,7 +318,7 @@ pub fn AssetCard(
     // If we have overlays or footer, wrap the ImageCard in our container
     if has_overlays || has_footer {
         view! {
-            <div class=wrapper_class>
+            <div class=wrapper_class style=tint_style>
                 <div class="asset-card__image-container">
                     <ImageCard
                         image_url=Signal::derive(move || resolved_url.get())
@@ -252,6 +327,10 @@ pub fn AssetCard(
                         accent_color=Signal::derive(move || accent_memo.get())
                         is_static=is_static
                         show_name=show_name
+                        aspect=aspect
+                        fit=fit
+                        blur_backdrop=blur_backdrop
+                        srcset=srcset
                         on_click=handle_click
                         on_load=handle_load
                     />
@@ -290,6 +369,10 @@ pub fn AssetCard(
                 accent_color=Signal::derive(move || accent_memo.get())
                 is_static=is_static
                 show_name=show_name
+                aspect=aspect
+                fit=fit
+                blur_backdrop=blur_backdrop
+                srcset=srcset
                 on_click=handle_click
                 on_load=handle_load
             />
@@ -329,6 +412,30 @@ mod tests {
         assert!(generate_iiif_url(policy_only, IiifSize::Thumb).is_none());
     }
 
+    #[test]
+    fn test_iiif_srcset() {
+        let asset_id = "b3dab69f7e6100849434fb1781e34bd12a916557f6231b8d2629b6f6506972617465313839";
+        let srcset = iiif_srcset(asset_id).unwrap();
+        let candidates: Vec<_> = srcset.split(", ").collect();
+        assert_eq!(candidates.len(), 2);
+        assert!(candidates[0].contains("/400,/") && candidates[0].ends_with(" 400w"));
+        assert!(candidates[1].contains("/1686,/") && candidates[1].ends_with(" 1686w"));
+
+        assert!(iiif_srcset("abc123").is_none());
+    }
+
+    #[test]
+    fn test_accent_style() {
+        assert_eq!(
+            accent_style("asset-card", (0, 114, 181)),
+            "--asset-card-accent: #0072b5; --asset-card-accent-text: #ffffff"
+        );
+        assert_eq!(
+            accent_style("policy-folder", (255, 255, 0)),
+            "--policy-folder-accent: #ffff00; --policy-folder-accent-text: #000000"
+        );
+    }
+
     #[test]
     fn test_iiif_size_for_card_size() {
         assert_eq!(IiifSize::for_card_size(CardSize::Xs), IiifSize::Thumb);
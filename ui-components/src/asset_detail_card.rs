@@ -7,7 +7,9 @@
 //!
 //! - Large image display with IIIF URL generation
 //! - Asset name and optional rarity rank
-//! - Traits displayed as label/value pairs
+//! - Traits displayed as label/value pairs, or read from CIP-25 metadata
+//! - Provenance: collection, policy, mint transaction and CIP-27 royalty
+//! - Raw metadata JSON viewer behind a toggle
 //! - Optional accent color (e.g., tier/rarity color)
 //! - Action slot for buttons (buy, sell, equip, etc.)
 //!
@@ -17,6 +19,10 @@
 //! - `image_url` - Direct image URL (fallback)
 //! - `name` - Asset display name
 //! - `traits` - Map of trait name to values
+//! - `metadata` - Raw CIP-25 asset metadata (attributes fallback and JSON viewer)
+//! - `collection_name` - Collection display name
+//! - `mint_tx` - Mint transaction hash, linked via `ExplorerLink`
+//! - `royalty` - CIP-27 royalty info (see [`RoyaltyInfo::from_cip27`])
 //! - `rarity_rank` - Optional rarity ranking
 //! - `accent_color` - Optional accent color for header
 //! - `actions` - Optional action buttons slot
@@ -43,12 +49,132 @@
 //!         on_close=move |()| set_selected.set(None)
 //!     />
 //! }
+//!
+//! // Provenance and raw metadata
+//! view! {
+//!     <AssetDetailCard
+//!         asset_id=unit
+//!         name="Pirate #189"
+//!         metadata=cip25_json
+//!         collection_name="Black Flag Pirates"
+//!         mint_tx=mint_tx_hash
+//!         royalty=RoyaltyInfo::from_cip27(&label_777_json)
+//!     />
+//! }
 //! ```
 
 use crate::asset_card::{generate_iiif_url, IiifSize};
+use crate::explorer_link::{ExplorerKind, ExplorerLink};
+use crate::i18n::use_locale;
+use crate::json_tree::JsonTree;
 use leptos::prelude::*;
+use serde_json::Value;
 use std::collections::HashMap;
 
+/// Length of a hex-encoded policy ID at the start of an asset ID
+const POLICY_ID_LEN: usize = 56;
+
+/// CIP-25 keys rendered elsewhere, never as attributes
+const CIP25_RESERVED_KEYS: &[&str] = &["name", "image", "mediaType", "description", "files"];
+
+/// CIP-25 keys that conventionally nest the attribute list
+const CIP25_ATTRIBUTE_KEYS: &[&str] = &["attributes", "traits"];
+
+/// CIP-27 royalty terms for a collection
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoyaltyInfo {
+    /// Royalty rate as a fraction (0.05 = 5%)
+    pub rate: f64,
+    /// Payment address
+    pub address: String,
+}
+
+impl RoyaltyInfo {
+    /// Parse the CIP-27 (label 777) metadata object
+    ///
+    /// Accepts `rate` (or the pre-standard `pct`) as a string or number, and
+    /// `addr` as a string or an array of 64-byte chunks.
+    pub fn from_cip27(value: &Value) -> Option<Self> {
+        let rate = match value.get("rate").or_else(|| value.get("pct"))? {
+            Value::String(s) => s.trim().parse().ok()?,
+            Value::Number(n) => n.as_f64()?,
+            _ => return None,
+        };
+        let address = match value.get("addr")? {
+            Value::String(s) => s.clone(),
+            Value::Array(chunks) => chunks.iter().filter_map(Value::as_str).collect(),
+            _ => return None,
+        };
+        (rate.is_finite() && rate >= 0.0 && !address.is_empty()).then_some(Self { rate, address })
+    }
+
+    /// Rate formatted as a percentage, e.g. "5%" or "2.5%"
+    pub fn percent_label(&self) -> String {
+        let pct = format!("{:.2}", self.rate * 100.0);
+        let pct = pct.trim_end_matches('0').trim_end_matches('.');
+        format!("{pct}%")
+    }
+}
+
+/// Policy ID prefix of an asset ID, if it has one
+fn policy_id_of(asset_id: &str) -> Option<&str> {
+    asset_id
+        .get(..POLICY_ID_LEN)
+        .filter(|p| p.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Render a metadata value as display text
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Attribute rows from CIP-25 asset metadata, sorted by name
+///
+/// Top-level keys other than the standard CIP-25 fields are attributes, and
+/// a nested `attributes`/`traits` object or `{trait_type, value}` list is
+/// flattened into the same table.
+fn metadata_attributes(metadata: &Value) -> Vec<(String, String)> {
+    let Some(fields) = metadata.as_object() else {
+        return Vec::new();
+    };
+
+    let mut rows = Vec::new();
+    for (key, value) in fields {
+        if CIP25_RESERVED_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+        if CIP25_ATTRIBUTE_KEYS.contains(&key.as_str()) {
+            match value {
+                Value::Object(nested) => {
+                    rows.extend(nested.iter().map(|(k, v)| (k.clone(), display_value(v))));
+                    continue;
+                }
+                Value::Array(items) if items.iter().all(Value::is_object) => {
+                    rows.extend(items.iter().filter_map(|item| {
+                        let name = item.get("trait_type").and_then(Value::as_str)?;
+                        Some((name.to_string(), display_value(item.get("value")?)))
+                    }));
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        rows.push((key.clone(), display_value(value)));
+    }
+
+    rows.sort_by(|a, b| a.0.cmp(&b.0));
+    rows
+}
+
 /// Asset detail card for displaying full asset information with traits
 #[component]
 pub fn AssetDetailCard(
@@ -70,6 +196,18 @@ pub fn AssetDetailCard(
     /// Rarity rank (lower = rarer)
     #[prop(into, optional)]
     rarity_rank: Signal<Option<u32>>,
+    /// Raw CIP-25 asset metadata - attributes fallback and JSON viewer
+    #[prop(into, optional)]
+    metadata: Option<Signal<Value>>,
+    /// Collection display name
+    #[prop(into, optional)]
+    collection_name: Option<Signal<String>>,
+    /// Mint transaction hash
+    #[prop(into, optional)]
+    mint_tx: Option<Signal<String>>,
+    /// CIP-27 royalty info
+    #[prop(into, optional)]
+    royalty: Signal<Option<RoyaltyInfo>>,
     /// Accent color for header bar
     #[prop(into, optional)]
     accent_color: Option<Signal<String>>,
@@ -80,6 +218,8 @@ pub fn AssetDetailCard(
     #[prop(into, optional)]
     on_close: Option<Callback<()>>,
 ) -> impl IntoView {
+    let locale = use_locale();
+
     // Track whether high-res image has loaded
     let (high_res_loaded, set_high_res_loaded) = signal(false);
 
@@ -142,6 +282,37 @@ pub fn AssetDetailCard(
             .map(|c| format!("background-color: {c};"))
     };
 
+    // Explicit traits win; otherwise read attributes from CIP-25 metadata
+    let attribute_rows = move || {
+        let mut rows: Vec<(String, String)> = traits.with(|t| {
+            t.iter()
+                .map(|(name, values)| (name.clone(), values.join(", ")))
+                .collect()
+        });
+        if rows.is_empty() {
+            if let Some(metadata) = metadata {
+                return metadata.with(metadata_attributes);
+            }
+        }
+        rows.sort_by(|a, b| a.0.cmp(&b.0));
+        rows
+    };
+
+    let non_empty =
+        |signal: Option<Signal<String>>| signal.map(|s| s.get()).filter(|v| !v.is_empty());
+    let collection = move || non_empty(collection_name);
+    let policy_id =
+        move || non_empty(asset_id).and_then(|id| policy_id_of(&id).map(str::to_string));
+    let mint = move || non_empty(mint_tx);
+    let has_provenance = move || {
+        collection().is_some()
+            || policy_id().is_some()
+            || mint().is_some()
+            || royalty.with(Option::is_some)
+    };
+
+    let show_raw = RwSignal::new(false);
+
     // Render children slot (footer content)
     let footer_content = children.map(|c| c());
 
@@ -157,7 +328,7 @@ pub fn AssetDetailCard(
                 <button
                     class="asset-detail-card__close"
                     on:click=move |_| cb.run(())
-                    aria-label="Close"
+                    aria-label=move || locale.t_or("asset_detail_card.close", "Close")
                 >
                     "×"
                 </button>
@@ -188,7 +359,11 @@ pub fn AssetDetailCard(
                 // Rarity rank pill overlay
                 {move || rarity_rank.get().map(|rank| view! {
                     <span class="asset-detail-card__rarity">
-                        {"Rank #"}{rank}
+                        {move || locale.t_or_with(
+                            "asset_detail_card.rank",
+                            "Rank #{rank}",
+                            &[("rank", rank.to_string())],
+                        )}
                     </span>
                 })}
             </div>
@@ -203,21 +378,62 @@ pub fn AssetDetailCard(
                 // Traits section
                 <div class="asset-detail-card__traits">
                     <For
-                        each=move || {
-                            let mut traits_vec: Vec<_> = traits.get().into_iter().collect();
-                            traits_vec.sort_by(|a, b| a.0.cmp(&b.0));
-                            traits_vec
-                        }
-                        key=|(name, _)| name.clone()
+                        each=attribute_rows
+                        key=|row| row.clone()
                         let:trait_entry
                     >
                         <TraitRow
                             name=trait_entry.0
-                            values=trait_entry.1
+                            value=trait_entry.1
                         />
                     </For>
                 </div>
 
+                // Provenance section
+                <Show when=has_provenance>
+                    <div class="asset-detail-card__provenance">
+                        {move || collection().map(|name| view! {
+                            <ProvenanceRow label_key="asset_detail_card.collection" label="Collection">{name}</ProvenanceRow>
+                        })}
+                        {move || policy_id().map(|id| view! {
+                            <ProvenanceRow label_key="asset_detail_card.policy" label="Policy">
+                                <ExplorerLink kind=ExplorerKind::Policy value=id />
+                            </ProvenanceRow>
+                        })}
+                        {move || mint().map(|tx| view! {
+                            <ProvenanceRow label_key="asset_detail_card.mint_tx" label="Mint tx">
+                                <ExplorerLink kind=ExplorerKind::Tx value=tx />
+                            </ProvenanceRow>
+                        })}
+                        {move || royalty.get().map(|r| view! {
+                            <ProvenanceRow label_key="asset_detail_card.royalty" label="Royalty">
+                                <span class="asset-detail-card__royalty-rate">{r.percent_label()}</span>
+                                <ExplorerLink kind=ExplorerKind::Address value=r.address />
+                            </ProvenanceRow>
+                        })}
+                    </div>
+                </Show>
+
+                // Raw metadata viewer
+                {metadata.map(|metadata| view! {
+                    <div class="asset-detail-card__raw">
+                        <button
+                            class="asset-detail-card__raw-toggle"
+                            aria-expanded=move || show_raw.get().to_string()
+                            on:click=move |_| show_raw.update(|v| *v = !*v)
+                        >
+                            {move || if show_raw.get() {
+                                locale.t_or("asset_detail_card.hide_raw", "Hide raw metadata")
+                            } else {
+                                locale.t_or("asset_detail_card.show_raw", "Show raw metadata")
+                            }}
+                        </button>
+                        <Show when=move || show_raw.get()>
+                            <JsonTree value=metadata class="asset-detail-card__raw-json" />
+                        </Show>
+                    </div>
+                })}
+
                 // Footer (always present for visual balance, contains children if provided)
                 <div class="asset-detail-card__footer">
                     {footer_content}
@@ -229,13 +445,113 @@ pub fn AssetDetailCard(
 
 /// Single trait row component
 #[component]
-fn TraitRow(name: String, values: Vec<String>) -> impl IntoView {
-    let display_value = values.join(", ");
-
+fn TraitRow(name: String, value: String) -> impl IntoView {
     view! {
         <div class="asset-detail-card__trait">
             <span class="asset-detail-card__trait-name">{name}</span>
-            <span class="asset-detail-card__trait-value">{display_value}</span>
+            <span class="asset-detail-card__trait-value">{value}</span>
         </div>
     }
 }
+
+/// Single provenance row component, labelled by message `label_key`
+#[component]
+fn ProvenanceRow(
+    label_key: &'static str,
+    label: &'static str,
+    children: Children,
+) -> impl IntoView {
+    let locale = use_locale();
+    view! {
+        <div class="asset-detail-card__provenance-row">
+            <span class="asset-detail-card__trait-name">{move || locale.t_or(label_key, label)}</span>
+            <span class="asset-detail-card__provenance-value">{children()}</span>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const POLICY: &str = "b3dab69f7e6100849434fb1781e34bd12a916557f6231b8d2629b6f6";
+
+    #[test]
+    fn royalty_parses_rate_and_chunked_address() {
+        let royalty = RoyaltyInfo::from_cip27(&json!({
+            "rate": "0.05",
+            "addr": ["addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x", "v8tha"]
+        }))
+        .unwrap();
+        assert_eq!(royalty.rate, 0.05);
+        assert!(royalty.address.ends_with("v8tha"));
+        assert_eq!(royalty.percent_label(), "5%");
+    }
+
+    #[test]
+    fn royalty_accepts_legacy_pct_and_rejects_missing_addr() {
+        let royalty = RoyaltyInfo::from_cip27(&json!({"pct": 0.025, "addr": "addr1x"})).unwrap();
+        assert_eq!(royalty.percent_label(), "2.5%");
+        assert_eq!(RoyaltyInfo::from_cip27(&json!({"rate": "0.1"})), None);
+        assert_eq!(
+            RoyaltyInfo::from_cip27(&json!({"rate": "lots", "addr": "addr1x"})),
+            None
+        );
+    }
+
+    #[test]
+    fn policy_id_requires_hex_prefix() {
+        assert_eq!(
+            policy_id_of(&format!("{POLICY}50697261746531")),
+            Some(POLICY)
+        );
+        assert_eq!(policy_id_of("short"), None);
+        assert_eq!(policy_id_of(&"z".repeat(60)), None);
+    }
+
+    #[test]
+    fn attributes_skip_standard_fields_and_sort() {
+        let rows = metadata_attributes(&json!({
+            "name": "Pirate #189",
+            "image": "ipfs://Qm...",
+            "mediaType": "image/png",
+            "Hat": "Tricorn",
+            "Background": "Ocean",
+            "Rank": 42
+        }));
+        assert_eq!(
+            rows,
+            vec![
+                ("Background".to_string(), "Ocean".to_string()),
+                ("Hat".to_string(), "Tricorn".to_string()),
+                ("Rank".to_string(), "42".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn attributes_flatten_nested_objects_and_trait_lists() {
+        let nested = metadata_attributes(&json!({
+            "attributes": {"Eyes": "Patch", "Accessories": ["Parrot", "Hook"]}
+        }));
+        assert_eq!(
+            nested,
+            vec![
+                ("Accessories".to_string(), "Parrot, Hook".to_string()),
+                ("Eyes".to_string(), "Patch".to_string()),
+            ]
+        );
+
+        let list = metadata_attributes(&json!({
+            "traits": [{"trait_type": "Ship", "value": "Galleon"}, {"value": "orphan"}]
+        }));
+        assert_eq!(list, vec![("Ship".to_string(), "Galleon".to_string())]);
+    }
+
+    #[test]
+    fn attributes_keep_plain_trait_arrays() {
+        let rows = metadata_attributes(&json!({"traits": ["Gold", "Rare"]}));
+        assert_eq!(rows, vec![("traits".to_string(), "Gold, Rare".to_string())]);
+    }
+}
@@ -7,13 +7,14 @@
 //!
 //! - Responsive auto-fit grid layout
 //! - Configurable min column width and gap
-//! - Empty state with customizable message
+//! - Empty state (shared `EmptyState`) with customizable message and variant
 //! - Loading state support
 //!
 //! ## Props
 //!
 //! - `children` - Grid content (typically `AssetCard` components)
 //! - `empty_message` - Message shown when grid is empty (default: "No assets")
+//! - `empty_variant` - Optional `EmptyStateVariant` preset for the empty state
 //! - `min_column_width` - Minimum column width for auto-fit (default: "120px")
 //! - `gap` - Gap between grid items (default: "1rem")
 //! - `columns` - Fixed number of columns (overrides auto-fit if set)
@@ -57,6 +58,8 @@
 //! </AssetGrid>
 //! ```
 
+use crate::empty_state::{EmptyState, EmptyStateVariant};
+use crate::i18n::use_locale;
 use leptos::prelude::*;
 
 /// Asset grid component for displaying collections of asset cards
@@ -67,9 +70,12 @@ pub fn AssetGrid(
     /// Signal indicating if grid is empty
     #[prop(into, optional)]
     is_empty: Signal<bool>,
-    /// Message shown when grid is empty
-    #[prop(into, optional, default = "No assets".into())]
-    empty_message: String,
+    /// Message shown when grid is empty (default: localized "No assets")
+    #[prop(into, optional)]
+    empty_message: Option<String>,
+    /// Preset for the empty state (icon and title)
+    #[prop(optional)]
+    empty_variant: Option<EmptyStateVariant>,
     /// Minimum column width for auto-fit layout
     #[prop(into, optional, default = "120px".into())]
     min_column_width: String,
@@ -86,6 +92,10 @@ pub fn AssetGrid(
     #[prop(into, optional)]
     class: String,
 ) -> impl IntoView {
+    let locale = use_locale();
+    let empty_message =
+        empty_message.unwrap_or_else(|| locale.t_or("asset_grid.empty", "No assets"));
+
     // Build grid template style
     let grid_style = if let Some(cols) = columns {
         format!("grid-template-columns: repeat({cols}, 1fr); gap: {gap};")
@@ -112,7 +122,7 @@ pub fn AssetGrid(
                 style:display=move || if loading.get() { "flex" } else { "none" }
             >
                 <div class="asset-grid__spinner"></div>
-                <span>"Loading..."</span>
+                <span>{move || locale.t_or("asset_grid.loading", "Loading...")}</span>
             </div>
 
             // Empty state
@@ -120,7 +130,12 @@ pub fn AssetGrid(
                 class="asset-grid__empty"
                 style:display=move || if !loading.get() && is_empty.get() { "block" } else { "none" }
             >
-                {empty_message}
+                {match empty_variant {
+                    Some(variant) => view! {
+                        <EmptyState variant=variant message=empty_message />
+                    }.into_any(),
+                    None => view! { <EmptyState message=empty_message /> }.into_any(),
+                }}
             </div>
 
             // Grid content
@@ -30,8 +30,11 @@
 //! })}
 //! ```
 
+use crate::i18n::use_locale;
+use crate::truncated_text::{TruncateMode, TruncatedText};
 use cardano_assets::AssetId;
 use leptos::prelude::*;
+use ui_core::urls::endpoints;
 
 /// Default large image size for IIIF requests
 const LARGE_IMAGE_SIZE: u16 = 1686;
@@ -56,15 +59,12 @@ pub fn AssetModal(
     #[prop(optional, default = LARGE_IMAGE_SIZE)]
     size: u16,
 ) -> impl IntoView {
+    let locale = use_locale();
     let (preview_loaded, set_preview_loaded) = signal(false);
     let (full_loaded, set_full_loaded) = signal(false);
 
     // Build IIIF URLs for both sizes
-    let base_url = format!(
-        "https://iiif.hodlcroft.com/iiif/3/{}:{}/full",
-        asset_id.policy_id(),
-        asset_id.asset_name_hex(),
-    );
+    let base_url = endpoints().iiif_full_base(&asset_id.policy_id(), &asset_id.asset_name_hex());
     let preview_url = format!("{}/{},/0/default.jpg", base_url, PREVIEW_IMAGE_SIZE);
     let full_url = format!("{}/{},/0/default.jpg", base_url, size);
 
@@ -77,15 +77,6 @@ pub fn AssetModal(
 
     // Short asset ID for display
     let asset_id_str = asset_id.concatenated();
-    let short_id = if asset_id_str.len() > 20 {
-        format!(
-            "{}...{}",
-            &asset_id_str[..12],
-            &asset_id_str[asset_id_str.len() - 8..]
-        )
-    } else {
-        asset_id_str
-    };
 
     let on_close_backdrop = on_close;
     let on_close_button = on_close;
@@ -96,7 +87,7 @@ pub fn AssetModal(
                 <button
                     class="ui-asset-modal__close"
                     on:click=move |_| on_close_button.run(())
-                    aria-label="Close"
+                    aria-label=move || locale.t_or("asset_modal.close", "Close")
                 >
                     "×"
                 </button>
@@ -128,7 +119,14 @@ pub fn AssetModal(
                         class:ui-asset-modal__progress--hidden=move || full_loaded.get()
                     ></div>
                     <h3 class="ui-asset-modal__name">{display_name}</h3>
-                    <code class="ui-asset-modal__id">{short_id}</code>
+                    <code class="ui-asset-modal__id">
+                        <TruncatedText
+                            value=asset_id_str
+                            mode=TruncateMode::Middle
+                            head=12
+                            tail=8
+                        />
+                    </code>
                 </div>
             </div>
         </div>
@@ -10,6 +10,7 @@
 use leptos::children::ChildrenFn;
 use leptos::prelude::*;
 
+use crate::i18n::use_locale;
 use crate::{AssetGrid, Modal};
 
 /// Represents an asset item in the picker
@@ -64,7 +65,7 @@ where
     V: IntoView + 'static,
 {
     let empty_msg = if empty_message.is_empty() {
-        "No assets available".to_string()
+        use_locale().t_or("asset_picker.empty", "No assets available")
     } else {
         empty_message
     };
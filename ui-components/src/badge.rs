@@ -1,6 +1,7 @@
 //! Badge Leptos Component
 //!
-//! A small label/badge for categorization or status.
+//! A small label/badge for categorization or status, plus a count badge for
+//! notification and presence indicators that can be pinned to any child.
 //!
 //! ## Props
 //!
@@ -8,16 +9,39 @@
 //! - `color` - Optional CSS color
 //! - `variant` - Style variant (Solid, Outline, Subtle)
 //!
+//! `CountBadge` and `BadgeAnchor` take:
+//!
+//! - `count` - Number to show (dot mode without a count is always shown)
+//! - `max` - Largest number shown before overflowing to "99+" (default: 99)
+//! - `dot` - Show a plain dot instead of the number
+//! - `show_zero` - Keep the badge visible at zero (default: false)
+//! - `pulse` - Pulse animation to draw attention
+//! - `color` - Optional CSS color
+//! - `position` - Corner the badge is pinned to (`BadgeAnchor` only)
+//!
 //! ## Usage
 //!
 //! ```ignore
 //! <Badge label="New" />
 //! <Badge label="Captain" color="#ffc107" variant=BadgeVariant::Outline />
 //! <Badge label="Active" variant=BadgeVariant::Subtle />
+//!
+//! <CountBadge count=unread />
+//!
+//! <BadgeAnchor count=unread max=9 pulse=true>
+//!     <span class="icon">"🔔"</span>
+//! </BadgeAnchor>
+//!
+//! <BadgeAnchor dot=true color="#4caf50" position=BadgePosition::BottomRight>
+//!     <UserAvatar fallback="A" />
+//! </BadgeAnchor>
 //! ```
 
 use leptos::prelude::*;
 
+/// Default largest count shown before overflowing to "99+"
+pub const DEFAULT_BADGE_MAX: u32 = 99;
+
 /// Badge style variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BadgeVariant {
@@ -62,6 +86,36 @@ impl BadgeSize {
     }
 }
 
+/// Corner a [`BadgeAnchor`] pins its badge to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BadgePosition {
+    #[default]
+    TopRight,
+    TopLeft,
+    BottomRight,
+    BottomLeft,
+}
+
+impl BadgePosition {
+    fn class_suffix(&self) -> &'static str {
+        match self {
+            BadgePosition::TopRight => "top-right",
+            BadgePosition::TopLeft => "top-left",
+            BadgePosition::BottomRight => "bottom-right",
+            BadgePosition::BottomLeft => "bottom-left",
+        }
+    }
+}
+
+/// Format a count for display, overflowing to "{max}+"
+pub fn format_count(count: u32, max: u32) -> String {
+    if count > max {
+        format!("{max}+")
+    } else {
+        count.to_string()
+    }
+}
+
 /// Badge component
 #[component]
 pub fn Badge(
@@ -90,3 +144,121 @@ pub fn Badge(
         </span>
     }
 }
+
+/// Numeric or dot badge for notification and presence counts
+#[component]
+pub fn CountBadge(
+    /// Number to show; without it a dot badge is always shown
+    #[prop(into, optional)]
+    count: Option<Signal<u32>>,
+    /// Largest number shown before overflowing
+    #[prop(optional, default = DEFAULT_BADGE_MAX)]
+    max: u32,
+    /// Show a plain dot instead of the number
+    #[prop(optional)]
+    dot: bool,
+    /// Keep the badge visible at zero
+    #[prop(optional)]
+    show_zero: bool,
+    /// Pulse animation
+    #[prop(into, optional)]
+    pulse: Signal<bool>,
+    /// Optional CSS color
+    #[prop(into, optional)]
+    color: Option<String>,
+) -> impl IntoView {
+    let style = color.map(|c| format!("--badge-color: {c}"));
+
+    let visible = move || match count {
+        Some(count) => show_zero || count.get() > 0,
+        None => dot,
+    };
+
+    let class = move || {
+        let mut classes = vec!["ui-count-badge"];
+        if dot {
+            classes.push("ui-count-badge--dot");
+        }
+        if pulse.get() {
+            classes.push("ui-count-badge--pulse");
+        }
+        classes.join(" ")
+    };
+
+    view! {
+        {move || visible().then(|| {
+            let text = (!dot).then(|| format_count(count.map(|c| c.get()).unwrap_or(0), max));
+            let label = count.map(|c| c.get().to_string());
+            view! {
+                <span class=class style=style.clone() aria-label=label>
+                    {text}
+                </span>
+            }
+        })}
+    }
+}
+
+/// Wraps any content and pins a [`CountBadge`] to one of its corners
+#[component]
+pub fn BadgeAnchor(
+    /// Content the badge is attached to (icon, avatar, button)
+    children: Children,
+    /// Corner to pin the badge to
+    #[prop(optional)]
+    position: BadgePosition,
+    /// Number to show; without it a dot badge is always shown
+    #[prop(into, optional)]
+    count: Option<Signal<u32>>,
+    /// Largest number shown before overflowing
+    #[prop(optional, default = DEFAULT_BADGE_MAX)]
+    max: u32,
+    /// Show a plain dot instead of the number
+    #[prop(optional)]
+    dot: bool,
+    /// Keep the badge visible at zero
+    #[prop(optional)]
+    show_zero: bool,
+    /// Pulse animation
+    #[prop(into, optional)]
+    pulse: Signal<bool>,
+    /// Optional CSS color
+    #[prop(into, optional)]
+    color: Option<String>,
+) -> impl IntoView {
+    let position_class = format!(
+        "ui-badge-anchor__badge ui-badge-anchor__badge--{}",
+        position.class_suffix()
+    );
+    // The color variable is inherited by the badge inside
+    let style = color.map(|c| format!("--badge-color: {c}"));
+    let badge = match count {
+        Some(count) => view! {
+            <CountBadge count=count max=max dot=dot show_zero=show_zero pulse=pulse />
+        }
+        .into_any(),
+        None => view! {
+            <CountBadge max=max dot=dot show_zero=show_zero pulse=pulse />
+        }
+        .into_any(),
+    };
+
+    view! {
+        <span class="ui-badge-anchor">
+            {children()}
+            <span class=position_class style=style>{badge}</span>
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_count_overflow() {
+        assert_eq!(format_count(0, 99), "0");
+        assert_eq!(format_count(99, 99), "99");
+        assert_eq!(format_count(100, 99), "99+");
+        assert_eq!(format_count(12, 9), "9+");
+    }
+}
@@ -0,0 +1,340 @@
+//! Breadcrumbs Leptos Component
+//!
+//! A navigation trail for multi-level pages. Middle crumbs collapse into an
+//! ellipsis menu once the trail gets too long, either past `max_visible` or
+//! when it no longer fits its container. Markup carries schema.org
+//! `BreadcrumbList` microdata, so the trail is also readable as structured
+//! data.
+//!
+//! ## Props
+//!
+//! - `items` - The trail; the last crumb is the current page
+//! - `max_visible` - Collapse middle crumbs past this many (first and last always show)
+//! - `collapse_on_overflow` - Collapse when the trail doesn't fit (default: true)
+//! - `on_navigate` - Called with a crumb's `href` instead of following the link
+//! - `class` - Additional CSS classes
+//!
+//! Plain left clicks go to `on_navigate` when it is set, so a client-side
+//! router can push the route; modified clicks (new tab, etc.) keep the normal
+//! link behaviour.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! <Breadcrumbs items=vec![
+//!     Breadcrumb::link("Admin", "/admin"),
+//!     Breadcrumb::link("Projects", "/admin/projects"),
+//!     Breadcrumb::link("Spacebudz", "/admin/projects/spacebudz"),
+//!     Breadcrumb::new("Policies"),
+//! ] />
+//!
+//! // Routed, collapsing to "Admin / … / Policy 3 / Assets"
+//! <Breadcrumbs
+//!     items=trail
+//!     max_visible=3
+//!     on_navigate=move |href: String| router.push(&href)
+//! />
+//! ```
+
+use std::ops::Range;
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+use crate::i18n::use_locale;
+
+/// Crumbs kept visible while the trail overflows its container
+const OVERFLOW_VISIBLE: usize = 3;
+
+/// A breadcrumb trail entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct Breadcrumb {
+    /// Text shown
+    pub label: String,
+    /// Link target; `None` renders plain text
+    pub href: Option<String>,
+}
+
+impl Breadcrumb {
+    /// Crumb without a link (usually the current page)
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            href: None,
+        }
+    }
+
+    /// Crumb linking to `href`
+    pub fn link(label: impl Into<String>, href: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            href: Some(href.into()),
+        }
+    }
+}
+
+/// Crumbs hidden behind the ellipsis so at most `limit` stay visible
+///
+/// The first crumb and the last `limit - 1` always show; `limit` is at
+/// least 2.
+fn hidden_range(len: usize, limit: usize) -> Option<Range<usize>> {
+    let limit = limit.max(2);
+    (len > limit).then(|| 1..len - (limit - 1))
+}
+
+/// Breadcrumb navigation trail
+#[component]
+pub fn Breadcrumbs(
+    /// The trail; the last crumb is the current page
+    #[prop(into)]
+    items: Signal<Vec<Breadcrumb>>,
+    /// Collapse middle crumbs past this many
+    #[prop(optional)]
+    max_visible: Option<usize>,
+    /// Collapse when the trail doesn't fit
+    #[prop(optional, default = true)]
+    collapse_on_overflow: bool,
+    /// Called with the `href` of a clicked crumb
+    #[prop(into, optional)]
+    on_navigate: Option<Callback<String>>,
+    /// Additional CSS classes
+    #[prop(into, optional)]
+    class: String,
+) -> impl IntoView {
+    let locale = use_locale();
+    let nav_ref = NodeRef::<leptos::html::Nav>::new();
+    let list_ref = NodeRef::<leptos::html::Ol>::new();
+    let (menu_open, set_menu_open) = signal(false);
+
+    // Width the full trail needs, set while collapsed for overflow
+    let overflow_width = RwSignal::new(None::<i32>);
+
+    if collapse_on_overflow {
+        let check = move || {
+            let (Some(nav), Some(list)) = (nav_ref.get_untracked(), list_ref.get_untracked())
+            else {
+                return;
+            };
+            match overflow_width.get_untracked() {
+                Some(needed) if nav.client_width() >= needed => overflow_width.set(None),
+                None if list.scroll_width() > list.client_width() => {
+                    overflow_width.set(Some(list.scroll_width()));
+                }
+                _ => {}
+            }
+        };
+
+        // A new trail is measured fully expanded first
+        Effect::new(move |_| {
+            items.track();
+            overflow_width.set(None);
+            request_animation_frame(check);
+        });
+
+        let observer = StoredValue::new(None::<SendWrapper<web_sys::ResizeObserver>>);
+        Effect::new(move |_| {
+            let Some(nav) = nav_ref.get() else {
+                return;
+            };
+            let callback = Closure::wrap(Box::new(
+                move |_entries: js_sys::Array, _observer: web_sys::ResizeObserver| check(),
+            )
+                as Box<dyn FnMut(js_sys::Array, web_sys::ResizeObserver)>);
+
+            if let Ok(resize) = web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref()) {
+                let element: web_sys::Element = nav.into();
+                resize.observe(&element);
+                callback.forget(); // Lives as long as the observer
+                observer.set_value(Some(SendWrapper::new(resize)));
+            }
+        });
+        on_cleanup(move || {
+            if let Some(resize) = observer.try_update_value(Option::take).flatten() {
+                resize.disconnect();
+            }
+        });
+    }
+
+    // Close the menu on a click outside the trail
+    let outside = window_event_listener(leptos::ev::pointerdown, move |ev| {
+        if !menu_open.get_untracked() {
+            return;
+        }
+        let inside = nav_ref.get_untracked().is_some_and(|nav| {
+            let target = ev.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+            nav.contains(target.as_ref())
+        });
+        if !inside {
+            set_menu_open.set(false);
+        }
+    });
+    on_cleanup(move || outside.remove());
+
+    let hidden = Memo::new(move |_| {
+        let limit = match overflow_width.get() {
+            Some(_) => max_visible.map_or(OVERFLOW_VISIBLE, |max| max.min(OVERFLOW_VISIBLE)),
+            None => max_visible.unwrap_or(usize::MAX),
+        };
+        items.with(|items| hidden_range(items.len(), limit))
+    });
+
+    // Collapsing again closes a menu left open
+    Effect::new(move |_| {
+        if hidden.get().is_none() {
+            set_menu_open.set(false);
+        }
+    });
+
+    let navigate = move |ev: web_sys::MouseEvent, href: &str| {
+        set_menu_open.set(false);
+        let plain = ev.button() == 0 && !(ev.ctrl_key() || ev.meta_key() || ev.shift_key());
+        if let Some(cb) = on_navigate.filter(|_| plain) {
+            ev.prevent_default();
+            cb.run(href.to_string());
+        }
+    };
+
+    let root_class = if class.is_empty() {
+        "ui-breadcrumbs".to_string()
+    } else {
+        format!("ui-breadcrumbs {class}")
+    };
+
+    view! {
+        <nav
+            node_ref=nav_ref
+            class=root_class
+            class:ui-breadcrumbs--collapsed=move || hidden.get().is_some()
+            aria-label=move || locale.t_or("breadcrumbs.label", "Breadcrumb")
+            on:keydown=move |ev| {
+                if ev.key() == "Escape" {
+                    set_menu_open.set(false);
+                }
+            }
+        >
+            <ol
+                node_ref=list_ref
+                class="ui-breadcrumbs__list"
+                itemscope=true
+                itemtype="https://schema.org/BreadcrumbList"
+            >
+                {move || {
+                    let items = items.get();
+                    let count = items.len();
+                    let hidden = hidden.get();
+                    let crumb = move |(i, item): (usize, Breadcrumb)| {
+                        crumb_view(item, i + 1, i + 1 == count, navigate)
+                    };
+
+                    match hidden {
+                        None => items.into_iter().enumerate().map(crumb).collect_view().into_any(),
+                        Some(range) => {
+                            let mut items = items.into_iter().enumerate();
+                            let first = items.next().map(crumb);
+                            let collapsed: Vec<_> = items.by_ref().take(range.len()).map(crumb).collect();
+                            let rest = items.map(crumb).collect_view();
+                            view! {
+                                {first}
+                                <li class="ui-breadcrumbs__item ui-breadcrumbs__item--ellipsis">
+                                    <button
+                                        type="button"
+                                        class="ui-breadcrumbs__toggle"
+                                        aria-haspopup="true"
+                                        aria-expanded=move || menu_open.get().to_string()
+                                        aria-label=move || locale.t_or("breadcrumbs.show_hidden", "Show hidden breadcrumbs")
+                                        on:click=move |_| set_menu_open.update(|open| *open = !*open)
+                                    >
+                                        "…"
+                                    </button>
+                                    // Kept in the DOM so the microdata stays complete
+                                    <ol
+                                        class="ui-breadcrumbs__menu"
+                                        class:ui-breadcrumbs__menu--open=move || menu_open.get()
+                                    >
+                                        {collapsed}
+                                    </ol>
+                                </li>
+                                {rest}
+                            }
+                            .into_any()
+                        }
+                    }
+                }}
+            </ol>
+        </nav>
+    }
+}
+
+/// One crumb with its ListItem microdata
+fn crumb_view(
+    crumb: Breadcrumb,
+    position: usize,
+    current: bool,
+    navigate: impl Fn(web_sys::MouseEvent, &str) + Copy + Send + Sync + 'static,
+) -> impl IntoView {
+    let label = match crumb.href.filter(|_| !current) {
+        Some(href) => view! {
+            <a
+                class="ui-breadcrumbs__link"
+                href=href.clone()
+                itemprop="item"
+                on:click=move |ev| navigate(ev, &href)
+            >
+                <span itemprop="name">{crumb.label}</span>
+            </a>
+        }
+        .into_any(),
+        None => view! {
+            <span
+                class="ui-breadcrumbs__text"
+                aria-current=current.then_some("page")
+                itemprop="name"
+            >
+                {crumb.label}
+            </span>
+        }
+        .into_any(),
+    };
+
+    view! {
+        <li
+            class="ui-breadcrumbs__item"
+            itemprop="itemListElement"
+            itemscope=true
+            itemtype="https://schema.org/ListItem"
+        >
+            {label}
+            <meta itemprop="position" content=position.to_string() />
+        </li>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breadcrumb_constructors() {
+        assert_eq!(Breadcrumb::new("Home").href, None);
+        assert_eq!(Breadcrumb::link("Home", "/").href.as_deref(), Some("/"));
+    }
+
+    #[test]
+    fn short_trails_stay_expanded() {
+        assert_eq!(hidden_range(0, 3), None);
+        assert_eq!(hidden_range(3, 3), None);
+        assert_eq!(hidden_range(5, usize::MAX), None);
+    }
+
+    #[test]
+    fn hides_middle_keeping_first_and_last() {
+        // Home / … / Parent / Current
+        assert_eq!(hidden_range(5, 3), Some(1..3));
+        assert_eq!(hidden_range(6, 4), Some(1..3));
+        // Limit below 2 still keeps the first and the current page
+        assert_eq!(hidden_range(4, 0), Some(1..3));
+        assert_eq!(hidden_range(4, 2), Some(1..3));
+    }
+}
@@ -28,6 +28,8 @@
 
 use leptos::prelude::*;
 
+use crate::density::density_attr;
+
 /// Button visual variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ButtonVariant {
@@ -109,6 +111,8 @@ pub fn Button(
         classes.join(" ")
     };
 
+    let density = density_attr();
+
     let is_disabled = move || {
         disabled.map(|d| d.get()).unwrap_or(false) || loading.map(|l| l.get()).unwrap_or(false)
     };
@@ -124,6 +128,7 @@ pub fn Button(
     view! {
         <button
             class=button_class
+            data-density=density
             disabled=is_disabled
             on:click=handle_click
         >
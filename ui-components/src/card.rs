@@ -1,6 +1,6 @@
 //! Card Leptos Component
 //!
-//! A generic card container with optional accent bar and slot support.
+//! A generic card container with optional accent bar, media and slot support.
 //!
 //! ## Props
 //!
@@ -8,8 +8,22 @@
 //! - `accent_color` - Optional accent color for top bar
 //! - `header` - Optional header slot content
 //! - `footer` - Optional footer slot content
+//! - `media` - Optional image URL shown above or beside the content
+//! - `media_alt` - Alt text for the media image (empty = decorative)
+//! - `media_placement` - Where the media sits (Top, Side)
+//! - `hoverable` - Lift the card with a shadow on hover
+//! - `href` - Make the whole card a link
+//! - `on_click` - Make the whole card a button
+//! - `label` - Accessible name for a clickable card
+//! - `loading` - Show skeleton placeholders instead of media and body
 //! - `children` - Main body content
 //!
+//! Side media stacks above the content on narrow screens.
+//!
+//! A clickable card (`href` or `on_click`) is a single interactive element:
+//! it is focusable, activates with Enter (and Space for `on_click`), and
+//! should not contain other links or buttons.
+//!
 //! ## Usage
 //!
 //! ```ignore
@@ -24,10 +38,45 @@
 //! >
 //!     <p>"Body content"</p>
 //! </Card>
+//!
+//! // Feature card linking elsewhere
+//! <Card
+//!     media="/img/staking.png"
+//!     media_placement=CardMediaPlacement::Side
+//!     href="/staking"
+//!     hoverable=true
+//! >
+//!     <h3>"Staking"</h3>
+//!     <p>"Delegate to earn rewards"</p>
+//! </Card>
+//!
+//! // Skeleton while data loads
+//! <Card media=image_url loading=is_loading>
+//!     <p>{description}</p>
+//! </Card>
 //! ```
 
 use leptos::prelude::*;
 
+/// Where a card's media image sits relative to its content
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CardMediaPlacement {
+    /// Full-width image above the content
+    #[default]
+    Top,
+    /// Image beside the content, stacking on narrow screens
+    Side,
+}
+
+impl CardMediaPlacement {
+    fn class_suffix(&self) -> &'static str {
+        match self {
+            Self::Top => "top",
+            Self::Side => "side",
+        }
+    }
+}
+
 /// Generic card container component
 #[component]
 pub fn Card(
@@ -43,36 +92,205 @@ pub fn Card(
     /// Footer slot content
     #[prop(optional)]
     footer: Option<Children>,
+    /// Image URL for the media slot
+    #[prop(into, optional)]
+    media: Option<String>,
+    /// Alt text for the media image (empty = decorative)
+    #[prop(into, optional)]
+    media_alt: String,
+    /// Where the media sits
+    #[prop(optional)]
+    media_placement: CardMediaPlacement,
+    /// Lift the card with a shadow on hover
+    #[prop(optional)]
+    hoverable: bool,
+    /// Make the whole card a link to this URL
+    #[prop(into, optional)]
+    href: Option<String>,
+    /// Make the whole card a button
+    #[prop(into, optional)]
+    on_click: Option<Callback<()>>,
+    /// Accessible name for a clickable card
+    #[prop(into, optional)]
+    label: Option<String>,
+    /// Show skeleton placeholders instead of media and body
+    #[prop(into, optional)]
+    loading: Option<Signal<bool>>,
     /// Main body content
     children: Children,
 ) -> impl IntoView {
-    let card_class = if class.is_empty() {
-        "ui-card".to_string()
-    } else {
-        format!("ui-card {class}")
+    let is_loading = move || loading.map(|l| l.get()).unwrap_or(false);
+    let clickable = href.is_some() || on_click.is_some();
+    let has_side_media = media.is_some() && media_placement == CardMediaPlacement::Side;
+
+    let base_class = card_class(
+        &class,
+        hoverable,
+        clickable,
+        media.as_ref().map(|_| media_placement),
+    );
+    let root_class = move || {
+        if is_loading() {
+            format!("{base_class} ui-card--loading")
+        } else {
+            base_class.clone()
+        }
     };
 
-    view! {
-        <div class=card_class>
-            {accent_color.map(|color| view! {
-                <div class="ui-card__accent" style=format!("background-color: {color}")></div>
-            })}
+    let media_view = media.map(|src| {
+        view! {
+            <div class="ui-card__media">
+                <img
+                    src=src
+                    alt=media_alt
+                    loading="lazy"
+                    hidden=is_loading
+                />
+                <Show when=is_loading>
+                    <div class="ui-skeleton ui-skeleton--rect ui-card__media-skeleton"></div>
+                </Show>
+            </div>
+        }
+    });
 
-            {header.map(|h| view! {
-                <div class="ui-card__header">
-                    {h()}
-                </div>
-            })}
+    let content = view! {
+        {header.map(|h| view! {
+            <div class="ui-card__header">
+                {h()}
+            </div>
+        })}
 
-            <div class="ui-card__body">
+        <div class="ui-card__body">
+            <Show when=is_loading>
+                <div class="ui-skeleton-text ui-card__skeleton">
+                    <div class="ui-skeleton ui-skeleton--text" style:width="70%"></div>
+                    <div class="ui-skeleton ui-skeleton--text" style:width="90%"></div>
+                    <div class="ui-skeleton ui-skeleton--text" style:width="60%"></div>
+                </div>
+            </Show>
+            <div class="ui-card__content" hidden=is_loading>
                 {children()}
             </div>
+        </div>
+
+        {footer.map(|f| view! {
+            <div class="ui-card__footer">
+                {f()}
+            </div>
+        })}
+    };
+
+    let inner = view! {
+        {accent_color.map(|color| view! {
+            <div class="ui-card__accent" style=format!("background-color: {color}")></div>
+        })}
+
+        {media_view}
 
-            {footer.map(|f| view! {
-                <div class="ui-card__footer">
-                    {f()}
+        {if has_side_media {
+            view! { <div class="ui-card__main">{content}</div> }.into_any()
+        } else {
+            content.into_any()
+        }}
+    };
+
+    let handle_click = move |_| {
+        if let Some(cb) = on_click {
+            cb.run(());
+        }
+    };
+
+    match href {
+        Some(href) => view! {
+            <a
+                class=root_class
+                href=href
+                aria-label=label
+                aria-busy=move || is_loading().then_some("true")
+                on:click=handle_click
+            >
+                {inner}
+            </a>
+        }
+        .into_any(),
+        None if on_click.is_some() => {
+            let handle_keydown = move |ev: leptos::ev::KeyboardEvent| {
+                if matches!(ev.key().as_str(), "Enter" | " ") {
+                    ev.prevent_default();
+                    if let Some(cb) = on_click {
+                        cb.run(());
+                    }
+                }
+            };
+            view! {
+                <div
+                    class=root_class
+                    role="button"
+                    tabindex="0"
+                    aria-label=label
+                    aria-busy=move || is_loading().then_some("true")
+                    on:click=handle_click
+                    on:keydown=handle_keydown
+                >
+                    {inner}
                 </div>
-            })}
-        </div>
+            }
+            .into_any()
+        }
+        None => view! {
+            <div
+                class=root_class
+                aria-busy=move || is_loading().then_some("true")
+            >
+                {inner}
+            </div>
+        }
+        .into_any(),
+    }
+}
+
+/// Static class list for a card
+fn card_class(
+    extra: &str,
+    hoverable: bool,
+    clickable: bool,
+    media: Option<CardMediaPlacement>,
+) -> String {
+    let mut classes = vec!["ui-card".to_string()];
+    if let Some(placement) = media {
+        classes.push(format!("ui-card--media-{}", placement.class_suffix()));
+    }
+    if hoverable {
+        classes.push("ui-card--hoverable".to_string());
+    }
+    if clickable {
+        classes.push("ui-card--clickable".to_string());
+    }
+    if !extra.is_empty() {
+        classes.push(extra.to_string());
+    }
+    classes.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_class_plain() {
+        assert_eq!(card_class("", false, false, None), "ui-card");
+        assert_eq!(card_class("extra", false, false, None), "ui-card extra");
+    }
+
+    #[test]
+    fn test_card_class_modifiers() {
+        assert_eq!(
+            card_class("", true, true, Some(CardMediaPlacement::Side)),
+            "ui-card ui-card--media-side ui-card--hoverable ui-card--clickable"
+        );
+        assert_eq!(
+            card_class("", false, false, Some(CardMediaPlacement::Top)),
+            "ui-card ui-card--media-top"
+        );
     }
 }
@@ -1,14 +1,25 @@
-//! ColorSwatch Leptos Component
+//! ColorSwatch Leptos Components
 //!
-//! A small color sample display, useful for showing tier colors, role colors, etc.
+//! A small color sample display, useful for showing tier colors, role colors, etc.,
+//! and a `PalettePicker` grid of swatches for choosing a color.
 //!
-//! ## Props
+//! ## Props (ColorSwatch)
 //!
 //! - `color` - CSS color value
 //! - `label` - Optional label text
 //! - `size` - Swatch size (Sm, Md, Lg)
 //! - `show_hex` - Whether to show the hex value
 //!
+//! ## Props (PalettePicker)
+//!
+//! - `value` - Currently selected hex color
+//! - `on_change` - Callback with the chosen color (lowercase `#rrggbb`)
+//! - `palette` - Preset colors (defaults to [`DEFAULT_PALETTE`])
+//! - `allow_custom` - Show the custom hex/color input (default true)
+//! - `recent_key` - Settings key recent colors are persisted under
+//! - `max_recent` - Recent colors remembered (0 disables the row)
+//! - `size` - Swatch size
+//!
 //! ## Usage
 //!
 //! ```ignore
@@ -23,9 +34,35 @@
 //!
 //! // Different sizes
 //! <ColorSwatch color="#17a2b8" size=SwatchSize::Lg />
+//!
+//! // Palette picker
+//! let (color, set_color) = signal("#3b82f6".to_string());
+//! <PalettePicker
+//!     value=color
+//!     on_change=Callback::new(move |c| set_color.set(c))
+//! />
 //! ```
 
+use crate::i18n::use_locale;
+use crate::use_setting::use_setting;
 use leptos::prelude::*;
+use ui_core::settings::Settings;
+
+/// Settings namespace recent palette colors are stored under
+const SETTINGS_NAMESPACE: &str = "palette-picker";
+
+/// Default settings key for recent colors
+pub const DEFAULT_RECENT_KEY: &str = "recent";
+
+/// Default number of recent colors remembered
+pub const DEFAULT_MAX_RECENT: usize = 8;
+
+/// Preset colors shown when no palette is given
+pub const DEFAULT_PALETTE: &[&str] = &[
+    "#f44336", "#e91e63", "#9c27b0", "#673ab7", "#3f51b5", "#3b82f6", "#03a9f4", "#00bcd4",
+    "#009688", "#4caf50", "#8bc34a", "#cddc39", "#ffeb3b", "#ffc107", "#ff9800", "#ff5722",
+    "#795548", "#9e9e9e", "#607d8b", "#ffffff", "#888888", "#2a2a4e", "#1a1a2e", "#000000",
+];
 
 /// Swatch size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -92,3 +129,254 @@ pub fn ColorSwatch(
         </div>
     }
 }
+
+/// Normalize a hex color to lowercase `#rrggbb`
+///
+/// Accepts 3 or 6 hex digits with or without the leading `#`.
+pub fn normalize_hex(input: &str) -> Option<String> {
+    let digits = input.trim();
+    let digits = digits.strip_prefix('#').unwrap_or(digits);
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let expanded: String = match digits.len() {
+        3 => digits.chars().flat_map(|c| [c, c]).collect(),
+        6 => digits.to_string(),
+        _ => return None,
+    };
+    Some(format!("#{}", expanded.to_ascii_lowercase()))
+}
+
+/// Move `color` to the front of the recent list, keeping at most `max`
+fn with_recent(mut recent: Vec<String>, color: &str, max: usize) -> Vec<String> {
+    recent.retain(|c| c != color);
+    recent.insert(0, color.to_string());
+    recent.truncate(max);
+    recent
+}
+
+/// Whether two colors name the same hex value
+fn same_color(a: &str, b: &str) -> bool {
+    match (normalize_hex(a), normalize_hex(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.eq_ignore_ascii_case(b),
+    }
+}
+
+/// Palette picker: a swatch grid with custom input and recent colors
+#[component]
+pub fn PalettePicker(
+    /// Currently selected color
+    #[prop(into)]
+    value: Signal<String>,
+    /// Callback with the chosen color
+    #[prop(into)]
+    on_change: Callback<String>,
+    /// Preset colors
+    #[prop(into, optional)]
+    palette: Option<Vec<String>>,
+    /// Show the custom color input
+    #[prop(optional, default = true)]
+    allow_custom: bool,
+    /// Settings key recent colors are persisted under
+    #[prop(optional, default = DEFAULT_RECENT_KEY)]
+    recent_key: &'static str,
+    /// Recent colors remembered (0 disables)
+    #[prop(optional, default = DEFAULT_MAX_RECENT)]
+    max_recent: usize,
+    /// Swatch size
+    #[prop(optional, default = SwatchSize::Md)]
+    size: SwatchSize,
+    /// Additional class
+    #[prop(into, optional)]
+    class: Option<String>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let palette =
+        palette.unwrap_or_else(|| DEFAULT_PALETTE.iter().map(|c| c.to_string()).collect());
+    let recent = use_setting(
+        &Settings::new(SETTINGS_NAMESPACE),
+        recent_key,
+        Vec::<String>::new(),
+    );
+
+    let picker_class = {
+        let mut classes = vec![
+            "ui-palette-picker".to_string(),
+            format!("ui-palette-picker--{}", size.class_suffix()),
+        ];
+        if let Some(c) = class {
+            classes.push(c);
+        }
+        classes.join(" ")
+    };
+
+    let select = move |color: String| {
+        let color = normalize_hex(&color).unwrap_or(color);
+        if max_recent > 0 {
+            recent.set(with_recent(
+                recent.signal().get_untracked(),
+                &color,
+                max_recent,
+            ));
+        }
+        on_change.run(color);
+    };
+
+    let swatch = move |color: String| {
+        let selected = {
+            let color = color.clone();
+            move || same_color(&value.get(), &color)
+        };
+        let style = format!("background-color: {color};");
+        let title = color.clone();
+        view! {
+            <button
+                type="button"
+                class="ui-palette-picker__swatch"
+                class:ui-palette-picker__swatch--selected=selected.clone()
+                style=style
+                title=title.clone()
+                aria-label=title
+                aria-pressed=move || selected().to_string()
+                on:click=move |_| select(color.clone())
+            ></button>
+        }
+    };
+
+    // Custom input: a draft hex field plus the native color picker
+    let draft = RwSignal::new(String::new());
+    let invalid = RwSignal::new(false);
+    let apply_draft = move || {
+        let text = draft.get_untracked();
+        if text.trim().is_empty() {
+            return;
+        }
+        match normalize_hex(&text) {
+            Some(color) => {
+                invalid.set(false);
+                draft.set(String::new());
+                select(color);
+            }
+            None => invalid.set(true),
+        }
+    };
+
+    view! {
+        <div class=picker_class>
+            <div class="ui-palette-picker__grid" role="group">
+                {palette.into_iter().map(swatch).collect_view()}
+            </div>
+
+            {(max_recent > 0).then(|| view! {
+                <Show when=move || !recent.get().is_empty()>
+                    <div class="ui-palette-picker__recent">
+                        <span class="ui-palette-picker__heading">
+                            {move || locale.t_or("palette_picker.recent", "Recent")}
+                        </span>
+                        <div class="ui-palette-picker__grid" role="group">
+                            {move || recent.get().into_iter().map(swatch).collect_view()}
+                        </div>
+                    </div>
+                </Show>
+            })}
+
+            {allow_custom.then(|| view! {
+                <div class="ui-palette-picker__custom">
+                    <span class="ui-palette-picker__heading">
+                        {move || locale.t_or("palette_picker.custom", "Custom")}
+                    </span>
+                    <div class="ui-palette-picker__custom-row">
+                        <input
+                            type="color"
+                            class="ui-palette-picker__native"
+                            prop:value=move || normalize_hex(&value.get()).unwrap_or_else(|| "#000000".to_string())
+                            aria-label=move || locale.t_or("palette_picker.custom", "Custom")
+                            on:change=move |ev| select(event_target::<web_sys::HtmlInputElement>(&ev).value())
+                        />
+                        <input
+                            type="text"
+                            class="ui-palette-picker__hex"
+                            class:ui-palette-picker__hex--invalid=move || invalid.get()
+                            placeholder="#rrggbb"
+                            maxlength="7"
+                            spellcheck="false"
+                            prop:value=move || draft.get()
+                            on:input=move |ev| {
+                                invalid.set(false);
+                                draft.set(event_target::<web_sys::HtmlInputElement>(&ev).value());
+                            }
+                            on:keydown=move |ev: web_sys::KeyboardEvent| {
+                                if ev.key() == "Enter" {
+                                    apply_draft();
+                                }
+                            }
+                            on:blur=move |_| apply_draft()
+                        />
+                    </div>
+                    <Show when=move || invalid.get()>
+                        <span class="ui-palette-picker__error">
+                            {move || locale.t_or("palette_picker.invalid", "Enter a hex color like #1e90ff")}
+                        </span>
+                    </Show>
+                </div>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_accepts_short_and_long_forms() {
+        assert_eq!(normalize_hex("#1E90FF").as_deref(), Some("#1e90ff"));
+        assert_eq!(normalize_hex("1e90ff").as_deref(), Some("#1e90ff"));
+        assert_eq!(normalize_hex("#fA0").as_deref(), Some("#ffaa00"));
+        assert_eq!(normalize_hex("  abc ").as_deref(), Some("#aabbcc"));
+    }
+
+    #[test]
+    fn normalize_rejects_invalid() {
+        assert_eq!(normalize_hex(""), None);
+        assert_eq!(normalize_hex("#"), None);
+        assert_eq!(normalize_hex("#12345"), None);
+        assert_eq!(normalize_hex("#gggggg"), None);
+        assert_eq!(normalize_hex("#1e90ff00"), None);
+        assert_eq!(normalize_hex("blue"), None);
+    }
+
+    #[test]
+    fn recent_moves_to_front_without_duplicates() {
+        let recent = vec!["#111111".to_string(), "#222222".to_string()];
+        assert_eq!(
+            with_recent(recent, "#222222", 8),
+            vec!["#222222".to_string(), "#111111".to_string()]
+        );
+    }
+
+    #[test]
+    fn recent_is_capped() {
+        let recent = vec!["#111111".to_string(), "#222222".to_string()];
+        assert_eq!(
+            with_recent(recent, "#333333", 2),
+            vec!["#333333".to_string(), "#111111".to_string()]
+        );
+    }
+
+    #[test]
+    fn same_color_ignores_case_and_form() {
+        assert!(same_color("#FFF", "#ffffff"));
+        assert!(same_color("#1e90ff", "1E90FF"));
+        assert!(!same_color("#1e90ff", "#1e90fe"));
+        assert!(same_color("red", "RED"));
+    }
+
+    #[test]
+    fn default_palette_is_normalized() {
+        for color in DEFAULT_PALETTE {
+            assert_eq!(normalize_hex(color).as_deref(), Some(*color));
+        }
+    }
+}
@@ -0,0 +1,373 @@
+//! Confirm Dialog Component
+//!
+//! Promise-style confirmations in place of `window.confirm`. `confirm()`
+//! opens a themed dialog and resolves to the user's answer, so destructive
+//! actions (reset game, disconnect wallet) read top to bottom:
+//!
+//! ```ignore
+//! let dialog = use_confirm();
+//!
+//! spawn_local(async move {
+//!     let reset = dialog
+//!         .confirm(
+//!             ConfirmOptions::new("Reset game?")
+//!                 .body("Scores and progress for everyone will be lost.")
+//!                 .confirm_label("Reset")
+//!                 .destructive(),
+//!         )
+//!         .await;
+//!     if reset {
+//!         reset_game();
+//!     }
+//! });
+//! ```
+//!
+//! The dialog sits above any open `Modal` or `ModalStack`, so it can be
+//! raised from inside one. Requests made while a dialog is showing queue up
+//! behind it. Escape, the backdrop and the cancel button answer `false`;
+//! focus starts on the safe choice (cancel for destructive dialogs), stays
+//! inside the dialog and goes back where it was afterwards.
+//!
+//! ## Setup
+//!
+//! ```ignore
+//! <ConfirmProvider>
+//!     <App />
+//! </ConfirmProvider>
+//! ```
+//!
+//! [`ConfirmDialog`] is also usable on its own for a controlled dialog.
+
+use crate::i18n::use_locale;
+use futures::channel::oneshot;
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use std::collections::VecDeque;
+use wasm_bindgen::JsCast;
+
+/// What a confirmation dialog says
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConfirmOptions {
+    /// Question asked
+    pub title: String,
+    /// Optional explanation below the title
+    pub body: Option<String>,
+    /// Confirm button text (default: "OK")
+    pub confirm_label: Option<String>,
+    /// Cancel button text (default: "Cancel")
+    pub cancel_label: Option<String>,
+    /// Style the confirm button as dangerous and focus cancel first
+    pub destructive: bool,
+}
+
+impl ConfirmOptions {
+    /// Dialog asking `title`
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the explanation
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set the confirm button text
+    pub fn confirm_label(mut self, label: impl Into<String>) -> Self {
+        self.confirm_label = Some(label.into());
+        self
+    }
+
+    /// Set the cancel button text
+    pub fn cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = Some(label.into());
+        self
+    }
+
+    /// Mark the action as destructive
+    pub fn destructive(mut self) -> Self {
+        self.destructive = true;
+        self
+    }
+}
+
+/// A dialog waiting for an answer
+struct PendingConfirm {
+    id: u64,
+    options: ConfirmOptions,
+    alert: bool,
+    reply: oneshot::Sender<bool>,
+}
+
+/// Context for asking the user to confirm
+#[derive(Clone, Copy)]
+pub struct ConfirmContext {
+    queue: RwSignal<VecDeque<PendingConfirm>>,
+    next_id: StoredValue<u64>,
+}
+
+impl ConfirmContext {
+    /// Create a new confirm context
+    pub fn new() -> Self {
+        Self {
+            queue: RwSignal::new(VecDeque::new()),
+            next_id: StoredValue::new(0),
+        }
+    }
+
+    /// Ask the user to confirm; `false` if they cancel or the provider goes away
+    pub async fn confirm(&self, options: ConfirmOptions) -> bool {
+        self.ask(options, false).await
+    }
+
+    /// Show a message with a single acknowledge button
+    pub async fn alert(&self, options: ConfirmOptions) {
+        self.ask(options, true).await;
+    }
+
+    fn ask(&self, options: ConfirmOptions, alert: bool) -> oneshot::Receiver<bool> {
+        let (reply, answer) = oneshot::channel();
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        self.queue.update(|queue| {
+            queue.push_back(PendingConfirm {
+                id,
+                options,
+                alert,
+                reply,
+            })
+        });
+        answer
+    }
+
+    /// Answer dialog `id`, showing the next one queued
+    fn answer(&self, id: u64, confirmed: bool) {
+        let is_current = self
+            .queue
+            .with_untracked(|queue| queue.front().is_some_and(|p| p.id == id));
+        if !is_current {
+            return;
+        }
+        let mut answered = None;
+        self.queue.update(|queue| answered = queue.pop_front());
+        if let Some(pending) = answered {
+            let _ = pending.reply.send(confirmed);
+        }
+    }
+}
+
+impl Default for ConfirmContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get the confirm context from the current scope
+///
+/// # Panics
+///
+/// Panics if called outside of a `ConfirmProvider`
+pub fn use_confirm() -> ConfirmContext {
+    expect_context::<ConfirmContext>()
+}
+
+/// Try to get the confirm context, returning None if not in a ConfirmProvider
+pub fn try_use_confirm() -> Option<ConfirmContext> {
+    use_context::<ConfirmContext>()
+}
+
+/// Provider that renders dialogs requested through [`use_confirm`]
+#[component]
+pub fn ConfirmProvider(children: Children) -> impl IntoView {
+    let ctx = ConfirmContext::new();
+    provide_context(ctx);
+
+    let current = Memo::new(move |_| {
+        ctx.queue
+            .with(|queue| queue.front().map(|p| (p.id, p.options.clone(), p.alert)))
+    });
+
+    view! {
+        {children()}
+        {move || {
+            current.get().map(|(id, options, alert)| {
+                view! {
+                    <ConfirmDialog
+                        options=options
+                        alert=alert
+                        on_answer=Callback::new(move |confirmed| ctx.answer(id, confirmed))
+                    />
+                }
+            })
+        }}
+    }
+}
+
+/// Unique id prefix for a dialog's title and body
+fn dialog_id() -> String {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static COUNTER: AtomicU64 = AtomicU64::new(1);
+    format!("ui-confirm-{}", COUNTER.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Confirmation dialog, shown while mounted
+#[component]
+pub fn ConfirmDialog(
+    /// Title, body, labels and styling
+    options: ConfirmOptions,
+    /// Only show the confirm button
+    #[prop(optional)]
+    alert: bool,
+    /// Called with `true` on confirm, `false` on cancel
+    #[prop(into)]
+    on_answer: Callback<bool>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let confirm_ref = NodeRef::<leptos::html::Button>::new();
+    let cancel_ref = NodeRef::<leptos::html::Button>::new();
+    let destructive = options.destructive;
+
+    // Start on the safe choice; restore focus when the dialog goes away
+    let previous_focus = StoredValue::new(
+        document()
+            .active_element()
+            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok())
+            .map(SendWrapper::new),
+    );
+    Effect::new(move |_| {
+        let initial = if destructive && !alert {
+            cancel_ref.get()
+        } else {
+            confirm_ref.get()
+        };
+        if let Some(button) = initial {
+            let _ = button.focus();
+        }
+    });
+    on_cleanup(move || {
+        if let Some(el) = previous_focus.try_update_value(Option::take).flatten() {
+            let _ = el.focus();
+        }
+    });
+
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| match ev.key().as_str() {
+        "Escape" => {
+            ev.prevent_default();
+            on_answer.run(false);
+        }
+        // Keep focus on the two buttons
+        "Tab" if !alert => {
+            ev.prevent_default();
+            let on_confirm = confirm_ref.get_untracked().is_some_and(|button| {
+                document().active_element().as_ref() == Some(button.as_ref())
+            });
+            let next = if on_confirm {
+                cancel_ref.get_untracked()
+            } else {
+                confirm_ref.get_untracked()
+            };
+            if let Some(button) = next {
+                let _ = button.focus();
+            }
+        }
+        "Tab" => ev.prevent_default(),
+        _ => {}
+    };
+
+    let id = dialog_id();
+    let title_id = format!("{id}-title");
+    let body_id = options.body.as_ref().map(|_| format!("{id}-body"));
+
+    let confirm_label = options
+        .confirm_label
+        .clone()
+        .unwrap_or_else(|| locale.t_or("confirm.ok", "OK"));
+    let cancel_label = options
+        .cancel_label
+        .clone()
+        .unwrap_or_else(|| locale.t_or("confirm.cancel", "Cancel"));
+    let confirm_class = if destructive {
+        "ui-button ui-button--danger ui-button--md"
+    } else {
+        "ui-button ui-button--primary ui-button--md"
+    };
+
+    view! {
+        <div class="ui-confirm-backdrop" on:click=move |_| on_answer.run(false)>
+            <div
+                class="ui-confirm"
+                class:ui-confirm--destructive=destructive
+                role="alertdialog"
+                aria-modal="true"
+                aria-labelledby=title_id.clone()
+                aria-describedby=body_id.clone()
+                on:click=|ev: web_sys::MouseEvent| ev.stop_propagation()
+                on:keydown=handle_keydown
+            >
+                <h2 class="ui-confirm__title" id=title_id>{options.title}</h2>
+                {options.body.map(|body| view! {
+                    <p class="ui-confirm__body" id=body_id>{body}</p>
+                })}
+                <div class="ui-confirm__actions">
+                    {(!alert).then(|| view! {
+                        <button
+                            node_ref=cancel_ref
+                            type="button"
+                            class="ui-button ui-button--secondary ui-button--md"
+                            on:click=move |_| on_answer.run(false)
+                        >
+                            {cancel_label}
+                        </button>
+                    })}
+                    <button
+                        node_ref=confirm_ref
+                        type="button"
+                        class=confirm_class
+                        on:click=move |_| on_answer.run(true)
+                    >
+                        {confirm_label}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_builder() {
+        let options = ConfirmOptions::new("Disconnect wallet?")
+            .body("You can reconnect at any time.")
+            .confirm_label("Disconnect")
+            .cancel_label("Stay connected")
+            .destructive();
+
+        assert_eq!(options.title, "Disconnect wallet?");
+        assert_eq!(
+            options.body.as_deref(),
+            Some("You can reconnect at any time.")
+        );
+        assert_eq!(options.confirm_label.as_deref(), Some("Disconnect"));
+        assert_eq!(options.cancel_label.as_deref(), Some("Stay connected"));
+        assert!(options.destructive);
+    }
+
+    #[test]
+    fn options_defaults() {
+        let options = ConfirmOptions::new("Continue?");
+        assert_eq!(options.body, None);
+        assert_eq!(options.confirm_label, None);
+        assert!(!options.destructive);
+    }
+
+    #[test]
+    fn dialog_ids_are_unique() {
+        assert_ne!(dialog_id(), dialog_id());
+    }
+}
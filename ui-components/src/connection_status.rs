@@ -5,9 +5,11 @@
 //!
 //! ## Props
 //!
-//! - `status` - Connection state: Connected, Connecting, Disconnected, Error
+//! - `status` - Connection state: Connected, Connecting, Reconnecting, Disconnected, Error
 //! - `show_text` - Whether to show status text (default: true)
 //! - `on_reconnect` - Callback when user clicks to reconnect (only when disconnected/error)
+//! - `latency_ms` - Heartbeat round-trip time, shown while connected
+//! - `last_disconnect` - Reason for the last disconnect, shown as a tooltip
 //!
 //! ## Usage
 //!
@@ -17,8 +19,38 @@
 //!     on_reconnect=move |_| { reconnect(); }
 //! />
 //! ```
+//!
+//! With a ui-flow connection, feed the extra props from its callbacks:
+//!
+//! ```ignore
+//! let config = ReconnectConfig::default();
+//! FlowConnection::builder()
+//!     .reconnect_config(config.clone())
+//!     .on_status(move |s| status.set(match s {
+//!         FlowStatus::Reconnecting { attempt } => {
+//!             ConnectionState::reconnecting(attempt, config.delay_for(attempt))
+//!         }
+//!         // ...
+//!     }))
+//!     .on_latency(move |ms| latency.set(Some(ms)))
+//!     .on_close(move |info| last_disconnect.set(Some(info.to_string())))
+//!     .connect()?;
+//!
+//! view! {
+//!     <ConnectionStatus status=status latency_ms=latency last_disconnect=last_disconnect />
+//! }
+//! ```
 
+use crate::i18n::use_locale;
 use leptos::prelude::*;
+use std::time::Duration;
+use ui_core::clock::local_now_ms;
+
+/// Latency at or above which the reading is flagged as slow
+pub const SLOW_LATENCY_MS: u32 = 300;
+
+/// How often the reconnect countdown refreshes
+const COUNTDOWN_TICK: Duration = Duration::from_millis(250);
 
 /// Connection state values
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -27,14 +59,30 @@ pub enum ConnectionState {
     Disconnected,
     Connecting,
     Connected,
+    /// Waiting to retry after losing the connection
+    Reconnecting {
+        /// Retry attempt number (1-based)
+        attempt: u32,
+        /// Wall-clock time of the next attempt, in ms since the epoch
+        retry_at_ms: u64,
+    },
     Error,
 }
 
 impl ConnectionState {
+    /// Reconnecting state for an attempt scheduled `delay_ms` from now
+    pub fn reconnecting(attempt: u32, delay_ms: u32) -> Self {
+        Self::Reconnecting {
+            attempt,
+            retry_at_ms: local_now_ms() + u64::from(delay_ms),
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Connected => "connected",
             Self::Connecting => "connecting",
+            Self::Reconnecting { .. } => "reconnecting",
             Self::Disconnected => "disconnected",
             Self::Error => "error",
         }
@@ -44,14 +92,38 @@ impl ConnectionState {
         match self {
             Self::Connected => "Connected",
             Self::Connecting => "Connecting...",
+            Self::Reconnecting { .. } => "Reconnecting...",
             Self::Disconnected => "Disconnected",
             Self::Error => "Connection Error",
         }
     }
 
+    /// i18n message key for the status text
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            Self::Connected => "connection_status.connected",
+            Self::Connecting => "connection_status.connecting",
+            Self::Reconnecting { .. } => "connection_status.reconnecting",
+            Self::Disconnected => "connection_status.disconnected",
+            Self::Error => "connection_status.error",
+        }
+    }
+
     pub fn is_reconnectable(&self) -> bool {
         matches!(self, Self::Disconnected | Self::Error)
     }
+
+    /// Whole seconds until the next reconnect attempt, rounded up
+    ///
+    /// `None` unless reconnecting; `Some(0)` once the attempt is due.
+    pub fn retry_in_secs(&self, now_ms: u64) -> Option<u64> {
+        match self {
+            Self::Reconnecting { retry_at_ms, .. } => {
+                Some(retry_at_ms.saturating_sub(now_ms).div_ceil(1000))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Connection status component
@@ -66,7 +138,28 @@ pub fn ConnectionStatus(
     /// Reconnect callback (called when clicking in disconnected/error state)
     #[prop(into, optional)]
     on_reconnect: Option<Callback<()>>,
+    /// Heartbeat round-trip time in ms, shown while connected
+    #[prop(into, optional)]
+    latency_ms: Option<Signal<Option<u32>>>,
+    /// Reason for the last disconnect, shown as a tooltip
+    #[prop(into, optional)]
+    last_disconnect: Option<Signal<Option<String>>>,
 ) -> impl IntoView {
+    let locale = use_locale();
+
+    // Re-renders the countdown; only ticks while reconnecting
+    let tick = RwSignal::new(());
+    if let Ok(handle) = set_interval_with_handle(
+        move || {
+            if matches!(status.get_untracked(), ConnectionState::Reconnecting { .. }) {
+                tick.set(());
+            }
+        },
+        COUNTDOWN_TICK,
+    ) {
+        on_cleanup(move || handle.clear());
+    }
+
     let status_class = move || {
         let s = status.get();
         let clickable = s.is_reconnectable() && on_reconnect.is_some();
@@ -75,6 +168,7 @@ pub fn ConnectionStatus(
             match s {
                 ConnectionState::Connected => "connection-status--connected",
                 ConnectionState::Connecting => "connection-status--connecting",
+                ConnectionState::Reconnecting { .. } => "connection-status--reconnecting",
                 ConnectionState::Disconnected => "connection-status--disconnected",
                 ConnectionState::Error => "connection-status--error",
             },
@@ -93,8 +187,51 @@ pub fn ConnectionStatus(
         }
     };
 
+    let tooltip = move || {
+        last_disconnect
+            .and_then(|reason| reason.get())
+            .map(|reason| {
+                locale.t_or_with(
+                    "connection_status.last_disconnect",
+                    "Last disconnect: {reason}",
+                    &[("reason", reason)],
+                )
+            })
+    };
+
+    let status_text = move |s: ConnectionState| {
+        // Read the clock directly so a fresh countdown doesn't wait for a tick
+        tick.track();
+        match s.retry_in_secs(local_now_ms()) {
+            Some(seconds) if seconds > 0 => locale.t_or_with(
+                "connection_status.reconnecting_in",
+                "Reconnecting in {seconds}s...",
+                &[("seconds", seconds.to_string())],
+            ),
+            _ => locale.t_or(s.message_key(), s.display_text()),
+        }
+    };
+
+    let latency = move || {
+        let ms = latency_ms.and_then(|latency| latency.get())?;
+        if status.get() != ConnectionState::Connected {
+            return None;
+        }
+        let class = if ms >= SLOW_LATENCY_MS {
+            "connection-status__latency connection-status__latency--slow"
+        } else {
+            "connection-status__latency"
+        };
+        let text = locale.t_or_with(
+            "connection_status.latency",
+            "{ms} ms",
+            &[("ms", ms.to_string())],
+        );
+        Some(view! { <span class=class>{text}</span> })
+    };
+
     view! {
-        <div class=status_class on:click=handle_click>
+        <div class=status_class title=tooltip on:click=handle_click>
             <span class="connection-status__indicator"></span>
             {move || show_text.then(|| {
                 let s = status.get();
@@ -105,9 +242,45 @@ pub fn ConnectionStatus(
                     "connection-status__text"
                 };
                 view! {
-                    <span class=text_class>{s.display_text()}</span>
+                    <span class=text_class>{status_text(s)}</span>
                 }
             })}
+            {latency}
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_in_secs_rounds_up() {
+        let state = ConnectionState::Reconnecting {
+            attempt: 2,
+            retry_at_ms: 10_000,
+        };
+        assert_eq!(state.retry_in_secs(5_000), Some(5));
+        assert_eq!(state.retry_in_secs(5_001), Some(5));
+        assert_eq!(state.retry_in_secs(9_999), Some(1));
+        assert_eq!(state.retry_in_secs(10_000), Some(0));
+        assert_eq!(state.retry_in_secs(12_000), Some(0));
+    }
+
+    #[test]
+    fn test_retry_in_secs_only_while_reconnecting() {
+        assert_eq!(ConnectionState::Connected.retry_in_secs(0), None);
+        assert_eq!(ConnectionState::Disconnected.retry_in_secs(0), None);
+    }
+
+    #[test]
+    fn test_reconnecting_is_not_clickable() {
+        let state = ConnectionState::Reconnecting {
+            attempt: 1,
+            retry_at_ms: 0,
+        };
+        assert!(!state.is_reconnectable());
+        assert_eq!(state.as_str(), "reconnecting");
+        assert_eq!(state.message_key(), "connection_status.reconnecting");
+    }
+}
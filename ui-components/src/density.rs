@@ -0,0 +1,117 @@
+//! Density Context
+//!
+//! Compact, comfortable or spacious spacing for a whole app or one section
+//! of it. Components that support density (Button, Select, InfoGrid,
+//! PlayerList) mark their root with `data-density`, which sets the
+//! `--ui-density-space` and `--ui-density-font` CSS multipliers their
+//! padding, gaps and font sizes are scaled by.
+//!
+//! Outside a provider no attribute is rendered and components keep their
+//! comfortable defaults. Any container can also opt in from plain markup
+//! with `data-density="compact"`.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! // App-wide, e.g. from a persisted setting
+//! provide_density(density_signal);
+//!
+//! // One section only
+//! <DensityProvider density=Density::Compact>
+//!     <InfoGrid>...</InfoGrid>
+//! </DensityProvider>
+//! ```
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How tightly components are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    /// Tight padding and smaller text, for admin tables and dashboards
+    Compact,
+    #[default]
+    Comfortable,
+    /// Extra room, for touch-first layouts
+    Spacious,
+}
+
+impl Density {
+    /// All densities, tightest first
+    pub const ALL: [Density; 3] = [Density::Compact, Density::Comfortable, Density::Spacious];
+
+    /// `data-density` attribute value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Density::Compact => "compact",
+            Density::Comfortable => "comfortable",
+            Density::Spacious => "spacious",
+        }
+    }
+
+    /// Display name
+    pub fn label(&self) -> &'static str {
+        match self {
+            Density::Compact => "Compact",
+            Density::Comfortable => "Comfortable",
+            Density::Spacious => "Spacious",
+        }
+    }
+}
+
+/// Context wrapper so other `Signal<Density>` contexts don't collide
+#[derive(Clone, Copy)]
+struct DensityContext(Signal<Density>);
+
+/// Provide a density to descendant components
+pub fn provide_density(density: impl Into<Signal<Density>>) {
+    provide_context(DensityContext(density.into()));
+}
+
+/// The nearest provided density, or comfortable outside a provider
+pub fn use_density() -> Signal<Density> {
+    use_context::<DensityContext>()
+        .map(|ctx| ctx.0)
+        .unwrap_or_else(|| Signal::stored(Density::default()))
+}
+
+/// `data-density` for a component root; `None` outside a provider
+pub(crate) fn density_attr() -> impl Fn() -> Option<&'static str> + Copy + Send + Sync + 'static {
+    let density = use_context::<DensityContext>().map(|ctx| ctx.0);
+    move || density.map(|d| d.get().as_str())
+}
+
+/// Scope a density to its children
+#[component]
+pub fn DensityProvider(
+    /// Density for the children
+    #[prop(into)]
+    density: Signal<Density>,
+    /// Content laid out at this density
+    children: Children,
+) -> impl IntoView {
+    provide_density(density);
+    children()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attribute_values() {
+        let values: Vec<_> = Density::ALL.iter().map(Density::as_str).collect();
+        assert_eq!(values, ["compact", "comfortable", "spacious"]);
+        assert_eq!(Density::default(), Density::Comfortable);
+    }
+
+    #[test]
+    fn serializes_as_attribute_value() {
+        for density in Density::ALL {
+            let json = serde_json::to_string(&density).unwrap();
+            assert_eq!(json, format!("\"{}\"", density.as_str()));
+            assert_eq!(serde_json::from_str::<Density>(&json).unwrap(), density);
+        }
+    }
+}
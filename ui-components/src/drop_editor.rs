@@ -7,10 +7,14 @@
 //!
 //! - Horizontal stack of AssetCards showing prizes
 //! - Drag-and-drop reordering
-//! - Modal for adding new drops (tips or CNFTs)
+//! - Modal for adding new drops, with fields generated from a [`DropSchema`]
 //! - Remove button on hover
 //! - Skeleton loading for NFT images
 //!
+//! [`DropEditor`] edits [`asset_intents::Drop`]s with the standard tip/CNFT
+//! schema (optionally customised). [`SchemaDropEditor`] edits validated
+//! [`DropRecord`]s of any schema, for campaign formats that aren't `Drop`s.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -29,15 +33,44 @@
 //!     />
 //! }
 //! ```
+//!
+//! With a custom schema:
+//!
+//! ```ignore
+//! use ui_components::{DropKind, DropRecord, DropSchema, FieldSpec, SchemaDropEditor};
+//!
+//! let schema = DropSchema::new().kind(
+//!     DropKind::new("points", "Points")
+//!         .icon("★")
+//!         .summary("{points} pts")
+//!         .field(FieldSpec::integer("points", "Points").required().min(1.0)),
+//! );
+//! let (records, set_records) = signal(Vec::<DropRecord>::new());
+//!
+//! view! {
+//!     <SchemaDropEditor
+//!         schema=schema
+//!         records=records
+//!         on_change=move |new_records| set_records.set(new_records)
+//!     />
+//! }
+//! ```
+
+use std::collections::BTreeMap;
 
+use crate::drop_schema::{DropInput, DropKind, DropRecord, DropSchema, FieldKind, FieldSpec};
+use crate::i18n::use_locale;
 use crate::{
     AssetCard, Button, ButtonVariant, CardSize, DraggableStack, ItemDragState, Modal, Reorder,
     Select, SelectOption, StackDirection,
 };
-use asset_intents::{format_number, AssetId, Drop};
+use asset_intents::Drop;
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
 
+/// Icon for cards of kinds without one
+const DEFAULT_ICON: &str = "$";
+
 /// Editor for a list of reward drops - horizontal compact layout
 #[component]
 pub fn DropEditor(
@@ -53,61 +86,109 @@ pub fn DropEditor(
     /// Card size for drop items
     #[prop(optional, default = CardSize::Sm)]
     size: CardSize,
+    /// Tip/CNFT schema with custom constraints; defaults to [`DropSchema::standard`]
+    ///
+    /// Kinds and field keys must match the standard schema for records to
+    /// convert back to drops.
+    #[prop(optional)]
+    schema: Option<DropSchema>,
+) -> impl IntoView {
+    let records = Signal::derive(move || drops.get().iter().map(DropRecord::from_drop).collect());
+    let on_records = Callback::new(move |records: Vec<DropRecord>| {
+        on_change.run(records.iter().filter_map(DropRecord::to_drop).collect());
+    });
+
+    view! {
+        <SchemaDropEditor
+            schema=schema.unwrap_or_else(DropSchema::standard)
+            records=records
+            on_change=on_records
+            readonly=readonly
+            size=size
+        />
+    }
+}
+
+/// Editor for a list of schema-described drops - horizontal compact layout
+#[component]
+pub fn SchemaDropEditor(
+    /// Drop kinds and their fields
+    schema: DropSchema,
+    /// The current list of drops
+    #[prop(into)]
+    records: Signal<Vec<DropRecord>>,
+    /// Called with the new list when drops are added, removed or reordered
+    #[prop(into)]
+    on_change: Callback<Vec<DropRecord>>,
+    /// If true, disables editing (no add/remove/reorder)
+    #[prop(into, optional)]
+    readonly: Signal<bool>,
+    /// Card size for drop items
+    #[prop(optional, default = CardSize::Sm)]
+    size: CardSize,
+    /// Add button tooltip and modal title
+    #[prop(into, optional, default = "Add Prize".into())]
+    add_label: String,
 ) -> impl IntoView {
+    let schema = StoredValue::new(schema);
+
     // Modal state
     let (show_add_modal, set_show_add_modal) = signal(false);
 
     // Handle reordering
     let on_reorder = move |reorder: Reorder| {
-        let mut new_drops = drops.get();
-        reorder.apply(&mut new_drops);
-        on_change.run(new_drops);
+        let mut new_records = records.get();
+        reorder.apply(&mut new_records);
+        on_change.run(new_records);
     };
 
     // Remove a drop by its key (since indices change during drag)
-    let remove_drop = move |drop_to_remove: Drop| {
-        let new_drops: Vec<Drop> = drops
+    let remove_record = move |to_remove: DropRecord| {
+        let new_records: Vec<DropRecord> = records
             .get()
             .into_iter()
-            .filter(|d| drop_key(d) != drop_key(&drop_to_remove))
+            .filter(|r| r.key() != to_remove.key())
             .collect();
-        on_change.run(new_drops);
+        on_change.run(new_records);
     };
 
     // Add a new drop from modal
-    let add_drop = move |drop: Drop| {
-        let mut new_drops = drops.get();
-        new_drops.push(drop);
-        on_change.run(new_drops);
+    let add_record = move |record: DropRecord| {
+        let mut new_records = records.get();
+        new_records.push(record);
+        on_change.run(new_records);
         set_show_add_modal.set(false);
     };
 
     let btn_size = size.pixels().unwrap_or(120);
+    let modal_title = add_label.clone();
 
     view! {
         <div class="drop-editor">
             <div class="drop-editor__items">
                 <Show
-                    when=move || !drops.get().is_empty()
+                    when=move || !records.get().is_empty()
                     fallback=|| ()
                 >
                     <DraggableStack
-                        items=drops
+                        items=records
                         on_reorder=on_reorder
-                        key_fn=|drop| drop_key(drop)
+                        key_fn=|record: &DropRecord| record.key()
                         direction=StackDirection::Horizontal
                         gap="0.5rem"
                         disabled=readonly
-                        render_item=move |drop: Drop, idx: usize, drag_state: ItemDragState| {
-                            let drop_for_remove = drop.clone();
+                        render_item=move |record: DropRecord, idx: usize, drag_state: ItemDragState| {
+                            let kind = schema.with_value(|s| s.find(&record.kind).cloned());
+                            let record_for_remove = record.clone();
                             view! {
                                 <DropItem
                                     index=idx
-                                    drop=drop
+                                    record=record
+                                    kind=kind
                                     size=size
                                     readonly=readonly
                                     drag_state=drag_state
-                                    on_remove=Callback::new(move |()| remove_drop(drop_for_remove.clone()))
+                                    on_remove=Callback::new(move |()| remove_record(record_for_remove.clone()))
                                 />
                             }
                         }
@@ -120,7 +201,7 @@ pub fn DropEditor(
                         class="drop-editor__add-btn"
                         style=format!("width: {btn_size}px; height: {btn_size}px;")
                         on:click=move |_| set_show_add_modal.set(true)
-                        title="Add prize"
+                        title=add_label.clone()
                     >
                         <span class="drop-editor__add-icon">"+"</span>
                     </button>
@@ -129,58 +210,39 @@ pub fn DropEditor(
 
             // Add drop modal
             <AddDropModal
+                schema=schema
+                title=modal_title
                 open=show_add_modal
                 on_close=move || set_show_add_modal.set(false)
-                on_add=add_drop
+                on_add=add_record
             />
         </div>
     }
 }
 
-/// Generate a unique key for a drop (for list rendering)
-fn drop_key(drop: &Drop) -> String {
-    match drop {
-        Drop::Tip { token, amount } => format!("tip-{token}-{amount}"),
-        Drop::WalletSend { asset_id, amount } => {
-            format!("ws-{}-{amount}", asset_id.concatenated())
-        }
-    }
-}
-
-/// Format drop display name for AssetCard
-fn drop_display_name(drop: &Drop) -> String {
-    match drop {
-        Drop::Tip { token, amount } => {
-            format!("{} {}", format_number(*amount), token)
-        }
-        Drop::WalletSend { asset_id, amount } => {
-            let name = asset_id.asset_name();
-            if *amount > 1 {
-                format!("{} x{}", name, format_number(*amount as f64))
-            } else {
-                name
-            }
-        }
-    }
-}
-
 /// A single drop item in the horizontal list
 #[component]
 fn DropItem(
     index: usize,
-    drop: Drop,
+    record: DropRecord,
+    kind: Option<DropKind>,
     size: CardSize,
     #[prop(into)] readonly: Signal<bool>,
     drag_state: ItemDragState,
     #[prop(into)] on_remove: Callback<()>,
 ) -> impl IntoView {
-    let display_name = drop_display_name(&drop);
+    let locale = use_locale();
+    let display_name = kind
+        .as_ref()
+        .map(|k| k.display_name(&record))
+        .unwrap_or_else(|| record.kind.clone());
+    let icon = kind
+        .as_ref()
+        .and_then(|k| k.icon.clone())
+        .unwrap_or_else(|| DEFAULT_ICON.to_string());
 
     // Get asset_id for NFTs
-    let asset_id = match &drop {
-        Drop::WalletSend { asset_id, .. } => Some(asset_id.concatenated()),
-        Drop::Tip { .. } => None,
-    };
+    let asset_id = kind.as_ref().and_then(|k| k.asset_id(&record));
 
     let item_class = move || {
         let mut classes = vec!["drop-item"];
@@ -211,13 +273,13 @@ fn DropItem(
                         />
                     }.into_any(),
                     None => view! {
-                        // Tip display - text card
+                        // Text card (tips and other non-asset drops)
                         <div class="drop-item__tip-card" style=format!(
                             "width: {}px; height: {}px;",
                             size.pixels().unwrap_or(120),
                             size.pixels().unwrap_or(120)
                         )>
-                            <div class="drop-item__tip-icon">"$"</div>
+                            <div class="drop-item__tip-icon">{icon}</div>
                             <div class="drop-item__tip-name">{display_name.clone()}</div>
                         </div>
                     }.into_any(),
@@ -232,7 +294,7 @@ fn DropItem(
                         ev.stop_propagation();
                         on_remove.run(());
                     }
-                    title="Remove"
+                    title=move || locale.t_or("drop_editor.remove", "Remove")
                 >
                     {"\u{00D7}"} // ×
                 </button>
@@ -244,21 +306,21 @@ fn DropItem(
 /// Form state for the AddDropModal
 #[derive(Clone)]
 struct AddDropFormState {
-    drop_type: String,
-    tip_token: String,
-    tip_amount: f64,
-    cnft_input: String,
-    cnft_error: Option<String>,
+    /// Selected [`DropKind::id`]
+    kind: String,
+    /// Raw input per field
+    input: DropInput,
+    /// Validation errors per field (`""` for the whole drop)
+    errors: BTreeMap<String, String>,
 }
 
-impl Default for AddDropFormState {
-    fn default() -> Self {
+impl AddDropFormState {
+    /// Fresh form for a kind, filled with its defaults
+    fn for_kind(kind: Option<&DropKind>) -> Self {
         Self {
-            drop_type: "tip".to_string(),
-            tip_token: "ADA".to_string(),
-            tip_amount: 100.0,
-            cnft_input: String::new(),
-            cnft_error: None,
+            kind: kind.map(|k| k.id.clone()).unwrap_or_default(),
+            input: kind.map(DropKind::initial_input).unwrap_or_default(),
+            errors: BTreeMap::new(),
         }
     }
 }
@@ -266,136 +328,109 @@ impl Default for AddDropFormState {
 /// Modal for adding a new drop
 #[component]
 fn AddDropModal(
+    schema: StoredValue<DropSchema>,
+    title: String,
     #[prop(into)] open: Signal<bool>,
     on_close: impl Fn() + Send + Sync + 'static + Copy,
-    on_add: impl Fn(Drop) + Send + Sync + 'static + Copy,
+    on_add: impl Fn(DropRecord) + Send + Sync + 'static + Copy,
 ) -> impl IntoView {
+    let locale = use_locale();
+    let first_kind = move || {
+        AddDropFormState::for_kind(schema.with_value(|s| s.kinds.first().cloned()).as_ref())
+    };
+
     // Form state as single struct
-    let (form, set_form) = signal(AddDropFormState::default());
+    let form = RwSignal::new(first_kind());
 
     // Reset form when modal opens
     Effect::new(move |_| {
         if open.get() {
-            set_form.set(AddDropFormState::default());
+            form.set(first_kind());
         }
     });
 
     let handle_add = move |_| {
-        let state = form.get();
-        if state.drop_type == "tip" {
-            let drop = Drop::tip(state.tip_token, state.tip_amount);
-            on_add(drop);
-        } else {
-            // CNFT - parse asset ID
-            match AssetId::parse_smart(state.cnft_input.trim()) {
-                Ok(asset_id) => {
-                    let drop = Drop::wallet_send_single(asset_id);
-                    on_add(drop);
-                }
-                Err(_) => {
-                    set_form.update(|f| f.cnft_error = Some("Invalid asset ID format".to_string()));
-                }
-            }
+        let result = form.with(|f| schema.with_value(|s| s.validate(&f.kind, &f.input)));
+        match result {
+            Ok(record) => on_add(record),
+            Err(errors) => form.update(|f| {
+                f.errors = errors.into_iter().map(|e| (e.field, e.message)).collect();
+            }),
         }
     };
 
-    let type_options = Signal::derive(|| {
-        vec![
-            SelectOption::new("tip", "Tip (Fungible Token)"),
-            SelectOption::new("cnft", "CNFT (NFT Transfer)"),
-        ]
+    let type_options = Signal::derive(move || {
+        schema.with_value(|s| {
+            s.kinds
+                .iter()
+                .map(|k| SelectOption::new(k.id.clone(), k.label.clone()))
+                .collect()
+        })
     });
+    let has_kind_choice = schema.with_value(|s| s.kinds.len() > 1);
+
+    let select_kind = move |id: String| {
+        let kind = schema.with_value(|s| s.find(&id).cloned());
+        form.set(AddDropFormState::for_kind(kind.as_ref()));
+    };
 
+    // Every visible required field filled in
     let can_add = move || {
-        let state = form.get();
-        if state.drop_type == "tip" {
-            state.tip_amount > 0.0 && !state.tip_token.is_empty()
-        } else {
-            !state.cnft_input.trim().is_empty()
-        }
+        form.with(|f| {
+            schema.with_value(|s| {
+                s.find(&f.kind).is_some_and(|kind| {
+                    kind.fields
+                        .iter()
+                        .filter(|field| field.required && field.is_visible(&f.input))
+                        .all(|field| {
+                            f.input
+                                .get(&field.key)
+                                .is_some_and(|v| !v.trim().is_empty())
+                        })
+                })
+            })
+        })
     };
 
-    // Derived signals for Select component (needs Signal<String>)
-    let drop_type_signal = Signal::derive(move || form.get().drop_type);
+    // Fields are rebuilt only when the kind changes so inputs keep focus
+    let kind_id = Memo::new(move |_| form.with(|f| f.kind.clone()));
+
+    let add_text = title.clone();
 
     view! {
         <Modal
             open=open
-            title="Add Prize"
+            title=title
             on_close=on_close
         >
             <div class="add-drop-modal">
-                <div class="add-drop-modal__field">
-                    <label>"Prize Type"</label>
-                    <Select
-                        value=drop_type_signal
-                        options=type_options
-                        on_change=move |v| set_form.update(|f| f.drop_type = v)
-                    />
-                </div>
-
-                // Tip fields
-                <Show when=move || form.get().drop_type == "tip">
-                    <div class="add-drop-modal__row">
-                        <div class="add-drop-modal__field add-drop-modal__field--amount">
-                            <label>"Amount"</label>
-                            <input
-                                type="number"
-                                class="add-drop-modal__input"
-                                prop:value=move || form.get().tip_amount.to_string()
-                                on:input=move |ev| {
-                                    let target = ev.target().unwrap();
-                                    let input: web_sys::HtmlInputElement = target.unchecked_into();
-                                    if let Ok(val) = input.value().parse::<f64>() {
-                                        set_form.update(|f| f.tip_amount = val);
-                                    }
-                                }
-                                min="0"
-                                step="0.01"
-                            />
-                        </div>
-                        <div class="add-drop-modal__field add-drop-modal__field--token">
-                            <label>"Token"</label>
-                            <input
-                                type="text"
-                                class="add-drop-modal__input"
-                                prop:value=move || form.get().tip_token.clone()
-                                on:input=move |ev| {
-                                    let target = ev.target().unwrap();
-                                    let input: web_sys::HtmlInputElement = target.unchecked_into();
-                                    set_form.update(|f| f.tip_token = input.value());
-                                }
-                                placeholder="ADA"
-                            />
-                        </div>
-                    </div>
-                </Show>
-
-                // CNFT fields
-                <Show when=move || form.get().drop_type == "cnft">
+                {has_kind_choice.then(|| view! {
                     <div class="add-drop-modal__field">
-                        <label>"Asset ID"</label>
-                        <input
-                            type="text"
-                            class="add-drop-modal__input"
-                            prop:value=move || form.get().cnft_input.clone()
-                            on:input=move |ev| {
-                                let target = ev.target().unwrap();
-                                let input: web_sys::HtmlInputElement = target.unchecked_into();
-                                set_form.update(|f| {
-                                    f.cnft_input = input.value();
-                                    f.cnft_error = None;
-                                });
-                            }
-                            placeholder="Paste asset ID (policy + asset name hex)"
+                        <label>{move || locale.t_or("drop_editor.prize_type", "Prize Type")}</label>
+                        <Select
+                            value=kind_id
+                            options=type_options
+                            on_change=select_kind
                         />
-                        <Show when=move || form.get().cnft_error.is_some()>
-                            <span class="add-drop-modal__error">{move || form.get().cnft_error.clone()}</span>
-                        </Show>
-                        <span class="add-drop-modal__hint">
-                            "Formats: policy_id + asset_name_hex, or policy_id.asset_name_hex"
-                        </span>
                     </div>
+                })}
+
+                <div class="add-drop-modal__fields">
+                    {move || {
+                        let fields = schema
+                            .with_value(|s| s.find(&kind_id.get()).map(|k| k.fields.clone()))
+                            .unwrap_or_default();
+                        fields
+                            .into_iter()
+                            .map(|field| view! { <DropField field=field form=form /> })
+                            .collect_view()
+                    }}
+                </div>
+
+                <Show when=move || form.with(|f| f.errors.contains_key(""))>
+                    <span class="add-drop-modal__error">
+                        {move || form.with(|f| f.errors.get("").cloned())}
+                    </span>
                 </Show>
 
                 <div class="add-drop-modal__actions">
@@ -403,17 +438,137 @@ fn AddDropModal(
                         variant=ButtonVariant::Secondary
                         on_click=move |()| on_close()
                     >
-                        "Cancel"
+                        {move || locale.t_or("drop_editor.cancel", "Cancel")}
                     </Button>
                     <Button
                         variant=ButtonVariant::Primary
                         on_click=handle_add
                         disabled=Signal::derive(move || !can_add())
                     >
-                        "Add Prize"
+                        {add_text}
                     </Button>
                 </div>
             </div>
         </Modal>
     }
 }
+
+/// One schema field in the AddDropModal
+#[component]
+fn DropField(field: FieldSpec, form: RwSignal<AddDropFormState>) -> impl IntoView {
+    let locale = use_locale();
+    let field = StoredValue::new(field);
+    let key = field.with_value(|f| f.key.clone());
+
+    let visible = move || form.with(|f| field.with_value(|spec| spec.is_visible(&f.input)));
+    let value = {
+        let key = key.clone();
+        move || form.with(|f| f.input.get(&key).cloned().unwrap_or_default())
+    };
+    let error = {
+        let key = key.clone();
+        move || form.with(|f| f.errors.get(&key).cloned())
+    };
+    let set_value = move |v: String| {
+        form.update(|f| {
+            f.errors.remove(&key);
+            f.input.insert(key.clone(), v);
+        })
+    };
+
+    let spec = field.get_value();
+    let field_class = if matches!(spec.kind, FieldKind::Toggle) {
+        "add-drop-modal__field add-drop-modal__field--toggle"
+    } else {
+        "add-drop-modal__field"
+    };
+
+    let control = match spec.kind {
+        FieldKind::Text { max_len } => view! {
+            <input
+                type="text"
+                class="add-drop-modal__input"
+                prop:value=value
+                on:input=move |ev| set_value(input_target(ev).value())
+                maxlength=max_len.map(|n| n.to_string())
+                placeholder=spec.placeholder.clone()
+            />
+        }
+        .into_any(),
+        FieldKind::Asset => view! {
+            <input
+                type="text"
+                class="add-drop-modal__input"
+                prop:value=value
+                on:input=move |ev| set_value(input_target(ev).value())
+                placeholder=spec.placeholder.clone()
+            />
+        }
+        .into_any(),
+        FieldKind::Number { min, max, step } => view! {
+            <input
+                type="number"
+                class="add-drop-modal__input"
+                prop:value=value
+                on:input=move |ev| set_value(input_target(ev).value())
+                min=min.map(|n| n.to_string())
+                max=max.map(|n| n.to_string())
+                step=step.map_or_else(|| "any".to_string(), |n| n.to_string())
+                placeholder=spec.placeholder.clone()
+            />
+        }
+        .into_any(),
+        FieldKind::Integer { min, max } => view! {
+            <input
+                type="number"
+                class="add-drop-modal__input"
+                prop:value=value
+                on:input=move |ev| set_value(input_target(ev).value())
+                min=min.map(|n| n.to_string())
+                max=max.map(|n| n.to_string())
+                step="1"
+                placeholder=spec.placeholder.clone()
+            />
+        }
+        .into_any(),
+        FieldKind::Choice { options } => view! {
+            <Select
+                value=Signal::derive(value)
+                options=options
+                on_change=set_value
+                placeholder=spec.placeholder.clone().unwrap_or_else(|| locale.t_or("drop_editor.select", "Select..."))
+            />
+        }
+        .into_any(),
+        FieldKind::Toggle => view! {
+            <input
+                type="checkbox"
+                class="add-drop-modal__checkbox"
+                prop:checked=move || value() == "true"
+                on:change=move |ev| set_value(input_target(ev).checked().to_string())
+            />
+        }
+        .into_any(),
+    };
+
+    view! {
+        // Hidden rather than unmounted so the input keeps its state
+        <div class=field_class hidden=move || !visible()>
+            <label>
+                {spec.label.clone()}
+                {spec.required.then(|| view! {
+                    <span class="add-drop-modal__required">"*"</span>
+                })}
+            </label>
+            {control}
+            {move || error().map(|e| view! { <span class="add-drop-modal__error">{e}</span> })}
+            {spec.hint.clone().map(|h| view! { <span class="add-drop-modal__hint">{h}</span> })}
+        </div>
+    }
+}
+
+/// The input element an event was fired on
+fn input_target(ev: web_sys::Event) -> web_sys::HtmlInputElement {
+    let target = ev.target().unwrap();
+    target.unchecked_into()
+}
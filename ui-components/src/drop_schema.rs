@@ -0,0 +1,922 @@
+//! Drop Schema
+//!
+//! Declarative description of the drop formats a
+//! [`SchemaDropEditor`](crate::SchemaDropEditor) can create: drop kinds, their
+//! fields, field constraints and conditional visibility. Form input is
+//! validated against the schema into a structured [`DropRecord`].
+//!
+//! [`DropSchema::standard`] describes the tip/CNFT drops used by
+//! [`DropEditor`](crate::DropEditor); records of that schema convert to and
+//! from [`asset_intents::Drop`].
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_components::{Condition, DropKind, DropSchema, FieldSpec, SelectOption};
+//!
+//! let schema = DropSchema::new()
+//!     .kind(
+//!         DropKind::new("points", "Points")
+//!             .icon("★")
+//!             .summary("{points} pts")
+//!             .field(FieldSpec::integer("points", "Points").required().min(1.0).max(10_000.0)),
+//!     )
+//!     .kind(
+//!         DropKind::new("role", "Discord Role")
+//!             .icon("@")
+//!             .summary("{role}")
+//!             .field(FieldSpec::choice("role", "Role", vec![
+//!                 SelectOption::new("og", "OG"),
+//!                 SelectOption::new("holder", "Holder"),
+//!             ]).required())
+//!             .field(FieldSpec::toggle("expires", "Expires"))
+//!             .field(
+//!                 FieldSpec::integer("days", "Days")
+//!                     .required()
+//!                     .min(1.0)
+//!                     .visible_when(Condition::is_set("expires")),
+//!             ),
+//!     );
+//!
+//! let record = schema.validate("points", &input)?;
+//! ```
+
+use std::collections::BTreeMap;
+
+use asset_intents::{format_number, AssetId, Drop};
+use serde::{Deserialize, Serialize};
+use ui_core::i18n::translate_or;
+
+use crate::SelectOption;
+
+/// Raw form input keyed by field
+pub type DropInput = BTreeMap<String, String>;
+
+/// A validated field value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FieldValue {
+    Bool(bool),
+    Integer(i64),
+    Number(f64),
+    Text(String),
+}
+
+impl FieldValue {
+    /// Value as it appears in a form input
+    pub fn to_input(&self) -> String {
+        match self {
+            Self::Bool(b) => b.to_string(),
+            Self::Integer(n) => n.to_string(),
+            Self::Number(n) => n.to_string(),
+            Self::Text(s) => s.clone(),
+        }
+    }
+
+    /// Numeric value, if any
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Integer(n) => Some(*n as f64),
+            Self::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Text value, if any
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Text(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// Field input type and its constraints
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldKind {
+    /// Free text, optionally length-limited (in characters)
+    Text { max_len: Option<usize> },
+    /// Decimal number
+    Number {
+        min: Option<f64>,
+        max: Option<f64>,
+        step: Option<f64>,
+    },
+    /// Whole number
+    Integer { min: Option<f64>, max: Option<f64> },
+    /// One of a fixed set of options
+    Choice { options: Vec<SelectOption> },
+    /// On/off checkbox
+    Toggle,
+    /// Cardano asset id (policy id + asset name hex)
+    Asset,
+}
+
+/// Visibility rule evaluated against the current form input
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    /// Field input equals the value
+    Equals { field: String, value: String },
+    /// Field input differs from the value
+    NotEquals { field: String, value: String },
+    /// Field is filled in (toggles: switched on)
+    IsSet(String),
+    /// Every condition holds
+    All(Vec<Condition>),
+    /// At least one condition holds
+    Any(Vec<Condition>),
+}
+
+impl Condition {
+    /// Field input equals `value`
+    pub fn equals(field: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::Equals {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Field input differs from `value`
+    pub fn not_equals(field: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::NotEquals {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Field is filled in (toggles: switched on)
+    pub fn is_set(field: impl Into<String>) -> Self {
+        Self::IsSet(field.into())
+    }
+
+    /// Whether the condition holds for `input`
+    pub fn matches(&self, input: &DropInput) -> bool {
+        let raw = |field: &str| input.get(field).map(|v| v.trim()).unwrap_or("");
+        match self {
+            Self::Equals { field, value } => raw(field) == value,
+            Self::NotEquals { field, value } => raw(field) != value,
+            Self::IsSet(field) => !matches!(raw(field), "" | "false"),
+            Self::All(conditions) => conditions.iter().all(|c| c.matches(input)),
+            Self::Any(conditions) => conditions.iter().any(|c| c.matches(input)),
+        }
+    }
+}
+
+/// One field of a drop kind
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSpec {
+    /// Key in the input and output maps
+    pub key: String,
+    /// Field label
+    pub label: String,
+    /// Input type and constraints
+    pub kind: FieldKind,
+    /// Must be filled in when visible
+    pub required: bool,
+    /// Initial value
+    pub default: Option<FieldValue>,
+    /// Input placeholder
+    pub placeholder: Option<String>,
+    /// Help text below the input
+    pub hint: Option<String>,
+    /// Only shown (and validated) while this holds
+    pub visible_when: Option<Condition>,
+}
+
+impl FieldSpec {
+    /// Field of any kind
+    pub fn new(key: impl Into<String>, label: impl Into<String>, kind: FieldKind) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            kind,
+            required: false,
+            default: None,
+            placeholder: None,
+            hint: None,
+            visible_when: None,
+        }
+    }
+
+    /// Free text field
+    pub fn text(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(key, label, FieldKind::Text { max_len: None })
+    }
+
+    /// Decimal number field
+    pub fn number(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(
+            key,
+            label,
+            FieldKind::Number {
+                min: None,
+                max: None,
+                step: None,
+            },
+        )
+    }
+
+    /// Whole number field
+    pub fn integer(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(
+            key,
+            label,
+            FieldKind::Integer {
+                min: None,
+                max: None,
+            },
+        )
+    }
+
+    /// Option list field
+    pub fn choice(
+        key: impl Into<String>,
+        label: impl Into<String>,
+        options: Vec<SelectOption>,
+    ) -> Self {
+        Self::new(key, label, FieldKind::Choice { options })
+    }
+
+    /// Checkbox field
+    pub fn toggle(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(key, label, FieldKind::Toggle)
+    }
+
+    /// Asset id field
+    pub fn asset(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(key, label, FieldKind::Asset)
+    }
+
+    /// Mark the field required
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Set the initial value
+    pub fn default_value(mut self, value: FieldValue) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    /// Set the input placeholder
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set the help text
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Only show the field while `condition` holds
+    pub fn visible_when(mut self, condition: Condition) -> Self {
+        self.visible_when = Some(condition);
+        self
+    }
+
+    /// Minimum value (number and integer fields)
+    pub fn min(mut self, value: f64) -> Self {
+        if let FieldKind::Number { min, .. } | FieldKind::Integer { min, .. } = &mut self.kind {
+            *min = Some(value);
+        }
+        self
+    }
+
+    /// Maximum value (number and integer fields)
+    pub fn max(mut self, value: f64) -> Self {
+        if let FieldKind::Number { max, .. } | FieldKind::Integer { max, .. } = &mut self.kind {
+            *max = Some(value);
+        }
+        self
+    }
+
+    /// Input step (number fields)
+    pub fn step(mut self, value: f64) -> Self {
+        if let FieldKind::Number { step, .. } = &mut self.kind {
+            *step = Some(value);
+        }
+        self
+    }
+
+    /// Maximum length in characters (text fields)
+    pub fn max_len(mut self, value: usize) -> Self {
+        if let FieldKind::Text { max_len } = &mut self.kind {
+            *max_len = Some(value);
+        }
+        self
+    }
+
+    /// Whether the field is shown for `input`
+    pub fn is_visible(&self, input: &DropInput) -> bool {
+        self.visible_when.as_ref().is_none_or(|c| c.matches(input))
+    }
+
+    /// Parse and check one raw input; `Ok(None)` for an empty optional field
+    pub fn parse(&self, raw: &str) -> Result<Option<FieldValue>, String> {
+        let raw = raw.trim();
+        if matches!(self.kind, FieldKind::Toggle) {
+            return Ok(Some(FieldValue::Bool(raw == "true")));
+        }
+        if raw.is_empty() {
+            return if self.required {
+                Err(translate_or("drop_schema.required", "Required", &[]))
+            } else {
+                Ok(None)
+            };
+        }
+
+        let value = match &self.kind {
+            FieldKind::Text { max_len } => {
+                if let Some(max) = max_len.filter(|max| raw.chars().count() > *max) {
+                    return Err(translate_or(
+                        "drop_schema.max_len",
+                        "At most {max} characters",
+                        &[("max", max.to_string())],
+                    ));
+                }
+                FieldValue::Text(raw.to_string())
+            }
+            FieldKind::Number { min, max, .. } => {
+                let n = raw
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|n| n.is_finite())
+                    .ok_or_else(|| translate_or("drop_schema.number", "Must be a number", &[]))?;
+                check_range(n, *min, *max)?;
+                FieldValue::Number(n)
+            }
+            FieldKind::Integer { min, max } => {
+                let n = raw.parse::<i64>().map_err(|_| {
+                    translate_or("drop_schema.integer", "Must be a whole number", &[])
+                })?;
+                check_range(n as f64, *min, *max)?;
+                FieldValue::Integer(n)
+            }
+            FieldKind::Choice { options } => {
+                if !options.iter().any(|o| o.value == raw && !o.disabled) {
+                    return Err(translate_or(
+                        "drop_schema.choice",
+                        "Choose one of the options",
+                        &[],
+                    ));
+                }
+                FieldValue::Text(raw.to_string())
+            }
+            FieldKind::Asset => {
+                let asset_id = AssetId::parse_smart(raw).map_err(|_| {
+                    translate_or("drop_schema.asset", "Invalid asset ID format", &[])
+                })?;
+                FieldValue::Text(asset_id.concatenated())
+            }
+            FieldKind::Toggle => unreachable!("handled above"),
+        };
+        Ok(Some(value))
+    }
+}
+
+fn check_range(n: f64, min: Option<f64>, max: Option<f64>) -> Result<(), String> {
+    if let Some(min) = min.filter(|min| n < *min) {
+        return Err(translate_or(
+            "drop_schema.min",
+            "Must be at least {min}",
+            &[("min", format_number(min))],
+        ));
+    }
+    if let Some(max) = max.filter(|max| n > *max) {
+        return Err(translate_or(
+            "drop_schema.max",
+            "Must be at most {max}",
+            &[("max", format_number(max))],
+        ));
+    }
+    Ok(())
+}
+
+/// A kind of drop and the fields that describe it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropKind {
+    /// Stable id, stored in [`DropRecord::kind`]
+    pub id: String,
+    /// Label in the kind selector
+    pub label: String,
+    /// Fields in display order
+    pub fields: Vec<FieldSpec>,
+    /// Card text template with `{field}` placeholders and `[optional]` sections
+    pub summary: Option<String>,
+    /// Icon on cards without an asset image
+    pub icon: Option<String>,
+}
+
+impl DropKind {
+    /// Kind with no fields
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            fields: Vec::new(),
+            summary: None,
+            icon: None,
+        }
+    }
+
+    /// Add a field
+    pub fn field(mut self, field: FieldSpec) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Set the card text template, e.g. `"{amount} {token}"`
+    ///
+    /// Text in `[...]` is only shown when its placeholders have values, e.g.
+    /// `"{asset}[ x{amount}]"`.
+    pub fn summary(mut self, template: impl Into<String>) -> Self {
+        self.summary = Some(template.into());
+        self
+    }
+
+    /// Set the card icon
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Initial form input from field defaults
+    pub fn initial_input(&self) -> DropInput {
+        self.fields
+            .iter()
+            .filter_map(|f| Some((f.key.clone(), f.default.as_ref()?.to_input())))
+            .collect()
+    }
+
+    /// Validate form input into a record
+    ///
+    /// Hidden fields are skipped and left out of the record.
+    pub fn validate(&self, input: &DropInput) -> Result<DropRecord, Vec<FieldError>> {
+        let mut values = BTreeMap::new();
+        let mut errors = Vec::new();
+        for field in self.fields.iter().filter(|f| f.is_visible(input)) {
+            let raw = input.get(&field.key).map(String::as_str).unwrap_or("");
+            match field.parse(raw) {
+                Ok(Some(value)) => {
+                    values.insert(field.key.clone(), value);
+                }
+                Ok(None) => {}
+                Err(message) => errors.push(FieldError {
+                    field: field.key.clone(),
+                    message,
+                }),
+            }
+        }
+        if errors.is_empty() {
+            Ok(DropRecord {
+                kind: self.id.clone(),
+                values,
+            })
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Card text for a record of this kind
+    ///
+    /// Fills the [`summary`](Self::summary) template, or joins the values when
+    /// there is none.
+    pub fn display_name(&self, record: &DropRecord) -> String {
+        let show = |key: &str| {
+            let value = record.values.get(key)?;
+            let field = self.fields.iter().find(|f| f.key == key);
+            Some(display_value(field.map(|f| &f.kind), value))
+        };
+        match &self.summary {
+            Some(template) => fill_template(template, &show),
+            None => self
+                .fields
+                .iter()
+                .filter(|f| !matches!(f.kind, FieldKind::Toggle))
+                .filter_map(|f| show(&f.key))
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+
+    /// First asset id in a record of this kind, for the card image
+    pub fn asset_id(&self, record: &DropRecord) -> Option<String> {
+        self.fields
+            .iter()
+            .filter(|f| matches!(f.kind, FieldKind::Asset))
+            .find_map(|f| record.values.get(&f.key)?.as_str().map(str::to_string))
+    }
+}
+
+fn display_value(kind: Option<&FieldKind>, value: &FieldValue) -> String {
+    match (kind, value) {
+        (Some(FieldKind::Asset), FieldValue::Text(id)) => AssetId::parse_smart(id)
+            .map(|a| a.asset_name())
+            .unwrap_or_else(|_| id.clone()),
+        (Some(FieldKind::Choice { options }), FieldValue::Text(v)) => options
+            .iter()
+            .find(|o| &o.value == v)
+            .map(|o| o.label.clone())
+            .unwrap_or_else(|| v.clone()),
+        (_, FieldValue::Integer(n)) => format_number(*n as f64),
+        (_, FieldValue::Number(n)) => format_number(*n),
+        (_, FieldValue::Bool(true)) => translate_or("drop_schema.yes", "yes", &[]),
+        (_, FieldValue::Bool(false)) => translate_or("drop_schema.no", "no", &[]),
+        (_, FieldValue::Text(s)) => s.clone(),
+    }
+}
+
+/// Fill `{key}` placeholders; `[...]` sections are dropped unless all of
+/// their placeholders have values
+fn fill_template(template: &str, value: &dyn Fn(&str) -> Option<String>) -> String {
+    let mut out = String::new();
+    let mut rest = template;
+    while let Some((open, close)) = rest
+        .find('[')
+        .and_then(|open| Some((open, open + rest[open..].find(']')?)))
+    {
+        out.push_str(&fill_segment(&rest[..open], value).0);
+        let (section, complete) = fill_segment(&rest[open + 1..close], value);
+        if complete {
+            out.push_str(&section);
+        }
+        rest = &rest[close + 1..];
+    }
+    out.push_str(&fill_segment(rest, value).0);
+    out
+}
+
+/// Fill placeholders in a segment, reporting whether every one had a value
+fn fill_segment(segment: &str, value: &dyn Fn(&str) -> Option<String>) -> (String, bool) {
+    let mut out = String::new();
+    let mut complete = true;
+    let mut rest = segment;
+    while let Some((open, close)) = rest
+        .find('{')
+        .and_then(|open| Some((open, open + rest[open..].find('}')?)))
+    {
+        out.push_str(&rest[..open]);
+        match value(&rest[open + 1..close]) {
+            Some(v) => out.push_str(&v),
+            None => complete = false,
+        }
+        rest = &rest[close + 1..];
+    }
+    out.push_str(rest);
+    (out, complete)
+}
+
+/// The set of drop kinds an editor offers
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DropSchema {
+    /// Kinds in selector order
+    pub kinds: Vec<DropKind>,
+}
+
+impl DropSchema {
+    /// Schema with no kinds
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a drop kind
+    pub fn kind(mut self, kind: DropKind) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+
+    /// Look up a kind by id
+    pub fn find(&self, id: &str) -> Option<&DropKind> {
+        self.kinds.iter().find(|k| k.id == id)
+    }
+
+    /// Tip and CNFT drops, matching [`asset_intents::Drop`]
+    ///
+    /// Records convert with [`DropRecord::from_drop`] and [`DropRecord::to_drop`].
+    /// Kinds may be customised (e.g. a token [`FieldKind::Choice`]) as long as
+    /// the field keys stay the same. Labels use the locale active when the
+    /// schema is built.
+    pub fn standard() -> Self {
+        Self::new()
+            .kind(
+                DropKind::new(
+                    TIP_KIND,
+                    translate_or("drop_schema.tip", "Tip (Fungible Token)", &[]),
+                )
+                .icon("$")
+                .summary("{amount} {token}")
+                .field(
+                    FieldSpec::number("amount", translate_or("drop_schema.amount", "Amount", &[]))
+                        .required()
+                        .min(0.01)
+                        .step(0.01)
+                        .default_value(FieldValue::Number(100.0)),
+                )
+                .field(
+                    FieldSpec::text("token", translate_or("drop_schema.token", "Token", &[]))
+                        .required()
+                        .max_len(32)
+                        .placeholder("ADA")
+                        .default_value(FieldValue::Text("ADA".to_string())),
+                ),
+            )
+            .kind(
+                DropKind::new(
+                    CNFT_KIND,
+                    translate_or("drop_schema.cnft", "CNFT (NFT Transfer)", &[]),
+                )
+                .summary("{asset}[ x{amount}]")
+                .field(
+                    FieldSpec::asset(
+                        "asset",
+                        translate_or("drop_schema.asset_id", "Asset ID", &[]),
+                    )
+                    .required()
+                    .placeholder(translate_or(
+                        "drop_schema.asset_placeholder",
+                        "Paste asset ID (policy + asset name hex)",
+                        &[],
+                    ))
+                    .hint(translate_or(
+                        "drop_schema.asset_hint",
+                        "Formats: policy_id + asset_name_hex, or policy_id.asset_name_hex",
+                        &[],
+                    )),
+                ),
+            )
+    }
+
+    /// Validate form input for a kind
+    pub fn validate(&self, kind: &str, input: &DropInput) -> Result<DropRecord, Vec<FieldError>> {
+        let Some(kind) = self.find(kind) else {
+            return Err(vec![FieldError {
+                field: String::new(),
+                message: translate_or(
+                    "drop_schema.unknown_kind",
+                    "Unknown drop type \"{kind}\"",
+                    &[("kind", kind.to_string())],
+                ),
+            }]);
+        };
+        kind.validate(input)
+    }
+}
+
+/// Kind id of tip drops in [`DropSchema::standard`]
+pub const TIP_KIND: &str = "tip";
+
+/// Kind id of CNFT drops in [`DropSchema::standard`]
+pub const CNFT_KIND: &str = "cnft";
+
+/// A field that failed validation
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    /// Field key (empty for errors about the whole drop)
+    pub field: String,
+    /// Human-readable reason
+    pub message: String,
+}
+
+/// A validated drop: its kind and field values
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DropRecord {
+    /// [`DropKind::id`]
+    pub kind: String,
+    /// Values of the visible, filled-in fields
+    pub values: BTreeMap<String, FieldValue>,
+}
+
+impl DropRecord {
+    /// Value of a field
+    pub fn get(&self, key: &str) -> Option<&FieldValue> {
+        self.values.get(key)
+    }
+
+    /// Stable key for list rendering
+    pub fn key(&self) -> String {
+        let values: Vec<_> = self
+            .values
+            .iter()
+            .map(|(k, v)| format!("{k}={}", v.to_input()))
+            .collect();
+        format!("{}-{}", self.kind, values.join("&"))
+    }
+
+    /// Record in [`DropSchema::standard`] form
+    pub fn from_drop(drop: &Drop) -> Self {
+        let values = match drop {
+            Drop::Tip { token, amount } => BTreeMap::from([
+                ("token".to_string(), FieldValue::Text(token.clone())),
+                ("amount".to_string(), FieldValue::Number(*amount)),
+            ]),
+            Drop::WalletSend { asset_id, amount } => {
+                let mut values = BTreeMap::from([(
+                    "asset".to_string(),
+                    FieldValue::Text(asset_id.concatenated()),
+                )]);
+                // Single sends leave the amount out, as the form does
+                if *amount > 1 {
+                    values.insert("amount".to_string(), FieldValue::Integer(*amount as i64));
+                }
+                values
+            }
+        };
+        let kind = match drop {
+            Drop::Tip { .. } => TIP_KIND,
+            Drop::WalletSend { .. } => CNFT_KIND,
+        };
+        Self {
+            kind: kind.to_string(),
+            values,
+        }
+    }
+
+    /// Convert a [`DropSchema::standard`] record back to a drop
+    ///
+    /// `None` for other kinds or missing fields. CNFT records without an
+    /// `amount` send a single asset.
+    pub fn to_drop(&self) -> Option<Drop> {
+        match self.kind.as_str() {
+            TIP_KIND => {
+                let token = self.get("token")?.as_str()?;
+                let amount = self.get("amount")?.as_f64()?;
+                Some(Drop::tip(token, amount))
+            }
+            CNFT_KIND => {
+                let asset_id = AssetId::parse_smart(self.get("asset")?.as_str()?).ok()?;
+                match self.get("amount").and_then(FieldValue::as_f64) {
+                    Some(amount) if amount > 1.0 => Some(Drop::WalletSend {
+                        asset_id,
+                        amount: amount as _,
+                    }),
+                    _ => Some(Drop::wallet_send_single(asset_id)),
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(pairs: &[(&str, &str)]) -> DropInput {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn role_kind() -> DropKind {
+        DropKind::new("role", "Role")
+            .summary("{role} for {days} days")
+            .field(
+                FieldSpec::choice(
+                    "role",
+                    "Role",
+                    vec![
+                        SelectOption::new("og", "OG"),
+                        SelectOption::new("vip", "VIP"),
+                    ],
+                )
+                .required(),
+            )
+            .field(FieldSpec::toggle("expires", "Expires"))
+            .field(
+                FieldSpec::integer("days", "Days")
+                    .required()
+                    .min(1.0)
+                    .max(365.0)
+                    .visible_when(Condition::is_set("expires")),
+            )
+    }
+
+    #[test]
+    fn hidden_fields_are_skipped() {
+        let record = role_kind()
+            .validate(&input(&[("role", "og"), ("days", "oops")]))
+            .unwrap();
+        assert_eq!(record.get("role"), Some(&FieldValue::Text("og".into())));
+        assert_eq!(record.get("expires"), Some(&FieldValue::Bool(false)));
+        assert_eq!(record.get("days"), None);
+    }
+
+    #[test]
+    fn visible_fields_are_validated() {
+        let kind = role_kind();
+        let errors = kind
+            .validate(&input(&[("role", "og"), ("expires", "true")]))
+            .unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "days");
+
+        let errors = kind
+            .validate(&input(&[
+                ("role", "admin"),
+                ("expires", "true"),
+                ("days", "400"),
+            ]))
+            .unwrap_err();
+        let fields: Vec<_> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["role", "days"]);
+
+        let record = kind
+            .validate(&input(&[
+                ("role", "vip"),
+                ("expires", "true"),
+                ("days", "30"),
+            ]))
+            .unwrap();
+        assert_eq!(record.get("days"), Some(&FieldValue::Integer(30)));
+        // Numbers go through `format_number`
+        let name = kind.display_name(&record);
+        assert!(name.starts_with("VIP for ") && name.ends_with(" days"));
+    }
+
+    #[test]
+    fn field_constraints() {
+        let amount = FieldSpec::number("amount", "Amount").required().min(1.0);
+        assert_eq!(amount.parse("2.5"), Ok(Some(FieldValue::Number(2.5))));
+        assert!(amount.parse("0.5").is_err());
+        assert!(amount.parse("abc").is_err());
+        assert!(amount.parse("NaN").is_err());
+        assert!(amount.parse("  ").is_err());
+
+        let note = FieldSpec::text("note", "Note").max_len(3);
+        assert_eq!(note.parse(""), Ok(None));
+        assert_eq!(note.parse("abc"), Ok(Some(FieldValue::Text("abc".into()))));
+        assert!(note.parse("abcd").is_err());
+
+        assert!(FieldSpec::integer("n", "N").parse("1.5").is_err());
+    }
+
+    #[test]
+    fn conditions() {
+        let values = input(&[("type", "tip"), ("flag", "false")]);
+        assert!(Condition::equals("type", "tip").matches(&values));
+        assert!(Condition::not_equals("type", "cnft").matches(&values));
+        assert!(!Condition::is_set("flag").matches(&values));
+        assert!(!Condition::is_set("missing").matches(&values));
+        assert!(Condition::Any(vec![
+            Condition::is_set("flag"),
+            Condition::equals("type", "tip"),
+        ])
+        .matches(&values));
+        assert!(!Condition::All(vec![
+            Condition::is_set("flag"),
+            Condition::equals("type", "tip"),
+        ])
+        .matches(&values));
+    }
+
+    #[test]
+    fn standard_tip_roundtrip() {
+        let schema = DropSchema::standard();
+        let tip = schema.find(TIP_KIND).unwrap();
+        let record = tip.validate(&tip.initial_input()).unwrap();
+        assert!(tip.display_name(&record).ends_with(" ADA"));
+
+        let drop = record.to_drop().unwrap();
+        assert_eq!(DropRecord::from_drop(&drop), record);
+    }
+
+    #[test]
+    fn template_optional_sections() {
+        let value = |key: &str| (key == "a").then(|| "1".to_string());
+        assert_eq!(fill_template("{a}[ x{b}]", &value), "1");
+        assert_eq!(fill_template("{a}[ x{a}]", &value), "1 x1");
+        assert_eq!(fill_template("{b} pts", &value), " pts");
+        assert_eq!(fill_template("[{a}", &value), "[1");
+    }
+
+    #[test]
+    fn unknown_kind_is_an_error() {
+        let errors = DropSchema::standard()
+            .validate("nope", &DropInput::new())
+            .unwrap_err();
+        assert_eq!(errors[0].field, "");
+    }
+
+    #[test]
+    fn records_serialize_as_plain_values() {
+        let record = role_kind()
+            .validate(&input(&[
+                ("role", "og"),
+                ("expires", "true"),
+                ("days", "7"),
+            ]))
+            .unwrap();
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "kind": "role",
+                "values": { "days": 7, "expires": true, "role": "og" }
+            })
+        );
+    }
+}
@@ -1,12 +1,19 @@
 //! EmptyState Leptos Component
 //!
-//! A placeholder for empty content areas with message and optional action.
+//! A placeholder for empty content areas with a title, message, illustration
+//! and optional actions. Variant presets cover the common cases so lists,
+//! grids and panels show the same wording and icon for the same situation.
 //!
 //! ## Props
 //!
-//! - `message` - Main message text
-//! - `icon` - Optional icon/emoji
-//! - `action` - Optional action slot (e.g., button)
+//! - `variant` - Preset (NoResults, Error, NoWallet, Offline) supplying icon, title and message
+//! - `title` - Optional heading (overrides the variant's)
+//! - `message` - Main message text (overrides the variant's)
+//! - `icon` - Optional icon/emoji (overrides the variant's)
+//! - `illustration` - Optional illustration slot, shown instead of the icon
+//! - `primary_action` / `on_primary` - Label and callback for a primary button
+//! - `secondary_action` / `on_secondary` - Label and callback for a secondary button
+//! - `action` - Optional custom action slot (e.g., button)
 //!
 //! ## Usage
 //!
@@ -14,38 +21,211 @@
 //! <EmptyState message="No items found" icon="📭" />
 //!
 //! <EmptyState
-//!     message="No crew assigned"
-//!     icon="👥"
-//!     action=view! { <Button on_click=add>"Add Crew"</Button> }
+//!     variant=EmptyStateVariant::NoWallet
+//!     primary_action="Connect Wallet"
+//!     on_primary=move |_| open_connect()
+//! />
+//!
+//! <EmptyState
+//!     variant=EmptyStateVariant::Error
+//!     message=error_text
+//!     primary_action="Retry"
+//!     on_primary=move |_| reload()
+//!     secondary_action="Go back"
+//!     on_secondary=move |_| go_back()
 //! />
 //! ```
 
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::i18n::use_locale;
 use leptos::prelude::*;
 
+/// Preset empty-state situations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyStateVariant {
+    /// A search or filter matched nothing
+    NoResults,
+    /// Loading the content failed
+    Error,
+    /// The content needs a connected wallet
+    NoWallet,
+    /// The network is unavailable
+    Offline,
+}
+
+impl EmptyStateVariant {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoResults => "no-results",
+            Self::Error => "error",
+            Self::NoWallet => "no-wallet",
+            Self::Offline => "offline",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::NoResults => "🔍",
+            Self::Error => "⚠️",
+            Self::NoWallet => "👛",
+            Self::Offline => "📡",
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Self::NoResults => "no_results",
+            Self::Error => "error",
+            Self::NoWallet => "no_wallet",
+            Self::Offline => "offline",
+        }
+    }
+
+    /// i18n message key for the title
+    pub fn title_key(&self) -> String {
+        format!("empty_state.{}.title", self.key())
+    }
+
+    /// i18n message key for the message
+    pub fn message_key(&self) -> String {
+        format!("empty_state.{}.message", self.key())
+    }
+
+    pub fn default_title(&self) -> &'static str {
+        match self {
+            Self::NoResults => "No results",
+            Self::Error => "Something went wrong",
+            Self::NoWallet => "No wallet connected",
+            Self::Offline => "You're offline",
+        }
+    }
+
+    pub fn default_message(&self) -> &'static str {
+        match self {
+            Self::NoResults => "Try adjusting your search or filters.",
+            Self::Error => "We couldn't load this content. Please try again.",
+            Self::NoWallet => "Connect a wallet to see your assets.",
+            Self::Offline => "Check your connection and try again.",
+        }
+    }
+}
+
 /// Empty state placeholder component
 #[component]
 pub fn EmptyState(
+    /// Preset supplying icon, title and message
+    #[prop(optional)]
+    variant: Option<EmptyStateVariant>,
+    /// Optional heading
+    #[prop(into, optional)]
+    title: Option<String>,
     /// Main message text
-    #[prop(into)]
-    message: String,
+    #[prop(into, optional)]
+    message: Option<String>,
     /// Optional icon/emoji
     #[prop(into, optional)]
     icon: Option<String>,
+    /// Optional illustration slot, shown instead of the icon
+    #[prop(optional)]
+    illustration: Option<Children>,
+    /// Label for the primary action button
+    #[prop(into, optional)]
+    primary_action: Option<String>,
+    /// Primary action callback
+    #[prop(into, optional)]
+    on_primary: Option<Callback<()>>,
+    /// Label for the secondary action button
+    #[prop(into, optional)]
+    secondary_action: Option<String>,
+    /// Secondary action callback
+    #[prop(into, optional)]
+    on_secondary: Option<Callback<()>>,
     /// Optional action slot
     #[prop(optional)]
     action: Option<Children>,
 ) -> impl IntoView {
+    let locale = use_locale();
+
+    let class = match variant {
+        Some(v) => format!("ui-empty-state ui-empty-state--{}", v.as_str()),
+        None => "ui-empty-state".to_string(),
+    };
+
+    let icon = icon.or_else(|| variant.map(|v| v.icon().to_string()));
+    let visual = match illustration {
+        Some(children) => {
+            Some(view! { <div class="ui-empty-state__illustration">{children()}</div> }.into_any())
+        }
+        None => icon.map(|i| view! { <div class="ui-empty-state__icon">{i}</div> }.into_any()),
+    };
+
+    let title = move || {
+        title
+            .clone()
+            .or_else(|| variant.map(|v| locale.t_or(&v.title_key(), v.default_title())))
+    };
+    let message = move || {
+        message
+            .clone()
+            .or_else(|| variant.map(|v| locale.t_or(&v.message_key(), v.default_message())))
+    };
+
+    let primary = primary_action.map(|label| {
+        view! {
+            <Button size=ButtonSize::Sm on_click=move |_| {
+                if let Some(cb) = on_primary {
+                    cb.run(());
+                }
+            }>
+                {label}
+            </Button>
+        }
+    });
+    let secondary = secondary_action.map(|label| {
+        view! {
+            <Button variant=ButtonVariant::Ghost size=ButtonSize::Sm on_click=move |_| {
+                if let Some(cb) = on_secondary {
+                    cb.run(());
+                }
+            }>
+                {label}
+            </Button>
+        }
+    });
+    let has_buttons = primary.is_some() || secondary.is_some();
     let action_content = action.map(|a| a());
 
     view! {
-        <div class="ui-empty-state">
-            {icon.map(|i| view! {
-                <div class="ui-empty-state__icon">{i}</div>
+        <div class=class>
+            {visual}
+            {move || title().map(|t| view! {
+                <h3 class="ui-empty-state__title">{t}</h3>
+            })}
+            {move || message().map(|m| view! {
+                <p class="ui-empty-state__message">{m}</p>
+            })}
+            {has_buttons.then(|| view! {
+                <div class="ui-empty-state__actions">
+                    {primary}
+                    {secondary}
+                </div>
             })}
-            <p class="ui-empty-state__message">{message}</p>
             {action_content.map(|a| view! {
                 <div class="ui-empty-state__action">{a}</div>
             })}
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variant_keys() {
+        let v = EmptyStateVariant::NoWallet;
+        assert_eq!(v.as_str(), "no-wallet");
+        assert_eq!(v.title_key(), "empty_state.no_wallet.title");
+        assert_eq!(v.message_key(), "empty_state.no_wallet.message");
+    }
+}
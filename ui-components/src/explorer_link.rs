@@ -0,0 +1,356 @@
+//! ExplorerLink Leptos Component
+//!
+//! Links an address, transaction, asset or policy to a blockchain explorer,
+//! showing a shortened value with an optional copy button.
+//!
+//! The explorer and network come from the nearest [`provide_explorer_config`]
+//! (Cardanoscan on mainnet by default) and can be overridden per link. When
+//! an explorer has no page for the target - pool.pm off mainnet, or a
+//! fingerprint-keyed asset page without a fingerprint - the value renders as
+//! plain text.
+//!
+//! ## Props
+//!
+//! - `kind` - What the value identifies (Address, Tx, Asset, Policy)
+//! - `value` - Bech32 address, tx hash, asset unit (policy + name hex) or `asset1...` fingerprint, policy ID
+//! - `explorer` - Override the configured explorer
+//! - `network` - Override the configured network
+//! - `label` - Display text instead of the shortened value
+//! - `head` / `tail` - Characters kept either side when shortening (default 8 / 6)
+//! - `copyable` - Show a copy button (default true)
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! // Once at the app root
+//! provide_explorer_config(ExplorerConfig::new(Explorer::Cexplorer, ExplorerNetwork::Preprod));
+//!
+//! <ExplorerLink kind=ExplorerKind::Address value=address />
+//! <ExplorerLink kind=ExplorerKind::Tx value=tx_hash />
+//! <ExplorerLink kind=ExplorerKind::Asset value=unit explorer=Explorer::PoolPm label="View NFT" copyable=false />
+//! ```
+
+use crate::i18n::use_locale;
+use crate::truncated_text::shorten_middle;
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// How long the copy button shows its confirmation
+const COPIED_RESET: Duration = Duration::from_millis(1500);
+
+/// Supported blockchain explorers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Explorer {
+    #[default]
+    Cardanoscan,
+    Cexplorer,
+    PoolPm,
+}
+
+impl Explorer {
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Explorer::Cardanoscan => "Cardanoscan",
+            Explorer::Cexplorer => "Cexplorer",
+            Explorer::PoolPm => "pool.pm",
+        }
+    }
+
+    /// Base URL for a network, or `None` if the explorer doesn't index it
+    fn base_url(&self, network: ExplorerNetwork) -> Option<&'static str> {
+        use ExplorerNetwork::*;
+        Some(match (self, network) {
+            (Explorer::Cardanoscan, Mainnet) => "https://cardanoscan.io",
+            (Explorer::Cardanoscan, Preprod) => "https://preprod.cardanoscan.io",
+            (Explorer::Cardanoscan, Preview) => "https://preview.cardanoscan.io",
+            (Explorer::Cexplorer, Mainnet) => "https://cexplorer.io",
+            (Explorer::Cexplorer, Preprod) => "https://preprod.cexplorer.io",
+            (Explorer::Cexplorer, Preview) => "https://preview.cexplorer.io",
+            (Explorer::PoolPm, Mainnet) => "https://pool.pm",
+            (Explorer::PoolPm, _) => return None,
+        })
+    }
+
+    /// Whether asset pages are keyed by CIP-14 fingerprint rather than unit
+    fn needs_fingerprint(&self) -> bool {
+        !matches!(self, Explorer::Cardanoscan)
+    }
+}
+
+/// Cardano network the linked value lives on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExplorerNetwork {
+    #[default]
+    Mainnet,
+    Preprod,
+    Preview,
+}
+
+/// What an explorer link points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplorerKind {
+    Address,
+    Tx,
+    Asset,
+    Policy,
+}
+
+/// App-wide explorer defaults
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExplorerConfig {
+    pub explorer: Explorer,
+    pub network: ExplorerNetwork,
+}
+
+impl ExplorerConfig {
+    pub fn new(explorer: Explorer, network: ExplorerNetwork) -> Self {
+        Self { explorer, network }
+    }
+}
+
+/// Provide explorer defaults to descendant [`ExplorerLink`]s
+pub fn provide_explorer_config(config: ExplorerConfig) {
+    provide_context(config);
+}
+
+/// Get the explorer defaults, or Cardanoscan on mainnet outside a provider
+pub fn use_explorer_config() -> ExplorerConfig {
+    use_context::<ExplorerConfig>().unwrap_or_default()
+}
+
+/// Build the explorer URL for a value
+///
+/// Asset values may be a unit (policy ID + asset name hex) or an `asset1...`
+/// fingerprint. Explorers keyed by fingerprint need the `wallet` feature to
+/// link a unit; without it only fingerprints produce a URL for them.
+pub fn explorer_url(
+    explorer: Explorer,
+    network: ExplorerNetwork,
+    kind: ExplorerKind,
+    value: &str,
+) -> Option<String> {
+    let base = explorer.base_url(network)?;
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    let url = match (explorer, kind) {
+        (Explorer::Cardanoscan, ExplorerKind::Address) => format!("{base}/address/{value}"),
+        (Explorer::Cardanoscan, ExplorerKind::Tx) => format!("{base}/transaction/{value}"),
+        (Explorer::Cardanoscan, ExplorerKind::Asset) => format!("{base}/token/{value}"),
+        (Explorer::Cardanoscan, ExplorerKind::Policy) => format!("{base}/tokenPolicy/{value}"),
+        (Explorer::Cexplorer, ExplorerKind::Address) => format!("{base}/address/{value}"),
+        (Explorer::Cexplorer, ExplorerKind::Tx) => format!("{base}/tx/{value}"),
+        (Explorer::Cexplorer, ExplorerKind::Asset) => {
+            format!("{base}/asset/{}", asset_ref(explorer, value)?)
+        }
+        (Explorer::Cexplorer, ExplorerKind::Policy) => format!("{base}/policy/{value}"),
+        (Explorer::PoolPm, ExplorerKind::Address) => format!("{base}/{value}"),
+        (Explorer::PoolPm, ExplorerKind::Tx) => format!("{base}/tx/{value}"),
+        (Explorer::PoolPm, ExplorerKind::Asset) => {
+            format!("{base}/{}", asset_ref(explorer, value)?)
+        }
+        (Explorer::PoolPm, ExplorerKind::Policy) => format!("{base}/policy/{value}"),
+    };
+    Some(url)
+}
+
+/// The asset identifier an explorer's asset page expects
+fn asset_ref(explorer: Explorer, value: &str) -> Option<String> {
+    if !explorer.needs_fingerprint() || value.starts_with("asset1") {
+        return Some(value.to_string());
+    }
+    unit_fingerprint(value)
+}
+
+#[cfg(feature = "wallet")]
+fn unit_fingerprint(unit: &str) -> Option<String> {
+    const POLICY_ID_HEX_LEN: usize = 56;
+    if unit.len() < POLICY_ID_HEX_LEN || !unit.is_char_boundary(POLICY_ID_HEX_LEN) {
+        return None;
+    }
+    let (policy_id, asset_name_hex) = unit.split_at(POLICY_ID_HEX_LEN);
+    wallet_pallas::asset_fingerprint(policy_id, asset_name_hex).ok()
+}
+
+#[cfg(not(feature = "wallet"))]
+fn unit_fingerprint(_unit: &str) -> Option<String> {
+    None
+}
+
+/// Explorer link component
+#[component]
+pub fn ExplorerLink(
+    /// What the value identifies
+    kind: ExplorerKind,
+    /// Address, tx hash, asset unit or fingerprint, or policy ID
+    #[prop(into)]
+    value: String,
+    /// Override the configured explorer
+    #[prop(optional)]
+    explorer: Option<Explorer>,
+    /// Override the configured network
+    #[prop(optional)]
+    network: Option<ExplorerNetwork>,
+    /// Display text instead of the shortened value
+    #[prop(into, optional)]
+    label: Option<String>,
+    /// Characters kept before the ellipsis
+    #[prop(optional, default = 8)]
+    head: usize,
+    /// Characters kept after the ellipsis
+    #[prop(optional, default = 6)]
+    tail: usize,
+    /// Show a copy button
+    #[prop(optional, default = true)]
+    copyable: bool,
+) -> impl IntoView {
+    let locale = use_locale();
+    let config = use_explorer_config();
+    let explorer = explorer.unwrap_or(config.explorer);
+    let network = network.unwrap_or(config.network);
+
+    let url = explorer_url(explorer, network, kind, &value);
+    let text = label.unwrap_or_else(|| shorten_middle(&value, head, tail));
+
+    let content = match url {
+        Some(url) => {
+            let title = locale.t_or_with(
+                "explorer_link.view_on",
+                "View on {explorer}",
+                &[("explorer", explorer.name().to_string())],
+            );
+            view! {
+                <a
+                    class="ui-explorer-link__link"
+                    href=url
+                    target="_blank"
+                    rel="noopener noreferrer"
+                    title=title
+                >
+                    {text}
+                </a>
+            }
+            .into_any()
+        }
+        None => view! {
+            <span class="ui-explorer-link__text" title=value.clone()>{text}</span>
+        }
+        .into_any(),
+    };
+
+    let copied = RwSignal::new(false);
+    let copy = move |ev: leptos::ev::MouseEvent| {
+        ev.prevent_default();
+        ev.stop_propagation();
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let promise = window.navigator().clipboard().write_text(&value);
+        wasm_bindgen_futures::spawn_local(async move {
+            match wasm_bindgen_futures::JsFuture::from(promise).await {
+                Ok(_) => {
+                    copied.set(true);
+                    set_timeout(move || copied.set(false), COPIED_RESET);
+                }
+                Err(e) => tracing::warn!("Failed to copy to clipboard: {e:?}"),
+            }
+        });
+    };
+
+    view! {
+        <span class="ui-explorer-link">
+            {content}
+            {copyable.then(|| view! {
+                <button
+                    class="ui-explorer-link__copy"
+                    class:ui-explorer-link__copy--copied=move || copied.get()
+                    on:click=copy
+                    aria-label=move || {
+                        if copied.get() {
+                            locale.t_or("explorer_link.copied", "Copied")
+                        } else {
+                            locale.t_or("explorer_link.copy", "Copy")
+                        }
+                    }
+                >
+                    {move || if copied.get() { "✓" } else { "⧉" }}
+                </button>
+            })}
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FINGERPRINT: &str = "asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92";
+    const UNIT: &str = "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373504154415445";
+
+    #[test]
+    fn test_explorer_url_networks() {
+        use ExplorerNetwork::*;
+        assert_eq!(
+            explorer_url(Explorer::Cardanoscan, Mainnet, ExplorerKind::Tx, "abc").as_deref(),
+            Some("https://cardanoscan.io/transaction/abc")
+        );
+        assert_eq!(
+            explorer_url(
+                Explorer::Cexplorer,
+                Preprod,
+                ExplorerKind::Address,
+                "addr_test1x"
+            )
+            .as_deref(),
+            Some("https://preprod.cexplorer.io/address/addr_test1x")
+        );
+        assert_eq!(
+            explorer_url(
+                Explorer::PoolPm,
+                Preprod,
+                ExplorerKind::Address,
+                "addr_test1x"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_explorer_url_assets() {
+        use ExplorerNetwork::Mainnet;
+        assert_eq!(
+            explorer_url(Explorer::Cardanoscan, Mainnet, ExplorerKind::Asset, UNIT),
+            Some(format!("https://cardanoscan.io/token/{UNIT}"))
+        );
+        assert_eq!(
+            explorer_url(Explorer::PoolPm, Mainnet, ExplorerKind::Asset, FINGERPRINT),
+            Some(format!("https://pool.pm/{FINGERPRINT}"))
+        );
+        assert_eq!(
+            explorer_url(
+                Explorer::Cexplorer,
+                Mainnet,
+                ExplorerKind::Policy,
+                &UNIT[..56]
+            ),
+            Some(format!("https://cexplorer.io/policy/{}", &UNIT[..56]))
+        );
+    }
+
+    #[cfg(feature = "wallet")]
+    #[test]
+    fn test_explorer_url_fingerprints_units() {
+        assert_eq!(
+            explorer_url(
+                Explorer::Cexplorer,
+                ExplorerNetwork::Mainnet,
+                ExplorerKind::Asset,
+                UNIT
+            ),
+            Some(format!("https://cexplorer.io/asset/{FINGERPRINT}"))
+        );
+    }
+}
@@ -0,0 +1,268 @@
+//! FlowDebugOverlay Leptos Component
+//!
+//! A collapsible panel listing the frames recorded by a [`ui_flow::FlowDebug`]
+//! handle, newest first, with optional [`ui_flow::FlowMetrics`] counters
+//! above the list. Development aid; requires the `flow-debug` feature.
+//!
+//! ## Props
+//!
+//! - `debug` - Recorder attached to the connection with `.debug(...)`
+//! - `metrics` - Counters attached with `.metrics(...)`, shown as a stats row
+//! - `open` - Start expanded (default: false)
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let debug = FlowDebug::default();
+//! let conn = FlowConnection::builder().url(&url).debug(debug.clone()).connect()?;
+//!
+//! view! { <FlowDebugOverlay debug=debug /> }
+//!
+//! // With traffic counters
+//! let metrics = FlowMetrics::new();
+//! let conn = FlowConnection::builder()
+//!     .url(&url)
+//!     .debug(debug.clone())
+//!     .metrics(metrics.clone())
+//!     .connect()?;
+//!
+//! view! { <FlowDebugOverlay debug=debug metrics=metrics /> }
+//! ```
+
+use std::time::Duration;
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use ui_flow::{FlowDebug, FlowMetrics, MessageRecord, MetricsSnapshot};
+
+/// How often metrics rates are refreshed while the panel is open
+const METRICS_REFRESH: Duration = Duration::from_secs(1);
+
+/// Floating panel showing recent Flow protocol traffic
+#[component]
+pub fn FlowDebugOverlay(
+    /// Recorder to display
+    debug: FlowDebug,
+    /// Traffic counters to display
+    #[prop(optional)]
+    metrics: Option<FlowMetrics>,
+    /// Start expanded
+    #[prop(optional)]
+    open: bool,
+) -> impl IntoView {
+    let records = RwSignal::new(debug.records());
+    let paused = RwSignal::new(!debug.is_enabled());
+    let open = RwSignal::new(open);
+    let capacity = debug.capacity();
+
+    let listener = debug.on_record(move |record| {
+        records.try_update(|records| {
+            records.push(record.clone());
+            if records.len() > capacity {
+                records.remove(0);
+            }
+        });
+    });
+
+    let debug = StoredValue::new(SendWrapper::new(debug));
+    on_cleanup(move || {
+        debug.with_value(|debug| debug.remove_listener(listener));
+    });
+
+    let has_metrics = metrics.is_some();
+    let snapshot = RwSignal::new(
+        metrics
+            .as_ref()
+            .map(FlowMetrics::snapshot)
+            .unwrap_or_default(),
+    );
+    if let Some(metrics) = metrics {
+        let listener = metrics.on_change(move |s| {
+            snapshot.try_set(s.clone());
+        });
+
+        // Rates only move with traffic; poll so idle rates fall to zero
+        let metrics = StoredValue::new(SendWrapper::new(metrics));
+        if let Ok(handle) = set_interval_with_handle(
+            move || {
+                if open.get_untracked() {
+                    snapshot.set(metrics.with_value(|m| m.snapshot()));
+                }
+            },
+            METRICS_REFRESH,
+        ) {
+            on_cleanup(move || handle.clear());
+        }
+        on_cleanup(move || {
+            metrics.with_value(|metrics| metrics.remove_listener(listener));
+        });
+    }
+
+    let toggle_open = move |_| open.update(|open| *open = !*open);
+    let toggle_paused = move |_| {
+        let pause = !paused.get_untracked();
+        debug.with_value(|debug| debug.set_enabled(!pause));
+        paused.set(pause);
+    };
+    let clear = move |_| {
+        debug.with_value(|debug| debug.clear());
+        records.set(Vec::new());
+    };
+
+    let error_count =
+        move || records.with(|records| records.iter().filter(|r| r.is_error()).count());
+
+    view! {
+        <div class="ui-flow-debug">
+            <div class="ui-flow-debug__header">
+                <button class="ui-flow-debug__toggle" on:click=toggle_open>
+                    "Flow "
+                    <span class="ui-flow-debug__count">{move || records.with(Vec::len)}</span>
+                    {move || {
+                        let errors = error_count();
+                        (errors > 0)
+                            .then(|| {
+                                view! {
+                                    <span class="ui-flow-debug__errors">{errors}" err"</span>
+                                }
+                            })
+                    }}
+                </button>
+                <Show when=move || open.get()>
+                    <button class="ui-flow-debug__action" on:click=toggle_paused>
+                        {move || if paused.get() { "Resume" } else { "Pause" }}
+                    </button>
+                    <button class="ui-flow-debug__action" on:click=clear>
+                        "Clear"
+                    </button>
+                </Show>
+            </div>
+            <Show when=move || open.get() && has_metrics>
+                {move || metrics_row(&snapshot.get())}
+            </Show>
+            <Show when=move || open.get()>
+                <ol class="ui-flow-debug__list">
+                    {move || {
+                        records
+                            .get()
+                            .iter()
+                            .rev()
+                            .map(record_row)
+                            .collect_view()
+                    }}
+                </ol>
+            </Show>
+        </div>
+    }
+}
+
+fn metrics_row(snapshot: &MetricsSnapshot) -> impl IntoView {
+    let stat = |label: &'static str, value: String| {
+        view! {
+            <span class="ui-flow-debug__stat">
+                <span class="ui-flow-debug__stat-label">{label}</span>
+                {value}
+            </span>
+        }
+    };
+    let latency = snapshot
+        .latency_ms
+        .map(|ms| format!("{ms} ms"))
+        .unwrap_or_else(|| "-".to_string());
+
+    view! {
+        <div class="ui-flow-debug__metrics">
+            {stat(
+                "in",
+                format!(
+                    "{} ({:.1}/s, {})",
+                    snapshot.messages_received,
+                    snapshot.messages_in_per_sec,
+                    format_bytes(snapshot.bytes_received),
+                ),
+            )}
+            {stat(
+                "out",
+                format!(
+                    "{} ({:.1}/s, {})",
+                    snapshot.messages_sent,
+                    snapshot.messages_out_per_sec,
+                    format_bytes(snapshot.bytes_sent),
+                ),
+            )}
+            {stat("rtt", latency)}
+            {stat("reconnects", snapshot.reconnects.to_string())}
+            {stat(
+                "failed",
+                format!("{} decode, {} send", snapshot.decode_failures, snapshot.send_failures),
+            )}
+        </div>
+    }
+}
+
+/// Byte count with a binary unit (`1.5 KiB`)
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+fn record_row(record: &MessageRecord) -> impl IntoView {
+    let class = if record.is_error() {
+        "ui-flow-debug__row ui-flow-debug__row--error"
+    } else {
+        "ui-flow-debug__row"
+    };
+
+    view! {
+        <li class=class>
+            <span class="ui-flow-debug__time">{format_time(record.timestamp_ms)}</span>
+            <span class="ui-flow-debug__direction">{record.direction.arrow()}</span>
+            <span class="ui-flow-debug__kind">
+                {record.kind.clone().unwrap_or_else(|| "?".to_string())}
+            </span>
+            <span class="ui-flow-debug__op">
+                {record.op_id.map(|op_id| op_id.to_string()).unwrap_or_default()}
+            </span>
+            <span class="ui-flow-debug__size">{format!("{} B", record.size)}</span>
+            {record
+                .error
+                .clone()
+                .map(|error| view! { <span class="ui-flow-debug__error">{error}</span> })}
+        </li>
+    }
+}
+
+/// Local wall-clock time as `HH:MM:SS.mmm`
+fn format_time(timestamp_ms: f64) -> String {
+    let date = js_sys::Date::new(&timestamp_ms.into());
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        date.get_hours(),
+        date.get_minutes(),
+        date.get_seconds(),
+        date.get_milliseconds()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024 * 1024), "3072.0 GiB");
+    }
+}
@@ -1,12 +1,19 @@
 //! Page Header Leptos Component
 //!
-//! A page header with title, optional subtitle, and action buttons slot.
+//! A page header with breadcrumbs, title, optional subtitle, and left/right
+//! action slots. Can stick to the top of the page, shrink once the page
+//! scrolls, and fold its right-hand actions into a menu on narrow widths.
 //!
 //! ## Props
 //!
 //! - `title` - Main page title
 //! - `subtitle` - Optional subtitle/description
-//! - `actions` - Optional slot for action buttons
+//! - `breadcrumbs` - Optional trail shown above the title; the last crumb is the current page
+//! - `left_actions` - Optional slot before the title (back button, logo)
+//! - `actions` - Optional slot for action buttons on the right
+//! - `collapse_below` - Header width (px) below which `actions` fold into a menu
+//! - `sticky` - Stick to the top of the scroll container
+//! - `shrink_on_scroll` - Compact padding and title once the window scrolls
 //!
 //! ## Usage
 //!
@@ -25,9 +32,49 @@
 //!         </Button>
 //!     }
 //! />
+//!
+//! // Widget bar: breadcrumbs, back button, sticky and collapsing
+//! <PageHeader
+//!     title="Raffle #12"
+//!     breadcrumbs=vec![
+//!         Breadcrumb::link("Raffles", "/raffles"),
+//!         Breadcrumb::new("Raffle #12"),
+//!     ]
+//!     left_actions=view! { <Button on_click=go_back>"←"</Button> }
+//!     actions=view! {
+//!         <Button on_click=edit>"Edit"</Button>
+//!         <Button on_click=draw>"Draw"</Button>
+//!     }
+//!     collapse_below=560
+//!     sticky=true
+//!     shrink_on_scroll=true
+//! />
 //! ```
 
 use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+use crate::breadcrumbs::{Breadcrumb, Breadcrumbs};
+use crate::i18n::use_locale;
+
+/// Scroll offset (px) past which a `shrink_on_scroll` header shrinks
+const SHRINK_AT: f64 = 48.0;
+
+/// Scroll offset (px) below which a shrunk header grows back
+///
+/// Lower than [`SHRINK_AT`] so the size change itself can't make it flicker.
+const GROW_AT: f64 = 16.0;
+
+/// Whether a shrink-on-scroll header should be shrunk at `scroll_y`
+fn next_shrunk(shrunk: bool, scroll_y: f64) -> bool {
+    if shrunk {
+        scroll_y > GROW_AT
+    } else {
+        scroll_y > SHRINK_AT
+    }
+}
 
 /// Page header component
 #[component]
@@ -38,13 +85,126 @@ pub fn PageHeader(
     /// Optional subtitle/description
     #[prop(into, optional)]
     subtitle: Option<String>,
+    /// Breadcrumb trail above the title
+    #[prop(into, optional)]
+    breadcrumbs: Option<Vec<Breadcrumb>>,
+    /// Optional slot before the title
+    #[prop(optional)]
+    left_actions: Option<Children>,
     /// Optional action buttons slot
     #[prop(optional)]
     actions: Option<Children>,
+    /// Header width in pixels below which `actions` fold into a menu
+    #[prop(optional)]
+    collapse_below: Option<u32>,
+    /// Stick to the top while scrolling
+    #[prop(optional)]
+    sticky: bool,
+    /// Shrink once the window has scrolled
+    #[prop(optional)]
+    shrink_on_scroll: bool,
 ) -> impl IntoView {
+    let locale = use_locale();
+    let header_ref = NodeRef::<leptos::html::Header>::new();
+
+    // Collapsed while narrower than `collapse_below`
+    let width = RwSignal::new(None::<f64>);
+    let collapsed = Memo::new(move |_| {
+        collapse_below.is_some_and(|min| width.get().is_some_and(|w| w < min as f64))
+    });
+    let (menu_open, set_menu_open) = signal(false);
+
+    if collapse_below.is_some() {
+        let observer = StoredValue::new(None::<SendWrapper<web_sys::ResizeObserver>>);
+
+        Effect::new(move |_| {
+            let Some(header) = header_ref.get() else {
+                return;
+            };
+            let element: web_sys::Element = header.into();
+            width.set(Some(element.client_width() as f64));
+
+            let callback = Closure::wrap(Box::new(
+                move |entries: js_sys::Array, _observer: web_sys::ResizeObserver| {
+                    if let Some(entry) = entries.get(0).dyn_ref::<web_sys::ResizeObserverEntry>() {
+                        width.set(Some(entry.target().client_width() as f64));
+                    }
+                },
+            )
+                as Box<dyn FnMut(js_sys::Array, web_sys::ResizeObserver)>);
+
+            if let Ok(resize) = web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref()) {
+                resize.observe(&element);
+                callback.forget(); // Lives as long as the observer
+                observer.set_value(Some(SendWrapper::new(resize)));
+            }
+        });
+
+        // Close the menu on a click outside the header
+        let outside = window_event_listener(leptos::ev::pointerdown, move |ev| {
+            if !menu_open.get_untracked() {
+                return;
+            }
+            let inside = header_ref.get_untracked().is_some_and(|header| {
+                let target = ev.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+                header.contains(target.as_ref())
+            });
+            if !inside {
+                set_menu_open.set(false);
+            }
+        });
+
+        on_cleanup(move || {
+            outside.remove();
+            if let Some(resize) = observer.try_update_value(Option::take).flatten() {
+                resize.disconnect();
+            }
+        });
+    }
+
+    // Expanding again closes a menu left open
+    Effect::new(move |_| {
+        if !collapsed.get() {
+            set_menu_open.set(false);
+        }
+    });
+
+    let shrunk = RwSignal::new(false);
+    if shrink_on_scroll {
+        let scroll_y = || {
+            web_sys::window()
+                .and_then(|w| w.scroll_y().ok())
+                .unwrap_or(0.0)
+        };
+        shrunk.set(next_shrunk(false, scroll_y()));
+        let scroll = window_event_listener(leptos::ev::scroll, move |_| {
+            let next = next_shrunk(shrunk.get_untracked(), scroll_y());
+            if next != shrunk.get_untracked() {
+                shrunk.set(next);
+            }
+        });
+        on_cleanup(move || scroll.remove());
+    }
+
     view! {
-        <header class="ui-page-header">
+        <header
+            node_ref=header_ref
+            class="ui-page-header"
+            class:ui-page-header--sticky=sticky
+            class:ui-page-header--shrunk=move || shrunk.get()
+            class:ui-page-header--collapsed=move || collapsed.get()
+        >
+            {left_actions.map(|a| view! {
+                <div class="ui-page-header__left">
+                    {a()}
+                </div>
+            })}
+
             <div class="ui-page-header__content">
+                {breadcrumbs.filter(|b| !b.is_empty()).map(|crumbs| view! {
+                    <Breadcrumbs items=crumbs class="ui-page-header__breadcrumbs" />
+                })}
+
                 {title.map(|t| view! {
                     <h1 class="ui-page-header__title">{t}</h1>
                 })}
@@ -55,10 +215,60 @@ pub fn PageHeader(
             </div>
 
             {actions.map(|a| view! {
-                <div class="ui-page-header__actions">
-                    {a()}
+                <div class="ui-page-header__right">
+                    <Show when=move || collapsed.get()>
+                        <button
+                            type="button"
+                            class="ui-page-header__menu-toggle"
+                            aria-haspopup="true"
+                            aria-expanded=move || menu_open.get().to_string()
+                            aria-label=move || locale.t_or("page_header.menu", "More actions")
+                            on:click=move |_| set_menu_open.update(|open| *open = !*open)
+                        >
+                            "⋯"
+                        </button>
+                    </Show>
+                    // Rendered once; collapsing restyles it as a dropdown
+                    <div
+                        class="ui-page-header__actions"
+                        class:ui-page-header__actions--menu=move || collapsed.get()
+                        class:ui-page-header__actions--open=move || menu_open.get()
+                        on:click=move |_| {
+                            if collapsed.get_untracked() {
+                                set_menu_open.set(false);
+                            }
+                        }
+                        on:keydown=move |ev| {
+                            if ev.key() == "Escape" {
+                                set_menu_open.set(false);
+                            }
+                        }
+                    >
+                        {a()}
+                    </div>
                 </div>
             })}
         </header>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_past_threshold() {
+        assert!(!next_shrunk(false, 0.0));
+        assert!(!next_shrunk(false, SHRINK_AT));
+        assert!(next_shrunk(false, SHRINK_AT + 1.0));
+    }
+
+    #[test]
+    fn grows_back_with_hysteresis() {
+        // Between the thresholds the current state is kept
+        let between = (GROW_AT + SHRINK_AT) / 2.0;
+        assert!(next_shrunk(true, between));
+        assert!(!next_shrunk(false, between));
+        assert!(!next_shrunk(true, GROW_AT));
+    }
+}
@@ -0,0 +1,140 @@
+//! Locale context for Leptos components
+//!
+//! Leptos adapter over [`ui_core::i18n`]. The active locale lives in a signal so
+//! translated text re-renders when the locale changes.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_components::{provide_locale, use_locale};
+//!
+//! #[component]
+//! fn App() -> impl IntoView {
+//!     ui_components::register_component_catalog();
+//!     provide_locale(auth.context().map(|ctx| ctx.claims()));
+//!     view! { <Content /> }
+//! }
+//!
+//! #[component]
+//! fn Content() -> impl IntoView {
+//!     let locale = use_locale();
+//!     view! {
+//!         <h1>{move || locale.t("game.title")}</h1>
+//!         <button on:click=move |_| locale.set("de")>"Deutsch"</button>
+//!     }
+//! }
+//! ```
+
+use leptos::prelude::*;
+use ui_core::i18n::{self, Catalog};
+use ui_core::WidgetClaims;
+
+/// English messages for the shared components
+const COMPONENT_MESSAGES_EN: &str = include_str!("../locales/en.json");
+
+/// Register the English catalog for the shared components
+///
+/// Components render their built-in English text without it; registering makes
+/// the keys discoverable and lets apps override individual messages by
+/// registering their own catalog afterwards.
+pub fn register_component_catalog() {
+    match Catalog::from_json("en", COMPONENT_MESSAGES_EN) {
+        Ok(catalog) => i18n::register_catalog(catalog),
+        Err(e) => tracing::warn!("Failed to load component catalog: {e}"),
+    }
+}
+
+/// Reactive handle to the active locale
+#[derive(Clone, Copy)]
+pub struct LocaleContext {
+    locale: RwSignal<String>,
+}
+
+impl LocaleContext {
+    fn new(locale: String) -> Self {
+        Self {
+            locale: RwSignal::new(locale),
+        }
+    }
+
+    /// Get the active locale (tracked)
+    pub fn get(&self) -> String {
+        self.locale.get()
+    }
+
+    /// Get the active locale as a read-only signal
+    pub fn signal(&self) -> Signal<String> {
+        self.locale.into()
+    }
+
+    /// Switch the active locale
+    pub fn set(&self, locale: &str) {
+        i18n::set_locale(locale);
+        self.locale.set(i18n::current_locale());
+    }
+
+    /// Translate a key, re-running when the locale changes
+    pub fn t(&self, key: &str) -> String {
+        self.locale.track();
+        i18n::translate(key, &[])
+    }
+
+    /// Translate a key with `{name}` arguments, re-running when the locale changes
+    pub fn t_with(&self, key: &str, args: &[(&str, String)]) -> String {
+        self.locale.track();
+        i18n::translate(key, args)
+    }
+
+    /// Translate a key with a built-in default, re-running when the locale changes
+    pub fn t_or(&self, key: &str, default: &str) -> String {
+        self.locale.track();
+        i18n::translate_or(key, default, &[])
+    }
+
+    /// Translate a key with a built-in default and `{name}` arguments
+    pub fn t_or_with(&self, key: &str, default: &str, args: &[(&str, String)]) -> String {
+        self.locale.track();
+        i18n::translate_or(key, default, args)
+    }
+}
+
+/// Negotiate the locale and provide a [`LocaleContext`] to descendants
+///
+/// Uses the JWT `locale` claim when available, then the browser languages.
+pub fn provide_locale(claims: Option<&WidgetClaims>) -> LocaleContext {
+    let ctx = LocaleContext::new(i18n::init_locale(claims));
+    provide_context(ctx);
+    ctx
+}
+
+/// Get the locale context
+///
+/// Outside a [`provide_locale`] scope this returns a detached context seeded
+/// with the current global locale, so components can always call it.
+pub fn use_locale() -> LocaleContext {
+    use_context::<LocaleContext>().unwrap_or_else(|| LocaleContext::new(i18n::current_locale()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_component_catalog_parses() {
+        let catalog = Catalog::from_json("en", COMPONENT_MESSAGES_EN).unwrap();
+        assert_eq!(catalog.get("modal.close"), Some("Close"));
+        assert_eq!(
+            catalog.get("pagination.info"),
+            Some("({current} of {total})")
+        );
+    }
+
+    #[cfg(feature = "wallet")]
+    #[test]
+    fn test_catalog_matches_sort_labels() {
+        let catalog = Catalog::from_json("en", COMPONENT_MESSAGES_EN).unwrap();
+        for sort in crate::wallet_nft_gallery::NftSort::ALL {
+            assert_eq!(catalog.get(sort.message_key()), Some(sort.label()));
+        }
+    }
+}
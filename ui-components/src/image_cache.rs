@@ -2,15 +2,38 @@
 //!
 //! This module provides a shared cache that stores preloaded images as blob URLs.
 //! Components can check this cache before loading images from the network.
+//!
+//! The in-memory tier is backed by the browser Cache Storage API, so images
+//! fetched in a previous session are served from disk instead of the network.
+//! The persistent tier is capped by total size (see [`set_persistent_limit`])
+//! and evicts least recently used images first. It is best-effort: when Cache
+//! Storage is unavailable (insecure context, private browsing) preloading falls
+//! back to memory only.
 
+use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+/// Cache Storage bucket holding image responses
+const CACHE_NAME: &str = "ui-image-cache-v1";
+
+/// localStorage key for the persistent tier's size/recency index
+const INDEX_KEY: &str = "ui-image-cache-v1:index";
+
+/// Default size cap for the persistent tier (100 MB)
+pub const DEFAULT_PERSISTENT_LIMIT: u64 = 100 * 1024 * 1024;
 
 thread_local! {
     /// Global cache mapping image URLs to blob URLs
     static IMAGE_CACHE: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+
+    /// Persistent tier index, loaded from localStorage on first use
+    static INDEX: RefCell<Option<PersistentIndex>> = const { RefCell::new(None) };
+
+    static PERSISTENT_LIMIT: RefCell<u64> = const { RefCell::new(DEFAULT_PERSISTENT_LIMIT) };
 }
 
 /// Check if an image URL is in the cache
@@ -30,6 +53,18 @@ pub fn cache_size() -> usize {
     IMAGE_CACHE.with(|cache| cache.borrow().len())
 }
 
+/// Set the size cap for the persistent tier in bytes
+///
+/// Takes effect on the next write.
+pub fn set_persistent_limit(bytes: u64) {
+    PERSISTENT_LIMIT.with(|limit| *limit.borrow_mut() = bytes);
+}
+
+/// Total bytes currently held in the persistent tier
+pub fn persistent_cache_bytes() -> u64 {
+    with_index(|index| index.total_bytes())
+}
+
 /// Preload an image and store it in the cache as a blob URL
 /// Returns a Future that resolves when the image is cached
 pub async fn preload_image(url: String) -> Result<String, JsValue> {
@@ -38,12 +73,17 @@ pub async fn preload_image(url: String) -> Result<String, JsValue> {
         return Ok(blob_url);
     }
 
+    // Check the persistent tier before going to the network
+    if let Some(blob) = persistent_get(&url).await {
+        let blob_url = web_sys::Url::create_object_url_with_blob(&blob)?;
+        cache_blob_url(url, blob_url.clone());
+        return Ok(blob_url);
+    }
+
     // Fetch the image as a blob
     let window = web_sys::window().ok_or("no window")?;
     let resp_promise = window.fetch_with_str(&url);
-    let resp: web_sys::Response = wasm_bindgen_futures::JsFuture::from(resp_promise)
-        .await?
-        .dyn_into()?;
+    let resp: web_sys::Response = JsFuture::from(resp_promise).await?.dyn_into()?;
 
     if !resp.ok() {
         return Err(JsValue::from_str(&format!(
@@ -53,15 +93,231 @@ pub async fn preload_image(url: String) -> Result<String, JsValue> {
     }
 
     let blob_promise = resp.blob()?;
-    let blob: web_sys::Blob = wasm_bindgen_futures::JsFuture::from(blob_promise)
-        .await?
-        .dyn_into()?;
+    let blob: web_sys::Blob = JsFuture::from(blob_promise).await?.dyn_into()?;
 
     // Create a blob URL
     let blob_url = web_sys::Url::create_object_url_with_blob(&blob)?;
 
+    persistent_put(&url, &blob).await;
+
     // Store in cache
     cache_blob_url(url, blob_url.clone());
 
     Ok(blob_url)
 }
+
+/// Clear both the in-memory and persistent tiers
+///
+/// Revokes every blob URL handed out so far, so images already rendered from
+/// the cache will need to be reloaded.
+pub async fn clear_cache() -> Result<(), JsValue> {
+    IMAGE_CACHE.with(|cache| {
+        for blob_url in cache.borrow_mut().drain().map(|(_, v)| v) {
+            let _ = web_sys::Url::revoke_object_url(&blob_url);
+        }
+    });
+
+    with_index(|index| index.entries.clear());
+    save_index();
+
+    let caches = web_sys::window().ok_or("no window")?.caches()?;
+    JsFuture::from(caches.delete(CACHE_NAME)).await?;
+    Ok(())
+}
+
+// ============================================================================
+// Persistent tier
+// ============================================================================
+
+/// One image held in the persistent tier
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct IndexEntry {
+    url: String,
+    bytes: u64,
+}
+
+/// Size and recency bookkeeping for the persistent tier
+///
+/// Cache Storage doesn't report entry sizes, so they're tracked here. Entries
+/// are kept in least-recently-used order (oldest first).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct PersistentIndex {
+    entries: Vec<IndexEntry>,
+}
+
+impl PersistentIndex {
+    fn total_bytes(&self) -> u64 {
+        self.entries.iter().map(|e| e.bytes).sum()
+    }
+
+    fn contains(&self, url: &str) -> bool {
+        self.entries.iter().any(|e| e.url == url)
+    }
+
+    /// Mark an entry as most recently used
+    fn touch(&mut self, url: &str) {
+        if let Some(pos) = self.entries.iter().position(|e| e.url == url) {
+            let entry = self.entries.remove(pos);
+            self.entries.push(entry);
+        }
+    }
+
+    fn remove(&mut self, url: &str) {
+        self.entries.retain(|e| e.url != url);
+    }
+
+    /// Record a new entry and evict until under `limit`
+    ///
+    /// Returns the URLs evicted. An entry larger than the limit on its own is
+    /// evicted immediately.
+    fn insert(&mut self, url: &str, bytes: u64, limit: u64) -> Vec<String> {
+        self.remove(url);
+        self.entries.push(IndexEntry {
+            url: url.to_string(),
+            bytes,
+        });
+
+        let mut evicted = Vec::new();
+        while self.total_bytes() > limit && !self.entries.is_empty() {
+            evicted.push(self.entries.remove(0).url);
+        }
+        evicted
+    }
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+fn with_index<R>(f: impl FnOnce(&mut PersistentIndex) -> R) -> R {
+    INDEX.with(|index| {
+        let mut index = index.borrow_mut();
+        let index = index.get_or_insert_with(|| {
+            local_storage()
+                .and_then(|s| s.get_item(INDEX_KEY).ok().flatten())
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        });
+        f(index)
+    })
+}
+
+fn save_index() {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+    let json = with_index(|index| serde_json::to_string(index).unwrap_or_default());
+    if let Err(e) = storage.set_item(INDEX_KEY, &json) {
+        tracing::warn!("Failed to save image cache index: {e:?}");
+    }
+}
+
+async fn open_cache() -> Option<web_sys::Cache> {
+    let caches = web_sys::window()?.caches().ok()?;
+    JsFuture::from(caches.open(CACHE_NAME))
+        .await
+        .ok()?
+        .dyn_into()
+        .ok()
+}
+
+/// Read an image from the persistent tier
+async fn persistent_get(url: &str) -> Option<web_sys::Blob> {
+    if !with_index(|index| index.contains(url)) {
+        return None;
+    }
+
+    let cache = open_cache().await?;
+    let found = JsFuture::from(cache.match_with_str(url)).await.ok()?;
+    let Ok(resp) = found.dyn_into::<web_sys::Response>() else {
+        // Evicted by the browser - drop the stale index entry
+        with_index(|index| index.remove(url));
+        save_index();
+        return None;
+    };
+
+    let blob = JsFuture::from(resp.blob().ok()?)
+        .await
+        .ok()?
+        .dyn_into()
+        .ok()?;
+    with_index(|index| index.touch(url));
+    save_index();
+    tracing::debug!("Image cache disk hit: {url}");
+    Some(blob)
+}
+
+/// Write an image to the persistent tier, evicting old entries past the cap
+async fn persistent_put(url: &str, blob: &web_sys::Blob) {
+    let Some(cache) = open_cache().await else {
+        return;
+    };
+    let Ok(resp) = web_sys::Response::new_with_opt_blob(Some(blob)) else {
+        return;
+    };
+    if let Err(e) = JsFuture::from(cache.put_with_str(url, &resp)).await {
+        // Usually quota exceeded - memory tier still has the image
+        tracing::warn!("Failed to persist image {url}: {e:?}");
+        return;
+    }
+
+    let limit = PERSISTENT_LIMIT.with(|limit| *limit.borrow());
+    let evicted = with_index(|index| index.insert(url, blob.size() as u64, limit));
+    save_index();
+
+    for old in evicted {
+        if let Err(e) = JsFuture::from(cache.delete_with_str(&old)).await {
+            tracing::warn!("Failed to evict cached image {old}: {e:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(index: &PersistentIndex) -> Vec<&str> {
+        index.entries.iter().map(|e| e.url.as_str()).collect()
+    }
+
+    #[test]
+    fn test_index_evicts_least_recently_used() {
+        let mut index = PersistentIndex::default();
+        assert!(index.insert("a", 40, 100).is_empty());
+        assert!(index.insert("b", 40, 100).is_empty());
+        index.touch("a");
+
+        let evicted = index.insert("c", 40, 100);
+        assert_eq!(evicted, vec!["b"]);
+        assert_eq!(urls(&index), vec!["a", "c"]);
+        assert_eq!(index.total_bytes(), 80);
+    }
+
+    #[test]
+    fn test_index_reinsert_replaces_entry() {
+        let mut index = PersistentIndex::default();
+        index.insert("a", 10, 100);
+        index.insert("b", 10, 100);
+        index.insert("a", 30, 100);
+        assert_eq!(urls(&index), vec!["b", "a"]);
+        assert_eq!(index.total_bytes(), 40);
+    }
+
+    #[test]
+    fn test_index_oversized_entry_is_evicted() {
+        let mut index = PersistentIndex::default();
+        index.insert("a", 10, 100);
+        let evicted = index.insert("huge", 500, 100);
+        assert_eq!(evicted, vec!["a", "huge"]);
+        assert!(index.entries.is_empty());
+    }
+
+    #[test]
+    fn test_index_roundtrips_json() {
+        let mut index = PersistentIndex::default();
+        index.insert("https://example.com/a.jpg", 1234, u64::MAX);
+        let json = serde_json::to_string(&index).unwrap();
+        let back: PersistentIndex = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, index);
+    }
+}
@@ -12,6 +12,10 @@
 //! - `is_static` - If true, card is non-interactive (no hover effect)
 //! - `show_name` - If true, show name overlay
 //! - `show_skeleton` - If true, shows skeleton while image loads (default: true)
+//! - `aspect` - Frame shape: Square (default), FourThree, SixteenNine, or Natural (image's own)
+//! - `fit` - How the image fills the frame: Cover (default), Contain, Fill, ScaleDown
+//! - `blur_backdrop` - Fill letterbox space with a blurred copy of the image (Contain/ScaleDown)
+//! - `srcset` - Responsive image candidates; `sizes` defaults to the card width
 //! - `on_click` - Callback when card is clicked (if not static)
 //! - `on_load` - Callback when image has loaded
 //!
@@ -25,6 +29,14 @@
 //!     show_name=true
 //!     on_click=move |_| { log!("clicked"); }
 //! />
+//!
+//! // Wide art, letterboxed instead of cropped
+//! <ImageCard
+//!     image_url=banner_url
+//!     aspect=AspectRatio::SixteenNine
+//!     fit=ImageFit::Contain
+//!     blur_backdrop=true
+//! />
 //! ```
 
 use leptos::prelude::*;
@@ -74,6 +86,63 @@ impl CardSize {
     }
 }
 
+/// Frame shape for the image
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AspectRatio {
+    /// 1:1
+    #[default]
+    Square,
+    /// 4:3 landscape
+    FourThree,
+    /// 16:9 landscape
+    SixteenNine,
+    /// The image's own proportions
+    Natural,
+}
+
+impl AspectRatio {
+    /// Get the CSS class suffix for this ratio
+    pub fn class_suffix(&self) -> &'static str {
+        match self {
+            AspectRatio::Square => "square",
+            AspectRatio::FourThree => "4-3",
+            AspectRatio::SixteenNine => "16-9",
+            AspectRatio::Natural => "natural",
+        }
+    }
+}
+
+/// How the image fills its frame (CSS `object-fit`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageFit {
+    /// Fill the frame, cropping overflow
+    #[default]
+    Cover,
+    /// Show the whole image, letterboxing the rest
+    Contain,
+    /// Stretch to the frame
+    Fill,
+    /// Like Contain, but never upscale
+    ScaleDown,
+}
+
+impl ImageFit {
+    /// Get the CSS class suffix for this fit
+    pub fn class_suffix(&self) -> &'static str {
+        match self {
+            ImageFit::Cover => "cover",
+            ImageFit::Contain => "contain",
+            ImageFit::Fill => "fill",
+            ImageFit::ScaleDown => "scale-down",
+        }
+    }
+
+    /// Whether the image can leave empty space in the frame
+    pub fn letterboxes(&self) -> bool {
+        matches!(self, ImageFit::Contain | ImageFit::ScaleDown)
+    }
+}
+
 /// Map from attribute string to CardSize variant
 static CARD_SIZE_MAP: phf::Map<&'static str, CardSize> = phf_map! {
     "auto" => CardSize::Auto,
@@ -121,6 +190,21 @@ pub fn ImageCard(
     /// If true, shows skeleton while image loads (default: true)
     #[prop(optional, default = true)]
     show_skeleton: bool,
+    /// Frame shape
+    #[prop(optional)]
+    aspect: AspectRatio,
+    /// How the image fills the frame
+    #[prop(optional)]
+    fit: ImageFit,
+    /// Fill letterbox space with a blurred copy of the image
+    #[prop(optional)]
+    blur_backdrop: bool,
+    /// Responsive image candidates (`srcset`)
+    #[prop(into, optional)]
+    srcset: Option<Signal<String>>,
+    /// Rendered width hint (`sizes`); defaults to the card width
+    #[prop(into, optional)]
+    sizes: Option<String>,
     /// Click callback
     #[prop(into, optional)]
     on_click: Option<Callback<()>>,
@@ -129,9 +213,16 @@ pub fn ImageCard(
     on_load: Option<Callback<()>>,
 ) -> impl IntoView {
     let size_class = format!("image-card--{}", size.class_suffix());
+    let aspect_class = format!("image-card--aspect-{}", aspect.class_suffix());
+    let image_class = format!(
+        "image-card__image image-card__image--{}",
+        fit.class_suffix()
+    );
+    let show_backdrop = blur_backdrop && fit.letterboxes();
+    let sizes = sizes.or_else(|| size.pixels().map(|px| format!("{px}px")));
 
     let card_class = move || {
-        let mut classes = vec!["image-card", &size_class];
+        let mut classes = vec!["image-card", &size_class, &aspect_class];
         if is_static {
             classes.push("image-card--static");
         }
@@ -189,10 +280,23 @@ pub fn ImageCard(
                 {move || {
                     let url = url_signal.get();
                     if !url.is_empty() {
-                        // Check cache for preloaded blob URL
-                        let resolved_url = crate::image_cache::get_cached_url(&url)
-                            .unwrap_or(url);
+                        // Check cache for preloaded blob URL; it's already
+                        // loaded, so responsive candidates would only refetch
+                        let cached = crate::image_cache::get_cached_url(&url);
+                        let srcset = match cached {
+                            Some(_) => None,
+                            None => srcset.map(|s| s.get()).filter(|s| !s.is_empty()),
+                        };
+                        let sizes = srcset.as_ref().and(sizes.clone());
+                        let resolved_url = cached.unwrap_or(url);
                         view! {
+                            {show_backdrop.then(|| view! {
+                                <div
+                                    class="image-card__backdrop"
+                                    style=format!("background-image: url(\"{resolved_url}\")")
+                                    aria-hidden="true"
+                                ></div>
+                            })}
                             // Show skeleton while loading (if enabled)
                             <Show when=move || show_skeleton && !is_loaded.get()>
                                 <div class="image-card__skeleton">
@@ -200,9 +304,11 @@ pub fn ImageCard(
                                 </div>
                             </Show>
                             <img
-                                class="image-card__image"
+                                class=image_class.clone()
                                 class:image-card__image--loading=move || show_skeleton && !is_loaded.get()
                                 src=resolved_url
+                                srcset=srcset
+                                sizes=sizes
                                 alt=move || name_signal.get()
                                 loading="lazy"
                                 on:load=move |_| {
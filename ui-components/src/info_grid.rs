@@ -26,6 +26,8 @@
 
 use leptos::prelude::*;
 
+use crate::density::density_attr;
+
 /// Info grid container
 #[component]
 pub fn InfoGrid(
@@ -42,7 +44,7 @@ pub fn InfoGrid(
     };
 
     view! {
-        <div class=grid_class>
+        <div class=grid_class data-density=density_attr()>
             {children()}
         </div>
     }
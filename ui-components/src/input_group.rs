@@ -0,0 +1,38 @@
+//! Prefix/suffix adornments shared by TextInput, Textarea and Select
+//!
+//! Fields without adornments render unchanged. With either slot set, the
+//! field is wrapped in a `.ui-input-group` that takes over the border and
+//! focus ring, so icons, units and buttons sit inside the control.
+
+use leptos::prelude::*;
+
+/// Wrap `field` with optional leading/trailing adornments
+pub(crate) fn input_group(
+    prefix: Option<Children>,
+    suffix: Option<Children>,
+    multiline: bool,
+    field: AnyView,
+) -> AnyView {
+    if prefix.is_none() && suffix.is_none() {
+        return field;
+    }
+
+    let class = if multiline {
+        "ui-input-group ui-input-group--multiline"
+    } else {
+        "ui-input-group"
+    };
+
+    view! {
+        <div class=class>
+            {prefix.map(|p| view! {
+                <span class="ui-input-group__prefix">{p()}</span>
+            })}
+            {field}
+            {suffix.map(|s| view! {
+                <span class="ui-input-group__suffix">{s()}</span>
+            })}
+        </div>
+    }
+    .into_any()
+}
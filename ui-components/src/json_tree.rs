@@ -0,0 +1,436 @@
+//! JsonTree Leptos Component
+//!
+//! A collapsible viewer for arbitrary JSON, for raw metadata, debug panels
+//! and admin tooling.
+//!
+//! ## Features
+//!
+//! - Syntax coloring by value type (strings, numbers, booleans, null)
+//! - Collapsed objects and arrays show their size (`{3}`, `[120]`)
+//! - Search highlights matching keys and values and opens their ancestors
+//! - Copy the path to any node (`$.attributes[0].name`)
+//! - Children render only once a node is opened, and large objects/arrays
+//!   show `page_size` entries at a time
+//!
+//! ## Props
+//!
+//! - `value` - The JSON to display
+//! - `expand_depth` - Levels open on first render (default 1)
+//! - `page_size` - Children shown per node before "Show more" (default 100)
+//! - `searchable` - Show the search box (default true)
+//! - `class` - Additional CSS classes
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! <JsonTree value=metadata />
+//!
+//! // Fully expanded, no search box
+//! <JsonTree value=payload expand_depth=usize::MAX searchable=false />
+//! ```
+
+use crate::i18n::use_locale;
+use leptos::prelude::*;
+use serde_json::Value;
+use std::time::Duration;
+
+/// How long a copy button shows its confirmation
+const COPIED_RESET: Duration = Duration::from_millis(1500);
+
+/// One step in a path from the root
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// JSONPath-style path (`$.a.b[0]["odd key"]`)
+fn format_path(path: &[PathSegment]) -> String {
+    let mut out = String::from("$");
+    for segment in path {
+        match segment {
+            PathSegment::Index(i) => out.push_str(&format!("[{i}]")),
+            PathSegment::Key(key) if is_identifier(key) => {
+                out.push('.');
+                out.push_str(key);
+            }
+            PathSegment::Key(key) => {
+                let quoted = serde_json::to_string(key).unwrap_or_default();
+                out.push_str(&format!("[{quoted}]"));
+            }
+        }
+    }
+    out
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// CSS modifier for a value's type
+fn value_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Display text for a scalar, or the size summary for a container
+fn summary(value: &Value) -> String {
+    match value {
+        Value::Array(items) => format!("[{}]", items.len()),
+        Value::Object(map) => format!("{{{}}}", map.len()),
+        Value::String(_) => serde_json::to_string(value).unwrap_or_default(),
+        other => other.to_string(),
+    }
+}
+
+/// Whether a key or scalar matches a lowercased query
+fn node_matches(key: Option<&str>, value: &Value, query: &str) -> bool {
+    if query.is_empty() {
+        return false;
+    }
+    let scalar = match value {
+        Value::String(s) => Some(s.to_lowercase()),
+        Value::Array(_) | Value::Object(_) => None,
+        other => Some(other.to_string()),
+    };
+    key.is_some_and(|k| k.to_lowercase().contains(query))
+        || scalar.is_some_and(|s| s.contains(query))
+}
+
+/// Whether anything strictly below `value` matches a lowercased query
+fn descendant_matches(value: &Value, query: &str) -> bool {
+    if query.is_empty() {
+        return false;
+    }
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .any(|v| node_matches(None, v, query) || descendant_matches(v, query)),
+        Value::Object(map) => map
+            .iter()
+            .any(|(k, v)| node_matches(Some(k), v, query) || descendant_matches(v, query)),
+        _ => false,
+    }
+}
+
+/// Children of a container with the label and path segment for each
+fn children_of(value: &Value) -> Vec<(String, PathSegment, Value)> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i.to_string(), PathSegment::Index(i), v.clone()))
+            .collect(),
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| (k.clone(), PathSegment::Key(k.clone()), v.clone()))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Settings shared by every node of one tree
+#[derive(Clone, Copy)]
+struct TreeContext {
+    /// Lowercased search query
+    query: Memo<String>,
+    expand_depth: usize,
+    page_size: usize,
+    /// Path most recently copied, for the confirmation tick
+    copied: RwSignal<Option<String>>,
+}
+
+/// Collapsible JSON viewer
+#[component]
+pub fn JsonTree(
+    /// The JSON to display
+    #[prop(into)]
+    value: Signal<Value>,
+    /// Levels open on first render
+    #[prop(optional, default = 1)]
+    expand_depth: usize,
+    /// Children shown per node before "Show more"
+    #[prop(optional, default = 100)]
+    page_size: usize,
+    /// Show the search box
+    #[prop(optional, default = true)]
+    searchable: bool,
+    /// Additional CSS classes
+    #[prop(into, optional)]
+    class: String,
+) -> impl IntoView {
+    let locale = use_locale();
+    let search = RwSignal::new(String::new());
+    let ctx = TreeContext {
+        query: Memo::new(move |_| search.with(|s| s.trim().to_lowercase())),
+        expand_depth,
+        page_size: page_size.max(1),
+        copied: RwSignal::new(None),
+    };
+
+    let class = if class.is_empty() {
+        "ui-json-tree".to_string()
+    } else {
+        format!("ui-json-tree {class}")
+    };
+
+    view! {
+        <div class=class>
+            {searchable.then(|| view! {
+                <input
+                    type="search"
+                    class="ui-json-tree__search"
+                    placeholder=move || locale.t_or("json_tree.search", "Search keys and values")
+                    prop:value=move || search.get()
+                    on:input=move |ev| search.set(event_target_value(&ev))
+                />
+            })}
+            <div class="ui-json-tree__body" role="tree">
+                {move || value.with(|v| json_node(ctx, None, Vec::new(), v.clone()))}
+            </div>
+        </div>
+    }
+}
+
+/// One row plus (when open) its children
+///
+/// A plain function rather than a component so it can recurse.
+fn json_node(
+    ctx: TreeContext,
+    label: Option<String>,
+    path: Vec<PathSegment>,
+    value: Value,
+) -> AnyView {
+    let locale = use_locale();
+    let depth = path.len();
+    let kind = value_kind(&value);
+    let is_container = matches!(value, Value::Array(_) | Value::Object(_));
+    let text = summary(&value);
+    let path_text = format_path(&path);
+
+    let self_match = {
+        let label = label.clone();
+        let value = value.clone();
+        Memo::new(move |_| {
+            ctx.query
+                .with(|q| node_matches(label.as_deref(), &value, q))
+        })
+    };
+    let below_match = {
+        let value = value.clone();
+        Memo::new(move |_| ctx.query.with(|q| descendant_matches(&value, q)))
+    };
+
+    // `None` follows the default (depth and search); a click pins it
+    let toggled = RwSignal::new(None::<bool>);
+    let open = move || {
+        toggled
+            .get()
+            .unwrap_or_else(|| depth < ctx.expand_depth || below_match.get())
+    };
+    let toggle = move |_| toggled.set(Some(!open()));
+
+    let copy = {
+        let path_text = path_text.clone();
+        move |ev: leptos::ev::MouseEvent| {
+            ev.stop_propagation();
+            let Some(window) = web_sys::window() else {
+                return;
+            };
+            let promise = window.navigator().clipboard().write_text(&path_text);
+            let path_text = path_text.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match wasm_bindgen_futures::JsFuture::from(promise).await {
+                    Ok(_) => {
+                        ctx.copied.set(Some(path_text.clone()));
+                        set_timeout(
+                            move || {
+                                ctx.copied.update(|c| {
+                                    if c.as_deref() == Some(path_text.as_str()) {
+                                        *c = None;
+                                    }
+                                })
+                            },
+                            COPIED_RESET,
+                        );
+                    }
+                    Err(e) => tracing::warn!("Failed to copy to clipboard: {e:?}"),
+                }
+            });
+        }
+    };
+    let is_copied = {
+        let path_text = path_text.clone();
+        move || {
+            ctx.copied
+                .with(|c| c.as_deref() == Some(path_text.as_str()))
+        }
+    };
+
+    let row = view! {
+        <div
+            class="ui-json-tree__row"
+            class:ui-json-tree__row--match=move || self_match.get()
+            style=format!("--json-tree-depth: {depth}")
+            role="treeitem"
+            aria-expanded=move || is_container.then(|| open().to_string())
+            title=path_text
+        >
+            {if is_container {
+                view! {
+                    <button
+                        class="ui-json-tree__toggle"
+                        class:ui-json-tree__toggle--open=open
+                        on:click=toggle
+                        aria-label=move || if open() {
+                            locale.t_or("json_tree.collapse", "Collapse")
+                        } else {
+                            locale.t_or("json_tree.expand", "Expand")
+                        }
+                    >
+                        "▸"
+                    </button>
+                }
+                .into_any()
+            } else {
+                view! { <span class="ui-json-tree__toggle-spacer"></span> }.into_any()
+            }}
+            {label.map(|l| view! { <span class="ui-json-tree__key">{l}</span> })}
+            <span class=format!("ui-json-tree__value ui-json-tree__value--{kind}")>{text}</span>
+            <button
+                class="ui-json-tree__copy"
+                class:ui-json-tree__copy--copied=is_copied.clone()
+                on:click=copy
+                aria-label=move || locale.t_or("json_tree.copy_path", "Copy path")
+            >
+                {move || if is_copied() { "✓" } else { "⧉" }}
+            </button>
+        </div>
+    };
+
+    if !is_container {
+        return row.into_any();
+    }
+
+    let children = StoredValue::new(children_of(&value));
+    let path = StoredValue::new(path);
+    let total = children.with_value(Vec::len);
+    let shown = RwSignal::new(ctx.page_size);
+
+    view! {
+        <div class="ui-json-tree__node">
+            {row}
+            <Show when=open>
+                <div class="ui-json-tree__children" role="group">
+                    {move || {
+                        children.with_value(|items| {
+                            items
+                                .iter()
+                                .take(shown.get())
+                                .map(|(label, segment, child)| {
+                                    let mut child_path = path.get_value();
+                                    child_path.push(segment.clone());
+                                    json_node(ctx, Some(label.clone()), child_path, child.clone())
+                                })
+                                .collect_view()
+                        })
+                    }}
+                    {move || {
+                        let remaining = total.saturating_sub(shown.get());
+                        (remaining > 0).then(|| view! {
+                            <button
+                                class="ui-json-tree__more"
+                                style=format!("--json-tree-depth: {}", depth + 1)
+                                on:click=move |_| shown.update(|n| *n += ctx.page_size)
+                            >
+                                {locale.t_or_with(
+                                    "json_tree.show_more",
+                                    "Show {count} more",
+                                    &[("count", remaining.min(ctx.page_size).to_string())],
+                                )}
+                            </button>
+                        })
+                    }}
+                </div>
+            </Show>
+        </div>
+    }
+    .into_any()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_format_path() {
+        assert_eq!(format_path(&[]), "$");
+        assert_eq!(
+            format_path(&[
+                PathSegment::Key("attributes".into()),
+                PathSegment::Index(0),
+                PathSegment::Key("name".into()),
+            ]),
+            "$.attributes[0].name"
+        );
+        assert_eq!(
+            format_path(&[
+                PathSegment::Key("721".into()),
+                PathSegment::Key("Pirate #1".into()),
+            ]),
+            r#"$["721"]["Pirate #1"]"#
+        );
+    }
+
+    #[test]
+    fn test_summary_and_kind() {
+        assert_eq!(summary(&json!([1, 2, 3])), "[3]");
+        assert_eq!(summary(&json!({"a": 1})), "{1}");
+        assert_eq!(summary(&json!("hi")), "\"hi\"");
+        assert_eq!(summary(&json!(null)), "null");
+        assert_eq!(value_kind(&json!(1.5)), "number");
+        assert_eq!(value_kind(&json!(true)), "boolean");
+    }
+
+    #[test]
+    fn test_search_matching() {
+        let value = json!({
+            "name": "Pirate #189",
+            "attributes": [{"trait": "Hat", "value": "Tricorn"}],
+            "rank": 42
+        });
+
+        assert!(node_matches(Some("Name"), &json!(1), "name"));
+        assert!(node_matches(None, &json!("Tricorn"), "tric"));
+        assert!(node_matches(None, &json!(42), "42"));
+        assert!(!node_matches(Some("x"), &json!({"tricorn": 1}), "tricorn"));
+
+        assert!(descendant_matches(&value, "tricorn"));
+        assert!(descendant_matches(&value, "rank"));
+        assert!(!descendant_matches(&value, "cutlass"));
+        assert!(!descendant_matches(&value, ""));
+    }
+
+    #[test]
+    fn test_children_of() {
+        let children = children_of(&json!({"b": 1, "a": [true]}));
+        let labels: Vec<_> = children.iter().map(|(l, _, _)| l.as_str()).collect();
+        assert_eq!(labels.len(), 2);
+        assert!(labels.contains(&"a"));
+
+        let items = children_of(&json!(["x", "y"]));
+        assert_eq!(items[1].1, PathSegment::Index(1));
+        assert!(children_of(&json!(3)).is_empty());
+    }
+}
@@ -9,6 +9,32 @@
 //! - `MemoryCard` - Flippable card for memory matching game (wraps AssetCard)
 //! - `ConnectionStatus` - WebSocket/realtime connection indicator
 //! - `AssetCache` - Non-visual component for preloading NFT images
+//! - `PhaseTransition` - Full-screen overlays between game phases
+//!
+//! ## Localization
+//!
+//! Component text goes through [`ui_core::i18n`]. Call [`provide_locale`] at the
+//! app root and [`use_locale`] in components; built-in English is used when no
+//! catalog provides a key. `locales/en.json` lists every key; the flow debug
+//! overlay is developer tooling and stays English-only.
+//!
+//! ## Density
+//!
+//! Call [`provide_density`] (or wrap a section in [`DensityProvider`]) to lay
+//! out Button, Select, InfoGrid and PlayerList compactly or spaciously
+//! without per-app CSS overrides.
+//!
+//! ## Permissions
+//!
+//! Call [`provide_auth`] with the loader's `AuthState` and wrap role-gated
+//! content in [`RequireRole`], which renders a fallback or an inline prompt
+//! for everyone else.
+//!
+//! ## Confirmations
+//!
+//! Wrap the app in [`ConfirmProvider`] and call [`use_confirm`] for an async
+//! `confirm(options) -> bool` backed by a themed dialog, instead of
+//! `window.confirm`.
 //!
 //! ## Styles
 //!
@@ -69,40 +95,65 @@ mod asset_detail_card;
 mod asset_grid;
 mod asset_picker;
 mod badge;
+mod breadcrumbs;
 mod button;
 mod button_group;
 mod card;
 mod color_swatch;
+mod confirm_dialog;
 mod connection_status;
+mod density;
 mod draggable_stack;
 mod drop_editor;
+mod drop_schema;
 mod empty_state;
+mod explorer_link;
 mod form_group;
 mod header;
 mod helpers;
+mod i18n;
 pub mod image_cache;
 mod image_card;
 mod info_grid;
+mod input_group;
+mod json_tree;
 mod loading_overlay;
 mod memory_card;
+mod mention_input;
 mod modal;
 mod modal_context;
 mod modal_stack;
+mod otp_input;
 mod pagination;
+mod phase_transition;
 mod player_card;
+mod player_chrome;
+mod player_list;
+mod product_tour;
 mod progress_bar;
+mod progress_ring;
 mod rating;
+mod require_role;
 mod role_dots;
 mod select;
 mod skeleton;
 mod stat_pill;
+mod sticky_action_bar;
 mod styles;
+mod system_banner;
 mod tabs;
 mod text_input;
 mod textarea;
 mod toast;
+mod truncated_text;
+mod use_countdown;
+mod use_debounced;
 mod use_draggable;
+mod use_fetch;
+mod use_persistent_order;
+mod use_setting;
 mod user_avatar;
+mod widget_config;
 
 // Wallet feature - components that depend on wallet-pallas types
 #[cfg(feature = "wallet")]
@@ -110,52 +161,103 @@ mod policy_folder;
 #[cfg(feature = "wallet")]
 mod wallet_nft_gallery;
 
+// Flow debug feature - traffic overlay for ui-flow connections
+#[cfg(feature = "flow-debug")]
+mod flow_debug_overlay;
+
 // Asset modal (no wallet feature needed - just needs cardano-assets)
 mod asset_modal;
 
 pub use accordion::{Accordion, AccordionItem};
 pub use alert::{Alert, AlertVariant};
-pub use asset_cache::{AssetCache, PreloadAsset};
-pub use asset_card::{generate_iiif_url, AssetCard, IiifSize};
-pub use asset_detail_card::AssetDetailCard;
+pub use asset_cache::{AssetCache, PreloadAsset, PreloadPriority};
+pub use asset_card::{generate_iiif_url, iiif_srcset, use_art_accent, AssetCard, IiifSize};
+pub use asset_detail_card::{AssetDetailCard, RoyaltyInfo};
 pub use asset_grid::AssetGrid;
 pub use asset_picker::{AssetPicker, PickerAsset};
-pub use badge::{Badge, BadgeSize, BadgeVariant};
+pub use badge::{
+    format_count, Badge, BadgeAnchor, BadgePosition, BadgeSize, BadgeVariant, CountBadge,
+    DEFAULT_BADGE_MAX,
+};
+pub use breadcrumbs::{Breadcrumb, Breadcrumbs};
 pub use button::{Button, ButtonSize, ButtonVariant};
 pub use button_group::ButtonGroup;
-pub use card::Card;
-pub use color_swatch::{ColorSwatch, SwatchSize};
-pub use connection_status::{ConnectionState, ConnectionStatus};
+pub use card::{Card, CardMediaPlacement};
+pub use color_swatch::{
+    normalize_hex, ColorSwatch, PalettePicker, SwatchSize, DEFAULT_MAX_RECENT, DEFAULT_PALETTE,
+    DEFAULT_RECENT_KEY,
+};
+pub use confirm_dialog::{
+    try_use_confirm, use_confirm, ConfirmContext, ConfirmDialog, ConfirmOptions, ConfirmProvider,
+};
+pub use connection_status::{ConnectionState, ConnectionStatus, SLOW_LATENCY_MS};
+pub use density::{provide_density, use_density, Density, DensityProvider};
 pub use draggable_stack::{DraggableStack, ItemDragState, StackDirection};
-pub use drop_editor::DropEditor;
-pub use empty_state::EmptyState;
+pub use drop_editor::{DropEditor, SchemaDropEditor};
+pub use drop_schema::{
+    Condition, DropInput, DropKind, DropRecord, DropSchema, FieldError, FieldKind, FieldSpec,
+    FieldValue, CNFT_KIND, TIP_KIND,
+};
+pub use empty_state::{EmptyState, EmptyStateVariant};
+pub use explorer_link::{
+    explorer_url, provide_explorer_config, use_explorer_config, Explorer, ExplorerConfig,
+    ExplorerKind, ExplorerLink, ExplorerNetwork,
+};
 pub use form_group::FormGroup;
 pub use header::PageHeader;
 pub use helpers::children_fn;
-pub use image_card::{parse_card_size, CardSize, ImageCard};
+pub use i18n::{provide_locale, register_component_catalog, use_locale, LocaleContext};
+pub use image_card::{parse_card_size, AspectRatio, CardSize, ImageCard, ImageFit};
 pub use info_grid::{InfoGrid, InfoRow};
+pub use json_tree::JsonTree;
 pub use loading_overlay::{LoadingOverlay, Spinner, SpinnerSize};
 pub use memory_card::MemoryCard;
+pub use mention_input::{
+    find_mentions, mention_segments, mention_users, Mention, MentionInput, MentionMessage,
+    MentionSegment, MentionText, MentionUser,
+};
 pub use modal::Modal;
-pub use modal_stack::{ModalStack, ModalStackContext};
+pub use modal_context::{use_modal_route, ModalRoute};
+pub use modal_stack::{ModalStack, ModalStackContext, ModalStackRoute};
+pub use otp_input::{sanitize_code, OtpCharset, OtpInput};
 pub use pagination::{use_adaptive_pagination, use_pagination, Pagination, PaginationState};
+pub use phase_transition::{PhaseOverlay, PhaseTransition, DEFAULT_PHASE_TRANSITION_MS};
 pub use player_card::PlayerCard;
+pub use player_chrome::{PlayerAvatar, StreakFlame, StreakTier};
+pub use player_list::{PlayerList, PlayerListEntry};
+pub use product_tour::{
+    is_tour_completed, reset_tour, ProductTour, TourAnchor, TourPlacement, TourStep,
+};
 pub use progress_bar::ProgressBar;
-pub use rating::{Rating, RatingSize};
+pub use progress_ring::{ProgressRing, RingThreshold};
+pub use rating::{format_rating, icon_fill, Rating, RatingIcons, RatingPrecision, RatingSize};
+pub use require_role::{provide_auth, use_auth, RequireRole, RoleAccess};
 pub use role_dots::{RoleDot, RoleDots};
 pub use select::{Select, SelectOption};
 pub use skeleton::{Skeleton, SkeletonVariant};
 pub use stat_pill::{StatPill, StatPillColor, StatPillColorInput, StatPillSize};
+pub use sticky_action_bar::{use_unsaved_changes_guard, StickyActionBar};
 pub use styles::STYLES;
-pub use tabs::{TabDef, TabPanel, TabPanelControlled, Tabs, TabsContext};
+pub use system_banner::{
+    format_countdown, BannerVariant, Countdown, SystemAnnouncement, SystemBanner,
+};
+pub use tabs::{TabDef, TabItem, TabPanel, TabPanelControlled, Tabs, TabsContext, TabsOrientation};
 pub use text_input::{InputType, TextInput};
 pub use textarea::Textarea;
 pub use toast::{
-    try_use_toasts, use_toasts, Toast, ToastContainer, ToastContext, ToastKind, ToastProvider,
-    DEFAULT_TOAST_DURATION_MS,
+    try_use_toasts, use_toasts, Toast, ToastContainer, ToastContext, ToastKind, ToastLayout,
+    ToastPosition, ToastProvider, ToastStack, DEFAULT_TOAST_DURATION_MS,
 };
+pub use truncated_text::{shorten_end, shorten_middle, TruncateMode, TruncatedText};
+pub use ui_flow_protocol::PresenceStatus;
+pub use use_countdown::use_countdown;
+pub use use_debounced::use_debounced_signal;
 pub use use_draggable::{use_draggable, DragAttrs, DragState, Draggable, Reorder};
+pub use use_fetch::{use_fetch, use_fetch_with, UseFetch};
+pub use use_persistent_order::{use_persistent_order, PersistentOrder};
+pub use use_setting::{use_setting, UseSetting};
 pub use user_avatar::{AvatarSize, UserAvatar};
+pub use widget_config::{provide_widget_config, use_widget_config};
 
 // Wallet feature exports
 #[cfg(feature = "wallet")]
@@ -163,5 +265,9 @@ pub use policy_folder::PolicyFolder;
 #[cfg(feature = "wallet")]
 pub use wallet_nft_gallery::WalletNftGallery;
 
+// Flow debug feature exports
+#[cfg(feature = "flow-debug")]
+pub use flow_debug_overlay::FlowDebugOverlay;
+
 // Asset modal export
 pub use asset_modal::AssetModal;
@@ -1,11 +1,18 @@
 //! LoadingOverlay Leptos Component
 //!
-//! A full-screen loading overlay with spinner and optional message.
+//! A loading overlay with spinner or progress bar and optional message.
+//! Covers the full screen by default, or just the wrapped content when
+//! `scoped` is set.
 //!
 //! ## Props
 //!
 //! - `loading` - Signal controlling visibility
 //! - `message` - Optional loading message
+//! - `progress` - Determinate progress (0.0 to 1.0); replaces the spinner
+//! - `on_cancel` - Shows a cancel button that runs this callback
+//! - `cancel_label` - Cancel button text (default: localized "Cancel")
+//! - `blur` - Blur the content behind a translucent overlay
+//! - `scoped` - Cover only `children` instead of the full screen
 //! - `children` - Content behind the overlay (optional)
 //!
 //! ## Usage
@@ -18,11 +25,25 @@
 //! <LoadingOverlay loading=is_loading message="Saving...">
 //!     <div>"Your content here"</div>
 //! </LoadingOverlay>
+//!
+//! // Cancellable preload scoped to a panel
+//! <LoadingOverlay
+//!     loading=preloading
+//!     message="Loading assets..."
+//!     progress=preload_progress
+//!     on_cancel=Callback::new(move |_| cancel_handle.cancel())
+//!     blur=true
+//!     scoped=true
+//! >
+//!     <AssetGrid assets=assets />
+//! </LoadingOverlay>
 //! ```
 
+use crate::i18n::use_locale;
+use crate::{Button, ButtonSize, ButtonVariant, ProgressBar};
 use leptos::prelude::*;
 
-/// Full-screen loading overlay with spinner
+/// Loading overlay with spinner or progress bar
 #[component]
 pub fn LoadingOverlay(
     /// Signal controlling visibility
@@ -31,22 +52,86 @@ pub fn LoadingOverlay(
     /// Optional loading message
     #[prop(into, optional)]
     message: Option<String>,
+    /// Determinate progress (0.0 to 1.0); shows a progress bar instead of the spinner
+    #[prop(into, optional)]
+    progress: Option<Signal<f32>>,
+    /// Shows a cancel button that runs this callback
+    #[prop(into, optional)]
+    on_cancel: Option<Callback<()>>,
+    /// Cancel button text
+    #[prop(into, optional)]
+    cancel_label: Option<String>,
+    /// Blur the content behind a translucent overlay
+    #[prop(optional)]
+    blur: bool,
+    /// Cover only the wrapped content instead of the full screen
+    #[prop(optional)]
+    scoped: bool,
     /// Content behind the overlay (optional)
     #[prop(optional)]
     children: Option<Children>,
 ) -> impl IntoView {
-    let message = message.unwrap_or_else(|| "Loading...".to_string());
+    let locale = use_locale();
+    let message = message.unwrap_or_else(|| locale.t_or("loading_overlay.loading", "Loading..."));
+    let cancel_label =
+        cancel_label.unwrap_or_else(|| locale.t_or("loading_overlay.cancel", "Cancel"));
 
-    view! {
-        {children.map(|c| c())}
+    let overlay_class = {
+        let mut classes = vec!["ui-loading-overlay"];
+        if blur {
+            classes.push("ui-loading-overlay--blur");
+        }
+        if scoped {
+            classes.push("ui-loading-overlay--scoped");
+        }
+        classes.join(" ")
+    };
+
+    let overlay = view! {
         <Show when=move || loading.get()>
-            <div class="ui-loading-overlay">
+            <div class=overlay_class.clone() role="status" aria-live="polite" aria-busy="true">
                 <div class="ui-loading-overlay__content">
-                    <div class="ui-loading-overlay__spinner"></div>
+                    {match progress {
+                        Some(progress) => view! {
+                            <div class="ui-loading-overlay__progress">
+                                <ProgressBar value=progress show_percentage=true />
+                            </div>
+                        }
+                        .into_any(),
+                        None => view! { <div class="ui-loading-overlay__spinner"></div> }.into_any(),
+                    }}
                     <p class="ui-loading-overlay__message">{message.clone()}</p>
+                    {on_cancel.map(|cb| {
+                        let label = cancel_label.clone();
+                        view! {
+                            <Button
+                                variant=ButtonVariant::Secondary
+                                size=ButtonSize::Sm
+                                on_click=cb
+                            >
+                                {label}
+                            </Button>
+                        }
+                    })}
                 </div>
             </div>
         </Show>
+    };
+
+    if scoped {
+        view! {
+            <div class="ui-loading-scope">
+                {children.map(|c| c())}
+                {overlay}
+            </div>
+        }
+        .into_any()
+    } else {
+        view! {
+            {children.map(|c| c())}
+            {overlay}
+        }
+        .into_any()
     }
 }
 
@@ -0,0 +1,603 @@
+//! MentionInput Leptos Component
+//!
+//! A textarea with `@`-mention autocomplete. Typing `@` followed by part of
+//! a name suggests matching users; picking one inserts `@Name` and records a
+//! structured [`Mention`] (user ID plus byte range), so receivers don't have
+//! to guess who `@Al` was meant to be. [`MentionText`] renders a message
+//! with its mentions highlighted.
+//!
+//! ## Features
+//!
+//! - Suggestions match the start of a name first, then anywhere in it
+//! - Arrow keys move through suggestions; Enter or Tab picks, Escape closes
+//! - Enter (without Shift) submits when `on_submit` is set
+//! - Mentions of users who have since left still resolve
+//!
+//! ## Props
+//!
+//! - `value` - Current text (Signal)
+//! - `on_change` - Callback with the text and the mentions in it
+//! - `users` - Who can be mentioned, e.g. from [`mention_users`]
+//! - `on_submit` - Called on Enter with the message
+//! - `placeholder` - Optional placeholder text
+//! - `disabled` - Whether the input is disabled
+//! - `rows` - Number of visible rows (default: 1)
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let (draft, set_draft) = signal(MentionMessage::default());
+//! let users = Signal::derive(move || mention_users(&presence.get(), &my_id));
+//!
+//! <MentionInput
+//!     value=Signal::derive(move || draft.with(|d| d.text.clone()))
+//!     on_change=move |m: MentionMessage| set_draft.set(m)
+//!     users=users
+//!     on_submit=move |m: MentionMessage| send(m.text, m.mentions)
+//! />
+//!
+//! // In the message list
+//! <MentionText text=msg.text mentions=msg.mentions current_user_id=my_id />
+//! ```
+
+use leptos::html;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use ui_flow_protocol::PresenceInfo;
+
+/// Most suggestions shown at once
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Longest `@query` that still counts as a mention in progress
+const MAX_QUERY_CHARS: usize = 32;
+
+/// Someone who can be mentioned
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MentionUser {
+    pub user_id: String,
+    pub name: String,
+}
+
+impl MentionUser {
+    /// A present user, named by their user ID if they have no display name
+    pub fn from_presence(info: &PresenceInfo) -> Self {
+        Self {
+            user_id: info.user_id.clone(),
+            name: info
+                .name
+                .clone()
+                .filter(|n| !n.trim().is_empty())
+                .unwrap_or_else(|| info.user_id.clone()),
+        }
+    }
+}
+
+/// Mentionable users from a presence list, by name, without `exclude_user_id`
+///
+/// A user connected from several tabs appears once.
+pub fn mention_users(presence: &[PresenceInfo], exclude_user_id: &str) -> Vec<MentionUser> {
+    let mut users: Vec<MentionUser> = Vec::new();
+    for info in presence {
+        if info.user_id != exclude_user_id && !users.iter().any(|u| u.user_id == info.user_id) {
+            users.push(MentionUser::from_presence(info));
+        }
+    }
+    users.sort_by_cached_key(|u| u.name.to_lowercase());
+    users
+}
+
+/// A mention in a message: `text[start..end]` is `@` and the user's name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Mention {
+    pub user_id: String,
+    /// Byte offset of the `@`
+    pub start: usize,
+    /// Byte offset just past the name
+    pub end: usize,
+}
+
+/// Text typed into a [`MentionInput`] with the mentions in it
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MentionMessage {
+    pub text: String,
+    pub mentions: Vec<Mention>,
+}
+
+impl MentionMessage {
+    /// Whether `user_id` is mentioned
+    pub fn mentions_user(&self, user_id: &str) -> bool {
+        self.mentions.iter().any(|m| m.user_id == user_id)
+    }
+}
+
+/// The mention being typed at byte offset `cursor`
+///
+/// Returns the offset of its `@` and the query typed after it. The `@` must
+/// start the text or follow whitespace, so e-mail addresses don't trigger
+/// suggestions.
+fn mention_query(text: &str, cursor: usize) -> Option<(usize, &str)> {
+    let before = text.get(..cursor)?;
+    let at = before.rfind('@')?;
+    let query = &before[at + 1..];
+    if query.chars().any(char::is_whitespace) || query.chars().count() > MAX_QUERY_CHARS {
+        return None;
+    }
+    let starts_word = before[..at]
+        .chars()
+        .next_back()
+        .is_none_or(char::is_whitespace);
+    starts_word.then_some((at, query))
+}
+
+/// Users matching `query`: name prefixes first, then names containing it
+fn suggest(users: &[MentionUser], query: &str, limit: usize) -> Vec<MentionUser> {
+    let query = query.to_lowercase();
+    let (mut prefix, mut contains) = (Vec::new(), Vec::new());
+    for user in users {
+        let name = user.name.to_lowercase();
+        if name.starts_with(&query) {
+            prefix.push(user.clone());
+        } else if name.contains(&query) {
+            contains.push(user.clone());
+        }
+    }
+    prefix.extend(contains);
+    prefix.truncate(limit);
+    prefix
+}
+
+/// Replace the mention typed at `at..cursor` with `@name `
+///
+/// Returns the new text and the byte offset to put the cursor at.
+fn insert_mention(text: &str, at: usize, cursor: usize, name: &str) -> (String, usize) {
+    let inserted = format!("@{name} ");
+    let mut rest = &text[cursor..];
+    // Don't double up the space when completing in the middle of the text
+    if rest.starts_with(' ') {
+        rest = &rest[1..];
+    }
+    let new_text = format!("{}{inserted}{rest}", &text[..at]);
+    (new_text, at + inserted.len())
+}
+
+/// Find `@name` for each of `users` in `text`
+///
+/// A mention must start a word and not run into a longer word, so `@Al`
+/// isn't found in `@Alice`. Longer names win where names overlap.
+pub fn find_mentions(text: &str, users: &[MentionUser]) -> Vec<Mention> {
+    let mut users: Vec<&MentionUser> = users.iter().filter(|u| !u.name.is_empty()).collect();
+    users.sort_by_key(|u| std::cmp::Reverse(u.name.len()));
+
+    let mut mentions: Vec<Mention> = Vec::new();
+    for user in users {
+        let needle = format!("@{}", user.name);
+        for (start, _) in text.match_indices(&needle) {
+            let end = start + needle.len();
+            let starts_word = text[..start]
+                .chars()
+                .next_back()
+                .is_none_or(char::is_whitespace);
+            let ends_word = text[end..]
+                .chars()
+                .next()
+                .is_none_or(|c| !c.is_alphanumeric() && c != '_');
+            let overlaps = mentions.iter().any(|m| start < m.end && m.start < end);
+            if starts_word && ends_word && !overlaps {
+                mentions.push(Mention {
+                    user_id: user.user_id.clone(),
+                    start,
+                    end,
+                });
+            }
+        }
+    }
+    mentions.sort_by_key(|m| m.start);
+    mentions
+}
+
+/// A run of message text, plain or a mention
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MentionSegment {
+    Text(String),
+    Mention { text: String, user_id: String },
+}
+
+/// Split `text` around its mentions
+///
+/// Mentions that don't fit the text (out of range, overlapping, or not on
+/// character boundaries) are left as plain text.
+pub fn mention_segments(text: &str, mentions: &[Mention]) -> Vec<MentionSegment> {
+    let mut sorted: Vec<&Mention> = mentions.iter().collect();
+    sorted.sort_by_key(|m| m.start);
+
+    let mut segments = Vec::new();
+    let mut pos = 0;
+    for mention in sorted {
+        let fits = mention.start >= pos
+            && mention.start < mention.end
+            && text.get(mention.start..mention.end).is_some();
+        if !fits {
+            continue;
+        }
+        if mention.start > pos {
+            segments.push(MentionSegment::Text(text[pos..mention.start].to_string()));
+        }
+        segments.push(MentionSegment::Mention {
+            text: text[mention.start..mention.end].to_string(),
+            user_id: mention.user_id.clone(),
+        });
+        pos = mention.end;
+    }
+    if pos < text.len() {
+        segments.push(MentionSegment::Text(text[pos..].to_string()));
+    }
+    segments
+}
+
+/// Byte offset in `text` of UTF-16 offset `index` (DOM selection offsets)
+fn byte_offset(text: &str, index: u32) -> usize {
+    let mut units = 0;
+    for (offset, c) in text.char_indices() {
+        if units >= index as usize {
+            return offset;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+/// UTF-16 offset of byte offset `offset` in `text`
+fn utf16_offset(text: &str, offset: usize) -> u32 {
+    text[..offset].encode_utf16().count() as u32
+}
+
+/// Mention being typed: `@` offset, cursor offset and the query
+#[derive(Debug, Clone, PartialEq)]
+struct ActiveQuery {
+    at: usize,
+    cursor: usize,
+    query: String,
+}
+
+/// Textarea with `@`-mention autocomplete
+#[component]
+pub fn MentionInput(
+    /// Current text
+    #[prop(into)]
+    value: Signal<String>,
+    /// Change callback, with the text and the mentions in it
+    #[prop(into)]
+    on_change: Callback<MentionMessage>,
+    /// Users who can be mentioned
+    #[prop(into)]
+    users: Signal<Vec<MentionUser>>,
+    /// Called on Enter (Shift+Enter adds a newline)
+    #[prop(into, optional)]
+    on_submit: Option<Callback<MentionMessage>>,
+    /// Placeholder text
+    #[prop(into, optional)]
+    placeholder: Option<String>,
+    /// Whether the input is disabled
+    #[prop(into, optional)]
+    disabled: Option<Signal<bool>>,
+    /// Number of visible rows
+    #[prop(optional, default = 1)]
+    rows: u32,
+    /// Additional class
+    #[prop(into, optional)]
+    class: Option<String>,
+) -> impl IntoView {
+    let textarea_ref = NodeRef::<html::Textarea>::new();
+    let is_disabled = move || disabled.map(|d| d.get()).unwrap_or(false);
+
+    // Everyone picked from the suggestions, so mentions of users who have
+    // since left still resolve
+    let picked = StoredValue::new(Vec::<MentionUser>::new());
+    let active = RwSignal::new(None::<ActiveQuery>);
+    let highlighted = RwSignal::new(0usize);
+
+    let suggestions = Memo::new(move |_| {
+        active.with(|active| match active {
+            Some(active) => users.with(|users| suggest(users, &active.query, MAX_SUGGESTIONS)),
+            None => Vec::new(),
+        })
+    });
+    let open = move || !suggestions.with(Vec::is_empty);
+
+    let message = move |text: String| {
+        let mut known = picked.get_value();
+        users.with_untracked(|users| {
+            for user in users {
+                if !known.iter().any(|k| k.user_id == user.user_id) {
+                    known.push(user.clone());
+                }
+            }
+        });
+        let mentions = find_mentions(&text, &known);
+        MentionMessage { text, mentions }
+    };
+
+    // Find the mention being typed at the caret
+    let refresh = move || {
+        let Some(el) = textarea_ref.get_untracked() else {
+            return;
+        };
+        let text = el.value();
+        let caret = el.selection_start().ok().flatten().unwrap_or(0);
+        let cursor = byte_offset(&text, caret);
+        let next = mention_query(&text, cursor).map(|(at, query)| ActiveQuery {
+            at,
+            cursor,
+            query: query.to_string(),
+        });
+        if active.get_untracked() != next {
+            highlighted.set(0);
+            active.set(next);
+        }
+    };
+
+    let pick = move |user: MentionUser| {
+        let (Some(el), Some(query)) = (textarea_ref.get_untracked(), active.get_untracked()) else {
+            return;
+        };
+        let text = el.value();
+        let (new_text, cursor) = insert_mention(&text, query.at, query.cursor, &user.name);
+        picked.update_value(|picked| {
+            if !picked.iter().any(|p| p.user_id == user.user_id) {
+                picked.push(user);
+            }
+        });
+        el.set_value(&new_text);
+        let caret = utf16_offset(&new_text, cursor);
+        let _ = el.set_selection_range(caret, caret);
+        active.set(None);
+        on_change.run(message(new_text));
+        let _ = el.focus();
+    };
+
+    let handle_input = move |_| {
+        if let Some(el) = textarea_ref.get_untracked() {
+            on_change.run(message(el.value()));
+        }
+        refresh();
+    };
+
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        if open() {
+            let count = suggestions.with_untracked(Vec::len);
+            match ev.key().as_str() {
+                "ArrowDown" => {
+                    ev.prevent_default();
+                    highlighted.update(|i| *i = (*i + 1) % count);
+                }
+                "ArrowUp" => {
+                    ev.prevent_default();
+                    highlighted.update(|i| *i = (*i + count - 1) % count);
+                }
+                "Enter" | "Tab" => {
+                    ev.prevent_default();
+                    let user =
+                        suggestions.with_untracked(|s| s.get(highlighted.get_untracked()).cloned());
+                    if let Some(user) = user {
+                        pick(user);
+                    }
+                }
+                "Escape" => {
+                    ev.prevent_default();
+                    active.set(None);
+                }
+                _ => {}
+            }
+            return;
+        }
+        if ev.key() == "Enter" && !ev.shift_key() {
+            if let (Some(on_submit), Some(el)) = (on_submit, textarea_ref.get_untracked()) {
+                ev.prevent_default();
+                let text = el.value();
+                if !text.trim().is_empty() {
+                    on_submit.run(message(text));
+                }
+            }
+        }
+    };
+
+    // Caret moves without typing also change the mention in progress
+    let handle_keyup = move |ev: web_sys::KeyboardEvent| {
+        if matches!(
+            ev.key().as_str(),
+            "ArrowLeft" | "ArrowRight" | "Home" | "End"
+        ) {
+            refresh();
+        }
+    };
+
+    // Drop the suggestions once the text is cleared from outside, e.g. sent
+    Effect::new(move |_| {
+        if value.with(String::is_empty) {
+            active.set(None);
+        }
+    });
+
+    let field_class = move || {
+        let mut classes = vec!["ui-mention-input__field"];
+        if let Some(ref c) = class {
+            classes.push(c);
+        }
+        classes.join(" ")
+    };
+
+    view! {
+        <div class="ui-mention-input">
+            <textarea
+                node_ref=textarea_ref
+                class=field_class
+                rows=rows
+                placeholder=placeholder.unwrap_or_default()
+                disabled=is_disabled
+                aria-autocomplete="list"
+                aria-expanded=move || open().to_string()
+                prop:value=move || value.get()
+                on:input=handle_input
+                on:keydown=handle_keydown
+                on:keyup=handle_keyup
+                on:click=move |_| refresh()
+                on:blur=move |_| active.set(None)
+            />
+            <Show when=open>
+                <ul class="ui-mention-input__suggestions" role="listbox">
+                    <For
+                        each=move || suggestions.get().into_iter().enumerate()
+                        key=|(i, user)| (*i, user.user_id.clone())
+                        children=move |(i, user)| {
+                            let name = user.name.clone();
+                            let selected = move || highlighted.get() == i;
+                            view! {
+                                <li
+                                    class="ui-mention-input__suggestion"
+                                    class:ui-mention-input__suggestion--active=selected
+                                    role="option"
+                                    aria-selected=move || selected().to_string()
+                                    // mousedown so the textarea doesn't blur first
+                                    on:mousedown=move |ev| {
+                                        ev.prevent_default();
+                                        pick(user.clone());
+                                    }
+                                    on:mouseenter=move |_| highlighted.set(i)
+                                >
+                                    "@"{name}
+                                </li>
+                            }
+                        }
+                    />
+                </ul>
+            </Show>
+        </div>
+    }
+}
+
+/// Message text with its mentions highlighted
+///
+/// Mentions of `current_user_id` get an extra class so pings stand out.
+#[component]
+pub fn MentionText(
+    /// Message text
+    #[prop(into)]
+    text: String,
+    /// Mentions in `text`
+    #[prop(optional)]
+    mentions: Vec<Mention>,
+    /// Highlight mentions of this user
+    #[prop(into, optional)]
+    current_user_id: Option<Signal<String>>,
+) -> impl IntoView {
+    mention_segments(&text, &mentions)
+        .into_iter()
+        .map(|segment| match segment {
+            MentionSegment::Text(text) => text.into_any(),
+            MentionSegment::Mention { text, user_id } => {
+                let is_me = move || current_user_id.is_some_and(|id| id.with(|id| *id == user_id));
+                view! {
+                    <span class="ui-mention" class:ui-mention--me=is_me>
+                        {text}
+                    </span>
+                }
+                .into_any()
+            }
+        })
+        .collect_view()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str, name: &str) -> MentionUser {
+        MentionUser {
+            user_id: id.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_mention_query() {
+        assert_eq!(mention_query("hi @al", 6), Some((3, "al")));
+        assert_eq!(mention_query("@", 1), Some((0, "")));
+        // Caret before the mention
+        assert_eq!(mention_query("hi @al", 2), None);
+        // Finished by a space
+        assert_eq!(mention_query("hi @al there", 12), None);
+        // Not at the start of a word
+        assert_eq!(mention_query("me@example", 10), None);
+    }
+
+    #[test]
+    fn test_suggest_prefers_prefix() {
+        let users = [user("1", "Malory"), user("2", "Alice"), user("3", "Bob")];
+        let names: Vec<_> = suggest(&users, "al", 5)
+            .into_iter()
+            .map(|u| u.name)
+            .collect();
+        assert_eq!(names, ["Alice", "Malory"]);
+        assert_eq!(suggest(&users, "", 2).len(), 2);
+    }
+
+    #[test]
+    fn test_insert_mention() {
+        assert_eq!(
+            insert_mention("hi @al", 3, 6, "Alice"),
+            ("hi @Alice ".to_string(), 10)
+        );
+        assert_eq!(
+            insert_mention("@b and me", 0, 2, "Bob"),
+            ("@Bob and me".to_string(), 5)
+        );
+    }
+
+    #[test]
+    fn test_find_mentions() {
+        let users = [user("1", "Al"), user("2", "Alice"), user("3", "Bo Jo")];
+        let mentions = find_mentions("@Alice, @Al and @Bo Jo! not@Al @Alan", &users);
+        let found: Vec<_> = mentions
+            .iter()
+            .map(|m| (m.user_id.as_str(), m.start, m.end))
+            .collect();
+        assert_eq!(found, [("2", 0, 6), ("1", 8, 11), ("3", 16, 22)]);
+    }
+
+    #[test]
+    fn test_mention_segments() {
+        let text = "hey @Bob!";
+        let mentions = [
+            Mention {
+                user_id: "b".into(),
+                start: 4,
+                end: 8,
+            },
+            // Out of range
+            Mention {
+                user_id: "x".into(),
+                start: 8,
+                end: 40,
+            },
+        ];
+        assert_eq!(
+            mention_segments(text, &mentions),
+            [
+                MentionSegment::Text("hey ".into()),
+                MentionSegment::Mention {
+                    text: "@Bob".into(),
+                    user_id: "b".into()
+                },
+                MentionSegment::Text("!".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_utf16_offsets() {
+        let text = "é😀@a";
+        assert_eq!(byte_offset(text, 3), 6);
+        assert_eq!(utf16_offset(text, 6), 3);
+        assert_eq!(byte_offset(text, 99), text.len());
+    }
+}
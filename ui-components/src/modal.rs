@@ -32,6 +32,7 @@
 //! </Modal>
 //! ```
 
+use crate::i18n::use_locale;
 use crate::modal_context::{use_modal_navigation, ModalViewId};
 use leptos::prelude::*;
 
@@ -56,6 +57,8 @@ pub fn Modal(
     /// Modal body content
     children: Children,
 ) -> impl IntoView {
+    let locale = use_locale();
+
     // Check if we're inside a ModalStack
     let modal_nav = use_modal_navigation();
 
@@ -137,7 +140,7 @@ pub fn Modal(
                         <button
                             class="ui-modal__close"
                             on:click=move |_| cb.run(())
-                            aria-label="Close"
+                            aria-label=move || locale.t_or("modal.close", "Close")
                         >
                             "\u{00D7}"
                         </button>
@@ -5,9 +5,35 @@
 //! When a `Modal` is rendered inside a `ModalStack`, it detects the `ModalNavigation`
 //! context and registers itself with the stack. The stack then manages breadcrumbs
 //! and navigation while the Modal renders its content inline.
+//!
+//! ## Route-Aware Modals
+//!
+//! A [`ModalRoute`] binds a modal to a URL hash parameter (e.g. `#asset=...`),
+//! so the modal survives a refresh, can be deep-linked, and closes with the
+//! browser back button. Other hash parameters are left untouched.
+//!
+//! ```ignore
+//! let asset_route = use_modal_route("asset");
+//!
+//! // Open from a grid click - pushes `#asset=<id>` onto history
+//! asset_route.open(asset_id.concatenated());
+//!
+//! {move || {
+//!     asset_route
+//!         .get()
+//!         .and_then(|id| AssetId::parse_concatenated(&id).ok())
+//!         .map(|asset_id| view! {
+//!             <AssetModal
+//!                 asset_id=asset_id
+//!                 on_close=Callback::new(move |()| asset_route.close())
+//!             />
+//!         })
+//! }}
+//! ```
 
 use leptos::prelude::*;
 use std::sync::Arc;
+use wasm_bindgen::{JsCast, JsValue};
 
 /// Unique identifier for a mounted modal view
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
@@ -80,3 +106,341 @@ impl ModalNavigation {
 pub fn use_modal_navigation() -> Option<ModalNavigation> {
     use_context::<ModalNavigation>()
 }
+
+/// Prefix for the per-route history depth stored in `history.state`
+const HISTORY_STATE_PREFIX: &str = "ui-modal-route:";
+
+/// A modal bound to a URL hash parameter
+///
+/// The parameter value is the modal's state (e.g. an asset ID). Opening
+/// pushes a history entry so the back button closes the modal; closing
+/// unwinds the entries this route pushed, or rewrites the URL when the
+/// modal was opened from a deep link.
+#[derive(Clone, Copy)]
+pub struct ModalRoute {
+    key: &'static str,
+    value: RwSignal<Option<String>>,
+    /// Set while `close()` waits for the browser to unwind history
+    pending_close: RwSignal<bool>,
+}
+
+impl ModalRoute {
+    /// Hash parameter name
+    pub fn key(&self) -> &'static str {
+        self.key
+    }
+
+    /// Current parameter value (tracked), `None` when closed
+    pub fn get(&self) -> Option<String> {
+        self.value.get()
+    }
+
+    /// Current parameter value without tracking
+    pub fn get_untracked(&self) -> Option<String> {
+        self.value.get_untracked()
+    }
+
+    /// Whether the parameter is present (tracked)
+    pub fn is_open(&self) -> bool {
+        self.value.with(Option::is_some)
+    }
+
+    /// Open the modal with `value`
+    ///
+    /// Pushes a history entry when closed; replaces the value when already
+    /// open so that back still closes the modal in one step.
+    pub fn open(&self, value: impl Into<String>) {
+        let value = value.into();
+        if self.value.with_untracked(Option::is_some) {
+            self.replace(Some(value));
+        } else {
+            self.push(value);
+        }
+    }
+
+    /// Close the modal
+    pub fn close(&self) {
+        let depth = history_depth(self.key);
+        self.value.set(None);
+        if depth > 0 {
+            self.pending_close.set(true);
+            if let Ok(history) = window().history() {
+                let _ = history.go_with_delta(-(depth as i32));
+            }
+        } else {
+            write_param(self.key, None, HistoryMode::Replace);
+        }
+    }
+
+    /// Set the value without adding a history entry
+    pub fn replace(&self, value: Option<String>) {
+        write_param(self.key, value.as_deref(), HistoryMode::Replace);
+        self.value.set(value);
+    }
+
+    /// Set the value as a new history entry
+    pub(crate) fn push(&self, value: String) {
+        write_param(self.key, Some(&value), HistoryMode::Push);
+        self.value.set(Some(value));
+    }
+
+    /// Step back to `value`, via history if this route pushed the current entry
+    pub(crate) fn pop_to(&self, value: String) {
+        if history_depth(self.key) > 0 {
+            if let Ok(history) = window().history() {
+                let _ = history.back();
+            }
+            self.value.set(Some(value));
+        } else {
+            self.replace(Some(value));
+        }
+    }
+
+    /// Re-read the parameter after the browser changed the URL
+    fn sync(&self) {
+        let value = hash_param(&current_hash(), self.key);
+        if self.pending_close.get_untracked() {
+            self.pending_close.set(false);
+            // Unwound to an entry that still has the parameter (deep link)
+            if value.is_some() {
+                write_param(self.key, None, HistoryMode::Replace);
+                self.value.set(None);
+                return;
+            }
+        }
+        if self.value.get_untracked() != value {
+            self.value.set(value);
+        }
+    }
+}
+
+/// Bind a modal to the hash parameter `key`
+///
+/// Reads the initial value from the current URL and follows back/forward
+/// navigation and manual hash edits.
+pub fn use_modal_route(key: &'static str) -> ModalRoute {
+    let route = ModalRoute {
+        key,
+        value: RwSignal::new(hash_param(&current_hash(), key)),
+        pending_close: RwSignal::new(false),
+    };
+
+    let popstate = window_event_listener(leptos::ev::popstate, move |_| route.sync());
+    let hashchange = window_event_listener(leptos::ev::hashchange, move |_| route.sync());
+    on_cleanup(move || {
+        popstate.remove();
+        hashchange.remove();
+    });
+
+    route
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum HistoryMode {
+    Push,
+    Replace,
+}
+
+fn current_hash() -> String {
+    window().location().hash().unwrap_or_default()
+}
+
+fn history_state_key(key: &str) -> JsValue {
+    JsValue::from_str(&format!("{HISTORY_STATE_PREFIX}{key}"))
+}
+
+/// Number of history entries this route has pushed up to the current one
+fn history_depth(key: &str) -> usize {
+    window()
+        .history()
+        .and_then(|h| h.state())
+        .ok()
+        .filter(|state| state.is_object())
+        .and_then(|state| js_sys::Reflect::get(&state, &history_state_key(key)).ok())
+        .and_then(|depth| depth.as_f64())
+        .map_or(0, |depth| depth as usize)
+}
+
+/// Write one hash parameter, keeping the rest of the URL
+fn write_param(key: &str, value: Option<&str>, mode: HistoryMode) {
+    let window = window();
+    let Ok(history) = window.history() else {
+        return;
+    };
+    let location = window.location();
+    let hash = with_hash_param(&current_hash(), key, value);
+    let url = format!(
+        "{}{}{hash}",
+        location.pathname().unwrap_or_default(),
+        location.search().unwrap_or_default()
+    );
+
+    // Carry the depth markers of every route forward
+    let state = js_sys::Object::new();
+    if let Ok(current) = history.state() {
+        if current.is_object() {
+            js_sys::Object::assign(&state, current.unchecked_ref());
+        }
+    }
+
+    let result = match mode {
+        HistoryMode::Push => {
+            let depth = history_depth(key) + 1;
+            let _ = js_sys::Reflect::set(
+                &state,
+                &history_state_key(key),
+                &JsValue::from_f64(depth as f64),
+            );
+            history.push_state_with_url(&state, "", Some(&url))
+        }
+        HistoryMode::Replace => history.replace_state_with_url(&state, "", Some(&url)),
+    };
+    if let Err(e) = result {
+        tracing::warn!("Failed to update modal route {key}: {e:?}");
+    }
+}
+
+/// Parse `#a=1&b=2` into decoded key/value pairs
+pub(crate) fn parse_hash_params(hash: &str) -> Vec<(String, String)> {
+    hash.trim_start_matches('#')
+        .split('&')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let (key, value) = part.split_once('=').unwrap_or((part, ""));
+            (decode_component(key), decode_component(value))
+        })
+        .collect()
+}
+
+/// Format key/value pairs as a hash (empty string when there are none)
+pub(crate) fn format_hash_params(params: &[(String, String)]) -> String {
+    if params.is_empty() {
+        return String::new();
+    }
+    let parts: Vec<String> = params
+        .iter()
+        .map(|(key, value)| format!("{}={}", encode_component(key), encode_component(value)))
+        .collect();
+    format!("#{}", parts.join("&"))
+}
+
+/// Value of one hash parameter
+pub(crate) fn hash_param(hash: &str, key: &str) -> Option<String> {
+    parse_hash_params(hash)
+        .into_iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, value)| value)
+}
+
+/// Set or remove one hash parameter, keeping the others in order
+pub(crate) fn with_hash_param(hash: &str, key: &str, value: Option<&str>) -> String {
+    let mut params = parse_hash_params(hash);
+    match (params.iter().position(|(k, _)| k == key), value) {
+        (Some(index), Some(value)) => params[index].1 = value.to_string(),
+        (Some(index), None) => {
+            params.remove(index);
+        }
+        (None, Some(value)) => params.push((key.to_string(), value.to_string())),
+        (None, None) => {}
+    }
+    format_hash_params(&params)
+}
+
+/// Join route segments into a `/`-separated path
+pub(crate) fn join_route_path<I, S>(segments: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    segments
+        .into_iter()
+        .map(|segment| encode_component(segment.as_ref()))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Split a `/`-separated route path into segments
+pub(crate) fn split_route_path(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(decode_component)
+        .collect()
+}
+
+/// Percent-encode everything but unreserved URL characters
+fn encode_component(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+/// Decode `%XX` escapes, leaving malformed ones as-is
+fn decode_component(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = |b: u8| (b as char).to_digit(16);
+            if let (Some(high), Some(low)) = (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                out.push((high * 16 + low) as u8);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hash_params() {
+        assert!(parse_hash_params("").is_empty());
+        assert!(parse_hash_params("#").is_empty());
+        assert_eq!(
+            parse_hash_params("#asset=abc&tab"),
+            vec![
+                ("asset".to_string(), "abc".to_string()),
+                ("tab".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_hash_param_keeps_other_params() {
+        assert_eq!(with_hash_param("", "asset", Some("abc")), "#asset=abc");
+        assert_eq!(
+            with_hash_param("#tab=2&asset=abc", "asset", Some("def")),
+            "#tab=2&asset=def"
+        );
+        assert_eq!(with_hash_param("#tab=2&asset=abc", "asset", None), "#tab=2");
+        assert_eq!(with_hash_param("#asset=abc", "asset", None), "");
+    }
+
+    #[test]
+    fn test_values_round_trip() {
+        let hash = with_hash_param("", "q", Some("a&b=c #d/é"));
+        assert_eq!(hash_param(&hash, "q").as_deref(), Some("a&b=c #d/é"));
+        assert_eq!(hash_param("#q=%zz", "q").as_deref(), Some("%zz"));
+        assert_eq!(hash_param("#q=100%", "q").as_deref(), Some("100%"));
+    }
+
+    #[test]
+    fn test_route_path_round_trip() {
+        let path = join_route_path(["add", "asset/1"]);
+        assert_eq!(path, "add/asset%2F1");
+        assert_eq!(split_route_path(&path), vec!["add", "asset/1"]);
+        assert!(split_route_path("").is_empty());
+    }
+}
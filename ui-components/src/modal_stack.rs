@@ -45,11 +45,91 @@
 //!     }
 //! />
 //! ```
+//!
+//! ## Routing
+//!
+//! Pass a [`ModalStackRoute`] to mirror the view stack into a hash parameter
+//! (e.g. `#settings=add/select`). The stack is restored on refresh, each
+//! pushed view is a history entry, and the back button pops views.
+//!
+//! ```ignore
+//! let settings = use_modal_route("settings");
+//! let route = ModalStackRoute::new(
+//!     settings,
+//!     |view: &MyView| match view {
+//!         MyView::Main => "main".into(),
+//!         MyView::AddItem => "add".into(),
+//!         MyView::SelectAsset { filter } => format!("select:{filter}"),
+//!     },
+//!     |segment| match segment.split_once(':') {
+//!         None if segment == "add" => Some(MyView::AddItem),
+//!         Some(("select", filter)) => Some(MyView::SelectAsset { filter: filter.into() }),
+//!         _ => None,
+//!     },
+//! );
+//!
+//! <ModalStack
+//!     open=Signal::derive(move || settings.is_open())
+//!     on_close=Callback::new(move |()| settings.close())
+//!     route=route
+//!     // ...
+//! />
+//! ```
 
-use crate::modal_context::{ModalNavigation, ModalViewId, MountedView};
+use crate::i18n::use_locale;
+use crate::modal_context::{
+    join_route_path, split_route_path, ModalNavigation, ModalRoute, ModalViewId, MountedView,
+};
 use leptos::prelude::*;
 use std::sync::Arc;
 
+/// Mirrors a ModalStack's views into a [`ModalRoute`]
+///
+/// The route value is the `/`-separated path of views pushed on top of the
+/// initial view, so an empty value means the stack is open at its root.
+#[derive(Clone)]
+pub struct ModalStackRoute<V> {
+    param: ModalRoute,
+    encode: Arc<dyn Fn(&V) -> String + Send + Sync>,
+    decode: Arc<dyn Fn(&str) -> Option<V> + Send + Sync>,
+}
+
+impl<V: Clone + 'static> ModalStackRoute<V> {
+    /// Create a stack route from view encode/decode functions
+    pub fn new<E, D>(param: ModalRoute, encode: E, decode: D) -> Self
+    where
+        E: Fn(&V) -> String + Send + Sync + 'static,
+        D: Fn(&str) -> Option<V> + Send + Sync + 'static,
+    {
+        Self {
+            param,
+            encode: Arc::new(encode),
+            decode: Arc::new(decode),
+        }
+    }
+
+    /// The underlying hash parameter route
+    pub fn route(&self) -> ModalRoute {
+        self.param
+    }
+
+    /// Route value for a view stack
+    fn path(&self, views: &[V]) -> String {
+        join_route_path(views.iter().skip(1).map(|v| (self.encode)(v)))
+    }
+
+    /// View stack for a route value; segments that don't decode are dropped
+    fn views(&self, initial_view: &V, path: &str) -> Vec<V> {
+        std::iter::once(initial_view.clone())
+            .chain(
+                split_route_path(path)
+                    .iter()
+                    .filter_map(|segment| (self.decode)(segment)),
+            )
+            .collect()
+    }
+}
+
 /// Context provided to view content for navigation
 #[derive(Clone)]
 pub struct ModalStackContext<V: Clone + 'static> {
@@ -98,17 +178,17 @@ struct StackState<V: Clone> {
 }
 
 impl<V: Clone> StackState<V> {
-    fn new(initial_view: V) -> Self {
+    fn new(views: Vec<V>) -> Self {
         Self {
-            typed_views: vec![initial_view],
+            typed_views: views,
             mounted_modals: vec![],
             slide_direction: SlideDirection::None,
             is_animating: false,
         }
     }
 
-    fn reset(&mut self, initial_view: V) {
-        self.typed_views = vec![initial_view];
+    fn reset(&mut self, views: Vec<V>) {
+        self.typed_views = views;
         self.mounted_modals.clear();
         self.slide_direction = SlideDirection::None;
         self.is_animating = false;
@@ -143,26 +223,78 @@ pub fn ModalStack<V, TitleFn, ContentFn>(
     flush: bool,
     /// Render function for view content - receives current view and navigation context
     view_content: ContentFn,
+    /// Mirror the view stack into a URL hash parameter
+    #[prop(optional)]
+    route: Option<ModalStackRoute<V>>,
 ) -> impl IntoView
 where
     V: Clone + PartialEq + Send + Sync + 'static,
     TitleFn: Fn(&V) -> String + Send + Sync + Clone + 'static,
     ContentFn: Fn(V, ModalStackContext<V>) -> AnyView + Send + Sync + Clone + 'static,
 {
+    let locale = use_locale();
+
+    // Views to open with - restored from the route when there is one
+    let opening_views = {
+        let route = route.clone();
+        let initial_view = initial_view.clone();
+        move || match &route {
+            Some(route) => {
+                let path = route.param.get_untracked().unwrap_or_default();
+                route.views(&initial_view, &path)
+            }
+            None => vec![initial_view.clone()],
+        }
+    };
+
     // Single state signal for atomic updates
-    let (state, set_state) = signal(StackState::new(initial_view.clone()));
+    let (state, set_state) = signal(StackState::new(opening_views()));
 
     // Reset state when modal closes and reopens
-    let initial_for_effect = initial_view.clone();
     Effect::new(move |prev_open: Option<bool>| {
         let currently_open = open.get();
         if currently_open && prev_open == Some(false) {
-            set_state.update(|s| s.reset(initial_for_effect.clone()));
+            set_state.update(|s| s.reset(opening_views()));
         }
         currently_open
     });
 
+    // Follow route changes made outside the stack (back/forward, edited URL)
+    if let Some(route) = route.clone() {
+        let initial_view = initial_view.clone();
+        Effect::new(move || {
+            let Some(path) = route.param.get() else {
+                return;
+            };
+            let views = route.views(&initial_view, &path);
+            let s = state.get_untracked();
+            if views == s.typed_views {
+                return;
+            }
+
+            let callbacks: Vec<_> = s
+                .mounted_modals
+                .iter()
+                .filter_map(|m| m.on_external_close.clone())
+                .collect();
+            set_state.update(|s| {
+                s.slide_direction = if views.len() < s.typed_views.len() {
+                    SlideDirection::Back
+                } else {
+                    SlideDirection::Forward
+                };
+                s.is_animating = true;
+                s.typed_views = views;
+                s.mounted_modals.clear();
+            });
+            for cb in callbacks {
+                cb();
+            }
+        });
+    }
+
     // Create the context for child views (typed navigation)
+    let route_for_push = route.clone();
     let push_typed_fn = Arc::new(move |view: V| {
         // Collect callbacks from mounted modals before clearing
         let callbacks: Vec<_> = {
@@ -190,8 +322,15 @@ where
         for cb in callbacks {
             cb();
         }
+
+        if let Some(route) = &route_for_push {
+            route
+                .param
+                .push(route.path(&state.get_untracked().typed_views));
+        }
     });
 
+    let route_for_pop = route.clone();
     let pop_fn = Arc::new(move || {
         // Get the callback before updating state (to avoid borrow issues)
         let callback_to_call = {
@@ -206,6 +345,7 @@ where
             }
         };
 
+        let mut popped_typed = false;
         set_state.update(|s| {
             if s.is_animating {
                 return;
@@ -219,9 +359,18 @@ where
                 s.slide_direction = SlideDirection::Back;
                 s.is_animating = true;
                 s.typed_views.pop();
+                popped_typed = true;
             }
         });
 
+        if popped_typed {
+            if let Some(route) = &route_for_pop {
+                route
+                    .param
+                    .pop_to(route.path(&state.get_untracked().typed_views));
+            }
+        }
+
         // Call the external close callback after state update
         if let Some(cb) = callback_to_call {
             cb();
@@ -272,6 +421,7 @@ where
 
     // Clone for use in view
     let view_title_for_breadcrumbs = view_title.clone();
+    let route_for_breadcrumbs = route.clone();
     let content_fn = view_content.clone();
     let ctx_for_content = ctx.clone();
 
@@ -341,7 +491,7 @@ where
                             <button
                                 class="ui-modal-stack__back"
                                 on:click=move |_| ctx.pop()
-                                aria-label="Go back"
+                                aria-label=move || locale.t_or("modal_stack.back", "Go back")
                             >
                                 "\u{2190}"
                             </button>
@@ -353,6 +503,7 @@ where
                         {move || {
                             let s = state.get();
                             let view_title = view_title_for_breadcrumbs.clone();
+                            let route = route_for_breadcrumbs.clone();
                             let total_items = s.total_breadcrumb_count();
 
                             // Build breadcrumb items: typed views first, then mounted modals
@@ -374,6 +525,7 @@ where
                             }
 
                             items.into_iter().map(|(title, is_last, typed_depth)| {
+                                let route = route.clone();
                                 view! {
                                     <span class="ui-modal-stack__breadcrumb">
                                         {if is_last {
@@ -400,6 +552,11 @@ where
                                                                     s.mounted_modals.clear();
                                                                 });
 
+                                                                if let Some(route) = &route {
+                                                                    let views = state.get_untracked().typed_views;
+                                                                    route.param.replace(Some(route.path(&views)));
+                                                                }
+
                                                                 // Call all external close callbacks
                                                                 for cb in callbacks {
                                                                     cb();
@@ -424,7 +581,7 @@ where
                         <button
                             class="ui-modal__close"
                             on:click=move |_| cb.run(())
-                            aria-label="Close"
+                            aria-label=move || locale.t_or("modal_stack.close", "Close")
                         >
                             "\u{00D7}"
                         </button>
@@ -0,0 +1,422 @@
+//! OtpInput Leptos Component
+//!
+//! A segmented one-time-code input: one box per character, for room join
+//! codes and verification codes.
+//!
+//! ## Features
+//!
+//! - Typing advances to the next box; Backspace clears and steps back
+//! - Arrow keys, Home and End move between boxes
+//! - Pasting (or OS one-time-code autofill) spreads the code across boxes
+//! - Numeric keyboard on mobile for numeric codes
+//! - Error state with a shake animation
+//!
+//! ## Props
+//!
+//! - `value` - Current code (Signal)
+//! - `on_change` - Callback with the code after every edit
+//! - `on_complete` - Callback when every box is filled
+//! - `length` - Number of boxes (default 6)
+//! - `charset` - Accepted characters: Numeric (default) or Alphanumeric
+//! - `label` - Optional label text
+//! - `error` - Show the error state (shakes when it turns on)
+//! - `disabled` - Whether the input is disabled
+//! - `autofocus` - Focus the first empty box on mount
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let (code, set_code) = signal(String::new());
+//! let (wrong, set_wrong) = signal(false);
+//!
+//! <OtpInput
+//!     value=code
+//!     on_change=move |c| { set_wrong.set(false); set_code.set(c) }
+//!     on_complete=move |c| verify(c)
+//!     error=wrong
+//! />
+//!
+//! // Room join code
+//! <OtpInput
+//!     value=room_code
+//!     on_change=move |c| set_room_code.set(c)
+//!     length=5
+//!     charset=OtpCharset::Alphanumeric
+//!     label="Room code"
+//! />
+//! ```
+
+use crate::i18n::use_locale;
+use leptos::html;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Characters an [`OtpInput`] accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OtpCharset {
+    /// Digits only, with a numeric keyboard on mobile
+    #[default]
+    Numeric,
+    /// ASCII letters and digits; letters are uppercased
+    Alphanumeric,
+}
+
+impl OtpCharset {
+    /// Normalize `c`, or `None` if the charset rejects it
+    pub fn accept(&self, c: char) -> Option<char> {
+        match self {
+            OtpCharset::Numeric => c.is_ascii_digit().then_some(c),
+            OtpCharset::Alphanumeric => c.is_ascii_alphanumeric().then(|| c.to_ascii_uppercase()),
+        }
+    }
+
+    fn input_mode(&self) -> &'static str {
+        match self {
+            OtpCharset::Numeric => "numeric",
+            OtpCharset::Alphanumeric => "text",
+        }
+    }
+}
+
+/// Keep the accepted characters of `input`, normalized, up to `length`
+pub fn sanitize_code(input: &str, charset: OtpCharset, length: usize) -> String {
+    input
+        .chars()
+        .filter_map(|c| charset.accept(c))
+        .take(length)
+        .collect()
+}
+
+/// Write `typed` into `code` starting at box `index`
+///
+/// Returns the new code and the box to focus next. Several characters (a
+/// paste or autofill) overwrite the following boxes. Boxes fill left to
+/// right, so typing past the end of the code appends.
+fn type_into_code(
+    code: &str,
+    index: usize,
+    typed: &str,
+    charset: OtpCharset,
+    length: usize,
+) -> (String, usize) {
+    let mut chars: Vec<char> = code.chars().take(length).collect();
+    let typed: Vec<char> = typed.chars().filter_map(|c| charset.accept(c)).collect();
+    let start = index.min(chars.len());
+    if typed.is_empty() {
+        return (chars.into_iter().collect(), start);
+    }
+
+    for (offset, c) in typed.iter().enumerate() {
+        let at = start + offset;
+        if at >= length {
+            break;
+        }
+        if at < chars.len() {
+            chars[at] = *c;
+        } else {
+            chars.push(*c);
+        }
+    }
+
+    let next = (start + typed.len()).min(length.saturating_sub(1));
+    (chars.into_iter().collect(), next)
+}
+
+/// Apply Backspace at box `index`
+///
+/// A filled box is cleared in place; an empty box clears the one before it
+/// and moves focus there. Later characters shift left so the code stays
+/// contiguous.
+fn backspace_code(code: &str, index: usize) -> (String, usize) {
+    let mut chars: Vec<char> = code.chars().collect();
+    let remove = if index < chars.len() {
+        index
+    } else if let Some(prev) = chars.len().checked_sub(1) {
+        prev
+    } else {
+        return (String::new(), 0);
+    };
+    chars.remove(remove);
+    (chars.into_iter().collect(), remove)
+}
+
+/// Segmented one-time-code input component
+#[component]
+pub fn OtpInput(
+    /// Current code
+    #[prop(into)]
+    value: Signal<String>,
+    /// Change callback, with the code after the edit
+    #[prop(into)]
+    on_change: Callback<String>,
+    /// Called with the code once every box is filled
+    #[prop(into, optional)]
+    on_complete: Option<Callback<String>>,
+    /// Number of boxes
+    #[prop(optional, default = 6)]
+    length: usize,
+    /// Accepted characters
+    #[prop(optional)]
+    charset: OtpCharset,
+    /// Optional label
+    #[prop(into, optional)]
+    label: Option<String>,
+    /// Show the error state
+    #[prop(into, optional)]
+    error: Option<Signal<bool>>,
+    /// Whether input is disabled
+    #[prop(into, optional)]
+    disabled: Option<Signal<bool>>,
+    /// Focus the first empty box on mount
+    #[prop(optional)]
+    autofocus: bool,
+    /// Additional class
+    #[prop(into, optional)]
+    class: Option<String>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let length = length.max(1);
+    let is_disabled = move || disabled.map(|d| d.get()).unwrap_or(false);
+    let has_error = move || error.map(|e| e.get()).unwrap_or(false);
+
+    let cells: Vec<NodeRef<html::Input>> = (0..length).map(|_| NodeRef::new()).collect();
+    let cells = StoredValue::new(cells);
+
+    let focus_cell = move |index: usize| {
+        if let Some(input) = cells.with_value(|c| c.get(index).and_then(|r| r.get_untracked())) {
+            let _ = input.focus();
+            input.select();
+        }
+    };
+
+    let commit = move |code: String| {
+        let complete = code.chars().count() == length;
+        on_change.run(code.clone());
+        if complete {
+            if let Some(cb) = on_complete {
+                cb.run(code);
+            }
+        }
+    };
+
+    let type_at = move |index: usize, typed: &str| {
+        let current = sanitize_code(&value.get_untracked(), charset, length);
+        let (code, next) = type_into_code(&current, index, typed, charset, length);
+        if code != current {
+            commit(code);
+        }
+        focus_cell(next);
+    };
+
+    if autofocus {
+        Effect::new(move |_| {
+            let filled = value.get_untracked().chars().count();
+            focus_cell(filled.min(length - 1));
+        });
+    }
+
+    let root_class = {
+        let mut classes = vec!["ui-otp-input"];
+        if let Some(ref c) = class {
+            classes.push(c);
+        }
+        classes.join(" ")
+    };
+
+    let cell_views = (0..length)
+        .map(|index| {
+            let node_ref = cells.with_value(|c| c[index]);
+            let cell_char = move |code: &str| {
+                sanitize_code(code, charset, length)
+                    .chars()
+                    .nth(index)
+                    .map(String::from)
+                    .unwrap_or_default()
+            };
+            let char_at = move || value.with(|code| cell_char(code));
+
+            let handle_input = move |ev: web_sys::Event| {
+                let input: web_sys::HtmlInputElement = ev.target().unwrap().unchecked_into();
+                let raw = input.value();
+                // Typing into a filled box that wasn't selected leaves the old
+                // character alongside the new one
+                let previous = value.with_untracked(|code| cell_char(code));
+                let typed = if previous.is_empty() || raw.len() <= previous.len() {
+                    raw
+                } else if let Some(rest) = raw.strip_prefix(previous.as_str()) {
+                    rest.to_string()
+                } else if let Some(rest) = raw.strip_suffix(previous.as_str()) {
+                    rest.to_string()
+                } else {
+                    raw
+                };
+                // Restore the box; the committed value re-renders it
+                input.set_value(&previous);
+                if typed.is_empty() {
+                    let current = sanitize_code(&value.get_untracked(), charset, length);
+                    let (code, next) = backspace_code(&current, index);
+                    commit(code);
+                    focus_cell(next);
+                } else {
+                    type_at(index, &typed);
+                }
+            };
+
+            let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+                let next = match ev.key().as_str() {
+                    "Backspace" => {
+                        ev.prevent_default();
+                        let current = sanitize_code(&value.get_untracked(), charset, length);
+                        if current.is_empty() {
+                            return;
+                        }
+                        let (code, next) = backspace_code(&current, index);
+                        commit(code);
+                        next
+                    }
+                    "ArrowLeft" => index.saturating_sub(1),
+                    "ArrowRight" => (index + 1).min(length - 1),
+                    "Home" => 0,
+                    "End" => length - 1,
+                    _ => return,
+                };
+                ev.prevent_default();
+                focus_cell(next);
+            };
+
+            let handle_paste = move |ev: web_sys::ClipboardEvent| {
+                let Some(text) = ev.clipboard_data().and_then(|d| d.get_data("text").ok()) else {
+                    return;
+                };
+                ev.prevent_default();
+                // A full code replaces everything, wherever it is pasted
+                let start = if sanitize_code(&text, charset, length).chars().count() == length {
+                    0
+                } else {
+                    index
+                };
+                type_at(start, &text);
+            };
+
+            let aria_label = move || {
+                locale.t_or_with(
+                    "otp_input.digit",
+                    "Character {index} of {length}",
+                    &[
+                        ("index", (index + 1).to_string()),
+                        ("length", length.to_string()),
+                    ],
+                )
+            };
+
+            view! {
+                <input
+                    node_ref=node_ref
+                    class="ui-otp-input__cell"
+                    class:ui-otp-input__cell--filled=move || !char_at().is_empty()
+                    type="text"
+                    inputmode=charset.input_mode()
+                    pattern=(charset == OtpCharset::Numeric).then_some("[0-9]*")
+                    autocomplete=if index == 0 { "one-time-code" } else { "off" }
+                    autocapitalize="characters"
+                    spellcheck="false"
+                    aria-label=aria_label
+                    aria-invalid=move || has_error().to_string()
+                    prop:value=char_at
+                    disabled=is_disabled
+                    on:input=handle_input
+                    on:keydown=handle_keydown
+                    on:paste=handle_paste
+                    on:focus=move |ev: web_sys::FocusEvent| {
+                        if let Some(input) = ev
+                            .target()
+                            .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+                        {
+                            input.select();
+                        }
+                    }
+                />
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <div class=root_class>
+            {label.map(|l| view! {
+                <label class="ui-otp-input__label">{l}</label>
+            })}
+            <div
+                class="ui-otp-input__cells"
+                class:ui-otp-input__cells--error=has_error
+                class:ui-otp-input__cells--disabled=is_disabled
+                role="group"
+            >
+                {cell_views}
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn charset_filters_and_normalizes() {
+        assert_eq!(sanitize_code("12-34 5x", OtpCharset::Numeric, 6), "12345");
+        assert_eq!(sanitize_code("ab-9z", OtpCharset::Alphanumeric, 6), "AB9Z");
+        assert_eq!(sanitize_code("1234567", OtpCharset::Numeric, 6), "123456");
+    }
+
+    #[test]
+    fn typing_advances_focus() {
+        assert_eq!(
+            type_into_code("12", 2, "3", OtpCharset::Numeric, 6),
+            ("123".to_string(), 3)
+        );
+        // Overwrite a filled box
+        assert_eq!(
+            type_into_code("123", 1, "9", OtpCharset::Numeric, 6),
+            ("193".to_string(), 2)
+        );
+        // Typing in a box past the end appends
+        assert_eq!(
+            type_into_code("1", 4, "2", OtpCharset::Numeric, 6),
+            ("12".to_string(), 2)
+        );
+        // Last box keeps focus
+        assert_eq!(
+            type_into_code("12345", 5, "6", OtpCharset::Numeric, 6),
+            ("123456".to_string(), 5)
+        );
+    }
+
+    #[test]
+    fn rejected_characters_leave_code_alone() {
+        assert_eq!(
+            type_into_code("12", 2, "a", OtpCharset::Numeric, 6),
+            ("12".to_string(), 2)
+        );
+    }
+
+    #[test]
+    fn paste_spreads_across_boxes() {
+        assert_eq!(
+            type_into_code("", 0, "123 456", OtpCharset::Numeric, 6),
+            ("123456".to_string(), 5)
+        );
+        assert_eq!(
+            type_into_code("12", 2, "3456789", OtpCharset::Numeric, 6),
+            ("123456".to_string(), 5)
+        );
+    }
+
+    #[test]
+    fn backspace_clears_then_steps_back() {
+        // Filled box clears in place, later characters shift left
+        assert_eq!(backspace_code("1234", 1), ("134".to_string(), 1));
+        // Empty box clears the previous one
+        assert_eq!(backspace_code("123", 3), ("12".to_string(), 2));
+        assert_eq!(backspace_code("", 0), (String::new(), 0));
+    }
+}
@@ -31,6 +31,7 @@
 //! <Pagination state=pagination />
 //! ```
 
+use crate::i18n::use_locale;
 use leptos::prelude::*;
 use wasm_bindgen::prelude::*;
 
@@ -265,6 +266,8 @@ pub fn Pagination(
     #[prop(into, optional)]
     class: Option<String>,
 ) -> impl IntoView {
+    let locale = use_locale();
+
     // Page jump input state
     let (jump_input, set_jump_input) = signal(String::new());
 
@@ -357,7 +360,7 @@ pub fn Pagination(
                 class="ui-pagination__btn ui-pagination__btn--nav ui-pagination__btn--first"
                 on:click=go_first
                 disabled=move || !state.has_prev()
-                title="First page"
+                title=move || locale.t_or("pagination.first", "First page")
             >
                 "«"
             </button>
@@ -367,7 +370,7 @@ pub fn Pagination(
                 class="ui-pagination__btn ui-pagination__btn--nav ui-pagination__btn--prev"
                 on:click=go_prev
                 disabled=move || !state.has_prev()
-                title="Previous page"
+                title=move || locale.t_or("pagination.previous", "Previous page")
             >
                 "‹"
             </button>
@@ -425,7 +428,7 @@ pub fn Pagination(
                 class="ui-pagination__btn ui-pagination__btn--nav ui-pagination__btn--next"
                 on:click=go_next
                 disabled=move || !state.has_next()
-                title="Next page"
+                title=move || locale.t_or("pagination.next", "Next page")
             >
                 "›"
             </button>
@@ -435,7 +438,7 @@ pub fn Pagination(
                 class="ui-pagination__btn ui-pagination__btn--nav ui-pagination__btn--last"
                 on:click=go_last
                 disabled=move || !state.has_next()
-                title="Last page"
+                title=move || locale.t_or("pagination.last", "Last page")
             >
                 "»"
             </button>
@@ -443,7 +446,14 @@ pub fn Pagination(
             // Page info
             <Show when=move || show_page_info fallback=|| ()>
                 <span class="ui-pagination__info">
-                    {move || format!("({} of {})", state.current_page.get(), state.total_pages())}
+                    {move || locale.t_or_with(
+                        "pagination.info",
+                        "({current} of {total})",
+                        &[
+                            ("current", state.current_page.get().to_string()),
+                            ("total", state.total_pages().to_string()),
+                        ],
+                    )}
                 </span>
             </Show>
 
@@ -452,7 +462,7 @@ pub fn Pagination(
                 <input
                     type="number"
                     class="ui-pagination__jump"
-                    placeholder="Go to"
+                    placeholder=move || locale.t_or("pagination.jump", "Go to")
                     min="1"
                     max=move || state.total_pages()
                     prop:value=move || jump_input.get()
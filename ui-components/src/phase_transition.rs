@@ -0,0 +1,249 @@
+//! PhaseTransition Leptos Component
+//!
+//! Full-screen animated overlays between game phases: a countdown, a short
+//! announcement ("All players ready"), or a winner announcement with confetti.
+//! Games map their own phase to a [`PhaseOverlay`]; the component handles
+//! animation and auto-dismissal, so any flow-based game can reuse it.
+//!
+//! ## Props
+//!
+//! - `overlay` - Overlay for the current phase, `None` for no overlay
+//! - `duration_ms` - How long announcements stay up (default: 2500, 0 = until the overlay changes)
+//! - `on_dismiss` - Called when the user clicks an announcement away
+//!
+//! Countdowns stay up for as long as `overlay` holds them; each new number
+//! replays the pop animation and drains a [`ProgressRing`] over one second.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let overlay = Signal::derive(move || match game_state.get().phase {
+//!     GamePhase::Starting { countdown } => Some(PhaseOverlay::Countdown { seconds: countdown }),
+//!     GamePhase::Playing => Some(PhaseOverlay::announcement("All players ready")),
+//!     GamePhase::Finished { .. } => Some(PhaseOverlay::winner("You win!")),
+//!     _ => None,
+//! });
+//!
+//! view! { <PhaseTransition overlay=overlay /> }
+//! ```
+
+use crate::i18n::use_locale;
+use crate::progress_ring::ProgressRing;
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// Default time announcements stay on screen
+pub const DEFAULT_PHASE_TRANSITION_MS: u32 = 2500;
+
+/// Number of confetti pieces in a winner announcement
+const CONFETTI_PIECES: usize = 48;
+
+const CONFETTI_COLORS: &[&str] = &[
+    "#ffd700", "#ff6b6b", "#4ecdc4", "#4a9eff", "#c77dff", "#7bd389",
+];
+
+/// What to show between phases
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhaseOverlay {
+    /// Big number counting down to the next phase
+    Countdown {
+        /// Seconds remaining
+        seconds: u8,
+    },
+    /// Short message, e.g. "All players ready"
+    Announcement {
+        title: String,
+        subtitle: Option<String>,
+    },
+    /// Winner announcement with confetti
+    Winner {
+        title: String,
+        subtitle: Option<String>,
+    },
+}
+
+impl PhaseOverlay {
+    /// Announcement without a subtitle
+    pub fn announcement(title: impl Into<String>) -> Self {
+        Self::Announcement {
+            title: title.into(),
+            subtitle: None,
+        }
+    }
+
+    /// Winner announcement without a subtitle
+    pub fn winner(title: impl Into<String>) -> Self {
+        Self::Winner {
+            title: title.into(),
+            subtitle: None,
+        }
+    }
+
+    /// Add a subtitle to an announcement or winner overlay
+    pub fn with_subtitle(mut self, text: impl Into<String>) -> Self {
+        if let Self::Announcement { subtitle, .. } | Self::Winner { subtitle, .. } = &mut self {
+            *subtitle = Some(text.into());
+        }
+        self
+    }
+
+    /// Whether the overlay hides itself after `duration_ms`
+    pub fn auto_dismisses(&self) -> bool {
+        !matches!(self, Self::Countdown { .. })
+    }
+}
+
+/// Full-screen overlay announcing phase changes
+#[component]
+pub fn PhaseTransition(
+    /// Overlay for the current phase
+    #[prop(into)]
+    overlay: Signal<Option<PhaseOverlay>>,
+    /// How long announcements stay up, in ms (0 = until the overlay changes)
+    #[prop(optional, default = DEFAULT_PHASE_TRANSITION_MS)]
+    duration_ms: u32,
+    /// Called when the user clicks an announcement away
+    #[prop(into, optional)]
+    on_dismiss: Option<Callback<()>>,
+) -> impl IntoView {
+    // Derived phase signals re-fire on every state change; only react to real changes
+    let current = Memo::new(move |_| overlay.get());
+    let visible = RwSignal::new(None::<PhaseOverlay>);
+    // Bumped per overlay so a stale dismiss timer can't hide a newer one
+    let generation = StoredValue::new(0u64);
+
+    Effect::new(move || {
+        let next = current.get();
+        generation.update_value(|g| *g += 1);
+        let this_generation = generation.get_value();
+
+        if duration_ms > 0 && next.as_ref().is_some_and(PhaseOverlay::auto_dismisses) {
+            set_timeout(
+                move || {
+                    if generation.get_value() == this_generation {
+                        visible.set(None);
+                    }
+                },
+                Duration::from_millis(u64::from(duration_ms)),
+            );
+        }
+        visible.set(next);
+    });
+
+    let dismiss = move |_| {
+        let dismissible =
+            visible.with_untracked(|o| o.as_ref().is_some_and(PhaseOverlay::auto_dismisses));
+        if dismissible {
+            visible.set(None);
+            if let Some(cb) = on_dismiss {
+                cb.run(());
+            }
+        }
+    };
+
+    view! {
+        {move || visible.get().map(|overlay| {
+            let class = match overlay {
+                PhaseOverlay::Countdown { .. } => "ui-phase-transition ui-phase-transition--countdown",
+                PhaseOverlay::Announcement { .. } => "ui-phase-transition ui-phase-transition--announcement",
+                PhaseOverlay::Winner { .. } => "ui-phase-transition ui-phase-transition--winner",
+            };
+            view! {
+                <div class=class role="status" aria-live="assertive" on:click=dismiss>
+                    {overlay_content(overlay)}
+                </div>
+            }
+        })}
+    }
+}
+
+fn overlay_content(overlay: PhaseOverlay) -> AnyView {
+    match overlay {
+        PhaseOverlay::Countdown { seconds } => {
+            let locale = use_locale();
+            view! {
+                <div class="ui-phase-transition__content">
+                    <p class="ui-phase-transition__label">
+                        {move || locale.t_or("phase_transition.get_ready", "Get ready")}
+                    </p>
+                    <ProgressRing
+                        class="ui-phase-transition__ring"
+                        deadline=Signal::stored(Some(js_sys::Date::now() as u64 + 1000))
+                        duration_ms=1000
+                        size=176
+                        stroke_width=8.0
+                    >
+                        <div class="ui-phase-transition__countdown">{seconds}</div>
+                    </ProgressRing>
+                </div>
+            }
+            .into_any()
+        }
+        PhaseOverlay::Announcement { title, subtitle } => view! {
+            <div class="ui-phase-transition__content">
+                <h2 class="ui-phase-transition__title">{title}</h2>
+                {subtitle.map(|s| view! { <p class="ui-phase-transition__subtitle">{s}</p> })}
+            </div>
+        }
+        .into_any(),
+        PhaseOverlay::Winner { title, subtitle } => view! {
+            <div class="ui-phase-transition__confetti" aria-hidden="true">
+                {(0..CONFETTI_PIECES).map(confetti_piece).collect_view()}
+            </div>
+            <div class="ui-phase-transition__content">
+                <div class="ui-phase-transition__trophy">"🏆"</div>
+                <h2 class="ui-phase-transition__title">{title}</h2>
+                {subtitle.map(|s| view! { <p class="ui-phase-transition__subtitle">{s}</p> })}
+            </div>
+        }
+        .into_any(),
+    }
+}
+
+/// One confetti piece, spread deterministically across the screen
+fn confetti_piece(i: usize) -> impl IntoView {
+    // Golden-ratio stepping spreads pieces evenly without a RNG
+    let spread = (i as f64 * 0.618_034).fract();
+    let left = spread * 100.0;
+    let delay = (i % 12) as f64 * 0.12;
+    let duration = 2.4 + (i % 5) as f64 * 0.35;
+    let rotation = (i * 47) % 360;
+    let color = CONFETTI_COLORS[i % CONFETTI_COLORS.len()];
+
+    view! {
+        <span
+            class="ui-phase-transition__confetti-piece"
+            style=format!(
+                "left: {left:.1}%; background: {color}; animation-delay: {delay:.2}s; \
+                 animation-duration: {duration:.2}s; --rotation: {rotation}deg;"
+            )
+        ></span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_countdown_does_not_auto_dismiss() {
+        assert!(!PhaseOverlay::Countdown { seconds: 3 }.auto_dismisses());
+        assert!(PhaseOverlay::announcement("All players ready").auto_dismisses());
+        assert!(PhaseOverlay::winner("You win!").auto_dismisses());
+    }
+
+    #[test]
+    fn test_with_subtitle() {
+        assert_eq!(
+            PhaseOverlay::winner("Alice wins!").with_subtitle("12 pairs"),
+            PhaseOverlay::Winner {
+                title: "Alice wins!".into(),
+                subtitle: Some("12 pairs".into()),
+            }
+        );
+        assert_eq!(
+            PhaseOverlay::Countdown { seconds: 1 }.with_subtitle("ignored"),
+            PhaseOverlay::Countdown { seconds: 1 }
+        );
+    }
+}
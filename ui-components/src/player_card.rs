@@ -11,6 +11,11 @@
 //! - `avatar_url` - Optional avatar image URL
 //! - `hero_url` - Optional hero/banner image URL
 //! - `meta` - Optional metadata items
+//! - `status` - Optional presence signal, shown as a dot on the avatar
+//! - `streak` - Optional streak signal, shown as a flame beside the name
+//! - `turn_progress` - Optional turn time remaining, shown as a ring
+//! - `host` - Show the host badge on the avatar
+//! - `crown` - Show the crown badge on the avatar
 //! - `on_click` - Optional click handler for the card
 //! - `actions` - Optional action buttons slot
 //!
@@ -24,6 +29,9 @@
 //!     avatar_url="https://..."
 //!     hero_url="https://..."
 //!     meta=vec!["Position: (10, 5)", "3 intents"]
+//!     status=presence
+//!     streak=streak
+//!     host=true
 //! >
 //!     <Button on_click=details>"Details"</Button>
 //!     <Button on_click=render variant=ButtonVariant::Secondary>"Render"</Button>
@@ -31,6 +39,9 @@
 //! ```
 
 use leptos::prelude::*;
+use ui_flow_protocol::PresenceStatus;
+
+use crate::player_chrome::{PlayerAvatar, StreakFlame};
 
 /// Player/user card component
 #[component]
@@ -56,6 +67,21 @@ pub fn PlayerCard(
     /// Optional click handler
     #[prop(into, optional)]
     on_click: Option<Callback<()>>,
+    /// Presence status; omit to hide the status dot
+    #[prop(into, optional)]
+    status: Option<Signal<Option<PresenceStatus>>>,
+    /// Current streak; omit to hide the flame
+    #[prop(into, optional)]
+    streak: Option<Signal<u32>>,
+    /// Turn time remaining as a fraction; omit to hide the ring
+    #[prop(into, optional)]
+    turn_progress: Option<Signal<f32>>,
+    /// Show the host badge
+    #[prop(into, optional)]
+    host: Signal<bool>,
+    /// Show the crown badge (leader/winner)
+    #[prop(into, optional)]
+    crown: Signal<bool>,
     /// Action buttons slot
     #[prop(optional)]
     children: Option<Children>,
@@ -82,20 +108,19 @@ pub fn PlayerCard(
 
                 // Avatar overlay
                 <div class="ui-player-card__avatar">
-                    {if let Some(url) = avatar_url {
-                        view! {
-                            <img src=url alt="" />
-                        }.into_any()
-                    } else {
-                        view! {
-                            <span class="ui-player-card__avatar-fallback">"👤"</span>
-                        }.into_any()
-                    }}
+                    <PlayerAvatar
+                        avatar_url=avatar_url
+                        status=status
+                        turn_progress=turn_progress
+                        host=host
+                        crown=crown
+                    />
                 </div>
 
                 // Name overlay on image
                 <div class="ui-player-card__name-overlay">
                     <h4 class="ui-player-card__name">{name}</h4>
+                    {streak.map(|streak| view! { <StreakFlame streak=streak /> })}
                 </div>
             </div>
 
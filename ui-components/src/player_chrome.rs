@@ -0,0 +1,240 @@
+//! Player Chrome Leptos Components
+//!
+//! Shared player decorations used by [`PlayerCard`](crate::PlayerCard) and
+//! [`PlayerList`](crate::PlayerList): an avatar with presence dot, turn-timer
+//! ring and host/crown badges, plus a streak flame.
+//!
+//! ## Props (PlayerAvatar)
+//!
+//! - `avatar_url` - Optional avatar image URL
+//! - `status` - Optional presence signal (`None` value renders as offline)
+//! - `turn_progress` - Optional turn time remaining (1.0 = full, 0.0 = expired)
+//! - `host` - Show the host badge
+//! - `crown` - Show the crown badge (leader/winner)
+//!
+//! The optional props take `Option` values as-is so wrappers can forward
+//! their own optional props.
+//!
+//! ## Props (StreakFlame)
+//!
+//! - `streak` - Current streak count; hidden below 2
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let status = RwSignal::new(Some(PresenceStatus::Active));
+//! let remaining = RwSignal::new(0.6_f32);
+//! let streak = RwSignal::new(3_u32);
+//!
+//! <PlayerAvatar
+//!     avatar_url=Some(url)
+//!     status=Some(status.into())
+//!     turn_progress=Some(remaining.into())
+//!     host=true
+//! />
+//! <StreakFlame streak=streak />
+//! ```
+
+use leptos::prelude::*;
+use ui_flow_protocol::PresenceStatus;
+
+use crate::i18n::use_locale;
+
+/// Radius of the turn-timer ring inside its 36x36 viewBox
+const RING_RADIUS: f32 = 16.0;
+
+/// Remaining turn fraction below which the ring is drawn as urgent
+const RING_URGENT_BELOW: f32 = 0.25;
+
+/// Circumference of the turn-timer ring
+fn ring_circumference() -> f32 {
+    2.0 * std::f32::consts::PI * RING_RADIUS
+}
+
+/// Stroke offset that leaves `progress` of the ring visible
+fn ring_dash_offset(progress: f32) -> f32 {
+    ring_circumference() * (1.0 - progress.clamp(0.0, 1.0))
+}
+
+/// CSS modifier and i18n key for a presence state (`None` = offline)
+fn status_parts(status: Option<PresenceStatus>) -> (&'static str, &'static str, &'static str) {
+    match status {
+        Some(PresenceStatus::Active) => ("online", "player_chrome.online", "Online"),
+        Some(PresenceStatus::Idle) => ("idle", "player_chrome.idle", "Idle"),
+        Some(PresenceStatus::Away) => ("away", "player_chrome.away", "Away"),
+        None => ("offline", "player_chrome.offline", "Offline"),
+    }
+}
+
+/// Visual intensity of a streak
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreakTier {
+    /// 2-3 in a row
+    Warm,
+    /// 4-5 in a row
+    Hot,
+    /// 6 or more in a row
+    Blazing,
+}
+
+impl StreakTier {
+    /// Tier for a streak count, `None` when too short to show
+    pub fn for_count(count: u32) -> Option<Self> {
+        match count {
+            0..=1 => None,
+            2..=3 => Some(Self::Warm),
+            4..=5 => Some(Self::Hot),
+            _ => Some(Self::Blazing),
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Self::Warm => "ui-streak-flame ui-streak-flame--warm",
+            Self::Hot => "ui-streak-flame ui-streak-flame--hot",
+            Self::Blazing => "ui-streak-flame ui-streak-flame--blazing",
+        }
+    }
+}
+
+/// Player avatar with presence, turn timer and host/crown badges
+#[component]
+pub fn PlayerAvatar(
+    /// Optional avatar image URL
+    #[prop(optional_no_strip)]
+    avatar_url: Option<String>,
+    /// Presence status; `None` hides the status dot
+    #[prop(optional_no_strip)]
+    status: Option<Signal<Option<PresenceStatus>>>,
+    /// Turn time remaining as a fraction; `None` hides the ring
+    #[prop(optional_no_strip)]
+    turn_progress: Option<Signal<f32>>,
+    /// Show the host badge
+    #[prop(into, optional)]
+    host: Signal<bool>,
+    /// Show the crown badge (leader/winner)
+    #[prop(into, optional)]
+    crown: Signal<bool>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let circumference = ring_circumference();
+
+    view! {
+        <div class="ui-player-avatar">
+            <div class="ui-player-avatar__image">
+                {if let Some(url) = avatar_url {
+                    view! { <img src=url alt="" /> }.into_any()
+                } else {
+                    view! { <span class="ui-player-avatar__fallback">"👤"</span> }.into_any()
+                }}
+            </div>
+
+            {turn_progress.map(|progress| view! {
+                <svg class="ui-player-avatar__ring" viewBox="0 0 36 36" aria-hidden="true">
+                    <circle class="ui-player-avatar__ring-track" cx="18" cy="18" r=RING_RADIUS />
+                    <circle
+                        class="ui-player-avatar__ring-fill"
+                        class:ui-player-avatar__ring-fill--urgent=move || progress.get() < RING_URGENT_BELOW
+                        cx="18"
+                        cy="18"
+                        r=RING_RADIUS
+                        stroke-dasharray=circumference
+                        stroke-dashoffset=move || ring_dash_offset(progress.get())
+                    />
+                </svg>
+            })}
+
+            {status.map(|status| {
+                let class = move || {
+                    format!("ui-player-avatar__status ui-player-avatar__status--{}", status_parts(status.get()).0)
+                };
+                let label = move || {
+                    let (_, key, default) = status_parts(status.get());
+                    locale.t_or(key, default)
+                };
+                view! { <span class=class title=label aria-label=label role="img"></span> }
+            })}
+
+            <Show when=move || crown.get()>
+                <span
+                    class="ui-player-avatar__crown"
+                    title=move || locale.t_or("player_chrome.leader", "Leader")
+                >
+                    "👑"
+                </span>
+            </Show>
+
+            <Show when=move || host.get()>
+                <span class="ui-player-avatar__host">
+                    {move || locale.t_or("player_chrome.host", "Host")}
+                </span>
+            </Show>
+        </div>
+    }
+}
+
+/// Streak flame with count, hidden until the streak reaches 2
+#[component]
+pub fn StreakFlame(
+    /// Current streak count
+    #[prop(into)]
+    streak: Signal<u32>,
+) -> impl IntoView {
+    let locale = use_locale();
+
+    move || {
+        let count = streak.get();
+        StreakTier::for_count(count).map(|tier| {
+            let label = locale.t_or_with(
+                "player_chrome.streak",
+                "{count} in a row",
+                &[("count", count.to_string())],
+            );
+            view! {
+                <span class=tier.class() title=label.clone() aria-label=label>
+                    <span class="ui-streak-flame__icon" aria-hidden="true">"🔥"</span>
+                    <span class="ui-streak-flame__count">{count}</span>
+                </span>
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ring_offset_spans_full_circle() {
+        let c = ring_circumference();
+        assert_eq!(ring_dash_offset(1.0), 0.0);
+        assert!((ring_dash_offset(0.0) - c).abs() < f32::EPSILON);
+        assert!((ring_dash_offset(0.5) - c / 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn ring_offset_clamps_out_of_range() {
+        assert_eq!(ring_dash_offset(1.5), 0.0);
+        assert_eq!(ring_dash_offset(-0.5), ring_circumference());
+    }
+
+    #[test]
+    fn status_modifiers() {
+        assert_eq!(status_parts(Some(PresenceStatus::Active)).0, "online");
+        assert_eq!(status_parts(Some(PresenceStatus::Idle)).0, "idle");
+        assert_eq!(status_parts(Some(PresenceStatus::Away)).0, "away");
+        assert_eq!(status_parts(None).0, "offline");
+    }
+
+    #[test]
+    fn streak_tiers() {
+        assert_eq!(StreakTier::for_count(0), None);
+        assert_eq!(StreakTier::for_count(1), None);
+        assert_eq!(StreakTier::for_count(2), Some(StreakTier::Warm));
+        assert_eq!(StreakTier::for_count(3), Some(StreakTier::Warm));
+        assert_eq!(StreakTier::for_count(4), Some(StreakTier::Hot));
+        assert_eq!(StreakTier::for_count(5), Some(StreakTier::Hot));
+        assert_eq!(StreakTier::for_count(6), Some(StreakTier::Blazing));
+        assert_eq!(StreakTier::for_count(40), Some(StreakTier::Blazing));
+    }
+}
@@ -0,0 +1,232 @@
+//! PlayerList Leptos Component
+//!
+//! A scoreboard of players with turn indicator, presence, streaks and
+//! host/crown/bot badges, built from the same chrome as
+//! [`PlayerCard`](crate::PlayerCard).
+//!
+//! ## Props
+//!
+//! - `players` - Players to list, in display order
+//! - `current_turn` - user_id whose turn it is (highlights the row)
+//! - `current_user_id` - Local user's id (marked "you")
+//! - `turn_progress` - Optional turn time remaining for the current player
+//! - `track_presence` - Show presence dots (missing presence = offline)
+//! - `crown_leader` - Crown the player with the unique highest score
+//! - `title` - Optional heading
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let players = Signal::derive(move || {
+//!     state.get().players.values().map(|p| PlayerListEntry {
+//!         user_id: p.user_id.clone(),
+//!         name: p.user_name.clone(),
+//!         score: p.score,
+//!         host: state.get().host.as_ref() == Some(&p.user_id),
+//!         ..Default::default()
+//!     }).collect()
+//! });
+//!
+//! <PlayerList
+//!     players=players
+//!     current_turn=current_turn
+//!     current_user_id=my_id
+//!     track_presence=true
+//!     crown_leader=true
+//! />
+//! ```
+
+use leptos::prelude::*;
+use ui_flow_protocol::PresenceStatus;
+
+use crate::density::density_attr;
+use crate::i18n::use_locale;
+use crate::player_chrome::{PlayerAvatar, StreakFlame};
+
+/// A player row in a [`PlayerList`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlayerListEntry {
+    /// Stable user id
+    pub user_id: String,
+    /// Display name
+    pub name: String,
+    /// Optional avatar image URL
+    pub avatar_url: Option<String>,
+    /// Current score
+    pub score: u32,
+    /// Current streak (flame shown from 2)
+    pub streak: u32,
+    /// Presence, `None` when the player is not connected
+    pub presence: Option<PresenceStatus>,
+    /// Watching rather than playing
+    pub spectating: bool,
+    /// Room host
+    pub host: bool,
+    /// Computer-controlled player
+    pub bot: bool,
+}
+
+/// user_id of the player with the unique highest non-zero score
+fn leader_id(players: &[PlayerListEntry]) -> Option<&str> {
+    let mut competing = players.iter().filter(|p| !p.spectating);
+    let mut leader = competing.next()?;
+    let mut tied = false;
+    for player in competing {
+        if player.score > leader.score {
+            leader = player;
+            tied = false;
+        } else if player.score == leader.score {
+            tied = true;
+        }
+    }
+    (!tied && leader.score > 0).then_some(leader.user_id.as_str())
+}
+
+/// Player list / scoreboard component
+#[component]
+pub fn PlayerList(
+    /// Players to list, in display order
+    #[prop(into)]
+    players: Signal<Vec<PlayerListEntry>>,
+    /// user_id whose turn it is; `None` in race mode or outside play
+    #[prop(into, optional)]
+    current_turn: Signal<Option<String>>,
+    /// Local user's id
+    #[prop(into, optional)]
+    current_user_id: Signal<String>,
+    /// Turn time remaining for the current player as a fraction
+    #[prop(into, optional)]
+    turn_progress: Option<Signal<f32>>,
+    /// Show presence dots for every player
+    #[prop(optional)]
+    track_presence: bool,
+    /// Crown the player with the unique highest score
+    #[prop(optional)]
+    crown_leader: bool,
+    /// Optional heading
+    #[prop(into, optional)]
+    title: Option<String>,
+) -> impl IntoView {
+    let locale = use_locale();
+
+    view! {
+        <div class="ui-player-list" data-density=density_attr()>
+            {title.map(|t| view! { <h3 class="ui-player-list__title">{t}</h3> })}
+            <ul class="ui-player-list__items">
+                {move || {
+                    let players_vec = players.get();
+                    let turn = current_turn.get();
+                    let my_id = current_user_id.get();
+                    let leader = leader_id(&players_vec)
+                        .filter(|_| crown_leader)
+                        .map(str::to_string);
+
+                    players_vec.into_iter().map(|player| {
+                        let is_current_turn = turn.as_ref() == Some(&player.user_id);
+                        let is_me = player.user_id == my_id;
+                        let is_leader = leader.as_ref() == Some(&player.user_id);
+                        let presence = player.presence;
+
+                        view! {
+                            <li
+                                class="ui-player-list__item"
+                                class:ui-player-list__item--turn=is_current_turn
+                                class:ui-player-list__item--me=is_me
+                                class:ui-player-list__item--spectator=player.spectating
+                            >
+                                <PlayerAvatar
+                                    avatar_url=player.avatar_url
+                                    status=track_presence.then(|| Signal::derive(move || presence))
+                                    turn_progress=turn_progress.filter(|_| is_current_turn)
+                                    host=player.host
+                                    crown=is_leader
+                                />
+                                <span class="ui-player-list__name">
+                                    {player.name}
+                                    {is_me.then(|| view! {
+                                        <span class="ui-player-list__me">
+                                            {move || locale.t_or("player_list.you", "(you)")}
+                                        </span>
+                                    })}
+                                    {player.bot.then(|| view! {
+                                        <span class="ui-player-list__bot">
+                                            {move || locale.t_or("player_list.bot", "Bot")}
+                                        </span>
+                                    })}
+                                </span>
+                                <StreakFlame streak=player.streak />
+                                {if player.spectating {
+                                    view! {
+                                        <span
+                                            class="ui-player-list__spectator"
+                                            title=move || locale.t_or("player_list.spectating", "Spectating")
+                                        >
+                                            "👁"
+                                        </span>
+                                    }.into_any()
+                                } else {
+                                    let score = player.score;
+                                    view! {
+                                        <span class="ui-player-list__score">
+                                            {move || locale.t_or_with(
+                                                "player_list.points",
+                                                "{score} pts",
+                                                &[("score", score.to_string())],
+                                            )}
+                                        </span>
+                                    }.into_any()
+                                }}
+                            </li>
+                        }
+                    }).collect_view()
+                }}
+            </ul>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, score: u32) -> PlayerListEntry {
+        PlayerListEntry {
+            user_id: id.to_string(),
+            name: id.to_string(),
+            score,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn leader_is_unique_top_score() {
+        let players = vec![entry("a", 2), entry("b", 5), entry("c", 3)];
+        assert_eq!(leader_id(&players), Some("b"));
+    }
+
+    #[test]
+    fn tie_has_no_leader() {
+        let players = vec![entry("a", 4), entry("b", 4), entry("c", 1)];
+        assert_eq!(leader_id(&players), None);
+    }
+
+    #[test]
+    fn tie_broken_by_higher_score() {
+        let players = vec![entry("a", 4), entry("b", 4), entry("c", 6)];
+        assert_eq!(leader_id(&players), Some("c"));
+    }
+
+    #[test]
+    fn zero_scores_have_no_leader() {
+        assert_eq!(leader_id(&[entry("a", 0)]), None);
+        assert_eq!(leader_id(&[]), None);
+    }
+
+    #[test]
+    fn spectators_are_not_crowned() {
+        let mut watcher = entry("w", 9);
+        watcher.spectating = true;
+        let players = vec![watcher, entry("a", 3)];
+        assert_eq!(leader_id(&players), Some("a"));
+    }
+}
@@ -9,6 +9,8 @@
 //! - Pagination with page controls
 //! - Optional custom title (for known collection names)
 //! - Controlled or uncontrolled expansion state
+//! - Optional multi-select (clicks toggle NFTs in a shared selection)
+//! - Optional header tint derived from the first NFT's art
 //!
 //! ## Usage
 //!
@@ -23,10 +25,11 @@
 //! />
 //! ```
 
-use crate::asset_card::AssetCard;
+use crate::asset_card::{accent_style, generate_iiif_url, use_art_accent, AssetCard, IiifSize};
 use crate::image_card::CardSize;
 use crate::pagination::{use_adaptive_pagination, Pagination};
 use leptos::prelude::*;
+use std::collections::HashSet;
 use wallet_pallas::{NativeToken, PolicyGroup};
 
 /// Collapsible folder for displaying NFTs from a single policy
@@ -55,6 +58,19 @@ pub fn PolicyFolder(
     #[prop(into, optional)]
     on_asset_click: Option<Callback<(String, String)>>,
 
+    /// Selected asset ids; clicking an NFT toggles it instead of calling
+    /// `on_asset_click`
+    #[prop(optional)]
+    selection: Option<RwSignal<HashSet<String>>>,
+
+    /// Only toggle selection while true (default: always)
+    #[prop(into, optional)]
+    select_mode: Option<Signal<bool>>,
+
+    /// Tint the header with the dominant color of the first NFT's art
+    #[prop(optional)]
+    tint_from_art: bool,
+
     /// Additional CSS class
     #[prop(into, optional)]
     class: Option<String>,
@@ -87,6 +103,18 @@ pub fn PolicyFolder(
     let nfts: Vec<NativeToken> = group.nfts().into_iter().cloned().collect();
     let total_count = nfts.len();
 
+    // Header tint sampled from the first NFT's thumbnail
+    let cover_url = nfts
+        .first()
+        .and_then(|nft| generate_iiif_url(&nft.asset_id(), IiifSize::Thumb));
+    let art_accent = use_art_accent(move || cover_url.clone().filter(|_| tint_from_art));
+    let header_style = move || {
+        art_accent
+            .get()
+            .map(|color| accent_style("policy-folder", color))
+            .unwrap_or_default()
+    };
+
     // Grid ref for adaptive pagination
     let grid_ref = NodeRef::<leptos::html::Div>::new();
 
@@ -106,7 +134,7 @@ pub fn PolicyFolder(
 
     view! {
         <div class=wrapper_class>
-            <button class="ui-policy-folder__header" on:click=toggle>
+            <button class="ui-policy-folder__header" style=header_style on:click=toggle>
                 <span class="ui-policy-folder__icon">
                     {move || if is_expanded() { "▼" } else { "▶" }}
                 </span>
@@ -140,13 +168,12 @@ pub fn PolicyFolder(
                                     key=|nft| nft.asset_id()
                                     let:nft
                                 >
-                                    {
-                                        if let Some(cb) = on_asset_click {
-                                            view! { <NftCard nft=nft on_click=cb /> }.into_any()
-                                        } else {
-                                            view! { <NftCard nft=nft /> }.into_any()
-                                        }
-                                    }
+                                    <NftCard
+                                        nft=nft
+                                        on_click=on_asset_click
+                                        selection=selection
+                                        select_mode=select_mode
+                                    />
                                 </For>
                             </div>
 
@@ -175,16 +202,35 @@ pub fn PolicyFolder(
 #[component]
 fn NftCard(
     nft: NativeToken,
-    #[prop(optional)] on_click: Option<Callback<(String, String)>>,
+    on_click: Option<Callback<(String, String)>>,
+    selection: Option<RwSignal<HashSet<String>>>,
+    select_mode: Option<Signal<bool>>,
 ) -> impl IntoView {
     let asset_id = nft.asset_id();
     let name = nft.display_name();
 
-    if let Some(cb) = on_click {
+    let is_selected = {
+        let id = asset_id.clone();
+        move || selection.is_some_and(|s| s.with(|s| s.contains(&id)))
+    };
+
+    let card = if on_click.is_some() || selection.is_some() {
         let id = asset_id.clone();
         let n = name.clone();
         let click_handler = Callback::new(move |_: String| {
-            cb.run((id.clone(), n.clone()));
+            let selecting = select_mode.is_none_or(|s| s.get_untracked());
+            match selection {
+                Some(selection) if selecting => selection.update(|s| {
+                    if !s.remove(&id) {
+                        s.insert(id.clone());
+                    }
+                }),
+                _ => {
+                    if let Some(cb) = on_click {
+                        cb.run((id.clone(), n.clone()));
+                    }
+                }
+            }
         });
 
         view! {
@@ -209,5 +255,14 @@ fn NftCard(
             />
         }
         .into_any()
+    };
+
+    view! {
+        <div class="ui-policy-folder__item" class:ui-policy-folder__item--selected=is_selected.clone()>
+            {card}
+            {move || is_selected().then(|| view! {
+                <span class="ui-policy-folder__check" aria-hidden="true">"✓"</span>
+            })}
+        </div>
     }
 }
@@ -0,0 +1,543 @@
+//! ProductTour Leptos Component
+//!
+//! A step-by-step onboarding walkthrough. Each step points at an element
+//! wrapped in [`TourAnchor`]: the anchor is cut out of a dimmed backdrop and
+//! a popover beside it explains what it does. Finishing or skipping marks the
+//! tour complete in [`ui_core::settings::Settings`], so it only starts on its
+//! own once per user.
+//!
+//! Steps whose anchor isn't mounted show a centered popover over a fully
+//! dimmed page. Arrow keys move between steps and Escape skips.
+//!
+//! ## Props
+//!
+//! - `id` - Tour identifier; completion is stored under it
+//! - `steps` - Steps in order
+//! - `open` - Control visibility (e.g. a "Replay tour" button); without it the
+//!   tour opens on mount unless already completed
+//! - `on_finish` - Called with `true` when completed, `false` when skipped
+//! - `children` - Page content containing the `TourAnchor`s
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_components::{ProductTour, TourAnchor, TourPlacement, TourStep};
+//!
+//! view! {
+//!     <ProductTour
+//!         id="raffle-onboarding"
+//!         steps=vec![
+//!             TourStep::new("wallet", "Connect a wallet", "Tickets are bought with ADA."),
+//!             TourStep::new("prizes", "Prizes", "Everything up for grabs this round.")
+//!                 .placement(TourPlacement::Right),
+//!         ]
+//!     >
+//!         <TourAnchor id="wallet"><WalletButton /></TourAnchor>
+//!         <TourAnchor id="prizes"><PrizeList /></TourAnchor>
+//!     </ProductTour>
+//! }
+//!
+//! // Replay on demand
+//! let open = RwSignal::new(false);
+//! view! {
+//!     <Button on_click=move |_| open.set(true)>"Show me around"</Button>
+//!     <ProductTour id="raffle-onboarding" steps=steps open=open>
+//!         ...
+//!     </ProductTour>
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use ui_core::settings::Settings;
+
+use crate::i18n::use_locale;
+
+/// Settings namespace tour completion flags are stored under
+const SETTINGS_NAMESPACE: &str = "product-tour";
+
+/// Space between the anchor and the popover (px)
+const POPOVER_GAP: f64 = 12.0;
+
+/// Popover width (px), matching `.ui-tour__popover`
+const POPOVER_WIDTH: f64 = 320.0;
+
+/// Room (px) the popover needs above or below its anchor before flipping
+const POPOVER_MIN_SPACE: f64 = 160.0;
+
+/// Closest the popover gets to the viewport edge (px)
+const VIEWPORT_MARGIN: f64 = 8.0;
+
+/// Spotlight padding around the anchor (px)
+const SPOTLIGHT_PADDING: f64 = 6.0;
+
+/// Side of the anchor the popover appears on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TourPlacement {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+/// One step of a tour
+#[derive(Debug, Clone, PartialEq)]
+pub struct TourStep {
+    /// Id of the [`TourAnchor`] to highlight
+    pub anchor: String,
+    /// Popover heading
+    pub title: String,
+    /// Popover text
+    pub body: String,
+    /// Preferred popover side; flipped when there's no room
+    pub placement: TourPlacement,
+}
+
+impl TourStep {
+    /// Step pointing at `anchor`, with the popover below it
+    pub fn new(
+        anchor: impl Into<String>,
+        title: impl Into<String>,
+        body: impl Into<String>,
+    ) -> Self {
+        Self {
+            anchor: anchor.into(),
+            title: title.into(),
+            body: body.into(),
+            placement: TourPlacement::default(),
+        }
+    }
+
+    /// Set the preferred popover side
+    pub fn placement(mut self, placement: TourPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+}
+
+/// Whether the tour `id` has been completed or skipped
+pub fn is_tour_completed(id: &str) -> bool {
+    Settings::new(SETTINGS_NAMESPACE).get_or(id, false)
+}
+
+/// Forget that the tour `id` was completed, so it opens on its own again
+pub fn reset_tour(id: &str) {
+    Settings::new(SETTINGS_NAMESPACE).remove(id);
+}
+
+fn mark_completed(id: &str) {
+    if let Err(e) = Settings::new(SETTINGS_NAMESPACE).set(id, &true) {
+        tracing::warn!("Failed to persist tour completion for {id}: {e}");
+    }
+}
+
+/// Anchors registered under the nearest `ProductTour`
+#[derive(Clone, Copy)]
+struct TourAnchors(RwSignal<HashMap<String, SendWrapper<web_sys::Element>>>);
+
+/// An anchor's viewport rectangle
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AnchorRect {
+    top: f64,
+    left: f64,
+    width: f64,
+    height: f64,
+}
+
+impl AnchorRect {
+    fn of(element: &web_sys::Element) -> Self {
+        let rect = element.get_bounding_client_rect();
+        Self {
+            top: rect.top(),
+            left: rect.left(),
+            width: rect.width(),
+            height: rect.height(),
+        }
+    }
+
+    fn bottom(&self) -> f64 {
+        self.top + self.height
+    }
+
+    fn right(&self) -> f64 {
+        self.left + self.width
+    }
+
+    fn grow(self, by: f64) -> Self {
+        Self {
+            top: self.top - by,
+            left: self.left - by,
+            width: self.width + by * 2.0,
+            height: self.height + by * 2.0,
+        }
+    }
+}
+
+/// Viewport size in px
+fn viewport() -> (f64, f64) {
+    let size = |v: Result<wasm_bindgen::JsValue, _>| v.ok().and_then(|v| v.as_f64());
+    web_sys::window()
+        .map(|w| {
+            (
+                size(w.inner_width()).unwrap_or(0.0),
+                size(w.inner_height()).unwrap_or(0.0),
+            )
+        })
+        .unwrap_or((0.0, 0.0))
+}
+
+/// `placement`, flipped to the opposite side if it doesn't fit but that does
+fn resolve_placement(
+    rect: AnchorRect,
+    placement: TourPlacement,
+    (width, height): (f64, f64),
+) -> TourPlacement {
+    let above = rect.top;
+    let below = height - rect.bottom();
+    let horizontal = POPOVER_WIDTH + POPOVER_GAP + VIEWPORT_MARGIN;
+    let before = rect.left;
+    let after = width - rect.right();
+
+    match placement {
+        TourPlacement::Bottom if below < POPOVER_MIN_SPACE && above > below => TourPlacement::Top,
+        TourPlacement::Top if above < POPOVER_MIN_SPACE && below > above => TourPlacement::Bottom,
+        TourPlacement::Left if before < horizontal && after > before => TourPlacement::Right,
+        TourPlacement::Right if after < horizontal && before > after => TourPlacement::Left,
+        other => other,
+    }
+}
+
+/// Fixed-position style placing the popover beside `rect`, or centered without one
+fn popover_style(
+    rect: Option<AnchorRect>,
+    placement: TourPlacement,
+    viewport: (f64, f64),
+) -> String {
+    let Some(rect) = rect else {
+        return "top: 50%; left: 50%; transform: translate(-50%, -50%);".to_string();
+    };
+
+    let (width, height) = viewport;
+    // Keep the popover's centre far enough from the edges for it to fit
+    let half = POPOVER_WIDTH / 2.0 + VIEWPORT_MARGIN;
+    let center_x = (rect.left + rect.width / 2.0).clamp(half, (width - half).max(half));
+    let center_y = (rect.top + rect.height / 2.0).clamp(
+        VIEWPORT_MARGIN,
+        (height - VIEWPORT_MARGIN).max(VIEWPORT_MARGIN),
+    );
+
+    let (top, left, transform) = match resolve_placement(rect, placement, viewport) {
+        TourPlacement::Bottom => (rect.bottom() + POPOVER_GAP, center_x, "translate(-50%, 0)"),
+        TourPlacement::Top => (rect.top - POPOVER_GAP, center_x, "translate(-50%, -100%)"),
+        TourPlacement::Right => (center_y, rect.right() + POPOVER_GAP, "translate(0, -50%)"),
+        TourPlacement::Left => (center_y, rect.left - POPOVER_GAP, "translate(-100%, -50%)"),
+    };
+    format!("top: {top}px; left: {left}px; transform: {transform};")
+}
+
+/// Onboarding walkthrough over the wrapped content
+#[component]
+pub fn ProductTour(
+    /// Tour identifier; completion is stored under it
+    #[prop(into)]
+    id: String,
+    /// Steps in order
+    steps: Vec<TourStep>,
+    /// Visibility; defaults to open unless already completed
+    #[prop(optional)]
+    open: Option<RwSignal<bool>>,
+    /// Called with `true` when completed, `false` when skipped
+    #[prop(into, optional)]
+    on_finish: Option<Callback<bool>>,
+    /// Content containing the tour's anchors
+    children: Children,
+) -> impl IntoView {
+    let locale = use_locale();
+    let anchors = TourAnchors(RwSignal::new(HashMap::new()));
+    provide_context(anchors);
+    let content = children();
+
+    let open = open.unwrap_or_else(|| RwSignal::new(!steps.is_empty() && !is_tour_completed(&id)));
+    let step_count = steps.len();
+    let steps = StoredValue::new(steps);
+    let id = StoredValue::new(id);
+    let step_index = RwSignal::new(0usize);
+
+    // Each opening starts from the first step
+    Effect::new(move |was_open: Option<bool>| {
+        let is_open = open.get();
+        if is_open && was_open == Some(false) {
+            step_index.set(0);
+        }
+        is_open
+    });
+
+    let current = move || steps.with_value(|steps| steps.get(step_index.get()).cloned());
+    let anchor_element = move || {
+        let anchor = current()?.anchor;
+        anchors
+            .0
+            .with(|anchors| anchors.get(&anchor).map(|el| (**el).clone()))
+    };
+
+    // Bumped to re-measure the anchor after layout changes
+    let layout = RwSignal::new(0u32);
+    let remeasure = move |_| {
+        if open.get_untracked() {
+            layout.update(|n| *n = n.wrapping_add(1));
+        }
+    };
+    let resize = window_event_listener(leptos::ev::resize, remeasure);
+    let scroll = window_event_listener(leptos::ev::scroll, remeasure);
+
+    let rect = move || {
+        layout.track();
+        if !open.get() {
+            return None;
+        }
+        anchor_element().map(|el| AnchorRect::of(&el))
+    };
+
+    // Bring each step's anchor on screen
+    Effect::new(move |_| {
+        if !open.get() {
+            return;
+        }
+        if let Some(el) = anchor_element() {
+            let rect = AnchorRect::of(&el);
+            let (_, height) = viewport();
+            if rect.top < 0.0 || rect.bottom() > height {
+                el.scroll_into_view();
+            }
+        }
+    });
+
+    let finish = move |completed: bool| {
+        open.set(false);
+        id.with_value(|id| mark_completed(id));
+        if let Some(cb) = on_finish {
+            cb.run(completed);
+        }
+    };
+    let next = move || {
+        if step_index.get_untracked() + 1 < step_count {
+            step_index.update(|i| *i += 1);
+        } else {
+            finish(true);
+        }
+    };
+    let back = move || step_index.update(|i| *i = i.saturating_sub(1));
+
+    let keys = window_event_listener(leptos::ev::keydown, move |ev| {
+        if !open.get_untracked() {
+            return;
+        }
+        match ev.key().as_str() {
+            "Escape" => finish(false),
+            "ArrowRight" => next(),
+            "ArrowLeft" => back(),
+            _ => {}
+        }
+    });
+
+    on_cleanup(move || {
+        resize.remove();
+        scroll.remove();
+        keys.remove();
+    });
+
+    let is_last = move || step_index.get() + 1 >= step_count;
+    let style = move || {
+        let placement = current().map(|s| s.placement).unwrap_or_default();
+        popover_style(rect(), placement, viewport())
+    };
+
+    view! {
+        {content}
+        <Show when=move || open.get() && current().is_some()>
+            <div class="ui-tour">
+                // Swallows clicks on the page; the spotlight does the dimming
+                <div class="ui-tour__blocker"></div>
+                {move || match rect() {
+                    Some(r) => {
+                        let r = r.grow(SPOTLIGHT_PADDING);
+                        let style = format!(
+                            "top: {}px; left: {}px; width: {}px; height: {}px;",
+                            r.top, r.left, r.width, r.height
+                        );
+                        view! { <div class="ui-tour__spotlight" style=style></div> }.into_any()
+                    }
+                    None => view! { <div class="ui-tour__backdrop"></div> }.into_any(),
+                }}
+                <div
+                    class="ui-tour__popover"
+                    role="dialog"
+                    aria-live="polite"
+                    aria-label=move || current().map(|s| s.title).unwrap_or_default()
+                    style=style
+                >
+                    <div class="ui-tour__progress">
+                        {move || locale.t_or_with(
+                            "product_tour.progress",
+                            "{current} of {total}",
+                            &[
+                                ("current", (step_index.get() + 1).to_string()),
+                                ("total", step_count.to_string()),
+                            ],
+                        )}
+                    </div>
+                    <h3 class="ui-tour__title">{move || current().map(|s| s.title)}</h3>
+                    <p class="ui-tour__body">{move || current().map(|s| s.body)}</p>
+                    <div class="ui-tour__actions">
+                        <button type="button" class="ui-tour__skip" on:click=move |_| finish(false)>
+                            {move || locale.t_or("product_tour.skip", "Skip tour")}
+                        </button>
+                        <Show when=move || { step_index.get() > 0 }>
+                            <button type="button" class="ui-tour__back" on:click=move |_| back()>
+                                {move || locale.t_or("product_tour.back", "Back")}
+                            </button>
+                        </Show>
+                        <button type="button" class="ui-tour__next" on:click=move |_| next()>
+                            {move || if is_last() {
+                                locale.t_or("product_tour.done", "Done")
+                            } else {
+                                locale.t_or("product_tour.next", "Next")
+                            }}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+/// Registers its content as a [`ProductTour`] step target
+///
+/// Renders a wrapping `div`; pass `class` to adjust its layout (e.g.
+/// `display: inline-block` around inline content).
+#[component]
+pub fn TourAnchor(
+    /// Id steps refer to with [`TourStep::anchor`]
+    #[prop(into)]
+    id: String,
+    /// Extra classes for the wrapper
+    #[prop(into, optional)]
+    class: Option<String>,
+    /// Highlighted content
+    children: Children,
+) -> impl IntoView {
+    let node_ref = NodeRef::<leptos::html::Div>::new();
+
+    if let Some(TourAnchors(anchors)) = use_context::<TourAnchors>() {
+        let key = id.clone();
+        Effect::new(move |_| {
+            if let Some(el) = node_ref.get() {
+                let el: web_sys::Element = el.into();
+                anchors.update(|a| {
+                    a.insert(key.clone(), SendWrapper::new(el));
+                });
+            }
+        });
+        let key = id.clone();
+        on_cleanup(move || {
+            anchors.try_update(|a| a.remove(&key));
+        });
+    }
+
+    let class = match class {
+        Some(extra) => format!("ui-tour-anchor {extra}"),
+        None => "ui-tour-anchor".to_string(),
+    };
+
+    view! {
+        <div node_ref=node_ref class=class data-tour-anchor=id>
+            {children()}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: (f64, f64) = (1200.0, 800.0);
+
+    fn rect(top: f64, left: f64) -> AnchorRect {
+        AnchorRect {
+            top,
+            left,
+            width: 100.0,
+            height: 40.0,
+        }
+    }
+
+    #[test]
+    fn keeps_placement_with_room() {
+        let r = rect(300.0, 500.0);
+        for placement in [
+            TourPlacement::Top,
+            TourPlacement::Bottom,
+            TourPlacement::Left,
+            TourPlacement::Right,
+        ] {
+            assert_eq!(resolve_placement(r, placement, VIEWPORT), placement);
+        }
+    }
+
+    #[test]
+    fn flips_when_out_of_room() {
+        // Near the bottom: below flips above
+        let low = rect(700.0, 500.0);
+        assert_eq!(
+            resolve_placement(low, TourPlacement::Bottom, VIEWPORT),
+            TourPlacement::Top
+        );
+        // Near the top: above flips below
+        let high = rect(20.0, 500.0);
+        assert_eq!(
+            resolve_placement(high, TourPlacement::Top, VIEWPORT),
+            TourPlacement::Bottom
+        );
+        // Near the left edge: left flips right
+        let edge = rect(300.0, 10.0);
+        assert_eq!(
+            resolve_placement(edge, TourPlacement::Left, VIEWPORT),
+            TourPlacement::Right
+        );
+    }
+
+    #[test]
+    fn popover_centered_without_anchor() {
+        assert!(popover_style(None, TourPlacement::Bottom, VIEWPORT).contains("top: 50%"));
+    }
+
+    #[test]
+    fn popover_below_anchor_and_clamped() {
+        let style = popover_style(Some(rect(100.0, 500.0)), TourPlacement::Bottom, VIEWPORT);
+        assert_eq!(
+            style,
+            "top: 152px; left: 550px; transform: translate(-50%, 0);"
+        );
+
+        // Anchor at the left edge: centre pushed in so the popover fits
+        let style = popover_style(Some(rect(100.0, 0.0)), TourPlacement::Bottom, VIEWPORT);
+        assert!(style.contains("left: 168px"));
+    }
+
+    #[test]
+    fn spotlight_padding() {
+        let r = rect(100.0, 100.0).grow(SPOTLIGHT_PADDING);
+        assert_eq!(r.top, 100.0 - SPOTLIGHT_PADDING);
+        assert_eq!(r.width, 100.0 + SPOTLIGHT_PADDING * 2.0);
+    }
+
+    #[test]
+    fn step_builder() {
+        let step = TourStep::new("wallet", "Title", "Body").placement(TourPlacement::Left);
+        assert_eq!(step.anchor, "wallet");
+        assert_eq!(step.placement, TourPlacement::Left);
+    }
+}
@@ -0,0 +1,304 @@
+//! ProgressRing Leptos Component
+//!
+//! A circular progress indicator that doubles as a countdown timer: bind
+//! `value` for determinate progress (uploads), or `deadline` to drain the
+//! ring until a point in time (turn timers).
+//!
+//! ## Props
+//!
+//! - `value` - Progress value (0.0 to 1.0), determinate mode
+//! - `deadline` - Unix ms the countdown ends at (`None` = not running), in
+//!   server time per [`ui_core::clock`]
+//! - `duration_ms` - Full countdown length, the ring is full this long before `deadline`
+//! - `thresholds` - Colors used once the fraction drops below a threshold
+//! - `size` - Diameter in px (default 48)
+//! - `stroke_width` - Ring thickness in px (default 4)
+//! - `show_value` - Show percentage (or seconds left) in the center
+//! - `label` - Accessible name
+//! - `on_expire` - Called once when a countdown reaches its deadline
+//! - `class` - Additional CSS classes
+//! - `children` - Custom center content, replaces `show_value`
+//!
+//! `deadline` takes precedence over `value` when both are set.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! // Upload progress
+//! <ProgressRing value=upload_progress show_value=true />
+//!
+//! // Turn timer: green, amber under 50%, red under 20%
+//! <ProgressRing
+//!     deadline=turn_deadline
+//!     duration_ms=30_000
+//!     thresholds=vec![
+//!         RingThreshold::new(0.5, "#ff9800"),
+//!         RingThreshold::new(0.2, "#f44336"),
+//!     ]
+//!     show_value=true
+//!     on_expire=move |_| end_turn()
+//! />
+//! ```
+
+use leptos::prelude::*;
+use std::time::Duration;
+
+use crate::i18n::use_locale;
+use ui_core::clock::now_ms;
+
+/// How often a running countdown redraws
+const COUNTDOWN_TICK: Duration = Duration::from_millis(250);
+
+/// Ring color applied once the fraction drops below `below`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RingThreshold {
+    /// Fraction (0.0 to 1.0) under which this color applies
+    pub below: f32,
+    /// CSS color for the ring
+    pub color: String,
+}
+
+impl RingThreshold {
+    /// Use `color` when the fraction is under `below`
+    pub fn new(below: f32, color: impl Into<String>) -> Self {
+        Self {
+            below,
+            color: color.into(),
+        }
+    }
+}
+
+/// Color of the lowest threshold `fraction` is under, if any
+fn threshold_color(fraction: f32, thresholds: &[RingThreshold]) -> Option<&str> {
+    thresholds
+        .iter()
+        .filter(|t| fraction < t.below)
+        .min_by(|a, b| a.below.total_cmp(&b.below))
+        .map(|t| t.color.as_str())
+}
+
+/// Fraction of a countdown left at `now_ms`
+///
+/// Without a duration the ring stays full until the deadline.
+fn countdown_fraction(now_ms: u64, deadline_ms: u64, duration_ms: u64) -> f32 {
+    let remaining = deadline_ms.saturating_sub(now_ms);
+    if remaining == 0 {
+        0.0
+    } else if duration_ms == 0 {
+        1.0
+    } else {
+        (remaining as f64 / duration_ms as f64).min(1.0) as f32
+    }
+}
+
+/// Time left as whole seconds (rounded up), `m:ss` from a minute up
+fn format_remaining(remaining_ms: u64) -> String {
+    let secs = remaining_ms.div_ceil(1000);
+    if secs >= 60 {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    } else {
+        secs.to_string()
+    }
+}
+
+/// Stroke offset that leaves `fraction` of a ring visible
+fn dash_offset(circumference: f32, fraction: f32) -> f32 {
+    circumference * (1.0 - fraction.clamp(0.0, 1.0))
+}
+
+/// Circular progress / countdown ring
+#[component]
+pub fn ProgressRing(
+    /// Progress value (0.0 to 1.0)
+    #[prop(into, optional)]
+    value: Option<Signal<f32>>,
+    /// Unix ms the countdown ends at
+    #[prop(into, optional)]
+    deadline: Option<Signal<Option<u64>>>,
+    /// Full countdown length in ms
+    #[prop(optional)]
+    duration_ms: u64,
+    /// Colors used below a fraction
+    #[prop(optional)]
+    thresholds: Vec<RingThreshold>,
+    /// Diameter in px
+    #[prop(optional, default = 48)]
+    size: u32,
+    /// Ring thickness in px
+    #[prop(optional, default = 4.0)]
+    stroke_width: f32,
+    /// Show percentage or seconds left in the center
+    #[prop(optional)]
+    show_value: bool,
+    /// Accessible name
+    #[prop(into, optional)]
+    label: Option<String>,
+    /// Called once when a countdown reaches its deadline
+    #[prop(into, optional)]
+    on_expire: Option<Callback<()>>,
+    /// Additional CSS classes
+    #[prop(into, optional)]
+    class: String,
+    /// Custom center content
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let now = RwSignal::new(now_ms());
+
+    // Ticks only while a countdown is running; fires `on_expire` once per
+    // deadline
+    if let Some(deadline) = deadline {
+        let expired_for = StoredValue::new(None::<u64>);
+        if let Ok(handle) = set_interval_with_handle(
+            move || {
+                let Some(end) = deadline.get_untracked() else {
+                    return;
+                };
+                let current = now_ms();
+                now.set(current);
+                if current >= end && expired_for.get_value() != Some(end) {
+                    expired_for.set_value(Some(end));
+                    if let Some(cb) = on_expire {
+                        cb.run(());
+                    }
+                }
+            },
+            COUNTDOWN_TICK,
+        ) {
+            on_cleanup(move || handle.clear());
+        }
+    }
+
+    let remaining_ms = move || {
+        deadline
+            .and_then(|d| d.get())
+            .map(|end| end.saturating_sub(now.get()))
+    };
+    let fraction = move || match deadline {
+        Some(d) => d
+            .get()
+            .map_or(0.0, |end| countdown_fraction(now.get(), end, duration_ms)),
+        None => value.map_or(0.0, |v| v.get().clamp(0.0, 1.0)),
+    };
+
+    let radius = ((size as f32 - stroke_width) / 2.0).max(0.0);
+    let circumference = 2.0 * std::f32::consts::PI * radius;
+    let center = size as f32 / 2.0;
+    let thresholds = StoredValue::new(thresholds);
+
+    let stroke = move || {
+        let fraction = fraction();
+        thresholds.with_value(|t| threshold_color(fraction, t).map(str::to_string))
+    };
+    let text = move || match deadline {
+        Some(_) => remaining_ms().map(format_remaining).unwrap_or_default(),
+        None => format!("{}%", (fraction() * 100.0).round() as u32),
+    };
+
+    let countdown = deadline.is_some();
+    let aria_label = label.unwrap_or_else(|| {
+        if countdown {
+            locale.t_or("progress_ring.time_remaining", "Time remaining")
+        } else {
+            locale.t_or("progress_ring.progress", "Progress")
+        }
+    });
+    let root_class = if class.is_empty() {
+        "ui-progress-ring".to_string()
+    } else {
+        format!("ui-progress-ring {class}")
+    };
+
+    view! {
+        <div
+            class=root_class
+            class:ui-progress-ring--expired=move || countdown && remaining_ms() == Some(0)
+            style=format!("width: {size}px; height: {size}px;")
+            role=if countdown { "timer" } else { "progressbar" }
+            aria-label=aria_label
+            aria-valuemin=(!countdown).then_some("0")
+            aria-valuemax=(!countdown).then_some("100")
+            aria-valuenow=move || (!countdown).then(|| ((fraction() * 100.0).round() as u32).to_string())
+        >
+            <svg
+                class="ui-progress-ring__svg"
+                viewBox=format!("0 0 {size} {size}")
+                aria-hidden="true"
+            >
+                <circle
+                    class="ui-progress-ring__track"
+                    cx=center
+                    cy=center
+                    r=radius
+                    stroke-width=stroke_width
+                />
+                <circle
+                    class="ui-progress-ring__fill"
+                    cx=center
+                    cy=center
+                    r=radius
+                    stroke-width=stroke_width
+                    stroke-dasharray=circumference
+                    stroke-dashoffset=move || dash_offset(circumference, fraction())
+                    style:stroke=stroke
+                />
+            </svg>
+            {match children {
+                Some(children) => Some(view! {
+                    <span class="ui-progress-ring__value">{children()}</span>
+                }.into_any()),
+                None => show_value.then(|| view! {
+                    <span class="ui-progress-ring__value" aria-hidden="true">
+                        {text}
+                    </span>
+                }.into_any()),
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn threshold_picks_lowest_matching() {
+        let thresholds = vec![
+            RingThreshold::new(0.5, "amber"),
+            RingThreshold::new(0.2, "red"),
+        ];
+        assert_eq!(threshold_color(0.8, &thresholds), None);
+        assert_eq!(threshold_color(0.4, &thresholds), Some("amber"));
+        assert_eq!(threshold_color(0.1, &thresholds), Some("red"));
+        assert_eq!(threshold_color(0.1, &[]), None);
+    }
+
+    #[test]
+    fn countdown_fraction_drains() {
+        assert_eq!(countdown_fraction(0, 10_000, 10_000), 1.0);
+        assert_eq!(countdown_fraction(5_000, 10_000, 10_000), 0.5);
+        assert_eq!(countdown_fraction(12_000, 10_000, 10_000), 0.0);
+        // Deadline further away than the duration stays full
+        assert_eq!(countdown_fraction(0, 20_000, 10_000), 1.0);
+        // No duration: full until expired
+        assert_eq!(countdown_fraction(9_999, 10_000, 0), 1.0);
+        assert_eq!(countdown_fraction(10_000, 10_000, 0), 0.0);
+    }
+
+    #[test]
+    fn remaining_rounds_up() {
+        assert_eq!(format_remaining(0), "0");
+        assert_eq!(format_remaining(1), "1");
+        assert_eq!(format_remaining(9_001), "10");
+        assert_eq!(format_remaining(60_000), "1:00");
+        assert_eq!(format_remaining(125_500), "2:06");
+    }
+
+    #[test]
+    fn dash_offset_clamps() {
+        assert_eq!(dash_offset(100.0, 1.0), 0.0);
+        assert_eq!(dash_offset(100.0, 0.25), 75.0);
+        assert_eq!(dash_offset(100.0, -1.0), 100.0);
+    }
+}
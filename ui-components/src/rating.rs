@@ -3,32 +3,48 @@
 //! A visual rating display using repeated icons/emojis.
 //! Useful for star ratings, difficulty indicators, etc.
 //!
+//! Fractional values (e.g. an aggregate score of 4.3) are drawn by clipping
+//! the filled icon over the empty one, rounded to the chosen precision.
+//!
 //! ## Props
 //!
-//! - `value` - Current rating value
+//! - `value` - Current rating value (fractional values supported)
 //! - `max` - Maximum rating (number of icons)
+//! - `precision` - Display/selection step: Full (default), Half or Quarter
 //! - `icon` - Icon/emoji to display (filled)
 //! - `empty_icon` - Optional empty icon (unfilled)
+//! - `icons` - Icon set preset, overrides `icon`/`empty_icon`
 //! - `size` - Display size
 //! - `color` - Icon color
+//! - `on_change` - Makes the rating selectable; emits the chosen value
+//! - `readonly` - Disable selection and show the numeric value as a tooltip
 //!
 //! ## Usage
 //!
 //! ```ignore
 //! // Star rating (3 out of 5)
-//! <Rating value=3 max=5 icon="⭐" empty_icon="☆" />
+//! <Rating value=3.0 max=5 icon="⭐" empty_icon="☆" />
 //!
 //! // Difficulty skulls (just shows filled count)
-//! <Rating value=3 max=5 icon="💀" />
+//! <Rating value=3.0 max=5 icon="💀" />
 //!
 //! // Heart rating
-//! <Rating value=4 max=5 icon="❤️" empty_icon="🤍" />
+//! <Rating value=4.0 max=5 icons=RatingIcons::hearts() />
+//!
+//! // Aggregate score with quarter stars and a "4.3 / 5" tooltip
+//! <Rating value=4.3 precision=RatingPrecision::Quarter readonly=true />
 //!
-//! // Custom color
-//! <Rating value=3 max=5 icon="●" empty_icon="○" color="#FFD700" />
+//! // Selectable in half steps, with hover preview
+//! <Rating
+//!     value=score
+//!     precision=RatingPrecision::Half
+//!     on_change=Callback::new(move |v| set_score.set(v))
+//! />
 //! ```
 
+use crate::i18n::use_locale;
 use leptos::prelude::*;
+use wasm_bindgen::JsCast;
 
 /// Rating size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -49,34 +65,149 @@ impl RatingSize {
     }
 }
 
+/// Smallest step a rating is displayed or selected in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RatingPrecision {
+    #[default]
+    Full,
+    Half,
+    Quarter,
+}
+
+impl RatingPrecision {
+    /// Size of one step in icons
+    pub fn step(&self) -> f64 {
+        match self {
+            RatingPrecision::Full => 1.0,
+            RatingPrecision::Half => 0.5,
+            RatingPrecision::Quarter => 0.25,
+        }
+    }
+
+    /// Round a value to the nearest step
+    pub fn round(&self, value: f64) -> f64 {
+        let step = self.step();
+        (value / step).round() * step
+    }
+
+    /// Round a value up to the next step (at least one step)
+    fn ceil(&self, value: f64) -> f64 {
+        let step = self.step();
+        ((value / step).ceil() * step).max(step)
+    }
+}
+
+/// Filled/empty icon pair
+#[derive(Debug, Clone, PartialEq)]
+pub struct RatingIcons {
+    /// Filled icon/emoji
+    pub filled: String,
+    /// Empty icon/emoji (if not set, only filled icons are shown)
+    pub empty: Option<String>,
+}
+
+impl RatingIcons {
+    /// Create a custom icon set
+    pub fn new(filled: impl Into<String>, empty: impl Into<String>) -> Self {
+        Self {
+            filled: filled.into(),
+            empty: Some(empty.into()),
+        }
+    }
+
+    /// Filled icons only (difficulty style)
+    pub fn filled_only(filled: impl Into<String>) -> Self {
+        Self {
+            filled: filled.into(),
+            empty: None,
+        }
+    }
+
+    /// ★ / ☆
+    pub fn stars() -> Self {
+        Self::new("★", "☆")
+    }
+
+    /// ❤️ / 🤍
+    pub fn hearts() -> Self {
+        Self::new("❤️", "🤍")
+    }
+
+    /// ● / ○
+    pub fn circles() -> Self {
+        Self::new("●", "○")
+    }
+}
+
+/// How much of the icon at `index` is filled for `value` (0.0 to 1.0)
+pub fn icon_fill(value: f64, index: u32) -> f64 {
+    (value - index as f64).clamp(0.0, 1.0)
+}
+
+/// Format a rating for display, dropping trailing zeros ("4.3", "4")
+pub fn format_rating(value: f64) -> String {
+    let text = format!("{value:.2}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
 /// Rating component
 #[component]
 pub fn Rating(
     /// Current rating value
     #[prop(into)]
-    value: Signal<u32>,
+    value: Signal<f64>,
     /// Maximum rating (total icons)
     #[prop(optional, default = 5)]
     max: u32,
+    /// Display and selection step
+    #[prop(optional)]
+    precision: RatingPrecision,
     /// Filled icon/emoji
     #[prop(into, optional, default = "★".into())]
     icon: String,
     /// Empty icon/emoji (if not provided, only filled icons shown)
     #[prop(into, optional)]
     empty_icon: Option<String>,
+    /// Icon set preset (overrides `icon` and `empty_icon`)
+    #[prop(optional)]
+    icons: Option<RatingIcons>,
     /// Display size
     #[prop(optional, default = RatingSize::Md)]
     size: RatingSize,
     /// Icon color
     #[prop(into, optional)]
     color: Option<String>,
+    /// Callback with the selected value (makes the rating selectable)
+    #[prop(into, optional)]
+    on_change: Option<Callback<f64>>,
+    /// Disable selection and show the value as a tooltip
+    #[prop(optional)]
+    readonly: bool,
     /// Additional class
     #[prop(into, optional)]
     class: Option<String>,
 ) -> impl IntoView {
+    let locale = use_locale();
+    let icons = icons.unwrap_or(RatingIcons {
+        filled: icon,
+        empty: empty_icon,
+    });
+    let interactive = on_change.is_some() && !readonly;
+    let max_value = max as f64;
+
+    // Pointer preview while hovering an interactive rating
+    let hover = RwSignal::new(None::<f64>);
+    let display = move || {
+        let raw = hover.get().unwrap_or_else(|| value.get());
+        precision.round(raw.clamp(0.0, max_value))
+    };
+
     let size_class = format!("ui-rating--{}", size.class_suffix());
     let rating_class = {
         let mut classes = vec!["ui-rating", &size_class];
+        if interactive {
+            classes.push("ui-rating--interactive");
+        }
         if let Some(ref c) = class {
             classes.push(c);
         }
@@ -85,34 +216,172 @@ pub fn Rating(
 
     let color_style = color.map(|c| format!("color: {c};"));
 
+    let label = move || {
+        locale.t_or_with(
+            "rating.value",
+            "{value} / {max}",
+            &[
+                ("value", format_rating(value.get())),
+                ("max", max.to_string()),
+            ],
+        )
+    };
+    let tooltip = move || readonly.then(label);
+
+    let select = move |selected: f64| {
+        if let Some(cb) = on_change {
+            cb.run(selected.clamp(0.0, max_value));
+        }
+    };
+
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        if !interactive {
+            return;
+        }
+        let current = precision.round(value.get_untracked());
+        let next = match ev.key().as_str() {
+            "ArrowRight" | "ArrowUp" => current + precision.step(),
+            "ArrowLeft" | "ArrowDown" => current - precision.step(),
+            "Home" => 0.0,
+            "End" => max_value,
+            _ => return,
+        };
+        ev.prevent_default();
+        select(next);
+    };
+
     view! {
-        <div class=rating_class style=color_style>
+        <div
+            class=rating_class
+            style=color_style
+            title=tooltip
+            role=if interactive { "slider" } else { "img" }
+            tabindex=interactive.then_some("0")
+            aria-label=label
+            aria-valuemin=interactive.then_some("0")
+            aria-valuemax=interactive.then(|| max.to_string())
+            aria-valuenow=move || interactive.then(|| format_rating(value.get()))
+            on:mouseleave=move |_| hover.set(None)
+            on:keydown=handle_keydown
+        >
             {move || {
-                let current = value.get().min(max);
-                let mut icons = Vec::new();
-
-                // Filled icons
-                for _ in 0..current {
-                    icons.push(view! {
-                        <span class="ui-rating__icon ui-rating__icon--filled">
-                            {icon.clone()}
-                        </span>
-                    });
-                }
-
-                // Empty icons (if empty_icon provided)
-                if let Some(ref empty) = empty_icon {
-                    for _ in current..max {
-                        icons.push(view! {
-                            <span class="ui-rating__icon ui-rating__icon--empty">
-                                {empty.clone()}
-                            </span>
-                        });
-                    }
-                }
-
-                icons
+                let current = display();
+                let empty = icons.empty.clone();
+
+                (0..max)
+                    .filter_map(|index| {
+                        let fill = icon_fill(current, index);
+                        // Without an empty icon, unfilled positions are omitted
+                        if fill <= 0.0 && empty.is_none() {
+                            return None;
+                        }
+
+                        let pointer_value = move |ev: &web_sys::MouseEvent| {
+                            let el = ev
+                                .current_target()
+                                .and_then(|t| t.dyn_into::<web_sys::Element>().ok())?;
+                            let rect = el.get_bounding_client_rect();
+                            if rect.width() <= 0.0 {
+                                return None;
+                            }
+                            let fraction = (ev.client_x() as f64 - rect.left()) / rect.width();
+                            Some(index as f64 + precision.ceil(fraction.clamp(0.0, 1.0)))
+                        };
+
+                        let icon_view = if fill >= 1.0 {
+                            view! {
+                                <span class="ui-rating__icon ui-rating__icon--filled">
+                                    {icons.filled.clone()}
+                                </span>
+                            }
+                            .into_any()
+                        } else if fill <= 0.0 {
+                            view! {
+                                <span class="ui-rating__icon ui-rating__icon--empty">
+                                    {empty.clone()}
+                                </span>
+                            }
+                            .into_any()
+                        } else {
+                            // Filled icon clipped over the empty (or hidden) base
+                            let base_class = if empty.is_some() {
+                                "ui-rating__base ui-rating__icon--empty"
+                            } else {
+                                "ui-rating__base ui-rating__base--hidden"
+                            };
+                            let base = empty.clone().unwrap_or_else(|| icons.filled.clone());
+                            let percent = fill * 100.0;
+                            let fill_style = format!("width: {percent}%;");
+                            view! {
+                                <span class="ui-rating__icon ui-rating__icon--partial">
+                                    <span class=base_class>{base}</span>
+                                    <span
+                                        class="ui-rating__fill"
+                                        style=fill_style
+                                    >
+                                        {icons.filled.clone()}
+                                    </span>
+                                </span>
+                            }
+                            .into_any()
+                        };
+
+                        Some(if interactive {
+                            view! {
+                                <span
+                                    class="ui-rating__hit"
+                                    on:mousemove=move |ev| hover.set(pointer_value(&ev))
+                                    on:click=move |ev| {
+                                        if let Some(selected) = pointer_value(&ev) {
+                                            select(selected);
+                                        }
+                                    }
+                                >
+                                    {icon_view}
+                                </span>
+                            }
+                            .into_any()
+                        } else {
+                            icon_view
+                        })
+                    })
+                    .collect_view()
             }}
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precision_round() {
+        assert_eq!(RatingPrecision::Full.round(4.3), 4.0);
+        assert_eq!(RatingPrecision::Half.round(4.3), 4.5);
+        assert_eq!(RatingPrecision::Quarter.round(4.3), 4.25);
+        assert_eq!(RatingPrecision::Quarter.round(4.4), 4.5);
+    }
+
+    #[test]
+    fn test_precision_ceil_selects_at_least_one_step() {
+        assert_eq!(RatingPrecision::Half.ceil(0.0), 0.5);
+        assert_eq!(RatingPrecision::Half.ceil(0.3), 0.5);
+        assert_eq!(RatingPrecision::Half.ceil(0.6), 1.0);
+        assert_eq!(RatingPrecision::Full.ceil(0.1), 1.0);
+    }
+
+    #[test]
+    fn test_icon_fill() {
+        assert_eq!(icon_fill(4.25, 3), 1.0);
+        assert_eq!(icon_fill(4.25, 4), 0.25);
+        assert_eq!(icon_fill(4.25, 5), 0.0);
+    }
+
+    #[test]
+    fn test_format_rating() {
+        assert_eq!(format_rating(4.3), "4.3");
+        assert_eq!(format_rating(4.0), "4");
+        assert_eq!(format_rating(3.75), "3.75");
+    }
+}
@@ -0,0 +1,169 @@
+//! RequireRole Leptos Component
+//!
+//! Renders its children only for users holding a role, replacing ad-hoc
+//! `if auth.has_role(..)` checks with one consistent fallback.
+//!
+//! The auth state comes from [`provide_auth`], typically called once at the
+//! root with the `AuthState` the loader returned. Outside a provider the
+//! user counts as signed out.
+//!
+//! ## Features
+//!
+//! - Children for users with the role (platform admins implicitly hold "admin")
+//! - Custom `fallback` for everyone else
+//! - Otherwise an inline prompt: sign in, session expired, or insufficient role
+//! - Nothing while authentication is still in progress
+//!
+//! ## Props
+//!
+//! - `role` - Role the user must hold
+//! - `fallback` - Rendered instead of children when access is denied
+//! - `prompt` - Show the inline prompt when there's no fallback (default true)
+//! - `children` - Gated content
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! provide_auth(auth_state);
+//!
+//! <RequireRole role="admin">
+//!     <AdminPanel />
+//! </RequireRole>
+//!
+//! <RequireRole role="moderator" fallback=|| view! { <p>"Read only"</p> }>
+//!     <EditButton />
+//! </RequireRole>
+//! ```
+
+use crate::alert::{Alert, AlertVariant};
+use crate::i18n::use_locale;
+use leptos::prelude::*;
+use ui_core::AuthState;
+
+/// Context wrapper so other `Signal<AuthState>` contexts don't collide
+#[derive(Clone, Copy)]
+struct AuthStateContext(Signal<AuthState>);
+
+/// Provide the auth state to descendant components
+pub fn provide_auth(auth: impl Into<Signal<AuthState>>) {
+    provide_context(AuthStateContext(auth.into()));
+}
+
+/// The provided auth state, or anonymous outside a provider
+pub fn use_auth() -> Signal<AuthState> {
+    use_context::<AuthStateContext>()
+        .map(|ctx| ctx.0)
+        .unwrap_or_else(|| Signal::stored(AuthState::Anonymous))
+}
+
+/// Outcome of a role check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoleAccess {
+    /// The user holds the role
+    Granted,
+    /// Authentication hasn't finished yet
+    Pending,
+    /// No token, or it couldn't be read
+    SignedOut,
+    /// The token expired
+    Expired,
+    /// Signed in without the role
+    Denied,
+}
+
+impl RoleAccess {
+    /// Check `auth` for `role`
+    pub fn check(auth: &AuthState, role: &str) -> Self {
+        match auth {
+            AuthState::Authenticated(ctx) if ctx.has_role(role) => RoleAccess::Granted,
+            AuthState::Authenticated(_) => RoleAccess::Denied,
+            AuthState::Authenticating => RoleAccess::Pending,
+            AuthState::TokenExpired => RoleAccess::Expired,
+            AuthState::Anonymous | AuthState::AuthError(_) => RoleAccess::SignedOut,
+        }
+    }
+}
+
+/// Role-gated content
+#[component]
+pub fn RequireRole(
+    /// Role the user must hold
+    #[prop(into)]
+    role: String,
+    /// Rendered instead of children when access is denied
+    #[prop(into, optional)]
+    fallback: Option<ViewFn>,
+    /// Show the inline prompt when there's no fallback
+    #[prop(optional, default = true)]
+    prompt: bool,
+    /// Gated content
+    children: ChildrenFn,
+) -> impl IntoView {
+    let auth = use_auth();
+    let locale = use_locale();
+    let role = StoredValue::new(role);
+    let access = Memo::new(move |_| auth.with(|a| role.with_value(|r| RoleAccess::check(a, r))));
+
+    move || match access.get() {
+        RoleAccess::Granted => children().into_any(),
+        RoleAccess::Pending => ().into_any(),
+        denied => match (&fallback, prompt) {
+            (Some(fallback), _) => fallback.run(),
+            (None, true) => {
+                let (variant, message) = match denied {
+                    RoleAccess::Expired => (
+                        AlertVariant::Warning,
+                        locale.t_or(
+                            "require_role.expired",
+                            "Your session has expired. Open a fresh link to continue.",
+                        ),
+                    ),
+                    RoleAccess::Denied => (
+                        AlertVariant::Warning,
+                        locale.t_or_with(
+                            "require_role.denied",
+                            "You need the {role} role to see this.",
+                            &[("role", role.get_value())],
+                        ),
+                    ),
+                    _ => (
+                        AlertVariant::Info,
+                        locale.t_or("require_role.signed_out", "Sign in to see this."),
+                    ),
+                };
+                view! {
+                    <Alert variant=variant class="ui-require-role">
+                        {message}
+                    </Alert>
+                }
+                .into_any()
+            }
+            (None, false) => ().into_any(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unauthenticated_access() {
+        assert_eq!(
+            RoleAccess::check(&AuthState::Anonymous, "admin"),
+            RoleAccess::SignedOut
+        );
+        assert_eq!(
+            RoleAccess::check(&AuthState::AuthError("bad".into()), "admin"),
+            RoleAccess::SignedOut
+        );
+        assert_eq!(
+            RoleAccess::check(&AuthState::TokenExpired, "admin"),
+            RoleAccess::Expired
+        );
+        assert_eq!(
+            RoleAccess::check(&AuthState::Authenticating, "admin"),
+            RoleAccess::Pending
+        );
+    }
+}
@@ -9,6 +9,8 @@
 //! - `on_change` - Callback when selection changes
 //! - `placeholder` - Placeholder text when no value selected
 //! - `disabled` - Whether select is disabled
+//! - `prefix` - Leading adornment inside the control (icon)
+//! - `suffix` - Trailing adornment after the dropdown arrow (spinner)
 //!
 //! ## Usage
 //!
@@ -25,6 +27,8 @@
 //! />
 //! ```
 
+use crate::density::density_attr;
+use crate::input_group::input_group;
 use leptos::prelude::*;
 
 /// Option for Select component
@@ -76,6 +80,12 @@ pub fn Select(
     /// Whether select is disabled
     #[prop(into, optional)]
     disabled: Option<Signal<bool>>,
+    /// Leading adornment
+    #[prop(optional)]
+    prefix: Option<Children>,
+    /// Trailing adornment
+    #[prop(optional)]
+    suffix: Option<Children>,
 ) -> impl IntoView {
     let handle_change = move |ev: web_sys::Event| {
         let target = event_target::<web_sys::HtmlSelectElement>(&ev);
@@ -83,10 +93,12 @@ pub fn Select(
     };
 
     let is_disabled = move || disabled.map(|d| d.get()).unwrap_or(false);
+    let density = density_attr();
 
-    view! {
+    let select = view! {
         <select
             class="ui-select"
+            data-density=density
             on:change=handle_change
             disabled=is_disabled
             prop:value=move || value.get()
@@ -110,5 +122,7 @@ pub fn Select(
                 }
             }).collect_view()}
         </select>
-    }
+    };
+
+    input_group(prefix, suffix, false, select.into_any())
 }
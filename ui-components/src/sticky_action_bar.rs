@@ -0,0 +1,297 @@
+//! StickyActionBar Leptos Component
+//!
+//! A save/discard bar pinned to the bottom of the viewport that slides in
+//! while a form or editor has unsaved changes, and guards against leaving
+//! the page with them.
+//!
+//! ## Features
+//!
+//! - Shown only while `dirty`; stays mounted so it can animate in and out
+//! - Save button with a pending state, optional discard button
+//! - Unsaved-changes guard: the browser's leave prompt on reload, close and
+//!   external links, and a confirmation (themed when a `ConfirmProvider` is
+//!   in scope) before following in-app links
+//!
+//! The guard is also available on its own as [`use_unsaved_changes_guard`]
+//! for editors that keep their own save button. Back/forward navigation
+//! within a single-page app can't be intercepted.
+//!
+//! ## Props
+//!
+//! - `dirty` - Whether there are unsaved changes
+//! - `on_save` - Save the changes
+//! - `on_discard` - Throw the changes away; no discard button without it
+//! - `saving` - Save in progress: shows a spinner and disables the buttons
+//! - `disabled` - Disable save, e.g. while the form is invalid
+//! - `message` - Text beside the buttons (default "You have unsaved changes")
+//! - `save_label` / `discard_label` - Button text
+//! - `guard` - Guard against leaving with unsaved changes (default true)
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let saved = RwSignal::new(load_drops());
+//! let draft = RwSignal::new(saved.get_untracked());
+//! let dirty = Signal::derive(move || draft.get() != saved.get());
+//!
+//! view! {
+//!     <DropEditor drops=draft on_change=move |d| draft.set(d) />
+//!     <StickyActionBar
+//!         dirty=dirty
+//!         saving=saving
+//!         on_save=move |_| save(draft.get())
+//!         on_discard=move |_| draft.set(saved.get())
+//!     />
+//! }
+//! ```
+
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::confirm_dialog::{try_use_confirm, ConfirmOptions};
+use crate::i18n::use_locale;
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+
+/// Whether following `href` from the page at `current` stays in the app
+/// and leaves this page
+///
+/// Other origins unload the page, which `beforeunload` already covers;
+/// hash-only changes don't leave it.
+fn leaves_in_app(href: &str, current: &str) -> bool {
+    fn origin(url: &str) -> &str {
+        let path_start = url
+            .find("://")
+            .and_then(|scheme| url[scheme + 3..].find('/').map(|i| scheme + 3 + i))
+            .unwrap_or(url.len());
+        &url[..path_start]
+    }
+    fn without_hash(url: &str) -> &str {
+        url.split('#').next().unwrap_or(url)
+    }
+    origin(href) == origin(current) && without_hash(href) != without_hash(current)
+}
+
+/// Warn before leaving the page while `dirty`
+///
+/// Reloading, closing the tab and following external links show the
+/// browser's own prompt. Clicks on in-app links ask first, through the
+/// [`ConfirmProvider`](crate::ConfirmProvider) dialog when there is one and
+/// `window.confirm` otherwise, then follow the link if the user agrees.
+pub fn use_unsaved_changes_guard(dirty: impl Into<Signal<bool>>) {
+    let dirty = dirty.into();
+    let locale = use_locale();
+    let confirm = try_use_confirm();
+    // Set once the user agreed to leave, so the link's own navigation
+    // isn't asked about again
+    let leaving = StoredValue::new(false);
+    let guarding = move || dirty.get_untracked() && !leaving.get_value();
+
+    Effect::new(move |_| {
+        if !dirty.get() {
+            leaving.set_value(false);
+        }
+    });
+
+    let unload = window_event_listener(leptos::ev::beforeunload, move |ev| {
+        if guarding() {
+            ev.prevent_default();
+            // Older browsers only prompt when a return value is set
+            ev.set_return_value("");
+        }
+    });
+
+    let on_click = Closure::wrap(Box::new(move |ev: web_sys::MouseEvent| {
+        if !guarding()
+            || ev.default_prevented()
+            || ev.button() != 0
+            || ev.ctrl_key()
+            || ev.meta_key()
+            || ev.shift_key()
+            || ev.alt_key()
+        {
+            return;
+        }
+        let Some(anchor) = ev
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+            .and_then(|el| el.closest("a[href]").ok().flatten())
+            .and_then(|el| el.dyn_into::<web_sys::HtmlAnchorElement>().ok())
+        else {
+            return;
+        };
+        let current = window().location().href().unwrap_or_default();
+        if anchor.has_attribute("download")
+            || !matches!(anchor.target().as_str(), "" | "_self")
+            || !leaves_in_app(&anchor.href(), &current)
+        {
+            return;
+        }
+
+        // Hold the navigation (and any router) until the user answers
+        ev.prevent_default();
+        ev.stop_immediate_propagation();
+
+        let title = locale.t_or("sticky_action_bar.leave_title", "Leave without saving?");
+        let body = locale.t_or(
+            "sticky_action_bar.leave_body",
+            "Your unsaved changes will be lost.",
+        );
+        let anchor = SendWrapper::new(anchor);
+        spawn_local(async move {
+            let leave = match confirm {
+                Some(confirm) => {
+                    confirm
+                        .confirm(
+                            ConfirmOptions::new(title)
+                                .body(body)
+                                .confirm_label(locale.t_or("sticky_action_bar.leave", "Leave"))
+                                .destructive(),
+                        )
+                        .await
+                }
+                None => window()
+                    .confirm_with_message(&format!("{title}\n\n{body}"))
+                    .unwrap_or(false),
+            };
+            if leave {
+                leaving.set_value(true);
+                anchor.click();
+            }
+        });
+    }) as Box<dyn FnMut(web_sys::MouseEvent)>);
+
+    // Capture phase, so the check runs before client-side routers
+    let _ = document().add_event_listener_with_callback_and_bool(
+        "click",
+        on_click.as_ref().unchecked_ref(),
+        true,
+    );
+    let on_click = SendWrapper::new(on_click);
+
+    on_cleanup(move || {
+        unload.remove();
+        let _ = document().remove_event_listener_with_callback_and_bool(
+            "click",
+            on_click.as_ref().unchecked_ref(),
+            true,
+        );
+    });
+}
+
+/// Sticky save/discard bar
+#[component]
+pub fn StickyActionBar(
+    /// Whether there are unsaved changes
+    #[prop(into)]
+    dirty: Signal<bool>,
+    /// Save the changes
+    #[prop(into)]
+    on_save: Callback<()>,
+    /// Throw the changes away; no discard button without it
+    #[prop(into, optional)]
+    on_discard: Option<Callback<()>>,
+    /// Save in progress
+    #[prop(into, optional)]
+    saving: Signal<bool>,
+    /// Disable save, e.g. while the form is invalid
+    #[prop(into, optional)]
+    disabled: Signal<bool>,
+    /// Text beside the buttons
+    #[prop(into, optional)]
+    message: Option<String>,
+    /// Save button text
+    #[prop(into, optional)]
+    save_label: Option<String>,
+    /// Discard button text
+    #[prop(into, optional)]
+    discard_label: Option<String>,
+    /// Guard against leaving with unsaved changes
+    #[prop(optional, default = true)]
+    guard: bool,
+) -> impl IntoView {
+    let locale = use_locale();
+    if guard {
+        use_unsaved_changes_guard(dirty);
+    }
+
+    let message = move || {
+        message
+            .clone()
+            .unwrap_or_else(|| locale.t_or("sticky_action_bar.unsaved", "You have unsaved changes"))
+    };
+    let save_label = move || {
+        save_label
+            .clone()
+            .unwrap_or_else(|| locale.t_or("sticky_action_bar.save", "Save"))
+    };
+    let hidden = move || (!dirty.get()).then_some("true");
+
+    let discard = on_discard.map(|on_discard| {
+        let label =
+            discard_label.unwrap_or_else(|| locale.t_or("sticky_action_bar.discard", "Discard"));
+        view! {
+            <Button
+                variant=ButtonVariant::Ghost
+                size=ButtonSize::Sm
+                disabled=saving
+                on_click=on_discard
+            >
+                {label}
+            </Button>
+        }
+    });
+
+    view! {
+        <div
+            class="ui-sticky-action-bar"
+            class:ui-sticky-action-bar--visible=move || dirty.get()
+            role="region"
+            aria-label=move || locale.t_or("sticky_action_bar.label", "Unsaved changes")
+            aria-hidden=hidden
+            inert=hidden
+        >
+            <span class="ui-sticky-action-bar__message" aria-live="polite">
+                {message}
+            </span>
+            <div class="ui-sticky-action-bar__actions">
+                {discard}
+                <Button
+                    size=ButtonSize::Sm
+                    loading=saving
+                    disabled=Signal::derive(move || saving.get() || disabled.get())
+                    on_click=on_save
+                >
+                    {save_label}
+                </Button>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leaves_in_app() {
+        let current = "https://app.example.com/drops/edit?id=4";
+        assert!(leaves_in_app("https://app.example.com/drops", current));
+        assert!(leaves_in_app(
+            "https://app.example.com/drops/edit?id=5",
+            current
+        ));
+        // Hash changes stay on the page
+        assert!(!leaves_in_app(
+            "https://app.example.com/drops/edit?id=4#prizes",
+            current
+        ));
+        // Other origins unload the page and get the browser's prompt
+        assert!(!leaves_in_app("https://example.com/drops", current));
+        assert!(!leaves_in_app(
+            "https://app.example.com.evil.io/drops",
+            current
+        ));
+    }
+}
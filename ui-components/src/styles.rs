@@ -18,15 +18,17 @@
 //! }
 //! ```
 //!
-//! Or import in your app's SCSS (if using trunk with SCSS):
+//! ## Adding styles
 //!
-//! ```scss
-//! @use "path/to/ui-components/src/styles/mod" as ui;
-//! ```
+//! Every `.scss` file in `src/styles` is compiled into the bundle. `mod.scss`
+//! goes first and fixes the cascade order of the files it lists; any others
+//! follow in file name order. Add new stylesheets to `mod.scss` too, since
+//! apps that `@use "ui-components/styles/mod"` from their own SCSS only get
+//! what it lists. Put shared variables and mixins in `_`-prefixed partials
+//! and `@use` them where needed.
 
-use scss_macros::scss;
+use scss_macros::scss_dir;
 
 /// Combined CSS for all ui-components.
 /// Include this once at the app root level.
-// Touch to force SCSS recompile
-pub const STYLES: &str = scss!("src/styles/mod.scss");
+pub const STYLES: &str = scss_dir!("src/styles");
@@ -0,0 +1,428 @@
+//! SystemBanner Leptos Component
+//!
+//! A full-width banner for system announcements: planned maintenance,
+//! degraded service, or general news. Content supports a small inline
+//! markdown subset (`**bold**`, `*italic*`, `` `code` ``, `[link](https://...)`),
+//! an optional countdown, and dismissal that is remembered per announcement.
+//!
+//! Announcements are plain data, so they can come from a flow `Command`
+//! (`ShowMaintenanceBanner`) or be deserialized from a config endpoint.
+//!
+//! ## Props
+//!
+//! - `announcement` - Announcement to show, `None` hides the banner
+//! - `on_dismiss` - Called with the announcement id when dismissed
+//!
+//! Dismissed ids are stored in the `system-banner` settings namespace, so a
+//! dismissed announcement stays hidden across reloads. Banners past their
+//! `until` time hide themselves.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let announcement = RwSignal::new(None::<SystemAnnouncement>);
+//!
+//! FlowConnection::builder()
+//!     .on_command(move |command| match command {
+//!         ClientCommand::ShowMaintenanceBanner { message, until } => announcement.set(Some(
+//!             SystemAnnouncement::maintenance("deploy-42", message).until(until),
+//!         )),
+//!         ClientCommand::HideMaintenanceBanner => announcement.set(None),
+//!         other => run_default_command(&other),
+//!     });
+//!
+//! view! { <SystemBanner announcement=announcement /> }
+//! ```
+
+use crate::i18n::use_locale;
+use crate::use_setting::use_setting;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use ui_core::clock::{format_duration, now_ms};
+use ui_core::settings::Settings;
+
+/// Settings namespace dismissed announcement ids are stored under
+const SETTINGS_NAMESPACE: &str = "system-banner";
+
+/// Settings key for the dismissed id list
+const DISMISSED_KEY: &str = "dismissed";
+
+/// Most recent dismissals remembered
+const MAX_REMEMBERED: usize = 20;
+
+/// How often the countdown refreshes
+const COUNTDOWN_TICK: Duration = Duration::from_secs(1);
+
+/// Banner visual variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BannerVariant {
+    #[default]
+    Info,
+    Warning,
+    Maintenance,
+}
+
+impl BannerVariant {
+    fn class_suffix(&self) -> &'static str {
+        match self {
+            BannerVariant::Info => "info",
+            BannerVariant::Warning => "warning",
+            BannerVariant::Maintenance => "maintenance",
+        }
+    }
+
+    fn icon(&self) -> &'static str {
+        match self {
+            BannerVariant::Info => "ℹ",
+            BannerVariant::Warning => "⚠",
+            BannerVariant::Maintenance => "🛠",
+        }
+    }
+}
+
+/// A system announcement shown by [`SystemBanner`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SystemAnnouncement {
+    /// Stable id; dismissal is remembered per id
+    pub id: String,
+    #[serde(default)]
+    pub variant: BannerVariant,
+    /// Message text (inline markdown subset)
+    pub message: String,
+    /// Counts down to this time (unix ms) while it is in the future
+    #[serde(default)]
+    pub starts_at: Option<u64>,
+    /// Hides the banner after this time (unix ms); counts down once started
+    #[serde(default)]
+    pub until: Option<u64>,
+    /// Whether the user can dismiss the banner
+    #[serde(default = "default_dismissible")]
+    pub dismissible: bool,
+}
+
+fn default_dismissible() -> bool {
+    true
+}
+
+impl SystemAnnouncement {
+    /// Dismissible announcement with no countdown
+    pub fn new(id: impl Into<String>, variant: BannerVariant, message: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            variant,
+            message: message.into(),
+            starts_at: None,
+            until: None,
+            dismissible: true,
+        }
+    }
+
+    /// Informational announcement
+    pub fn info(id: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(id, BannerVariant::Info, message)
+    }
+
+    /// Maintenance announcement
+    pub fn maintenance(id: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(id, BannerVariant::Maintenance, message)
+    }
+
+    /// Count down to a start time
+    pub fn starts_at(mut self, at_ms: impl Into<Option<u64>>) -> Self {
+        self.starts_at = at_ms.into();
+        self
+    }
+
+    /// Hide after an end time
+    pub fn until(mut self, at_ms: impl Into<Option<u64>>) -> Self {
+        self.until = at_ms.into();
+        self
+    }
+
+    /// Whether the user can dismiss the banner
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    /// Whether the announcement has ended at `now_ms`
+    pub fn is_expired(&self, now_ms: u64) -> bool {
+        self.until.is_some_and(|until| now_ms >= until)
+    }
+
+    /// What the countdown shows at `now_ms`, if anything
+    pub fn countdown(&self, now_ms: u64) -> Option<Countdown> {
+        if let Some(start) = self.starts_at.filter(|&start| start > now_ms) {
+            return Some(Countdown::StartsIn(start - now_ms));
+        }
+        self.until
+            .filter(|&until| until > now_ms)
+            .map(|until| Countdown::EndsIn(until - now_ms))
+    }
+}
+
+/// Remaining time shown next to an announcement, in ms
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Countdown {
+    StartsIn(u64),
+    EndsIn(u64),
+}
+
+/// Format a duration as "2h 05m", "5m 30s" or "45s"
+///
+/// Same as [`ui_core::clock::format_duration`].
+pub fn format_countdown(ms: u64) -> String {
+    format_duration(ms)
+}
+
+/// A run of inline markdown
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Inline {
+    Text(String),
+    Bold(String),
+    Italic(String),
+    Code(String),
+    Link { text: String, url: String },
+}
+
+/// Parse the supported inline markdown subset
+///
+/// Unclosed markers and non-http(s) links are kept as plain text.
+fn parse_inline(input: &str) -> Vec<Inline> {
+    let mut out = Vec::new();
+    let mut text = String::new();
+    let mut rest = input;
+
+    while let Some(c) = rest.chars().next() {
+        let parsed = match c {
+            '*' if rest.starts_with("**") => {
+                delimited(&rest[2..], "**").map(|(s, r)| (Inline::Bold(s.into()), r))
+            }
+            '*' => delimited(&rest[1..], "*").map(|(s, r)| (Inline::Italic(s.into()), r)),
+            '`' => delimited(&rest[1..], "`").map(|(s, r)| (Inline::Code(s.into()), r)),
+            '[' => parse_link(rest),
+            _ => None,
+        };
+        match parsed {
+            Some((inline, remaining)) => {
+                if !text.is_empty() {
+                    out.push(Inline::Text(std::mem::take(&mut text)));
+                }
+                out.push(inline);
+                rest = remaining;
+            }
+            None => {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    if !text.is_empty() {
+        out.push(Inline::Text(text));
+    }
+    out
+}
+
+/// Non-empty text up to `end`, and what follows it
+fn delimited<'a>(input: &'a str, end: &str) -> Option<(&'a str, &'a str)> {
+    let close = input.find(end)?;
+    (close > 0).then(|| (&input[..close], &input[close + end.len()..]))
+}
+
+fn parse_link(input: &str) -> Option<(Inline, &str)> {
+    let (text, rest) = delimited(&input[1..], "]")?;
+    let (url, rest) = delimited(rest.strip_prefix('(')?, ")")?;
+    let safe = url.starts_with("https://") || url.starts_with("http://");
+    safe.then(|| {
+        (
+            Inline::Link {
+                text: text.into(),
+                url: url.into(),
+            },
+            rest,
+        )
+    })
+}
+
+fn render_inline(inline: Inline) -> AnyView {
+    match inline {
+        Inline::Text(t) => t.into_any(),
+        Inline::Bold(t) => view! { <strong>{t}</strong> }.into_any(),
+        Inline::Italic(t) => view! { <em>{t}</em> }.into_any(),
+        Inline::Code(t) => view! { <code>{t}</code> }.into_any(),
+        Inline::Link { text, url } => view! {
+            <a href=url target="_blank" rel="noopener noreferrer">{text}</a>
+        }
+        .into_any(),
+    }
+}
+
+/// Append `id` to the dismissed list, keeping only the most recent entries
+fn with_dismissed(mut dismissed: Vec<String>, id: &str) -> Vec<String> {
+    dismissed.retain(|d| d != id);
+    dismissed.push(id.to_string());
+    let excess = dismissed.len().saturating_sub(MAX_REMEMBERED);
+    dismissed.drain(..excess);
+    dismissed
+}
+
+/// System announcement banner
+#[component]
+pub fn SystemBanner(
+    /// Announcement to show
+    #[prop(into)]
+    announcement: Signal<Option<SystemAnnouncement>>,
+    /// Called with the announcement id when dismissed
+    #[prop(into, optional)]
+    on_dismiss: Option<Callback<String>>,
+) -> impl IntoView {
+    let locale = use_locale();
+    let dismissed = use_setting(
+        &Settings::new(SETTINGS_NAMESPACE),
+        DISMISSED_KEY,
+        Vec::<String>::new(),
+    );
+
+    // Drives the countdown and expiry; only ticks while a banner has times
+    let tick = RwSignal::new(());
+    if let Ok(handle) = set_interval_with_handle(
+        move || {
+            let timed = announcement.with_untracked(|a| {
+                a.as_ref()
+                    .is_some_and(|a| a.starts_at.is_some() || a.until.is_some())
+            });
+            if timed {
+                tick.set(());
+            }
+        },
+        COUNTDOWN_TICK,
+    ) {
+        on_cleanup(move || handle.clear());
+    }
+
+    let visible = move || {
+        tick.track();
+        announcement
+            .get()
+            .filter(|a| !a.is_expired(now_ms()) && !dismissed.get().contains(&a.id))
+    };
+
+    let dismiss = move |id: String| {
+        dismissed.set(with_dismissed(dismissed.signal().get_untracked(), &id));
+        if let Some(cb) = on_dismiss {
+            cb.run(id);
+        }
+    };
+
+    view! {
+        {move || visible().map(|a| {
+            let class = format!("ui-system-banner ui-system-banner--{}", a.variant.class_suffix());
+            let icon = a.variant.icon();
+            let message = parse_inline(&a.message).into_iter().map(render_inline).collect_view();
+            let id = a.id.clone();
+            let dismissible = a.dismissible;
+            let countdown = move || {
+                tick.track();
+                a.countdown(now_ms()).map(|c| {
+                    let (key, default, ms) = match c {
+                        Countdown::StartsIn(ms) => ("system_banner.starts_in", "Starts in {time}", ms),
+                        Countdown::EndsIn(ms) => ("system_banner.ends_in", "Ends in {time}", ms),
+                    };
+                    let text = locale.t_or_with(key, default, &[("time", format_countdown(ms))]);
+                    view! { <span class="ui-system-banner__countdown">{text}</span> }
+                })
+            };
+            view! {
+                <div class=class role="status">
+                    <span class="ui-system-banner__icon" aria-hidden="true">{icon}</span>
+                    <div class="ui-system-banner__message">{message}</div>
+                    {countdown}
+                    {dismissible.then(|| view! {
+                        <button
+                            class="ui-system-banner__close"
+                            on:click=move |_| dismiss(id.clone())
+                            aria-label=move || locale.t_or("system_banner.dismiss", "Dismiss")
+                        >
+                            "×"
+                        </button>
+                    })}
+                </div>
+            }
+        })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_inline_markdown() {
+        assert_eq!(
+            parse_inline("**Heads up:** `v2` is *live*, see [notes](https://defrag.au/notes)."),
+            vec![
+                Inline::Bold("Heads up:".into()),
+                Inline::Text(" ".into()),
+                Inline::Code("v2".into()),
+                Inline::Text(" is ".into()),
+                Inline::Italic("live".into()),
+                Inline::Text(", see ".into()),
+                Inline::Link {
+                    text: "notes".into(),
+                    url: "https://defrag.au/notes".into(),
+                },
+                Inline::Text(".".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_inline_keeps_unsafe_and_unclosed_as_text() {
+        assert_eq!(
+            parse_inline("[x](javascript:alert(1)) 2 * 3"),
+            vec![Inline::Text("[x](javascript:alert(1)) 2 * 3".into())]
+        );
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(45_000), "45s");
+        assert_eq!(format_countdown(44_001), "45s");
+        assert_eq!(format_countdown(330_000), "5m 30s");
+        assert_eq!(format_countdown(7_500_000), "2h 05m");
+    }
+
+    #[test]
+    fn test_countdown_and_expiry() {
+        let a = SystemAnnouncement::maintenance("m1", "Down for upgrades")
+            .starts_at(10_000)
+            .until(70_000);
+        assert_eq!(a.countdown(4_000), Some(Countdown::StartsIn(6_000)));
+        assert_eq!(a.countdown(10_000), Some(Countdown::EndsIn(60_000)));
+        assert!(!a.is_expired(69_999));
+        assert!(a.is_expired(70_000));
+        assert_eq!(a.countdown(70_000), None);
+    }
+
+    #[test]
+    fn test_dismissed_list_is_bounded() {
+        let ids: Vec<String> = (0..MAX_REMEMBERED).map(|i| i.to_string()).collect();
+        let next = with_dismissed(ids, "new");
+        assert_eq!(next.len(), MAX_REMEMBERED);
+        assert_eq!(next.first().map(String::as_str), Some("1"));
+        assert_eq!(next.last().map(String::as_str), Some("new"));
+        assert_eq!(
+            with_dismissed(vec!["a".into(), "b".into()], "a"),
+            vec!["b", "a"]
+        );
+    }
+
+    #[test]
+    fn test_announcement_from_config_json() {
+        let a: SystemAnnouncement =
+            serde_json::from_str(r#"{"id": "news-1", "message": "New season!"}"#).unwrap();
+        assert_eq!(a, SystemAnnouncement::info("news-1", "New season!"));
+    }
+}
@@ -7,6 +7,8 @@
 //! - `active` - Signal for the currently active tab value
 //! - `on_change` - Callback when tab selection changes
 //! - `tabs` - Vector of tab definitions (value, label pairs)
+//! - `items` - Rich tab definitions with icons, badges and disabled state
+//! - `orientation` - Horizontal (default) or Vertical tab list
 //! - `children` - Tab panel content (should use TabPanel components)
 //!
 //! ## Usage
@@ -27,13 +29,100 @@
 //!     <TabPanel value="details">"Details content"</TabPanel>
 //!     <TabPanel value="settings">"Settings content"</TabPanel>
 //! </Tabs>
+//!
+//! // Vertical settings navigation with icons, badges and a disabled tab
+//! <Tabs
+//!     active=active_tab
+//!     on_change=Callback::new(move |tab| set_active_tab.set(tab))
+//!     orientation=TabsOrientation::Vertical
+//!     items=vec![
+//!         TabItem::new("profile", "Profile").with_icon("👤"),
+//!         TabItem::new("inbox", "Inbox").with_icon("✉").with_badge(unread),
+//!         TabItem::new("billing", "Billing").disabled(true),
+//!     ]
+//! >
+//!     <TabPanel value="profile">"Profile settings"</TabPanel>
+//!     <TabPanel value="inbox">"Messages"</TabPanel>
+//! </Tabs>
 //! ```
 
+use crate::CountBadge;
 use leptos::prelude::*;
 
 /// Tab definition: (value, label)
 pub type TabDef = (String, String);
 
+/// Tab list layout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TabsOrientation {
+    /// Tabs in a row above the panels
+    #[default]
+    Horizontal,
+    /// Tabs in a column beside the panels
+    Vertical,
+}
+
+impl TabsOrientation {
+    fn class_suffix(&self) -> &'static str {
+        match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        }
+    }
+}
+
+/// Rich tab definition with optional icon, badge and disabled state
+#[derive(Clone)]
+pub struct TabItem {
+    /// Value matched against `TabPanel` values
+    pub value: String,
+    /// Label text
+    pub label: String,
+    /// Optional icon/emoji before the label
+    pub icon: Option<String>,
+    /// Optional count shown in a badge after the label
+    pub badge: Option<Signal<u32>>,
+    /// Whether the tab can be selected
+    pub disabled: Signal<bool>,
+}
+
+impl TabItem {
+    /// Create a new tab with value and label
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            icon: None,
+            badge: None,
+            disabled: Signal::from(false),
+        }
+    }
+
+    /// Set an icon shown before the label
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set a count badge shown after the label (hidden at zero)
+    pub fn with_badge(mut self, count: impl Into<Signal<u32>>) -> Self {
+        self.badge = Some(count.into());
+        self
+    }
+
+    /// Set whether the tab is disabled
+    pub fn disabled(mut self, disabled: impl Into<Signal<bool>>) -> Self {
+        self.disabled = disabled.into();
+        self
+    }
+}
+
+impl From<TabDef> for TabItem {
+    fn from((value, label): TabDef) -> Self {
+        Self::new(value, label)
+    }
+}
+
 /// Context for active tab state - provided by Tabs, used by TabPanel
 #[derive(Clone)]
 pub struct TabsContext {
@@ -50,8 +139,14 @@ pub fn Tabs(
     #[prop(into)]
     on_change: Callback<String>,
     /// Tab definitions: Vec<(value, label)>
-    #[prop(into)]
+    #[prop(into, optional)]
     tabs: Vec<TabDef>,
+    /// Rich tab definitions, rendered after `tabs`
+    #[prop(into, optional)]
+    items: Vec<TabItem>,
+    /// Tab list layout
+    #[prop(optional)]
+    orientation: TabsOrientation,
     /// Tab panel content
     children: Children,
 ) -> impl IntoView {
@@ -61,27 +156,51 @@ pub fn Tabs(
     // Eagerly render children once (after context is provided)
     let panels_content = children();
 
+    let items: Vec<TabItem> = tabs.into_iter().map(TabItem::from).chain(items).collect();
+    let root_class = format!("ui-tabs ui-tabs--{}", orientation.class_suffix());
+
     view! {
-        <div class="ui-tabs">
-            <div class="ui-tabs__list" role="tablist">
-                {tabs.into_iter().map(|(value, label)| {
+        <div class=root_class>
+            <div
+                class="ui-tabs__list"
+                role="tablist"
+                aria-orientation=orientation.class_suffix()
+            >
+                {items.into_iter().map(|item| {
+                    let TabItem { value, label, icon, badge, disabled } = item;
                     let value_for_class = value.clone();
                     let value_for_click = value.clone();
 
                     view! {
                         <button
                             class=move || {
+                                let mut classes = vec!["ui-tabs__tab"];
                                 if active.get() == value_for_class {
-                                    "ui-tabs__tab ui-tabs__tab--active"
-                                } else {
-                                    "ui-tabs__tab"
+                                    classes.push("ui-tabs__tab--active");
+                                }
+                                if disabled.get() {
+                                    classes.push("ui-tabs__tab--disabled");
+                                }
+                                classes.join(" ")
+                            }
+                            on:click=move |_| {
+                                if !disabled.get_untracked() {
+                                    on_change.run(value_for_click.clone());
                                 }
                             }
-                            on:click=move |_| on_change.run(value_for_click.clone())
                             role="tab"
+                            disabled=move || disabled.get()
                             aria-selected=move || active.get() == value
                         >
-                            {label}
+                            {icon.map(|icon| view! {
+                                <span class="ui-tabs__icon" aria-hidden="true">{icon}</span>
+                            })}
+                            <span class="ui-tabs__label">{label}</span>
+                            {badge.map(|count| view! {
+                                <span class="ui-tabs__badge">
+                                    <CountBadge count=count />
+                                </span>
+                            })}
                         </button>
                     }
                 }).collect_view()}
@@ -10,6 +10,8 @@
 //! - `placeholder` - Optional placeholder text
 //! - `disabled` - Whether input is disabled
 //! - `input_type` - Input type (text, email, password, etc.)
+//! - `prefix` - Leading adornment inside the field (icon, currency)
+//! - `suffix` - Trailing adornment inside the field (unit, clear button, spinner)
 //!
 //! ## Usage
 //!
@@ -22,8 +24,18 @@
 //!     label="Username"
 //!     placeholder="Enter your username"
 //! />
+//!
+//! // Amount with a unit
+//! <TextInput
+//!     value=amount
+//!     on_change=move |v| set_amount.set(v)
+//!     input_type=InputType::Number
+//!     prefix=|| "₳"
+//!     suffix=|| "ADA"
+//! />
 //! ```
 
+use crate::input_group::input_group;
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
 
@@ -78,6 +90,12 @@ pub fn TextInput(
     /// Additional class
     #[prop(into, optional)]
     class: Option<String>,
+    /// Leading adornment
+    #[prop(optional)]
+    prefix: Option<Children>,
+    /// Trailing adornment
+    #[prop(optional)]
+    suffix: Option<Children>,
 ) -> impl IntoView {
     let is_disabled = move || disabled.map(|d| d.get()).unwrap_or(false);
 
@@ -103,14 +121,16 @@ pub fn TextInput(
             {label.map(|l| view! {
                 <label class="ui-text-input__label">{l}</label>
             })}
-            <input
-                type=input_type.as_str()
-                class=input_class
-                value=move || value.get()
-                placeholder=placeholder.unwrap_or_default()
-                disabled=is_disabled
-                on:input=handle_input
-            />
+            {input_group(prefix, suffix, false, view! {
+                <input
+                    type=input_type.as_str()
+                    class=input_class
+                    value=move || value.get()
+                    placeholder=placeholder.unwrap_or_default()
+                    disabled=is_disabled
+                    on:input=handle_input
+                />
+            }.into_any())}
         </div>
     }
 }
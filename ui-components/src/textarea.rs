@@ -10,6 +10,8 @@
 //! - `placeholder` - Optional placeholder text
 //! - `disabled` - Whether textarea is disabled
 //! - `rows` - Number of visible rows (default: 4)
+//! - `prefix` - Leading adornment, aligned to the first line
+//! - `suffix` - Trailing adornment, aligned to the first line
 //!
 //! ## Usage
 //!
@@ -25,6 +27,7 @@
 //! />
 //! ```
 
+use crate::input_group::input_group;
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
 
@@ -52,6 +55,12 @@ pub fn Textarea(
     /// Additional class
     #[prop(into, optional)]
     class: Option<String>,
+    /// Leading adornment
+    #[prop(optional)]
+    prefix: Option<Children>,
+    /// Trailing adornment
+    #[prop(optional)]
+    suffix: Option<Children>,
 ) -> impl IntoView {
     let is_disabled = move || disabled.map(|d| d.get()).unwrap_or(false);
 
@@ -77,15 +86,17 @@ pub fn Textarea(
             {label.map(|l| view! {
                 <label class="ui-textarea__label">{l}</label>
             })}
-            <textarea
-                class=textarea_class
-                rows=rows
-                placeholder=placeholder.unwrap_or_default()
-                disabled=is_disabled
-                on:input=handle_input
-            >
-                {move || value.get()}
-            </textarea>
+            {input_group(prefix, suffix, true, view! {
+                <textarea
+                    class=textarea_class
+                    rows=rows
+                    placeholder=placeholder.unwrap_or_default()
+                    disabled=is_disabled
+                    on:input=handle_input
+                >
+                    {move || value.get()}
+                </textarea>
+            }.into_any())}
         </div>
     }
 }
@@ -31,10 +31,27 @@
 //!     }
 //! }
 //! ```
+//!
+//! ## Placement
+//!
+//! `ToastProvider` takes `position`, `stack`, `max_width` and `z_index` so
+//! toasts can be moved clear of fixed HUDs. A single toast can pick its own
+//! corner with [`Toast::with_position`]; the container renders one stack per
+//! position in use.
+//!
+//! ```ignore
+//! <ToastProvider position=ToastPosition::BottomCenter stack=ToastStack::Up z_index=50>
+//!     <Game />
+//!     <ToastContainer />
+//! </ToastProvider>
+//! ```
 
+use crate::i18n::use_locale;
 use leptos::prelude::*;
 use std::collections::VecDeque;
 
+pub use ui_toast::{ToastPosition, ToastStack};
+
 /// Toast notification kind
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ToastKind {
@@ -85,6 +102,8 @@ pub struct Toast {
     pub icon: Option<String>,
     /// Duration in milliseconds before auto-dismiss
     pub duration_ms: u32,
+    /// Position override (if None, uses the provider's position)
+    pub position: Option<ToastPosition>,
 }
 
 impl Toast {
@@ -96,6 +115,7 @@ impl Toast {
             message: message.into(),
             icon: None,
             duration_ms: DEFAULT_TOAST_DURATION_MS,
+            position: None,
         }
     }
 
@@ -131,6 +151,12 @@ impl Toast {
         self
     }
 
+    /// Show this toast at a different position than the provider's default
+    pub fn with_position(mut self, position: ToastPosition) -> Self {
+        self.position = Some(position);
+        self
+    }
+
     /// Get the icon to display (custom or default based on kind)
     pub fn display_icon(&self) -> &str {
         self.icon
@@ -139,22 +165,82 @@ impl Toast {
     }
 }
 
+/// Container placement shared by every toast in a provider
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ToastLayout {
+    /// Default screen position
+    pub position: ToastPosition,
+    /// Which way new toasts push existing ones
+    pub stack: ToastStack,
+    /// CSS max-width of each toast (default: 400px)
+    pub max_width: Option<String>,
+    /// z-index of the container (default: 9999)
+    pub z_index: Option<i32>,
+}
+
+impl ToastLayout {
+    /// Class list for the container at `position`
+    fn container_class(&self, position: ToastPosition) -> String {
+        let mut class = format!("ui-toast-container ui-toast-container--{position}");
+        if self.stack == ToastStack::Up {
+            class.push_str(" ui-toast-container--stack-up");
+        }
+        class
+    }
+
+    /// Inline custom properties for max width and z-index
+    fn container_style(&self) -> String {
+        let mut style = String::new();
+        if let Some(max_width) = &self.max_width {
+            style.push_str(&format!("--toast-max-width: {max_width};"));
+        }
+        if let Some(z_index) = self.z_index {
+            style.push_str(&format!("--toast-z-index: {z_index};"));
+        }
+        style
+    }
+
+    /// Positions that currently need a container, default position first
+    fn positions_in_use<'a>(&self, toasts: impl Iterator<Item = &'a Toast>) -> Vec<ToastPosition> {
+        let mut positions = vec![self.position];
+        for toast in toasts {
+            let position = toast.position.unwrap_or(self.position);
+            if !positions.contains(&position) {
+                positions.push(position);
+            }
+        }
+        positions
+    }
+}
+
 /// Context for managing toast notifications
 #[derive(Clone)]
 pub struct ToastContext {
     toasts: RwSignal<VecDeque<Toast>>,
     next_id: RwSignal<u32>,
+    layout: StoredValue<ToastLayout>,
 }
 
 impl ToastContext {
     /// Create a new toast context
     pub fn new() -> Self {
+        Self::with_layout(ToastLayout::default())
+    }
+
+    /// Create a toast context with custom container placement
+    pub fn with_layout(layout: ToastLayout) -> Self {
         Self {
             toasts: RwSignal::new(VecDeque::new()),
             next_id: RwSignal::new(0),
+            layout: StoredValue::new(layout),
         }
     }
 
+    /// Container placement for this provider
+    pub fn layout(&self) -> ToastLayout {
+        self.layout.get_value()
+    }
+
     /// Show a toast notification
     pub fn show(&self, mut toast: Toast) {
         let id = self.next_id.get();
@@ -221,14 +307,33 @@ pub fn try_use_toasts() -> Option<ToastContext> {
 /// # Example
 ///
 /// ```ignore
-/// <ToastProvider>
+/// <ToastProvider position=ToastPosition::BottomRight max_width="320px">
 ///     <App />
 ///     <ToastContainer />
 /// </ToastProvider>
 /// ```
 #[component]
-pub fn ToastProvider(children: Children) -> impl IntoView {
-    provide_context(ToastContext::new());
+pub fn ToastProvider(
+    /// Default container position (default: top-right)
+    #[prop(optional)]
+    position: ToastPosition,
+    /// Stacking direction for new toasts (default: down)
+    #[prop(optional)]
+    stack: ToastStack,
+    /// CSS max-width of each toast, e.g. "320px" (default: 400px)
+    #[prop(into, optional)]
+    max_width: Option<String>,
+    /// z-index of the container (default: 9999)
+    #[prop(into, optional)]
+    z_index: Option<i32>,
+    children: Children,
+) -> impl IntoView {
+    provide_context(ToastContext::with_layout(ToastLayout {
+        position,
+        stack,
+        max_width,
+        z_index,
+    }));
     children()
 }
 
@@ -247,26 +352,53 @@ pub fn ToastProvider(children: Children) -> impl IntoView {
 /// ```
 #[component]
 pub fn ToastContainer(
-    /// Optional position class override (default: top-right)
+    /// Optional class override for the default position's container
+    /// (prefer `ToastProvider`'s `position` prop)
     #[prop(into, optional)]
     position: Option<String>,
 ) -> impl IntoView {
     let ctx = use_toasts();
     let toasts = ctx.toasts();
+    let layout = ctx.layout();
+    let default_position = layout.position;
+    let style = layout.container_style();
 
-    let position_class = position.unwrap_or_else(|| "ui-toast-container--top-right".to_string());
-    let container_class = format!("ui-toast-container {position_class}");
+    let positions = {
+        let layout = layout.clone();
+        move || toasts.with(|t| layout.positions_in_use(t.iter()))
+    };
 
     view! {
-        <div class=container_class>
-            <For
-                each={move || toasts.get().into_iter().collect::<Vec<_>>()}
-                key={|t| t.id}
-                let:toast
-            >
-                <ToastItem toast=toast />
-            </For>
-        </div>
+        <For
+            each=positions
+            key=|p| *p
+            let:pos
+        >
+            {
+                let container_class = match &position {
+                    Some(class) if pos == default_position => format!("ui-toast-container {class}"),
+                    _ => layout.container_class(pos),
+                };
+                view! {
+                    <div class=container_class style=style.clone()>
+                        <For
+                            each=move || {
+                                toasts.with(|t| {
+                                    t.iter()
+                                        .filter(|toast| toast.position.unwrap_or(default_position) == pos)
+                                        .cloned()
+                                        .collect::<Vec<_>>()
+                                })
+                            }
+                            key=|t| t.id
+                            let:toast
+                        >
+                            <ToastItem toast=toast />
+                        </For>
+                    </div>
+                }
+            }
+        </For>
     }
 }
 
@@ -274,6 +406,7 @@ pub fn ToastContainer(
 #[component]
 fn ToastItem(toast: Toast) -> impl IntoView {
     let ctx = use_toasts();
+    let locale = use_locale();
     let id = toast.id;
     let kind_class = format!("ui-toast--{}", toast.kind.class_suffix());
     let toast_class = format!("ui-toast {kind_class}");
@@ -287,10 +420,58 @@ fn ToastItem(toast: Toast) -> impl IntoView {
             <button
                 class="ui-toast__dismiss"
                 on:click=move |_| ctx.dismiss(id)
-                aria-label="Dismiss"
+                aria-label=move || locale.t_or("toast.dismiss", "Dismiss")
             >
                 "\u{2715}"
             </button>
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn container_class_includes_position_and_stack() {
+        let layout = ToastLayout {
+            stack: ToastStack::Up,
+            ..Default::default()
+        };
+        assert_eq!(
+            layout.container_class(ToastPosition::BottomCenter),
+            "ui-toast-container ui-toast-container--bottom-center ui-toast-container--stack-up"
+        );
+    }
+
+    #[test]
+    fn container_style_sets_only_overrides() {
+        assert_eq!(ToastLayout::default().container_style(), "");
+        let layout = ToastLayout {
+            max_width: Some("320px".into()),
+            z_index: Some(50),
+            ..Default::default()
+        };
+        assert_eq!(
+            layout.container_style(),
+            "--toast-max-width: 320px;--toast-z-index: 50;"
+        );
+    }
+
+    #[test]
+    fn positions_in_use_keeps_default_first() {
+        let layout = ToastLayout {
+            position: ToastPosition::BottomRight,
+            ..Default::default()
+        };
+        let toasts = [
+            Toast::info("a").with_position(ToastPosition::TopLeft),
+            Toast::info("b"),
+            Toast::info("c").with_position(ToastPosition::TopLeft),
+        ];
+        assert_eq!(
+            layout.positions_in_use(toasts.iter()),
+            vec![ToastPosition::BottomRight, ToastPosition::TopLeft]
+        );
+    }
+}
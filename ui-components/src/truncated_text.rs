@@ -0,0 +1,183 @@
+//! TruncatedText Leptos Component
+//!
+//! Shortens long text while keeping the full value one hover away.
+//!
+//! ## Features
+//!
+//! - End ellipsis, either by the container width (CSS) or a character limit
+//! - Middle ellipsis for addresses, hashes and IDs, where both ends matter
+//! - Multi-line clamping
+//! - Tooltip with the full value, shown only when something was cut
+//!
+//! ## Props
+//!
+//! - `value` - Full text
+//! - `mode` - `End` (default) or `Middle`
+//! - `max_chars` - Character limit for `End`; without it the container width decides
+//! - `head` / `tail` - Characters kept either side in `Middle` mode (default 8 / 6)
+//! - `lines` - Clamp `End` text to this many lines instead of one
+//! - `tooltip` - Show the full value on hover when truncated (default true)
+//! - `class` - Additional CSS class
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! <TruncatedText value=address mode=TruncateMode::Middle />
+//! <TruncatedText value=tx_hash mode=TruncateMode::Middle head=12 tail=8 />
+//! <TruncatedText value=description lines=3 />
+//! <TruncatedText value=name max_chars=24 />
+//! ```
+//!
+//! Outside a component, [`shorten_middle`] and [`shorten_end`] do the same
+//! shortening on plain strings.
+
+use leptos::html;
+use leptos::prelude::*;
+
+/// Where the ellipsis goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TruncateMode {
+    /// Cut the end
+    #[default]
+    End,
+    /// Keep both ends and cut the middle
+    Middle,
+}
+
+/// Shorten to `head...tail`, leaving values that are already short untouched
+pub fn shorten_middle(value: &str, head: usize, tail: usize) -> String {
+    let len = value.chars().count();
+    if len <= head + tail + 3 {
+        return value.to_string();
+    }
+    let start: String = value.chars().take(head).collect();
+    let end: String = value.chars().skip(len - tail).collect();
+    format!("{start}...{end}")
+}
+
+/// Shorten to at most `max` characters, ending in `...`
+pub fn shorten_end(value: &str, max: usize) -> String {
+    if value.chars().count() <= max {
+        return value.to_string();
+    }
+    let start: String = value.chars().take(max.saturating_sub(3)).collect();
+    format!("{start}...")
+}
+
+/// Truncated text component
+#[component]
+pub fn TruncatedText(
+    /// Full text
+    #[prop(into)]
+    value: Signal<String>,
+    /// Where the ellipsis goes
+    #[prop(optional)]
+    mode: TruncateMode,
+    /// Character limit in `End` mode; without it the container width decides
+    #[prop(optional)]
+    max_chars: Option<usize>,
+    /// Characters kept before the ellipsis in `Middle` mode
+    #[prop(optional, default = 8)]
+    head: usize,
+    /// Characters kept after the ellipsis in `Middle` mode
+    #[prop(optional, default = 6)]
+    tail: usize,
+    /// Clamp `End` text to this many lines
+    #[prop(optional)]
+    lines: Option<u32>,
+    /// Show the full value on hover when truncated
+    #[prop(optional, default = true)]
+    tooltip: bool,
+    /// Additional CSS class
+    #[prop(into, optional)]
+    class: String,
+) -> impl IntoView {
+    let node_ref = NodeRef::<html::Span>::new();
+    // Set on hover when the browser clipped the text
+    let overflowing = RwSignal::new(false);
+
+    let text = Memo::new(move |_| {
+        value.with(|v| match (mode, max_chars) {
+            (TruncateMode::Middle, _) => shorten_middle(v, head, tail),
+            (TruncateMode::End, Some(max)) => shorten_end(v, max),
+            (TruncateMode::End, None) => v.clone(),
+        })
+    });
+    let shortened = move || value.with(|v| text.with(|t| t != v));
+
+    // Only CSS truncation can clip; character limits are known up front
+    let css_clipped = mode == TruncateMode::End && max_chars.is_none();
+    let on_mouseenter = move |_| {
+        if !css_clipped {
+            return;
+        }
+        if let Some(el) = node_ref.get_untracked() {
+            overflowing.set(
+                el.scroll_width() > el.client_width() || el.scroll_height() > el.client_height(),
+            );
+        }
+    };
+
+    let title = move || (tooltip && (shortened() || overflowing.get())).then(|| value.get());
+    let aria_label = move || shortened().then(|| value.get());
+
+    let class = {
+        let mut classes = vec!["ui-truncated-text"];
+        match (css_clipped, lines) {
+            (true, Some(_)) => classes.push("ui-truncated-text--clamp"),
+            (true, None) => classes.push("ui-truncated-text--clip"),
+            (false, _) => classes.push("ui-truncated-text--nowrap"),
+        }
+        if !class.is_empty() {
+            classes.push(&class);
+        }
+        classes.join(" ")
+    };
+    let style = lines
+        .filter(|_| css_clipped)
+        .map(|n| format!("--truncate-lines: {n}"));
+
+    view! {
+        <span
+            node_ref=node_ref
+            class=class
+            style=style
+            title=title
+            aria-label=aria_label
+            on:mouseenter=on_mouseenter
+        >
+            {move || text.get()}
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shorten_middle() {
+        assert_eq!(
+            shorten_middle(
+                "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n",
+                8,
+                6
+            ),
+            "addr1qx2...dzer3n"
+        );
+        assert_eq!(shorten_middle("abc123", 8, 6), "abc123");
+        assert_eq!(
+            shorten_middle("0123456789abcdefg", 8, 6),
+            "0123456789abcdefg"
+        );
+    }
+
+    #[test]
+    fn test_shorten_end() {
+        assert_eq!(shorten_end("Captain Jack Sparrow", 12), "Captain J...");
+        assert_eq!(shorten_end("Captain Jack", 12), "Captain Jack");
+        // Counts characters, not bytes
+        assert_eq!(shorten_end("ÅÅÅÅÅÅ", 5), "ÅÅ...");
+        assert_eq!(shorten_end("abcdef", 2), "...");
+    }
+}
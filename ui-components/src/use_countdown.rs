@@ -0,0 +1,56 @@
+//! Countdown Signal Hook
+//!
+//! Leptos adapter over [`ui_core::clock`]. Follows a deadline signal and
+//! yields the time left, measured on the server-adjusted clock so a skewed
+//! client clock doesn't shift the countdown. Ticks once a second while the
+//! deadline is in the future; the interval is cleared when the owning
+//! component unmounts.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_components::use_countdown;
+//! use ui_core::clock::format_duration;
+//!
+//! let remaining = use_countdown(turn_deadline);
+//!
+//! view! {
+//!     <span>{move || remaining.get().map(format_duration)}</span>
+//! }
+//! ```
+
+use leptos::prelude::*;
+use std::time::Duration;
+use ui_core::clock::now_ms;
+
+const TICK: Duration = Duration::from_secs(1);
+
+/// Time left until `deadline` (unix ms, server time), or `None` without one
+///
+/// Stays at `Some(0)` once the deadline has passed.
+pub fn use_countdown(deadline: impl Into<Signal<Option<u64>>>) -> Signal<Option<u64>> {
+    let deadline = deadline.into();
+    let now = RwSignal::new(now_ms());
+
+    // A new deadline shouldn't wait for the next tick
+    Effect::new(move |_| {
+        deadline.track();
+        now.set(now_ms());
+    });
+
+    if let Ok(handle) = set_interval_with_handle(
+        move || {
+            let running = deadline
+                .get_untracked()
+                .is_some_and(|end| end > now.get_untracked());
+            if running {
+                now.set(now_ms());
+            }
+        },
+        TICK,
+    ) {
+        on_cleanup(move || handle.clear());
+    }
+
+    Signal::derive(move || deadline.get().map(|end| end.saturating_sub(now.get())))
+}
@@ -0,0 +1,56 @@
+//! Debounced Signal Hook
+//!
+//! Leptos adapter over [`ui_core::timing::debounced`]. Follows a source
+//! signal, updating only once it has been still for the given delay. The
+//! pending timer is cancelled when the owning component unmounts.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_components::use_debounced_signal;
+//!
+//! let (query, set_query) = signal(String::new());
+//! let search = use_debounced_signal(query, 300);
+//!
+//! let results = use_fetch(move || fetch_results(search.get()));
+//!
+//! view! {
+//!     <TextInput value=query on_change=Callback::new(move |v| set_query.set(v)) />
+//! }
+//! ```
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use ui_core::timing::debounced;
+
+/// Follow `source`, updating only after it stops changing for `ms` milliseconds
+///
+/// Starts with the source's current value. Unchanged values are not re-emitted.
+pub fn use_debounced_signal<T>(source: impl Into<Signal<T>>, ms: u32) -> Signal<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let source = source.into();
+    let value = RwSignal::new(source.get_untracked());
+
+    let debouncer = debounced(
+        move |next: T| {
+            if value.with_untracked(|current| *current != next) {
+                value.set(next);
+            }
+        },
+        ms,
+    );
+    // Dropped with the owner, which cancels any pending update
+    let debouncer = StoredValue::new(SendWrapper::new(debouncer));
+
+    Effect::new(move |prev: Option<()>| {
+        let next = source.get();
+        // The initial value is already in place
+        if prev.is_some() {
+            debouncer.with_value(|d| d.call(next));
+        }
+    });
+
+    value.into()
+}
@@ -0,0 +1,224 @@
+//! Fetch Hooks
+//!
+//! Leptos adapter over [`ui_core::FetchState`]. Runs an async request, exposes
+//! its state as a signal, and tracks retry metadata.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_components::{use_fetch, use_fetch_with};
+//!
+//! // Independent fetch
+//! let profile = use_fetch(move || get_json::<Profile>("/api/me", None));
+//!
+//! // Dependent fetch - runs once the profile has loaded, re-runs if it changes
+//! let inventory = use_fetch_with(
+//!     move || profile.data().map(|p| p.id),
+//!     move |id| get_json::<Inventory>(&format!("/api/inventory/{id}"), None),
+//! );
+//!
+//! view! {
+//!     {move || match profile.state().zip(inventory.state()) {
+//!         FetchState::Loaded((p, inv)) => view! { <Summary profile=p inventory=inv /> }.into_any(),
+//!         FetchState::Failed(err) => view! {
+//!             <Alert message=err />
+//!             <Button on_click=move |_| inventory.refetch()>"Retry"</Button>
+//!         }.into_any(),
+//!         _ => view! { <Spinner /> }.into_any(),
+//!     }}
+//! }
+//! ```
+
+use leptos::prelude::*;
+use std::fmt::Display;
+use std::future::Future;
+use ui_core::{FetchState, RetryMeta};
+use wasm_bindgen_futures::spawn_local;
+
+/// Reactive handle returned by [`use_fetch`] and [`use_fetch_with`]
+pub struct UseFetch<T: Send + Sync + 'static> {
+    state: RwSignal<FetchState<T>>,
+    retry: RwSignal<RetryMeta>,
+    trigger: RwSignal<u32>,
+}
+
+impl<T: Send + Sync + 'static> Clone for UseFetch<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for UseFetch<T> {}
+
+impl<T: Clone + Send + Sync + 'static> UseFetch<T> {
+    /// Current fetch state (tracked)
+    pub fn state(&self) -> FetchState<T> {
+        self.state.get()
+    }
+
+    /// Loaded data if available (tracked)
+    pub fn data(&self) -> Option<T> {
+        self.state.with(|s| s.data().cloned())
+    }
+}
+
+impl<T: Send + Sync + 'static> UseFetch<T> {
+    /// Read-only signal of the fetch state
+    pub fn signal(&self) -> Signal<FetchState<T>> {
+        self.state.into()
+    }
+
+    /// Retry metadata (tracked)
+    pub fn retry_meta(&self) -> RetryMeta {
+        self.retry.get()
+    }
+
+    /// Whether the fetch is in flight (tracked)
+    pub fn is_loading(&self) -> bool {
+        self.state.with(|s| s.is_loading())
+    }
+
+    /// Error message if the last attempt failed (tracked)
+    pub fn error(&self) -> Option<String> {
+        self.state.with(|s| s.error().map(|e| e.to_string()))
+    }
+
+    /// Run the request again with the current inputs
+    pub fn refetch(&self) {
+        self.trigger.update(|n| *n = n.wrapping_add(1));
+    }
+
+    /// Replace the state locally (e.g. after an optimistic update)
+    pub fn set(&self, state: FetchState<T>) {
+        self.state.set(state);
+    }
+}
+
+/// Tags requests so responses from superseded ones can be dropped
+#[derive(Debug, Default)]
+struct RequestSeq {
+    latest: u64,
+}
+
+impl RequestSeq {
+    /// Start a request, superseding any still in flight
+    fn start(&mut self) -> u64 {
+        self.latest = self.latest.wrapping_add(1);
+        self.latest
+    }
+
+    /// Supersede any request in flight without starting a new one
+    fn cancel(&mut self) {
+        self.start();
+    }
+
+    /// Whether `id` is the latest request
+    fn is_current(&self, id: u64) -> bool {
+        self.latest == id
+    }
+}
+
+/// Run a request when the component mounts
+///
+/// Call [`UseFetch::refetch`] to run it again.
+pub fn use_fetch<T, E, Fut, F>(fetcher: F) -> UseFetch<T>
+where
+    T: Send + Sync + 'static,
+    E: Display + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    F: Fn() -> Fut + 'static,
+{
+    use_fetch_with(|| Some(()), move |()| fetcher())
+}
+
+/// Run a request whenever `source` yields a value
+///
+/// `source` is tracked: while it returns `None` the state stays `Idle`, and
+/// each new `Some(input)` starts a fresh request. Responses from superseded
+/// requests are ignored, so only the latest input's result is shown.
+pub fn use_fetch_with<S, T, E, Fut, Src, F>(source: Src, fetcher: F) -> UseFetch<T>
+where
+    T: Send + Sync + 'static,
+    E: Display + 'static,
+    Fut: Future<Output = Result<T, E>> + 'static,
+    Src: Fn() -> Option<S> + 'static,
+    F: Fn(S) -> Fut + 'static,
+{
+    let handle = UseFetch {
+        state: RwSignal::new(FetchState::Idle),
+        retry: RwSignal::new(RetryMeta::default()),
+        trigger: RwSignal::new(0),
+    };
+    let requests = StoredValue::new(RequestSeq::default());
+
+    Effect::new(move |_| {
+        handle.trigger.track();
+
+        let Some(input) = source() else {
+            // Drop any response still in flight so it can't overwrite Idle
+            requests.update_value(RequestSeq::cancel);
+            handle.state.set(FetchState::Idle);
+            return;
+        };
+
+        let Some(id) = requests.try_update_value(RequestSeq::start) else {
+            return;
+        };
+
+        handle.state.set(FetchState::Loading);
+        handle.retry.update(|r| r.record_attempt());
+
+        let fut = fetcher(input);
+        spawn_local(async move {
+            let result = fut.await;
+
+            // A newer request has started - drop this response
+            if !requests.with_value(|r| r.is_current(id)) {
+                return;
+            }
+
+            match result {
+                Ok(data) => {
+                    handle.retry.update(|r| r.record_success());
+                    handle.state.set(FetchState::Loaded(data));
+                }
+                Err(err) => {
+                    let msg = err.to_string();
+                    tracing::warn!("Fetch failed: {msg}");
+                    handle.retry.update(|r| r.record_failure(msg.clone()));
+                    handle.state.set(FetchState::Failed(msg));
+                }
+            }
+        });
+    });
+
+    handle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_newer_request_supersedes_older() {
+        let mut requests = RequestSeq::default();
+        let first = requests.start();
+        let second = requests.start();
+        assert!(!requests.is_current(first));
+        assert!(requests.is_current(second));
+    }
+
+    #[test]
+    fn test_clearing_source_drops_pending_fetch() {
+        let mut requests = RequestSeq::default();
+        let pending = requests.start();
+
+        // Source returned None while the fetch was still in flight
+        requests.cancel();
+        assert!(!requests.is_current(pending));
+
+        // The next input starts fresh
+        let next = requests.start();
+        assert!(requests.is_current(next));
+    }
+}
@@ -0,0 +1,174 @@
+//! Persistent Order Hook
+//!
+//! Remembers a user-customized ordering (e.g. from [`DraggableStack`]) in
+//! [`ui_core::settings::Settings`] and reapplies it to incoming item lists.
+//! Only item keys are stored, so the items themselves can keep coming from
+//! the server.
+//!
+//! Items missing from the saved order (added since it was saved) keep their
+//! incoming position; saved keys with no matching item are skipped and
+//! dropped on the next reorder.
+//!
+//! [`DraggableStack`]: crate::DraggableStack
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_components::{use_persistent_order, DraggableStack};
+//! use ui_core::settings::Settings;
+//!
+//! let settings = Settings::new("dashboard");
+//! let cards = use_persistent_order(&settings, "card_order", cards, |c: &Card| c.id.clone());
+//!
+//! view! {
+//!     <DraggableStack
+//!         items=cards.signal()
+//!         on_reorder=move |reorder| cards.reorder(reorder)
+//!         key_fn=|c: &Card| c.id.clone()
+//!         render_item=move |card, _idx, _drag| view! { <DashboardCard card=card /> }
+//!     />
+//! }
+//! ```
+
+use crate::use_setting::{use_setting, UseSetting};
+use crate::Reorder;
+use leptos::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use ui_core::settings::Settings;
+
+/// Reactive handle to a persisted ordering
+pub struct PersistentOrder<T: Send + Sync + 'static, K: Send + Sync + 'static> {
+    items: Signal<Vec<T>>,
+    keys: Signal<Vec<K>>,
+    order: UseSetting<Vec<K>>,
+}
+
+impl<T: Send + Sync + 'static, K: Send + Sync + 'static> Clone for PersistentOrder<T, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static, K: Send + Sync + 'static> Copy for PersistentOrder<T, K> {}
+
+impl<T, K> PersistentOrder<T, K>
+where
+    T: Clone + Send + Sync + 'static,
+    K: Clone + Serialize + Send + Sync + 'static,
+{
+    /// Items in the saved order (tracked)
+    pub fn get(&self) -> Vec<T> {
+        self.items.get()
+    }
+
+    /// Read-only signal of the ordered items
+    pub fn signal(&self) -> Signal<Vec<T>> {
+        self.items
+    }
+
+    /// Move an item and persist the new order
+    ///
+    /// Indices refer to the ordered list, as reported by `DraggableStack`.
+    pub fn reorder(&self, reorder: Reorder) {
+        let mut keys = self.keys.get_untracked();
+        reorder.apply(&mut keys);
+        self.order.set(keys);
+    }
+
+    /// Forget the saved order and show items as they come in
+    pub fn reset(&self) {
+        self.order.set(Vec::new());
+    }
+}
+
+/// Apply a saved key order to `items`
+///
+/// Items with a saved position fill the slots of known items in saved
+/// order; items without one stay at their incoming index.
+fn apply_order<T, K: PartialEq>(items: Vec<T>, order: &[K], key_fn: impl Fn(&T) -> K) -> Vec<T> {
+    let rank = |item: &T| {
+        let key = key_fn(item);
+        order.iter().position(|k| *k == key)
+    };
+    let ranks: Vec<Option<usize>> = items.iter().map(&rank).collect();
+
+    let mut known: Vec<(usize, T)> = Vec::new();
+    let mut slots: Vec<Option<T>> = Vec::with_capacity(items.len());
+    for (item, rank) in items.into_iter().zip(&ranks) {
+        match rank {
+            Some(rank) => {
+                known.push((*rank, item));
+                slots.push(None);
+            }
+            None => slots.push(Some(item)),
+        }
+    }
+    known.sort_by_key(|(rank, _)| *rank);
+
+    let mut known = known.into_iter().map(|(_, item)| item);
+    slots
+        .into_iter()
+        .filter_map(|slot| slot.or_else(|| known.next()))
+        .collect()
+}
+
+/// Keep `items` in a user-customized order persisted under `key`
+///
+/// The saved order follows the setting, so other components (or tabs)
+/// reordering the same key are picked up too.
+pub fn use_persistent_order<T, K>(
+    settings: &Settings,
+    key: &'static str,
+    items: impl Into<Signal<Vec<T>>>,
+    key_fn: impl Fn(&T) -> K + Clone + Send + Sync + 'static,
+) -> PersistentOrder<T, K>
+where
+    T: Clone + Send + Sync + 'static,
+    K: Clone + PartialEq + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    let items = items.into();
+    let order = use_setting(settings, key, Vec::<K>::new());
+
+    let ordered = {
+        let key_fn = key_fn.clone();
+        Signal::derive(move || apply_order(items.get(), &order.get(), &key_fn))
+    };
+    let keys = Signal::derive(move || ordered.with(|items| items.iter().map(&key_fn).collect()));
+
+    PersistentOrder {
+        items: ordered,
+        keys,
+        order,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_order() {
+        let id = |s: &&str| s.to_string();
+        let saved = ["c", "a", "b"].map(String::from);
+
+        assert_eq!(
+            apply_order(vec!["a", "b", "c"], &saved, id),
+            ["c", "a", "b"]
+        );
+        // No saved order keeps the incoming one
+        assert_eq!(apply_order(vec!["a", "b", "c"], &[], id), ["a", "b", "c"]);
+        // Removed items are skipped
+        assert_eq!(apply_order(vec!["a", "b"], &saved, id), ["a", "b"]);
+        assert_eq!(apply_order(vec!["c", "b"], &saved, id), ["c", "b"]);
+        // New items keep their incoming position
+        assert_eq!(
+            apply_order(vec!["new", "a", "b", "c"], &saved, id),
+            ["new", "c", "a", "b"]
+        );
+        assert_eq!(
+            apply_order(vec!["a", "b", "new", "c"], &saved, id),
+            ["c", "a", "new", "b"]
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! Persistent Setting Hook
+//!
+//! Leptos adapter over [`ui_core::settings::Settings`]. Exposes one setting
+//! as a signal that stays in sync with storage and with other components
+//! reading the same key.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_components::use_setting;
+//! use ui_core::settings::{keys, Settings};
+//!
+//! let settings = Settings::new("memory-game");
+//! let sound = use_setting(&settings, keys::SOUND_ENABLED, true);
+//!
+//! view! {
+//!     <button on:click=move |_| sound.set(!sound.get())>
+//!         {move || if sound.get() { "🔊" } else { "🔇" }}
+//!     </button>
+//! }
+//! ```
+
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use ui_core::settings::Settings;
+
+/// Reactive handle to a single persisted setting
+pub struct UseSetting<T: Send + Sync + 'static> {
+    value: RwSignal<T>,
+    settings: StoredValue<SendWrapper<Settings>>,
+    key: &'static str,
+}
+
+impl<T: Send + Sync + 'static> Clone for UseSetting<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for UseSetting<T> {}
+
+impl<T: Clone + Serialize + Send + Sync + 'static> UseSetting<T> {
+    /// Current value (tracked)
+    pub fn get(&self) -> T {
+        self.value.get()
+    }
+
+    /// Read-only signal of the value
+    pub fn signal(&self) -> Signal<T> {
+        self.value.into()
+    }
+
+    /// Persist a new value
+    ///
+    /// The signal updates immediately even if persisting fails.
+    pub fn set(&self, value: T) {
+        let key = self.key;
+        self.value.set(value.clone());
+        if let Err(e) = self.settings.with_value(|s| s.set(key, &value)) {
+            tracing::warn!("Failed to persist setting {key}: {e}");
+        }
+    }
+}
+
+/// Bind a persisted setting to a signal
+///
+/// Reads the stored value (or `default`) and keeps the signal updated when
+/// the key is written elsewhere - another component, or another `Settings`
+/// handle for the same namespace.
+pub fn use_setting<T>(settings: &Settings, key: &'static str, default: T) -> UseSetting<T>
+where
+    T: Clone + PartialEq + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    let value = RwSignal::new(settings.get(key).unwrap_or_else(|| default.clone()));
+
+    let subscription = settings.subscribe({
+        let settings = settings.clone();
+        move |changed| {
+            if changed != key {
+                return;
+            }
+            let next = settings.get(key).unwrap_or_else(|| default.clone());
+            if value.with_untracked(|current| *current != next) {
+                value.set(next);
+            }
+        }
+    });
+    let subscription = SendWrapper::new(subscription);
+    on_cleanup(move || drop(subscription));
+
+    UseSetting {
+        value,
+        settings: StoredValue::new(SendWrapper::new(settings.clone())),
+        key,
+    }
+}
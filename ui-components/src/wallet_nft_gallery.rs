@@ -7,9 +7,15 @@
 //!
 //! - Displays NFTs grouped by policy ID
 //! - Summary header with NFT and policy counts
+//! - Toolbar with name search, collection filter and sort
+//! - Optional multi-select mode with a selected-count toolbar
 //! - Loading and empty states
 //! - Data-driven - takes `Vec<PolicyGroup>` as input
 //!
+//! Searching matches NFT names, collection names and policy ids, and opens
+//! every folder with a match. "Recently acquired" sorting needs
+//! `acquired_at` timestamps, which a CIP-30 balance doesn't carry.
+//!
 //! ## Usage
 //!
 //! ```ignore
@@ -18,19 +24,144 @@
 //!
 //! let groups: Signal<Vec<PolicyGroup>> = ...;
 //! let loading: Signal<bool> = ...;
+//! let selection = RwSignal::new(HashSet::<String>::new());
 //!
 //! <WalletNftGallery
 //!     groups=groups
 //!     loading=loading
 //!     show_summary=true
+//!     selectable=true
+//!     selection=selection
 //! />
 //! ```
 
+use crate::button::{Button, ButtonSize, ButtonVariant};
 use crate::empty_state::EmptyState;
+use crate::i18n::use_locale;
 use crate::loading_overlay::Spinner;
 use crate::policy_folder::PolicyFolder;
+use crate::select::{Select, SelectOption};
+use crate::text_input::TextInput;
+use crate::use_debounced::use_debounced_signal;
 use leptos::prelude::*;
-use wallet_pallas::PolicyGroup;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use wallet_pallas::{NativeToken, PolicyGroup};
+
+/// Debounce for the search box, in ms
+const SEARCH_DEBOUNCE_MS: u32 = 200;
+
+/// How the gallery orders collections and the NFTs inside them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NftSort {
+    /// Collections and NFTs alphabetically
+    #[default]
+    Name,
+    /// Newest first, by `acquired_at`; unknown times last
+    RecentlyAcquired,
+    /// Largest collections first, NFTs by name
+    Quantity,
+}
+
+impl NftSort {
+    /// All sort orders, in menu order
+    pub const ALL: [NftSort; 3] = [NftSort::Name, NftSort::RecentlyAcquired, NftSort::Quantity];
+
+    /// Value used in the sort menu
+    pub fn value(&self) -> &'static str {
+        match self {
+            NftSort::Name => "name",
+            NftSort::RecentlyAcquired => "recent",
+            NftSort::Quantity => "quantity",
+        }
+    }
+
+    /// Parse a sort menu value
+    pub fn from_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|sort| sort.value() == value)
+    }
+
+    /// Menu label
+    pub fn label(&self) -> &'static str {
+        match self {
+            NftSort::Name => "Name",
+            NftSort::RecentlyAcquired => "Recently acquired",
+            NftSort::Quantity => "Quantity",
+        }
+    }
+
+    /// Message key for the localized menu label
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            NftSort::Name => "wallet_nft_gallery.sort.name",
+            NftSort::RecentlyAcquired => "wallet_nft_gallery.sort.recently_acquired",
+            NftSort::Quantity => "wallet_nft_gallery.sort.quantity",
+        }
+    }
+}
+
+/// Folder title: the known collection name, else the short policy id
+fn group_title(group: &PolicyGroup, collection_names: &HashMap<String, String>) -> String {
+    collection_names
+        .get(&group.policy_id)
+        .cloned()
+        .unwrap_or_else(|| group.policy_id_short.clone())
+}
+
+/// NFT groups narrowed to `policy` and `search`, in `sort` order
+///
+/// Each returned group holds only its matching NFTs. A search matching the
+/// collection name or policy id keeps the whole collection.
+fn visible_groups(
+    groups: &[PolicyGroup],
+    policy: Option<&str>,
+    search: &str,
+    sort: NftSort,
+    acquired_at: &HashMap<String, u64>,
+    collection_names: &HashMap<String, String>,
+) -> Vec<PolicyGroup> {
+    let search = search.trim().to_lowercase();
+    let acquired = |token: &NativeToken| acquired_at.get(&token.asset_id()).copied();
+
+    let mut visible: Vec<(String, PolicyGroup)> = groups
+        .iter()
+        .filter(|group| policy.is_none_or(|p| group.policy_id == p))
+        .filter_map(|group| {
+            let title = group_title(group, collection_names);
+            let whole_group =
+                title.to_lowercase().contains(&search) || group.policy_id.contains(&search);
+
+            let mut nfts: Vec<(String, &NativeToken)> = group
+                .nfts()
+                .into_iter()
+                .map(|token| (token.display_name().to_lowercase(), token))
+                .filter(|(name, _)| whole_group || name.contains(&search))
+                .collect();
+            if nfts.is_empty() {
+                return None;
+            }
+            match sort {
+                NftSort::RecentlyAcquired => nfts
+                    .sort_by_cached_key(|(name, token)| (Reverse(acquired(token)), name.clone())),
+                NftSort::Name | NftSort::Quantity => nfts.sort_by(|(a, _), (b, _)| a.cmp(b)),
+            }
+
+            let tokens = nfts.into_iter().map(|(_, token)| token.clone()).collect();
+            Some((title, PolicyGroup::new(group.policy_id.clone(), tokens)))
+        })
+        .collect();
+
+    match sort {
+        NftSort::Name => visible.sort_by_cached_key(|(title, _)| title.to_lowercase()),
+        NftSort::RecentlyAcquired => visible.sort_by_cached_key(|(title, group)| {
+            let newest = group.tokens.iter().filter_map(acquired).max();
+            (Reverse(newest), title.to_lowercase())
+        }),
+        NftSort::Quantity => visible
+            .sort_by_cached_key(|(title, group)| (Reverse(group.nft_count), title.to_lowercase())),
+    }
+    visible.into_iter().map(|(_, group)| group).collect()
+}
 
 /// Gallery view for displaying NFTs grouped by policy
 #[component]
@@ -47,9 +178,29 @@ pub fn WalletNftGallery(
     #[prop(optional, default = true)]
     show_summary: bool,
 
-    /// Empty state message
-    #[prop(into, optional, default = "No NFTs found".into())]
-    empty_message: String,
+    /// Show the search, filter and sort toolbar
+    #[prop(optional, default = true)]
+    show_toolbar: bool,
+
+    /// Known collection names by policy id (folder titles and filter labels)
+    #[prop(into, optional)]
+    collection_names: Option<Signal<HashMap<String, String>>>,
+
+    /// When each asset (by asset_id) was acquired; enables "Recently acquired"
+    #[prop(into, optional)]
+    acquired_at: Option<Signal<HashMap<String, u64>>>,
+
+    /// Offer a multi-select mode
+    #[prop(optional)]
+    selectable: bool,
+
+    /// Selected asset ids (internal if not provided)
+    #[prop(optional)]
+    selection: Option<RwSignal<HashSet<String>>>,
+
+    /// Empty state message (default: localized "No NFTs found")
+    #[prop(into, optional)]
+    empty_message: Option<String>,
 
     /// Callback when an asset is clicked (receives asset_id and display_name)
     #[prop(into, optional)]
@@ -59,13 +210,76 @@ pub fn WalletNftGallery(
     #[prop(into, optional)]
     class: Option<String>,
 ) -> impl IntoView {
+    let locale = use_locale();
+    let empty_message =
+        empty_message.unwrap_or_else(|| locale.t_or("wallet_nft_gallery.empty", "No NFTs found"));
     let is_loading = move || loading.map(|s| s.get()).unwrap_or(false);
 
+    let search = RwSignal::new(String::new());
+    let debounced_search = use_debounced_signal(search, SEARCH_DEBOUNCE_MS);
+    // Empty = all collections
+    let policy_filter = RwSignal::new(String::new());
+    let sort = RwSignal::new(NftSort::default());
+    let select_mode = RwSignal::new(false);
+    let selection = selection.unwrap_or_else(|| RwSignal::new(HashSet::new()));
+    // Folders opened by hand; every match is open while searching
+    let opened = RwSignal::new(HashSet::<String>::new());
+
+    let names = move || collection_names.map(|s| s.get()).unwrap_or_default();
+
+    let visible = Memo::new(move |_| {
+        let policy = policy_filter.get();
+        visible_groups(
+            &groups.get(),
+            (!policy.is_empty()).then_some(policy.as_str()),
+            &debounced_search.get(),
+            sort.get(),
+            &acquired_at.map(|s| s.get()).unwrap_or_default(),
+            &names(),
+        )
+    });
+
     // Compute totals for summary
     let nft_count = Memo::new(move |_| groups.get().iter().map(|g| g.nft_count).sum::<usize>());
+    let visible_count =
+        Memo::new(move |_| visible.get().iter().map(|g| g.nft_count).sum::<usize>());
 
     let policy_count = Memo::new(move |_| groups.get().len());
 
+    let policy_options = Signal::derive(move || {
+        let names = names();
+        let mut options = vec![SelectOption::new(
+            "",
+            locale.t_or("wallet_nft_gallery.all_collections", "All collections"),
+        )];
+        let mut groups = groups.get();
+        groups.sort_by_cached_key(|g| group_title(g, &names).to_lowercase());
+        options.extend(groups.iter().map(|g| {
+            let label = format!("{} ({})", group_title(g, &names), g.nft_count);
+            SelectOption::new(g.policy_id.clone(), label)
+        }));
+        options
+    });
+
+    let sort_options = Signal::derive(move || {
+        let has_times = acquired_at.is_some();
+        NftSort::ALL
+            .into_iter()
+            .filter(|s| has_times || *s != NftSort::RecentlyAcquired)
+            .map(|s| SelectOption::new(s.value(), locale.t_or(s.message_key(), s.label())))
+            .collect::<Vec<_>>()
+    });
+
+    let select_visible = move || {
+        let ids: Vec<String> = visible.with(|groups| {
+            groups
+                .iter()
+                .flat_map(|g| g.tokens.iter().map(NativeToken::asset_id))
+                .collect()
+        });
+        selection.update(|s| s.extend(ids));
+    };
+
     let wrapper_class = {
         let class = class.clone();
         move || {
@@ -83,7 +297,7 @@ pub fn WalletNftGallery(
             {move || is_loading().then(|| view! {
                 <div class="ui-nft-gallery__loading">
                     <Spinner />
-                    <span>"Loading NFTs..."</span>
+                    <span>{locale.t_or("wallet_nft_gallery.loading", "Loading NFTs...")}</span>
                 </div>
             })}
 
@@ -92,8 +306,78 @@ pub fn WalletNftGallery(
                 <div class="ui-nft-gallery__summary">
                     <span class="ui-nft-gallery__count">{nft_count.get()}</span>
                     <span class="ui-nft-gallery__policies">
-                        " NFTs across " {policy_count.get()} " policies"
+                        " "
+                        {locale.t_or_with(
+                            "wallet_nft_gallery.summary",
+                            "NFTs across {policies} policies",
+                            &[("policies", policy_count.get().to_string())],
+                        )}
+                    </span>
+                </div>
+            })}
+
+            // Search, filter and sort
+            {move || (show_toolbar && !is_loading() && nft_count.get() > 0).then(|| view! {
+                <div class="ui-nft-gallery__toolbar">
+                    <TextInput
+                        value=search
+                        on_change=Callback::new(move |value| search.set(value))
+                        placeholder=locale.t_or("wallet_nft_gallery.search", "Search NFTs")
+                        class="ui-nft-gallery__search"
+                    />
+                    <Select
+                        value=policy_filter
+                        options=policy_options
+                        on_change=Callback::new(move |value| policy_filter.set(value))
+                    />
+                    <Select
+                        value=Signal::derive(move || sort.get().value().to_string())
+                        options=sort_options
+                        on_change=Callback::new(move |value: String| {
+                            sort.set(NftSort::from_value(&value).unwrap_or_default())
+                        })
+                    />
+                    {selectable.then(|| view! {
+                        <Button
+                            variant=ButtonVariant::Secondary
+                            size=ButtonSize::Sm
+                            on_click=Callback::new(move |_| select_mode.update(|on| *on = !*on))
+                        >
+                            {move || if select_mode.get() {
+                                locale.t_or("wallet_nft_gallery.done", "Done")
+                            } else {
+                                locale.t_or("wallet_nft_gallery.select", "Select")
+                            }}
+                        </Button>
+                    })}
+                </div>
+            })}
+
+            // Selection
+            {move || (select_mode.get() && !is_loading()).then(|| view! {
+                <div class="ui-nft-gallery__selection" role="status">
+                    <span class="ui-nft-gallery__selected-count">
+                        {move || locale.t_or_with(
+                            "wallet_nft_gallery.selected",
+                            "{count} selected",
+                            &[("count", selection.with(HashSet::len).to_string())],
+                        )}
                     </span>
+                    <Button
+                        variant=ButtonVariant::Ghost
+                        size=ButtonSize::Sm
+                        on_click=Callback::new(move |_| select_visible())
+                    >
+                        {move || locale.t_or("wallet_nft_gallery.select_all", "Select all")}
+                    </Button>
+                    <Button
+                        variant=ButtonVariant::Ghost
+                        size=ButtonSize::Sm
+                        disabled=Signal::derive(move || selection.with(HashSet::is_empty))
+                        on_click=Callback::new(move |_| selection.update(HashSet::clear))
+                    >
+                        {move || locale.t_or("wallet_nft_gallery.clear", "Clear")}
+                    </Button>
                 </div>
             })}
 
@@ -102,16 +386,61 @@ pub fn WalletNftGallery(
                 <EmptyState message=empty_message.clone() />
             })}
 
+            // Nothing matches the search or filter
+            {move || (!is_loading() && nft_count.get() > 0 && visible_count.get() == 0).then(|| view! {
+                <div class="ui-nft-gallery__empty">
+                    {locale.t_or("wallet_nft_gallery.no_matches", "No NFTs match your search")}
+                </div>
+            })}
+
             // Policy folders
             {move || {
-                let current_groups = groups.get();
+                let current_groups = visible.get();
+                let names = names();
+                let searching = !debounced_search.get().trim().is_empty();
                 (!is_loading() && !current_groups.is_empty()).then(|| view! {
                     <div class="ui-nft-gallery__folders">
                         {current_groups.into_iter().map(|group| {
+                            let title = group_title(&group, &names);
+                            let policy_id = group.policy_id.clone();
+                            let expanded = {
+                                let policy_id = policy_id.clone();
+                                Signal::derive(move || searching || opened.with(|o| o.contains(&policy_id)))
+                            };
+                            let on_toggle = Callback::new(move |open: bool| {
+                                opened.update(|o| {
+                                    if open {
+                                        o.insert(policy_id.clone());
+                                    } else {
+                                        o.remove(&policy_id);
+                                    }
+                                });
+                            });
                             if let Some(cb) = on_asset_click {
-                                view! { <PolicyFolder group=group on_asset_click=cb /> }.into_any()
+                                view! {
+                                    <PolicyFolder
+                                        group=group
+                                        title=title
+                                        expanded=expanded
+                                        on_toggle=on_toggle
+                                        on_asset_click=cb
+                                        selection=selection
+                                        select_mode=select_mode
+                                    />
+                                }
+                                .into_any()
                             } else {
-                                view! { <PolicyFolder group=group /> }.into_any()
+                                view! {
+                                    <PolicyFolder
+                                        group=group
+                                        title=title
+                                        expanded=expanded
+                                        on_toggle=on_toggle
+                                        selection=selection
+                                        select_mode=select_mode
+                                    />
+                                }
+                                .into_any()
                             }
                         }).collect::<Vec<_>>()}
                     </div>
@@ -120,3 +449,146 @@ pub fn WalletNftGallery(
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICY_A: &str = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    const POLICY_B: &str = "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+
+    fn token(policy_id: &str, name: &str) -> NativeToken {
+        NativeToken {
+            policy_id: policy_id.to_string(),
+            asset_name_hex: name.bytes().map(|b| format!("{b:02x}")).collect(),
+            asset_name: Some(name.to_string()),
+            quantity: 1,
+        }
+    }
+
+    fn groups() -> Vec<PolicyGroup> {
+        vec![
+            PolicyGroup::new(
+                POLICY_A.into(),
+                vec![token(POLICY_A, "zebra"), token(POLICY_A, "apple")],
+            ),
+            PolicyGroup::new(
+                POLICY_B.into(),
+                vec![
+                    token(POLICY_B, "kiwi"),
+                    token(POLICY_B, "mango"),
+                    token(POLICY_B, "pear"),
+                ],
+            ),
+        ]
+    }
+
+    fn names(groups: &[PolicyGroup]) -> Vec<Vec<String>> {
+        groups
+            .iter()
+            .map(|g| g.tokens.iter().map(NativeToken::display_name).collect())
+            .collect()
+    }
+
+    fn collections() -> HashMap<String, String> {
+        HashMap::from([
+            (POLICY_A.to_string(), "Zoo".to_string()),
+            (POLICY_B.to_string(), "Fruit".to_string()),
+        ])
+    }
+
+    #[test]
+    fn sorts_by_name() {
+        let visible = visible_groups(
+            &groups(),
+            None,
+            "",
+            NftSort::Name,
+            &HashMap::new(),
+            &collections(),
+        );
+        assert_eq!(
+            names(&visible),
+            [vec!["kiwi", "mango", "pear"], vec!["apple", "zebra"]]
+        );
+    }
+
+    #[test]
+    fn sorts_by_quantity() {
+        let visible = visible_groups(
+            &groups(),
+            None,
+            "",
+            NftSort::Quantity,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+        assert_eq!(visible[0].policy_id, POLICY_B);
+    }
+
+    #[test]
+    fn sorts_by_recently_acquired() {
+        let acquired = HashMap::from([
+            (token(POLICY_A, "zebra").asset_id(), 300),
+            (token(POLICY_B, "pear").asset_id(), 200),
+        ]);
+        let visible = visible_groups(
+            &groups(),
+            None,
+            "",
+            NftSort::RecentlyAcquired,
+            &acquired,
+            &HashMap::new(),
+        );
+        assert_eq!(
+            names(&visible),
+            [vec!["zebra", "apple"], vec!["pear", "kiwi", "mango"]]
+        );
+    }
+
+    #[test]
+    fn searches_names_and_collections() {
+        let visible = visible_groups(
+            &groups(),
+            None,
+            " AN ",
+            NftSort::Name,
+            &HashMap::new(),
+            &collections(),
+        );
+        assert_eq!(names(&visible), [vec!["mango"]]);
+        assert_eq!(visible[0].nft_count, 1);
+
+        let visible = visible_groups(
+            &groups(),
+            None,
+            "zoo",
+            NftSort::Name,
+            &HashMap::new(),
+            &collections(),
+        );
+        assert_eq!(names(&visible), [vec!["apple", "zebra"]]);
+    }
+
+    #[test]
+    fn filters_by_policy() {
+        let visible = visible_groups(
+            &groups(),
+            Some(POLICY_A),
+            "",
+            NftSort::Name,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0].policy_id, POLICY_A);
+    }
+
+    #[test]
+    fn sort_values_round_trip() {
+        for sort in NftSort::ALL {
+            assert_eq!(NftSort::from_value(sort.value()), Some(sort));
+        }
+        assert_eq!(NftSort::from_value("bogus"), None);
+    }
+}
@@ -0,0 +1,43 @@
+//! Widget config context
+//!
+//! Leptos adapter over [`ui_core::config`]. Load the config once at startup,
+//! provide it at the app root, and read it anywhere below without threading
+//! it through props.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_components::{provide_widget_config, use_widget_config};
+//! use ui_core::ConfigLoader;
+//!
+//! let config = ConfigLoader::new().load().await?;
+//! config.clone().install(); // for non-Leptos code and `urls::endpoints()`
+//!
+//! mount_to_body(move || {
+//!     provide_widget_config(config);
+//!     view! { <App /> }
+//! });
+//!
+//! // In any component
+//! let config = use_widget_config();
+//! let title = config.branding.app_name.clone().unwrap_or_default();
+//! ```
+
+use leptos::prelude::*;
+use ui_core::config::{self, WidgetConfig};
+
+/// Context wrapper so other `WidgetConfig` contexts don't collide
+#[derive(Clone)]
+struct WidgetConfigContext(StoredValue<WidgetConfig>);
+
+/// Provide a loaded config to descendant components
+pub fn provide_widget_config(config: WidgetConfig) {
+    provide_context(WidgetConfigContext(StoredValue::new(config)));
+}
+
+/// The provided config, or the installed/same-origin default outside a provider
+pub fn use_widget_config() -> WidgetConfig {
+    use_context::<WidgetConfigContext>()
+        .map(|ctx| ctx.0.get_value())
+        .unwrap_or_else(config::config)
+}
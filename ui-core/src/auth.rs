@@ -3,7 +3,9 @@
 //! Provides authentication state management with explicit error variants.
 //! When authenticated, an `AuthContext` holds the token and decoded claims.
 
-use crate::token::{decode_token_claims, WidgetClaims};
+use crate::token::{
+    decode_and_validate, decode_token_claims, TokenError, Validation, WidgetClaims,
+};
 
 /// Authentication state
 #[derive(Debug, Clone)]
@@ -38,6 +40,15 @@ impl AuthContext {
         Some(Self { token, claims })
     }
 
+    /// Create a new auth context from a token, validating audience/issuer/expiry
+    pub fn from_token_validated(
+        token: String,
+        validation: &Validation,
+    ) -> Result<Self, TokenError> {
+        let claims = decode_and_validate(&token, validation)?;
+        Ok(Self { token, claims })
+    }
+
     /// Create a mock auth context for dev mode
     ///
     /// This creates a context that behaves like authenticated but uses mock claims.
@@ -94,6 +105,31 @@ impl AuthContext {
     pub fn avatar_url(&self) -> Option<String> {
         self.claims.avatar_url()
     }
+
+    /// Get the roles granted to the user
+    pub fn roles(&self) -> &[String] {
+        &self.claims.roles
+    }
+
+    /// Check if the user holds a role (platform admins implicitly hold "admin")
+    pub fn has_role(&self, role: &str) -> bool {
+        self.claims.has_role(role)
+    }
+
+    /// Get the scopes granted to the token
+    pub fn scopes(&self) -> Vec<&str> {
+        self.claims.scopes().collect()
+    }
+
+    /// Check if the token was granted a scope
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.claims.has_scope(scope)
+    }
+
+    /// Get the user's preferred locale if the token carries one
+    pub fn locale(&self) -> Option<&str> {
+        self.claims.locale.as_deref()
+    }
 }
 
 impl AuthState {
@@ -120,6 +156,26 @@ impl AuthState {
         }
     }
 
+    /// Create auth state from a token string, applying claim validation
+    ///
+    /// Like [`AuthState::from_token`], but also checks audience, issuer,
+    /// not-before, and expiry (with leeway) per `validation`.
+    pub fn from_token_validated(token: Option<String>, validation: &Validation) -> Self {
+        match token.filter(|t| !t.is_empty()) {
+            Some(token) => match AuthContext::from_token_validated(token, validation) {
+                Ok(ctx) => Self::Authenticated(Box::new(ctx)),
+                Err(TokenError::Expired) => Self::TokenExpired,
+                Err(e) => Self::AuthError(e.to_string()),
+            },
+            None => Self::Anonymous,
+        }
+    }
+
+    /// Check if the authenticated user holds a role
+    pub fn has_role(&self, role: &str) -> bool {
+        self.context().is_some_and(|ctx| ctx.has_role(role))
+    }
+
     /// Create auth state from URL query parameters
     ///
     /// Looks for a `token` parameter in the current URL.
@@ -0,0 +1,333 @@
+//! Server-aware wall clock
+//!
+//! Deadlines come from the server, but the client's clock can be minutes
+//! off, so countdowns computed from `Date.now()` drift. The clock keeps an
+//! offset to server time, learned from a ping/pong round trip or an HTTP
+//! `Date` header, and [`now_ms`] applies it.
+//!
+//! - [`now_ms`] - estimated server time in unix ms
+//! - [`record_round_trip`] - learn the offset from a ping/pong exchange
+//! - [`record_date_header`] - learn it from an HTTP response's `Date` header
+//! - [`set_offset`] - inject a known offset
+//! - [`remaining_ms`] / [`countdown`] - time left until a deadline
+//! - [`format_duration`] / [`format_relative`] - humanized durations
+//!
+//! The offset is kept per page; a better sample (shorter round trip)
+//! replaces a worse one, and any sample replaces one older than
+//! [`RESAMPLE_AFTER_MS`] so the offset follows a clock that was changed or
+//! slept.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use ui_core::clock;
+//!
+//! // From a ui-flow Pong: the ping's timestamp, the server's, and arrival
+//! clock::record_round_trip(client_ts, server_ts, clock::local_now_ms());
+//!
+//! // From an HTTP response
+//! let sent = clock::local_now_ms();
+//! let response = Request::get(url).send().await?;
+//! if let Some(date) = response.headers().get("date") {
+//!     clock::record_date_header(&date, sent, clock::local_now_ms());
+//! }
+//!
+//! let label = clock::format_duration(clock::remaining_ms(deadline));
+//! let _timer = clock::countdown(deadline, 1000, |left| render(left));
+//! ```
+
+use std::cell::Cell;
+
+use gloo_timers::callback::Interval;
+use wallet_core::calendar::days_from_civil;
+
+use crate::i18n::translate_or;
+
+/// A sample older than this is replaced by any new one
+pub const RESAMPLE_AFTER_MS: u64 = 5 * 60 * 1000;
+
+/// An estimate of how far the server's clock is ahead of ours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockSample {
+    /// Server time minus local time
+    pub offset_ms: i64,
+    /// How far off the estimate may be
+    pub uncertainty_ms: u64,
+}
+
+impl ClockSample {
+    /// Estimate from a request sent at `sent_ms` that the server stamped
+    /// `server_ms`, answered at `received_ms` (both local)
+    ///
+    /// Assumes the server stamped it halfway through the round trip.
+    pub fn from_round_trip(sent_ms: u64, server_ms: u64, received_ms: u64) -> Self {
+        let half_trip = received_ms.saturating_sub(sent_ms) / 2;
+        let midpoint = sent_ms + half_trip;
+        Self {
+            offset_ms: server_ms as i64 - midpoint as i64,
+            uncertainty_ms: half_trip,
+        }
+    }
+
+    /// Whether to replace `current`, taken at local time `taken_ms`, with
+    /// this sample at local time `now_ms`
+    fn replaces(&self, current: Option<(ClockSample, u64)>, now_ms: u64) -> bool {
+        match current {
+            None => true,
+            Some((current, taken_ms)) => {
+                self.uncertainty_ms <= current.uncertainty_ms
+                    || now_ms.saturating_sub(taken_ms) >= RESAMPLE_AFTER_MS
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// Best sample so far and the local time it was taken
+    static SAMPLE: Cell<Option<(ClockSample, u64)>> = const { Cell::new(None) };
+}
+
+/// The client's own clock, in unix ms
+pub fn local_now_ms() -> u64 {
+    js_sys::Date::now() as u64
+}
+
+/// Estimated server time, in unix ms
+pub fn now_ms() -> u64 {
+    local_now_ms().saturating_add_signed(offset_ms())
+}
+
+/// Server time minus local time; 0 until a sample is recorded
+pub fn offset_ms() -> i64 {
+    SAMPLE.with(|s| s.get().map_or(0, |(sample, _)| sample.offset_ms))
+}
+
+/// Use `offset_ms` as the server offset until a better sample arrives
+pub fn set_offset(offset_ms: i64) {
+    record(ClockSample {
+        offset_ms,
+        uncertainty_ms: 0,
+    });
+}
+
+/// Forget the offset and trust the local clock again
+pub fn reset() {
+    SAMPLE.with(|s| s.set(None));
+}
+
+/// Learn the offset from a ping sent at `sent_ms`, answered with the
+/// server's time `server_ms`, received at `received_ms`
+///
+/// Returns whether the sample was used.
+pub fn record_round_trip(sent_ms: u64, server_ms: u64, received_ms: u64) -> bool {
+    record(ClockSample::from_round_trip(
+        sent_ms,
+        server_ms,
+        received_ms,
+    ))
+}
+
+/// Learn the offset from an HTTP `Date` header on a response to a request
+/// sent at `sent_ms` and received at `received_ms`
+///
+/// Returns whether the header parsed and the sample was used. The header
+/// only has whole seconds, so it is less trusted than a round trip.
+pub fn record_date_header(date: &str, sent_ms: u64, received_ms: u64) -> bool {
+    let Some(server_ms) = parse_http_date(date) else {
+        return false;
+    };
+    // The true time is somewhere in the stamped second
+    let mut sample = ClockSample::from_round_trip(sent_ms, server_ms + 500, received_ms);
+    sample.uncertainty_ms += 500;
+    record(sample)
+}
+
+fn record(sample: ClockSample) -> bool {
+    let now = local_now_ms();
+    SAMPLE.with(|s| {
+        let replace = sample.replaces(s.get(), now);
+        if replace {
+            s.set(Some((sample, now)));
+        }
+        replace
+    })
+}
+
+/// Time left until `deadline_ms` (server time), or 0 once it has passed
+pub fn remaining_ms(deadline_ms: u64) -> u64 {
+    deadline_ms.saturating_sub(now_ms())
+}
+
+/// A running [`countdown`]; stops when dropped
+pub struct CountdownTimer {
+    _interval: Interval,
+}
+
+/// Call `on_tick` with the time left until `deadline_ms` now and every
+/// `tick_ms`, ending with a final call at 0
+pub fn countdown(
+    deadline_ms: u64,
+    tick_ms: u32,
+    on_tick: impl Fn(u64) + 'static,
+) -> CountdownTimer {
+    let finished = Cell::new(false);
+    let tick = move || {
+        if finished.get() {
+            return;
+        }
+        let left = remaining_ms(deadline_ms);
+        finished.set(left == 0);
+        on_tick(left);
+    };
+    tick();
+    CountdownTimer {
+        _interval: Interval::new(tick_ms, tick),
+    }
+}
+
+/// Format a duration as "3d 04h", "2h 05m", "5m 30s" or "45s"
+///
+/// Partial seconds round up, so a countdown never shows 0s early.
+pub fn format_duration(ms: u64) -> String {
+    let secs = ms.div_ceil(1000);
+    let (days, hours) = (secs / 86_400, (secs % 86_400) / 3600);
+    let (mins, secs) = ((secs % 3600) / 60, secs % 60);
+    if days > 0 {
+        format!("{days}d {hours:02}h")
+    } else if hours > 0 {
+        format!("{hours}h {mins:02}m")
+    } else if mins > 0 {
+        format!("{mins}m {secs:02}s")
+    } else {
+        format!("{secs}s")
+    }
+}
+
+/// Describe `target_ms` relative to `now_ms`: "in 5 minutes", "2 hours
+/// ago", "just now"
+///
+/// Uses the `clock.*` i18n keys, with English defaults.
+pub fn format_relative(target_ms: u64, now_ms: u64) -> String {
+    const UNITS: [(&str, u64); 3] = [("day", 86_400), ("hour", 3600), ("minute", 60)];
+
+    let future = target_ms > now_ms;
+    let secs = target_ms.abs_diff(now_ms) / 1000;
+    if secs < 45 {
+        return translate_or("clock.just_now", "just now", &[]);
+    }
+
+    // Largest unit with at least one whole step, rounded to nearest
+    let (unit, size) = UNITS
+        .into_iter()
+        .find(|&(_, size)| secs >= size)
+        .unwrap_or(("minute", 60));
+    let count = ((secs + size / 2) / size).max(1);
+    let plural = if count == 1 { "" } else { "s" };
+
+    let (key, default) = if future {
+        (
+            format!("clock.in_{unit}{plural}"),
+            format!("in {{count}} {unit}{plural}"),
+        )
+    } else {
+        (
+            format!("clock.{unit}{plural}_ago"),
+            format!("{{count}} {unit}{plural} ago"),
+        )
+    };
+    translate_or(&key, &default, &[("count", count.to_string())])
+}
+
+/// Parse an HTTP date (`Sun, 06 Nov 1994 08:49:37 GMT`) to unix ms
+///
+/// Only the IMF-fixdate form servers are required to send is accepted.
+pub fn parse_http_date(value: &str) -> Option<u64> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let (_weekday, rest) = value.trim().split_once(", ")?;
+    let mut parts = rest.split(' ');
+    let day: u32 = parts.next()?.parse().ok()?;
+    let month_name = parts.next()?;
+    let month = MONTHS.iter().position(|&m| m == month_name)? as u32 + 1;
+    let year: i64 = parts.next()?.parse().ok()?;
+    let mut time = parts.next()?.split(':').map(|p| p.parse::<u64>().ok());
+    let (hour, min, sec) = (time.next()??, time.next()??, time.next()??);
+    if parts.next()? != "GMT" || parts.next().is_some() || time.next().is_some() {
+        return None;
+    }
+    if !(1..=31).contains(&day) || hour > 23 || min > 59 || sec > 60 {
+        return None;
+    }
+
+    let days = u64::try_from(days_from_civil(year, month, day)).ok()?;
+    Some((days * 86_400 + hour * 3600 + min * 60 + sec) * 1000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_sample() {
+        // Sent at 1000, back at 1200; the server said 6100 at the midpoint
+        let sample = ClockSample::from_round_trip(1_000, 6_100, 1_200);
+        assert_eq!(sample.offset_ms, 5_000);
+        assert_eq!(sample.uncertainty_ms, 100);
+
+        let behind = ClockSample::from_round_trip(10_000, 7_000, 10_000);
+        assert_eq!(behind.offset_ms, -3_000);
+    }
+
+    #[test]
+    fn test_better_or_fresher_samples_replace() {
+        let tight = ClockSample {
+            offset_ms: 10,
+            uncertainty_ms: 20,
+        };
+        let loose = ClockSample {
+            offset_ms: 90,
+            uncertainty_ms: 400,
+        };
+        assert!(loose.replaces(None, 0));
+        assert!(tight.replaces(Some((loose, 0)), 1_000));
+        assert!(!loose.replaces(Some((tight, 0)), 1_000));
+        assert!(loose.replaces(Some((tight, 0)), RESAMPLE_AFTER_MS));
+    }
+
+    #[test]
+    fn test_parse_http_date() {
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(784_111_777_000)
+        );
+        assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"), Some(0));
+        assert_eq!(
+            parse_http_date("Thu, 29 Feb 2024 12:00:00 GMT"),
+            Some(1_709_208_000_000)
+        );
+        assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 PST"), None);
+        assert_eq!(parse_http_date("Sun, 06 Foo 1994 08:49:37 GMT"), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(44_001), "45s");
+        assert_eq!(format_duration(330_000), "5m 30s");
+        assert_eq!(format_duration(7_500_000), "2h 05m");
+        assert_eq!(format_duration(273_600_000), "3d 04h");
+    }
+
+    #[test]
+    fn test_format_relative() {
+        let now = 1_000_000_000;
+        assert_eq!(format_relative(now + 10_000, now), "just now");
+        assert_eq!(format_relative(now + 60_000, now), "in 1 minute");
+        assert_eq!(format_relative(now + 300_000, now), "in 5 minutes");
+        assert_eq!(format_relative(now - 2 * 3_600_000, now), "2 hours ago");
+        assert_eq!(format_relative(now - 36 * 3_600_000, now), "2 days ago");
+    }
+}
@@ -1,7 +1,42 @@
 //! Color utilities for widget styling
 //!
 //! Provides helpers for working with colors, including contrast detection
-//! for determining optimal text colors on colored backgrounds.
+//! for determining optimal text colors on colored backgrounds, and palette
+//! extraction from images for art-derived accents.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use ui_core::color::{contrast_text_for, dominant_colors, rgb_to_hex};
+//!
+//! let palette = dominant_colors(&image_url, 3).await?;
+//! if let Some(&accent) = palette.first() {
+//!     let style = format!(
+//!         "--accent: {}; --accent-text: {}",
+//!         rgb_to_hex(accent),
+//!         contrast_text_for(accent),
+//!     );
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::error::WidgetError;
+
+/// An RGB color
+pub type Rgb = (u8, u8, u8);
+
+/// Edge length of the canvas images are downsampled to before sampling
+const SAMPLE_SIZE: u32 = 64;
+
+/// Pixels more transparent than this are ignored when sampling
+const MIN_ALPHA: u8 = 128;
+
+/// Minimum squared RGB distance between returned palette colors
+const MIN_PALETTE_DISTANCE_SQ: u32 = 48 * 48;
 
 /// Determines the optimal text color (black or white) for a given background color.
 ///
@@ -26,21 +61,160 @@
 /// ```
 pub fn optimal_text_color(hex_color: &str) -> &'static str {
     match parse_hex_color(hex_color) {
-        Some((r, g, b)) => {
-            let luminance = relative_luminance(r, g, b);
-            // Threshold of 0.35 balances readability:
-            // - Light colors (yellow, light green, white) get black text
-            // - Most saturated colors get white text
-            if luminance > 0.35 {
-                "#000000" // Light background -> black text
-            } else {
-                "#ffffff" // Dark background -> white text
-            }
-        }
+        Some(rgb) => contrast_text_for(rgb),
         None => "#ffffff", // Default to white text on parse failure
     }
 }
 
+/// Determines the optimal text color (black or white) for an RGB background.
+///
+/// Same threshold as [`optimal_text_color`], for colors that are already
+/// parsed (e.g. from [`dominant_colors`]).
+///
+/// # Example
+/// ```
+/// use ui_core::color::contrast_text_for;
+///
+/// assert_eq!(contrast_text_for((255, 255, 0)), "#000000");
+/// assert_eq!(contrast_text_for((0, 114, 181)), "#ffffff");
+/// ```
+pub fn contrast_text_for(bg: Rgb) -> &'static str {
+    let (r, g, b) = bg;
+    // Threshold of 0.35 balances readability:
+    // - Light colors (yellow, light green, white) get black text
+    // - Most saturated colors get white text
+    if relative_luminance(r, g, b) > 0.35 {
+        "#000000" // Light background -> black text
+    } else {
+        "#ffffff" // Dark background -> white text
+    }
+}
+
+/// Formats an RGB color as a lowercase `#rrggbb` hex string.
+pub fn rgb_to_hex(color: Rgb) -> String {
+    let (r, g, b) = color;
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// Extracts up to `n` dominant colors from an image, most common first.
+///
+/// The image is downsampled onto a canvas and its pixels quantized (see
+/// [`palette_from_pixels`]). The image must be same-origin or served with
+/// CORS headers, otherwise the canvas is tainted and sampling fails.
+pub async fn dominant_colors(image_url: &str, n: usize) -> Result<Vec<Rgb>, WidgetError> {
+    let image = load_image(image_url).await?;
+
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| WidgetError::Other("No document available".to_string()))?;
+
+    let canvas: web_sys::HtmlCanvasElement = document
+        .create_element("canvas")
+        .map_err(|e| WidgetError::Other(format!("Failed to create canvas: {e:?}")))?
+        .unchecked_into();
+    canvas.set_width(SAMPLE_SIZE);
+    canvas.set_height(SAMPLE_SIZE);
+
+    let ctx: web_sys::CanvasRenderingContext2d = canvas
+        .get_context("2d")
+        .ok()
+        .flatten()
+        .ok_or_else(|| WidgetError::Other("Canvas 2D context unavailable".to_string()))?
+        .unchecked_into();
+
+    let size = SAMPLE_SIZE as f64;
+    ctx.draw_image_with_html_image_element_and_dw_and_dh(&image, 0.0, 0.0, size, size)
+        .map_err(|e| WidgetError::Other(format!("Failed to draw image: {e:?}")))?;
+
+    let data = ctx
+        .get_image_data(0.0, 0.0, size, size)
+        .map_err(|_| {
+            WidgetError::Other(format!(
+                "Cannot read pixels from {image_url} (missing CORS headers?)"
+            ))
+        })?
+        .data();
+
+    Ok(palette_from_pixels(&data, n))
+}
+
+/// Loads an image with CORS enabled and waits for it to decode.
+async fn load_image(url: &str) -> Result<web_sys::HtmlImageElement, WidgetError> {
+    let image = web_sys::HtmlImageElement::new()
+        .map_err(|e| WidgetError::Other(format!("Failed to create image: {e:?}")))?;
+    image.set_cross_origin(Some("anonymous"));
+
+    let loaded = js_sys::Promise::new(&mut |resolve, reject| {
+        image.set_onload(Some(&resolve));
+        image.set_onerror(Some(&reject));
+    });
+    image.set_src(url);
+
+    let result = JsFuture::from(loaded).await;
+    image.set_onload(None);
+    image.set_onerror(None);
+
+    result
+        .map(|_| image)
+        .map_err(|_| WidgetError::Network(format!("Failed to load image: {url}")))
+}
+
+/// Quantizes RGBA pixel data into up to `n` dominant colors, most common first.
+///
+/// Pixels are bucketed at 4 bits per channel; each bucket's color is the
+/// average of its pixels. Mostly-transparent pixels are skipped, and colors
+/// too close to an already chosen one are dropped so the palette stays varied.
+pub fn palette_from_pixels(rgba: &[u8], n: usize) -> Vec<Rgb> {
+    #[derive(Default)]
+    struct Bucket {
+        count: u32,
+        r: u32,
+        g: u32,
+        b: u32,
+    }
+
+    let mut buckets: HashMap<u16, Bucket> = HashMap::new();
+    for px in rgba.chunks_exact(4) {
+        if px[3] < MIN_ALPHA {
+            continue;
+        }
+        let key = ((px[0] as u16 >> 4) << 8) | ((px[1] as u16 >> 4) << 4) | (px[2] as u16 >> 4);
+        let bucket = buckets.entry(key).or_default();
+        bucket.count += 1;
+        bucket.r += px[0] as u32;
+        bucket.g += px[1] as u32;
+        bucket.b += px[2] as u32;
+    }
+
+    let mut ranked: Vec<(u16, Bucket)> = buckets.into_iter().collect();
+    // Tie-break on key so output is deterministic
+    ranked.sort_by(|(ka, a), (kb, b)| b.count.cmp(&a.count).then(ka.cmp(kb)));
+
+    let mut palette: Vec<Rgb> = Vec::with_capacity(n);
+    for (_, bucket) in ranked {
+        if palette.len() >= n {
+            break;
+        }
+        let color = (
+            (bucket.r / bucket.count) as u8,
+            (bucket.g / bucket.count) as u8,
+            (bucket.b / bucket.count) as u8,
+        );
+        if palette
+            .iter()
+            .all(|&chosen| distance_sq(chosen, color) >= MIN_PALETTE_DISTANCE_SQ)
+        {
+            palette.push(color);
+        }
+    }
+    palette
+}
+
+fn distance_sq(a: Rgb, b: Rgb) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
 /// Calculates the relative luminance of an RGB color.
 ///
 /// Uses the WCAG 2.0 formula for relative luminance:
@@ -55,6 +229,23 @@ pub fn relative_luminance(r: u8, g: u8, b: u8) -> f64 {
     0.2126 * r + 0.7152 * g + 0.0722 * b
 }
 
+/// WCAG contrast ratio between two colors, from 1.0 (identical) to 21.0.
+///
+/// WCAG AA asks for at least 4.5 for body text and 3.0 for large text.
+///
+/// # Example
+/// ```
+/// use ui_core::color::contrast_ratio;
+///
+/// assert_eq!(contrast_ratio((0, 0, 0), (255, 255, 255)).round(), 21.0);
+/// ```
+pub fn contrast_ratio(a: Rgb, b: Rgb) -> f64 {
+    let la = relative_luminance(a.0, a.1, a.2);
+    let lb = relative_luminance(b.0, b.1, b.2);
+    let (lighter, darker) = if la > lb { (la, lb) } else { (lb, la) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
 /// Linearizes an sRGB color component (gamma correction).
 fn linearize(c: f64) -> f64 {
     if c <= 0.03928 {
@@ -88,6 +279,44 @@ pub fn parse_hex_color(hex: &str) -> Option<(u8, u8, u8)> {
     }
 }
 
+/// Parses a CSS color as returned by `getComputedStyle` into RGB and alpha.
+///
+/// Supports `rgb()`/`rgba()` in both comma and space syntax, hex colors and
+/// `transparent`. Alpha is 0.0-1.0.
+pub fn parse_css_color(css: &str) -> Option<(Rgb, f64)> {
+    let css = css.trim();
+    if css.eq_ignore_ascii_case("transparent") {
+        return Some(((0, 0, 0), 0.0));
+    }
+    if css.starts_with('#') {
+        return parse_hex_color(css).map(|rgb| (rgb, 1.0));
+    }
+
+    let inner = css
+        .strip_prefix("rgba(")
+        .or_else(|| css.strip_prefix("rgb("))?
+        .strip_suffix(')')?;
+    let parts: Vec<&str> = inner
+        .split([',', ' ', '/'])
+        .filter(|p| !p.is_empty())
+        .collect();
+    if !(3..=4).contains(&parts.len()) {
+        return None;
+    }
+
+    let channel =
+        |p: &str| -> Option<u8> { Some(p.parse::<f64>().ok()?.round().clamp(0.0, 255.0) as u8) };
+    let rgb = (channel(parts[0])?, channel(parts[1])?, channel(parts[2])?);
+    let alpha = match parts.get(3) {
+        Some(a) => match a.strip_suffix('%') {
+            Some(pct) => pct.parse::<f64>().ok()? / 100.0,
+            None => a.parse::<f64>().ok()?,
+        },
+        None => 1.0,
+    };
+    Some((rgb, alpha.clamp(0.0, 1.0)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,6 +344,37 @@ mod tests {
         assert_eq!(optimal_text_color("#000"), "#ffffff");
     }
 
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((contrast_ratio((0, 0, 0), (255, 255, 255)) - 21.0).abs() < 0.01);
+        assert_eq!(contrast_ratio((10, 20, 30), (10, 20, 30)), 1.0);
+        // Symmetric
+        assert_eq!(
+            contrast_ratio((0, 114, 181), (255, 255, 255)),
+            contrast_ratio((255, 255, 255), (0, 114, 181))
+        );
+        // #888 on #1a1a2e only just passes AA body text
+        let muted = contrast_ratio((136, 136, 136), (26, 26, 46));
+        assert!(muted > 4.0 && muted < 5.5);
+    }
+
+    #[test]
+    fn test_parse_css_color() {
+        assert_eq!(
+            parse_css_color("rgb(255, 0, 10)"),
+            Some(((255, 0, 10), 1.0))
+        );
+        assert_eq!(
+            parse_css_color("rgba(0, 0, 0, 0.5)"),
+            Some(((0, 0, 0), 0.5))
+        );
+        assert_eq!(parse_css_color("rgb(1 2 3 / 25%)"), Some(((1, 2, 3), 0.25)));
+        assert_eq!(parse_css_color("#fff"), Some(((255, 255, 255), 1.0)));
+        assert_eq!(parse_css_color("transparent"), Some(((0, 0, 0), 0.0)));
+        assert_eq!(parse_css_color("hsl(0, 0%, 0%)"), None);
+        assert_eq!(parse_css_color("rgb(1, 2)"), None);
+    }
+
     #[test]
     fn test_parse_hex_color() {
         assert_eq!(parse_hex_color("#ffffff"), Some((255, 255, 255)));
@@ -126,4 +386,52 @@ mod tests {
         assert_eq!(parse_hex_color("invalid"), None);
         assert_eq!(parse_hex_color("#gg0000"), None);
     }
+
+    /// An RGBA color and how many pixels of it to emit
+    type PixelRun = ((u8, u8, u8, u8), usize);
+
+    fn pixels(colors: &[PixelRun]) -> Vec<u8> {
+        colors
+            .iter()
+            .flat_map(|&((r, g, b, a), count)| std::iter::repeat_n([r, g, b, a], count))
+            .flatten()
+            .collect()
+    }
+
+    #[test]
+    fn test_palette_orders_by_frequency() {
+        let data = pixels(&[
+            ((200, 30, 30, 255), 10),
+            ((20, 20, 220, 255), 30),
+            ((240, 240, 240, 255), 5),
+        ]);
+
+        let palette = palette_from_pixels(&data, 3);
+        assert_eq!(palette, vec![(20, 20, 220), (200, 30, 30), (240, 240, 240)]);
+        assert_eq!(palette_from_pixels(&data, 1), vec![(20, 20, 220)]);
+    }
+
+    #[test]
+    fn test_palette_skips_transparent_and_similar() {
+        let data = pixels(&[
+            ((0, 0, 0, 0), 100),
+            ((100, 100, 100, 255), 20),
+            ((112, 100, 100, 255), 10),
+            ((250, 250, 0, 255), 5),
+        ]);
+
+        let palette = palette_from_pixels(&data, 3);
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette[1], (250, 250, 0));
+        assert!(palette_from_pixels(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn test_contrast_text_for_matches_hex() {
+        for hex in ["#ffffff", "#000000", "#0072b5", "#7dc45c", "#c45cc4"] {
+            let rgb = parse_hex_color(hex).unwrap();
+            assert_eq!(contrast_text_for(rgb), optimal_text_color(hex));
+            assert_eq!(rgb_to_hex(rgb), hex);
+        }
+    }
 }
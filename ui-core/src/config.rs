@@ -0,0 +1,618 @@
+//! Typed widget configuration
+//!
+//! [`WidgetConfig`] gathers the settings a widget needs at startup (API bases,
+//! feature endpoints, branding) from three layers, later layers winning:
+//!
+//! 1. Build-time defaults (`WIDGET_*` env vars read with `option_env!`, or a
+//!    [`PartialConfig`] passed to [`ConfigLoader::with_defaults`])
+//! 2. A runtime `/config.json` served next to the widget
+//! 3. URL params prefixed with `config.` (e.g. `?config.api_base=...`)
+//!
+//! Anything still unset falls back to the page location, as [`Endpoints`]
+//! does. The merged result is validated, so a typo in `config.json` or a
+//! malformed URL fails with a [`ConfigError`] naming the offending field.
+//!
+//! URL overrides can point API calls (and the auth token) at another host, so
+//! they are only honored outside production unless explicitly allowed.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use ui_core::config::{config, ConfigLoader};
+//!
+//! let cfg = ConfigLoader::new().load().await?;
+//! cfg.install(); // also installs `urls::endpoints()`
+//!
+//! let leaderboard = config().feature_url("leaderboard");
+//! ```
+//!
+//! `config.json`:
+//!
+//! ```json
+//! {
+//!   "api_base": "https://api.example.com",
+//!   "features": { "leaderboard": "/v1/leaderboard" },
+//!   "branding": { "app_name": "Pirate Games", "accent_color": "#d4a017" }
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::color::parse_hex_color;
+use crate::error::WidgetError;
+use crate::http::get_text;
+use crate::urls::{Endpoints, Environment, DEFAULT_IIIF_BASE};
+
+/// Where [`ConfigLoader`] looks for the runtime config by default
+pub const DEFAULT_CONFIG_PATH: &str = "/config.json";
+
+/// Prefix for URL params that override config fields
+pub const QUERY_PREFIX: &str = "config.";
+
+/// Errors from loading or validating configuration
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// A URL field has the wrong scheme or is malformed
+    #[error("config `{field}`: `{value}` is not a valid {expected} URL")]
+    InvalidUrl {
+        field: String,
+        value: String,
+        expected: &'static str,
+    },
+
+    /// A color field is not a hex color
+    #[error("config `{field}`: `{value}` is not a hex color")]
+    InvalidColor { field: String, value: String },
+
+    /// A URL param named an unknown field
+    #[error("config: unknown URL param `{0}`")]
+    UnknownParam(String),
+
+    /// The runtime config could not be parsed
+    #[error("config.json: {0}")]
+    Parse(String),
+
+    /// The runtime config could not be fetched
+    #[error("config.json: {0}")]
+    Fetch(String),
+}
+
+impl From<ConfigError> for WidgetError {
+    fn from(e: ConfigError) -> Self {
+        match e {
+            ConfigError::Fetch(msg) => WidgetError::Network(msg),
+            ConfigError::Parse(msg) => WidgetError::Parse(msg),
+            other => WidgetError::Other(other.to_string()),
+        }
+    }
+}
+
+/// Display settings for white-labelled widgets
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Branding {
+    /// Product name shown in headers and titles
+    pub app_name: Option<String>,
+    /// Logo image URL
+    pub logo_url: Option<String>,
+    /// Accent color as `#RRGGBB` or `#RGB`
+    pub accent_color: Option<String>,
+}
+
+impl Branding {
+    fn merge(self, over: Branding) -> Branding {
+        Branding {
+            app_name: over.app_name.or(self.app_name),
+            logo_url: over.logo_url.or(self.logo_url),
+            accent_color: over.accent_color.or(self.accent_color),
+        }
+    }
+}
+
+/// One configuration layer; unset fields defer to earlier layers
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PartialConfig {
+    pub api_base: Option<String>,
+    pub ws_base: Option<String>,
+    pub iiif_base: Option<String>,
+    /// Feature name -> endpoint (absolute URL or path on `api_base`)
+    pub features: BTreeMap<String, String>,
+    pub branding: Branding,
+}
+
+impl PartialConfig {
+    /// Defaults baked in at compile time from `WIDGET_*` env vars
+    ///
+    /// Reads `WIDGET_API_BASE`, `WIDGET_WS_BASE`, `WIDGET_IIIF_BASE`,
+    /// `WIDGET_APP_NAME`, `WIDGET_LOGO_URL` and `WIDGET_ACCENT_COLOR`.
+    pub fn from_build_env() -> Self {
+        let var = |v: Option<&str>| v.filter(|s| !s.is_empty()).map(str::to_string);
+        Self {
+            api_base: var(option_env!("WIDGET_API_BASE")),
+            ws_base: var(option_env!("WIDGET_WS_BASE")),
+            iiif_base: var(option_env!("WIDGET_IIIF_BASE")),
+            features: BTreeMap::new(),
+            branding: Branding {
+                app_name: var(option_env!("WIDGET_APP_NAME")),
+                logo_url: var(option_env!("WIDGET_LOGO_URL")),
+                accent_color: var(option_env!("WIDGET_ACCENT_COLOR")),
+            },
+        }
+    }
+
+    /// Parse a `config.json` document, rejecting unknown fields
+    pub fn from_json(json: &str) -> Result<Self, ConfigError> {
+        serde_json::from_str(json).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+
+    /// Collect `config.*` params from a query string (with or without `?`)
+    ///
+    /// Other params are ignored; unknown `config.*` params are an error.
+    ///
+    /// # Example
+    /// ```
+    /// use ui_core::config::PartialConfig;
+    ///
+    /// let layer = PartialConfig::from_query(
+    ///     "?token=abc&config.api_base=http%3A%2F%2Flocalhost%3A8787&config.features.chat=/chat",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(layer.api_base.as_deref(), Some("http://localhost:8787"));
+    /// assert_eq!(layer.features["chat"], "/chat");
+    /// ```
+    pub fn from_query(query: &str) -> Result<Self, ConfigError> {
+        let mut layer = Self::default();
+        let query = query.strip_prefix('?').unwrap_or(query);
+
+        for pair in query.split('&').filter(|p| !p.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let key = decode_component(key);
+            let Some(field) = key.strip_prefix(QUERY_PREFIX) else {
+                continue;
+            };
+            let value = decode_component(value);
+
+            match field {
+                "api_base" => layer.api_base = Some(value),
+                "ws_base" => layer.ws_base = Some(value),
+                "iiif_base" => layer.iiif_base = Some(value),
+                "branding.app_name" => layer.branding.app_name = Some(value),
+                "branding.logo_url" => layer.branding.logo_url = Some(value),
+                "branding.accent_color" => layer.branding.accent_color = Some(value),
+                _ => match field.strip_prefix("features.") {
+                    Some(name) if !name.is_empty() => {
+                        layer.features.insert(name.to_string(), value);
+                    }
+                    _ => return Err(ConfigError::UnknownParam(key)),
+                },
+            }
+        }
+
+        Ok(layer)
+    }
+
+    /// Overlay `over` on top of this layer
+    pub fn merge(mut self, over: PartialConfig) -> PartialConfig {
+        self.features.extend(over.features);
+        PartialConfig {
+            api_base: over.api_base.or(self.api_base),
+            ws_base: over.ws_base.or(self.ws_base),
+            iiif_base: over.iiif_base.or(self.iiif_base),
+            features: self.features,
+            branding: self.branding.merge(over.branding),
+        }
+    }
+
+    /// Fill unset bases from `fallback` and validate every field
+    pub fn resolve(self, fallback: &Endpoints) -> Result<WidgetConfig, ConfigError> {
+        let base = |value: Option<String>, default: &str| {
+            value
+                .unwrap_or_else(|| default.to_string())
+                .trim_end_matches('/')
+                .to_string()
+        };
+        let config = WidgetConfig {
+            api_base: base(self.api_base, fallback.api_base()),
+            ws_base: base(self.ws_base, fallback.ws_base()),
+            iiif_base: base(self.iiif_base, DEFAULT_IIIF_BASE),
+            features: self.features,
+            branding: self.branding,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// Validated widget configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WidgetConfig {
+    /// HTTP API base, without trailing slash
+    pub api_base: String,
+    /// WebSocket base, without trailing slash
+    pub ws_base: String,
+    /// IIIF image server base, without trailing slash
+    pub iiif_base: String,
+    /// Feature name -> endpoint (absolute URL or path on `api_base`)
+    pub features: BTreeMap<String, String>,
+    pub branding: Branding,
+}
+
+impl WidgetConfig {
+    /// Check URL schemes and colors
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check_url(
+            "api_base",
+            &self.api_base,
+            &["http://", "https://"],
+            "http(s)",
+        )?;
+        check_url("ws_base", &self.ws_base, &["ws://", "wss://"], "ws(s)")?;
+        check_url(
+            "iiif_base",
+            &self.iiif_base,
+            &["http://", "https://"],
+            "http(s)",
+        )?;
+
+        for (name, endpoint) in &self.features {
+            if !endpoint.starts_with('/') {
+                check_url(
+                    &format!("features.{name}"),
+                    endpoint,
+                    &["http://", "https://", "ws://", "wss://"],
+                    "absolute or path",
+                )?;
+            }
+        }
+
+        if let Some(logo) = &self.branding.logo_url {
+            if !logo.starts_with('/') {
+                check_url("branding.logo_url", logo, &["https://", "http://"], "image")?;
+            }
+        }
+        if let Some(color) = &self.branding.accent_color {
+            if !color.starts_with('#') || parse_hex_color(color).is_none() {
+                return Err(ConfigError::InvalidColor {
+                    field: "branding.accent_color".to_string(),
+                    value: color.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Endpoint for a named feature, with paths joined onto `api_base`
+    pub fn feature_url(&self, name: &str) -> Option<String> {
+        let endpoint = self.features.get(name)?;
+        if endpoint.starts_with('/') {
+            Some(format!("{}{endpoint}", self.api_base))
+        } else {
+            Some(endpoint.clone())
+        }
+    }
+
+    /// Whether a feature endpoint is configured
+    pub fn has_feature(&self, name: &str) -> bool {
+        self.features.contains_key(name)
+    }
+
+    /// URL builders for the configured bases
+    pub fn endpoints(&self) -> Endpoints {
+        Endpoints::new(&self.api_base, &self.ws_base).with_iiif_base(&self.iiif_base)
+    }
+
+    /// Make this the config returned by [`config()`], and install its
+    /// [`Endpoints`] as [`crate::urls::endpoints()`]
+    pub fn install(self) {
+        self.endpoints().install();
+        WIDGET_CONFIG.with(|c| *c.borrow_mut() = Some(self));
+    }
+}
+
+impl Default for WidgetConfig {
+    /// Same-origin bases from the page location, no features or branding
+    fn default() -> Self {
+        let endpoints = Endpoints::from_location();
+        Self {
+            api_base: endpoints.api_base().to_string(),
+            ws_base: endpoints.ws_base().to_string(),
+            iiif_base: DEFAULT_IIIF_BASE.to_string(),
+            features: BTreeMap::new(),
+            branding: Branding::default(),
+        }
+    }
+}
+
+thread_local! {
+    /// Installed config (WASM is single-threaded)
+    static WIDGET_CONFIG: RefCell<Option<WidgetConfig>> = const { RefCell::new(None) };
+}
+
+/// Get the installed config, or the same-origin default
+pub fn config() -> WidgetConfig {
+    WIDGET_CONFIG.with(|c| c.borrow().clone().unwrap_or_default())
+}
+
+/// Loads and merges the configuration layers
+///
+/// # Example
+/// ```ignore
+/// let cfg = ConfigLoader::new()
+///     .with_defaults(PartialConfig {
+///         api_base: Some("https://api.example.com".into()),
+///         ..Default::default()
+///     })
+///     .load()
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConfigLoader {
+    defaults: PartialConfig,
+    config_url: Option<String>,
+    query: Option<String>,
+    url_overrides: bool,
+}
+
+impl Default for ConfigLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfigLoader {
+    /// Build-time defaults, `/config.json`, and URL overrides outside production
+    pub fn new() -> Self {
+        Self {
+            defaults: PartialConfig::from_build_env(),
+            config_url: Some(DEFAULT_CONFIG_PATH.to_string()),
+            query: None,
+            url_overrides: Environment::detect() != Environment::Production,
+        }
+    }
+
+    /// Layer `defaults` over the build-time env defaults
+    pub fn with_defaults(mut self, defaults: PartialConfig) -> Self {
+        self.defaults = self.defaults.merge(defaults);
+        self
+    }
+
+    /// Fetch the runtime config from `url` instead of [`DEFAULT_CONFIG_PATH`]
+    pub fn config_url(mut self, url: impl Into<String>) -> Self {
+        self.config_url = Some(url.into());
+        self
+    }
+
+    /// Skip the runtime config fetch
+    pub fn without_runtime_config(mut self) -> Self {
+        self.config_url = None;
+        self
+    }
+
+    /// Read overrides from `query` instead of the page URL
+    pub fn with_query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Honor (or ignore) `config.*` URL params regardless of environment
+    pub fn allow_url_overrides(mut self, allow: bool) -> Self {
+        self.url_overrides = allow;
+        self
+    }
+
+    /// Fetch the runtime config, merge all layers and validate
+    ///
+    /// A missing `config.json` (HTTP 404) is not an error.
+    pub async fn load(self) -> Result<WidgetConfig, ConfigError> {
+        let runtime = match &self.config_url {
+            Some(url) => fetch_runtime_config(url).await?,
+            None => PartialConfig::default(),
+        };
+        self.resolve(runtime)
+    }
+
+    /// Merge `runtime` with the defaults and URL overrides, then validate
+    pub fn resolve(self, runtime: PartialConfig) -> Result<WidgetConfig, ConfigError> {
+        let mut merged = self.defaults.merge(runtime);
+
+        if self.url_overrides {
+            let query = self.query.or_else(current_search).unwrap_or_default();
+            merged = merged.merge(PartialConfig::from_query(&query)?);
+        }
+
+        merged.resolve(&Endpoints::from_location())
+    }
+}
+
+async fn fetch_runtime_config(url: &str) -> Result<PartialConfig, ConfigError> {
+    match get_text(url, None).await {
+        Ok(text) => PartialConfig::from_json(&text),
+        Err(WidgetError::Http { status: 404, .. }) => Ok(PartialConfig::default()),
+        Err(e) => Err(ConfigError::Fetch(e.to_string())),
+    }
+}
+
+/// The page's query string, or `None` outside a browser
+fn current_search() -> Option<String> {
+    if cfg!(target_arch = "wasm32") {
+        web_sys::window()?.location().search().ok()
+    } else {
+        None
+    }
+}
+
+fn check_url(
+    field: &str,
+    value: &str,
+    schemes: &[&str],
+    expected: &'static str,
+) -> Result<(), ConfigError> {
+    let host = schemes
+        .iter()
+        .find_map(|scheme| value.strip_prefix(scheme))
+        .map(|rest| rest.split(['/', '?', '#']).next().unwrap_or(rest));
+
+    match host {
+        Some(host) if !host.is_empty() && !host.contains(char::is_whitespace) => Ok(()),
+        _ => Err(ConfigError::InvalidUrl {
+            field: field.to_string(),
+            value: value.to_string(),
+            expected,
+        }),
+    }
+}
+
+/// Decode a `application/x-www-form-urlencoded` component
+fn decode_component(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => match (hex_val(bytes[i + 1]), hex_val(bytes[i + 2])) {
+                (Some(hi), Some(lo)) => {
+                    out.push((hi << 4) | lo);
+                    i += 2;
+                }
+                _ => out.push(b'%'),
+            },
+            byte => out.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn hex_val(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|d| d as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local() -> Endpoints {
+        Endpoints::for_host("localhost:8787")
+    }
+
+    #[test]
+    fn test_layers_merge_in_order() {
+        let defaults = PartialConfig {
+            api_base: Some("https://build.example.com".into()),
+            iiif_base: Some("https://img.example.com/iiif/".into()),
+            branding: Branding {
+                app_name: Some("Build".into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let runtime = PartialConfig::from_json(
+            r##"{"api_base": "https://runtime.example.com",
+                "features": {"leaderboard": "/v1/leaderboard"},
+                "branding": {"accent_color": "#d4a017"}}"##,
+        )
+        .unwrap();
+        let url =
+            PartialConfig::from_query("config.features.chat=wss%3A%2F%2Fchat.example.com").unwrap();
+
+        let cfg = defaults
+            .merge(runtime)
+            .merge(url)
+            .resolve(&local())
+            .unwrap();
+
+        assert_eq!(cfg.api_base, "https://runtime.example.com");
+        assert_eq!(cfg.ws_base, "ws://localhost:8787");
+        assert_eq!(cfg.iiif_base, "https://img.example.com/iiif");
+        assert_eq!(cfg.branding.app_name.as_deref(), Some("Build"));
+        assert_eq!(cfg.branding.accent_color.as_deref(), Some("#d4a017"));
+        assert_eq!(
+            cfg.feature_url("leaderboard").as_deref(),
+            Some("https://runtime.example.com/v1/leaderboard")
+        );
+        assert_eq!(
+            cfg.feature_url("chat").as_deref(),
+            Some("wss://chat.example.com")
+        );
+        assert_eq!(cfg.feature_url("missing"), None);
+    }
+
+    #[test]
+    fn test_validation_names_field() {
+        let bad_ws = PartialConfig {
+            ws_base: Some("https://rt.example.com".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            bad_ws.resolve(&local()),
+            Err(ConfigError::InvalidUrl {
+                field: "ws_base".into(),
+                value: "https://rt.example.com".into(),
+                expected: "ws(s)",
+            })
+        );
+
+        let bad_feature = PartialConfig::from_query("config.features.stats=stats").unwrap();
+        let err = bad_feature.resolve(&local()).unwrap_err();
+        assert!(err.to_string().contains("features.stats"));
+
+        let bad_color = PartialConfig::from_json(r#"{"branding": {"accent_color": "gold"}}"#)
+            .unwrap()
+            .resolve(&local());
+        assert!(matches!(bad_color, Err(ConfigError::InvalidColor { .. })));
+    }
+
+    #[test]
+    fn test_runtime_config_rejects_unknown_fields() {
+        let err = PartialConfig::from_json(r#"{"api_bsae": "https://x"}"#).unwrap_err();
+        assert!(matches!(err, ConfigError::Parse(msg) if msg.contains("api_bsae")));
+    }
+
+    #[test]
+    fn test_query_params() {
+        let layer = PartialConfig::from_query(
+            "?token=abc&config.branding.app_name=Pirate+Games&config.ws_base=wss://rt.example.com",
+        )
+        .unwrap();
+        assert_eq!(layer.branding.app_name.as_deref(), Some("Pirate Games"));
+        assert_eq!(layer.ws_base.as_deref(), Some("wss://rt.example.com"));
+        assert_eq!(layer.api_base, None);
+
+        assert_eq!(
+            PartialConfig::from_query("config.apibase=x"),
+            Err(ConfigError::UnknownParam("config.apibase".into()))
+        );
+    }
+
+    #[test]
+    fn test_loader_ignores_url_overrides_when_disallowed() {
+        let loader = || {
+            ConfigLoader::new()
+                .without_runtime_config()
+                .with_defaults(PartialConfig {
+                    api_base: Some("https://api.example.com".into()),
+                    ..Default::default()
+                })
+                .with_query("config.api_base=https://evil.example.com")
+        };
+
+        let cfg = loader()
+            .allow_url_overrides(false)
+            .resolve(PartialConfig::default())
+            .unwrap();
+        assert_eq!(cfg.api_base, "https://api.example.com");
+
+        let cfg = loader()
+            .allow_url_overrides(true)
+            .resolve(PartialConfig::default())
+            .unwrap();
+        assert_eq!(cfg.api_base, "https://evil.example.com");
+    }
+}
@@ -22,11 +22,38 @@
 //!     FetchState::Failed(err) => show_error(err),
 //! }
 //! ```
+//!
+//! ## Composing fetches
+//!
+//! Dependent and parallel fetches compose without nested matches:
+//!
+//! ```ignore
+//! // Both must be loaded before rendering the summary
+//! let summary = profile.as_ref().zip(inventory.as_ref()).map(|(p, i)| Summary::new(p, i));
+//!
+//! // Derive a value that may itself be unavailable
+//! let avatar = profile.as_ref().and_then(|p| match &p.avatar {
+//!     Some(url) => FetchState::Loaded(url.clone()),
+//!     None => FetchState::Failed("No avatar".to_string()),
+//! });
+//! ```
+//!
+//! ## Framework adapters
+//!
+//! With Seed, turn a request future straight into a message:
+//!
+//! ```ignore
+//! orders.perform_cmd(async { Msg::UserLoaded(FetchState::from_future(fetch_user()).await) });
+//! ```
+//!
+//! For Leptos, `ui_components::use_fetch` wraps this in signals.
+
+use std::fmt::Display;
+use std::future::Future;
 
 /// Represents the state of an async fetch operation.
 /// Use pattern matching to handle the different states.
-#[derive(Debug, Clone, PartialEq)]
-#[derive(Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum FetchState<T> {
     /// No fetch has been initiated
     #[default]
@@ -85,5 +112,204 @@ impl<T> FetchState<T> {
             FetchState::Failed(err) => FetchState::Failed(err),
         }
     }
+
+    /// Chain a computation that can itself be idle, loading, or failed
+    pub fn and_then<U, F: FnOnce(T) -> FetchState<U>>(self, f: F) -> FetchState<U> {
+        match self {
+            FetchState::Idle => FetchState::Idle,
+            FetchState::Loading => FetchState::Loading,
+            FetchState::Loaded(data) => f(data),
+            FetchState::Failed(err) => FetchState::Failed(err),
+        }
+    }
+
+    /// Map the error message
+    pub fn map_err<F: FnOnce(String) -> String>(self, f: F) -> FetchState<T> {
+        match self {
+            FetchState::Failed(err) => FetchState::Failed(f(err)),
+            other => other,
+        }
+    }
+
+    /// Combine two states into one holding both values
+    ///
+    /// The result is `Failed` if either failed (first error wins), `Loading`
+    /// if either is still loading, `Idle` if either is idle, and `Loaded`
+    /// only when both are loaded.
+    pub fn zip<U>(self, other: FetchState<U>) -> FetchState<(T, U)> {
+        match (self, other) {
+            (FetchState::Loaded(a), FetchState::Loaded(b)) => FetchState::Loaded((a, b)),
+            (FetchState::Failed(err), _) | (_, FetchState::Failed(err)) => FetchState::Failed(err),
+            (FetchState::Loading, _) | (_, FetchState::Loading) => FetchState::Loading,
+            _ => FetchState::Idle,
+        }
+    }
+
+    /// Borrow the loaded data without consuming the state
+    pub fn as_ref(&self) -> FetchState<&T> {
+        match self {
+            FetchState::Idle => FetchState::Idle,
+            FetchState::Loading => FetchState::Loading,
+            FetchState::Loaded(data) => FetchState::Loaded(data),
+            FetchState::Failed(err) => FetchState::Failed(err.clone()),
+        }
+    }
+
+    /// Take the loaded data, discarding other states
+    pub fn into_data(self) -> Option<T> {
+        match self {
+            FetchState::Loaded(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Get the loaded data or a default value
+    pub fn unwrap_or(self, default: T) -> T {
+        self.into_data().unwrap_or(default)
+    }
+
+    /// Build a state from a finished request
+    pub fn from_result<E: Display>(result: Result<T, E>) -> Self {
+        match result {
+            Ok(data) => FetchState::Loaded(data),
+            Err(err) => FetchState::Failed(err.to_string()),
+        }
+    }
+
+    /// Await a request future and convert its result into a state
+    ///
+    /// Framework-agnostic - suitable for Seed's `orders.perform_cmd`.
+    pub async fn from_future<E, Fut>(fut: Fut) -> Self
+    where
+        E: Display,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        Self::from_result(fut.await)
+    }
+}
+
+impl<T, E: Display> From<Result<T, E>> for FetchState<T> {
+    fn from(result: Result<T, E>) -> Self {
+        Self::from_result(result)
+    }
+}
+
+/// Attempt tracking for a fetch that may be retried
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetryMeta {
+    /// Number of attempts started (including the first)
+    pub attempts: u32,
+    /// Consecutive failures since the last success
+    pub consecutive_failures: u32,
+    /// Error from the most recent failed attempt
+    pub last_error: Option<String>,
+    /// Maximum attempts allowed (`None` for unlimited)
+    pub max_attempts: Option<u32>,
+}
+
+impl RetryMeta {
+    /// Create retry metadata with an attempt limit
+    pub fn with_max_attempts(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: Some(max_attempts),
+            ..Self::default()
+        }
+    }
+
+    /// Record that an attempt has started
+    pub fn record_attempt(&mut self) {
+        self.attempts += 1;
+    }
+
+    /// Record a failed attempt
+    pub fn record_failure(&mut self, error: impl Into<String>) {
+        self.consecutive_failures += 1;
+        self.last_error = Some(error.into());
+    }
+
+    /// Record a successful attempt
+    pub fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.last_error = None;
+    }
+
+    /// Whether this is a retry rather than the first attempt
+    pub fn is_retry(&self) -> bool {
+        self.attempts > 1
+    }
+
+    /// Whether another attempt is allowed
+    pub fn can_retry(&self) -> bool {
+        self.max_attempts.is_none_or(|max| self.attempts < max)
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_and_then() {
+        let loaded: FetchState<u32> = FetchState::Loaded(2);
+        assert_eq!(
+            loaded.clone().and_then(|n| FetchState::Loaded(n * 2)),
+            FetchState::Loaded(4)
+        );
+        assert_eq!(
+            loaded.and_then(|_| FetchState::<u32>::Failed("nope".into())),
+            FetchState::Failed("nope".into())
+        );
+        assert_eq!(
+            FetchState::<u32>::Loading.and_then(FetchState::Loaded),
+            FetchState::Loading
+        );
+    }
+
+    #[test]
+    fn test_zip() {
+        let a: FetchState<u32> = FetchState::Loaded(1);
+        let b: FetchState<&str> = FetchState::Loaded("x");
+        assert_eq!(a.clone().zip(b.clone()), FetchState::Loaded((1, "x")));
+        assert_eq!(
+            a.clone().zip(FetchState::<&str>::Loading),
+            FetchState::Loading
+        );
+        assert_eq!(
+            FetchState::<u32>::Loading.zip(FetchState::<&str>::Failed("err".into())),
+            FetchState::Failed("err".into())
+        );
+        assert_eq!(FetchState::<u32>::Idle.zip(b), FetchState::Idle);
+        assert_eq!(
+            FetchState::<u32>::Idle.zip(FetchState::<&str>::Loading),
+            FetchState::Loading
+        );
+    }
+
+    #[test]
+    fn test_from_result() {
+        let ok: FetchState<u32> = Ok::<_, String>(5).into();
+        assert_eq!(ok, FetchState::Loaded(5));
+
+        let err: FetchState<u32> = Err::<u32, _>("boom").into();
+        assert_eq!(err.error(), Some("boom"));
+    }
+
+    #[test]
+    fn test_retry_meta() {
+        let mut meta = RetryMeta::with_max_attempts(2);
+        assert!(meta.can_retry());
+
+        meta.record_attempt();
+        meta.record_failure("timeout");
+        assert!(!meta.is_retry());
+        assert_eq!(meta.last_error.as_deref(), Some("timeout"));
+        assert!(meta.can_retry());
+
+        meta.record_attempt();
+        meta.record_success();
+        assert!(meta.is_retry());
+        assert_eq!(meta.consecutive_failures, 0);
+        assert!(meta.last_error.is_none());
+        assert!(!meta.can_retry());
+    }
+}
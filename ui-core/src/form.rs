@@ -0,0 +1,499 @@
+//! Form field state, DOM bindings and validation
+//!
+//! A [`Field`] holds one form value plus its validation error. The `bind_*`
+//! helpers keep a field and a raw DOM control in sync in both directions, so
+//! custom elements and non-Leptos widgets don't have to read
+//! `HtmlInputElement::value()` by hand in every event handler. The DOM side
+//! also gets `aria-invalid` while the field is in error.
+//!
+//! - [`bind_input_value`] - text-like `<input>` ↔ `Field<String>` (on `input`)
+//! - [`bind_checked`] - checkbox/radio `<input>` ↔ `Field<bool>` (on `change`)
+//! - [`bind_select_value`] - `<select>` ↔ `Field<String>` (on `change`)
+//!
+//! Validators are small composable rules; a field reports the first failing
+//! rule's message.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use ui_core::form::{bind_input_value, max_length, required, Field};
+//!
+//! let name = Field::new(String::new())
+//!     .with_validator(required("Name is required").and(max_length(32, "Too long")));
+//! let _binding = bind_input_value(&input_el, &name);
+//!
+//! let _sub = name.subscribe(|value| tracing::debug!("name is now {value}"));
+//! if name.validate() {
+//!     submit(name.get());
+//! }
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, Event, EventTarget, HtmlInputElement, HtmlSelectElement};
+
+// ============================================================================
+// Validation
+// ============================================================================
+
+type Rule<T> = Rc<dyn Fn(&T) -> Result<(), String>>;
+
+/// An ordered set of validation rules
+///
+/// Rules run in order and the first failure's message is returned.
+pub struct Validator<T: ?Sized> {
+    rules: Vec<Rule<T>>,
+}
+
+impl<T: ?Sized> Clone for Validator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rules: self.rules.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized + 'static> Validator<T> {
+    /// A validator from a single rule
+    pub fn new(rule: impl Fn(&T) -> Result<(), String> + 'static) -> Self {
+        Self {
+            rules: vec![Rc::new(rule)],
+        }
+    }
+
+    /// Also require `other` to pass, after this validator's rules
+    pub fn and(mut self, other: Validator<T>) -> Self {
+        self.rules.extend(other.rules);
+        self
+    }
+
+    /// Check `value`, returning the first failing rule's message
+    pub fn validate(&self, value: &T) -> Result<(), String> {
+        self.rules.iter().try_for_each(|rule| rule(value))
+    }
+}
+
+/// Pass when `predicate` holds, otherwise fail with `message`
+pub fn must<T: ?Sized + 'static>(
+    predicate: impl Fn(&T) -> bool + 'static,
+    message: impl Into<String>,
+) -> Validator<T> {
+    let message = message.into();
+    Validator::new(move |value| {
+        if predicate(value) {
+            Ok(())
+        } else {
+            Err(message.clone())
+        }
+    })
+}
+
+/// Non-blank text
+pub fn required(message: impl Into<String>) -> Validator<String> {
+    must(|value: &String| !value.trim().is_empty(), message)
+}
+
+/// At least `min` characters (empty values pass; combine with [`required`])
+pub fn min_length(min: usize, message: impl Into<String>) -> Validator<String> {
+    must(
+        move |value: &String| value.is_empty() || value.chars().count() >= min,
+        message,
+    )
+}
+
+/// At most `max` characters
+pub fn max_length(max: usize, message: impl Into<String>) -> Validator<String> {
+    must(move |value: &String| value.chars().count() <= max, message)
+}
+
+/// A number within `min..=max` (empty values pass; combine with [`required`])
+pub fn number_in_range(min: f64, max: f64, message: impl Into<String>) -> Validator<String> {
+    must(
+        move |value: &String| {
+            let value = value.trim();
+            value.is_empty() || value.parse::<f64>().is_ok_and(|n| (min..=max).contains(&n))
+        },
+        message,
+    )
+}
+
+/// A ticked checkbox, e.g. accepting terms
+pub fn checked(message: impl Into<String>) -> Validator<bool> {
+    must(|value: &bool| *value, message)
+}
+
+// ============================================================================
+// Field
+// ============================================================================
+
+type Listener<T> = Rc<dyn Fn(&T)>;
+
+struct FieldInner<T> {
+    value: RefCell<T>,
+    validator: RefCell<Option<Validator<T>>>,
+    error: RefCell<Option<String>>,
+    touched: Cell<bool>,
+    next_listener: Cell<u64>,
+    listeners: RefCell<Vec<(u64, Listener<T>)>>,
+}
+
+/// Shared state for one form value
+///
+/// Cloning shares the same value. Errors are recomputed on every
+/// [`set`](Self::set) but only reported once the field is touched, so an
+/// untouched empty form doesn't open covered in errors.
+pub struct Field<T> {
+    inner: Rc<FieldInner<T>>,
+}
+
+impl<T> Clone for Field<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Field<T> {
+    /// An untouched field holding `initial`
+    pub fn new(initial: T) -> Self {
+        Self {
+            inner: Rc::new(FieldInner {
+                value: RefCell::new(initial),
+                validator: RefCell::new(None),
+                error: RefCell::new(None),
+                touched: Cell::new(false),
+                next_listener: Cell::new(0),
+                listeners: RefCell::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Attach a validator, replacing any previous one
+    pub fn with_validator(self, validator: Validator<T>) -> Self {
+        *self.inner.validator.borrow_mut() = Some(validator);
+        self.revalidate();
+        self
+    }
+
+    /// Current value
+    pub fn get(&self) -> T {
+        self.inner.value.borrow().clone()
+    }
+
+    /// Replace the value, mark the field touched and notify subscribers
+    ///
+    /// Setting the current value again is a no-op.
+    pub fn set(&self, value: T) {
+        if *self.inner.value.borrow() == value {
+            return;
+        }
+        *self.inner.value.borrow_mut() = value;
+        self.inner.touched.set(true);
+        self.revalidate();
+        self.notify();
+    }
+
+    /// Whether the value has been changed since creation or [`reset`](Self::reset)
+    pub fn touched(&self) -> bool {
+        self.inner.touched.get()
+    }
+
+    /// Error to show, if the field is touched and invalid
+    pub fn error(&self) -> Option<String> {
+        if self.touched() {
+            self.inner.error.borrow().clone()
+        } else {
+            None
+        }
+    }
+
+    /// Whether the current value passes validation, touched or not
+    pub fn is_valid(&self) -> bool {
+        self.inner.error.borrow().is_none()
+    }
+
+    /// Mark touched (e.g. on submit) and return whether the value is valid
+    pub fn validate(&self) -> bool {
+        if !self.inner.touched.replace(true) {
+            self.notify();
+        }
+        self.is_valid()
+    }
+
+    /// Replace the value and clear the touched flag
+    pub fn reset(&self, value: T) {
+        *self.inner.value.borrow_mut() = value;
+        self.inner.touched.set(false);
+        self.revalidate();
+        self.notify();
+    }
+
+    /// Call `listener` with the value whenever it or the touched state changes
+    pub fn subscribe(&self, listener: impl Fn(&T) + 'static) -> FieldSubscription<T> {
+        let id = self.inner.next_listener.get();
+        self.inner.next_listener.set(id + 1);
+        self.inner
+            .listeners
+            .borrow_mut()
+            .push((id, Rc::new(listener)));
+        FieldSubscription {
+            field: Rc::downgrade(&self.inner),
+            id,
+        }
+    }
+
+    fn revalidate(&self) {
+        let error = self
+            .inner
+            .validator
+            .borrow()
+            .as_ref()
+            .and_then(|v| v.validate(&self.inner.value.borrow()).err());
+        *self.inner.error.borrow_mut() = error;
+    }
+
+    fn notify(&self) {
+        // Clone out so listeners may read, set or unsubscribe
+        let listeners: Vec<Listener<T>> = self
+            .inner
+            .listeners
+            .borrow()
+            .iter()
+            .map(|(_, f)| Rc::clone(f))
+            .collect();
+        let value = self.get();
+        for listener in listeners {
+            listener(&value);
+        }
+    }
+}
+
+/// Handle for a field listener; unsubscribes when dropped
+#[must_use = "the listener is removed when the subscription is dropped"]
+pub struct FieldSubscription<T> {
+    field: Weak<FieldInner<T>>,
+    id: u64,
+}
+
+impl<T> Drop for FieldSubscription<T> {
+    fn drop(&mut self) {
+        if let Some(inner) = self.field.upgrade() {
+            inner
+                .listeners
+                .borrow_mut()
+                .retain(|(id, _)| *id != self.id);
+        }
+    }
+}
+
+// ============================================================================
+// DOM bindings
+// ============================================================================
+
+/// A live field ↔ element binding; unbinds when dropped
+#[must_use = "the binding is removed when dropped"]
+pub struct Binding {
+    target: EventTarget,
+    event: &'static str,
+    callback: Closure<dyn FnMut(Event)>,
+    _subscription: Box<dyn std::any::Any>,
+}
+
+impl Drop for Binding {
+    fn drop(&mut self) {
+        let _ = self.target.remove_event_listener_with_callback(
+            self.event,
+            self.callback.as_ref().unchecked_ref(),
+        );
+    }
+}
+
+/// Keep a text-like `<input>` and a `Field<String>` in sync
+///
+/// The field follows every keystroke; setting the field updates the input.
+pub fn bind_input_value(input: &HtmlInputElement, field: &Field<String>) -> Binding {
+    input.set_value(&field.get());
+    bind(
+        input,
+        "input",
+        field,
+        {
+            let input = input.clone();
+            move || input.value()
+        },
+        {
+            let input = input.clone();
+            move |value: &String| {
+                // Skip no-op writes so the caret doesn't jump
+                if input.value() != *value {
+                    input.set_value(value);
+                }
+            }
+        },
+    )
+}
+
+/// Keep a checkbox or radio `<input>` and a `Field<bool>` in sync
+pub fn bind_checked(input: &HtmlInputElement, field: &Field<bool>) -> Binding {
+    input.set_checked(field.get());
+    bind(
+        input,
+        "change",
+        field,
+        {
+            let input = input.clone();
+            move || input.checked()
+        },
+        {
+            let input = input.clone();
+            move |value: &bool| input.set_checked(*value)
+        },
+    )
+}
+
+/// Keep a `<select>` and a `Field<String>` holding the selected option's value in sync
+pub fn bind_select_value(select: &HtmlSelectElement, field: &Field<String>) -> Binding {
+    select.set_value(&field.get());
+    bind(
+        select,
+        "change",
+        field,
+        {
+            let select = select.clone();
+            move || select.value()
+        },
+        {
+            let select = select.clone();
+            move |value: &String| {
+                if select.value() != *value {
+                    select.set_value(value);
+                }
+            }
+        },
+    )
+}
+
+fn bind<T: Clone + PartialEq + 'static>(
+    element: &Element,
+    event: &'static str,
+    field: &Field<T>,
+    read: impl Fn() -> T + 'static,
+    write: impl Fn(&T) + 'static,
+) -> Binding {
+    set_aria_invalid(element, field.error().is_some());
+
+    let callback = Closure::<dyn FnMut(Event)>::new({
+        let field = field.clone();
+        move |_: Event| field.set(read())
+    });
+    let target: EventTarget = element.clone().into();
+    let _ = target.add_event_listener_with_callback(event, callback.as_ref().unchecked_ref());
+
+    // Hold the field weakly so the binding doesn't keep it alive
+    let weak = Rc::downgrade(&field.inner);
+    let element = element.clone();
+    let subscription = field.subscribe(move |value| {
+        write(value);
+        if let Some(inner) = weak.upgrade() {
+            let field = Field { inner };
+            set_aria_invalid(&element, field.error().is_some());
+        }
+    });
+
+    Binding {
+        target,
+        event,
+        callback,
+        _subscription: Box::new(subscription),
+    }
+}
+
+fn set_aria_invalid(element: &Element, invalid: bool) {
+    if invalid {
+        let _ = element.set_attribute("aria-invalid", "true");
+    } else {
+        let _ = element.remove_attribute("aria-invalid");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validator_reports_first_failure() {
+        let v = required("required").and(min_length(3, "too short"));
+        assert_eq!(v.validate(&"  ".to_string()), Err("required".to_string()));
+        assert_eq!(v.validate(&"ab".to_string()), Err("too short".to_string()));
+        assert_eq!(v.validate(&"abc".to_string()), Ok(()));
+    }
+
+    #[test]
+    fn test_length_counts_chars() {
+        let v = max_length(3, "too long");
+        assert!(v.validate(&"₳₳₳".to_string()).is_ok());
+        assert!(v.validate(&"₳₳₳₳".to_string()).is_err());
+        // Empty passes min_length so optional fields stay optional
+        assert!(min_length(2, "short").validate(&String::new()).is_ok());
+    }
+
+    #[test]
+    fn test_number_in_range() {
+        let v = number_in_range(1.0, 10.0, "1 to 10");
+        assert!(v.validate(&"5".to_string()).is_ok());
+        assert!(v.validate(&" 10 ".to_string()).is_ok());
+        assert!(v.validate(&"".to_string()).is_ok());
+        assert!(v.validate(&"11".to_string()).is_err());
+        assert!(v.validate(&"abc".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_checked() {
+        let v = checked("accept the terms");
+        assert!(v.validate(&true).is_ok());
+        assert!(v.validate(&false).is_err());
+    }
+
+    #[test]
+    fn test_field_errors_wait_for_touch() {
+        let field = Field::new(String::new()).with_validator(required("required"));
+        assert!(!field.is_valid());
+        assert_eq!(field.error(), None);
+
+        assert!(!field.validate());
+        assert_eq!(field.error(), Some("required".to_string()));
+
+        field.set("ok".to_string());
+        assert_eq!(field.error(), None);
+
+        field.reset(String::new());
+        assert!(!field.touched());
+        assert_eq!(field.error(), None);
+    }
+
+    #[test]
+    fn test_field_subscription() {
+        let field = Field::new(0u32);
+        let seen = Rc::new(Cell::new(0));
+        let sub = field.subscribe({
+            let seen = seen.clone();
+            move |v| seen.set(*v)
+        });
+
+        field.set(3);
+        assert_eq!(seen.get(), 3);
+
+        // Same value doesn't notify
+        seen.set(0);
+        field.set(3);
+        assert_eq!(seen.get(), 0);
+
+        drop(sub);
+        field.set(4);
+        assert_eq!(seen.get(), 0);
+    }
+}
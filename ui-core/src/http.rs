@@ -4,6 +4,7 @@
 
 use crate::auth::AuthState;
 use crate::error::WidgetError;
+use crate::retry::{retry_async, RetryPolicy};
 use gloo_net::http::Request;
 use serde::de::DeserializeOwned;
 
@@ -37,6 +38,17 @@ pub async fn get_json<T: DeserializeOwned>(
     handle_response(response).await
 }
 
+/// GET JSON, retrying network errors, timeouts, 429 and 5xx per `policy`
+///
+/// Only for idempotent reads; auth errors and other 4xx return at once.
+pub async fn get_json_with_retry<T: DeserializeOwned>(
+    url: &str,
+    auth: Option<&AuthState>,
+    policy: &RetryPolicy,
+) -> Result<T, WidgetError> {
+    retry_async(policy, || get_json(url, auth)).await
+}
+
 /// Helper to make POST requests with JSON body
 ///
 /// Pass `Some(&auth)` for authenticated requests, or `None` for unauthenticated.
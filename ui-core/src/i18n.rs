@@ -0,0 +1,462 @@
+//! Internationalization with embedded message catalogs
+//!
+//! Catalogs are JSON files embedded at compile time with `include_str!` and
+//! registered once at startup. Nested objects are flattened into dot-separated
+//! keys, so `{"wallet": {"connect": "Connect"}}` is looked up as `wallet.connect`.
+//!
+//! The active locale is negotiated from the JWT `locale` claim (if present),
+//! then `navigator.languages`, then the fallback locale (`en` by default).
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use ui_core::i18n::{self, Catalog};
+//! use ui_core::t;
+//!
+//! i18n::register_catalog(Catalog::from_json("en", include_str!("../locales/en.json"))?);
+//! i18n::register_catalog(Catalog::from_json("de", include_str!("../locales/de.json"))?);
+//! i18n::init_locale(auth.context().map(|ctx| ctx.claims()));
+//!
+//! let title = t!("game.title");
+//! let greeting = t!("game.greeting", name = player_name, count = 3);
+//! ```
+//!
+//! Placeholders in messages use `{name}` syntax. Missing keys fall back to the
+//! fallback locale's catalog, then to the key itself.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::error::WidgetError;
+use crate::token::WidgetClaims;
+
+/// Locale used when nothing better can be negotiated
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// A flattened set of messages for a single locale
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Catalog {
+    locale: String,
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    /// Create an empty catalog for a locale
+    pub fn new(locale: impl Into<String>) -> Self {
+        Self {
+            locale: normalize_locale(&locale.into()),
+            messages: HashMap::new(),
+        }
+    }
+
+    /// Parse a catalog from JSON, flattening nested objects into dot keys
+    pub fn from_json(locale: impl Into<String>, json: &str) -> Result<Self, WidgetError> {
+        let value: serde_json::Value = serde_json::from_str(json)
+            .map_err(|e| WidgetError::Parse(format!("Invalid catalog JSON: {e}")))?;
+
+        let mut catalog = Self::new(locale);
+        flatten_into(&mut catalog.messages, "", &value);
+        Ok(catalog)
+    }
+
+    /// Add or replace a single message
+    pub fn with_message(mut self, key: impl Into<String>, message: impl Into<String>) -> Self {
+        self.messages.insert(key.into(), message.into());
+        self
+    }
+
+    /// The (normalized) locale tag this catalog serves
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Look up a message by key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(|s| s.as_str())
+    }
+
+    /// Number of messages in the catalog
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Check if the catalog has no messages
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Merge another catalog's messages into this one (other wins on conflict)
+    pub fn merge(&mut self, other: Catalog) {
+        self.messages.extend(other.messages);
+    }
+}
+
+fn flatten_into(out: &mut HashMap<String, String>, prefix: &str, value: &serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_into(out, &path, child);
+            }
+        }
+        serde_json::Value::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        serde_json::Value::Null => {}
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
+
+/// Registry of catalogs plus the active/fallback locale
+#[derive(Debug)]
+struct Registry {
+    catalogs: HashMap<String, Catalog>,
+    active: String,
+    fallback: String,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self {
+            catalogs: HashMap::new(),
+            active: DEFAULT_LOCALE.to_string(),
+            fallback: DEFAULT_LOCALE.to_string(),
+        }
+    }
+}
+
+thread_local! {
+    /// Global i18n registry (WASM is single-threaded)
+    static REGISTRY: RefCell<Registry> = RefCell::new(Registry::default());
+}
+
+/// Register a catalog. Catalogs for the same locale are merged.
+pub fn register_catalog(catalog: Catalog) {
+    REGISTRY.with(|reg| {
+        let mut reg = reg.borrow_mut();
+        match reg.catalogs.get_mut(catalog.locale()) {
+            Some(existing) => existing.merge(catalog),
+            None => {
+                reg.catalogs.insert(catalog.locale.clone(), catalog);
+            }
+        }
+    });
+}
+
+/// Locales that have a registered catalog
+pub fn available_locales() -> Vec<String> {
+    REGISTRY.with(|reg| {
+        let mut locales: Vec<String> = reg.borrow().catalogs.keys().cloned().collect();
+        locales.sort();
+        locales
+    })
+}
+
+/// Get the active locale
+pub fn current_locale() -> String {
+    REGISTRY.with(|reg| reg.borrow().active.clone())
+}
+
+/// Set the active locale (normalized, not validated against catalogs)
+pub fn set_locale(locale: &str) {
+    let locale = normalize_locale(locale);
+    REGISTRY.with(|reg| reg.borrow_mut().active = locale);
+}
+
+/// Set the locale used when a key is missing from the active catalog
+pub fn set_fallback_locale(locale: &str) {
+    let locale = normalize_locale(locale);
+    REGISTRY.with(|reg| reg.borrow_mut().fallback = locale);
+}
+
+/// Negotiate and activate a locale from JWT claims and the browser
+///
+/// Preference order: the `locale` claim, `navigator.languages`, then the
+/// fallback locale. Returns the locale that was activated.
+pub fn init_locale(claims: Option<&WidgetClaims>) -> String {
+    let mut requested: Vec<String> = Vec::new();
+    if let Some(locale) = claims.and_then(|c| c.locale.as_deref()) {
+        requested.push(locale.to_string());
+    }
+    requested.extend(browser_locales());
+
+    let fallback = REGISTRY.with(|reg| reg.borrow().fallback.clone());
+    let available = available_locales();
+    let available: Vec<&str> = available.iter().map(|s| s.as_str()).collect();
+
+    let locale = negotiate_locale(&requested, &available, &fallback);
+    set_locale(&locale);
+    locale
+}
+
+/// Read the user's preferred languages from `navigator.languages`
+///
+/// Falls back to `navigator.language`, or an empty list outside a browser.
+pub fn browser_locales() -> Vec<String> {
+    let Some(window) = web_sys::window() else {
+        return Vec::new();
+    };
+    let navigator = window.navigator();
+
+    let languages: Vec<String> = navigator
+        .languages()
+        .iter()
+        .filter_map(|v| v.as_string())
+        .collect();
+
+    if languages.is_empty() {
+        navigator.language().into_iter().collect()
+    } else {
+        languages
+    }
+}
+
+/// Pick the best available locale for a list of requested locales
+///
+/// Each requested tag is tried as an exact match first, then by its primary
+/// language subtag (`de-AT` matches `de`), then any available regional
+/// variant of that language (`pt` matches `pt-br`).
+///
+/// # Example
+/// ```
+/// use ui_core::i18n::negotiate_locale;
+///
+/// let requested = vec!["de-AT".to_string(), "en-US".to_string()];
+/// assert_eq!(negotiate_locale(&requested, &["en", "de"], "en"), "de");
+/// assert_eq!(negotiate_locale(&requested, &["fr"], "en"), "en");
+/// ```
+pub fn negotiate_locale(requested: &[String], available: &[&str], fallback: &str) -> String {
+    let available: Vec<String> = available.iter().map(|l| normalize_locale(l)).collect();
+
+    for tag in requested {
+        let tag = normalize_locale(tag);
+        if available.contains(&tag) {
+            return tag;
+        }
+
+        let language = primary_language(&tag);
+        if let Some(found) = available.iter().find(|a| a.as_str() == language) {
+            return found.clone();
+        }
+        if let Some(found) = available.iter().find(|a| primary_language(a) == language) {
+            return found.clone();
+        }
+    }
+
+    normalize_locale(fallback)
+}
+
+/// Normalize a locale tag to lowercase with `-` separators (`en_US` -> `en-us`)
+pub fn normalize_locale(tag: &str) -> String {
+    tag.trim().replace('_', "-").to_lowercase()
+}
+
+fn primary_language(tag: &str) -> &str {
+    tag.split('-').next().unwrap_or(tag)
+}
+
+/// Translate a key in the active locale, substituting `{name}` placeholders
+///
+/// Prefer the [`t!`](crate::t) macro over calling this directly.
+pub fn translate(key: &str, args: &[(&str, String)]) -> String {
+    lookup(key)
+        .map(|template| format_message(&template, args))
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Translate a key, using `default` when no catalog has it
+///
+/// Useful for shared components that must render sensibly before any
+/// catalog has been registered.
+pub fn translate_or(key: &str, default: &str, args: &[(&str, String)]) -> String {
+    let template = lookup(key).unwrap_or_else(|| default.to_string());
+    format_message(&template, args)
+}
+
+fn lookup(key: &str) -> Option<String> {
+    REGISTRY.with(|reg| {
+        let reg = reg.borrow();
+        let from = |locale: &str| {
+            reg.catalogs
+                .get(locale)
+                .and_then(|c| c.get(key))
+                .map(|s| s.to_string())
+        };
+
+        from(&reg.active)
+            .or_else(|| from(primary_language(&reg.active)))
+            .or_else(|| from(&reg.fallback))
+    })
+}
+
+/// Substitute `{name}` placeholders in a message template
+///
+/// Unknown placeholders are left as-is. Use `{{` and `}}` for literal braces.
+///
+/// # Example
+/// ```
+/// use ui_core::i18n::format_message;
+///
+/// let msg = format_message("Hello {name}!", &[("name", "Ada".to_string())]);
+/// assert_eq!(msg, "Hello Ada!");
+/// ```
+pub fn format_message(template: &str, args: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                let mut closed = false;
+                for n in chars.by_ref() {
+                    if n == '}' {
+                        closed = true;
+                        break;
+                    }
+                    name.push(n);
+                }
+
+                let value = args
+                    .iter()
+                    .find(|(k, _)| *k == name.trim())
+                    .map(|(_, v)| v.as_str());
+                match (value, closed) {
+                    (Some(v), true) => out.push_str(v),
+                    (None, true) => {
+                        out.push('{');
+                        out.push_str(&name);
+                        out.push('}');
+                    }
+                    (_, false) => {
+                        out.push('{');
+                        out.push_str(&name);
+                    }
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Translate a message key in the active locale
+///
+/// # Example
+/// ```ignore
+/// use ui_core::t;
+///
+/// let label = t!("wallet.connect");
+/// let summary = t!("gallery.count", count = assets.len());
+/// ```
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::translate($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::translate(
+            $key,
+            &[$((stringify!($name), ::std::string::ToString::to_string(&$value))),+],
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_flattens_nested_keys() {
+        let catalog = Catalog::from_json(
+            "en",
+            r#"{"wallet": {"connect": "Connect", "count": 3}, "title": "Hi", "none": null}"#,
+        )
+        .unwrap();
+
+        assert_eq!(catalog.get("wallet.connect"), Some("Connect"));
+        assert_eq!(catalog.get("wallet.count"), Some("3"));
+        assert_eq!(catalog.get("title"), Some("Hi"));
+        assert_eq!(catalog.get("none"), None);
+        assert_eq!(catalog.len(), 3);
+    }
+
+    #[test]
+    fn test_catalog_invalid_json() {
+        let err = Catalog::from_json("en", "not json").unwrap_err();
+        assert!(matches!(err, WidgetError::Parse(_)));
+    }
+
+    #[test]
+    fn test_negotiate_locale() {
+        let requested = |tags: &[&str]| tags.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            negotiate_locale(&requested(&["de"]), &["en", "de"], "en"),
+            "de"
+        );
+        assert_eq!(
+            negotiate_locale(&requested(&["en_US"]), &["en-us", "en"], "en"),
+            "en-us"
+        );
+        assert_eq!(
+            negotiate_locale(&requested(&["de-AT"]), &["en", "de"], "en"),
+            "de"
+        );
+        assert_eq!(
+            negotiate_locale(&requested(&["pt"]), &["en", "pt-BR"], "en"),
+            "pt-br"
+        );
+        assert_eq!(
+            negotiate_locale(&requested(&["ja", "fr"]), &["en", "fr"], "en"),
+            "fr"
+        );
+        assert_eq!(negotiate_locale(&requested(&["ja"]), &["en"], "en"), "en");
+        assert_eq!(negotiate_locale(&[], &["en"], "EN"), "en");
+    }
+
+    #[test]
+    fn test_format_message() {
+        let args = [("name", "Ada".to_string()), ("count", "3".to_string())];
+        assert_eq!(format_message("Hi {name}", &args), "Hi Ada");
+        assert_eq!(format_message("{count} items", &args), "3 items");
+        assert_eq!(format_message("{ name }", &args), "Ada");
+        assert_eq!(format_message("{missing}", &args), "{missing}");
+        assert_eq!(format_message("{{literal}}", &args), "{literal}");
+        assert_eq!(format_message("open {name", &args), "open {name");
+    }
+
+    #[test]
+    fn test_translate_with_fallback() {
+        register_catalog(
+            Catalog::new("en")
+                .with_message("greeting", "Hello {name}")
+                .with_message("only_en", "English only"),
+        );
+        register_catalog(Catalog::new("de").with_message("greeting", "Hallo {name}"));
+
+        set_locale("de-AT");
+        assert_eq!(t!("greeting", name = "Ada"), "Hallo Ada");
+        assert_eq!(t!("only_en"), "English only");
+        assert_eq!(t!("unknown.key"), "unknown.key");
+        assert_eq!(translate_or("unknown.key", "Default", &[]), "Default");
+
+        set_locale("en");
+        assert_eq!(t!("greeting", name = "Ada"), "Hello Ada");
+    }
+}
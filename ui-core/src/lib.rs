@@ -6,26 +6,45 @@
 //! ## Modules
 //!
 //! - [`auth`] - Authentication state management
-//! - [`color`] - Color utilities (contrast detection, luminance)
+//! - [`clock`] - Server-offset aware time, countdowns and duration formatting
+//! - [`color`] - Color utilities (contrast detection, luminance, image palettes)
+//! - [`config`] - Typed widget config from build defaults, `/config.json` and URL params
 //! - [`error`] - Error types with HTTP status handling
 //! - [`fetch_state`] - Generic async fetch state management
+//! - [`form`] - Form field state, two-way DOM bindings and validators
 //! - [`http`] - HTTP helpers using gloo-net
+//! - [`i18n`] - Message catalogs, locale negotiation, and the [`t!`] macro
+//! - [`retry`] - Retry with jittered exponential backoff
 //! - [`runtime`] - Widget initialization (panic hooks, tracing)
+//! - [`settings`] - Persistent per-widget user preferences
+//! - [`timing`] - Debounce and throttle wrappers
 //! - [`token`] - JWT token parsing
 //! - [`urls`] - URL building utilities
 
 pub mod auth;
+pub mod clock;
 pub mod color;
+pub mod config;
 pub mod error;
 pub mod fetch_state;
+pub mod form;
 pub mod http;
+pub mod i18n;
+pub mod retry;
 pub mod runtime;
+pub mod settings;
+pub mod timing;
 pub mod token;
 pub mod urls;
 
 // Re-export commonly used types
 pub use auth::{AuthContext, AuthState};
+pub use config::{ConfigError, ConfigLoader, WidgetConfig};
 pub use error::WidgetError;
-pub use fetch_state::FetchState;
+pub use fetch_state::{FetchState, RetryMeta};
+pub use form::{bind_checked, bind_input_value, bind_select_value, Field, Validator};
+pub use retry::{retry_async, retry_async_if, RetryPolicy, Retryable};
 pub use runtime::{init_widget, init_widget_with_level};
-pub use token::{decode_token_claims, WidgetClaims};
+pub use settings::Settings;
+pub use timing::{debounced, throttled, Debounced, Throttled};
+pub use token::{decode_claims, decode_token_claims, TokenError, Validation, WidgetClaims};
@@ -0,0 +1,325 @@
+//! Retry with jittered exponential backoff
+//!
+//! One backoff implementation for everything that retries: HTTP calls,
+//! reconnects and loader fetches.
+//!
+//! - [`RetryPolicy`] - attempt limit, base/max delay and jitter
+//! - [`retry_async`] - retry an async operation while its error is
+//!   [`Retryable`]
+//! - [`retry_async_if`] - same, with a custom retry-on predicate
+//!
+//! Delay before retry `n` (1-based) is `base * 2^(n-1)`, capped at the max,
+//! then reduced by up to `jitter` of itself so clients that failed together
+//! don't retry in lockstep. Each failed attempt is traced at `debug`, giving
+//! up at `warn`.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use ui_core::retry::{retry_async, RetryPolicy};
+//!
+//! let policy = RetryPolicy::new(4).base_delay_ms(250);
+//! // Retries network errors, timeouts, 429 and 5xx; other errors return at once
+//! let items: Vec<Item> = retry_async(&policy, || http::get_json(&url, None)).await?;
+//!
+//! // Only retry 503s
+//! let status = retry_async_if(
+//!     &policy,
+//!     |e: &WidgetError| matches!(e, WidgetError::Http { status: 503, .. }),
+//!     || http::get_json(&status_url, None),
+//! )
+//! .await?;
+//! ```
+
+use std::fmt::Display;
+use std::future::Future;
+
+use crate::error::WidgetError;
+
+/// How many times to try and how long to wait in between
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first (1 = no retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for each one after
+    pub base_delay_ms: u32,
+    /// Upper bound on any single delay
+    pub max_delay_ms: u32,
+    /// Fraction (0.0 to 1.0) of each delay that is randomized away
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 500,
+            max_delay_ms: 10_000,
+            jitter: 0.5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Default delays with `max_attempts` total attempts
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..Self::default()
+        }
+    }
+
+    /// Set the delay before the first retry
+    pub fn base_delay_ms(mut self, ms: u32) -> Self {
+        self.base_delay_ms = ms;
+        self
+    }
+
+    /// Set the upper bound on a single delay
+    pub fn max_delay_ms(mut self, ms: u32) -> Self {
+        self.max_delay_ms = ms;
+        self
+    }
+
+    /// Set the randomized fraction of each delay (clamped to 0.0-1.0)
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Backoff before retry `attempt` (1-based) without jitter
+    pub fn backoff_ms(&self, attempt: u32) -> u32 {
+        let multiplier = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay_ms
+            .saturating_mul(multiplier)
+            .min(self.max_delay_ms)
+    }
+
+    /// Backoff before retry `attempt` with jitter, `random` in `[0, 1)`
+    pub fn delay_ms(&self, attempt: u32, random: f64) -> u32 {
+        let backoff = f64::from(self.backoff_ms(attempt));
+        let jitter = self.jitter.clamp(0.0, 1.0) * random.clamp(0.0, 1.0);
+        (backoff * (1.0 - jitter)).round() as u32
+    }
+}
+
+/// Errors that know whether trying again could help
+pub trait Retryable {
+    /// Whether the failed operation is worth retrying
+    fn is_retryable(&self) -> bool;
+}
+
+impl Retryable for WidgetError {
+    /// Network errors (including timeouts), 408, 429 and 5xx
+    fn is_retryable(&self) -> bool {
+        match self {
+            WidgetError::Network(_) => true,
+            WidgetError::Http { status, .. } => {
+                matches!(*status, 408 | 429) || (500..600).contains(status)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Run `op` until it succeeds, fails with a non-retryable error, or
+/// `policy.max_attempts` is used up
+pub async fn retry_async<T, E, F, Fut>(policy: &RetryPolicy, op: F) -> Result<T, E>
+where
+    E: Retryable + Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    retry_async_if(policy, E::is_retryable, op).await
+}
+
+/// Like [`retry_async`], retrying only errors `retry_on` accepts
+pub async fn retry_async_if<T, E, F, Fut, P>(
+    policy: &RetryPolicy,
+    retry_on: P,
+    op: F,
+) -> Result<T, E>
+where
+    E: Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    P: Fn(&E) -> bool,
+{
+    retry_with(policy, retry_on, op, js_sys::Math::random, |ms| {
+        gloo_timers::future::TimeoutFuture::new(ms)
+    })
+    .await
+}
+
+/// Retry loop with the randomness and sleeping passed in, for tests
+async fn retry_with<T, E, F, Fut, P, R, S, SFut>(
+    policy: &RetryPolicy,
+    retry_on: P,
+    mut op: F,
+    mut random: R,
+    mut sleep: S,
+) -> Result<T, E>
+where
+    E: Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    P: Fn(&E) -> bool,
+    R: FnMut() -> f64,
+    S: FnMut(u32) -> SFut,
+    SFut: Future<Output = ()>,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt < max_attempts && retry_on(&e) => {
+                let delay = policy.delay_ms(attempt, random());
+                tracing::debug!(
+                    "Attempt {attempt}/{max_attempts} failed: {e}; retrying in {delay}ms"
+                );
+                sleep(delay).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                if attempt > 1 {
+                    tracing::warn!("Giving up after {attempt} attempts: {e}");
+                }
+                return Err(e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::cell::RefCell;
+
+    /// Run `op` under `policy` with no jitter, recording the sleeps
+    fn run<T>(
+        policy: &RetryPolicy,
+        results: Vec<Result<T, WidgetError>>,
+    ) -> (Result<T, WidgetError>, usize, Vec<u32>) {
+        let results = RefCell::new(results.into_iter());
+        let calls = RefCell::new(0);
+        let sleeps = RefCell::new(Vec::new());
+        let result = block_on(retry_with(
+            policy,
+            WidgetError::is_retryable,
+            || {
+                *calls.borrow_mut() += 1;
+                let next = results.borrow_mut().next().expect("op called too often");
+                async move { next }
+            },
+            || 0.0,
+            |ms| {
+                sleeps.borrow_mut().push(ms);
+                async {}
+            },
+        ));
+        (result, calls.into_inner(), sleeps.into_inner())
+    }
+
+    fn server_error() -> WidgetError {
+        WidgetError::Http {
+            status: 503,
+            message: "unavailable".into(),
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        let policy = RetryPolicy::new(10).base_delay_ms(100).max_delay_ms(1000);
+        assert_eq!(policy.backoff_ms(1), 100);
+        assert_eq!(policy.backoff_ms(2), 200);
+        assert_eq!(policy.backoff_ms(4), 800);
+        assert_eq!(policy.backoff_ms(5), 1000);
+        assert_eq!(policy.backoff_ms(40), 1000);
+    }
+
+    #[test]
+    fn test_jitter_only_shortens() {
+        let policy = RetryPolicy::new(3).base_delay_ms(1000).jitter(0.5);
+        assert_eq!(policy.delay_ms(1, 0.0), 1000);
+        assert_eq!(policy.delay_ms(1, 0.5), 750);
+        assert_eq!(policy.delay_ms(1, 0.999_999), 500);
+        assert_eq!(RetryPolicy::new(3).jitter(0.0).delay_ms(2, 0.9), 1000);
+    }
+
+    #[test]
+    fn test_retryable_errors() {
+        assert!(WidgetError::Network("timeout".into()).is_retryable());
+        assert!(server_error().is_retryable());
+        for status in [408, 429, 500, 599] {
+            let err = WidgetError::Http {
+                status,
+                message: String::new(),
+            };
+            assert!(err.is_retryable(), "{status}");
+        }
+        for status in [400, 401, 404] {
+            let err = WidgetError::Http {
+                status,
+                message: String::new(),
+            };
+            assert!(!err.is_retryable(), "{status}");
+        }
+        assert!(!WidgetError::TokenExpired.is_retryable());
+        assert!(!WidgetError::Parse("bad json".into()).is_retryable());
+    }
+
+    #[test]
+    fn test_retries_until_success() {
+        let policy = RetryPolicy::new(5).base_delay_ms(100);
+        let (result, calls, sleeps) = run(
+            &policy,
+            vec![
+                Err(server_error()),
+                Err(WidgetError::Network("timeout".into())),
+                Ok(7),
+            ],
+        );
+        assert_eq!(result, Ok(7));
+        assert_eq!(calls, 3);
+        assert_eq!(sleeps, vec![100, 200]);
+    }
+
+    #[test]
+    fn test_gives_up_after_max_attempts() {
+        let policy = RetryPolicy::new(3).base_delay_ms(100);
+        let (result, calls, sleeps) = run::<()>(
+            &policy,
+            vec![
+                Err(server_error()),
+                Err(server_error()),
+                Err(server_error()),
+            ],
+        );
+        assert_eq!(result, Err(server_error()));
+        assert_eq!(calls, 3);
+        assert_eq!(sleeps, vec![100, 200]);
+    }
+
+    #[test]
+    fn test_non_retryable_returns_immediately() {
+        let (result, calls, sleeps) = run::<()>(
+            &RetryPolicy::new(5),
+            vec![Err(WidgetError::Http {
+                status: 404,
+                message: "not found".into(),
+            })],
+        );
+        assert!(matches!(result, Err(WidgetError::Http { status: 404, .. })));
+        assert_eq!(calls, 1);
+        assert!(sleeps.is_empty());
+    }
+
+    #[test]
+    fn test_zero_attempts_still_runs_once() {
+        let (result, calls, _) = run(&RetryPolicy::new(0), vec![Ok(1)]);
+        assert_eq!(result, Ok(1));
+        assert_eq!(calls, 1);
+    }
+}
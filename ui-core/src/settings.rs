@@ -0,0 +1,417 @@
+//! Persistent per-widget user settings
+//!
+//! A namespace-scoped, serde-backed key-value store for user preferences such
+//! as sound, theme, or reduced motion. Values are stored as JSON under
+//! `{namespace}:{key}`, so widgets sharing an origin don't collide.
+//!
+//! Storage is `localStorage` when available. Where it is blocked (sandboxed
+//! iframes, some private browsing modes), [`Settings::open`] falls back to
+//! IndexedDB, and finally to an in-memory store for the session.
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use ui_core::settings::{keys, Settings};
+//!
+//! let settings = Settings::new("memory-game");
+//!
+//! let sound_on = settings.get_or(keys::SOUND_ENABLED, true);
+//! settings.set(keys::SOUND_ENABLED, &false)?;
+//!
+//! // React to changes from anywhere in the app
+//! let _sub = settings.subscribe(|key| tracing::info!("setting changed: {key}"));
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::error::WidgetError;
+
+/// Well-known preference keys shared across widgets
+pub mod keys {
+    /// `bool` - play sound effects
+    pub const SOUND_ENABLED: &str = "sound_enabled";
+    /// `String` - theme name (e.g. "dark", "light")
+    pub const THEME: &str = "theme";
+    /// `bool` - minimize animations
+    pub const REDUCED_MOTION: &str = "reduced_motion";
+}
+
+/// IndexedDB database used by the fallback backend
+const IDB_NAME: &str = "shared_ui_settings";
+/// Object store holding `key -> JSON string`
+const IDB_STORE: &str = "settings";
+
+/// A synchronous string key-value backend
+///
+/// Async stores (IndexedDB) are hydrated into memory up front so reads stay
+/// synchronous; writes are persisted in the background.
+pub trait SettingsBackend {
+    fn get(&self, key: &str) -> Option<String>;
+    fn set(&self, key: &str, value: &str) -> Result<(), WidgetError>;
+    fn remove(&self, key: &str);
+}
+
+/// `window.localStorage` backend
+pub struct LocalStorageBackend {
+    storage: web_sys::Storage,
+}
+
+impl LocalStorageBackend {
+    /// Returns `None` if localStorage is unavailable or blocked
+    pub fn new() -> Option<Self> {
+        let storage = web_sys::window()?.local_storage().ok()??;
+        // Some browsers expose storage but throw on write - probe once
+        let probe = "__shared_ui_probe__";
+        storage.set_item(probe, "1").ok()?;
+        let _ = storage.remove_item(probe);
+        Some(Self { storage })
+    }
+}
+
+impl SettingsBackend for LocalStorageBackend {
+    fn get(&self, key: &str) -> Option<String> {
+        self.storage.get_item(key).ok().flatten()
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), WidgetError> {
+        self.storage
+            .set_item(key, value)
+            .map_err(|e| WidgetError::Other(format!("Failed to write setting {key}: {e:?}")))
+    }
+
+    fn remove(&self, key: &str) {
+        let _ = self.storage.remove_item(key);
+    }
+}
+
+/// In-memory backend (session only)
+#[derive(Default)]
+pub struct MemoryBackend {
+    values: RefCell<HashMap<String, String>>,
+}
+
+impl SettingsBackend for MemoryBackend {
+    fn get(&self, key: &str) -> Option<String> {
+        self.values.borrow().get(key).cloned()
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), WidgetError> {
+        self.values
+            .borrow_mut()
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) {
+        self.values.borrow_mut().remove(key);
+    }
+}
+
+/// IndexedDB backend with an in-memory read cache
+pub struct IndexedDbBackend {
+    db: web_sys::IdbDatabase,
+    cache: MemoryBackend,
+}
+
+impl IndexedDbBackend {
+    /// Open the settings database and load all stored values
+    pub async fn open() -> Result<Self, WidgetError> {
+        let factory = web_sys::window()
+            .and_then(|w| w.indexed_db().ok().flatten())
+            .ok_or_else(|| WidgetError::Other("IndexedDB unavailable".to_string()))?;
+
+        let open = factory.open_with_u32(IDB_NAME, 1).map_err(idb_err)?;
+        let upgrade = Closure::once_into_js({
+            let open = open.clone();
+            move |_: web_sys::Event| {
+                if let Ok(result) = open.result() {
+                    let db: web_sys::IdbDatabase = result.unchecked_into();
+                    if !db.object_store_names().contains(IDB_STORE) {
+                        let _ = db.create_object_store(IDB_STORE);
+                    }
+                }
+            }
+        });
+        open.set_onupgradeneeded(Some(upgrade.unchecked_ref()));
+
+        let db: web_sys::IdbDatabase = request_result(&open).await?.unchecked_into();
+
+        let store = db
+            .transaction_with_str(IDB_STORE)
+            .and_then(|tx| tx.object_store(IDB_STORE))
+            .map_err(idb_err)?;
+        let keys: js_sys::Array = request_result(&store.get_all_keys().map_err(idb_err)?)
+            .await?
+            .unchecked_into();
+        let values: js_sys::Array = request_result(&store.get_all().map_err(idb_err)?)
+            .await?
+            .unchecked_into();
+
+        let cache = MemoryBackend::default();
+        for (key, value) in keys.iter().zip(values.iter()) {
+            if let (Some(key), Some(value)) = (key.as_string(), value.as_string()) {
+                let _ = cache.set(&key, &value);
+            }
+        }
+
+        Ok(Self { db, cache })
+    }
+
+    fn store(&self) -> Result<web_sys::IdbObjectStore, WidgetError> {
+        self.db
+            .transaction_with_str_and_mode(IDB_STORE, web_sys::IdbTransactionMode::Readwrite)
+            .and_then(|tx| tx.object_store(IDB_STORE))
+            .map_err(idb_err)
+    }
+}
+
+impl SettingsBackend for IndexedDbBackend {
+    fn get(&self, key: &str) -> Option<String> {
+        self.cache.get(key)
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), WidgetError> {
+        self.cache.set(key, value)?;
+        self.store()?
+            .put_with_key(&JsValue::from_str(value), &JsValue::from_str(key))
+            .map_err(idb_err)?;
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) {
+        self.cache.remove(key);
+        if let Ok(store) = self.store() {
+            let _ = store.delete(&JsValue::from_str(key));
+        }
+    }
+}
+
+fn idb_err(e: JsValue) -> WidgetError {
+    WidgetError::Other(format!("IndexedDB error: {e:?}"))
+}
+
+/// Wait for an IndexedDB request to complete and return its result
+async fn request_result(request: &web_sys::IdbRequest) -> Result<JsValue, WidgetError> {
+    let done = js_sys::Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    JsFuture::from(done).await.map_err(idb_err)?;
+    request.result().map_err(idb_err)
+}
+
+type Listener = Rc<dyn Fn(&str)>;
+type ListenerMap = HashMap<String, Vec<(u64, Listener)>>;
+
+thread_local! {
+    /// Change listeners by namespace, shared by all `Settings` handles
+    static LISTENERS: RefCell<ListenerMap> = RefCell::new(HashMap::new());
+    static NEXT_LISTENER_ID: RefCell<u64> = const { RefCell::new(0) };
+}
+
+fn notify(namespace: &str, key: &str) {
+    // Clone out so listeners may subscribe/unsubscribe or write settings
+    let listeners: Vec<Listener> = LISTENERS.with(|l| {
+        l.borrow()
+            .get(namespace)
+            .map(|subs| subs.iter().map(|(_, f)| Rc::clone(f)).collect())
+            .unwrap_or_default()
+    });
+    for listener in listeners {
+        listener(key);
+    }
+}
+
+/// Handle for a change listener; unsubscribes when dropped
+#[must_use = "the listener is removed when the subscription is dropped"]
+pub struct Subscription {
+    namespace: String,
+    id: u64,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        LISTENERS.with(|l| {
+            if let Some(subs) = l.borrow_mut().get_mut(&self.namespace) {
+                subs.retain(|(id, _)| *id != self.id);
+            }
+        });
+    }
+}
+
+/// Namespace-scoped settings store
+#[derive(Clone)]
+pub struct Settings {
+    namespace: String,
+    backend: Rc<dyn SettingsBackend>,
+}
+
+impl Settings {
+    /// Create a store backed by localStorage, or memory if it is unavailable
+    ///
+    /// Use [`Settings::open`] to also try IndexedDB before falling back to memory.
+    pub fn new(namespace: impl Into<String>) -> Self {
+        let backend: Rc<dyn SettingsBackend> = match LocalStorageBackend::new() {
+            Some(local) => Rc::new(local),
+            None => {
+                tracing::warn!("localStorage unavailable, settings will not persist");
+                Rc::new(MemoryBackend::default())
+            }
+        };
+        Self::with_backend(namespace, backend)
+    }
+
+    /// Create a store backed by localStorage, then IndexedDB, then memory
+    pub async fn open(namespace: impl Into<String>) -> Self {
+        if let Some(local) = LocalStorageBackend::new() {
+            return Self::with_backend(namespace, Rc::new(local));
+        }
+
+        match IndexedDbBackend::open().await {
+            Ok(idb) => Self::with_backend(namespace, Rc::new(idb)),
+            Err(e) => {
+                tracing::warn!("No persistent storage for settings: {e}");
+                Self::with_backend(namespace, Rc::new(MemoryBackend::default()))
+            }
+        }
+    }
+
+    /// Create a store over a custom backend
+    pub fn with_backend(namespace: impl Into<String>, backend: Rc<dyn SettingsBackend>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            backend,
+        }
+    }
+
+    /// The namespace this store writes under
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    fn storage_key(&self, key: &str) -> String {
+        format!("{}:{key}", self.namespace)
+    }
+
+    /// Read a setting, returning `None` if missing or not deserializable as `T`
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let raw = self.backend.get(&self.storage_key(key))?;
+        match serde_json::from_str(&raw) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                tracing::warn!("Ignoring unreadable setting {}: {e}", self.storage_key(key));
+                None
+            }
+        }
+    }
+
+    /// Read a setting or fall back to a default
+    pub fn get_or<T: DeserializeOwned>(&self, key: &str, default: T) -> T {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Write a setting and notify listeners
+    pub fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<(), WidgetError> {
+        let raw = serde_json::to_string(value)
+            .map_err(|e| WidgetError::Parse(format!("Failed to serialize setting {key}: {e}")))?;
+        self.backend.set(&self.storage_key(key), &raw)?;
+        notify(&self.namespace, key);
+        Ok(())
+    }
+
+    /// Remove a setting and notify listeners
+    pub fn remove(&self, key: &str) {
+        self.backend.remove(&self.storage_key(key));
+        notify(&self.namespace, key);
+    }
+
+    /// Call `listener` with the key whenever a setting in this namespace changes
+    pub fn subscribe(&self, listener: impl Fn(&str) + 'static) -> Subscription {
+        let id = NEXT_LISTENER_ID.with(|n| {
+            let mut n = n.borrow_mut();
+            *n += 1;
+            *n
+        });
+        LISTENERS.with(|l| {
+            l.borrow_mut()
+                .entry(self.namespace.clone())
+                .or_default()
+                .push((id, Rc::new(listener)));
+        });
+        Subscription {
+            namespace: self.namespace.clone(),
+            id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_settings(namespace: &str) -> Settings {
+        Settings::with_backend(namespace, Rc::new(MemoryBackend::default()))
+    }
+
+    #[test]
+    fn test_get_set_roundtrip() {
+        let settings = memory_settings("game");
+
+        assert_eq!(settings.get::<bool>(keys::SOUND_ENABLED), None);
+        assert!(settings.get_or(keys::SOUND_ENABLED, true));
+
+        settings.set(keys::SOUND_ENABLED, &false).unwrap();
+        settings.set(keys::THEME, &"light").unwrap();
+        assert_eq!(settings.get::<bool>(keys::SOUND_ENABLED), Some(false));
+        assert_eq!(
+            settings.get::<String>(keys::THEME).as_deref(),
+            Some("light")
+        );
+
+        // Wrong type reads as missing
+        assert_eq!(settings.get::<u32>(keys::THEME), None);
+
+        settings.remove(keys::THEME);
+        assert_eq!(settings.get::<String>(keys::THEME), None);
+    }
+
+    #[test]
+    fn test_namespaces_are_isolated() {
+        let backend: Rc<dyn SettingsBackend> = Rc::new(MemoryBackend::default());
+        let a = Settings::with_backend("a", Rc::clone(&backend));
+        let b = Settings::with_backend("b", backend);
+
+        a.set(keys::REDUCED_MOTION, &true).unwrap();
+        assert_eq!(b.get::<bool>(keys::REDUCED_MOTION), None);
+        assert_eq!(a.get::<bool>(keys::REDUCED_MOTION), Some(true));
+    }
+
+    #[test]
+    fn test_subscribe_and_unsubscribe() {
+        let settings = memory_settings("listeners");
+        let other = memory_settings("other");
+        let seen = Rc::new(RefCell::new(Vec::<String>::new()));
+
+        let sub = settings.subscribe({
+            let seen = Rc::clone(&seen);
+            move |key| seen.borrow_mut().push(key.to_string())
+        });
+
+        settings.set(keys::THEME, &"dark").unwrap();
+        other.set(keys::THEME, &"dark").unwrap();
+        settings.remove(keys::THEME);
+        assert_eq!(*seen.borrow(), vec!["theme", "theme"]);
+
+        drop(sub);
+        settings.set(keys::THEME, &"light").unwrap();
+        assert_eq!(seen.borrow().len(), 2);
+    }
+}
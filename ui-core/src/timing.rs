@@ -0,0 +1,386 @@
+//! Debounce and throttle wrappers
+//!
+//! Rate-limit a callback without hand-rolled `setTimeout` bookkeeping.
+//! Pending timers are cancelled when the wrapper (and all its clones) are
+//! dropped, so a callback never fires after its owner is gone.
+//!
+//! - [`debounced`] - run once calls have stopped for `ms`, with the last argument
+//! - [`throttled`] - run at most once per `ms`: immediately, then with the
+//!   latest argument at the end of the window
+//!
+//! ## Example
+//!
+//! ```ignore
+//! use ui_core::timing::{debounced, throttled};
+//!
+//! let search = debounced(|query: String| fetch_results(&query), 300);
+//! search.call("car".into());
+//! search.call("cardano".into()); // only this one runs, 300ms later
+//!
+//! let save = throttled(|value: f64| persist(value), 500);
+//! slider.on_input(move |v| save.call(v));
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gloo_timers::callback::Timeout;
+
+/// A one-shot timer that is cancelled when dropped
+///
+/// Abstracts the timer so the wrappers can be driven deterministically in tests.
+pub trait Timer: 'static {
+    /// Start a timer that runs `callback` after `ms` milliseconds
+    fn start(ms: u32, callback: Box<dyn FnOnce()>) -> Self;
+}
+
+/// Browser `setTimeout` timer
+pub struct BrowserTimer {
+    _timeout: Timeout,
+}
+
+impl Timer for BrowserTimer {
+    fn start(ms: u32, callback: Box<dyn FnOnce()>) -> Self {
+        Self {
+            _timeout: Timeout::new(ms, callback),
+        }
+    }
+}
+
+struct DebounceInner<A, T> {
+    f: Box<dyn Fn(A)>,
+    ms: u32,
+    pending: RefCell<Option<A>>,
+    timer: RefCell<Option<T>>,
+}
+
+impl<A: 'static, T: Timer> DebounceInner<A, T> {
+    fn fire(&self) {
+        // Leave the fired timer in place; dropping it here would drop the
+        // closure that is currently running
+        let pending = self.pending.borrow_mut().take();
+        if let Some(arg) = pending {
+            (self.f)(arg);
+        }
+    }
+}
+
+/// A debounced callback; see [`debounced`]
+pub struct Debounced<A, T: Timer = BrowserTimer> {
+    inner: Rc<DebounceInner<A, T>>,
+}
+
+impl<A, T: Timer> Clone for Debounced<A, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
+impl<A: 'static, T: Timer> Debounced<A, T> {
+    /// Wrap `f` so it runs `ms` after the last call
+    pub fn new(f: impl Fn(A) + 'static, ms: u32) -> Self {
+        Self {
+            inner: Rc::new(DebounceInner {
+                f: Box::new(f),
+                ms,
+                pending: RefCell::new(None),
+                timer: RefCell::new(None),
+            }),
+        }
+    }
+
+    /// Schedule a run with `arg`, replacing any pending argument and restarting the delay
+    pub fn call(&self, arg: A) {
+        *self.inner.pending.borrow_mut() = Some(arg);
+        let weak = Rc::downgrade(&self.inner);
+        let timer = T::start(
+            self.inner.ms,
+            Box::new(move || {
+                if let Some(inner) = weak.upgrade() {
+                    inner.fire();
+                }
+            }),
+        );
+        // Replacing the previous timer cancels it
+        let previous = self.inner.timer.borrow_mut().replace(timer);
+        drop(previous);
+    }
+
+    /// Drop the pending call without running it
+    pub fn cancel(&self) {
+        self.inner.pending.borrow_mut().take();
+        let previous = self.inner.timer.borrow_mut().take();
+        drop(previous);
+    }
+
+    /// Run the pending call now, if any
+    pub fn flush(&self) {
+        let previous = self.inner.timer.borrow_mut().take();
+        drop(previous);
+        self.inner.fire();
+    }
+
+    /// Whether a call is waiting to run
+    pub fn is_pending(&self) -> bool {
+        self.inner.pending.borrow().is_some()
+    }
+}
+
+/// Wrap `f` so it runs only after calls stop for `ms` milliseconds
+///
+/// Each call restarts the delay; the last argument wins.
+pub fn debounced<A: 'static>(f: impl Fn(A) + 'static, ms: u32) -> Debounced<A> {
+    Debounced::new(f, ms)
+}
+
+struct ThrottleInner<A, T> {
+    f: Box<dyn Fn(A)>,
+    ms: u32,
+    pending: RefCell<Option<A>>,
+    /// Running while inside a throttle window
+    window: RefCell<Option<T>>,
+}
+
+impl<A: 'static, T: Timer> ThrottleInner<A, T> {
+    fn open_window(self: &Rc<Self>) {
+        let weak = Rc::downgrade(self);
+        let timer = T::start(
+            self.ms,
+            Box::new(move || {
+                if let Some(inner) = weak.upgrade() {
+                    inner.close_window();
+                }
+            }),
+        );
+        let previous = self.window.borrow_mut().replace(timer);
+        drop(previous);
+    }
+
+    fn close_window(self: &Rc<Self>) {
+        let pending = self.pending.borrow_mut().take();
+        match pending {
+            // Trailing call: run it and start a new window
+            Some(arg) => {
+                (self.f)(arg);
+                self.open_window();
+            }
+            None => {
+                let finished = self.window.borrow_mut().take();
+                drop(finished);
+            }
+        }
+    }
+}
+
+/// A throttled callback; see [`throttled`]
+pub struct Throttled<A, T: Timer = BrowserTimer> {
+    inner: Rc<ThrottleInner<A, T>>,
+}
+
+impl<A, T: Timer> Clone for Throttled<A, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
+impl<A: 'static, T: Timer> Throttled<A, T> {
+    /// Wrap `f` so it runs at most once per `ms`
+    pub fn new(f: impl Fn(A) + 'static, ms: u32) -> Self {
+        Self {
+            inner: Rc::new(ThrottleInner {
+                f: Box::new(f),
+                ms,
+                pending: RefCell::new(None),
+                window: RefCell::new(None),
+            }),
+        }
+    }
+
+    /// Run now if outside a window, otherwise keep `arg` for the end of the window
+    pub fn call(&self, arg: A) {
+        let in_window = self.inner.window.borrow().is_some();
+        if in_window {
+            *self.inner.pending.borrow_mut() = Some(arg);
+        } else {
+            (self.inner.f)(arg);
+            self.inner.open_window();
+        }
+    }
+
+    /// Drop the trailing call and end the current window
+    pub fn cancel(&self) {
+        self.inner.pending.borrow_mut().take();
+        let previous = self.inner.window.borrow_mut().take();
+        drop(previous);
+    }
+
+    /// Whether a trailing call is waiting to run
+    pub fn is_pending(&self) -> bool {
+        self.inner.pending.borrow().is_some()
+    }
+}
+
+/// Wrap `f` so it runs at most once every `ms` milliseconds
+///
+/// The first call runs immediately; calls during the window collapse into one
+/// trailing run with the latest argument when the window ends.
+pub fn throttled<A: 'static>(f: impl Fn(A) + 'static, ms: u32) -> Throttled<A> {
+    Throttled::new(f, ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Callback = Rc<RefCell<Option<Box<dyn FnOnce()>>>>;
+
+    thread_local! {
+        static TIMERS: RefCell<Vec<(u64, Callback)>> = const { RefCell::new(Vec::new()) };
+        static NEXT_ID: RefCell<u64> = const { RefCell::new(0) };
+    }
+
+    /// Timer fired by hand with [`fire_all`]
+    struct ManualTimer {
+        id: u64,
+    }
+
+    impl Timer for ManualTimer {
+        fn start(_ms: u32, callback: Box<dyn FnOnce()>) -> Self {
+            let id = NEXT_ID.with(|n| {
+                let mut n = n.borrow_mut();
+                *n += 1;
+                *n
+            });
+            TIMERS.with(|t| {
+                t.borrow_mut()
+                    .push((id, Rc::new(RefCell::new(Some(callback)))))
+            });
+            Self { id }
+        }
+    }
+
+    impl Drop for ManualTimer {
+        fn drop(&mut self) {
+            TIMERS.with(|t| t.borrow_mut().retain(|(id, _)| *id != self.id));
+        }
+    }
+
+    fn active_timers() -> usize {
+        TIMERS.with(|t| t.borrow().len())
+    }
+
+    /// Fire every currently scheduled timer once
+    fn fire_all() {
+        let due: Vec<_> = TIMERS.with(|t| t.borrow().iter().map(|(_, cb)| cb.clone()).collect());
+        for cb in due {
+            let callback = cb.borrow_mut().take();
+            if let Some(callback) = callback {
+                callback();
+            }
+        }
+    }
+
+    fn recorder<A: Clone + 'static>() -> (Rc<RefCell<Vec<A>>>, impl Fn(A) + 'static) {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let sink = calls.clone();
+        (calls, move |a: A| sink.borrow_mut().push(a))
+    }
+
+    #[test]
+    fn debounce_runs_last_argument_once() {
+        let (calls, f) = recorder::<u32>();
+        let d = Debounced::<u32, ManualTimer>::new(f, 100);
+        d.call(1);
+        d.call(2);
+        d.call(3);
+        assert_eq!(active_timers(), 1);
+        assert!(d.is_pending());
+
+        fire_all();
+        assert_eq!(*calls.borrow(), vec![3]);
+        assert!(!d.is_pending());
+    }
+
+    #[test]
+    fn debounce_cancel_and_flush() {
+        let (calls, f) = recorder::<&str>();
+        let d = Debounced::<&str, ManualTimer>::new(f, 100);
+
+        d.call("a");
+        d.cancel();
+        assert_eq!(active_timers(), 0);
+        fire_all();
+        assert!(calls.borrow().is_empty());
+
+        d.call("b");
+        d.flush();
+        assert_eq!(*calls.borrow(), vec!["b"]);
+        assert_eq!(active_timers(), 0);
+    }
+
+    #[test]
+    fn debounce_drop_cancels_timer() {
+        let (calls, f) = recorder::<u32>();
+        let d = Debounced::<u32, ManualTimer>::new(f, 100);
+        let clone = d.clone();
+        d.call(1);
+        drop(d);
+        assert_eq!(active_timers(), 1, "a clone keeps the timer alive");
+        drop(clone);
+        assert_eq!(active_timers(), 0);
+        fire_all();
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[test]
+    fn throttle_runs_leading_and_trailing() {
+        let (calls, f) = recorder::<u32>();
+        let t = Throttled::<u32, ManualTimer>::new(f, 100);
+        t.call(1);
+        t.call(2);
+        t.call(3);
+        assert_eq!(*calls.borrow(), vec![1]);
+        assert!(t.is_pending());
+
+        // Window ends: trailing call runs and opens a new window
+        fire_all();
+        assert_eq!(*calls.borrow(), vec![1, 3]);
+        assert_eq!(active_timers(), 1);
+
+        // Quiet window ends without running
+        fire_all();
+        assert_eq!(*calls.borrow(), vec![1, 3]);
+        assert_eq!(active_timers(), 0);
+
+        t.call(4);
+        assert_eq!(*calls.borrow(), vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn throttle_cancel_drops_trailing_call() {
+        let (calls, f) = recorder::<u32>();
+        let t = Throttled::<u32, ManualTimer>::new(f, 100);
+        t.call(1);
+        t.call(2);
+        t.cancel();
+        assert_eq!(active_timers(), 0);
+        t.call(3);
+        assert_eq!(*calls.borrow(), vec![1, 3]);
+    }
+
+    #[test]
+    fn throttle_drop_cancels_window() {
+        let (calls, f) = recorder::<u32>();
+        let t = Throttled::<u32, ManualTimer>::new(f, 100);
+        t.call(1);
+        t.call(2);
+        drop(t);
+        assert_eq!(active_timers(), 0);
+        fire_all();
+        assert_eq!(*calls.borrow(), vec![1]);
+    }
+}
@@ -4,7 +4,10 @@
 //! Note: This is for display/routing purposes only - the server always verifies tokens.
 
 use base64::Engine;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
 
 /// Claims extracted from a widget JWT token
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +35,142 @@ pub struct WidgetClaims {
     /// Whether the user is a platform admin
     #[serde(default)]
     pub is_admin: bool,
+    /// Preferred locale (BCP 47 tag, e.g. "en-US") if known
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// Issuer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iss: Option<String>,
+    /// Audience (single string or array in the token)
+    #[serde(default, skip_serializing_if = "Audience::is_empty")]
+    pub aud: Audience,
+    /// Not-before timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<u64>,
+    /// OAuth-style space-separated scopes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Roles granted to the user
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+    /// Any claims not covered above, for custom extraction via [`WidgetClaims::claim`]
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// JWT `aud` claim, which may be a single string or an array
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Audience {
+    /// No audience claim
+    #[default]
+    None,
+    /// A single audience
+    One(String),
+    /// Multiple audiences
+    Many(Vec<String>),
+}
+
+impl Audience {
+    /// Check if there is no audience
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Audience::None => true,
+            Audience::One(_) => false,
+            Audience::Many(list) => list.is_empty(),
+        }
+    }
+
+    /// Check if the audience includes a value
+    pub fn contains(&self, aud: &str) -> bool {
+        match self {
+            Audience::None => false,
+            Audience::One(a) => a == aud,
+            Audience::Many(list) => list.iter().any(|a| a == aud),
+        }
+    }
+
+    /// Iterate over all audience values
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        let slice: &[String] = match self {
+            Audience::None => &[],
+            Audience::One(a) => std::slice::from_ref(a),
+            Audience::Many(list) => list,
+        };
+        slice.iter().map(|s| s.as_str())
+    }
+}
+
+/// Reasons a decoded token fails validation
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TokenError {
+    /// Token is not a well-formed JWT or the payload could not be parsed
+    #[error("Malformed token")]
+    Malformed,
+
+    /// Token expired (beyond the allowed leeway)
+    #[error("Token expired")]
+    Expired,
+
+    /// Token `nbf` is in the future (beyond the allowed leeway)
+    #[error("Token not yet valid")]
+    NotYetValid,
+
+    /// None of the token audiences are accepted
+    #[error("Invalid audience")]
+    InvalidAudience,
+
+    /// Token issuer is not accepted
+    #[error("Invalid issuer: {0}")]
+    InvalidIssuer(String),
+}
+
+/// Options for validating decoded claims
+///
+/// Empty audience/issuer lists accept any value.
+///
+/// # Example
+/// ```
+/// use ui_core::token::Validation;
+///
+/// let validation = Validation::new()
+///     .audience("widgets")
+///     .audience("admin-ui")
+///     .issuer("https://auth.example.com")
+///     .leeway_secs(30);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Validation {
+    /// Accepted audiences (token must contain at least one)
+    pub audiences: Vec<String>,
+    /// Accepted issuers
+    pub issuers: Vec<String>,
+    /// Clock skew tolerance in seconds for `exp` and `nbf`
+    pub leeway_secs: u64,
+}
+
+impl Validation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept an audience
+    pub fn audience(mut self, aud: impl Into<String>) -> Self {
+        self.audiences.push(aud.into());
+        self
+    }
+
+    /// Accept an issuer
+    pub fn issuer(mut self, iss: impl Into<String>) -> Self {
+        self.issuers.push(iss.into());
+        self
+    }
+
+    /// Set the clock skew tolerance in seconds
+    pub fn leeway_secs(mut self, leeway: u64) -> Self {
+        self.leeway_secs = leeway;
+        self
+    }
 }
 
 /// Action-specific claims
@@ -91,6 +230,13 @@ impl WidgetClaims {
             display_name: Some("Dev User".to_string()),
             avatar_hash: None,
             is_admin: true, // Dev users are admins
+            locale: None,
+            iss: None,
+            aud: Audience::None,
+            nbf: None,
+            scope: None,
+            roles: Vec::new(),
+            extra: HashMap::new(),
         }
     }
 
@@ -106,8 +252,73 @@ impl WidgetClaims {
 
     /// Check if the token is expired
     pub fn is_expired(&self) -> bool {
+        self.is_expired_with_leeway(0)
+    }
+
+    /// Check if the token is expired, allowing `leeway` seconds of clock skew
+    pub fn is_expired_with_leeway(&self, leeway: u64) -> bool {
+        let now = js_sys::Date::now() as u64 / 1000;
+        self.exp.saturating_add(leeway) < now
+    }
+
+    /// Validate expiry, not-before, audience, and issuer against the current time
+    pub fn validate(&self, validation: &Validation) -> Result<(), TokenError> {
         let now = js_sys::Date::now() as u64 / 1000;
-        self.exp < now
+        self.validate_at(validation, now)
+    }
+
+    /// Validate against an explicit `now` (unix seconds)
+    pub fn validate_at(&self, validation: &Validation, now: u64) -> Result<(), TokenError> {
+        let leeway = validation.leeway_secs;
+
+        if self.exp.saturating_add(leeway) < now {
+            return Err(TokenError::Expired);
+        }
+
+        if let Some(nbf) = self.nbf {
+            if nbf > now.saturating_add(leeway) {
+                return Err(TokenError::NotYetValid);
+            }
+        }
+
+        if !validation.audiences.is_empty()
+            && !validation.audiences.iter().any(|a| self.aud.contains(a))
+        {
+            return Err(TokenError::InvalidAudience);
+        }
+
+        if !validation.issuers.is_empty() {
+            let iss = self.iss.as_deref().unwrap_or_default();
+            if !validation.issuers.iter().any(|i| i == iss) {
+                return Err(TokenError::InvalidIssuer(iss.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Scopes from the space-separated `scope` claim
+    pub fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.scope.as_deref().unwrap_or_default().split_whitespace()
+    }
+
+    /// Check if a scope was granted
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes().any(|s| s == scope)
+    }
+
+    /// Check if a role was granted (platform admins implicitly hold "admin")
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role) || (role == "admin" && self.is_admin)
+    }
+
+    /// Extract a custom claim not covered by the standard fields
+    ///
+    /// Returns `None` if the claim is missing or does not deserialize as `T`.
+    pub fn claim<T: DeserializeOwned>(&self, name: &str) -> Option<T> {
+        self.extra
+            .get(name)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
     }
 
     /// Get seconds until expiration (0 if already expired)
@@ -149,6 +360,25 @@ impl WidgetClaims {
 /// This extracts the payload from a JWT for client-side use.
 /// The server always verifies tokens - this is just for display/routing.
 pub fn decode_token_claims(token: &str) -> Option<WidgetClaims> {
+    decode_claims(token)
+}
+
+/// Decode JWT claims into a custom claims type without verification
+///
+/// Use this when a widget's tokens carry a schema beyond [`WidgetClaims`].
+///
+/// # Example
+/// ```ignore
+/// #[derive(Deserialize)]
+/// struct TradeClaims {
+///     sub: String,
+///     exp: u64,
+///     offer_id: String,
+/// }
+///
+/// let claims: TradeClaims = decode_claims(&token)?;
+/// ```
+pub fn decode_claims<C: DeserializeOwned>(token: &str) -> Option<C> {
     // JWT format: header.payload.signature
     let parts: Vec<&str> = token.split('.').collect();
     if parts.len() != 3 {
@@ -164,8 +394,20 @@ pub fn decode_token_claims(token: &str) -> Option<WidgetClaims> {
         .decode(payload)
         .ok()?;
 
-    let claims: WidgetClaims = serde_json::from_slice(&decoded).ok()?;
-    Some(claims)
+    serde_json::from_slice(&decoded).ok()
+}
+
+/// Decode and validate widget claims
+///
+/// Still unverified (no signature check) - validation only guards against
+/// tokens minted for another audience/issuer or outside their lifetime.
+pub fn decode_and_validate(
+    token: &str,
+    validation: &Validation,
+) -> Result<WidgetClaims, TokenError> {
+    let claims = decode_token_claims(token).ok_or(TokenError::Malformed)?;
+    claims.validate(validation)?;
+    Ok(claims)
 }
 
 #[cfg(test)]
@@ -179,4 +421,119 @@ mod tests {
         assert!(decode_token_claims("invalid").is_none());
         assert!(decode_token_claims("a.b.c").is_none());
     }
+
+    fn make_token(payload: serde_json::Value) -> String {
+        let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        let header = engine.encode(r#"{"alg":"HS256","typ":"JWT"}"#);
+        let body = engine.encode(payload.to_string());
+        format!("{header}.{body}.sig")
+    }
+
+    fn sample_claims(extra: serde_json::Value) -> WidgetClaims {
+        let mut payload = serde_json::json!({
+            "sub": "123",
+            "guild_id": "456",
+            "exp": 1_000,
+            "iat": 0,
+        });
+        if let (Some(base), Some(extra)) = (payload.as_object_mut(), extra.as_object()) {
+            base.extend(extra.clone());
+        }
+        decode_token_claims(&make_token(payload)).expect("valid token")
+    }
+
+    #[test]
+    fn test_decode_audience_forms() {
+        let single = sample_claims(serde_json::json!({ "aud": "widgets" }));
+        assert_eq!(single.aud, Audience::One("widgets".to_string()));
+
+        let many = sample_claims(serde_json::json!({ "aud": ["widgets", "admin"] }));
+        assert!(many.aud.contains("admin"));
+        assert_eq!(many.aud.iter().count(), 2);
+
+        let none = sample_claims(serde_json::json!({}));
+        assert!(none.aud.is_empty());
+    }
+
+    #[test]
+    fn test_validate_expiry_with_leeway() {
+        let claims = sample_claims(serde_json::json!({ "nbf": 100 }));
+
+        assert_eq!(claims.validate_at(&Validation::new(), 500), Ok(()));
+        assert_eq!(
+            claims.validate_at(&Validation::new(), 1_010),
+            Err(TokenError::Expired)
+        );
+        assert_eq!(
+            claims.validate_at(&Validation::new().leeway_secs(30), 1_010),
+            Ok(())
+        );
+        assert_eq!(
+            claims.validate_at(&Validation::new(), 50),
+            Err(TokenError::NotYetValid)
+        );
+        assert_eq!(
+            claims.validate_at(&Validation::new().leeway_secs(60), 50),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_validate_audience_and_issuer() {
+        let claims = sample_claims(serde_json::json!({
+            "aud": ["widgets", "admin"],
+            "iss": "https://auth.example.com",
+        }));
+
+        let ok = Validation::new()
+            .audience("other")
+            .audience("admin")
+            .issuer("https://auth.example.com");
+        assert_eq!(claims.validate_at(&ok, 500), Ok(()));
+
+        let bad_aud = Validation::new().audience("other");
+        assert_eq!(
+            claims.validate_at(&bad_aud, 500),
+            Err(TokenError::InvalidAudience)
+        );
+
+        let bad_iss = Validation::new().issuer("https://evil.example.com");
+        assert_eq!(
+            claims.validate_at(&bad_iss, 500),
+            Err(TokenError::InvalidIssuer(
+                "https://auth.example.com".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_scopes_roles_and_custom_claims() {
+        let claims = sample_claims(serde_json::json!({
+            "scope": "read:map write:reports",
+            "roles": ["captain"],
+            "tribe_id": 42,
+        }));
+
+        assert!(claims.has_scope("write:reports"));
+        assert!(!claims.has_scope("admin"));
+        assert!(claims.has_role("captain"));
+        assert!(!claims.has_role("admin"));
+        assert_eq!(claims.claim::<u32>("tribe_id"), Some(42));
+        assert_eq!(claims.claim::<String>("tribe_id"), None);
+        assert_eq!(claims.claim::<u32>("missing"), None);
+    }
+
+    #[test]
+    fn test_decode_custom_claims() {
+        #[derive(Deserialize)]
+        struct Custom {
+            sub: String,
+            offer_id: String,
+        }
+
+        let token = make_token(serde_json::json!({ "sub": "1", "offer_id": "abc" }));
+        let custom: Custom = decode_claims(&token).expect("custom claims");
+        assert_eq!(custom.sub, "1");
+        assert_eq!(custom.offer_id, "abc");
+    }
 }
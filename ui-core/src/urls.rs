@@ -2,6 +2,328 @@
 //!
 //! Provides consistent URL construction for cross-widget navigation.
 //! This ensures all widgets use the correct URL format with JWT tokens.
+//!
+//! ## Endpoints
+//!
+//! [`Endpoints`] holds the API, IIIF, and WebSocket bases for the current
+//! environment. Configure it once at startup and use the typed builders
+//! everywhere else instead of formatting host strings by hand:
+//!
+//! ```ignore
+//! use ui_core::urls::{endpoints, Endpoints};
+//!
+//! // Same-origin worker (API and WebSocket on the page's host)
+//! Endpoints::from_location().install();
+//!
+//! let ws = endpoints().ws_room("lobby");       // wss://host/ws/lobby
+//! let img = endpoints().iiif(&asset_id, 400);  // https://iiif.../full/400,/0/default.jpg
+//! let api = endpoints().api("/v1/profile");    // https://host/v1/profile
+//! ```
+
+use std::cell::RefCell;
+
+/// Default IIIF image server base
+pub const DEFAULT_IIIF_BASE: &str = "https://iiif.hodlcroft.com/iiif/3";
+
+/// Host used for local development when no browser location is available
+pub const DEFAULT_LOCAL_HOST: &str = "localhost:8787";
+
+/// Cardano policy IDs are 28 bytes (56 hex chars)
+const POLICY_ID_HEX_LEN: usize = 56;
+
+/// Deployment environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Environment {
+    /// Local development (`localhost`, `127.0.0.1`)
+    Local,
+    /// Preview/staging deployments
+    Staging,
+    /// Production
+    #[default]
+    Production,
+}
+
+impl Environment {
+    /// Guess the environment from a hostname
+    ///
+    /// # Example
+    /// ```
+    /// use ui_core::urls::Environment;
+    ///
+    /// assert_eq!(Environment::from_host("localhost:8787"), Environment::Local);
+    /// assert_eq!(Environment::from_host("staging.example.com"), Environment::Staging);
+    /// assert_eq!(Environment::from_host("app.example.com"), Environment::Production);
+    /// ```
+    pub fn from_host(host: &str) -> Self {
+        let hostname = host.split(':').next().unwrap_or(host);
+        if hostname == "localhost" || hostname == "127.0.0.1" || hostname.ends_with(".local") {
+            Environment::Local
+        } else if hostname.starts_with("staging.")
+            || hostname.starts_with("preview.")
+            || hostname.contains(".staging.")
+            || hostname.ends_with(".workers.dev")
+            || hostname.ends_with(".pages.dev")
+        {
+            Environment::Staging
+        } else {
+            Environment::Production
+        }
+    }
+
+    /// Detect the environment from the current page's host
+    pub fn detect() -> Self {
+        current_host()
+            .map(|h| Self::from_host(&h))
+            .unwrap_or(Environment::Local)
+    }
+}
+
+/// Registry of service base URLs for one environment
+///
+/// Bases are stored without trailing slashes; builder paths may or may not
+/// start with `/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoints {
+    environment: Environment,
+    api_base: String,
+    ws_base: String,
+    iiif_base: String,
+}
+
+impl Endpoints {
+    /// Create endpoints from explicit API and WebSocket bases
+    pub fn new(api_base: impl Into<String>, ws_base: impl Into<String>) -> Self {
+        let api_base = trim_base(api_base.into());
+        Self {
+            environment: Environment::from_host(host_of(&api_base)),
+            api_base,
+            ws_base: trim_base(ws_base.into()),
+            iiif_base: DEFAULT_IIIF_BASE.to_string(),
+        }
+    }
+
+    /// Create endpoints for a host, choosing `https`/`wss` unless it is local
+    ///
+    /// # Example
+    /// ```
+    /// use ui_core::urls::Endpoints;
+    ///
+    /// let local = Endpoints::for_host("localhost:8787");
+    /// assert_eq!(local.api_base(), "http://localhost:8787");
+    /// assert_eq!(local.ws_base(), "ws://localhost:8787");
+    ///
+    /// let prod = Endpoints::for_host("game.example.com");
+    /// assert_eq!(prod.ws_base(), "wss://game.example.com");
+    /// ```
+    pub fn for_host(host: &str) -> Self {
+        let secure = Environment::from_host(host) != Environment::Local;
+        Self::for_host_with_scheme(host, secure)
+    }
+
+    fn for_host_with_scheme(host: &str, secure: bool) -> Self {
+        let (http, ws) = if secure {
+            ("https", "wss")
+        } else {
+            ("http", "ws")
+        };
+        Self::new(format!("{http}://{host}"), format!("{ws}://{host}"))
+    }
+
+    /// Derive endpoints from the current page (same-origin API and WebSocket)
+    ///
+    /// Falls back to [`DEFAULT_LOCAL_HOST`] outside a browser.
+    pub fn from_location() -> Self {
+        let Some(location) = browser_location() else {
+            return Self::for_host(DEFAULT_LOCAL_HOST);
+        };
+
+        let host = location
+            .host()
+            .ok()
+            .filter(|h| !h.is_empty())
+            .unwrap_or_else(|| DEFAULT_LOCAL_HOST.to_string());
+        let secure = location.protocol().map(|p| p == "https:").unwrap_or(false);
+
+        Self::for_host_with_scheme(&host, secure)
+    }
+
+    /// Override the API base
+    pub fn with_api_base(mut self, base: impl Into<String>) -> Self {
+        self.api_base = trim_base(base.into());
+        self
+    }
+
+    /// Override the WebSocket base
+    pub fn with_ws_base(mut self, base: impl Into<String>) -> Self {
+        self.ws_base = trim_base(base.into());
+        self
+    }
+
+    /// Override the IIIF image server base
+    pub fn with_iiif_base(mut self, base: impl Into<String>) -> Self {
+        self.iiif_base = trim_base(base.into());
+        self
+    }
+
+    /// Override the detected environment
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Make these endpoints the global default returned by [`endpoints()`]
+    pub fn install(self) {
+        ENDPOINTS.with(|e| *e.borrow_mut() = Some(self));
+    }
+
+    pub fn environment(&self) -> Environment {
+        self.environment
+    }
+
+    pub fn api_base(&self) -> &str {
+        &self.api_base
+    }
+
+    pub fn ws_base(&self) -> &str {
+        &self.ws_base
+    }
+
+    pub fn iiif_base(&self) -> &str {
+        &self.iiif_base
+    }
+
+    /// Build an API URL
+    pub fn api(&self, path: &str) -> String {
+        join(&self.api_base, path)
+    }
+
+    /// Build a WebSocket URL
+    pub fn ws(&self, path: &str) -> String {
+        join(&self.ws_base, path)
+    }
+
+    /// WebSocket URL for a flow room (`/ws/{room_id}`)
+    pub fn ws_room(&self, room_id: &str) -> String {
+        self.ws(&format!("/ws/{}", encode_component(room_id)))
+    }
+
+    /// IIIF image URL from a concatenated asset ID (`policy_id` + `asset_name_hex`)
+    ///
+    /// Returns `None` if the ID is too short to hold a policy ID and asset name.
+    pub fn iiif(&self, asset_id: &str, size: u16) -> Option<String> {
+        let (policy_id, asset_name_hex) = split_asset_id(asset_id)?;
+        Some(self.iiif_parts(policy_id, asset_name_hex, size))
+    }
+
+    /// IIIF image URL from a policy ID and hex asset name
+    pub fn iiif_parts(&self, policy_id: &str, asset_name_hex: &str, size: u16) -> String {
+        format!(
+            "{}/{size},/0/default.jpg",
+            self.iiif_full_base(policy_id, asset_name_hex)
+        )
+    }
+
+    /// IIIF region base (`.../{policy}:{name}/full`) for callers that pick
+    /// their own size segment
+    pub fn iiif_full_base(&self, policy_id: &str, asset_name_hex: &str) -> String {
+        format!("{}/{policy_id}:{asset_name_hex}/full", self.iiif_base)
+    }
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Self::from_location()
+    }
+}
+
+thread_local! {
+    /// Installed endpoints (WASM is single-threaded)
+    static ENDPOINTS: RefCell<Option<Endpoints>> = const { RefCell::new(None) };
+}
+
+/// Get the installed endpoints, or derive them from the page location
+pub fn endpoints() -> Endpoints {
+    ENDPOINTS.with(|e| e.borrow().clone().unwrap_or_default())
+}
+
+/// Append query parameters to a URL, percent-encoding values
+///
+/// # Example
+/// ```
+/// use ui_core::urls::append_query;
+///
+/// let url = append_query("wss://host/ws/lobby", &[("user_name", "Ada L")]);
+/// assert_eq!(url, "wss://host/ws/lobby?user_name=Ada%20L");
+/// ```
+pub fn append_query(url: &str, params: &[(&str, &str)]) -> String {
+    let mut out = url.to_string();
+    for (i, (key, value)) in params.iter().enumerate() {
+        let sep = if i == 0 && !url.contains('?') {
+            '?'
+        } else {
+            '&'
+        };
+        out.push(sep);
+        out.push_str(&encode_component(key));
+        out.push('=');
+        out.push_str(&encode_component(value));
+    }
+    out
+}
+
+/// Percent-encode a URL component (RFC 3986 unreserved characters pass through)
+pub fn encode_component(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+/// Split a concatenated asset ID into `(policy_id, asset_name_hex)`
+pub fn split_asset_id(asset_id: &str) -> Option<(&str, &str)> {
+    if asset_id.len() <= POLICY_ID_HEX_LEN || !asset_id.is_char_boundary(POLICY_ID_HEX_LEN) {
+        return None;
+    }
+    Some(asset_id.split_at(POLICY_ID_HEX_LEN))
+}
+
+fn current_host() -> Option<String> {
+    browser_location()?.host().ok()
+}
+
+/// The page location, or `None` outside a browser (including native tests)
+fn browser_location() -> Option<web_sys::Location> {
+    if cfg!(target_arch = "wasm32") {
+        web_sys::window().map(|w| w.location())
+    } else {
+        None
+    }
+}
+
+fn trim_base(base: String) -> String {
+    base.trim_end_matches('/').to_string()
+}
+
+fn host_of(url: &str) -> &str {
+    let rest = url.split_once("://").map(|(_, r)| r).unwrap_or(url);
+    rest.split('/').next().unwrap_or(rest)
+}
+
+fn join(base: &str, path: &str) -> String {
+    if path.is_empty() {
+        base.to_string()
+    } else if path.starts_with('/') {
+        format!("{base}{path}")
+    } else {
+        format!("{base}/{path}")
+    }
+}
 
 /// Build a widget URL with the given token.
 ///
@@ -120,4 +442,63 @@ mod tests {
         let url = build_widget_url_optional("/map/", None);
         assert_eq!(url, None);
     }
+
+    const POLICY: &str = "b3dab69f7e6100849434fb1781e34bd12a916557f6231b8d2629b6f6";
+
+    #[test]
+    fn test_endpoints_builders() {
+        let endpoints = Endpoints::new("https://api.example.com/", "wss://rt.example.com");
+
+        assert_eq!(endpoints.environment(), Environment::Production);
+        assert_eq!(endpoints.api("/v1/me"), "https://api.example.com/v1/me");
+        assert_eq!(endpoints.api("v1/me"), "https://api.example.com/v1/me");
+        assert_eq!(
+            endpoints.ws_room("lobby 1"),
+            "wss://rt.example.com/ws/lobby%201"
+        );
+        assert_eq!(
+            endpoints.ws("/memory/abc"),
+            "wss://rt.example.com/memory/abc"
+        );
+    }
+
+    #[test]
+    fn test_endpoints_iiif() {
+        let endpoints =
+            Endpoints::for_host("localhost:8787").with_iiif_base("https://img.test/iiif/");
+        let asset_id = format!("{POLICY}506972617465313839");
+
+        assert_eq!(
+            endpoints.iiif(&asset_id, 400),
+            Some(format!(
+                "https://img.test/iiif/{POLICY}:506972617465313839/full/400,/0/default.jpg"
+            ))
+        );
+        assert_eq!(endpoints.iiif(POLICY, 400), None);
+        assert_eq!(endpoints.iiif("short", 400), None);
+    }
+
+    #[test]
+    fn test_environment_from_host() {
+        assert_eq!(Environment::from_host("127.0.0.1:3000"), Environment::Local);
+        assert_eq!(
+            Environment::from_host("flow-demo.acct.workers.dev"),
+            Environment::Staging
+        );
+        assert_eq!(Environment::from_host("cnft.dev"), Environment::Production);
+        assert_eq!(
+            Endpoints::new("http://localhost:8787", "ws://localhost:8787").environment(),
+            Environment::Local
+        );
+    }
+
+    #[test]
+    fn test_append_query() {
+        assert_eq!(
+            append_query("/ws/room", &[("user_id", "01H"), ("user_name", "a&b")]),
+            "/ws/room?user_id=01H&user_name=a%26b"
+        );
+        assert_eq!(append_query("/x?a=1", &[("b", "2")]), "/x?a=1&b=2");
+        assert_eq!(append_query("/x", &[]), "/x");
+    }
 }
@@ -0,0 +1,296 @@
+//! Proc-macros for forward-compatible Flow protocol types
+//!
+//! Re-exported from `ui-flow-protocol`; depend on that crate rather than this
+//! one.
+//!
+//! ## `#[flow_delta]`
+//!
+//! Place above the `#[derive(Serialize, Deserialize)]` of a Delta enum:
+//!
+//! ```ignore
+//! use ui_flow_protocol::flow_delta;
+//!
+//! #[flow_delta]
+//! #[derive(Debug, Clone, Serialize, Deserialize)]
+//! #[serde(tag = "type", rename_all = "snake_case")]
+//! pub enum GameDelta {
+//!     ScoreChanged { score: u32 },
+//!     PlayerLeft { user_id: String },
+//! }
+//! ```
+//!
+//! The macro appends an `Unknown(RawDelta)` variant. A delta whose variant
+//! name this build doesn't know decodes into `Unknown` with the raw value
+//! instead of failing the whole `Delta`/`Deltas` message, so the server can
+//! add variants without breaking deployed clients. Known variants still
+//! decode strictly. `Unknown` re-serializes the raw value unchanged.
+//!
+//! Also generated:
+//!
+//! - `KNOWN_VARIANTS` - wire names this build decodes
+//! - `is_unknown()` - whether a value is the fallback variant
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Attribute, Expr, ItemEnum, Lit, Meta, Token};
+
+/// Forward-compatible deserialization for a Delta enum
+///
+/// See crate-level documentation for usage.
+#[proc_macro_attribute]
+pub fn flow_delta(args: TokenStream, input: TokenStream) -> TokenStream {
+    if !args.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`#[flow_delta]` takes no arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let item = parse_macro_input!(input as ItemEnum);
+    match generate(item) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn generate(mut item: ItemEnum) -> syn::Result<TokenStream2> {
+    let ident = item.ident.clone();
+
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item.generics,
+            "`#[flow_delta]` does not support generic enums",
+        ));
+    }
+    if !derives(&item.attrs, "Serialize") || !derives(&item.attrs, "Deserialize") {
+        return Err(syn::Error::new_spanned(
+            &ident,
+            "`#[flow_delta]` must be placed above `#[derive(Serialize, Deserialize)]`",
+        ));
+    }
+    if let Some(existing) = item.variants.iter().find(|v| v.ident == "Unknown") {
+        return Err(syn::Error::new_spanned(
+            existing,
+            "`#[flow_delta]` adds its own `Unknown` variant",
+        ));
+    }
+
+    let container = serde_options(&item.attrs)?;
+    let rename_all = container
+        .rename_all
+        .as_deref()
+        .map(|rule| RenameRule::parse(rule, &ident))
+        .transpose()?;
+
+    let mut known = Vec::new();
+    for variant in &item.variants {
+        let options = serde_options(&variant.attrs)?;
+        if options.skip {
+            continue;
+        }
+        let name = match (options.rename, rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => rule.apply(&variant.ident.to_string()),
+            (None, None) => variant.ident.to_string(),
+        };
+        known.push(name);
+        known.extend(options.aliases);
+    }
+
+    let tag = match container.tag {
+        Some(tag) => quote! { ::core::option::Option::Some(#tag) },
+        None => quote! { ::core::option::Option::None },
+    };
+
+    item.attrs
+        .push(syn::parse_quote!(#[serde(remote = "Self")]));
+    item.variants.push(syn::parse_quote! {
+        /// A variant this build doesn't know, kept as the raw decoded value
+        #[serde(skip)]
+        Unknown(::ui_flow_protocol::RawDelta)
+    });
+
+    Ok(quote! {
+        #item
+
+        impl #ident {
+            /// Wire names of the variants this build decodes
+            pub const KNOWN_VARIANTS: &'static [&'static str] = &[#(#known),*];
+
+            /// Whether this delta is a variant this build doesn't know
+            pub fn is_unknown(&self) -> bool {
+                ::core::matches!(self, Self::Unknown(_))
+            }
+        }
+
+        impl ::ui_flow_protocol::__private::serde::Serialize for #ident {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::ui_flow_protocol::__private::serde::Serializer,
+            {
+                match self {
+                    Self::Unknown(raw) => {
+                        ::ui_flow_protocol::__private::serde::Serialize::serialize(raw, serializer)
+                    }
+                    _ => #ident::serialize(self, serializer),
+                }
+            }
+        }
+
+        impl<'de> ::ui_flow_protocol::__private::serde::Deserialize<'de> for #ident {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: ::ui_flow_protocol::__private::serde::Deserializer<'de>,
+            {
+                let raw = <::ui_flow_protocol::RawDelta as ::ui_flow_protocol::__private::serde::Deserialize>::deserialize(deserializer)?;
+                let known = ::ui_flow_protocol::variant_name(&raw, #tag)
+                    .is_some_and(|name| Self::KNOWN_VARIANTS.contains(&name));
+                if known {
+                    let bytes = ::ui_flow_protocol::__private::raw_bytes(&raw);
+                    let mut de =
+                        ::ui_flow_protocol::__private::rmp_serde::Deserializer::new(bytes.as_slice());
+                    #ident::deserialize(&mut de).map_err(
+                        <D::Error as ::ui_flow_protocol::__private::serde::de::Error>::custom,
+                    )
+                } else {
+                    ::core::result::Result::Ok(Self::Unknown(raw))
+                }
+            }
+        }
+    })
+}
+
+/// Whether `#[derive(...)]` lists a trait whose path ends in `name`
+fn derives(attrs: &[Attribute], name: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|path| path.segments.last().is_some_and(|s| s.ident == name))
+}
+
+/// The `#[serde(...)]` options that decide a variant's wire name
+#[derive(Default)]
+struct SerdeOptions {
+    tag: Option<String>,
+    rename_all: Option<String>,
+    rename: Option<String>,
+    aliases: Vec<String>,
+    skip: bool,
+}
+
+fn serde_options(attrs: &[Attribute]) -> syn::Result<SerdeOptions> {
+    let mut options = SerdeOptions::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            match meta {
+                Meta::NameValue(nv) => {
+                    let Some(key) = nv.path.get_ident().map(ToString::to_string) else {
+                        continue;
+                    };
+                    let value = match nv.value {
+                        Expr::Lit(syn::ExprLit {
+                            lit: Lit::Str(s), ..
+                        }) => s.value(),
+                        _ => continue,
+                    };
+                    match key.as_str() {
+                        "tag" => options.tag = Some(value),
+                        "rename_all" => options.rename_all = Some(value),
+                        "rename" => options.rename = Some(value),
+                        "alias" => options.aliases.push(value),
+                        _ => {}
+                    }
+                }
+                Meta::Path(path) => {
+                    if path.is_ident("skip") || path.is_ident("skip_deserializing") {
+                        options.skip = true;
+                    }
+                }
+                Meta::List(list) => {
+                    if list.path.is_ident("rename_all") || list.path.is_ident("rename") {
+                        return Err(syn::Error::new_spanned(
+                            list,
+                            "`#[flow_delta]` needs a single name; use `rename = \"...\"`",
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(options)
+}
+
+/// serde's `rename_all` rules, applied to PascalCase variant names
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(rule: &str, span: &syn::Ident) -> syn::Result<Self> {
+        Ok(match rule {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            other => {
+                return Err(syn::Error::new_spanned(
+                    span,
+                    format!("unknown rename_all rule `{other}`"),
+                ))
+            }
+        })
+    }
+
+    fn apply(self, variant: &str) -> String {
+        match self {
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Pascal => variant.to_string(),
+            Self::Camel => {
+                let mut chars = variant.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_ascii_lowercase().to_string() + chars.as_str()
+                })
+            }
+            Self::Snake => snake_case(variant),
+            Self::ScreamingSnake => snake_case(variant).to_ascii_uppercase(),
+            Self::Kebab => snake_case(variant).replace('_', "-"),
+            Self::ScreamingKebab => snake_case(variant).replace('_', "-").to_ascii_uppercase(),
+        }
+    }
+}
+
+fn snake_case(variant: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in variant.char_indices() {
+        if i > 0 && ch.is_uppercase() {
+            snake.push('_');
+        }
+        snake.push(ch.to_ascii_lowercase());
+    }
+    snake
+}
@@ -0,0 +1,212 @@
+//! Compatibility checks between Delta versions
+//!
+//! Deployed clients keep decoding with the Delta type they were built with
+//! while the server moves on. These helpers encode samples of the newer type
+//! and decode them as the older one, bare and wrapped in `Delta`/`Deltas`
+//! messages, so a breaking change fails a test instead of a live client.
+//!
+//! ```ignore
+//! // Keep a copy of the last released enum next to the current one
+//! mod v1 {
+//!     #[flow_delta]
+//!     #[derive(Debug, Serialize, Deserialize)]
+//!     pub enum GameDelta { ScoreChanged { score: u32 } }
+//! }
+//!
+//! #[test]
+//! fn deltas_stay_forward_compatible() {
+//!     let decoded = assert_forward_compatible::<v1::GameDelta, GameDelta>(&[
+//!         GameDelta::ScoreChanged { score: 1 },
+//!         GameDelta::BonusAwarded { amount: 5 },
+//!     ]);
+//!     assert!(decoded[1].is_unknown());
+//! }
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+use crate::{decode, encode, ProtocolError, ServerMessage};
+
+/// Encode `value` and decode it as `Old`, as an older client would
+pub fn decode_as<Old, New>(value: &New) -> Result<Old, ProtocolError>
+where
+    Old: DeserializeOwned,
+    New: Serialize,
+{
+    decode(&encode(value)?)
+}
+
+/// Assert every sample of `New` decodes as `Old`; returns the decoded values
+///
+/// Each sample is checked bare, inside `ServerMessage::Delta`, and all of
+/// them together inside `ServerMessage::Deltas`.
+///
+/// # Panics
+///
+/// With the failing sample and decode error if any sample fails to decode.
+pub fn assert_forward_compatible<Old, New>(samples: &[New]) -> Vec<Old>
+where
+    Old: DeserializeOwned + Debug,
+    New: Serialize + Debug,
+{
+    let decoded = samples
+        .iter()
+        .map(|sample| {
+            decode_as::<Old, _>(sample)
+                .unwrap_or_else(|e| panic!("{sample:?} does not decode as the older type: {e}"))
+        })
+        .collect();
+
+    for sample in samples {
+        let msg = ServerMessage::<(), &New, ()>::delta(sample, 1, 0);
+        decode_as::<ServerMessage<(), Old, ()>, _>(&msg)
+            .unwrap_or_else(|e| panic!("Delta message with {sample:?} fails to decode: {e}"));
+    }
+
+    let msg = ServerMessage::<(), &New, ()>::deltas(samples.iter().collect(), 1, 0);
+    decode_as::<ServerMessage<(), Old, ()>, _>(&msg)
+        .unwrap_or_else(|e| panic!("Deltas message fails to decode: {e}"));
+
+    decoded
+}
+
+/// Assert every sample survives an encode/decode roundtrip unchanged
+///
+/// # Panics
+///
+/// With the failing sample if it fails to decode or decodes differently.
+pub fn assert_roundtrip<T>(samples: &[T])
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    for sample in samples {
+        let decoded: T =
+            decode_as(sample).unwrap_or_else(|e| panic!("{sample:?} does not roundtrip: {e}"));
+        assert_eq!(&decoded, sample, "{sample:?} changed in a roundtrip");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow_delta;
+    use serde::Deserialize;
+
+    mod v1 {
+        use super::*;
+
+        #[flow_delta]
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        pub enum TaggedDelta {
+            CounterChanged { value: u64 },
+            UserLeft { user_id: String },
+        }
+
+        #[flow_delta]
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        pub enum PlainDelta {
+            Reset,
+            Scored(u32),
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum TaggedDelta {
+        CounterChanged { value: u64 },
+        UserLeft { user_id: String },
+        BonusAwarded { amount: u32 },
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum PlainDelta {
+        Reset,
+        Scored(u32),
+        Doubled,
+        Renamed { name: String },
+    }
+
+    #[test]
+    fn test_known_variants() {
+        assert_eq!(
+            v1::TaggedDelta::KNOWN_VARIANTS,
+            &["counter_changed", "user_left"]
+        );
+        assert_eq!(v1::PlainDelta::KNOWN_VARIANTS, &["Reset", "Scored"]);
+    }
+
+    #[test]
+    fn test_internally_tagged_unknown_variant() {
+        let decoded = assert_forward_compatible::<v1::TaggedDelta, _>(&[
+            TaggedDelta::CounterChanged { value: 3 },
+            TaggedDelta::BonusAwarded { amount: 5 },
+        ]);
+
+        assert_eq!(decoded[0], v1::TaggedDelta::CounterChanged { value: 3 });
+        assert!(decoded[1].is_unknown());
+        assert!(!decoded[0].is_unknown());
+    }
+
+    #[test]
+    fn test_externally_tagged_unknown_variants() {
+        let decoded = assert_forward_compatible::<v1::PlainDelta, _>(&[
+            PlainDelta::Reset,
+            PlainDelta::Scored(7),
+            PlainDelta::Doubled,
+            PlainDelta::Renamed {
+                name: "pirate".into(),
+            },
+        ]);
+
+        assert_eq!(decoded[0], v1::PlainDelta::Reset);
+        assert_eq!(decoded[1], v1::PlainDelta::Scored(7));
+        assert!(decoded[2].is_unknown());
+        assert!(decoded[3].is_unknown());
+    }
+
+    #[test]
+    fn test_unknown_reserializes_raw_value() {
+        let sample = TaggedDelta::BonusAwarded { amount: 5 };
+        let unknown: v1::TaggedDelta = decode_as(&sample).unwrap();
+        let back: TaggedDelta = decode_as(&unknown).unwrap();
+        assert_eq!(back, sample);
+    }
+
+    #[test]
+    fn test_malformed_known_variant_still_fails() {
+        #[derive(Serialize)]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        enum Broken {
+            CounterChanged { value: String },
+        }
+
+        let result = decode_as::<v1::TaggedDelta, _>(&Broken::CounterChanged {
+            value: "three".into(),
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_flow_delta_roundtrip() {
+        assert_roundtrip(&[
+            v1::TaggedDelta::CounterChanged { value: 1 },
+            v1::TaggedDelta::UserLeft {
+                user_id: "u1".into(),
+            },
+        ]);
+        assert_roundtrip(&[v1::PlainDelta::Reset, v1::PlainDelta::Scored(2)]);
+    }
+
+    #[test]
+    fn test_plain_enum_is_not_forward_compatible() {
+        #[derive(Debug, Serialize, Deserialize)]
+        enum Strict {
+            Reset,
+        }
+
+        assert!(decode_as::<Strict, _>(&PlainDelta::Doubled).is_err());
+    }
+}
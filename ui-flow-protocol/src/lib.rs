@@ -17,6 +17,68 @@
 //! | 3000-3999 | Signalling | WebRTC connection setup |
 //! | 4000-4999 | Notifications | Application events |
 //! | 5000-5999 | Action feedback | Optimistic UI support |
+//! | 6000-6999 | Commands | Operator instructions (reload, resync, maintenance) |
+//!
+//! ## Private Deltas
+//!
+//! `PrivateDelta` carries state only one user may see (their own hand, a
+//! race-mode card flip). It is stamped with the current shared `seq` but
+//! doesn't advance it, so other clients' delta replay is unaffected. Servers
+//! route it with an [`Audience`]; clients keep it apart from shared state
+//! (see `SyncedState` in ui-flow).
+//!
+//! ## Domain Snapshots
+//!
+//! Large rooms can split their state into named domains (`"chat"`,
+//! `"leaderboard"`, ...) that clients pull on demand. A client sends
+//! `SnapshotRequest { domain }` and gets back a `DomainSnapshot` carrying
+//! that slice as encoded bytes (build it with
+//! [`ServerMessage::domain_snapshot_with`], read it with [`decode`]).
+//! Requesting a domain also subscribes the connection to its `Notify`
+//! events, so the slice can be kept current. Unknown domains get a
+//! non-fatal `Error` with code [`UNKNOWN_DOMAIN`].
+//!
+//! ## Acknowledged Notifications
+//!
+//! `Notify` is fire-and-forget by default, so an event sent while a client is
+//! disconnected is lost. Critical events (a reward granted, a trade settled)
+//! can be sent AckRequired by setting `ack_id`: the client replies
+//! `NotifyAck { id }` once it has handled the event. Servers keep unacked
+//! events in a [`NotifyOutbox`] per user and redeliver them when the user
+//! reconnects; clients use [`SeenNotifies`] to ack a redelivered event
+//! without handling it twice.
+//!
+//! ## Leaving
+//!
+//! A client that leaves on purpose sends `Goodbye { reason }` before closing
+//! its socket, so servers can drop the user's presence immediately instead of
+//! holding a ghost until the connection times out. On page unload the
+//! browser client can also POST the encoded `Goodbye` with
+//! `navigator.sendBeacon` to an app-chosen URL, since frames queued on a
+//! socket that is being torn down may never arrive.
+//!
+//! ## Size Limits & Validation
+//!
+//! Servers should decode untrusted client messages with [`decode_validated`],
+//! which rejects payloads over a byte limit before decoding and then runs the
+//! message's [`Validate`] hook (string length and collection caps). The
+//! built-in client messages validate themselves; applications implement
+//! [`Validate`] for their `Action` type.
+//!
+//! ## End-to-End Encryption
+//!
+//! Rooms whose content the server must not read (private chats) can use
+//! [`Sealed`] as their `Action`/`Delta` type. Clients exchange X25519 keys
+//! through `Signal` messages ([`SignalPayload::KeyExchange`], then
+//! [`SignalPayload::RoomKey`] to share a room key) and seal payloads with
+//! AES-256-GCM; the server only relays and stores the opaque bytes. The
+//! crypto lives in `secure` behind the `secure` feature.
+//!
+//! ## Schema Evolution
+//!
+//! Mark Delta enums with [`flow_delta`] so variants added on the server decode
+//! as `Unknown` on older clients instead of failing the message, and check
+//! it with the [`compat`] test helpers.
 //!
 //! ## Example
 //!
@@ -48,12 +110,22 @@
 //! let client_msg: ClientMessage<MyAction> = decode(&bytes)?;
 //! ```
 
+// Lets `#[flow_delta]` output name this crate from its own tests
+extern crate self as ui_flow_protocol;
+
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::fmt;
 use std::sync::atomic::{AtomicU64, Ordering};
 use thiserror::Error;
 
+pub mod compat;
+#[cfg(feature = "secure")]
+pub mod secure;
+
+#[cfg(feature = "secure")]
+pub use secure::{KeyPair, SecureCodec, SecureError};
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Error Types
 // ─────────────────────────────────────────────────────────────────────────────
@@ -69,6 +141,12 @@ pub enum ProtocolError {
 
     #[error("Unknown message tag: {0}")]
     UnknownTag(u16),
+
+    #[error("Message is {size} bytes, limit is {max}")]
+    TooLarge { size: usize, max: usize },
+
+    #[error("Invalid message: {0}")]
+    Invalid(#[from] ValidationError),
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -85,6 +163,86 @@ pub fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, ProtocolError> {
     rmp_serde::from_slice(bytes).map_err(ProtocolError::from)
 }
 
+/// Default size limit for client-to-server messages
+pub const DEFAULT_MAX_CLIENT_MESSAGE_BYTES: usize = 64 * 1024;
+
+/// Encode a message, failing if the result exceeds `max_bytes`
+pub fn encode_limited<T: Serialize>(msg: &T, max_bytes: usize) -> Result<Vec<u8>, ProtocolError> {
+    let bytes = encode(msg)?;
+    check_size(bytes.len(), max_bytes)?;
+    Ok(bytes)
+}
+
+/// Decode a message, rejecting payloads over `max_bytes` without decoding
+pub fn decode_limited<T: DeserializeOwned>(
+    bytes: &[u8],
+    max_bytes: usize,
+) -> Result<T, ProtocolError> {
+    check_size(bytes.len(), max_bytes)?;
+    decode(bytes)
+}
+
+/// Decode an untrusted message with a size limit, then validate it
+pub fn decode_validated<T: DeserializeOwned + Validate>(
+    bytes: &[u8],
+    max_bytes: usize,
+) -> Result<T, ProtocolError> {
+    let msg: T = decode_limited(bytes, max_bytes)?;
+    msg.validate()?;
+    Ok(msg)
+}
+
+fn check_size(size: usize, max: usize) -> Result<(), ProtocolError> {
+    if size > max {
+        return Err(ProtocolError::TooLarge { size, max });
+    }
+    Ok(())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Schema Evolution
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Forward-compatible deserialization for Delta enums
+///
+/// Unknown variants decode into a generated `Unknown(RawDelta)` variant
+/// instead of failing the whole message. See [`compat`] for testing that an
+/// older Delta type still decodes a newer one.
+pub use ui_flow_protocol_macros::flow_delta;
+
+/// Undecoded value of a delta variant this build doesn't know
+pub type RawDelta = rmpv::Value;
+
+/// Variant name of a serialized enum value
+///
+/// `tag` is the `#[serde(tag = "...")]` field for internally tagged enums;
+/// `None` reads serde's default external tagging.
+pub fn variant_name<'a>(raw: &'a RawDelta, tag: Option<&str>) -> Option<&'a str> {
+    match (raw, tag) {
+        (RawDelta::Map(entries), Some(tag)) => entries
+            .iter()
+            .find(|(key, _)| key.as_str() == Some(tag))
+            .and_then(|(_, name)| name.as_str()),
+        (RawDelta::String(name), None) => name.as_str(),
+        (RawDelta::Map(entries), None) if entries.len() == 1 => entries[0].0.as_str(),
+        _ => None,
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use rmp_serde;
+    pub use serde;
+
+    /// Re-encode a raw value so known variants decode with rmp-serde's own
+    /// enum handling
+    pub fn raw_bytes(raw: &super::RawDelta) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        rmpv::encode::write_value(&mut bytes, raw).expect("writing to a Vec cannot fail");
+        bytes
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Operation ID
 // ─────────────────────────────────────────────────────────────────────────────
@@ -140,6 +298,8 @@ pub enum ServerTag {
     Snapshot = 1000,
     Delta = 1001,
     Deltas = 1002,
+    PrivateDelta = 1003,
+    DomainSnapshot = 1004,
 
     // Presence (2000-2999)
     Presence = 2000,
@@ -154,6 +314,9 @@ pub enum ServerTag {
     Progress = 5000,
     ActionOk = 5001,
     ActionErr = 5002,
+
+    // Commands (6000-6999)
+    Command = 6000,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -167,6 +330,8 @@ pub enum ClientTag {
     // Core protocol (0-999)
     Ping = 0,
     Resync = 1,
+    SnapshotRequest = 2,
+    Goodbye = 3,
 
     // Actions (1000-1999)
     Action = 1000,
@@ -177,6 +342,9 @@ pub enum ClientTag {
 
     // WebRTC signalling (3000-3999)
     Signal = 3000,
+
+    // Notifications (4000-4999)
+    NotifyAck = 4000,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -205,6 +373,41 @@ pub enum PresenceStatus {
     Away,
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Routing
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Which users a server message is delivered to
+///
+/// Server-side only; never sent on the wire. Broadcast helpers check each
+/// connection's user against it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Audience {
+    /// Every connection
+    #[default]
+    All,
+    /// Only connections of these users
+    Users(Vec<String>),
+    /// Every connection except those of these users
+    AllExcept(Vec<String>),
+}
+
+impl Audience {
+    /// Only connections of a single user (all their tabs/devices)
+    pub fn user(user_id: impl Into<String>) -> Self {
+        Self::Users(vec![user_id.into()])
+    }
+
+    /// Whether a connection for `user_id` should receive the message
+    pub fn includes(&self, user_id: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Users(users) => users.iter().any(|u| u == user_id),
+            Self::AllExcept(users) => !users.iter().any(|u| u == user_id),
+        }
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // WebRTC Signalling Types
 // ─────────────────────────────────────────────────────────────────────────────
@@ -223,12 +426,78 @@ pub enum SignalPayload {
         sdp_mid: Option<String>,
         sdp_m_line_index: Option<u16>,
     },
+    /// X25519 public key offered for end-to-end payload encryption
+    KeyExchange {
+        #[serde(with = "serde_bytes")]
+        public_key: Vec<u8>,
+    },
+    /// Room key sealed with the sender/recipient pairwise key
+    RoomKey { sealed: Sealed },
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// End-to-End Encryption
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Length of an X25519 public key, in bytes
+pub const PUBLIC_KEY_LEN: usize = 32;
+
+/// Length of an AES-GCM nonce, in bytes
+pub const NONCE_LEN: usize = 12;
+
+/// An encrypted payload the server relays without reading
+///
+/// Used as the `Action` or `Delta` type of an end-to-end encrypted room.
+/// `key_id` names the room key generation so clients can rotate keys and
+/// tell a stale key from a tampered message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sealed {
+    /// Room key generation the payload was sealed with
+    pub key_id: u32,
+    /// AES-GCM nonce
+    #[serde(with = "serde_bytes")]
+    pub nonce: Vec<u8>,
+    /// Ciphertext with authentication tag
+    #[serde(with = "serde_bytes")]
+    pub ciphertext: Vec<u8>,
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Client Commands
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Operator instruction sent to connected clients
+///
+/// Lets a server drain or upgrade gracefully, e.g. tell every client to
+/// reload after a deploy. Commands added later decode as `Unknown` on older
+/// clients, which ignore them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ClientCommand {
+    /// Discard local state and request a fresh snapshot
+    ForceResync,
+    /// Reload the page after a random delay of up to `max_delay_ms`
+    ///
+    /// The delay spreads reconnects so a deploy doesn't cause a stampede.
+    Reload { max_delay_ms: u32 },
+    /// Show a maintenance notice, optionally with an end time (unix ms)
+    ShowMaintenanceBanner { message: String, until: Option<u64> },
+    /// Hide a previously shown maintenance notice
+    HideMaintenanceBanner,
+    /// Navigate the page to another URL
+    RedirectTo { url: String },
+    /// A command this build doesn't know
+    #[serde(other)]
+    Unknown,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Server Messages
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Error code sent when a client requests a domain the room doesn't have
+pub const UNKNOWN_DOMAIN: &str = "unknown_domain";
+
 /// Server-to-client message
 ///
 /// Generic over:
@@ -246,7 +515,10 @@ pub enum ServerMessage<State, Delta, Event> {
     Connected {
         /// Protocol version for compatibility checking
         protocol_version: u8,
-        /// Server-assigned connection ID (for debugging/logging)
+        /// Server-assigned connection ID
+        ///
+        /// Also authorizes the client's leave beacon, so it should be
+        /// unguessable and never sent to other clients.
         connection_id: String,
     },
 
@@ -306,6 +578,33 @@ pub enum ServerMessage<State, Delta, Event> {
         timestamp: u64,
     },
 
+    /// Incremental update visible only to the receiving user
+    ///
+    /// Applies on top of shared state at `seq` without advancing it.
+    #[serde(rename = "1003")]
+    PrivateDelta {
+        /// Private change to apply
+        delta: Delta,
+        /// Shared sequence number this delta was sent at (unchanged by it)
+        seq: u64,
+        /// Server timestamp
+        timestamp: u64,
+    },
+
+    /// One domain's slice of state - sent in reply to `SnapshotRequest`
+    #[serde(rename = "1004")]
+    DomainSnapshot {
+        /// Domain the slice belongs to
+        domain: String,
+        /// Encoded slice, application-defined per domain
+        #[serde(with = "serde_bytes")]
+        data: Vec<u8>,
+        /// Shared sequence number the slice was taken at
+        seq: u64,
+        /// Server timestamp
+        timestamp: u64,
+    },
+
     // ─────────────────────────────────────────────────────────────
     // Presence (2000-2999)
     // ─────────────────────────────────────────────────────────────
@@ -344,6 +643,10 @@ pub enum ServerMessage<State, Delta, Event> {
         event: Event,
         /// Optional correlation ID (links to triggering action)
         correlation_id: Option<OpId>,
+        /// Set when the event is AckRequired: the client must reply
+        /// `NotifyAck` with this ID, and the server redelivers until it does
+        #[serde(default)]
+        ack_id: Option<u64>,
     },
 
     // ─────────────────────────────────────────────────────────────
@@ -376,9 +679,56 @@ pub enum ServerMessage<State, Delta, Event> {
         /// Human-readable error message
         message: String,
     },
+
+    // ─────────────────────────────────────────────────────────────
+    // Commands (6000-6999)
+    // ─────────────────────────────────────────────────────────────
+    /// Operator instruction for the client
+    #[serde(rename = "6000")]
+    Command { command: ClientCommand },
 }
 
 impl<State, Delta, Event> ServerMessage<State, Delta, Event> {
+    /// Wire tag of this message
+    pub fn tag(&self) -> ServerTag {
+        match self {
+            Self::Connected { .. } => ServerTag::Connected,
+            Self::Pong { .. } => ServerTag::Pong,
+            Self::Error { .. } => ServerTag::Error,
+            Self::Snapshot { .. } => ServerTag::Snapshot,
+            Self::Delta { .. } => ServerTag::Delta,
+            Self::Deltas { .. } => ServerTag::Deltas,
+            Self::PrivateDelta { .. } => ServerTag::PrivateDelta,
+            Self::DomainSnapshot { .. } => ServerTag::DomainSnapshot,
+            Self::Presence { .. } => ServerTag::Presence,
+            Self::Signal { .. } => ServerTag::Signal,
+            Self::Notify { .. } => ServerTag::Notify,
+            Self::Progress { .. } => ServerTag::Progress,
+            Self::ActionOk { .. } => ServerTag::ActionOk,
+            Self::ActionErr { .. } => ServerTag::ActionErr,
+            Self::Command { .. } => ServerTag::Command,
+        }
+    }
+
+    /// Ack ID of an AckRequired `Notify`
+    pub fn ack_id(&self) -> Option<u64> {
+        match self {
+            Self::Notify { ack_id, .. } => *ack_id,
+            _ => None,
+        }
+    }
+
+    /// Operation this message relates to (action feedback or correlated notify)
+    pub fn op_id(&self) -> Option<OpId> {
+        match self {
+            Self::Progress { op_id, .. }
+            | Self::ActionOk { op_id, .. }
+            | Self::ActionErr { op_id, .. } => Some(*op_id),
+            Self::Notify { correlation_id, .. } => *correlation_id,
+            _ => None,
+        }
+    }
+
     /// Create a Connected message
     pub fn connected(protocol_version: u8, connection_id: String) -> Self {
         Self::Connected {
@@ -444,6 +794,45 @@ impl<State, Delta, Event> ServerMessage<State, Delta, Event> {
         }
     }
 
+    /// Create a PrivateDelta message at the current shared `seq`
+    pub fn private_delta(delta: Delta, seq: u64, timestamp: u64) -> Self {
+        Self::PrivateDelta {
+            delta,
+            seq,
+            timestamp,
+        }
+    }
+
+    /// Create a DomainSnapshot message from an encoded slice
+    pub fn domain_snapshot(
+        domain: impl Into<String>,
+        data: Vec<u8>,
+        seq: u64,
+        timestamp: u64,
+    ) -> Self {
+        Self::DomainSnapshot {
+            domain: domain.into(),
+            data,
+            seq,
+            timestamp,
+        }
+    }
+
+    /// Create a DomainSnapshot message carrying a typed slice
+    pub fn domain_snapshot_with<T: Serialize>(
+        domain: impl Into<String>,
+        slice: &T,
+        seq: u64,
+        timestamp: u64,
+    ) -> Result<Self, ProtocolError> {
+        Ok(Self::domain_snapshot(
+            domain,
+            encode(slice)?,
+            seq,
+            timestamp,
+        ))
+    }
+
     /// Create a Presence message
     pub fn presence(users: Vec<PresenceInfo>) -> Self {
         Self::Presence { users }
@@ -455,6 +844,25 @@ impl<State, Delta, Event> ServerMessage<State, Delta, Event> {
             domain: domain.into(),
             event,
             correlation_id,
+            ack_id: None,
+        }
+    }
+
+    /// Create an AckRequired Notify message
+    ///
+    /// Usually built by [`NotifyOutbox::push`], which also tracks it for
+    /// redelivery.
+    pub fn notify_ack_required(
+        domain: impl Into<String>,
+        event: Event,
+        correlation_id: Option<OpId>,
+        ack_id: u64,
+    ) -> Self {
+        Self::Notify {
+            domain: domain.into(),
+            event,
+            correlation_id,
+            ack_id: Some(ack_id),
         }
     }
 
@@ -467,11 +875,26 @@ impl<State, Delta, Event> ServerMessage<State, Delta, Event> {
         }
     }
 
+    /// Create a Command message
+    pub fn command(command: ClientCommand) -> Self {
+        Self::Command { command }
+    }
+
     /// Create an ActionOk message
     pub fn action_ok(op_id: OpId, result: Option<Vec<u8>>) -> Self {
         Self::ActionOk { op_id, result }
     }
 
+    /// Create an ActionOk message carrying a typed result
+    ///
+    /// The client decodes it with [`decode_result`].
+    pub fn action_ok_with<R: Serialize>(op_id: OpId, result: &R) -> Result<Self, ProtocolError> {
+        Ok(Self::ActionOk {
+            op_id,
+            result: Some(encode(result)?),
+        })
+    }
+
     /// Create an ActionErr message
     pub fn action_err(op_id: OpId, message: impl Into<String>) -> Self {
         Self::ActionErr {
@@ -499,6 +922,12 @@ impl<State, Delta, Event> ServerMessage<State, Delta, Event> {
 // Client Messages
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Goodbye reason for an explicit close by the app
+pub const GOODBYE_CLOSED: &str = "closed";
+
+/// Goodbye reason sent when the page is unloaded (tab closed, navigated away)
+pub const GOODBYE_UNLOAD: &str = "unload";
+
 /// Client-to-server message
 ///
 /// Generic over:
@@ -523,6 +952,23 @@ pub enum ClientMessage<Action> {
         last_seq: Option<u64>,
     },
 
+    /// Request one domain's slice of state (also subscribes to the domain)
+    #[serde(rename = "2")]
+    SnapshotRequest {
+        /// Domain to fetch
+        domain: String,
+    },
+
+    /// The client is leaving on purpose (closing, navigating away)
+    ///
+    /// Servers can drop the user's presence right away instead of waiting
+    /// for the socket to time out.
+    #[serde(rename = "3")]
+    Goodbye {
+        /// Why the client left, e.g. [`GOODBYE_CLOSED`] or [`GOODBYE_UNLOAD`]
+        reason: String,
+    },
+
     // ─────────────────────────────────────────────────────────────
     // Actions (1000-1999)
     // ─────────────────────────────────────────────────────────────
@@ -563,9 +1009,42 @@ pub enum ClientMessage<Action> {
         /// Signalling payload (offer, answer, ICE candidate)
         signal: SignalPayload,
     },
+
+    // ─────────────────────────────────────────────────────────────
+    // Notifications (4000-4999)
+    // ─────────────────────────────────────────────────────────────
+    /// The client handled an AckRequired `Notify`
+    #[serde(rename = "4000")]
+    NotifyAck {
+        /// `ack_id` of the notification
+        id: u64,
+    },
 }
 
 impl<Action> ClientMessage<Action> {
+    /// Wire tag of this message
+    pub fn tag(&self) -> ClientTag {
+        match self {
+            Self::Ping { .. } => ClientTag::Ping,
+            Self::Resync { .. } => ClientTag::Resync,
+            Self::SnapshotRequest { .. } => ClientTag::SnapshotRequest,
+            Self::Goodbye { .. } => ClientTag::Goodbye,
+            Self::Action { .. } => ClientTag::Action,
+            Self::Subscribe { .. } => ClientTag::Subscribe,
+            Self::Unsubscribe { .. } => ClientTag::Unsubscribe,
+            Self::Signal { .. } => ClientTag::Signal,
+            Self::NotifyAck { .. } => ClientTag::NotifyAck,
+        }
+    }
+
+    /// Operation ID of an action message
+    pub fn op_id(&self) -> Option<OpId> {
+        match self {
+            Self::Action { op_id, .. } => Some(*op_id),
+            _ => None,
+        }
+    }
+
     /// Create a Ping message
     pub fn ping(ts: u64) -> Self {
         Self::Ping { ts }
@@ -576,6 +1055,20 @@ impl<Action> ClientMessage<Action> {
         Self::Resync { last_seq }
     }
 
+    /// Create a SnapshotRequest message
+    pub fn snapshot_request(domain: impl Into<String>) -> Self {
+        Self::SnapshotRequest {
+            domain: domain.into(),
+        }
+    }
+
+    /// Create a Goodbye message
+    pub fn goodbye(reason: impl Into<String>) -> Self {
+        Self::Goodbye {
+            reason: reason.into(),
+        }
+    }
+
     /// Create an Action message
     pub fn action(op_id: OpId, action: Action) -> Self {
         Self::Action { op_id, action }
@@ -598,6 +1091,382 @@ impl<Action> ClientMessage<Action> {
             signal,
         }
     }
+
+    /// Create a NotifyAck message
+    pub fn notify_ack(id: u64) -> Self {
+        Self::NotifyAck { id }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Request/Response (RPC)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Errors from a request/response call made over an action
+#[derive(Error, Debug)]
+pub enum RpcError {
+    /// The server answered with `ActionErr`
+    #[error("{message}")]
+    Remote {
+        code: Option<String>,
+        message: String,
+    },
+
+    /// The `ActionOk` result did not decode into the expected type
+    #[error("Failed to decode result: {0}")]
+    Decode(#[from] ProtocolError),
+
+    /// No reply arrived before the deadline
+    #[error("Call {0} timed out")]
+    Timeout(OpId),
+
+    /// The connection closed before a reply arrived
+    #[error("Connection closed before reply")]
+    Disconnected,
+}
+
+/// Decode the `result` payload of an `ActionOk` into a typed reply
+///
+/// A missing payload decodes as MessagePack `nil`, so `()` and `Option<T>`
+/// replies work for actions that return nothing.
+pub fn decode_result<R: DeserializeOwned>(result: Option<&[u8]>) -> Result<R, RpcError> {
+    const NIL: &[u8] = &[0xc0];
+    Ok(decode(result.unwrap_or(NIL))?)
+}
+
+/// Calls awaiting an `ActionOk`/`ActionErr`, keyed by [`OpId`]
+///
+/// Transport-agnostic: `T` is whatever the client uses to wake the waiting
+/// caller (a oneshot sender, a callback, ...).
+#[derive(Debug)]
+pub struct PendingCalls<T> {
+    calls: std::collections::HashMap<OpId, T>,
+}
+
+impl<T> PendingCalls<T> {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self {
+            calls: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Register a call awaiting a reply
+    pub fn insert(&mut self, op_id: OpId, waiter: T) {
+        self.calls.insert(op_id, waiter);
+    }
+
+    /// Remove and return the waiter for `op_id`, if one is pending
+    pub fn take(&mut self, op_id: OpId) -> Option<T> {
+        self.calls.remove(&op_id)
+    }
+
+    /// Whether `op_id` is awaiting a reply
+    pub fn contains(&self, op_id: OpId) -> bool {
+        self.calls.contains_key(&op_id)
+    }
+
+    /// Remove every pending waiter (e.g. when the connection drops)
+    pub fn drain(&mut self) -> impl Iterator<Item = (OpId, T)> + '_ {
+        self.calls.drain()
+    }
+
+    /// Number of calls awaiting a reply
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Whether no calls are pending
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+}
+
+impl<T> Default for PendingCalls<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Acknowledged Delivery
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Maximum unacked notifications kept per user; the oldest is dropped past it
+pub const MAX_UNACKED_NOTIFIES: usize = 64;
+
+/// An AckRequired notification waiting for its `NotifyAck`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnackedNotify<Event> {
+    /// Ack ID the client echoes in `NotifyAck`
+    pub id: u64,
+    /// Notification domain
+    pub domain: String,
+    /// Event payload
+    pub event: Event,
+    /// Action that triggered the event, if any
+    pub correlation_id: Option<OpId>,
+}
+
+impl<Event: Clone> UnackedNotify<Event> {
+    /// The `Notify` message to (re)send
+    pub fn to_message<State, Delta>(&self) -> ServerMessage<State, Delta, Event> {
+        ServerMessage::notify_ack_required(
+            self.domain.clone(),
+            self.event.clone(),
+            self.correlation_id,
+            self.id,
+        )
+    }
+}
+
+/// Server-side store of AckRequired notifications, per user
+///
+/// Serializable so a room can persist it alongside its state and redeliver
+/// after being evicted. IDs are unique within one outbox.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifyOutbox<Event> {
+    next_id: u64,
+    pending: std::collections::HashMap<String, std::collections::VecDeque<UnackedNotify<Event>>>,
+}
+
+impl<Event: Clone> NotifyOutbox<Event> {
+    /// Create an empty outbox
+    pub fn new() -> Self {
+        Self {
+            next_id: 1,
+            pending: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Track a notification for `user_id` and return the message to send now
+    pub fn push<State, Delta>(
+        &mut self,
+        user_id: &str,
+        domain: impl Into<String>,
+        event: Event,
+        correlation_id: Option<OpId>,
+    ) -> ServerMessage<State, Delta, Event> {
+        let notify = UnackedNotify {
+            id: self.next_id,
+            domain: domain.into(),
+            event,
+            correlation_id,
+        };
+        self.next_id += 1;
+
+        let message = notify.to_message();
+        let queue = self.pending.entry(user_id.to_string()).or_default();
+        if queue.len() >= MAX_UNACKED_NOTIFIES {
+            queue.pop_front();
+        }
+        queue.push_back(notify);
+        message
+    }
+
+    /// Record a `NotifyAck`; returns whether `id` was pending for the user
+    pub fn ack(&mut self, user_id: &str, id: u64) -> bool {
+        let Some(queue) = self.pending.get_mut(user_id) else {
+            return false;
+        };
+        let before = queue.len();
+        queue.retain(|n| n.id != id);
+        let acked = queue.len() != before;
+        if queue.is_empty() {
+            self.pending.remove(user_id);
+        }
+        acked
+    }
+
+    /// Unacked notifications for a user, oldest first
+    pub fn pending(&self, user_id: &str) -> impl Iterator<Item = &UnackedNotify<Event>> {
+        self.pending.get(user_id).into_iter().flatten()
+    }
+
+    /// Messages to resend when `user_id` reconnects, oldest first
+    pub fn redeliver<State, Delta>(
+        &self,
+        user_id: &str,
+    ) -> Vec<ServerMessage<State, Delta, Event>> {
+        self.pending(user_id)
+            .map(UnackedNotify::to_message)
+            .collect()
+    }
+
+    /// Drop everything pending for a user (e.g. when they leave for good)
+    pub fn forget(&mut self, user_id: &str) {
+        self.pending.remove(user_id);
+    }
+
+    /// Total unacked notifications across users
+    pub fn len(&self) -> usize {
+        self.pending.values().map(|q| q.len()).sum()
+    }
+
+    /// Whether nothing is awaiting an ack
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+impl<Event: Clone> Default for NotifyOutbox<Event> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Ack IDs a client has already handled
+///
+/// A redelivered notification is acked again but not handled twice. Only
+/// the last [`MAX_UNACKED_NOTIFIES`] IDs are remembered.
+#[derive(Debug, Default)]
+pub struct SeenNotifies {
+    ids: std::collections::VecDeque<u64>,
+}
+
+impl SeenNotifies {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `id`; returns false if it was already handled
+    pub fn first_delivery(&mut self, id: u64) -> bool {
+        if self.ids.contains(&id) {
+            return false;
+        }
+        if self.ids.len() >= MAX_UNACKED_NOTIFIES {
+            self.ids.pop_front();
+        }
+        self.ids.push_back(id);
+        true
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Validation
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Maximum subscription domains in one Subscribe/Unsubscribe
+pub const MAX_DOMAINS: usize = 32;
+
+/// Maximum length of a subscription domain or user ID, in bytes
+pub const MAX_ID_LEN: usize = 128;
+
+/// Maximum length of an SDP or ICE candidate string, in bytes
+pub const MAX_SIGNAL_LEN: usize = 16 * 1024;
+
+/// A decoded message broke a content limit
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    #[error("{field} is {len} bytes, maximum is {max}")]
+    TooLong {
+        field: &'static str,
+        len: usize,
+        max: usize,
+    },
+
+    #[error("{field} has {len} items, maximum is {max}")]
+    TooMany {
+        field: &'static str,
+        len: usize,
+        max: usize,
+    },
+
+    #[error("{field} is invalid: {reason}")]
+    Invalid { field: &'static str, reason: String },
+}
+
+/// Content checks run on decoded client messages
+///
+/// Size limits bound the whole payload; `validate` bounds individual fields
+/// so one oversized chat message can't end up in durable storage.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+/// Check that a string is at most `max` bytes
+pub fn check_len(field: &'static str, value: &str, max: usize) -> Result<(), ValidationError> {
+    if value.len() > max {
+        return Err(ValidationError::TooLong {
+            field,
+            len: value.len(),
+            max,
+        });
+    }
+    Ok(())
+}
+
+/// Check that a collection has at most `max` items
+pub fn check_count(field: &'static str, len: usize, max: usize) -> Result<(), ValidationError> {
+    if len > max {
+        return Err(ValidationError::TooMany { field, len, max });
+    }
+    Ok(())
+}
+
+impl<Action: Validate> Validate for ClientMessage<Action> {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::Ping { .. } | Self::Resync { .. } | Self::NotifyAck { .. } => Ok(()),
+            Self::SnapshotRequest { domain } => check_len("domain", domain, MAX_ID_LEN),
+            Self::Goodbye { reason } => check_len("reason", reason, MAX_ID_LEN),
+            Self::Action { action, .. } => action.validate(),
+            Self::Subscribe { domains } | Self::Unsubscribe { domains } => {
+                check_count("domains", domains.len(), MAX_DOMAINS)?;
+                domains
+                    .iter()
+                    .try_for_each(|domain| check_len("domain", domain, MAX_ID_LEN))
+            }
+            Self::Signal {
+                target_user_id,
+                signal,
+            } => {
+                check_len("target_user_id", target_user_id, MAX_ID_LEN)?;
+                signal.validate()
+            }
+        }
+    }
+}
+
+impl Validate for SignalPayload {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::Offer { sdp } | Self::Answer { sdp } => check_len("sdp", sdp, MAX_SIGNAL_LEN),
+            Self::IceCandidate {
+                candidate, sdp_mid, ..
+            } => {
+                check_len("candidate", candidate, MAX_SIGNAL_LEN)?;
+                sdp_mid
+                    .as_deref()
+                    .map_or(Ok(()), |mid| check_len("sdp_mid", mid, MAX_ID_LEN))
+            }
+            Self::KeyExchange { public_key } => {
+                check_exact_len("public_key", public_key.len(), PUBLIC_KEY_LEN)
+            }
+            Self::RoomKey { sealed } => sealed.validate(),
+        }
+    }
+}
+
+impl Validate for Sealed {
+    fn validate(&self) -> Result<(), ValidationError> {
+        check_exact_len("nonce", self.nonce.len(), NONCE_LEN)
+    }
+}
+
+fn check_exact_len(
+    field: &'static str,
+    len: usize,
+    expected: usize,
+) -> Result<(), ValidationError> {
+    if len != expected {
+        return Err(ValidationError::Invalid {
+            field,
+            reason: format!("expected {expected} bytes, got {len}"),
+        });
+    }
+    Ok(())
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -726,6 +1595,17 @@ mod tests {
         } else {
             panic!("Expected Subscribe");
         }
+
+        // Test Goodbye
+        let msg: Msg = ClientMessage::goodbye(GOODBYE_UNLOAD);
+        assert_eq!(msg.tag(), ClientTag::Goodbye);
+        let bytes = encode(&msg).unwrap();
+        let decoded: Msg = decode(&bytes).unwrap();
+        if let ClientMessage::Goodbye { reason } = decoded {
+            assert_eq!(reason, GOODBYE_UNLOAD);
+        } else {
+            panic!("Expected Goodbye");
+        }
     }
 
     #[test]
@@ -766,4 +1646,396 @@ mod tests {
         let decoded: SignalPayload = decode(&bytes).unwrap();
         assert!(matches!(decoded, SignalPayload::IceCandidate { .. }));
     }
+
+    #[test]
+    fn test_sealed_signal_roundtrip() {
+        let signal = SignalPayload::KeyExchange {
+            public_key: vec![7; PUBLIC_KEY_LEN],
+        };
+        let decoded: SignalPayload = decode(&encode(&signal).unwrap()).unwrap();
+        assert!(
+            matches!(&decoded, SignalPayload::KeyExchange { public_key } if public_key.len() == PUBLIC_KEY_LEN)
+        );
+        assert!(decoded.validate().is_ok());
+
+        let sealed = Sealed {
+            key_id: 3,
+            nonce: vec![1; NONCE_LEN],
+            ciphertext: vec![0xAB; 40],
+        };
+        let signal = SignalPayload::RoomKey {
+            sealed: sealed.clone(),
+        };
+        let decoded: SignalPayload = decode(&encode(&signal).unwrap()).unwrap();
+        assert!(matches!(&decoded, SignalPayload::RoomKey { sealed: s } if *s == sealed));
+        assert!(decoded.validate().is_ok());
+
+        let bad_nonce = Sealed {
+            nonce: vec![1; 8],
+            ..sealed
+        };
+        assert!(matches!(
+            bad_nonce.validate(),
+            Err(ValidationError::Invalid { field: "nonce", .. })
+        ));
+        let bad_key = SignalPayload::KeyExchange {
+            public_key: vec![7; 16],
+        };
+        assert!(matches!(
+            bad_key.validate(),
+            Err(ValidationError::Invalid {
+                field: "public_key",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_private_delta_roundtrip() {
+        type Msg = ServerMessage<TestState, TestDelta, TestEvent>;
+
+        let msg: Msg = ServerMessage::private_delta(TestDelta::CounterChanged(7), 12, 0);
+        assert_eq!(msg.tag(), ServerTag::PrivateDelta);
+        let decoded: Msg = decode(&encode(&msg).unwrap()).unwrap();
+        if let ServerMessage::PrivateDelta { delta, seq, .. } = decoded {
+            assert_eq!(delta, TestDelta::CounterChanged(7));
+            assert_eq!(seq, 12);
+        } else {
+            panic!("Expected PrivateDelta");
+        }
+    }
+
+    #[test]
+    fn test_domain_snapshot_roundtrip() {
+        type Msg = ServerMessage<TestState, TestDelta, TestEvent>;
+
+        let slice = vec!["gm".to_string(), "gl".to_string()];
+        let msg: Msg = ServerMessage::domain_snapshot_with("chat", &slice, 9, 0).unwrap();
+        assert_eq!(msg.tag(), ServerTag::DomainSnapshot);
+        let decoded: Msg = decode(&encode(&msg).unwrap()).unwrap();
+        if let ServerMessage::DomainSnapshot {
+            domain, data, seq, ..
+        } = decoded
+        {
+            assert_eq!(domain, "chat");
+            assert_eq!(seq, 9);
+            assert_eq!(decode::<Vec<String>>(&data).unwrap(), slice);
+        } else {
+            panic!("Expected DomainSnapshot");
+        }
+
+        let request: ClientMessage<ChatAction> = ClientMessage::snapshot_request("chat");
+        assert_eq!(request.tag(), ClientTag::SnapshotRequest);
+        let decoded: ClientMessage<ChatAction> = decode(&encode(&request).unwrap()).unwrap();
+        assert!(matches!(&decoded, ClientMessage::SnapshotRequest { domain } if domain == "chat"));
+        assert!(decoded.validate().is_ok());
+        let too_long: ClientMessage<ChatAction> =
+            ClientMessage::snapshot_request("d".repeat(MAX_ID_LEN + 1));
+        assert!(too_long.validate().is_err());
+    }
+
+    #[test]
+    fn test_audience_includes() {
+        assert!(Audience::All.includes("alice"));
+        assert!(Audience::user("alice").includes("alice"));
+        assert!(!Audience::user("alice").includes("bob"));
+        let others = Audience::AllExcept(vec!["alice".to_string()]);
+        assert!(!others.includes("alice"));
+        assert!(others.includes("bob"));
+    }
+
+    #[test]
+    fn test_command_roundtrip() {
+        type Msg = ServerMessage<TestState, TestDelta, TestEvent>;
+
+        let msg: Msg = ServerMessage::command(ClientCommand::Reload {
+            max_delay_ms: 5_000,
+        });
+        assert_eq!(msg.tag(), ServerTag::Command);
+        let decoded: Msg = decode(&encode(&msg).unwrap()).unwrap();
+        assert!(matches!(
+            decoded,
+            ServerMessage::Command {
+                command: ClientCommand::Reload {
+                    max_delay_ms: 5_000
+                }
+            }
+        ));
+    }
+
+    #[test]
+    fn test_unknown_command_decodes() {
+        #[derive(Serialize)]
+        #[serde(tag = "kind", rename_all = "snake_case")]
+        enum NewerCommand {
+            DrainRegion { region: String },
+        }
+
+        let bytes = encode(&NewerCommand::DrainRegion {
+            region: "syd".to_string(),
+        })
+        .unwrap();
+        let decoded: ClientCommand = decode(&bytes).unwrap();
+        assert_eq!(decoded, ClientCommand::Unknown);
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Quote {
+        price: u64,
+        symbol: String,
+    }
+
+    #[test]
+    fn test_action_ok_with_result_roundtrip() {
+        type Msg = ServerMessage<TestState, TestDelta, TestEvent>;
+
+        let op_id = OpId::new();
+        let quote = Quote {
+            price: 1_500_000,
+            symbol: "ADA".to_string(),
+        };
+        let msg: Msg = ServerMessage::action_ok_with(op_id, &quote).unwrap();
+        let bytes = encode(&msg).unwrap();
+        let decoded: Msg = decode(&bytes).unwrap();
+
+        if let ServerMessage::ActionOk { op_id: id, result } = decoded {
+            assert_eq!(id, op_id);
+            let reply: Quote = decode_result(result.as_deref()).unwrap();
+            assert_eq!(reply, quote);
+        } else {
+            panic!("Expected ActionOk");
+        }
+    }
+
+    #[test]
+    fn test_decode_result_without_payload() {
+        decode_result::<()>(None).unwrap();
+        assert_eq!(decode_result::<Option<u64>>(None).unwrap(), None);
+        assert!(matches!(
+            decode_result::<Quote>(None),
+            Err(RpcError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn test_pending_calls() {
+        let mut pending = PendingCalls::new();
+        let (a, b) = (OpId::new(), OpId::new());
+        pending.insert(a, "a");
+        pending.insert(b, "b");
+        assert_eq!(pending.len(), 2);
+        assert!(pending.contains(a));
+
+        assert_eq!(pending.take(a), Some("a"));
+        assert_eq!(pending.take(a), None);
+        assert!(!pending.contains(a));
+
+        let drained: Vec<_> = pending.drain().collect();
+        assert_eq!(drained, vec![(b, "b")]);
+        assert!(pending.is_empty());
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum ChatAction {
+        Send { text: String },
+    }
+
+    impl Validate for ChatAction {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                Self::Send { text } => check_len("text", text, 16),
+            }
+        }
+    }
+
+    #[test]
+    fn test_size_limits() {
+        let msg: ClientMessage<TestAction> = ClientMessage::action(OpId(1), TestAction::Increment);
+        let bytes = encode(&msg).unwrap();
+
+        assert!(encode_limited(&msg, bytes.len()).is_ok());
+        assert!(matches!(
+            encode_limited(&msg, bytes.len() - 1),
+            Err(ProtocolError::TooLarge { .. })
+        ));
+        assert!(matches!(
+            decode_limited::<ClientMessage<TestAction>>(&bytes, bytes.len() - 1),
+            Err(ProtocolError::TooLarge { size, max }) if size == bytes.len() && max == bytes.len() - 1
+        ));
+    }
+
+    #[test]
+    fn test_decode_validated() {
+        type Msg = ClientMessage<ChatAction>;
+
+        let ok = encode(&Msg::action(
+            OpId(1),
+            ChatAction::Send { text: "hi".into() },
+        ))
+        .unwrap();
+        assert!(decode_validated::<Msg>(&ok, DEFAULT_MAX_CLIENT_MESSAGE_BYTES).is_ok());
+
+        let long = ChatAction::Send {
+            text: "x".repeat(17),
+        };
+        let bytes = encode(&Msg::action(OpId(2), long)).unwrap();
+        assert!(matches!(
+            decode_validated::<Msg>(&bytes, DEFAULT_MAX_CLIENT_MESSAGE_BYTES),
+            Err(ProtocolError::Invalid(ValidationError::TooLong {
+                field: "text",
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_builtin_message_caps() {
+        type Msg = ClientMessage<ChatAction>;
+
+        let domains = (0..=MAX_DOMAINS).map(|i| format!("d{i}")).collect();
+        assert!(matches!(
+            Msg::subscribe(domains).validate(),
+            Err(ValidationError::TooMany {
+                field: "domains",
+                ..
+            })
+        ));
+
+        let signal = Msg::Signal {
+            target_user_id: "peer".into(),
+            signal: SignalPayload::Offer {
+                sdp: "v".repeat(MAX_SIGNAL_LEN + 1),
+            },
+        };
+        assert!(matches!(
+            signal.validate(),
+            Err(ValidationError::TooLong { field: "sdp", .. })
+        ));
+
+        let goodbye = Msg::goodbye("r".repeat(MAX_ID_LEN + 1));
+        assert!(matches!(
+            goodbye.validate(),
+            Err(ValidationError::TooLong {
+                field: "reason",
+                ..
+            })
+        ));
+
+        assert!(Msg::ping(0).validate().is_ok());
+        assert!(Msg::goodbye(GOODBYE_CLOSED).validate().is_ok());
+    }
+
+    #[test]
+    fn test_message_tags() {
+        type Msg = ServerMessage<TestState, TestDelta, TestEvent>;
+
+        let op_id = OpId::new();
+        let msg: Msg = ServerMessage::action_ok(op_id, None);
+        assert_eq!(msg.tag(), ServerTag::ActionOk);
+        assert_eq!(msg.op_id(), Some(op_id));
+
+        let msg: Msg = ServerMessage::delta(TestDelta::CounterChanged(1), 1, 0);
+        assert_eq!(msg.tag(), ServerTag::Delta);
+        assert_eq!(msg.op_id(), None);
+
+        let msg = ClientMessage::action(op_id, TestAction::Increment);
+        assert_eq!(msg.tag(), ClientTag::Action);
+        assert_eq!(msg.op_id(), Some(op_id));
+
+        let msg: ClientMessage<TestAction> = ClientMessage::ping(0);
+        assert_eq!(msg.tag(), ClientTag::Ping);
+        assert_eq!(msg.op_id(), None);
+    }
+
+    #[test]
+    fn test_notify_ack_roundtrip() {
+        type Msg = ServerMessage<TestState, TestDelta, TestEvent>;
+
+        let msg: Msg = ServerMessage::notify_ack_required(
+            "rewards",
+            TestEvent::Announcement("gold".into()),
+            None,
+            7,
+        );
+        let decoded: Msg = decode(&encode(&msg).unwrap()).unwrap();
+        assert_eq!(decoded.ack_id(), Some(7));
+
+        let plain: Msg =
+            ServerMessage::notify("rewards", TestEvent::Announcement("hi".into()), None);
+        assert_eq!(plain.ack_id(), None);
+
+        let ack: ClientMessage<TestAction> = ClientMessage::notify_ack(7);
+        assert_eq!(ack.tag(), ClientTag::NotifyAck);
+        let decoded: ClientMessage<TestAction> = decode(&encode(&ack).unwrap()).unwrap();
+        assert!(matches!(decoded, ClientMessage::NotifyAck { id: 7 }));
+    }
+
+    #[test]
+    fn test_notify_outbox_tracks_until_acked() {
+        type Msg = ServerMessage<TestState, TestDelta, TestEvent>;
+        let mut outbox = NotifyOutbox::new();
+
+        let first: Msg = outbox.push(
+            "alice",
+            "rewards",
+            TestEvent::Announcement("a".into()),
+            None,
+        );
+        let second: Msg = outbox.push(
+            "alice",
+            "rewards",
+            TestEvent::Announcement("b".into()),
+            None,
+        );
+        let _: Msg = outbox.push("bob", "rewards", TestEvent::Announcement("c".into()), None);
+        let (first_id, second_id) = (first.ack_id().unwrap(), second.ack_id().unwrap());
+        assert_ne!(first_id, second_id);
+        assert_eq!(outbox.len(), 3);
+
+        // Acks only count for the user they were sent to
+        assert!(!outbox.ack("bob", first_id));
+        assert!(outbox.ack("alice", first_id));
+        assert!(!outbox.ack("alice", first_id));
+
+        let resend: Vec<Msg> = outbox.redeliver("alice");
+        assert_eq!(resend.len(), 1);
+        assert_eq!(resend[0].ack_id(), Some(second_id));
+
+        outbox.forget("bob");
+        assert!(outbox.ack("alice", second_id));
+        assert!(outbox.is_empty());
+    }
+
+    #[test]
+    fn test_notify_outbox_drops_oldest_past_cap() {
+        type Msg = ServerMessage<TestState, TestDelta, TestEvent>;
+        let mut outbox = NotifyOutbox::new();
+        let ids: Vec<u64> = (0..=MAX_UNACKED_NOTIFIES)
+            .map(|i| {
+                let msg: Msg = outbox.push(
+                    "alice",
+                    "rewards",
+                    TestEvent::Announcement(i.to_string()),
+                    None,
+                );
+                msg.ack_id().unwrap()
+            })
+            .collect();
+
+        assert_eq!(outbox.len(), MAX_UNACKED_NOTIFIES);
+        assert_eq!(outbox.pending("alice").next().map(|n| n.id), Some(ids[1]));
+    }
+
+    #[test]
+    fn test_seen_notifies() {
+        let mut seen = SeenNotifies::new();
+        assert!(seen.first_delivery(1));
+        assert!(!seen.first_delivery(1));
+
+        for id in 2..=(MAX_UNACKED_NOTIFIES as u64 + 1) {
+            assert!(seen.first_delivery(id));
+        }
+        // The oldest ID has been forgotten
+        assert!(seen.first_delivery(1));
+    }
 }
@@ -0,0 +1,517 @@
+//! End-to-end payload encryption
+//!
+//! Seals `Action`/`Delta` payloads so the server relays them without being
+//! able to read them. Keys are agreed with X25519 over the `Signal` channel
+//! and payloads are encrypted with AES-256-GCM.
+//!
+//! ## Key Setup
+//!
+//! 1. Every client creates a [`KeyPair`] and sends [`KeyPair::key_exchange`]
+//!    to its peers with `ClientMessage::signal`.
+//! 2. On a peer's `KeyExchange`, [`KeyPair::agree`] derives a pairwise
+//!    [`SecureCodec`]. Two-party rooms can use it directly.
+//! 3. Group rooms: one member (the host) creates a room key with
+//!    [`SecureCodec::generate`] and sends each peer
+//!    [`SecureCodec::share_with`] over their pairwise codec; peers unwrap it
+//!    with [`SecureCodec::accept`].
+//!
+//! Rotate the room key (with a new `key_id`) when a member leaves so they
+//! can't read later messages.
+//!
+//! ## Threat Model
+//!
+//! Sealed payloads are safe from a server that honestly relays messages but
+//! reads what it stores. Public keys, however, travel over the
+//! server-relayed `Signal` channel unauthenticated: a malicious or
+//! compromised relay can swap them for its own and sit in the middle of
+//! every exchange, reading everything. Rooms that need protection from the
+//! relay itself must have users compare [`KeyPair::safety_number`] over
+//! another channel (in person, a call) before trusting a peer.
+//!
+//! Key material (secrets, shared secrets, derived and room keys) is zeroed
+//! when dropped.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use ui_flow_protocol::{ClientMessage, KeyPair, OpId, Sealed, SecureCodec, SignalPayload};
+//!
+//! let keys = KeyPair::generate()?;
+//! send(ClientMessage::<Sealed>::signal(peer_id, keys.key_exchange()));
+//!
+//! // ...peer's KeyExchange arrives
+//! let pairwise = keys.agree(&peer_public_key, room_id.as_bytes())?;
+//!
+//! // Seal an action; the server only sees `Sealed`
+//! let msg = ClientMessage::action(OpId::new(), pairwise.seal(&ChatAction::Say(text))?);
+//!
+//! // Open a delta
+//! let delta: ChatDelta = pairwise.open(&sealed_delta)?;
+//! ```
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use hkdf::Hkdf;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use thiserror::Error;
+use x25519_dalek::{PublicKey, StaticSecret};
+use zeroize::Zeroize;
+
+use crate::{decode, encode, ProtocolError, Sealed, SignalPayload, NONCE_LEN, PUBLIC_KEY_LEN};
+
+/// Domain separation for derived pairwise keys
+const KDF_INFO: &[u8] = b"ui-flow-protocol/e2e/v1";
+
+/// `key_id` of pairwise codecs
+pub const PAIRWISE_KEY_ID: u32 = 0;
+
+/// Length of an AES-256 key, in bytes
+const KEY_LEN: usize = 32;
+
+/// Domain separation for safety numbers
+const SAFETY_NUMBER_INFO: &[u8] = b"ui-flow-protocol/safety-number/v1";
+
+/// Groups of five digits in a safety number
+const SAFETY_NUMBER_GROUPS: usize = 6;
+
+/// End-to-end encryption errors
+#[derive(Error, Debug)]
+pub enum SecureError {
+    #[error(transparent)]
+    Protocol(#[from] ProtocolError),
+
+    #[error("Invalid public key")]
+    InvalidPublicKey,
+
+    #[error("Payload sealed with key {got}, expected key {expected}")]
+    KeyMismatch { expected: u32, got: u32 },
+
+    #[error("Payload failed to decrypt")]
+    Decrypt,
+
+    #[error("Payload failed to encrypt")]
+    Encrypt,
+
+    #[error("No randomness available: {0}")]
+    Random(String),
+}
+
+fn random_bytes<const N: usize>() -> Result<[u8; N], SecureError> {
+    let mut bytes = [0u8; N];
+    getrandom::fill(&mut bytes).map_err(|e| SecureError::Random(e.to_string()))?;
+    Ok(bytes)
+}
+
+/// An X25519 key pair for agreeing pairwise keys
+pub struct KeyPair {
+    secret: StaticSecret,
+    public: PublicKey,
+}
+
+impl fmt::Debug for KeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyPair")
+            .field("public", self.public.as_bytes())
+            .finish_non_exhaustive()
+    }
+}
+
+impl KeyPair {
+    /// Generate a fresh key pair
+    pub fn generate() -> Result<Self, SecureError> {
+        Ok(Self::from_secret(random_bytes()?))
+    }
+
+    /// Restore a key pair from its secret bytes
+    pub fn from_secret(mut bytes: [u8; 32]) -> Self {
+        let secret = StaticSecret::from(bytes);
+        bytes.zeroize();
+        let public = PublicKey::from(&secret);
+        Self { secret, public }
+    }
+
+    /// Public key bytes to share with peers
+    pub fn public_key(&self) -> [u8; PUBLIC_KEY_LEN] {
+        self.public.to_bytes()
+    }
+
+    /// Signal payload announcing this public key
+    pub fn key_exchange(&self) -> SignalPayload {
+        SignalPayload::KeyExchange {
+            public_key: self.public_key().to_vec(),
+        }
+    }
+
+    /// Derive the pairwise codec shared with the owner of `peer_public`
+    ///
+    /// `context` binds the key to a room (e.g. the room id) so the same key
+    /// pairs don't share a key across rooms. Both sides derive the same codec.
+    pub fn agree(&self, peer_public: &[u8], context: &[u8]) -> Result<SecureCodec, SecureError> {
+        let peer = parse_public_key(peer_public)?;
+
+        let shared = self.secret.diffie_hellman(&peer);
+        // Low-order peer keys yield an all-zero secret anyone could compute
+        if !shared.was_contributory() {
+            return Err(SecureError::InvalidPublicKey);
+        }
+
+        let salt = self.ordered_keys(&peer);
+        let info = [KDF_INFO, context].concat();
+
+        let mut key = [0u8; KEY_LEN];
+        Hkdf::<Sha256>::new(Some(&salt), shared.as_bytes())
+            .expand(&info, &mut key)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+        let codec = SecureCodec::from_key(key, PAIRWISE_KEY_ID);
+        key.zeroize();
+        Ok(codec)
+    }
+
+    /// Safety number for the pair of this key and `peer_public`
+    ///
+    /// Both sides compute the same digits. If they match when compared
+    /// outside the relay, no one has swapped the keys in transit. Formatted
+    /// as groups of five digits, e.g. `"01234 56789 ..."`.
+    pub fn safety_number(&self, peer_public: &[u8]) -> Result<String, SecureError> {
+        let peer = parse_public_key(peer_public)?;
+        let digest = Sha256::new()
+            .chain_update(SAFETY_NUMBER_INFO)
+            .chain_update(self.ordered_keys(&peer))
+            .finalize();
+
+        let groups: Vec<String> = digest
+            .chunks_exact(5)
+            .take(SAFETY_NUMBER_GROUPS)
+            .map(|chunk| {
+                let value = chunk.iter().fold(0u64, |n, &b| (n << 8) | u64::from(b));
+                format!("{:05}", value % 100_000)
+            })
+            .collect();
+        Ok(groups.join(" "))
+    }
+
+    /// Both public keys, lowest first, so both sides agree on the order
+    fn ordered_keys(&self, peer: &PublicKey) -> Vec<u8> {
+        let (first, second) = if self.public.as_bytes() <= peer.as_bytes() {
+            (self.public.as_bytes(), peer.as_bytes())
+        } else {
+            (peer.as_bytes(), self.public.as_bytes())
+        };
+        [first.as_slice(), second.as_slice()].concat()
+    }
+}
+
+fn parse_public_key(bytes: &[u8]) -> Result<PublicKey, SecureError> {
+    let key: [u8; PUBLIC_KEY_LEN] = bytes
+        .try_into()
+        .map_err(|_| SecureError::InvalidPublicKey)?;
+    Ok(PublicKey::from(key))
+}
+
+/// Seals and opens payloads with one AES-256-GCM key
+#[derive(Clone)]
+pub struct SecureCodec {
+    key: [u8; KEY_LEN],
+    key_id: u32,
+    cipher: Aes256Gcm,
+}
+
+impl fmt::Debug for SecureCodec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecureCodec")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for SecureCodec {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+impl SecureCodec {
+    /// Codec for a known key
+    pub fn from_key(key: [u8; KEY_LEN], key_id: u32) -> Self {
+        Self {
+            cipher: Aes256Gcm::new(&key.into()),
+            key,
+            key_id,
+        }
+    }
+
+    /// Codec for a fresh random room key
+    pub fn generate(key_id: u32) -> Result<Self, SecureError> {
+        Ok(Self::from_key(random_bytes()?, key_id))
+    }
+
+    /// Key generation this codec seals with
+    pub fn key_id(&self) -> u32 {
+        self.key_id
+    }
+
+    /// Signal payload carrying this room key, sealed for one peer
+    pub fn share_with(&self, pairwise: &SecureCodec) -> Result<SignalPayload, SecureError> {
+        let sealed = pairwise.seal(&RoomKey {
+            key_id: self.key_id,
+            key: self.key.to_vec(),
+        })?;
+        Ok(SignalPayload::RoomKey { sealed })
+    }
+
+    /// Unwrap a room key received from a peer
+    pub fn accept(pairwise: &SecureCodec, sealed: &Sealed) -> Result<Self, SecureError> {
+        let room: RoomKey = pairwise.open(sealed)?;
+        let mut key: [u8; KEY_LEN] = room
+            .key
+            .as_slice()
+            .try_into()
+            .map_err(|_| SecureError::Decrypt)?;
+        let codec = Self::from_key(key, room.key_id);
+        key.zeroize();
+        Ok(codec)
+    }
+
+    /// Encrypt a value
+    pub fn seal<T: Serialize>(&self, value: &T) -> Result<Sealed, SecureError> {
+        let plaintext = encode(value)?;
+        let nonce: [u8; NONCE_LEN] = random_bytes()?;
+        let aad = self.key_id.to_be_bytes();
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &plaintext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| SecureError::Encrypt)?;
+        Ok(Sealed {
+            key_id: self.key_id,
+            nonce: nonce.to_vec(),
+            ciphertext,
+        })
+    }
+
+    /// Decrypt and decode a value
+    ///
+    /// Fails with [`SecureError::KeyMismatch`] for another key generation and
+    /// [`SecureError::Decrypt`] if the payload was tampered with.
+    pub fn open<T: DeserializeOwned>(&self, sealed: &Sealed) -> Result<T, SecureError> {
+        if sealed.key_id != self.key_id {
+            return Err(SecureError::KeyMismatch {
+                expected: self.key_id,
+                got: sealed.key_id,
+            });
+        }
+        if sealed.nonce.len() != NONCE_LEN {
+            return Err(SecureError::Decrypt);
+        }
+        let aad = sealed.key_id.to_be_bytes();
+        let plaintext = self
+            .cipher
+            .decrypt(
+                Nonce::from_slice(&sealed.nonce),
+                Payload {
+                    msg: &sealed.ciphertext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| SecureError::Decrypt)?;
+        Ok(decode(&plaintext)?)
+    }
+
+    /// Seal a value and encode the [`Sealed`] envelope to MessagePack
+    pub fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, SecureError> {
+        Ok(encode(&self.seal(value)?)?)
+    }
+
+    /// Decode a [`Sealed`] envelope from MessagePack and open it
+    pub fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, SecureError> {
+        self.open(&decode::<Sealed>(bytes)?)
+    }
+}
+
+/// Room key as sent inside [`SignalPayload::RoomKey`]
+#[derive(Serialize, serde::Deserialize)]
+struct RoomKey {
+    key_id: u32,
+    #[serde(with = "serde_bytes")]
+    key: Vec<u8>,
+}
+
+impl Drop for RoomKey {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ClientMessage, OpId, ServerMessage, Validate};
+    use serde::Deserialize;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum ChatAction {
+        Say { text: String },
+    }
+
+    fn pair() -> (SecureCodec, SecureCodec) {
+        let alice = KeyPair::generate().unwrap();
+        let bob = KeyPair::generate().unwrap();
+        let a = alice.agree(&bob.public_key(), b"room-1").unwrap();
+        let b = bob.agree(&alice.public_key(), b"room-1").unwrap();
+        (a, b)
+    }
+
+    #[test]
+    fn pairwise_keys_match() {
+        let (alice, bob) = pair();
+        let action = ChatAction::Say {
+            text: "hello".into(),
+        };
+        let sealed = alice.seal(&action).unwrap();
+        assert_eq!(bob.open::<ChatAction>(&sealed).unwrap(), action);
+    }
+
+    #[test]
+    fn context_separates_rooms() {
+        let alice = KeyPair::generate().unwrap();
+        let bob = KeyPair::generate().unwrap();
+        let room1 = alice.agree(&bob.public_key(), b"room-1").unwrap();
+        let room2 = bob.agree(&alice.public_key(), b"room-2").unwrap();
+        let sealed = room1.seal(&1u32).unwrap();
+        assert!(matches!(
+            room2.open::<u32>(&sealed),
+            Err(SecureError::Decrypt)
+        ));
+    }
+
+    #[test]
+    fn ciphertext_hides_plaintext() {
+        let (alice, _) = pair();
+        let sealed = alice
+            .seal(&ChatAction::Say {
+                text: "top secret".into(),
+            })
+            .unwrap();
+        let haystack = String::from_utf8_lossy(&sealed.ciphertext);
+        assert!(!haystack.contains("top secret"));
+    }
+
+    #[test]
+    fn tampering_is_detected() {
+        let (alice, bob) = pair();
+        let mut sealed = alice.seal(&42u32).unwrap();
+        sealed.ciphertext[0] ^= 1;
+        assert!(matches!(
+            bob.open::<u32>(&sealed),
+            Err(SecureError::Decrypt)
+        ));
+    }
+
+    #[test]
+    fn key_id_mismatch_is_reported() {
+        let old = SecureCodec::generate(1).unwrap();
+        let new = SecureCodec::generate(2).unwrap();
+        let sealed = old.seal(&"hi").unwrap();
+        assert!(matches!(
+            new.open::<String>(&sealed),
+            Err(SecureError::KeyMismatch {
+                expected: 2,
+                got: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_public_keys() {
+        let keys = KeyPair::generate().unwrap();
+        assert!(matches!(
+            keys.agree(&[1, 2, 3], b"room"),
+            Err(SecureError::InvalidPublicKey)
+        ));
+        // The identity point gives an all-zero shared secret
+        assert!(matches!(
+            keys.agree(&[0u8; 32], b"room"),
+            Err(SecureError::InvalidPublicKey)
+        ));
+    }
+
+    #[test]
+    fn room_key_is_shared_over_pairwise_codec() {
+        let (host_to_guest, guest_to_host) = pair();
+        let room = SecureCodec::generate(7).unwrap();
+
+        let SignalPayload::RoomKey { sealed } = room.share_with(&host_to_guest).unwrap() else {
+            panic!("expected a RoomKey payload");
+        };
+        let guest_room = SecureCodec::accept(&guest_to_host, &sealed).unwrap();
+        assert_eq!(guest_room.key_id(), 7);
+
+        let msg = room.seal(&"welcome").unwrap();
+        assert_eq!(guest_room.open::<String>(&msg).unwrap(), "welcome");
+    }
+
+    #[test]
+    fn codec_wraps_encode_and_decode() {
+        let (alice, bob) = pair();
+        let action = ChatAction::Say { text: "yo".into() };
+        let bytes = alice.encode(&action).unwrap();
+        assert_eq!(bob.decode::<ChatAction>(&bytes).unwrap(), action);
+    }
+
+    #[test]
+    fn sealed_payloads_ride_in_protocol_messages() {
+        let (alice, bob) = pair();
+        let action = ChatAction::Say { text: "hi".into() };
+
+        let msg = ClientMessage::action(OpId(1), alice.seal(&action).unwrap());
+        let bytes = encode(&msg).unwrap();
+
+        // The server decodes the envelope without the key
+        let relayed: ClientMessage<Sealed> = decode(&bytes).unwrap();
+        let ClientMessage::Action { action: sealed, .. } = relayed else {
+            panic!("expected an Action");
+        };
+        assert!(sealed.validate().is_ok());
+
+        let delta = ServerMessage::<(), Sealed, ()>::delta(sealed, 1, 0);
+        let received: ServerMessage<(), Sealed, ()> = decode(&encode(&delta).unwrap()).unwrap();
+        let ServerMessage::Delta { delta, .. } = received else {
+            panic!("expected a Delta");
+        };
+        assert_eq!(bob.open::<ChatAction>(&delta).unwrap(), action);
+    }
+
+    #[test]
+    fn safety_numbers_match_on_both_sides() {
+        let alice = KeyPair::generate().unwrap();
+        let bob = KeyPair::generate().unwrap();
+        let mallory = KeyPair::generate().unwrap();
+
+        let number = alice.safety_number(&bob.public_key()).unwrap();
+        assert_eq!(number, bob.safety_number(&alice.public_key()).unwrap());
+        assert_eq!(number.len(), SAFETY_NUMBER_GROUPS * 6 - 1);
+        assert!(number.split(' ').all(|g| g.len() == 5));
+
+        // A relay that swapped in its own key shows a different number
+        assert_ne!(number, alice.safety_number(&mallory.public_key()).unwrap());
+        assert!(alice.safety_number(&[0; 31]).is_err());
+    }
+
+    #[test]
+    fn key_exchange_validates() {
+        let keys = KeyPair::generate().unwrap();
+        assert!(keys.key_exchange().validate().is_ok());
+        let short = SignalPayload::KeyExchange {
+            public_key: vec![0; 31],
+        };
+        assert!(short.validate().is_err());
+    }
+}
@@ -0,0 +1,70 @@
+//! Default handling for server-sent [`ClientCommand`]s
+//!
+//! A connection handles `ForceResync` itself. Everything else goes to the
+//! builder's `on_command` callback, or to [`run_default_command`] when none
+//! is registered. Apps that render their own maintenance banner can handle
+//! those commands and still fall back to the defaults for the rest:
+//!
+//! ```ignore
+//! FlowConnection::builder()
+//!     .on_command(move |command| match command {
+//!         ClientCommand::ShowMaintenanceBanner { message, .. } => banner.set(Some(message)),
+//!         ClientCommand::HideMaintenanceBanner => banner.set(None),
+//!         other => run_default_command(&other),
+//!     })
+//! ```
+
+use ui_flow_protocol::ClientCommand;
+
+/// Apply the built-in behavior for `command`
+///
+/// - `Reload` reloads the page after a random delay of up to `max_delay_ms`
+/// - `RedirectTo` navigates to the URL
+/// - `ForceResync` is a no-op here; the connection already requested a snapshot
+/// - Banner and unknown commands are only logged, since they need app UI
+pub fn run_default_command(command: &ClientCommand) {
+    match command {
+        ClientCommand::Reload { max_delay_ms } => {
+            let delay = reload_delay_ms(*max_delay_ms, js_sys::Math::random());
+            tracing::info!("Server requested reload, reloading in {}ms", delay);
+            wasm_bindgen_futures::spawn_local(async move {
+                gloo_timers::future::TimeoutFuture::new(delay).await;
+                if let Some(window) = web_sys::window() {
+                    let _ = window.location().reload();
+                }
+            });
+        }
+        ClientCommand::RedirectTo { url } => {
+            tracing::info!("Server requested redirect to {}", url);
+            if let Some(window) = web_sys::window() {
+                let _ = window.location().set_href(url);
+            }
+        }
+        ClientCommand::ForceResync => {}
+        ClientCommand::ShowMaintenanceBanner { message, .. } => {
+            tracing::warn!("Maintenance: {}", message);
+        }
+        ClientCommand::HideMaintenanceBanner => {}
+        ClientCommand::Unknown => {
+            tracing::debug!("Ignoring unknown server command");
+        }
+    }
+}
+
+/// Spread a reload over `0..=max_delay_ms` using `random` in `[0, 1)`
+fn reload_delay_ms(max_delay_ms: u32, random: f64) -> u32 {
+    (f64::from(max_delay_ms) * random.clamp(0.0, 1.0)).round() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reload_delay_spread() {
+        assert_eq!(reload_delay_ms(0, 0.7), 0);
+        assert_eq!(reload_delay_ms(10_000, 0.0), 0);
+        assert_eq!(reload_delay_ms(10_000, 0.25), 2_500);
+        assert_eq!(reload_delay_ms(10_000, 0.99999), 10_000);
+    }
+}
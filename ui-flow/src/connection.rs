@@ -5,17 +5,25 @@
 //! to integrate with their reactive systems.
 
 use std::cell::RefCell;
+use std::pin::pin;
 use std::rc::Rc;
 
+use futures::channel::oneshot;
+use futures::future::{select, Either};
+use gloo_timers::future::TimeoutFuture;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::{CloseEvent, MessageEvent, WebSocket};
 
+use crate::command::run_default_command;
+use crate::debug::{FlowDebug, MessageRecord};
+use crate::metrics::FlowMetrics;
 use crate::status::{CloseInfo, ConnectionStatus};
 use ui_flow_protocol::{
-    decode, encode, ClientMessage, OpId, PresenceInfo, ProtocolError, ServerMessage,
+    decode, decode_result, encode, ClientCommand, ClientMessage, OpId, PendingCalls, PresenceInfo,
+    ProtocolError, RpcError, SeenNotifies, ServerMessage, GOODBYE_UNLOAD,
 };
 
 // Type aliases to reduce complexity warnings
@@ -23,6 +31,23 @@ type DeltasCallback<Delta> = Option<Rc<dyn Fn(Vec<Delta>, u64)>>;
 type NotifyCallback<Event> = Option<Rc<dyn Fn(String, Event, Option<OpId>)>>;
 type ProgressCallback = Option<Rc<dyn Fn(OpId, Option<u8>, Option<String>)>>;
 type ActionErrorCallback = Option<Rc<dyn Fn(OpId, Option<String>, String)>>;
+type CallReply = oneshot::Sender<Result<Option<Vec<u8>>, RpcError>>;
+type LatencyCallback = Option<Rc<dyn Fn(u32)>>;
+type CloseCallback = Option<Rc<dyn Fn(CloseInfo)>>;
+type CommandCallback = Option<Rc<dyn Fn(ClientCommand)>>;
+type DomainSnapshotCallback = Option<Rc<dyn Fn(String, Vec<u8>, u64)>>;
+
+/// Default deadline for [`FlowConnection::call`], in milliseconds
+pub const DEFAULT_CALL_TIMEOUT_MS: u32 = 30_000;
+
+/// How long [`FlowConnection::close`] waits for in-flight calls, in milliseconds
+pub const CLOSE_FLUSH_TIMEOUT_MS: u32 = 2_000;
+
+/// How often `close` checks whether in-flight calls have settled
+const CLOSE_FLUSH_POLL_MS: u32 = 50;
+
+/// WebSocket close code for an intentional close
+const NORMAL_CLOSURE: u16 = 1000;
 
 /// Configuration for reconnection behavior
 #[derive(Debug, Clone)]
@@ -48,6 +73,15 @@ impl Default for ReconnectConfig {
     }
 }
 
+impl ReconnectConfig {
+    /// Delay before reconnection `attempt` (1-based), in milliseconds
+    pub fn delay_for(&self, attempt: u32) -> u32 {
+        let multiplier = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.base_delay_ms.saturating_mul(multiplier);
+        delay.min(self.max_delay_ms)
+    }
+}
+
 /// Builder for creating a Flow connection
 ///
 /// # Type Parameters
@@ -75,6 +109,7 @@ pub struct FlowConnectionBuilder<State, Delta, Event, Action> {
     on_snapshot: Option<Rc<dyn Fn(State, u64)>>,
     on_delta: Option<Rc<dyn Fn(Delta, u64)>>,
     on_deltas: DeltasCallback<Delta>,
+    on_private_delta: Option<Rc<dyn Fn(Delta, u64)>>,
     on_presence: Option<Rc<dyn Fn(Vec<PresenceInfo>)>>,
     on_notify: NotifyCallback<Event>,
     on_status: Option<Rc<dyn Fn(ConnectionStatus)>>,
@@ -82,6 +117,15 @@ pub struct FlowConnectionBuilder<State, Delta, Event, Action> {
     on_action_complete: Option<Rc<dyn Fn(OpId)>>,
     on_action_error: ActionErrorCallback,
     on_error: Option<Rc<dyn Fn(String, bool)>>,
+    call_timeout_ms: Option<u32>,
+    debug: Option<FlowDebug>,
+    metrics: Option<FlowMetrics>,
+    on_latency: LatencyCallback,
+    on_close: CloseCallback,
+    on_command: CommandCallback,
+    on_domain_snapshot: DomainSnapshotCallback,
+    goodbye_on_unload: bool,
+    leave_beacon_url: Option<String>,
     _action: std::marker::PhantomData<Action>,
 }
 
@@ -101,6 +145,7 @@ where
             on_snapshot: None,
             on_delta: None,
             on_deltas: None,
+            on_private_delta: None,
             on_presence: None,
             on_notify: None,
             on_status: None,
@@ -108,6 +153,15 @@ where
             on_action_complete: None,
             on_action_error: None,
             on_error: None,
+            call_timeout_ms: Some(DEFAULT_CALL_TIMEOUT_MS),
+            debug: None,
+            metrics: None,
+            on_latency: None,
+            on_close: None,
+            on_command: None,
+            on_domain_snapshot: None,
+            goodbye_on_unload: true,
+            leave_beacon_url: None,
             _action: std::marker::PhantomData,
         }
     }
@@ -160,6 +214,21 @@ where
         self
     }
 
+    /// Callback when a private (this user only) delta is received
+    ///
+    /// Private deltas don't advance [`FlowConnection::current_seq`]; the `u64`
+    /// is the shared seq they apply on top of. Without this callback they go
+    /// to [`on_delta`](Self::on_delta), which suits apps that keep a single
+    /// merged state. See [`SyncedState`](crate::SyncedState) for keeping
+    /// private state separate.
+    pub fn on_private_delta<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Delta, u64) + 'static,
+    {
+        self.on_private_delta = Some(Rc::new(f));
+        self
+    }
+
     /// Callback when presence update is received
     pub fn on_presence<F>(mut self, f: F) -> Self
     where
@@ -223,19 +292,120 @@ where
         self
     }
 
+    /// Callback with the round-trip time in ms each time a heartbeat Pong arrives
+    ///
+    /// A ping is sent as soon as the socket opens, then every
+    /// [`ReconnectConfig::ping_interval_ms`].
+    pub fn on_latency<F>(mut self, f: F) -> Self
+    where
+        F: Fn(u32) + 'static,
+    {
+        self.on_latency = Some(Rc::new(f));
+        self
+    }
+
+    /// Callback when the socket closes, before any reconnection is scheduled
+    pub fn on_close<F>(mut self, f: F) -> Self
+    where
+        F: Fn(CloseInfo) + 'static,
+    {
+        self.on_close = Some(Rc::new(f));
+        self
+    }
+
+    /// Callback for operator commands sent by the server
+    ///
+    /// `ForceResync` is handled by the connection before this is called.
+    /// Without a callback, commands go to [`run_default_command`].
+    pub fn on_command<F>(mut self, f: F) -> Self
+    where
+        F: Fn(ClientCommand) + 'static,
+    {
+        self.on_command = Some(Rc::new(f));
+        self
+    }
+
+    /// Callback when a domain slice arrives after [`FlowConnection::request_snapshot`]
+    ///
+    /// Receives the domain, the encoded slice (read it with
+    /// `ui_flow_protocol::decode`) and the shared seq it was taken at.
+    pub fn on_domain_snapshot<F>(mut self, f: F) -> Self
+    where
+        F: Fn(String, Vec<u8>, u64) + 'static,
+    {
+        self.on_domain_snapshot = Some(Rc::new(f));
+        self
+    }
+
+    /// Deadline for [`FlowConnection::call`] replies (`None` waits forever)
+    ///
+    /// Defaults to [`DEFAULT_CALL_TIMEOUT_MS`].
+    pub fn call_timeout_ms(mut self, timeout_ms: Option<u32>) -> Self {
+        self.call_timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Record inbound/outbound frames into `debug`
+    pub fn debug(mut self, debug: FlowDebug) -> Self {
+        self.debug = Some(debug);
+        self
+    }
+
+    /// Count traffic, failures, reconnects and latency into `metrics`
+    pub fn metrics(mut self, metrics: FlowMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Say Goodbye when the page is unloaded (default: true)
+    ///
+    /// Listens for `pagehide` and closes the connection with
+    /// [`GOODBYE_UNLOAD`], so the server can drop this user's presence at
+    /// once. `beforeunload` isn't used: the user can still cancel leaving
+    /// after it fires. A page restored from the back/forward cache needs a
+    /// new connection.
+    pub fn goodbye_on_unload(mut self, enabled: bool) -> Self {
+        self.goodbye_on_unload = enabled;
+        self
+    }
+
+    /// Also POST the Goodbye to `url` with `navigator.sendBeacon` on unload
+    ///
+    /// Frames queued on a socket the browser is tearing down may never be
+    /// sent, while a beacon is delivered after the page is gone. The body is
+    /// the MessagePack-encoded `ClientMessage::Goodbye`, and `connection_id`
+    /// from `Connected` is appended to the URL so the server can close just
+    /// this socket (e.g. `/ws/room/leave?connection_id=...`). No beacon is
+    /// sent before `Connected` arrives.
+    pub fn leave_beacon(mut self, url: &str) -> Self {
+        self.leave_beacon_url = Some(url.to_string());
+        self
+    }
+
     /// Build and connect
     pub fn connect(self) -> Result<FlowConnection<Action>, FlowError> {
         if self.url.is_empty() {
             return Err(FlowError::Configuration("URL is required".into()));
         }
 
-        FlowConnection::connect_internal(
+        let call_timeout_ms = self.call_timeout_ms;
+        let debug = self.debug;
+        let metrics = self.metrics;
+        let on_latency = self.on_latency;
+        let on_close = self.on_close;
+        let on_command = self.on_command;
+        let on_domain_snapshot = self.on_domain_snapshot;
+        let goodbye_on_unload = self.goodbye_on_unload;
+        let leave_beacon_url = self.leave_beacon_url;
+        let on_private_delta = self.on_private_delta.or_else(|| self.on_delta.clone());
+        let connection = FlowConnection::connect_internal(
             self.url,
             self.reconnect_config,
             self.on_connected,
             self.on_snapshot,
             self.on_delta,
             self.on_deltas,
+            on_private_delta,
             self.on_presence,
             self.on_notify,
             self.on_status,
@@ -243,7 +413,22 @@ where
             self.on_action_complete,
             self.on_action_error,
             self.on_error,
-        )
+        )?;
+        {
+            let mut inner = connection.inner.borrow_mut();
+            inner.call_timeout_ms = call_timeout_ms;
+            inner.debug = debug;
+            inner.metrics = metrics;
+            inner.on_latency = on_latency;
+            inner.on_close = on_close;
+            inner.on_command = on_command;
+            inner.on_domain_snapshot = on_domain_snapshot;
+            inner.leave_beacon_url = leave_beacon_url;
+        }
+        if goodbye_on_unload {
+            connection.listen_for_unload();
+        }
+        Ok(connection)
     }
 }
 
@@ -272,6 +457,24 @@ struct ConnectionInner<Action> {
     reconnect_config: ReconnectConfig,
     reconnect_attempt: u32,
     current_seq: u64,
+    call_timeout_ms: Option<u32>,
+    // Calls made with `FlowConnection::call` awaiting ActionOk/ActionErr
+    pending_calls: PendingCalls<CallReply>,
+    // AckRequired notifications already handled
+    seen_notifies: SeenNotifies,
+    debug: Option<FlowDebug>,
+    metrics: Option<FlowMetrics>,
+    on_latency: LatencyCallback,
+    on_close: CloseCallback,
+    on_command: CommandCallback,
+    on_domain_snapshot: DomainSnapshotCallback,
+    // Set by close/disconnect; the socket is not reconnected
+    closed: bool,
+    leave_beacon_url: Option<String>,
+    // From `Connected`; identifies this socket to the leave beacon
+    connection_id: Option<String>,
+    // `pagehide` listener registered for goodbye_on_unload
+    unload_listener: Option<Closure<dyn FnMut(JsValue)>>,
     // Store closures to prevent them from being dropped
     _closures: Vec<Closure<dyn FnMut(JsValue)>>,
     _action: std::marker::PhantomData<Action>,
@@ -299,6 +502,7 @@ where
         on_snapshot: Option<Rc<dyn Fn(State, u64)>>,
         on_delta: Option<Rc<dyn Fn(Delta, u64)>>,
         on_deltas: DeltasCallback<Delta>,
+        on_private_delta: Option<Rc<dyn Fn(Delta, u64)>>,
         on_presence: Option<Rc<dyn Fn(Vec<PresenceInfo>)>>,
         on_notify: NotifyCallback<Event>,
         on_status: Option<Rc<dyn Fn(ConnectionStatus)>>,
@@ -325,6 +529,19 @@ where
             reconnect_config,
             reconnect_attempt: 0,
             current_seq: 0,
+            call_timeout_ms: Some(DEFAULT_CALL_TIMEOUT_MS),
+            pending_calls: PendingCalls::new(),
+            seen_notifies: SeenNotifies::new(),
+            debug: None,
+            metrics: None,
+            on_latency: None,
+            on_close: None,
+            on_command: None,
+            on_domain_snapshot: None,
+            closed: false,
+            leave_beacon_url: None,
+            connection_id: None,
+            unload_listener: None,
             _closures: Vec::new(),
             _action: std::marker::PhantomData,
         }));
@@ -355,6 +572,11 @@ where
                     cb(ConnectionStatus::Connected);
                 }
 
+                // Measure latency right away rather than after the first interval
+                if inner.borrow().on_latency.is_some() {
+                    send_heartbeat(&inner);
+                }
+
                 // Start ping timer if configured
                 if ping_interval > 0 {
                     let inner_ping = inner.clone();
@@ -372,6 +594,7 @@ where
             let on_snapshot = on_snapshot.clone();
             let on_delta = on_delta.clone();
             let on_deltas = on_deltas.clone();
+            let on_private_delta = on_private_delta.clone();
             let on_presence = on_presence.clone();
             let on_notify = on_notify.clone();
             let on_progress = on_progress.clone();
@@ -398,7 +621,22 @@ where
                         return;
                     };
 
-                match decode::<ServerMessage<State, Delta, Event>>(&bytes) {
+                let decoded = decode::<ServerMessage<State, Delta, Event>>(&bytes);
+                let (debug, metrics) = {
+                    let inner = inner.borrow();
+                    (inner.debug.clone(), inner.metrics.clone())
+                };
+                if let Some(metrics) = metrics {
+                    metrics.record_received(bytes.len(), decoded.is_ok());
+                }
+                if let Some(debug) = debug {
+                    debug.record(match decoded {
+                        Ok(ref msg) => MessageRecord::inbound(msg, bytes.len()),
+                        Err(ref e) => MessageRecord::inbound_error(bytes.len(), e),
+                    });
+                }
+
+                match decoded {
                     Ok(msg) => {
                         handle_server_message(
                             msg,
@@ -407,6 +645,7 @@ where
                             &on_snapshot,
                             &on_delta,
                             &on_deltas,
+                            &on_private_delta,
                             &on_presence,
                             &on_notify,
                             &on_progress,
@@ -448,9 +687,21 @@ where
                     close_info.reason
                 );
 
+                let on_close = inner.borrow().on_close.clone();
+                if let Some(cb) = on_close {
+                    cb(close_info.clone());
+                }
+
+                // Closed on purpose with close/disconnect
+                if inner.borrow().closed {
+                    return;
+                }
+
                 let should_reconnect = {
                     let mut inner = inner.borrow_mut();
                     inner.ws = None;
+                    // Replies to in-flight calls were lost with the socket
+                    inner.pending_calls.drain().for_each(drop);
 
                     if close_info.is_auth_failure() {
                         inner.status = ConnectionStatus::AuthFailed;
@@ -480,10 +731,16 @@ where
                 };
 
                 if should_reconnect {
-                    let delay = {
+                    let (delay, metrics) = {
                         let inner = inner.borrow();
-                        calculate_backoff(inner.reconnect_attempt, &inner.reconnect_config)
+                        (
+                            inner.reconnect_config.delay_for(inner.reconnect_attempt),
+                            inner.metrics.clone(),
+                        )
                     };
+                    if let Some(metrics) = metrics {
+                        metrics.record_reconnect();
+                    }
 
                     tracing::info!("Scheduling reconnection in {}ms", delay);
 
@@ -494,6 +751,9 @@ where
 
                     wasm_bindgen_futures::spawn_local(async move {
                         gloo_timers::future::TimeoutFuture::new(delay).await;
+                        if inner.borrow().closed {
+                            return;
+                        }
                         if let Err(e) = reconnect(&inner, &url, &on_status, &on_error) {
                             tracing::error!("Reconnection failed: {}", e);
                         }
@@ -547,6 +807,69 @@ where
         self.send_message(&msg)
     }
 
+    /// Send an action and await its typed reply
+    ///
+    /// Assigns a fresh [`OpId`], then resolves with the `ActionOk` result
+    /// decoded into `R` (see [`ServerMessage::action_ok_with`]), or fails with
+    /// [`RpcError::Remote`] on `ActionErr`. Replies to calls are not passed to
+    /// `on_action_complete`/`on_action_error`.
+    ///
+    /// Fails with [`RpcError::Timeout`] after the builder's
+    /// [`call_timeout_ms`](FlowConnectionBuilder::call_timeout_ms).
+    ///
+    /// ```ignore
+    /// let quote: Quote = connection.call(GameAction::GetQuote { item }).await?;
+    /// ```
+    pub async fn call<R: DeserializeOwned>(&self, action: Action) -> Result<R, FlowError> {
+        let timeout_ms = self.inner.borrow().call_timeout_ms;
+        self.call_with_timeout(action, timeout_ms).await
+    }
+
+    /// [`call`](Self::call) with an explicit deadline (`None` waits forever)
+    pub async fn call_with_timeout<R: DeserializeOwned>(
+        &self,
+        action: Action,
+        timeout_ms: Option<u32>,
+    ) -> Result<R, FlowError> {
+        let op_id = OpId::new();
+        let (tx, rx) = oneshot::channel();
+        self.inner.borrow_mut().pending_calls.insert(op_id, tx);
+
+        if let Err(e) = self.send_action(op_id, action) {
+            self.inner.borrow_mut().pending_calls.take(op_id);
+            return Err(e);
+        }
+
+        let reply = match timeout_ms {
+            Some(ms) => match select(rx, pin!(TimeoutFuture::new(ms))).await {
+                Either::Left((reply, _)) => reply,
+                Either::Right(_) => {
+                    self.inner.borrow_mut().pending_calls.take(op_id);
+                    return Err(RpcError::Timeout(op_id).into());
+                }
+            },
+            None => rx.await,
+        };
+
+        let result = reply.unwrap_or(Err(RpcError::Disconnected))?;
+        Ok(decode_result(result.as_deref())?)
+    }
+
+    /// Number of [`call`](Self::call)s awaiting a reply
+    pub fn pending_calls(&self) -> usize {
+        self.inner.borrow().pending_calls.len()
+    }
+
+    /// Frame recorder attached with [`FlowConnectionBuilder::debug`]
+    pub fn debug(&self) -> Option<FlowDebug> {
+        self.inner.borrow().debug.clone()
+    }
+
+    /// Traffic counters attached with [`FlowConnectionBuilder::metrics`]
+    pub fn metrics(&self) -> Option<FlowMetrics> {
+        self.inner.borrow().metrics.clone()
+    }
+
     /// Request state resynchronization
     pub fn resync(&self, last_seq: Option<u64>) -> Result<(), FlowError> {
         let msg: ClientMessage<Action> = ClientMessage::resync(last_seq);
@@ -565,6 +888,16 @@ where
         self.send_message(&msg)
     }
 
+    /// Request one domain's slice of state
+    ///
+    /// The reply goes to the `on_domain_snapshot` callback, and the
+    /// connection is subscribed to the domain's notifications. Unknown
+    /// domains are reported to `on_error`.
+    pub fn request_snapshot(&self, domain: impl Into<String>) -> Result<(), FlowError> {
+        let msg: ClientMessage<Action> = ClientMessage::snapshot_request(domain);
+        self.send_message(&msg)
+    }
+
     /// Send a ping with timestamp for latency measurement
     pub fn send_ping(&self) -> Result<(), FlowError> {
         let ts = js_sys::Date::now() as u64;
@@ -572,25 +905,80 @@ where
         self.send_message(&msg)
     }
 
-    /// Disconnect and clean up
-    pub fn disconnect(&self) {
-        let mut inner = self.inner.borrow_mut();
-        if let Some(ref ws) = inner.ws {
-            let _ = ws.close();
+    /// Leave gracefully: settle in-flight calls, say Goodbye, then close
+    ///
+    /// Waits up to [`CLOSE_FLUSH_TIMEOUT_MS`] for [`call`](Self::call)s still
+    /// awaiting a reply, sends `Goodbye { reason }` so the server can drop
+    /// this user's presence right away, and closes the socket with code 1000.
+    /// Actions already sent are delivered ahead of the close frame. The
+    /// connection does not reconnect afterwards.
+    ///
+    /// ```ignore
+    /// connection.close(protocol::GOODBYE_CLOSED).await;
+    /// ```
+    pub async fn close(&self, reason: impl Into<String>) {
+        let reason = reason.into();
+        let deadline = js_sys::Date::now() + f64::from(CLOSE_FLUSH_TIMEOUT_MS);
+        while self.pending_calls() > 0 && js_sys::Date::now() < deadline {
+            TimeoutFuture::new(CLOSE_FLUSH_POLL_MS).await;
         }
-        inner.ws = None;
-        inner.status = ConnectionStatus::Disconnected;
-        inner.reconnect_attempt = 0;
+        say_goodbye(&self.inner, &reason);
     }
 
-    fn send_message<M: Serialize>(&self, msg: &M) -> Result<(), FlowError> {
-        let inner = self.inner.borrow();
-        let ws = inner.ws.as_ref().ok_or(FlowError::NotConnected)?;
+    /// Disconnect and clean up, without telling the server why
+    pub fn disconnect(&self) {
+        shut_down(&mut self.inner.borrow_mut());
+    }
 
-        let bytes = encode(msg).map_err(|e| FlowError::Serialization(e.to_string()))?;
+    /// Close with [`GOODBYE_UNLOAD`] on `pagehide`
+    fn listen_for_unload(&self) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let weak = Rc::downgrade(&self.inner);
+        let listener = Closure::wrap(Box::new(move |_: JsValue| {
+            if let Some(inner) = weak.upgrade() {
+                leave_on_unload(&inner);
+            }
+        }) as Box<dyn FnMut(JsValue)>);
 
-        ws.send_with_u8_array(&bytes)
-            .map_err(|e| FlowError::Send(format!("{:?}", e)))
+        if window
+            .add_event_listener_with_callback("pagehide", listener.as_ref().unchecked_ref())
+            .is_ok()
+        {
+            self.inner.borrow_mut().unload_listener = Some(listener);
+        }
+    }
+
+    fn send_message<A: Serialize>(&self, msg: &ClientMessage<A>) -> Result<(), FlowError> {
+        let (result, size) = {
+            let inner = self.inner.borrow();
+            match inner.ws.as_ref() {
+                Some(ws) => match encode(msg) {
+                    Ok(bytes) => (
+                        ws.send_with_u8_array(&bytes)
+                            .map_err(|e| FlowError::Send(format!("{:?}", e))),
+                        bytes.len(),
+                    ),
+                    Err(e) => (Err(FlowError::Serialization(e.to_string())), 0),
+                },
+                None => (Err(FlowError::NotConnected), 0),
+            }
+        };
+
+        let (debug, metrics) = {
+            let inner = self.inner.borrow();
+            (inner.debug.clone(), inner.metrics.clone())
+        };
+        if let Some(metrics) = metrics {
+            metrics.record_sent(size, result.is_ok());
+        }
+        if let Some(debug) = debug {
+            let error = result.as_ref().err().map(ToString::to_string);
+            debug.record(MessageRecord::outbound(msg, size, error));
+        }
+
+        result
     }
 }
 
@@ -602,6 +990,7 @@ fn handle_server_message<State, Delta, Event, Action>(
     on_snapshot: &Option<Rc<dyn Fn(State, u64)>>,
     on_delta: &Option<Rc<dyn Fn(Delta, u64)>>,
     on_deltas: &DeltasCallback<Delta>,
+    on_private_delta: &Option<Rc<dyn Fn(Delta, u64)>>,
     on_presence: &Option<Rc<dyn Fn(Vec<PresenceInfo>)>>,
     on_notify: &NotifyCallback<Event>,
     on_progress: &ProgressCallback,
@@ -612,12 +1001,25 @@ fn handle_server_message<State, Delta, Event, Action>(
     match msg {
         ServerMessage::Connected { connection_id, .. } => {
             tracing::debug!("Server acknowledged connection: {}", connection_id);
+            inner.borrow_mut().connection_id = Some(connection_id.clone());
             if let Some(ref cb) = on_connected {
                 cb(connection_id);
             }
         }
-        ServerMessage::Pong { .. } => {
-            tracing::trace!("Received pong");
+        ServerMessage::Pong { client_ts, .. } => {
+            let latency_ms = (js_sys::Date::now() as u64).saturating_sub(client_ts);
+            tracing::trace!("Received pong ({}ms)", latency_ms);
+            let latency_ms = u32::try_from(latency_ms).unwrap_or(u32::MAX);
+            let (on_latency, metrics) = {
+                let inner = inner.borrow();
+                (inner.on_latency.clone(), inner.metrics.clone())
+            };
+            if let Some(metrics) = metrics {
+                metrics.record_latency(latency_ms);
+            }
+            if let Some(cb) = on_latency {
+                cb(latency_ms);
+            }
         }
         ServerMessage::Error { message, fatal, .. } => {
             tracing::error!("Server error (fatal={}): {}", fatal, message);
@@ -643,6 +1045,19 @@ fn handle_server_message<State, Delta, Event, Action>(
                 cb(deltas, seq);
             }
         }
+        ServerMessage::PrivateDelta { delta, seq, .. } => {
+            if let Some(ref cb) = on_private_delta {
+                cb(delta, seq);
+            }
+        }
+        ServerMessage::DomainSnapshot {
+            domain, data, seq, ..
+        } => {
+            let on_domain_snapshot = inner.borrow().on_domain_snapshot.clone();
+            if let Some(cb) = on_domain_snapshot {
+                cb(domain, data, seq);
+            }
+        }
         ServerMessage::Presence { users } => {
             if let Some(ref cb) = on_presence {
                 cb(users);
@@ -656,9 +1071,18 @@ fn handle_server_message<State, Delta, Event, Action>(
             domain,
             event,
             correlation_id,
+            ack_id,
         } => {
-            if let Some(ref cb) = on_notify {
-                cb(domain, event, correlation_id);
+            // Redelivered after a lost ack: ack again, don't handle twice
+            let first = ack_id.is_none_or(|id| inner.borrow_mut().seen_notifies.first_delivery(id));
+            if first {
+                if let Some(ref cb) = on_notify {
+                    cb(domain, event, correlation_id);
+                }
+            }
+            if let Some(id) = ack_id {
+                let msg: ClientMessage<()> = ClientMessage::notify_ack(id);
+                send_control(inner, &msg);
             }
         }
         ServerMessage::Progress {
@@ -670,7 +1094,12 @@ fn handle_server_message<State, Delta, Event, Action>(
                 cb(op_id, percent, message);
             }
         }
-        ServerMessage::ActionOk { op_id, .. } => {
+        ServerMessage::ActionOk { op_id, result } => {
+            let call = inner.borrow_mut().pending_calls.take(op_id);
+            if let Some(reply) = call {
+                let _ = reply.send(Ok(result));
+                return;
+            }
             if let Some(ref cb) = on_action_complete {
                 cb(op_id);
             }
@@ -680,19 +1109,32 @@ fn handle_server_message<State, Delta, Event, Action>(
             code,
             message,
         } => {
+            let call = inner.borrow_mut().pending_calls.take(op_id);
+            if let Some(reply) = call {
+                let _ = reply.send(Err(RpcError::Remote { code, message }));
+                return;
+            }
             if let Some(ref cb) = on_action_error {
                 cb(op_id, code, message);
             }
         }
+        ServerMessage::Command { command } => {
+            tracing::info!("Received server command: {:?}", command);
+            if command == ClientCommand::ForceResync {
+                let msg: ClientMessage<()> = ClientMessage::resync(None);
+                if !send_control(inner, &msg) {
+                    tracing::warn!("Failed to request resync");
+                }
+            }
+            let on_command = inner.borrow().on_command.clone();
+            match on_command {
+                Some(cb) => cb(command),
+                None => run_default_command(&command),
+            }
+        }
     }
 }
 
-fn calculate_backoff(attempt: u32, config: &ReconnectConfig) -> u32 {
-    let multiplier = 2u32.saturating_pow(attempt.saturating_sub(1));
-    let delay = config.base_delay_ms.saturating_mul(multiplier);
-    delay.min(config.max_delay_ms)
-}
-
 fn reconnect<Action>(
     inner: &Rc<RefCell<ConnectionInner<Action>>>,
     url: &str,
@@ -718,6 +1160,78 @@ fn reconnect<Action>(
     Ok(())
 }
 
+/// Send Goodbye if the socket is up, then shut down
+fn say_goodbye<Action>(inner: &Rc<RefCell<ConnectionInner<Action>>>, reason: &str) {
+    let open = {
+        let inner = inner.borrow();
+        !inner.closed && inner.ws.is_some()
+    };
+    if open {
+        let msg: ClientMessage<()> = ClientMessage::goodbye(reason);
+        send_control(inner, &msg);
+    }
+    shut_down(&mut inner.borrow_mut());
+}
+
+/// Beacon the Goodbye if configured, then say it over the socket too
+fn leave_on_unload<Action>(inner: &Rc<RefCell<ConnectionInner<Action>>>) {
+    let beacon_url = {
+        let inner = inner.borrow();
+        if inner.closed {
+            return;
+        }
+        inner
+            .leave_beacon_url
+            .as_deref()
+            .zip(inner.connection_id.as_deref())
+            .map(|(url, id)| beacon_url_for(url, id))
+    };
+    if let Some(url) = beacon_url {
+        send_beacon(&url, GOODBYE_UNLOAD);
+    }
+    say_goodbye(inner, GOODBYE_UNLOAD);
+}
+
+/// Append the socket's `connection_id` to the leave beacon URL
+fn beacon_url_for(url: &str, connection_id: &str) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let id = js_sys::encode_uri_component(connection_id);
+    format!("{url}{separator}connection_id={id}")
+}
+
+/// POST an encoded Goodbye with `navigator.sendBeacon`
+fn send_beacon(url: &str, reason: &str) {
+    let msg: ClientMessage<()> = ClientMessage::goodbye(reason);
+    let (Some(window), Ok(bytes)) = (web_sys::window(), encode(&msg)) else {
+        return;
+    };
+    let body: js_sys::Object = js_sys::Uint8Array::from(bytes.as_slice()).into();
+    match window
+        .navigator()
+        .send_beacon_with_opt_buffer_source(url, Some(&body))
+    {
+        Ok(true) => tracing::debug!("Queued leave beacon to {}", url),
+        _ => tracing::warn!("Failed to queue leave beacon to {}", url),
+    }
+}
+
+/// Close the socket for good and drop in-flight calls
+fn shut_down<Action>(inner: &mut ConnectionInner<Action>) {
+    inner.closed = true;
+    if let Some(ws) = inner.ws.take() {
+        let _ = ws.close_with_code(NORMAL_CLOSURE);
+    }
+    inner.status = ConnectionStatus::Disconnected;
+    inner.reconnect_attempt = 0;
+    inner.pending_calls.drain().for_each(drop);
+
+    // The closure is kept: this may run inside the listener itself
+    if let (Some(listener), Some(window)) = (inner.unload_listener.as_ref(), web_sys::window()) {
+        let _ = window
+            .remove_event_listener_with_callback("pagehide", listener.as_ref().unchecked_ref());
+    }
+}
+
 fn start_ping_timer<Action: 'static>(
     inner: Rc<RefCell<ConnectionInner<Action>>>,
     interval_ms: u32,
@@ -731,30 +1245,48 @@ fn start_ping_timer<Action: 'static>(
                 inner.status.is_connected() && inner.ws.is_some()
             };
 
-            if should_ping {
-                let result = {
-                    let inner = inner.borrow();
-                    if let Some(ref ws) = inner.ws {
-                        let ts = js_sys::Date::now() as u64;
-                        let msg: ClientMessage<()> = ClientMessage::ping(ts);
-                        encode(&msg)
-                            .ok()
-                            .and_then(|bytes| ws.send_with_u8_array(&bytes).ok())
-                    } else {
-                        None
-                    }
-                };
-
-                if result.is_none() {
-                    break;
-                }
-            } else {
+            if !should_ping || !send_heartbeat(&inner) {
                 break;
             }
         }
     });
 }
 
+/// Send a timestamped Ping; returns whether it went out
+fn send_heartbeat<Action>(inner: &Rc<RefCell<ConnectionInner<Action>>>) -> bool {
+    let ts = js_sys::Date::now() as u64;
+    let msg: ClientMessage<()> = ClientMessage::ping(ts);
+    send_control(inner, &msg)
+}
+
+/// Send a connection-level message that carries no action
+fn send_control<Action>(
+    inner: &Rc<RefCell<ConnectionInner<Action>>>,
+    msg: &ClientMessage<()>,
+) -> bool {
+    let (result, debug, metrics) = {
+        let inner = inner.borrow();
+        let sent = inner.ws.as_ref().and_then(|ws| {
+            encode(msg)
+                .ok()
+                .and_then(|bytes| ws.send_with_u8_array(&bytes).ok().map(|_| bytes.len()))
+        });
+        (sent, inner.debug.clone(), inner.metrics.clone())
+    };
+
+    if let Some(metrics) = metrics {
+        metrics.record_sent(result.unwrap_or(0), result.is_some());
+    }
+
+    if let Some(debug) = debug {
+        let size = result.unwrap_or(0);
+        let error = result.is_none().then(|| "Send failed".to_string());
+        debug.record(MessageRecord::outbound(msg, size, error));
+    }
+
+    result.is_some()
+}
+
 /// Errors that can occur with Flow connections
 #[derive(Debug, thiserror::Error)]
 pub enum FlowError {
@@ -770,4 +1302,6 @@ pub enum FlowError {
     Send(String),
     #[error("Protocol error: {0}")]
     Protocol(#[from] ProtocolError),
+    #[error("Call failed: {0}")]
+    Rpc(#[from] RpcError),
 }
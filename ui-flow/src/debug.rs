@@ -0,0 +1,330 @@
+//! Message tracing for diagnosing protocol issues
+//!
+//! A [`FlowDebug`] handle attached to a connection records the last N
+//! inbound and outbound frames with their timestamp, size, message kind and
+//! decode result. Inspect it from the console, or mount the
+//! `FlowDebugOverlay` panel from ui-components (`flow-debug` feature).
+//!
+//! ```ignore
+//! let debug = FlowDebug::new(200);
+//! let connection = FlowConnection::builder()
+//!     .url(&ws_url)
+//!     .debug(debug.clone())
+//!     .connect()?;
+//!
+//! for record in debug.records() {
+//!     tracing::debug!("{record}");
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+use ui_flow_protocol::{ClientMessage, OpId, ServerMessage};
+
+use crate::operation::now_ms;
+
+/// Default number of records kept by [`FlowDebug::default`]
+pub const DEFAULT_DEBUG_CAPACITY: usize = 100;
+
+/// Which way a frame travelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageDirection {
+    /// Server → client
+    Inbound,
+    /// Client → server
+    Outbound,
+}
+
+impl MessageDirection {
+    /// Arrow used when rendering a record
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            Self::Inbound => "←",
+            Self::Outbound => "→",
+        }
+    }
+}
+
+/// One recorded frame
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageRecord {
+    /// Which way the frame travelled
+    pub direction: MessageDirection,
+    /// Wall-clock time the frame was sent or received, in milliseconds
+    pub timestamp_ms: f64,
+    /// Encoded size in bytes
+    pub size: usize,
+    /// Message tag name (e.g. `"Snapshot"`), `None` if the frame didn't decode
+    pub kind: Option<String>,
+    /// Operation the message relates to, if any
+    pub op_id: Option<OpId>,
+    /// Decode or encode failure, if any
+    pub error: Option<String>,
+}
+
+impl MessageRecord {
+    /// Record a decoded server message
+    pub fn inbound<State, Delta, Event>(
+        msg: &ServerMessage<State, Delta, Event>,
+        size: usize,
+    ) -> Self {
+        Self {
+            direction: MessageDirection::Inbound,
+            timestamp_ms: now_ms(),
+            size,
+            kind: Some(format!("{:?}", msg.tag())),
+            op_id: msg.op_id(),
+            error: None,
+        }
+    }
+
+    /// Record a server frame that failed to decode
+    pub fn inbound_error(size: usize, error: impl fmt::Display) -> Self {
+        Self {
+            direction: MessageDirection::Inbound,
+            timestamp_ms: now_ms(),
+            size,
+            kind: None,
+            op_id: None,
+            error: Some(error.to_string()),
+        }
+    }
+
+    /// Record a client message; `error` is set if it failed to encode or send
+    pub fn outbound<Action>(
+        msg: &ClientMessage<Action>,
+        size: usize,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            direction: MessageDirection::Outbound,
+            timestamp_ms: now_ms(),
+            size,
+            kind: Some(format!("{:?}", msg.tag())),
+            op_id: msg.op_id(),
+            error,
+        }
+    }
+
+    /// Whether the frame failed to decode, encode or send
+    pub fn is_error(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+impl fmt::Display for MessageRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} ({} B)",
+            self.direction.arrow(),
+            self.kind.as_deref().unwrap_or("?"),
+            self.size
+        )?;
+        if let Some(op_id) = self.op_id {
+            write!(f, " {op_id}")?;
+        }
+        if let Some(ref error) = self.error {
+            write!(f, " error: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+type Listener = Rc<dyn Fn(&MessageRecord)>;
+
+struct DebugLog {
+    capacity: usize,
+    enabled: bool,
+    records: VecDeque<MessageRecord>,
+    next_listener: u64,
+    listeners: Vec<(u64, Listener)>,
+}
+
+/// Ring buffer of recent frames, shared between a connection and its viewers
+///
+/// Cloning shares the same buffer.
+#[derive(Clone)]
+pub struct FlowDebug {
+    log: Rc<RefCell<DebugLog>>,
+}
+
+impl FlowDebug {
+    /// Keep the last `capacity` frames
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            log: Rc::new(RefCell::new(DebugLog {
+                capacity,
+                enabled: true,
+                records: VecDeque::with_capacity(capacity),
+                next_listener: 0,
+                listeners: Vec::new(),
+            })),
+        }
+    }
+
+    /// Append a record, evicting the oldest once full
+    ///
+    /// Ignored while recording is disabled.
+    pub fn record(&self, record: MessageRecord) {
+        let listeners = {
+            let mut log = self.log.borrow_mut();
+            if !log.enabled || log.capacity == 0 {
+                return;
+            }
+            while log.records.len() >= log.capacity {
+                log.records.pop_front();
+            }
+            log.records.push_back(record.clone());
+            log.listeners
+                .iter()
+                .map(|(_, cb)| cb.clone())
+                .collect::<Vec<_>>()
+        };
+        for cb in listeners {
+            cb(&record);
+        }
+    }
+
+    /// Recorded frames, oldest first
+    pub fn records(&self) -> Vec<MessageRecord> {
+        self.log.borrow().records.iter().cloned().collect()
+    }
+
+    /// Number of recorded frames
+    pub fn len(&self) -> usize {
+        self.log.borrow().records.len()
+    }
+
+    /// Whether nothing has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.log.borrow().records.is_empty()
+    }
+
+    /// Maximum number of frames kept
+    pub fn capacity(&self) -> usize {
+        self.log.borrow().capacity
+    }
+
+    /// Drop all recorded frames
+    pub fn clear(&self) {
+        self.log.borrow_mut().records.clear();
+    }
+
+    /// Pause or resume recording
+    pub fn set_enabled(&self, enabled: bool) {
+        self.log.borrow_mut().enabled = enabled;
+    }
+
+    /// Whether frames are being recorded
+    pub fn is_enabled(&self) -> bool {
+        self.log.borrow().enabled
+    }
+
+    /// Call `f` for every new record; returns an id for [`remove_listener`](Self::remove_listener)
+    pub fn on_record<F>(&self, f: F) -> u64
+    where
+        F: Fn(&MessageRecord) + 'static,
+    {
+        let mut log = self.log.borrow_mut();
+        let id = log.next_listener;
+        log.next_listener += 1;
+        log.listeners.push((id, Rc::new(f)));
+        id
+    }
+
+    /// Stop calling a listener registered with [`on_record`](Self::on_record)
+    pub fn remove_listener(&self, id: u64) {
+        self.log.borrow_mut().listeners.retain(|(i, _)| *i != id);
+    }
+}
+
+impl Default for FlowDebug {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEBUG_CAPACITY)
+    }
+}
+
+impl fmt::Debug for FlowDebug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let log = self.log.borrow();
+        f.debug_struct("FlowDebug")
+            .field("capacity", &log.capacity)
+            .field("enabled", &log.enabled)
+            .field("records", &log.records.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn ping() -> MessageRecord {
+        MessageRecord::outbound(&ClientMessage::<()>::ping(0), 8, None)
+    }
+
+    #[test]
+    fn test_keeps_last_n_records() {
+        let debug = FlowDebug::new(2);
+        debug.record(ping());
+        debug.record(MessageRecord::inbound_error(3, "bad frame"));
+        debug.record(MessageRecord::inbound(
+            &ServerMessage::<(), (), ()>::action_ok(OpId(7), None),
+            12,
+        ));
+
+        let records = debug.records();
+        assert_eq!(records.len(), 2);
+        assert!(records[0].is_error());
+        assert_eq!(records[1].kind.as_deref(), Some("ActionOk"));
+        assert_eq!(records[1].op_id, Some(OpId(7)));
+        assert_eq!(records[1].direction, MessageDirection::Inbound);
+    }
+
+    #[test]
+    fn test_disabled_debug_ignores_records() {
+        let debug = FlowDebug::new(10);
+        debug.set_enabled(false);
+        debug.record(ping());
+        assert!(debug.is_empty());
+
+        debug.set_enabled(true);
+        debug.record(ping());
+        assert_eq!(debug.len(), 1);
+        debug.clear();
+        assert!(debug.is_empty());
+    }
+
+    #[test]
+    fn test_listeners() {
+        let debug = FlowDebug::new(10);
+        let seen = Rc::new(Cell::new(0));
+        let id = debug.on_record({
+            let seen = seen.clone();
+            move |_| seen.set(seen.get() + 1)
+        });
+
+        debug.record(ping());
+        debug.remove_listener(id);
+        debug.record(ping());
+        assert_eq!(seen.get(), 1);
+    }
+
+    #[test]
+    fn test_display() {
+        let record = MessageRecord::outbound(
+            &ClientMessage::action(OpId(3), ()),
+            20,
+            Some("Not connected".into()),
+        );
+        assert_eq!(
+            record.to_string(),
+            "→ Action (20 B) op:3 error: Not connected"
+        );
+    }
+}
@@ -8,7 +8,10 @@
 //!
 //! - **Snapshot**: Full state sent on connection
 //! - **Delta**: Incremental updates sent after the snapshot
+//! - **Domain snapshot**: One named slice of a large state, fetched on demand
+//!   with `request_snapshot`
 //! - **FlowState**: Trait for state types that can receive deltas
+//! - **SyncedState**: Shared state with per-user private deltas layered on top
 //! - **OpId**: Unique identifier for tracking client actions through their lifecycle
 //!
 //! ## Quick Start
@@ -71,6 +74,25 @@
 //! connection.send_action(op_id, GameAction::Move { direction: "north".into() })?;
 //! ```
 //!
+//! ## Request/Response
+//!
+//! Actions that return data can be awaited as typed calls. The server replies
+//! with `ServerMessage::action_ok_with(op_id, &value)`:
+//!
+//! ```ignore
+//! let quote: Quote = connection.call(GameAction::GetQuote { item }).await?;
+//! ```
+//!
+//! ## Leaving
+//!
+//! `connection.close(reason).await` waits briefly for in-flight calls, sends
+//! a `Goodbye` so the server can drop the user's presence immediately, and
+//! closes without reconnecting. Connections also say Goodbye on `pagehide`;
+//! add `.leave_beacon(url)` to deliver it with `navigator.sendBeacon` when
+//! the socket can't be relied on during unload. The beacon carries the
+//! `connection_id` from `Connected`, so servers should issue one per socket
+//! and keep it private to that client.
+//!
 //! ## Optimistic UI
 //!
 //! Use `OperationTracker` to manage pending operations:
@@ -94,6 +116,37 @@
 //! let data = tracker.fail(&error);
 //! ```
 //!
+//! ## Server Commands
+//!
+//! Servers can send a [`ClientCommand`] (force resync, reload, maintenance
+//! banner, redirect) so operators can drain or upgrade sessions gracefully.
+//! Register `.on_command(...)` on the builder; without one, reloads and
+//! redirects run via [`run_default_command`].
+//!
+//! ## Debugging
+//!
+//! Attach a [`FlowDebug`] with `.debug(...)` on a connection builder to keep
+//! the last N frames (direction, timestamp, size, kind, decode result) for
+//! inspection. ui-components renders it with `<FlowDebugOverlay>`.
+//!
+//! ## Metrics
+//!
+//! Attach a [`FlowMetrics`] with `.metrics(...)` to count messages, bytes,
+//! decode failures and reconnects, track latency, and read per-second rates.
+//! Subscribe with [`FlowMetrics::on_change`]; [`MetricsSnapshot`] serializes
+//! for forwarding to telemetry.
+//!
+//! ## Tab Sharing
+//!
+//! Use [`transport::SharedTabTransport`] with [`PollingFlowConnection`] to
+//! open one socket per browser instead of one per tab; see [`tabs`].
+//!
+//! ## Testing
+//!
+//! With the `testing` feature, [`testing::TestServer`] and
+//! [`testing::TestClient`] run the protocol over an in-memory transport so
+//! state and connection handling can be tested with plain `cargo test`.
+//!
 //! ## Framework Integration
 //!
 //! This crate is framework-agnostic and uses callbacks. Framework-specific
@@ -110,6 +163,8 @@ pub mod transport;
 
 // Callback-based connection (web-sys only)
 #[cfg(feature = "web-sys-transport")]
+mod command;
+#[cfg(feature = "web-sys-transport")]
 mod connection;
 
 // Polling-based connection (works with both transports)
@@ -118,17 +173,26 @@ mod polling_connection;
 // Notification-only connection (web-sys only, uses callback-based connection)
 #[cfg(feature = "web-sys-transport")]
 pub mod notify;
+mod debug;
+mod metrics;
 mod operation;
 mod state;
 mod status;
+pub mod tabs;
+
+// In-memory transport and test server
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 // Re-export the protocol types from ui_flow_protocol
 pub use ui_flow_protocol::{
     self as protocol,
     // Codec functions
     decode,
+    decode_result,
     encode,
     // Core message types
+    ClientCommand,
     ClientMessage,
     // Helper types
     OpId,
@@ -136,13 +200,24 @@ pub use ui_flow_protocol::{
     PresenceStatus,
     // Error types
     ProtocolError,
+    RpcError,
+    Sealed,
     ServerMessage,
     SignalPayload,
 };
 
+// End-to-end payload encryption
+#[cfg(feature = "secure")]
+pub use ui_flow_protocol::{KeyPair, SecureCodec, SecureError};
+
 // Callback-based connection (web-sys only)
 #[cfg(feature = "web-sys-transport")]
-pub use connection::{FlowConnection, FlowConnectionBuilder, FlowError, ReconnectConfig};
+pub use command::run_default_command;
+#[cfg(feature = "web-sys-transport")]
+pub use connection::{
+    FlowConnection, FlowConnectionBuilder, FlowError, ReconnectConfig, CLOSE_FLUSH_TIMEOUT_MS,
+    DEFAULT_CALL_TIMEOUT_MS,
+};
 
 // Polling-based connection (works with both transports)
 pub use polling_connection::{
@@ -150,6 +225,8 @@ pub use polling_connection::{
     ReconnectConfig as PollingReconnectConfig,
 };
 
+pub use debug::{FlowDebug, MessageDirection, MessageRecord, DEFAULT_DEBUG_CAPACITY};
+pub use metrics::{FlowMetrics, MetricsSnapshot, RATE_WINDOW_MS};
 pub use operation::{ActionError, ActionProgress, OperationTracker, PendingOperation};
-pub use state::FlowState;
+pub use state::{FlowState, PrivateMerge, SyncedState};
 pub use status::{CloseInfo, ConnectionStatus};
@@ -0,0 +1,332 @@
+//! Traffic counters for capacity planning
+//!
+//! A [`FlowMetrics`] handle attached to a connection counts messages and
+//! bytes in each direction, decode and send failures, reconnects and the
+//! latest ping latency, plus per-second rates over a short sliding window.
+//! Read a [`MetricsSnapshot`] on demand or subscribe to changes; snapshots
+//! serialize, so they can be forwarded to whatever telemetry sink the app
+//! uses. `FlowDebugOverlay` in ui-components shows them alongside the
+//! frame log.
+//!
+//! ```ignore
+//! let metrics = FlowMetrics::new();
+//! let connection = FlowConnection::builder()
+//!     .url(&ws_url)
+//!     .metrics(metrics.clone())
+//!     .connect()?;
+//!
+//! metrics.on_change(|snapshot| {
+//!     tracing::trace!("{} msg/s in", snapshot.messages_in_per_sec);
+//! });
+//! ```
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::rc::Rc;
+
+use serde::Serialize;
+
+use crate::debug::MessageDirection;
+use crate::operation::now_ms;
+
+/// Window over which per-second rates are averaged, in milliseconds
+pub const RATE_WINDOW_MS: f64 = 5_000.0;
+
+/// Point-in-time copy of a connection's counters
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MetricsSnapshot {
+    /// Messages sent to the server
+    pub messages_sent: u64,
+    /// Messages received from the server, including undecodable frames
+    pub messages_received: u64,
+    /// Encoded bytes sent
+    pub bytes_sent: u64,
+    /// Encoded bytes received
+    pub bytes_received: u64,
+    /// Inbound frames that failed to decode
+    pub decode_failures: u64,
+    /// Outbound messages that failed to encode or send
+    pub send_failures: u64,
+    /// Reconnection attempts since the connection was created
+    pub reconnects: u64,
+    /// Most recent ping round trip, in milliseconds
+    pub latency_ms: Option<u32>,
+    /// Inbound messages per second over the last [`RATE_WINDOW_MS`]
+    pub messages_in_per_sec: f64,
+    /// Outbound messages per second over the last [`RATE_WINDOW_MS`]
+    pub messages_out_per_sec: f64,
+    /// Inbound bytes per second over the last [`RATE_WINDOW_MS`]
+    pub bytes_in_per_sec: f64,
+    /// Outbound bytes per second over the last [`RATE_WINDOW_MS`]
+    pub bytes_out_per_sec: f64,
+}
+
+/// One frame in the rate window
+struct Sample {
+    at_ms: f64,
+    direction: MessageDirection,
+    size: usize,
+}
+
+type Listener = Rc<dyn Fn(&MetricsSnapshot)>;
+
+struct MetricsState {
+    counters: MetricsSnapshot,
+    window: VecDeque<Sample>,
+    next_listener: u64,
+    listeners: Vec<(u64, Listener)>,
+}
+
+impl MetricsState {
+    fn trim(&mut self, now: f64) {
+        while self
+            .window
+            .front()
+            .is_some_and(|s| now - s.at_ms > RATE_WINDOW_MS)
+        {
+            self.window.pop_front();
+        }
+    }
+
+    fn snapshot(&mut self, now: f64) -> MetricsSnapshot {
+        self.trim(now);
+        let secs = RATE_WINDOW_MS / 1000.0;
+        let mut snapshot = self.counters.clone();
+        for sample in &self.window {
+            match sample.direction {
+                MessageDirection::Inbound => {
+                    snapshot.messages_in_per_sec += 1.0;
+                    snapshot.bytes_in_per_sec += sample.size as f64;
+                }
+                MessageDirection::Outbound => {
+                    snapshot.messages_out_per_sec += 1.0;
+                    snapshot.bytes_out_per_sec += sample.size as f64;
+                }
+            }
+        }
+        snapshot.messages_in_per_sec /= secs;
+        snapshot.messages_out_per_sec /= secs;
+        snapshot.bytes_in_per_sec /= secs;
+        snapshot.bytes_out_per_sec /= secs;
+        snapshot
+    }
+}
+
+/// Shared traffic counters for one connection
+///
+/// Cloning shares the same counters.
+#[derive(Clone)]
+pub struct FlowMetrics {
+    state: Rc<RefCell<MetricsState>>,
+}
+
+impl FlowMetrics {
+    /// Counters starting at zero
+    pub fn new() -> Self {
+        Self {
+            state: Rc::new(RefCell::new(MetricsState {
+                counters: MetricsSnapshot::default(),
+                window: VecDeque::new(),
+                next_listener: 0,
+                listeners: Vec::new(),
+            })),
+        }
+    }
+
+    /// Count a received frame of `size` bytes; `decoded` is false if it failed to decode
+    pub fn record_received(&self, size: usize, decoded: bool) {
+        self.record_received_at(now_ms(), size, decoded);
+    }
+
+    /// Count a sent message of `size` bytes; `sent` is false if it failed to encode or send
+    pub fn record_sent(&self, size: usize, sent: bool) {
+        self.record_sent_at(now_ms(), size, sent);
+    }
+
+    /// Count a reconnection attempt
+    pub fn record_reconnect(&self) {
+        self.update(now_ms(), |c| c.reconnects += 1);
+    }
+
+    /// Store the latest ping round trip
+    pub fn record_latency(&self, latency_ms: u32) {
+        self.update(now_ms(), |c| c.latency_ms = Some(latency_ms));
+    }
+
+    fn record_received_at(&self, now: f64, size: usize, decoded: bool) {
+        self.push_sample(now, MessageDirection::Inbound, size);
+        self.update(now, |c| {
+            c.messages_received += 1;
+            c.bytes_received += size as u64;
+            if !decoded {
+                c.decode_failures += 1;
+            }
+        });
+    }
+
+    fn record_sent_at(&self, now: f64, size: usize, sent: bool) {
+        if !sent {
+            self.update(now, |c| c.send_failures += 1);
+            return;
+        }
+        self.push_sample(now, MessageDirection::Outbound, size);
+        self.update(now, |c| {
+            c.messages_sent += 1;
+            c.bytes_sent += size as u64;
+        });
+    }
+
+    fn push_sample(&self, now: f64, direction: MessageDirection, size: usize) {
+        let mut state = self.state.borrow_mut();
+        state.window.push_back(Sample {
+            at_ms: now,
+            direction,
+            size,
+        });
+        state.trim(now);
+    }
+
+    /// Apply `f` to the counters, then notify listeners
+    fn update(&self, now: f64, f: impl FnOnce(&mut MetricsSnapshot)) {
+        let (snapshot, listeners) = {
+            let mut state = self.state.borrow_mut();
+            f(&mut state.counters);
+            if state.listeners.is_empty() {
+                return;
+            }
+            let listeners = state
+                .listeners
+                .iter()
+                .map(|(_, cb)| cb.clone())
+                .collect::<Vec<_>>();
+            (state.snapshot(now), listeners)
+        };
+        for cb in listeners {
+            cb(&snapshot);
+        }
+    }
+
+    /// Current counters and rates
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        self.snapshot_at(now_ms())
+    }
+
+    fn snapshot_at(&self, now: f64) -> MetricsSnapshot {
+        self.state.borrow_mut().snapshot(now)
+    }
+
+    /// Zero all counters and rates
+    pub fn reset(&self) {
+        let mut state = self.state.borrow_mut();
+        state.counters = MetricsSnapshot::default();
+        state.window.clear();
+    }
+
+    /// Call `f` whenever a counter changes; returns an id for [`remove_listener`](Self::remove_listener)
+    ///
+    /// Rates only change with traffic, so poll [`snapshot`](Self::snapshot)
+    /// as well if idle rates should decay to zero on screen.
+    pub fn on_change<F>(&self, f: F) -> u64
+    where
+        F: Fn(&MetricsSnapshot) + 'static,
+    {
+        let mut state = self.state.borrow_mut();
+        let id = state.next_listener;
+        state.next_listener += 1;
+        state.listeners.push((id, Rc::new(f)));
+        id
+    }
+
+    /// Stop calling a listener registered with [`on_change`](Self::on_change)
+    pub fn remove_listener(&self, id: u64) {
+        self.state.borrow_mut().listeners.retain(|(i, _)| *i != id);
+    }
+}
+
+impl Default for FlowMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for FlowMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowMetrics")
+            .field("counters", &self.state.borrow().counters)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_counts_traffic() {
+        let metrics = FlowMetrics::new();
+        metrics.record_received_at(0.0, 100, true);
+        metrics.record_received_at(0.0, 7, false);
+        metrics.record_sent_at(0.0, 20, true);
+        metrics.record_sent_at(0.0, 0, false);
+        metrics.record_reconnect();
+        metrics.record_latency(42);
+
+        let snapshot = metrics.snapshot_at(0.0);
+        assert_eq!(snapshot.messages_received, 2);
+        assert_eq!(snapshot.bytes_received, 107);
+        assert_eq!(snapshot.decode_failures, 1);
+        assert_eq!(snapshot.messages_sent, 1);
+        assert_eq!(snapshot.bytes_sent, 20);
+        assert_eq!(snapshot.send_failures, 1);
+        assert_eq!(snapshot.reconnects, 1);
+        assert_eq!(snapshot.latency_ms, Some(42));
+    }
+
+    #[test]
+    fn test_rates_over_window() {
+        let metrics = FlowMetrics::new();
+        for i in 0..10 {
+            metrics.record_received_at(i as f64 * 100.0, 50, true);
+        }
+        metrics.record_sent_at(500.0, 10, true);
+
+        let snapshot = metrics.snapshot_at(1_000.0);
+        let secs = RATE_WINDOW_MS / 1000.0;
+        assert_eq!(snapshot.messages_in_per_sec, 10.0 / secs);
+        assert_eq!(snapshot.bytes_in_per_sec, 500.0 / secs);
+        assert_eq!(snapshot.messages_out_per_sec, 1.0 / secs);
+
+        // Rates decay once the window passes; totals don't
+        let later = metrics.snapshot_at(1_000.0 + RATE_WINDOW_MS);
+        assert_eq!(later.messages_in_per_sec, 0.0);
+        assert_eq!(later.messages_out_per_sec, 0.0);
+        assert_eq!(later.messages_received, 10);
+    }
+
+    #[test]
+    fn test_reset() {
+        let metrics = FlowMetrics::new();
+        metrics.record_received_at(0.0, 10, true);
+        metrics.reset();
+        assert_eq!(metrics.snapshot_at(0.0), MetricsSnapshot::default());
+    }
+
+    #[test]
+    fn test_listeners() {
+        let metrics = FlowMetrics::new();
+        let seen = Rc::new(Cell::new(0));
+        let id = metrics.on_change({
+            let seen = seen.clone();
+            move |snapshot| seen.set(snapshot.messages_sent)
+        });
+
+        metrics.record_sent_at(0.0, 8, true);
+        assert_eq!(seen.get(), 1);
+
+        metrics.remove_listener(id);
+        metrics.record_sent_at(0.0, 8, true);
+        assert_eq!(seen.get(), 1);
+    }
+}
@@ -30,12 +30,39 @@
 //! // Send an action
 //! let op_id = conn.send(MyAction::Apply { placement_id })?;
 //! ```
+//!
+//! # Shared client with typed domains
+//!
+//! [`NotifyClient`] is a cloneable handle meant to be shared between
+//! components. Each subscription picks its own event type, and domains are
+//! reference counted: the server subscription is sent when the first
+//! subscriber for a domain appears and dropped with the last one, and all
+//! live domains are re-subscribed after a reconnect.
+//!
+//! ```ignore
+//! use ui_flow::notify::NotifyClient;
+//!
+//! let client = NotifyClient::<NoAction>::builder()
+//!     .url("wss://example.com/ws")
+//!     .connect()?;
+//!
+//! // Two components, one server subscription
+//! let rewards = client.subscribe("rewards:blackflag", |event: RewardEvent, _| { /* ... */ })?;
+//! let feed = client.subscribe("rewards:blackflag", |event: FeedItem, _| { /* ... */ })?;
+//!
+//! // Dropping the last handle for a domain unsubscribes
+//! drop(rewards);
+//! drop(feed);
+//! ```
 
 use serde::de::DeserializeOwned;
-use serde::Serialize;
-use std::rc::Rc;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
 
 use crate::connection::{FlowConnection, FlowConnectionBuilder, FlowError, ReconnectConfig};
+use crate::debug::FlowDebug;
 use crate::status::ConnectionStatus;
 use ui_flow_protocol::OpId;
 
@@ -65,6 +92,7 @@ pub struct NotifyConnectionBuilder<Event, Action = NoAction> {
     on_action_complete: Option<Rc<dyn Fn(OpId)>>,
     on_action_error: Option<Rc<dyn Fn(OpId, Option<String>, String)>>,
     on_error: Option<Rc<dyn Fn(String, bool)>>,
+    debug: Option<FlowDebug>,
     _action: std::marker::PhantomData<Action>,
 }
 
@@ -84,6 +112,7 @@ where
             on_action_complete: None,
             on_action_error: None,
             on_error: None,
+            debug: None,
             _action: std::marker::PhantomData,
         }
     }
@@ -159,6 +188,12 @@ where
         self
     }
 
+    /// Record inbound/outbound frames into `debug`
+    pub fn debug(mut self, debug: FlowDebug) -> Self {
+        self.debug = Some(debug);
+        self
+    }
+
     /// Build and connect
     pub fn connect(self) -> Result<NotifyConnection<Event, Action>, FlowError> {
         let url = self
@@ -198,6 +233,10 @@ where
             builder = builder.on_error(move |msg, fatal| cb(msg, fatal));
         }
 
+        if let Some(debug) = self.debug {
+            builder = builder.debug(debug);
+        }
+
         // Ignore state callbacks - we don't use them in notify-only mode
         builder = builder
             .on_snapshot(|_, _| {})
@@ -252,6 +291,11 @@ where
         self.inner.is_connected()
     }
 
+    /// Say Goodbye and close; see [`FlowConnection::close`]
+    pub async fn close(&self, reason: impl Into<String>) {
+        self.inner.close(reason).await;
+    }
+
     /// Disconnect and clean up
     pub fn disconnect(&self) {
         self.inner.disconnect();
@@ -289,6 +333,376 @@ where
     }
 }
 
+/// Notification event before per-subscription typed decoding
+///
+/// Any self-describing payload decodes into this, so one connection can carry
+/// events of different types for different domains.
+pub type RawEvent = serde_json::Value;
+
+type Handler = Rc<dyn Fn(&RawEvent, Option<OpId>)>;
+type DecodeErrorCallback = Option<Rc<dyn Fn(String, String)>>;
+
+/// Per-domain handler registry
+///
+/// A domain is subscribed on the server while it has at least one handler.
+#[derive(Default)]
+struct Registry {
+    handlers: HashMap<String, Vec<(u64, Handler)>>,
+    next_id: u64,
+}
+
+impl Registry {
+    /// Add a handler, returning its id and whether it is the domain's first
+    fn add(&mut self, domain: &str, handler: Handler) -> (u64, bool) {
+        let id = self.next_id;
+        self.next_id += 1;
+        let handlers = self.handlers.entry(domain.to_string()).or_default();
+        handlers.push((id, handler));
+        (id, handlers.len() == 1)
+    }
+
+    /// Remove a handler, returning whether it was the domain's last
+    fn remove(&mut self, domain: &str, id: u64) -> bool {
+        let Some(handlers) = self.handlers.get_mut(domain) else {
+            return false;
+        };
+        handlers.retain(|(h, _)| *h != id);
+        if handlers.is_empty() {
+            self.handlers.remove(domain);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn count(&self, domain: &str) -> usize {
+        self.handlers.get(domain).map_or(0, Vec::len)
+    }
+
+    fn domains(&self) -> Vec<String> {
+        self.handlers.keys().cloned().collect()
+    }
+
+    /// Handlers for a domain, cloned so they can run without holding a borrow
+    fn handlers(&self, domain: &str) -> Vec<Handler> {
+        self.handlers
+            .get(domain)
+            .map(|hs| hs.iter().map(|(_, h)| h.clone()).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Handle for one [`NotifyClient::subscribe`] registration
+///
+/// Dropping it removes the handler, and unsubscribes the domain on the server
+/// if it was the last one.
+#[must_use = "dropping a Subscription unsubscribes immediately"]
+pub struct Subscription {
+    domain: String,
+    release: Option<Box<dyn FnOnce()>>,
+}
+
+impl Subscription {
+    /// The subscribed domain
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(release) = self.release.take() {
+            release();
+        }
+    }
+}
+
+/// Builder for [`NotifyClient`]
+#[allow(clippy::type_complexity)]
+pub struct NotifyClientBuilder<Action = NoAction> {
+    inner: NotifyConnectionBuilder<RawEvent, Action>,
+    on_connected: Option<Rc<dyn Fn(String)>>,
+    on_decode_error: DecodeErrorCallback,
+}
+
+impl<Action> NotifyClientBuilder<Action>
+where
+    Action: Serialize + 'static,
+{
+    /// Create a new builder
+    pub fn new() -> Self {
+        Self {
+            inner: NotifyConnectionBuilder::new(),
+            on_connected: None,
+            on_decode_error: None,
+        }
+    }
+
+    /// Set the WebSocket URL
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.inner = self.inner.url(url);
+        self
+    }
+
+    /// Configure reconnection behavior
+    pub fn reconnect_config(mut self, config: ReconnectConfig) -> Self {
+        self.inner = self.inner.reconnect_config(config);
+        self
+    }
+
+    /// Callback when connection status changes
+    pub fn on_status<F>(mut self, f: F) -> Self
+    where
+        F: Fn(ConnectionStatus) + 'static,
+    {
+        self.inner = self.inner.on_status(f);
+        self
+    }
+
+    /// Callback when connection is established (receives connection_id)
+    ///
+    /// Runs after live domains have been re-subscribed.
+    pub fn on_connected<F>(mut self, f: F) -> Self
+    where
+        F: Fn(String) + 'static,
+    {
+        self.on_connected = Some(Rc::new(f));
+        self
+    }
+
+    /// Callback when an action completes successfully
+    pub fn on_action_complete<F>(mut self, f: F) -> Self
+    where
+        F: Fn(OpId) + 'static,
+    {
+        self.inner = self.inner.on_action_complete(f);
+        self
+    }
+
+    /// Callback when an action fails (op_id, error_code, message)
+    pub fn on_action_error<F>(mut self, f: F) -> Self
+    where
+        F: Fn(OpId, Option<String>, String) + 'static,
+    {
+        self.inner = self.inner.on_action_error(f);
+        self
+    }
+
+    /// Callback for connection/protocol errors (message, is_fatal)
+    pub fn on_error<F>(mut self, f: F) -> Self
+    where
+        F: Fn(String, bool) + 'static,
+    {
+        self.inner = self.inner.on_error(f);
+        self
+    }
+
+    /// Callback when an event doesn't match a subscriber's type (domain, error)
+    ///
+    /// Without this, decode failures are only logged.
+    pub fn on_decode_error<F>(mut self, f: F) -> Self
+    where
+        F: Fn(String, String) + 'static,
+    {
+        self.on_decode_error = Some(Rc::new(f));
+        self
+    }
+
+    /// Record inbound/outbound frames into `debug`
+    pub fn debug(mut self, debug: FlowDebug) -> Self {
+        self.inner = self.inner.debug(debug);
+        self
+    }
+
+    /// Build and connect
+    pub fn connect(self) -> Result<NotifyClient<Action>, FlowError> {
+        let registry = Rc::new(RefCell::new(Registry::default()));
+        let conn_slot = Rc::new(RefCell::new(
+            Weak::<NotifyConnection<RawEvent, Action>>::new(),
+        ));
+
+        let dispatch_registry = registry.clone();
+        let resubscribe_registry = registry.clone();
+        let resubscribe_slot = conn_slot.clone();
+        let user_on_connected = self.on_connected;
+
+        let conn = self
+            .inner
+            .on_notify(move |domain, event, correlation_id| {
+                let handlers = dispatch_registry.borrow().handlers(&domain);
+                for handler in handlers {
+                    handler(&event, correlation_id);
+                }
+            })
+            .on_connected(move |connection_id| {
+                // Subscriptions don't survive a reconnect; restore them
+                let domains = resubscribe_registry.borrow().domains();
+                if !domains.is_empty() {
+                    if let Some(conn) = resubscribe_slot.borrow().upgrade() {
+                        if let Err(e) = conn.subscribe(domains) {
+                            tracing::warn!("Failed to restore notify subscriptions: {}", e);
+                        }
+                    }
+                }
+                if let Some(ref cb) = user_on_connected {
+                    cb(connection_id);
+                }
+            })
+            .connect()?;
+
+        let conn = Rc::new(conn);
+        *conn_slot.borrow_mut() = Rc::downgrade(&conn);
+
+        Ok(NotifyClient {
+            conn,
+            registry,
+            on_decode_error: self.on_decode_error,
+        })
+    }
+}
+
+impl<Action> Default for NotifyClientBuilder<Action>
+where
+    Action: Serialize + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shared notification client with reference-counted domain subscriptions
+///
+/// Cheap to clone; clones share the connection and subscriptions.
+pub struct NotifyClient<Action = NoAction> {
+    conn: Rc<NotifyConnection<RawEvent, Action>>,
+    registry: Rc<RefCell<Registry>>,
+    on_decode_error: DecodeErrorCallback,
+}
+
+impl<Action> Clone for NotifyClient<Action> {
+    fn clone(&self) -> Self {
+        Self {
+            conn: self.conn.clone(),
+            registry: self.registry.clone(),
+            on_decode_error: self.on_decode_error.clone(),
+        }
+    }
+}
+
+impl<Action> NotifyClient<Action>
+where
+    Action: Serialize + 'static,
+{
+    /// Create a new builder
+    pub fn builder() -> NotifyClientBuilder<Action> {
+        NotifyClientBuilder::new()
+    }
+
+    /// Get current connection status
+    pub fn status(&self) -> ConnectionStatus {
+        self.conn.status()
+    }
+
+    /// Check if connected
+    pub fn is_connected(&self) -> bool {
+        self.conn.is_connected()
+    }
+
+    /// Disconnect and clean up
+    pub fn disconnect(&self) {
+        self.conn.disconnect();
+    }
+
+    /// Number of live subscriptions for a domain
+    pub fn subscriber_count(&self, domain: &str) -> usize {
+        self.registry.borrow().count(domain)
+    }
+
+    /// Receive events for `domain`, decoded as `T`
+    ///
+    /// Events that don't decode as `T` are skipped for this subscriber (and
+    /// reported via `on_decode_error`); other subscribers to the same domain
+    /// may use different types. If not yet connected, the server subscription
+    /// is sent once the connection is established.
+    pub fn subscribe<T, F>(
+        &self,
+        domain: impl Into<String>,
+        callback: F,
+    ) -> Result<Subscription, FlowError>
+    where
+        T: DeserializeOwned + 'static,
+        F: Fn(T, Option<OpId>) + 'static,
+    {
+        let domain = domain.into();
+
+        let handler_domain = domain.clone();
+        let on_decode_error = self.on_decode_error.clone();
+        let handler: Handler = Rc::new(move |raw: &RawEvent, correlation_id: Option<OpId>| {
+            match <T as Deserialize>::deserialize(raw) {
+                Ok(event) => callback(event, correlation_id),
+                Err(e) => {
+                    tracing::warn!("Failed to decode event for {}: {}", handler_domain, e);
+                    if let Some(ref cb) = on_decode_error {
+                        cb(handler_domain.clone(), e.to_string());
+                    }
+                }
+            }
+        });
+
+        let (id, first) = self.registry.borrow_mut().add(&domain, handler);
+        if first && self.conn.is_connected() {
+            if let Err(e) = self.conn.subscribe(vec![domain.clone()]) {
+                self.registry.borrow_mut().remove(&domain, id);
+                return Err(e);
+            }
+        }
+
+        let registry = Rc::downgrade(&self.registry);
+        let conn = Rc::downgrade(&self.conn);
+        let release_domain = domain.clone();
+        let release: Box<dyn FnOnce()> = Box::new(move || {
+            let Some(registry) = registry.upgrade() else {
+                return;
+            };
+            let last = registry.borrow_mut().remove(&release_domain, id);
+            if let Some(conn) = conn.upgrade().filter(|c| last && c.is_connected()) {
+                if let Err(e) = conn.unsubscribe(vec![release_domain]) {
+                    tracing::warn!("Failed to unsubscribe: {}", e);
+                }
+            }
+        });
+
+        Ok(Subscription {
+            domain,
+            release: Some(release),
+        })
+    }
+
+    /// Receive events for `domain` into a Leptos signal holding the latest one
+    ///
+    /// Keep the returned [`Subscription`] alive for as long as the signal
+    /// should update, e.g. in a `StoredValue::new_local`.
+    #[cfg(feature = "leptos")]
+    pub fn subscribe_signal<T>(
+        &self,
+        domain: impl Into<String>,
+    ) -> Result<(leptos::prelude::ReadSignal<Option<T>>, Subscription), FlowError>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        use leptos::prelude::Set;
+
+        let (event, set_event) = leptos::prelude::signal(None);
+        let subscription = self.subscribe(domain, move |e: T, _| set_event.set(Some(e)))?;
+        Ok((event, subscription))
+    }
+
+    /// Send an action to the server, returning the operation ID for tracking
+    pub fn send(&self, action: Action) -> Result<OpId, FlowError> {
+        self.conn.send(action)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,4 +722,54 @@ mod tests {
         let json = serde_json::to_string(&action).unwrap();
         assert_eq!(json, "null");
     }
+
+    fn recording_handler(log: &Rc<RefCell<Vec<String>>>, tag: &'static str) -> Handler {
+        let log = log.clone();
+        Rc::new(move |_: &RawEvent, _: Option<OpId>| log.borrow_mut().push(tag.to_string()))
+    }
+
+    #[test]
+    fn test_registry_reference_counts_domains() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut registry = Registry::default();
+
+        let (a, first) = registry.add("rewards:bf", recording_handler(&log, "a"));
+        assert!(first);
+        let (b, first) = registry.add("rewards:bf", recording_handler(&log, "b"));
+        assert!(!first);
+        assert_eq!(registry.count("rewards:bf"), 2);
+
+        assert!(!registry.remove("rewards:bf", a));
+        assert!(registry.remove("rewards:bf", b));
+        assert_eq!(registry.count("rewards:bf"), 0);
+        assert!(registry.domains().is_empty());
+
+        // Unknown ids and domains are ignored
+        assert!(!registry.remove("rewards:bf", a));
+    }
+
+    #[test]
+    fn test_registry_dispatches_only_to_domain_handlers() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut registry = Registry::default();
+        registry.add("rewards:bf", recording_handler(&log, "rewards"));
+        registry.add("widget_bridge:bf", recording_handler(&log, "widget"));
+
+        for handler in registry.handlers("rewards:bf") {
+            handler(&RawEvent::Null, None);
+        }
+        assert_eq!(*log.borrow(), vec!["rewards".to_string()]);
+    }
+
+    #[test]
+    fn test_raw_event_decodes_typed() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Reward {
+            amount: u32,
+        }
+
+        let raw: RawEvent = serde_json::json!({ "amount": 5 });
+        assert_eq!(Reward::deserialize(&raw).unwrap(), Reward { amount: 5 });
+        assert!(u32::deserialize(&raw).is_err());
+    }
 }
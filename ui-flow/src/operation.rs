@@ -13,18 +13,26 @@ use std::collections::HashMap;
 pub use ui_flow_protocol::OpId;
 
 /// Get current time in milliseconds
-#[cfg(all(feature = "web-sys-transport", not(feature = "macroquad")))]
-fn now_ms() -> f64 {
+#[cfg(all(
+    feature = "web-sys-transport",
+    not(feature = "macroquad"),
+    target_arch = "wasm32"
+))]
+pub(crate) fn now_ms() -> f64 {
     js_sys::Date::now()
 }
 
 #[cfg(feature = "macroquad")]
-fn now_ms() -> f64 {
+pub(crate) fn now_ms() -> f64 {
     miniquad::date::now() * 1000.0
 }
 
-#[cfg(not(any(feature = "web-sys-transport", feature = "macroquad")))]
-fn now_ms() -> f64 {
+// js-sys imports panic off wasm (e.g. native test runs)
+#[cfg(not(any(
+    all(feature = "web-sys-transport", target_arch = "wasm32"),
+    feature = "macroquad"
+)))]
+pub(crate) fn now_ms() -> f64 {
     0.0 // Fallback - timing features won't work
 }
 
@@ -5,13 +5,18 @@
 //! connection requires calling `poll()` regularly to process incoming messages.
 //!
 //! This is ideal for game loops where you're already polling each frame.
+//!
+//! The transport defaults to the active WebSocket backend; tests can use
+//! `testing::InMemoryTransport` instead.
 
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::status::{CloseInfo, ConnectionStatus};
 use crate::transport::{DefaultTransport, WebSocketEvent, WebSocketTransport};
-use ui_flow_protocol::{decode, encode, ClientMessage, OpId, PresenceInfo, ServerMessage};
+use ui_flow_protocol::{
+    decode, encode, ClientCommand, ClientMessage, OpId, PresenceInfo, SeenNotifies, ServerMessage,
+};
 
 /// Configuration for reconnection behavior
 #[derive(Debug, Clone)]
@@ -45,6 +50,14 @@ pub enum FlowEvent<State, Delta, Event> {
     Delta { delta: Delta, seq: u64 },
     /// Batch of deltas received
     Deltas { deltas: Vec<Delta>, seq: u64 },
+    /// Delta for this user only; `seq` is the shared seq it applies on
+    PrivateDelta { delta: Delta, seq: u64 },
+    /// One domain's encoded slice of state, after `request_snapshot`
+    DomainSnapshot {
+        domain: String,
+        data: Vec<u8>,
+        seq: u64,
+    },
     /// Presence update received
     Presence(Vec<PresenceInfo>),
     /// Notification event received
@@ -73,6 +86,10 @@ pub enum FlowEvent<State, Delta, Event> {
     Error { message: String, fatal: bool },
     /// Pong received (for latency measurement)
     Pong { client_ts: u64, server_ts: u64 },
+    /// Operator command from the server
+    ///
+    /// `ForceResync` has already been acted on when this is emitted.
+    Command(ClientCommand),
 }
 
 /// Polling-based Flow connection
@@ -98,8 +115,8 @@ pub enum FlowEvent<State, Delta, Event> {
 ///     // ... rest of game loop
 /// }
 /// ```
-pub struct PollingFlowConnection<State, Delta, Event, Action> {
-    transport: DefaultTransport,
+pub struct PollingFlowConnection<State, Delta, Event, Action, T = DefaultTransport> {
+    transport: T,
     url: String,
     status: ConnectionStatus,
     reconnect_config: ReconnectConfig,
@@ -107,15 +124,18 @@ pub struct PollingFlowConnection<State, Delta, Event, Action> {
     current_seq: u64,
     // Time tracking for reconnection delays
     reconnect_delay_until: Option<f64>,
+    // AckRequired notifications already handled
+    seen_notifies: SeenNotifies,
     _phantom: std::marker::PhantomData<(State, Delta, Event, Action)>,
 }
 
-impl<State, Delta, Event, Action> PollingFlowConnection<State, Delta, Event, Action>
+impl<State, Delta, Event, Action, T> PollingFlowConnection<State, Delta, Event, Action, T>
 where
     State: DeserializeOwned,
     Delta: DeserializeOwned,
     Event: DeserializeOwned,
     Action: Serialize,
+    T: WebSocketTransport,
 {
     /// Connect to a WebSocket server
     pub fn connect(url: &str) -> Result<Self, FlowError> {
@@ -124,8 +144,7 @@ where
 
     /// Connect with custom reconnection configuration
     pub fn connect_with_config(url: &str, config: ReconnectConfig) -> Result<Self, FlowError> {
-        let transport =
-            DefaultTransport::connect(url).map_err(|e| FlowError::Connection(format!("{e}")))?;
+        let transport = T::connect(url).map_err(|e| FlowError::Connection(format!("{e}")))?;
 
         Ok(Self {
             transport,
@@ -135,6 +154,7 @@ where
             reconnect_attempt: 0,
             current_seq: 0,
             reconnect_delay_until: None,
+            seen_notifies: SeenNotifies::new(),
             _phantom: std::marker::PhantomData,
         })
     }
@@ -169,7 +189,9 @@ where
                 self.reconnect_attempt = 0;
                 Some(FlowEvent::StatusChanged(ConnectionStatus::Connected))
             }
-            WebSocketEvent::Message(bytes) => self.handle_message(&bytes),
+            // Messages with nothing to report (signals, redelivered
+            // notifications) move on to the next event
+            WebSocketEvent::Message(bytes) => self.handle_message(&bytes).or_else(|| self.poll()),
             WebSocketEvent::Close { code, reason } => {
                 let close_info = CloseInfo { code, reason };
                 self.handle_close(close_info)
@@ -221,6 +243,12 @@ where
                 self.current_seq = seq;
                 Some(FlowEvent::Deltas { deltas, seq })
             }
+            ServerMessage::PrivateDelta { delta, seq, .. } => {
+                Some(FlowEvent::PrivateDelta { delta, seq })
+            }
+            ServerMessage::DomainSnapshot {
+                domain, data, seq, ..
+            } => Some(FlowEvent::DomainSnapshot { domain, data, seq }),
             ServerMessage::Presence { users } => Some(FlowEvent::Presence(users)),
             ServerMessage::Signal { .. } => {
                 // WebRTC signalling - not implemented
@@ -230,11 +258,28 @@ where
                 domain,
                 event,
                 correlation_id,
-            } => Some(FlowEvent::Notify {
-                domain,
-                event,
-                correlation_id,
-            }),
+                ack_id,
+            } => {
+                let Some(id) = ack_id else {
+                    return Some(FlowEvent::Notify {
+                        domain,
+                        event,
+                        correlation_id,
+                    });
+                };
+                let msg: ClientMessage<Action> = ClientMessage::notify_ack(id);
+                if let Err(e) = self.send_message(&msg) {
+                    tracing::warn!("Failed to ack notification {}: {}", id, e);
+                }
+                // Redelivered after a lost ack: acked again, not emitted twice
+                self.seen_notifies
+                    .first_delivery(id)
+                    .then_some(FlowEvent::Notify {
+                        domain,
+                        event,
+                        correlation_id,
+                    })
+            }
             ServerMessage::Progress {
                 op_id,
                 percent,
@@ -254,6 +299,14 @@ where
                 code,
                 message,
             }),
+            ServerMessage::Command { command } => {
+                if command == ClientCommand::ForceResync {
+                    if let Err(e) = self.resync(None) {
+                        tracing::warn!("Failed to request resync: {}", e);
+                    }
+                }
+                Some(FlowEvent::Command(command))
+            }
         }
     }
 
@@ -287,8 +340,8 @@ where
     }
 
     fn attempt_reconnect(&mut self) -> Result<(), FlowError> {
-        self.transport = DefaultTransport::connect(&self.url)
-            .map_err(|e| FlowError::Connection(format!("{e}")))?;
+        self.transport =
+            T::connect(&self.url).map_err(|e| FlowError::Connection(format!("{e}")))?;
         self.status = ConnectionStatus::Connecting;
         Ok(())
     }
@@ -332,12 +385,29 @@ where
         self.send_message(&msg)
     }
 
+    /// Request one domain's slice of state (also subscribes to the domain)
+    pub fn request_snapshot(&self, domain: impl Into<String>) -> Result<(), FlowError> {
+        let msg: ClientMessage<Action> = ClientMessage::snapshot_request(domain);
+        self.send_message(&msg)
+    }
+
     /// Send a ping for latency measurement
     pub fn send_ping(&self, timestamp: u64) -> Result<(), FlowError> {
         let msg: ClientMessage<Action> = ClientMessage::ping(timestamp);
         self.send_message(&msg)
     }
 
+    /// Say Goodbye so the server can drop this user's presence, then disconnect
+    pub fn close(&mut self, reason: impl Into<String>) {
+        if self.transport.is_connected() {
+            let msg: ClientMessage<Action> = ClientMessage::goodbye(reason);
+            if let Err(e) = self.send_message(&msg) {
+                tracing::debug!("Failed to send goodbye: {}", e);
+            }
+        }
+        self.disconnect();
+    }
+
     /// Disconnect and clean up
     pub fn disconnect(&mut self) {
         self.transport.close();
@@ -366,8 +436,13 @@ fn calculate_backoff(attempt: u32, config: &ReconnectConfig) -> u32 {
 
 /// Get current time in milliseconds
 ///
-/// Uses different implementations for web-sys vs macroquad
-#[cfg(all(feature = "web-sys-transport", not(feature = "macroquad")))]
+/// Uses different implementations for web-sys vs macroquad, and the system
+/// clock off-browser (e.g. under `cargo test`)
+#[cfg(all(
+    target_arch = "wasm32",
+    feature = "web-sys-transport",
+    not(feature = "macroquad")
+))]
 fn current_time_ms() -> f64 {
     js_sys::Date::now()
 }
@@ -378,7 +453,17 @@ fn current_time_ms() -> f64 {
     miniquad::date::now() * 1000.0
 }
 
-#[cfg(not(any(feature = "web-sys-transport", feature = "macroquad")))]
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "macroquad")))]
+fn current_time_ms() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |d| d.as_millis() as f64)
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    not(any(feature = "web-sys-transport", feature = "macroquad"))
+))]
 fn current_time_ms() -> f64 {
     0.0 // Fallback - reconnection timing won't work
 }
@@ -55,3 +55,176 @@ pub trait FlowState: Clone + Default {
     /// Apply a delta to update the state
     fn apply_delta(&mut self, delta: Self::Delta);
 }
+
+/// What happens to private deltas when a shared snapshot arrives
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrivateMerge {
+    /// Drop them; the server resends whatever private state still applies
+    #[default]
+    Reset,
+    /// Re-apply them on top of the snapshot
+    Retain,
+}
+
+/// Shared state with this user's private deltas layered on top
+///
+/// Shared snapshots and deltas update both the shared base and the merged
+/// view; private deltas only touch the merged view and never advance `seq`.
+/// Render from [`state`](Self::state), resync from [`seq`](Self::seq).
+///
+/// # Example
+///
+/// ```ignore
+/// let game = Rc::new(RefCell::new(SyncedState::<GameState>::new(PrivateMerge::Reset)));
+///
+/// FlowConnection::builder()
+///     .on_snapshot({ let game = game.clone(); move |s, seq| game.borrow_mut().apply_snapshot(s, seq) })
+///     .on_delta({ let game = game.clone(); move |d, seq| game.borrow_mut().apply_delta(d, seq) })
+///     .on_private_delta({ let game = game.clone(); move |d, _| game.borrow_mut().apply_private_delta(d) })
+/// ```
+pub struct SyncedState<S: FlowState> {
+    shared: S,
+    merged: S,
+    private: Vec<S::Delta>,
+    seq: u64,
+    policy: PrivateMerge,
+}
+
+impl<S> SyncedState<S>
+where
+    S: FlowState,
+    S::Delta: Clone,
+{
+    /// Empty state at seq 0
+    pub fn new(policy: PrivateMerge) -> Self {
+        Self {
+            shared: S::default(),
+            merged: S::default(),
+            private: Vec::new(),
+            seq: 0,
+            policy,
+        }
+    }
+
+    /// State including private deltas - what this user sees
+    pub fn state(&self) -> &S {
+        &self.merged
+    }
+
+    /// State every user sees
+    pub fn shared(&self) -> &S {
+        &self.shared
+    }
+
+    /// Last shared sequence number applied
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Replace the shared state, merging private deltas per the policy
+    pub fn apply_snapshot(&mut self, state: S, seq: u64) {
+        self.shared = state;
+        self.seq = seq;
+        if self.policy == PrivateMerge::Reset {
+            self.private.clear();
+        }
+        self.rebuild();
+    }
+
+    /// Apply a shared delta
+    pub fn apply_delta(&mut self, delta: S::Delta, seq: u64) {
+        self.shared.apply_delta(delta.clone());
+        self.merged.apply_delta(delta);
+        self.seq = seq;
+    }
+
+    /// Apply a batch of shared deltas ending at `seq`
+    pub fn apply_deltas(&mut self, deltas: Vec<S::Delta>, seq: u64) {
+        for delta in deltas {
+            self.apply_delta(delta, seq);
+        }
+    }
+
+    /// Apply a delta only this user sees
+    pub fn apply_private_delta(&mut self, delta: S::Delta) {
+        self.merged.apply_delta(delta.clone());
+        self.private.push(delta);
+    }
+
+    /// Drop all private deltas, leaving the shared state
+    pub fn clear_private(&mut self) {
+        self.private.clear();
+        self.rebuild();
+    }
+
+    fn rebuild(&mut self) {
+        self.merged = self.shared.clone();
+        for delta in &self.private {
+            self.merged.apply_delta(delta.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Clone, Default, Debug, PartialEq)]
+    struct Hand {
+        table: Vec<u32>,
+        mine: Vec<u32>,
+    }
+
+    #[derive(Clone, Deserialize)]
+    enum HandDelta {
+        Played(u32),
+        Drew(u32),
+    }
+
+    impl FlowState for Hand {
+        type Delta = HandDelta;
+
+        fn apply_delta(&mut self, delta: HandDelta) {
+            match delta {
+                HandDelta::Played(card) => self.table.push(card),
+                HandDelta::Drew(card) => self.mine.push(card),
+            }
+        }
+    }
+
+    #[test]
+    fn test_private_deltas_stay_out_of_shared_state() {
+        let mut synced = SyncedState::<Hand>::new(PrivateMerge::Reset);
+        synced.apply_delta(HandDelta::Played(1), 1);
+        synced.apply_private_delta(HandDelta::Drew(9));
+        synced.apply_delta(HandDelta::Played(2), 2);
+
+        assert_eq!(synced.seq(), 2);
+        assert_eq!(synced.shared().mine, Vec::<u32>::new());
+        assert_eq!(synced.state().table, vec![1, 2]);
+        assert_eq!(synced.state().mine, vec![9]);
+    }
+
+    #[test]
+    fn test_snapshot_merge_policies() {
+        let snapshot = Hand {
+            table: vec![5],
+            mine: Vec::new(),
+        };
+
+        let mut reset = SyncedState::<Hand>::new(PrivateMerge::Reset);
+        reset.apply_private_delta(HandDelta::Drew(9));
+        reset.apply_snapshot(snapshot.clone(), 10);
+        assert_eq!(reset.state(), &snapshot);
+
+        let mut retain = SyncedState::<Hand>::new(PrivateMerge::Retain);
+        retain.apply_private_delta(HandDelta::Drew(9));
+        retain.apply_snapshot(snapshot, 10);
+        assert_eq!(retain.state().table, vec![5]);
+        assert_eq!(retain.state().mine, vec![9]);
+
+        retain.clear_private();
+        assert_eq!(retain.state(), retain.shared());
+    }
+}
@@ -1,6 +1,7 @@
 //! Connection status types
 
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Current status of the WebSocket connection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -79,6 +80,34 @@ impl CloseInfo {
     pub fn is_normal(&self) -> bool {
         self.code == 1000 || self.code == 1001
     }
+
+    /// Short description of standard close codes
+    pub fn code_description(&self) -> &'static str {
+        match self.code {
+            1000 => "Normal closure",
+            1001 => "Going away",
+            1006 => "Connection lost",
+            1008 => "Policy violation",
+            1011 => "Server error",
+            1012 => "Server restarting",
+            1013 => "Try again later",
+            4001..=4009 => "Authentication failed",
+            _ => "Connection closed",
+        }
+    }
+}
+
+/// Human-readable reason, e.g. `"Connection lost (1006)"`
+///
+/// Uses the server-provided reason when there is one.
+impl fmt::Display for CloseInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.reason.is_empty() {
+            write!(f, "{} ({})", self.code_description(), self.code)
+        } else {
+            write!(f, "{} ({})", self.reason, self.code)
+        }
+    }
 }
 
 impl Default for CloseInfo {
@@ -0,0 +1,224 @@
+//! Tab coordination: one WebSocket per browser, shared by every tab
+//!
+//! Tabs of the same app connected to the same URL elect a leader over a
+//! `BroadcastChannel`. Only the leader opens a socket; it forwards every
+//! server frame to the other tabs and sends their frames for them. The
+//! server sees one connection and one presence entry per user instead of
+//! one per tab.
+//!
+//! ## Election
+//!
+//! Every tab announces itself with [`TabMessage::Hello`] and then sends a
+//! [`TabMessage::Present`] heartbeat every [`HEARTBEAT_MS`]. Tabs that miss
+//! heartbeats for [`TAB_TIMEOUT_MS`], or say [`TabMessage::Leaving`], drop
+//! out. The leader is the longest-running live tab, so a new tab never takes
+//! over from a working leader and every tab reaches the same answer from its
+//! own [`TabRoster`].
+//!
+//! When the leader goes away the followers report a close, reconnect as
+//! usual, and the oldest remaining tab opens the socket.
+//!
+//! ## Goodbye
+//!
+//! A follower closing must not end the user's session for the other tabs,
+//! so Goodbye frames are only sent by the last tab standing.
+//!
+//! Use it through [`SharedTabTransport`](crate::transport::SharedTabTransport):
+//!
+//! ```ignore
+//! use ui_flow::transport::SharedTabTransport;
+//!
+//! let conn = PollingFlowConnection::<State, Delta, Event, Action, SharedTabTransport>::connect(&url)?;
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use ui_flow_protocol::{decode, ClientMessage, ClientTag, RawDelta};
+
+/// How often each tab announces it is alive, in milliseconds
+pub const HEARTBEAT_MS: f64 = 1_000.0;
+
+/// A tab missing heartbeats this long is considered gone, in milliseconds
+pub const TAB_TIMEOUT_MS: f64 = 3_500.0;
+
+/// How long a new tab listens for others before electing, in milliseconds
+pub const ELECTION_MS: f64 = 250.0;
+
+/// Close code reported to followers when the leader tab goes away
+pub const LEADER_GONE_CODE: u16 = 1001;
+
+/// A tab's identity and election rank
+///
+/// Ordered by start time, then by the random id to break ties, so the
+/// smallest `TabId` is the longest-running tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TabId {
+    /// When the tab started, in ms since the epoch
+    pub started: u64,
+    /// Random id, unique per tab
+    pub id: u64,
+}
+
+/// Messages exchanged between tabs on the channel
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "t")]
+pub enum TabMessage {
+    /// A tab joined; everyone answers with `Present`
+    Hello { tab: TabId },
+    /// Heartbeat
+    Present { tab: TabId },
+    /// A tab is closing
+    Leaving { tab: TabId },
+    /// Server frame forwarded by the leader
+    Frame {
+        #[serde(with = "serde_bytes")]
+        bytes: Vec<u8>,
+    },
+    /// Client frame for the leader to send
+    Send {
+        #[serde(with = "serde_bytes")]
+        bytes: Vec<u8>,
+    },
+    /// The leader's socket closed
+    Closed { code: u16, reason: String },
+}
+
+/// Live tabs as seen from one of them
+#[derive(Debug, Clone)]
+pub struct TabRoster {
+    me: TabId,
+    /// Other tabs and when each was last heard from
+    peers: HashMap<TabId, f64>,
+}
+
+impl TabRoster {
+    pub fn new(me: TabId) -> Self {
+        Self {
+            me,
+            peers: HashMap::new(),
+        }
+    }
+
+    /// This tab
+    pub fn me(&self) -> TabId {
+        self.me
+    }
+
+    /// Record a message from `tab`
+    pub fn seen(&mut self, tab: TabId, now: f64) {
+        if tab != self.me {
+            self.peers.insert(tab, now);
+        }
+    }
+
+    /// Forget a tab that said it is leaving
+    pub fn left(&mut self, tab: TabId) {
+        self.peers.remove(&tab);
+    }
+
+    /// Drop tabs not heard from within [`TAB_TIMEOUT_MS`]
+    ///
+    /// Returns whether any were dropped.
+    pub fn expire(&mut self, now: f64) -> bool {
+        let before = self.peers.len();
+        self.peers.retain(|_, seen| now - *seen < TAB_TIMEOUT_MS);
+        self.peers.len() != before
+    }
+
+    /// The elected tab: the longest-running one still alive
+    pub fn leader(&self) -> TabId {
+        self.peers.keys().copied().fold(self.me, TabId::min)
+    }
+
+    pub fn is_leader(&self) -> bool {
+        self.leader() == self.me
+    }
+
+    /// Number of other live tabs
+    pub fn others(&self) -> usize {
+        self.peers.len()
+    }
+}
+
+/// Whether an encoded client frame is a Goodbye
+pub fn is_goodbye(bytes: &[u8]) -> bool {
+    decode::<ClientMessage<RawDelta>>(bytes).is_ok_and(|msg| msg.tag() == ClientTag::Goodbye)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ui_flow_protocol::{encode, OpId};
+
+    fn tab(started: u64, id: u64) -> TabId {
+        TabId { started, id }
+    }
+
+    #[test]
+    fn test_oldest_tab_leads() {
+        let mut roster = TabRoster::new(tab(200, 9));
+        assert!(roster.is_leader());
+
+        // A newer tab doesn't take over
+        roster.seen(tab(300, 1), 0.0);
+        assert!(roster.is_leader());
+
+        // An older one does; same start time breaks on id
+        roster.seen(tab(200, 3), 0.0);
+        assert_eq!(roster.leader(), tab(200, 3));
+        assert_eq!(roster.others(), 2);
+
+        roster.left(tab(200, 3));
+        assert!(roster.is_leader());
+
+        // Our own messages echoed back are ignored
+        roster.seen(tab(200, 9), 0.0);
+        assert_eq!(roster.others(), 1);
+    }
+
+    #[test]
+    fn test_silent_tabs_expire() {
+        let mut roster = TabRoster::new(tab(200, 1));
+        roster.seen(tab(100, 1), 0.0);
+        roster.seen(tab(150, 1), 2_000.0);
+        assert_eq!(roster.leader(), tab(100, 1));
+
+        assert!(!roster.expire(TAB_TIMEOUT_MS - 1.0));
+        assert!(roster.expire(TAB_TIMEOUT_MS));
+        assert_eq!(roster.leader(), tab(150, 1));
+
+        assert!(roster.expire(2_000.0 + TAB_TIMEOUT_MS));
+        assert!(roster.is_leader());
+    }
+
+    #[test]
+    fn test_messages_round_trip() {
+        let messages = [
+            TabMessage::Hello { tab: tab(1, 2) },
+            TabMessage::Frame {
+                bytes: vec![0x81, 0xa1, 0x74],
+            },
+            TabMessage::Closed {
+                code: 1006,
+                reason: String::new(),
+            },
+        ];
+        for message in messages {
+            let bytes = encode(&message).unwrap();
+            assert_eq!(decode::<TabMessage>(&bytes).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn test_is_goodbye() {
+        let goodbye = encode(&ClientMessage::<()>::goodbye("closed")).unwrap();
+        let action = encode(&ClientMessage::action(OpId::new(), "move")).unwrap();
+        let resync = encode(&ClientMessage::<()>::resync(Some(4))).unwrap();
+
+        assert!(is_goodbye(&goodbye));
+        assert!(!is_goodbye(&action));
+        assert!(!is_goodbye(&resync));
+        assert!(!is_goodbye(b"garbage"));
+    }
+}
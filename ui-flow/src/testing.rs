@@ -0,0 +1,912 @@
+//! In-memory transport and test server
+//!
+//! Runs the Flow protocol without a network so `FlowState` implementations
+//! and connection handling can be exercised in plain `cargo test`.
+//!
+//! A [`TestServer`] listens on a URL (any string, e.g. `mem://game`) for the
+//! current thread. [`InMemoryTransport::connect`] with that URL links to it,
+//! and messages are delivered synchronously: anything the client sends is
+//! handled before `send` returns, and the replies are waiting on the next
+//! `poll`. Timestamps in server messages are always 0.
+//!
+//! Enable with the `testing` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! ui-flow = { path = "../ui-flow", features = ["testing"] }
+//! ```
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_flow::testing::{TestClient, TestServer};
+//!
+//! let server = TestServer::<Counter, CounterAction>::listen("mem://counter", Counter::default())
+//!     .on_action(|_state, action| match action {
+//!         CounterAction::Add(n) => Ok(vec![CounterDelta::Added(n)]),
+//!     });
+//!
+//! let mut client = TestClient::<Counter, CounterAction>::connect("mem://counter")?;
+//! client.pump(); // Open, Connected, Snapshot
+//!
+//! client.send_action(CounterAction::Add(2))?;
+//! client.pump(); // Delta, ActionOk
+//! assert_eq!(client.state().value, 2);
+//!
+//! // Drop the connection and watch the client reconnect and resync
+//! server.drop_connections(1006, "gone");
+//! client.pump();
+//! ```
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::polling_connection::{FlowError, FlowEvent, PollingFlowConnection, ReconnectConfig};
+use crate::state::{FlowState, PrivateMerge, SyncedState};
+use crate::transport::{WebSocketEvent, WebSocketTransport};
+use ui_flow_protocol::{
+    decode, encode, ClientCommand, ClientMessage, OpId, ServerMessage, UNKNOWN_DOMAIN,
+};
+
+/// Protocol version reported in `Connected`
+const TEST_PROTOCOL_VERSION: u8 = 1;
+
+thread_local! {
+    /// Test servers listening on this thread, by URL
+    static SERVERS: RefCell<HashMap<String, Rc<dyn Endpoint>>> = RefCell::new(HashMap::new());
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Transport
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// One client connection as seen by both ends
+struct Link {
+    id: String,
+    open: bool,
+    /// Events waiting for the client's next `poll`
+    inbox: VecDeque<WebSocketEvent>,
+    /// Notification domains the client subscribed to
+    domains: HashSet<String>,
+}
+
+type SharedLink = Rc<RefCell<Link>>;
+
+impl Link {
+    fn deliver(&mut self, bytes: Vec<u8>) {
+        if self.open {
+            self.inbox.push_back(WebSocketEvent::Message(bytes));
+        }
+    }
+
+    fn shut(&mut self, code: u16, reason: &str) {
+        if self.open {
+            self.open = false;
+            self.inbox.push_back(WebSocketEvent::Close {
+                code,
+                reason: reason.to_string(),
+            });
+        }
+    }
+}
+
+/// Server side of a link, erased over state and action types
+trait Endpoint {
+    fn accept(&self) -> Result<SharedLink, InMemoryTransportError>;
+    fn receive(&self, link: &SharedLink, bytes: &[u8]);
+    fn release(&self, link: &SharedLink);
+}
+
+/// Errors from the in-memory transport
+#[derive(Debug, thiserror::Error)]
+pub enum InMemoryTransportError {
+    #[error("No test server listening on {0}")]
+    NoServer(String),
+    #[error("Connection refused")]
+    Refused,
+    #[error("Not connected")]
+    NotConnected,
+}
+
+/// Transport connected to a [`TestServer`] on the same thread
+pub struct InMemoryTransport {
+    link: SharedLink,
+    endpoint: Rc<dyn Endpoint>,
+}
+
+impl InMemoryTransport {
+    /// Connection ID assigned by the server
+    pub fn connection_id(&self) -> String {
+        self.link.borrow().id.clone()
+    }
+}
+
+impl WebSocketTransport for InMemoryTransport {
+    type Error = InMemoryTransportError;
+
+    fn connect(url: &str) -> Result<Self, Self::Error> {
+        let endpoint = SERVERS
+            .with(|servers| servers.borrow().get(url).cloned())
+            .ok_or_else(|| InMemoryTransportError::NoServer(url.to_string()))?;
+        let link = endpoint.accept()?;
+        Ok(Self { link, endpoint })
+    }
+
+    fn send(&self, data: &[u8]) -> Result<(), Self::Error> {
+        if !self.link.borrow().open {
+            return Err(InMemoryTransportError::NotConnected);
+        }
+        self.endpoint.receive(&self.link, data);
+        Ok(())
+    }
+
+    fn poll(&mut self) -> Option<WebSocketEvent> {
+        self.link.borrow_mut().inbox.pop_front()
+    }
+
+    fn is_connected(&self) -> bool {
+        self.link.borrow().open
+    }
+
+    fn close(&mut self) {
+        self.link.borrow_mut().open = false;
+        self.endpoint.release(&self.link);
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Server
+// ─────────────────────────────────────────────────────────────────────────────
+
+type ActionHandler<S, Action> =
+    Box<dyn FnMut(&S, Action) -> Result<Vec<<S as FlowState>::Delta>, String>>;
+
+struct ServerInner<S: FlowState, Action> {
+    state: S,
+    seq: u64,
+    links: Vec<SharedLink>,
+    next_connection: u64,
+    refuse: bool,
+    handler: Option<ActionHandler<S, Action>>,
+    actions: Vec<(String, OpId, Action)>,
+    goodbyes: Vec<(String, String)>,
+    /// Encoded domain slices served to `SnapshotRequest`
+    domains: HashMap<String, Vec<u8>>,
+    /// Encoded AckRequired notifications awaiting `NotifyAck`, by ack ID
+    unacked: Vec<(u64, Vec<u8>)>,
+    next_ack_id: u64,
+}
+
+impl<S, Action> ServerInner<S, Action>
+where
+    S: FlowState + Serialize,
+    S::Delta: Serialize,
+{
+    fn snapshot(&self) -> Vec<u8> {
+        encode_server(&ServerMessage::<&S, (), ()>::snapshot(
+            &self.state,
+            self.seq,
+            0,
+        ))
+    }
+
+    fn broadcast(&mut self, bytes: &[u8]) {
+        self.links.retain(|link| link.borrow().open);
+        for link in &self.links {
+            link.borrow_mut().deliver(bytes.to_vec());
+        }
+    }
+
+    /// Apply deltas as one seq step and send them to every client
+    fn commit(&mut self, deltas: Vec<S::Delta>) {
+        if deltas.is_empty() {
+            return;
+        }
+        self.seq += 1;
+        let bytes = if deltas.len() == 1 {
+            encode_server(&ServerMessage::<(), _, ()>::delta(&deltas[0], self.seq, 0))
+        } else {
+            let refs: Vec<&S::Delta> = deltas.iter().collect();
+            encode_server(&ServerMessage::<(), _, ()>::deltas(refs, self.seq, 0))
+        };
+        for delta in deltas {
+            self.state.apply_delta(delta);
+        }
+        self.broadcast(&bytes);
+    }
+}
+
+impl<S, Action> Endpoint for RefCell<ServerInner<S, Action>>
+where
+    S: FlowState + Serialize,
+    S::Delta: Serialize,
+    Action: DeserializeOwned + Clone,
+{
+    fn accept(&self) -> Result<SharedLink, InMemoryTransportError> {
+        let mut inner = self.borrow_mut();
+        if inner.refuse {
+            return Err(InMemoryTransportError::Refused);
+        }
+
+        inner.next_connection += 1;
+        let id = format!("conn-{}", inner.next_connection);
+        let mut link = Link {
+            id: id.clone(),
+            open: true,
+            inbox: VecDeque::from([WebSocketEvent::Open]),
+            domains: HashSet::new(),
+        };
+        link.deliver(encode_server(&ServerMessage::<(), (), ()>::connected(
+            TEST_PROTOCOL_VERSION,
+            id,
+        )));
+        link.deliver(inner.snapshot());
+        for (_, bytes) in &inner.unacked {
+            link.deliver(bytes.clone());
+        }
+
+        let link = Rc::new(RefCell::new(link));
+        inner.links.push(link.clone());
+        Ok(link)
+    }
+
+    fn receive(&self, link: &SharedLink, bytes: &[u8]) {
+        let message = match decode::<ClientMessage<Action>>(bytes) {
+            Ok(message) => message,
+            Err(e) => {
+                let reply =
+                    ServerMessage::<(), (), ()>::error(format!("Invalid message: {e}"), false);
+                link.borrow_mut().deliver(encode_server(&reply));
+                return;
+            }
+        };
+
+        let mut inner = self.borrow_mut();
+        let inner = &mut *inner;
+        match message {
+            ClientMessage::Ping { ts } => {
+                let reply = ServerMessage::<(), (), ()>::pong(ts, 0);
+                link.borrow_mut().deliver(encode_server(&reply));
+            }
+            ClientMessage::Resync { .. } => {
+                let snapshot = inner.snapshot();
+                link.borrow_mut().deliver(snapshot);
+            }
+            ClientMessage::SnapshotRequest { domain } => {
+                let mut link = link.borrow_mut();
+                match inner.domains.get(&domain) {
+                    Some(data) => {
+                        let reply = ServerMessage::<(), (), ()>::domain_snapshot(
+                            domain.clone(),
+                            data.clone(),
+                            inner.seq,
+                            0,
+                        );
+                        link.deliver(encode_server(&reply));
+                        link.domains.insert(domain);
+                    }
+                    None => {
+                        let reply = ServerMessage::<(), (), ()>::error_with_code(
+                            UNKNOWN_DOMAIN,
+                            format!("Unknown domain: {domain}"),
+                            false,
+                        );
+                        link.deliver(encode_server(&reply));
+                    }
+                }
+            }
+            ClientMessage::Goodbye { reason } => {
+                let mut link = link.borrow_mut();
+                link.open = false;
+                inner.goodbyes.push((link.id.clone(), reason));
+            }
+            ClientMessage::Action { op_id, action } => {
+                let connection_id = link.borrow().id.clone();
+                let result = match inner.handler.as_mut() {
+                    Some(handler) => handler(&inner.state, action.clone()),
+                    None => Ok(Vec::new()),
+                };
+                inner.actions.push((connection_id, op_id, action));
+
+                match result {
+                    Ok(deltas) => {
+                        inner.commit(deltas);
+                        let reply = ServerMessage::<(), (), ()>::action_ok(op_id, None);
+                        link.borrow_mut().deliver(encode_server(&reply));
+                    }
+                    Err(message) => {
+                        let reply = ServerMessage::<(), (), ()>::action_err(op_id, message);
+                        link.borrow_mut().deliver(encode_server(&reply));
+                    }
+                }
+            }
+            ClientMessage::Subscribe { domains } => {
+                link.borrow_mut().domains.extend(domains);
+            }
+            ClientMessage::Unsubscribe { domains } => {
+                let mut link = link.borrow_mut();
+                for domain in &domains {
+                    link.domains.remove(domain);
+                }
+            }
+            ClientMessage::Signal { .. } => {}
+            ClientMessage::NotifyAck { id } => {
+                inner.unacked.retain(|(ack_id, _)| *ack_id != id);
+            }
+        }
+    }
+
+    fn release(&self, link: &SharedLink) {
+        self.borrow_mut()
+            .links
+            .retain(|other| !Rc::ptr_eq(other, link));
+    }
+}
+
+fn encode_server<M: Serialize>(message: &M) -> Vec<u8> {
+    encode(message).expect("test server messages encode")
+}
+
+/// In-process Flow server with snapshot/delta/action semantics
+///
+/// Clients get `Connected` and a `Snapshot` on connect. Actions run through
+/// the [`on_action`](Self::on_action) handler: returned deltas are applied
+/// to the server state, advance `seq` by one and are broadcast to every
+/// client before the sender gets `ActionOk`; an error is sent back as
+/// `ActionErr`. `Resync` answers with a fresh snapshot, `SnapshotRequest`
+/// with the slice set by [`set_domain`](Self::set_domain), and `Ping` with
+/// `Pong`. `Goodbye` drops the connection without a `Close` event, as the
+/// client is already closing. AckRequired notifications are resent to every
+/// new connection until a client acks them - all connections count as the
+/// same user.
+///
+/// The server stops listening when dropped.
+pub struct TestServer<S: FlowState, Action> {
+    url: String,
+    inner: Rc<RefCell<ServerInner<S, Action>>>,
+}
+
+impl<S, Action> TestServer<S, Action>
+where
+    S: FlowState + Serialize + 'static,
+    S::Delta: Serialize,
+    Action: DeserializeOwned + Clone + 'static,
+{
+    /// Listen on `url` for this thread, starting from `state` at seq 0
+    ///
+    /// Replaces any server already listening on `url`.
+    pub fn listen(url: impl Into<String>, state: S) -> Self {
+        let url = url.into();
+        let inner = Rc::new(RefCell::new(ServerInner {
+            state,
+            seq: 0,
+            links: Vec::new(),
+            next_connection: 0,
+            refuse: false,
+            handler: None,
+            actions: Vec::new(),
+            goodbyes: Vec::new(),
+            domains: HashMap::new(),
+            unacked: Vec::new(),
+            next_ack_id: 1,
+        }));
+        let endpoint: Rc<dyn Endpoint> = inner.clone();
+        SERVERS.with(|servers| servers.borrow_mut().insert(url.clone(), endpoint));
+        Self { url, inner }
+    }
+
+    /// Handle actions: return the deltas to apply, or an error message
+    ///
+    /// Without a handler, actions are acknowledged with no deltas.
+    pub fn on_action<F>(self, handler: F) -> Self
+    where
+        F: FnMut(&S, Action) -> Result<Vec<S::Delta>, String> + 'static,
+    {
+        self.inner.borrow_mut().handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Serve `slice` for `SnapshotRequest { domain }`
+    ///
+    /// Only affects later requests; send updates with [`notify`](Self::notify).
+    pub fn set_domain<T: Serialize>(&self, domain: impl Into<String>, slice: &T) {
+        self.inner
+            .borrow_mut()
+            .domains
+            .insert(domain.into(), encode_server(slice));
+    }
+
+    /// URL clients connect to
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Current server state
+    pub fn state(&self) -> S {
+        self.inner.borrow().state.clone()
+    }
+
+    /// Current sequence number
+    pub fn seq(&self) -> u64 {
+        self.inner.borrow().seq
+    }
+
+    /// IDs of the open connections, oldest first
+    pub fn connection_ids(&self) -> Vec<String> {
+        let mut inner = self.inner.borrow_mut();
+        inner.links.retain(|link| link.borrow().open);
+        inner
+            .links
+            .iter()
+            .map(|link| link.borrow().id.clone())
+            .collect()
+    }
+
+    /// Apply a delta and broadcast it, as if another client acted
+    pub fn broadcast(&self, delta: S::Delta) {
+        self.inner.borrow_mut().commit(vec![delta]);
+    }
+
+    /// Apply several deltas as one seq step and broadcast them as a batch
+    pub fn broadcast_batch(&self, deltas: Vec<S::Delta>) {
+        self.inner.borrow_mut().commit(deltas);
+    }
+
+    /// Send a delta to one connection only; the shared state is unchanged
+    pub fn send_private(&self, connection_id: &str, delta: &S::Delta) {
+        let inner = self.inner.borrow();
+        let bytes = encode_server(&ServerMessage::<(), _, ()>::private_delta(
+            delta, inner.seq, 0,
+        ));
+        for link in &inner.links {
+            let mut link = link.borrow_mut();
+            if link.id == connection_id {
+                link.deliver(bytes.clone());
+            }
+        }
+    }
+
+    /// Send a notification to connections subscribed to `domain`
+    pub fn notify<E: Serialize>(&self, domain: &str, event: &E) {
+        let bytes = encode_server(&ServerMessage::<(), (), _>::notify(domain, event, None));
+        for link in &self.inner.borrow().links {
+            let mut link = link.borrow_mut();
+            if link.domains.contains(domain) {
+                link.deliver(bytes.clone());
+            }
+        }
+    }
+
+    /// Send an AckRequired notification to every connection, returning its
+    /// ack ID
+    ///
+    /// It is redelivered on each new connection until a client acks it.
+    pub fn notify_ack_required<E: Serialize>(&self, domain: &str, event: &E) -> u64 {
+        let mut inner = self.inner.borrow_mut();
+        let id = inner.next_ack_id;
+        inner.next_ack_id += 1;
+        let bytes = encode_server(&ServerMessage::<(), (), _>::notify_ack_required(
+            domain, event, None, id,
+        ));
+        inner.broadcast(&bytes);
+        inner.unacked.push((id, bytes));
+        id
+    }
+
+    /// Ack IDs of notifications no client has acked yet
+    pub fn unacked_notifies(&self) -> Vec<u64> {
+        self.inner
+            .borrow()
+            .unacked
+            .iter()
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Send an operator command to every connection
+    pub fn send_command(&self, command: ClientCommand) {
+        let bytes = encode_server(&ServerMessage::<(), (), ()>::command(command));
+        self.inner.borrow_mut().broadcast(&bytes);
+    }
+
+    /// Close every connection with `code` (clients see a `Close` event)
+    pub fn drop_connections(&self, code: u16, reason: &str) {
+        let links = std::mem::take(&mut self.inner.borrow_mut().links);
+        for link in links {
+            link.borrow_mut().shut(code, reason);
+        }
+    }
+
+    /// Refuse new connections (reconnect attempts fail) until reset
+    pub fn set_refuse_connections(&self, refuse: bool) {
+        self.inner.borrow_mut().refuse = refuse;
+    }
+
+    /// Actions received so far as `(connection_id, op_id, action)`
+    pub fn actions(&self) -> Vec<(String, OpId, Action)> {
+        self.inner.borrow().actions.clone()
+    }
+
+    /// Goodbyes received so far as `(connection_id, reason)`
+    pub fn goodbyes(&self) -> Vec<(String, String)> {
+        self.inner.borrow().goodbyes.clone()
+    }
+}
+
+impl<S: FlowState, Action> Drop for TestServer<S, Action> {
+    fn drop(&mut self) {
+        // Only unregister ourselves - `listen` may have replaced us
+        let _ = SERVERS.try_with(|servers| {
+            let mut servers = servers.borrow_mut();
+            let ours = servers.get(&self.url).is_some_and(|endpoint| {
+                std::ptr::eq(
+                    Rc::as_ptr(endpoint) as *const (),
+                    Rc::as_ptr(&self.inner) as *const (),
+                )
+            });
+            if ours {
+                servers.remove(&self.url);
+            }
+        });
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Client
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Events from a test client's connection
+pub type TestEvent<S, Event> = FlowEvent<S, <S as FlowState>::Delta, Event>;
+
+/// Polling connection over [`InMemoryTransport`] that keeps a synced state
+///
+/// [`pump`](Self::pump) drains the connection and applies snapshots, deltas
+/// and private deltas to a [`SyncedState`], so tests can assert on the state
+/// a real client would render.
+pub struct TestClient<S: FlowState, Action, Event = ()> {
+    connection: PollingFlowConnection<S, S::Delta, Event, Action, InMemoryTransport>,
+    state: SyncedState<S>,
+}
+
+impl<S, Action, Event> TestClient<S, Action, Event>
+where
+    S: FlowState + DeserializeOwned,
+    S::Delta: Clone,
+    Event: DeserializeOwned,
+    Action: Serialize,
+{
+    /// Connect to a test server, reconnecting without delay
+    pub fn connect(url: &str) -> Result<Self, FlowError> {
+        Self::connect_with_config(
+            url,
+            ReconnectConfig {
+                base_delay_ms: 0,
+                max_delay_ms: 0,
+                max_attempts: Some(3),
+            },
+        )
+    }
+
+    /// Connect with custom reconnection configuration
+    pub fn connect_with_config(url: &str, config: ReconnectConfig) -> Result<Self, FlowError> {
+        Ok(Self {
+            connection: PollingFlowConnection::connect_with_config(url, config)?,
+            state: SyncedState::new(PrivateMerge::Reset),
+        })
+    }
+
+    /// Process every pending event, applying state updates
+    pub fn pump(&mut self) -> Vec<TestEvent<S, Event>> {
+        let mut events = Vec::new();
+        while let Some(event) = self.connection.poll() {
+            match &event {
+                FlowEvent::Snapshot { state, seq } => {
+                    self.state.apply_snapshot(state.clone(), *seq);
+                }
+                FlowEvent::Delta { delta, seq } => {
+                    self.state.apply_delta(delta.clone(), *seq);
+                }
+                FlowEvent::Deltas { deltas, seq } => {
+                    self.state.apply_deltas(deltas.clone(), *seq);
+                }
+                FlowEvent::PrivateDelta { delta, .. } => {
+                    self.state.apply_private_delta(delta.clone());
+                }
+                _ => {}
+            }
+            events.push(event);
+        }
+        events
+    }
+
+    /// Request one domain's slice; it arrives as `FlowEvent::DomainSnapshot`
+    pub fn request_snapshot(&self, domain: &str) -> Result<(), FlowError> {
+        self.connection.request_snapshot(domain)
+    }
+
+    /// Send an action, returning its operation ID
+    pub fn send_action(&self, action: Action) -> Result<OpId, FlowError> {
+        let op_id = OpId::new();
+        self.connection.send_action(op_id, action)?;
+        Ok(op_id)
+    }
+
+    /// State including private deltas
+    pub fn state(&self) -> &S {
+        self.state.state()
+    }
+
+    /// Synced state with shared and private layers
+    pub fn synced(&self) -> &SyncedState<S> {
+        &self.state
+    }
+
+    /// The underlying connection
+    pub fn connection(
+        &self,
+    ) -> &PollingFlowConnection<S, S::Delta, Event, Action, InMemoryTransport> {
+        &self.connection
+    }
+
+    /// The underlying connection, mutably
+    pub fn connection_mut(
+        &mut self,
+    ) -> &mut PollingFlowConnection<S, S::Delta, Event, Action, InMemoryTransport> {
+        &mut self.connection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::status::ConnectionStatus;
+    use serde::Deserialize;
+    use ui_flow_protocol::GOODBYE_CLOSED;
+
+    #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+    struct Counter {
+        value: i64,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    enum CounterDelta {
+        Added(i64),
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    enum CounterAction {
+        Add(i64),
+    }
+
+    impl FlowState for Counter {
+        type Delta = CounterDelta;
+
+        fn apply_delta(&mut self, delta: CounterDelta) {
+            match delta {
+                CounterDelta::Added(n) => self.value += n,
+            }
+        }
+    }
+
+    fn counter_server(url: &str) -> TestServer<Counter, CounterAction> {
+        TestServer::listen(url, Counter::default()).on_action(|state, action| match action {
+            CounterAction::Add(n) if state.value + n < 0 => Err("Counter can't go negative".into()),
+            CounterAction::Add(n) => Ok(vec![CounterDelta::Added(n)]),
+        })
+    }
+
+    #[test]
+    fn test_connect_receives_snapshot() {
+        let server = counter_server("mem://snapshot");
+        server.broadcast(CounterDelta::Added(5));
+
+        let mut client = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        let events = client.pump();
+
+        assert!(matches!(
+            events[0],
+            FlowEvent::StatusChanged(ConnectionStatus::Connected)
+        ));
+        assert!(matches!(&events[1], FlowEvent::Connected(id) if id == "conn-1"));
+        assert!(matches!(events[2], FlowEvent::Snapshot { seq: 1, .. }));
+        assert_eq!(client.state().value, 5);
+        assert_eq!(client.synced().seq(), 1);
+    }
+
+    #[test]
+    fn test_actions_broadcast_deltas() {
+        let server = counter_server("mem://actions");
+        let mut alice = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        let mut bob = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        alice.pump();
+        bob.pump();
+
+        let op_id = alice.send_action(CounterAction::Add(3)).unwrap();
+        let events = alice.pump();
+        assert!(matches!(events[0], FlowEvent::Delta { seq: 1, .. }));
+        assert!(matches!(events[1], FlowEvent::ActionOk(id) if id == op_id));
+
+        bob.pump();
+        assert_eq!(alice.state().value, 3);
+        assert_eq!(bob.state().value, 3);
+        assert_eq!(server.state().value, 3);
+        assert_eq!(server.actions()[0].2, CounterAction::Add(3));
+    }
+
+    #[test]
+    fn test_rejected_action_leaves_state() {
+        let server = counter_server("mem://rejected");
+        let mut client = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        client.pump();
+
+        client.send_action(CounterAction::Add(-1)).unwrap();
+        let events = client.pump();
+        assert!(
+            matches!(&events[0], FlowEvent::ActionErr { message, .. } if message.contains("negative"))
+        );
+        assert_eq!(server.seq(), 0);
+        assert_eq!(client.state().value, 0);
+    }
+
+    #[test]
+    fn test_private_delta_only_reaches_target() {
+        let server = counter_server("mem://private");
+        let mut alice = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        let mut bob = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        alice.pump();
+        bob.pump();
+
+        server.send_private("conn-1", &CounterDelta::Added(10));
+        alice.pump();
+        bob.pump();
+
+        assert_eq!(alice.state().value, 10);
+        assert_eq!(alice.synced().shared().value, 0);
+        assert_eq!(bob.state().value, 0);
+    }
+
+    #[test]
+    fn test_reconnect_resyncs_state() {
+        let server = counter_server("mem://reconnect");
+        let mut client = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        client.pump();
+
+        server.drop_connections(1006, "gone");
+        server.broadcast(CounterDelta::Added(7));
+        let events = client.pump();
+
+        assert!(events.iter().any(|e| matches!(
+            e,
+            FlowEvent::StatusChanged(ConnectionStatus::Reconnecting { attempt: 1 })
+        )));
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, FlowEvent::Snapshot { seq: 1, .. })));
+        assert_eq!(client.state().value, 7);
+        assert_eq!(server.connection_ids(), vec!["conn-2".to_string()]);
+    }
+
+    #[test]
+    fn test_refused_reconnect_reports_error() {
+        let server = counter_server("mem://refused");
+        let mut client = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        client.pump();
+
+        server.set_refuse_connections(true);
+        server.drop_connections(1006, "gone");
+        let events = client.pump();
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, FlowEvent::Error { fatal: false, .. })));
+        assert!(!client.connection().is_connected());
+    }
+
+    #[test]
+    fn test_domain_snapshot_request() {
+        let server = counter_server("mem://domains");
+        server.set_domain("leaderboard", &vec![("alice".to_string(), 3u32)]);
+        let mut client = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        client.pump();
+
+        client.request_snapshot("leaderboard").unwrap();
+        let events = client.pump();
+        let FlowEvent::DomainSnapshot { domain, data, seq } = &events[0] else {
+            panic!("Expected DomainSnapshot, got {:?}", events[0]);
+        };
+        assert_eq!(domain, "leaderboard");
+        assert_eq!(*seq, 0);
+        let board: Vec<(String, u32)> = decode(data).unwrap();
+        assert_eq!(board, vec![("alice".to_string(), 3)]);
+
+        // Requesting subscribed us to the domain's notifications
+        server.notify("leaderboard", &());
+        assert!(matches!(
+            &client.pump()[0],
+            FlowEvent::Notify { domain, .. } if domain == "leaderboard"
+        ));
+
+        client.request_snapshot("chat").unwrap();
+        assert!(matches!(
+            client.pump()[0],
+            FlowEvent::Error { fatal: false, .. }
+        ));
+    }
+
+    #[test]
+    fn test_ack_required_notify_is_acked() {
+        let server = counter_server("mem://ack");
+        let mut client = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        client.pump();
+
+        server.notify_ack_required("rewards", &());
+        let events = client.pump();
+        assert!(matches!(&events[0], FlowEvent::Notify { domain, .. } if domain == "rewards"));
+        assert!(server.unacked_notifies().is_empty());
+    }
+
+    #[test]
+    fn test_unacked_notify_redelivered_on_reconnect() {
+        let server = counter_server("mem://redeliver");
+        let mut client = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        client.pump();
+
+        // Sent while the client is away
+        server.drop_connections(1006, "gone");
+        let id = server.notify_ack_required("rewards", &());
+        assert_eq!(server.unacked_notifies(), vec![id]);
+
+        let events = client.pump();
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, FlowEvent::Notify { domain, .. } if domain == "rewards")));
+        assert!(server.unacked_notifies().is_empty());
+    }
+
+    #[test]
+    fn test_redelivered_notify_not_emitted_twice() {
+        let server = counter_server("mem://dedupe");
+        let mut client = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        client.pump();
+
+        // The client handles the notify but its ack dies with the socket
+        server.notify_ack_required("rewards", &());
+        server.drop_connections(1006, "gone");
+        let events = client.pump();
+        let notifies = events
+            .iter()
+            .filter(|e| matches!(e, FlowEvent::Notify { .. }))
+            .count();
+        assert_eq!(notifies, 1);
+        assert!(server.unacked_notifies().is_empty());
+    }
+
+    #[test]
+    fn test_close_says_goodbye() {
+        let server = counter_server("mem://goodbye");
+        let mut client = TestClient::<Counter, CounterAction>::connect(server.url()).unwrap();
+        client.pump();
+
+        client.connection_mut().close(GOODBYE_CLOSED);
+        assert_eq!(
+            server.goodbyes(),
+            vec![("conn-1".to_string(), "closed".to_string())]
+        );
+        assert!(server.connection_ids().is_empty());
+
+        // Nothing to reconnect after an intentional close
+        assert!(client.pump().is_empty());
+        assert_eq!(client.connection().status(), ConnectionStatus::Disconnected);
+    }
+
+    #[test]
+    fn test_no_server() {
+        assert!(matches!(
+            InMemoryTransport::connect("mem://nobody"),
+            Err(InMemoryTransportError::NoServer(_))
+        ));
+    }
+}
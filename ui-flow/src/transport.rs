@@ -58,6 +58,12 @@ mod web_sys_transport;
 #[cfg(all(feature = "web-sys-transport", not(feature = "macroquad")))]
 pub use web_sys_transport::WebSysTransport;
 
+#[cfg(all(feature = "web-sys-transport", not(feature = "macroquad")))]
+mod shared_tab_transport;
+
+#[cfg(all(feature = "web-sys-transport", not(feature = "macroquad")))]
+pub use shared_tab_transport::SharedTabTransport;
+
 #[cfg(feature = "macroquad")]
 mod quad_net_transport;
 
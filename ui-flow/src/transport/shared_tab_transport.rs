@@ -0,0 +1,314 @@
+//! Transport that shares one WebSocket between tabs
+//!
+//! See [`crate::tabs`] for the election and forwarding rules.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{BroadcastChannel, MessageEvent};
+
+use super::web_sys_transport::{WebSysTransport, WebSysTransportError};
+use super::{WebSocketEvent, WebSocketTransport};
+use crate::tabs::{
+    is_goodbye, TabId, TabMessage, TabRoster, ELECTION_MS, HEARTBEAT_MS, LEADER_GONE_CODE,
+};
+use ui_flow_protocol::{decode, encode, ClientMessage};
+
+thread_local! {
+    /// This page's identity, shared by every transport it opens so a
+    /// reconnecting leader keeps its rank
+    static THIS_TAB: TabId = TabId {
+        started: js_sys::Date::now() as u64,
+        id: (js_sys::Math::random() * 9_007_199_254_740_992.0) as u64,
+    };
+}
+
+enum Role {
+    /// Listening for other tabs before deciding
+    Electing { until: f64 },
+    /// This tab owns the socket
+    Leader(WebSysTransport),
+    /// Another tab owns the socket
+    Follower { leader: TabId },
+    /// Closed, or the leader went away; the connection will reconnect
+    Closed,
+}
+
+/// [`WebSocketTransport`] that elects one tab to hold the socket
+///
+/// Drop-in for [`WebSysTransport`] with
+/// [`PollingFlowConnection`](crate::PollingFlowConnection). Tabs connecting
+/// to the same URL share a socket; a follower's first `Open` is followed by
+/// a resync request so it receives a snapshot.
+pub struct SharedTabTransport {
+    channel: BroadcastChannel,
+    roster: TabRoster,
+    role: Role,
+    /// Channel messages not yet handled
+    inbox: Rc<RefCell<VecDeque<TabMessage>>>,
+    /// Events to return from `poll` ahead of the socket's
+    events: VecDeque<WebSocketEvent>,
+    last_heartbeat: f64,
+    _onmessage: Closure<dyn FnMut(JsValue)>,
+    pagehide: Option<Closure<dyn FnMut(JsValue)>>,
+}
+
+impl SharedTabTransport {
+    /// Whether this tab currently holds the socket
+    pub fn is_leader(&self) -> bool {
+        matches!(self.role, Role::Leader(_))
+    }
+
+    /// Number of other tabs sharing the connection
+    pub fn other_tabs(&self) -> usize {
+        self.roster.others()
+    }
+
+    fn post(&self, message: &TabMessage) {
+        post(&self.channel, message);
+    }
+
+    fn handle(&mut self, message: TabMessage, now: f64) {
+        match message {
+            TabMessage::Hello { tab } => {
+                self.roster.seen(tab, now);
+                self.post(&TabMessage::Present {
+                    tab: self.roster.me(),
+                });
+            }
+            TabMessage::Present { tab } => self.roster.seen(tab, now),
+            TabMessage::Leaving { tab } => self.roster.left(tab),
+            TabMessage::Frame { bytes } => {
+                if matches!(self.role, Role::Follower { .. }) {
+                    self.events.push_back(WebSocketEvent::Message(bytes));
+                }
+            }
+            TabMessage::Send { bytes } => {
+                if let Role::Leader(socket) = &self.role {
+                    if let Err(e) = socket.send(&bytes) {
+                        tracing::debug!("Dropped frame from another tab: {e}");
+                    }
+                }
+            }
+            TabMessage::Closed { code, reason } => {
+                if matches!(self.role, Role::Follower { .. }) {
+                    self.role = Role::Closed;
+                    self.events
+                        .push_back(WebSocketEvent::Close { code, reason });
+                }
+            }
+        }
+    }
+
+    /// Heartbeat, expire silent tabs, and act on election changes
+    fn tick(&mut self, now: f64) {
+        if now - self.last_heartbeat >= HEARTBEAT_MS {
+            self.last_heartbeat = now;
+            self.post(&TabMessage::Present {
+                tab: self.roster.me(),
+            });
+        }
+        self.roster.expire(now);
+
+        match &mut self.role {
+            Role::Electing { until } if now >= *until => self.elect(),
+            Role::Follower { leader } if *leader != self.roster.leader() => {
+                tracing::info!("Leader tab went away; reconnecting");
+                self.role = Role::Closed;
+                self.events.push_back(WebSocketEvent::Close {
+                    code: LEADER_GONE_CODE,
+                    reason: "leader tab closed".into(),
+                });
+            }
+            Role::Leader(socket) if !self.roster.is_leader() => {
+                // Two tabs elected at once; the older one keeps the socket
+                tracing::info!("An older tab leads; handing over the connection");
+                socket.close();
+                self.role = Role::Closed;
+                self.events.push_back(WebSocketEvent::Close {
+                    code: LEADER_GONE_CODE,
+                    reason: "another tab leads".into(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn elect(&mut self) {
+        if self.roster.is_leader() {
+            let url = self.channel.name();
+            let url = url.strip_prefix(CHANNEL_PREFIX).unwrap_or(&url);
+            match WebSysTransport::connect(url) {
+                Ok(socket) => self.role = Role::Leader(socket),
+                Err(e) => {
+                    self.role = Role::Closed;
+                    self.events.push_back(WebSocketEvent::Error(e.to_string()));
+                    self.events.push_back(WebSocketEvent::Close {
+                        code: 1006,
+                        reason: e.to_string(),
+                    });
+                }
+            }
+            return;
+        }
+
+        let leader = self.roster.leader();
+        tracing::debug!("Sharing the connection of tab {leader:?}");
+        self.role = Role::Follower { leader };
+        self.events.push_back(WebSocketEvent::Open);
+        // The leader's snapshot went out before we joined; ask for another
+        if let Ok(bytes) = encode(&ClientMessage::<()>::resync(None)) {
+            self.post(&TabMessage::Send { bytes });
+        }
+    }
+}
+
+/// Channel names are the socket URL behind this prefix
+const CHANNEL_PREFIX: &str = "ui-flow:";
+
+impl WebSocketTransport for SharedTabTransport {
+    type Error = WebSysTransportError;
+
+    fn connect(url: &str) -> Result<Self, Self::Error> {
+        let channel = BroadcastChannel::new(&format!("{CHANNEL_PREFIX}{url}"))
+            .map_err(|e| WebSysTransportError::Creation(format!("{e:?}")))?;
+        let me = THIS_TAB.with(|tab| *tab);
+        let now = js_sys::Date::now();
+
+        let inbox: Rc<RefCell<VecDeque<TabMessage>>> = Rc::default();
+        let onmessage = {
+            let inbox = inbox.clone();
+            Closure::wrap(Box::new(move |event: JsValue| {
+                let event: MessageEvent = event.unchecked_into();
+                let bytes = js_sys::Uint8Array::new(&event.data()).to_vec();
+                match decode::<TabMessage>(&bytes) {
+                    Ok(message) => inbox.borrow_mut().push_back(message),
+                    Err(e) => tracing::warn!("Ignoring malformed tab message: {e}"),
+                }
+            }) as Box<dyn FnMut(JsValue)>)
+        };
+        channel.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+
+        // Let the other tabs know at once instead of after the timeout
+        let pagehide = web_sys::window().and_then(|window| {
+            let channel = channel.clone();
+            let listener = Closure::wrap(Box::new(move |_: JsValue| {
+                post(&channel, &TabMessage::Leaving { tab: me });
+            }) as Box<dyn FnMut(JsValue)>);
+            window
+                .add_event_listener_with_callback("pagehide", listener.as_ref().unchecked_ref())
+                .ok()
+                .map(|_| listener)
+        });
+
+        post(&channel, &TabMessage::Hello { tab: me });
+
+        Ok(Self {
+            channel,
+            roster: TabRoster::new(me),
+            role: Role::Electing {
+                until: now + ELECTION_MS,
+            },
+            inbox,
+            events: VecDeque::new(),
+            last_heartbeat: now,
+            _onmessage: onmessage,
+            pagehide,
+        })
+    }
+
+    fn send(&self, data: &[u8]) -> Result<(), Self::Error> {
+        match &self.role {
+            Role::Leader(socket) => {
+                if self.roster.others() > 0 && is_goodbye(data) {
+                    // The other tabs are still here; don't end their session
+                    return Ok(());
+                }
+                socket.send(data)
+            }
+            Role::Follower { .. } => {
+                if !is_goodbye(data) {
+                    self.post(&TabMessage::Send {
+                        bytes: data.to_vec(),
+                    });
+                }
+                Ok(())
+            }
+            Role::Electing { .. } | Role::Closed => Err(WebSysTransportError::NotConnected),
+        }
+    }
+
+    fn poll(&mut self) -> Option<WebSocketEvent> {
+        let now = js_sys::Date::now();
+        let messages: Vec<_> = self.inbox.borrow_mut().drain(..).collect();
+        for message in messages {
+            self.handle(message, now);
+        }
+        self.tick(now);
+
+        if let Some(event) = self.events.pop_front() {
+            return Some(event);
+        }
+
+        let Role::Leader(socket) = &mut self.role else {
+            return None;
+        };
+        let event = socket.poll()?;
+        match &event {
+            WebSocketEvent::Message(bytes) => self.post(&TabMessage::Frame {
+                bytes: bytes.clone(),
+            }),
+            WebSocketEvent::Close { code, reason } => self.post(&TabMessage::Closed {
+                code: *code,
+                reason: reason.clone(),
+            }),
+            WebSocketEvent::Open | WebSocketEvent::Error(_) => {}
+        }
+        Some(event)
+    }
+
+    fn is_connected(&self) -> bool {
+        match &self.role {
+            Role::Leader(socket) => socket.is_connected(),
+            Role::Follower { .. } => true,
+            Role::Electing { .. } | Role::Closed => false,
+        }
+    }
+
+    fn close(&mut self) {
+        self.post(&TabMessage::Leaving {
+            tab: self.roster.me(),
+        });
+        if let Role::Leader(socket) = &mut self.role {
+            socket.close();
+        }
+        self.role = Role::Closed;
+    }
+}
+
+impl Drop for SharedTabTransport {
+    fn drop(&mut self) {
+        if !matches!(self.role, Role::Closed) {
+            self.close();
+        }
+        if let (Some(window), Some(listener)) = (web_sys::window(), self.pagehide.take()) {
+            let _ = window
+                .remove_event_listener_with_callback("pagehide", listener.as_ref().unchecked_ref());
+        }
+        self.channel.set_onmessage(None);
+        self.channel.close();
+    }
+}
+
+fn post(channel: &BroadcastChannel, message: &TabMessage) {
+    let Ok(bytes) = encode(message) else {
+        return;
+    };
+    let array = js_sys::Uint8Array::from(bytes.as_slice());
+    if let Err(e) = channel.post_message(&array) {
+        tracing::debug!("Failed to post tab message: {e:?}");
+    }
+}
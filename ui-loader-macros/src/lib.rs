@@ -180,7 +180,8 @@ pub fn leptos_main(input: TokenStream) -> TokenStream {
 /// Static widget entry point for read-only widgets
 ///
 /// For widgets that load data once and render without interactivity.
-/// Uses maud for HTML templating and skips reactive framework overhead.
+/// Uses maud for HTML templating and skips reactive framework overhead,
+/// apart from any Leptos islands declared for interactive parts.
 ///
 /// See `static_widget` module documentation for usage examples.
 #[proc_macro]
@@ -31,11 +31,47 @@
 //! The `render` function receives the loaded data and auth state,
 //! and returns a `maud::Markup` that gets set as innerHTML of the
 //! mount point.
+//!
+//! ## Islands
+//!
+//! Pages that need a little interactivity can declare islands: Leptos
+//! components mounted into marked nodes after the static HTML is in place.
+//! Mark nodes with `ui_loader::island(name, fallback)` in `render` and map
+//! each name to a component:
+//!
+//! ```ignore
+//! widget_loader::static_widget! {
+//!     config: LoaderConfig::new(),
+//!     load: |auth, _loader| async move { Ok(fetch_sales(&auth).await?) },
+//!
+//!     render: |data: &Sales, _auth: &AuthState| {
+//!         maud::html! {
+//!             h1 { "Sales" }
+//!             (ui_loader::island("chart", maud::html! { p { "Loading chart..." } }))
+//!         }
+//!     },
+//!
+//!     islands: {
+//!         "chart" => SalesChart,
+//!     },
+//! }
+//!
+//! #[component]
+//! pub fn SalesChart(result: Rc<LoadResult<Sales>>) -> impl IntoView {
+//!     // Same data the static page was rendered from
+//! }
+//! ```
+//!
+//! Every island component receives the one `Rc<LoadResult<T>>` the page was
+//! rendered from. Each marked node gets its own instance, replacing the
+//! fallback content. A widget with islands needs `leptos` as a dependency;
+//! without them nothing Leptos-related is generated.
 
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::parse::{Parse, ParseStream};
-use syn::{Expr, Ident, Token};
+use syn::punctuated::Punctuated;
+use syn::{braced, Expr, Ident, LitStr, Token};
 
 /// Input structure for static_widget! macro
 pub struct StaticWidgetInput {
@@ -44,6 +80,36 @@ pub struct StaticWidgetInput {
     pub render: Expr,
     /// Optional mount point (defaults to "app")
     pub mount: Option<Expr>,
+    /// Leptos components to mount into marked nodes
+    pub islands: Vec<Island>,
+}
+
+/// One `"name" => Component` entry of the `islands` field
+pub struct Island {
+    pub name: LitStr,
+    pub component: Expr,
+}
+
+impl Parse for Island {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: LitStr = input.parse()?;
+        let value = name.value();
+        let valid = !value.is_empty()
+            && value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(syn::Error::new(
+                name.span(),
+                "island names may only contain letters, digits, `-` and `_`",
+            ));
+        }
+        input.parse::<Token![=>]>()?;
+        Ok(Island {
+            name,
+            component: input.parse()?,
+        })
+    }
 }
 
 impl Parse for StaticWidgetInput {
@@ -52,6 +118,7 @@ impl Parse for StaticWidgetInput {
         let mut load: Option<Expr> = None;
         let mut render: Option<Expr> = None;
         let mut mount: Option<Expr> = None;
+        let mut islands = Vec::new();
 
         while !input.is_empty() {
             let key: Ident = input.parse()?;
@@ -70,11 +137,18 @@ impl Parse for StaticWidgetInput {
                 "mount" => {
                     mount = Some(input.parse()?);
                 }
+                "islands" => {
+                    let content;
+                    braced!(content in input);
+                    islands = Punctuated::<Island, Token![,]>::parse_terminated(&content)?
+                        .into_iter()
+                        .collect();
+                }
                 other => {
                     return Err(syn::Error::new(
                         key.span(),
                         format!(
-                            "unknown field `{other}`, expected `config`, `load`, `render`, or `mount`"
+                            "unknown field `{other}`, expected `config`, `load`, `render`, `mount`, or `islands`"
                         ),
                     ));
                 }
@@ -91,6 +165,7 @@ impl Parse for StaticWidgetInput {
             load: load.ok_or_else(|| input.error("missing `load` field"))?,
             render: render.ok_or_else(|| input.error("missing `render` field"))?,
             mount,
+            islands,
         })
     }
 }
@@ -105,6 +180,8 @@ pub fn generate(input: StaticWidgetInput) -> TokenStream {
         .as_ref()
         .map(|m| quote! { #m })
         .unwrap_or_else(|| quote! { "app" });
+    let island_names = input.islands.iter().map(|i| &i.name);
+    let island_components = input.islands.iter().map(|i| &i.component);
 
     let expanded = quote! {
         #[wasm_bindgen::prelude::wasm_bindgen(start)]
@@ -127,8 +204,19 @@ pub fn generate(input: StaticWidgetInput) -> TokenStream {
                         .expect("mount point not found");
 
                     // Render the static HTML
+                    let loaded = std::rc::Rc::new(loaded);
                     let html = render_fn(&loaded.data, &loaded.auth);
                     mount.set_inner_html(&html.into_string());
+
+                    // Mount islands into their marked nodes (forget handles to keep mounted)
+                    #(
+                        for node in ui_loader::take_island_nodes(&mount, #island_names) {
+                            let result = loaded.clone();
+                            leptos::mount::mount_to(node, move || {
+                                leptos::view! { <#island_components result=result /> }
+                            }).forget();
+                        }
+                    )*
                 }
                 Err(_) => {
                     // Error screen already shown by orchestrator
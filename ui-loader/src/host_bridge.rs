@@ -0,0 +1,601 @@
+//! Typed postMessage bridge between an embedded widget and its host page
+//!
+//! Widgets running in an iframe talk to the embedding site through
+//! `window.parent.postMessage`. [`HostBridge`] wraps that in a small typed
+//! protocol so every widget sends the same messages and every host can
+//! handle them the same way.
+//!
+//! ## Schema
+//!
+//! Every message is a JSON object wrapped in an envelope:
+//!
+//! ```json
+//! { "protocol": "defrag-widget", "version": 1, "message": { "type": "resize", "height": 480 } }
+//! ```
+//!
+//! Widget to host ([`WidgetMessage`]):
+//!
+//! - `{"type": "ready"}` - the widget has loaded
+//! - `{"type": "resize", "height": 480, "width": null}` - content size changed
+//! - `{"type": "navigate", "url": "...", "new_tab": false}` - open a URL in the host
+//! - `{"type": "auth_refresh"}` - the token is about to expire, please send a new one
+//! - `{"type": "close"}` - the user dismissed the widget
+//!
+//! Host to widget ([`HostMessage`]):
+//!
+//! - `{"type": "auth_token", "token": "..."}` - a refreshed token
+//! - `{"type": "auth_failed", "reason": "..."}` - the token could not be refreshed
+//!
+//! ## Origin validation
+//!
+//! Incoming messages are only accepted from the parent window and from an
+//! allowed origin. Allowed origins are exact (`https://defrag.au`) or a
+//! subdomain wildcard (`https://*.defrag.au`). Outgoing messages are posted to
+//! the host's concrete origin (taken from `document.referrer`, or the single
+//! exact allowed origin), never to `"*"`.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use ui_loader::{HostBridge, HostMessage};
+//!
+//! let bridge = HostBridge::new(["https://*.defrag.au"]);
+//! bridge.ready()?;
+//!
+//! let _listener = bridge.on_message(|msg| match msg {
+//!     HostMessage::AuthToken { token } => auth.set_token(token),
+//!     HostMessage::AuthFailed { reason } => show_error(&reason),
+//! });
+//!
+//! bridge.request_resize(document_height)?;
+//! ```
+//!
+//! ## Auto-resize
+//!
+//! Opt in to reporting the content height whenever it changes, so the host
+//! can size the iframe without scrollbars or empty space:
+//!
+//! ```ignore
+//! let _resize = bridge.auto_resize(AutoResizeConfig::new().min_height(200).max_height(1200))?;
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+use ui_core::error::WidgetError;
+use ui_core::timing::{debounced, Debounced};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Protocol name carried in every envelope
+pub const BRIDGE_PROTOCOL: &str = "defrag-widget";
+
+/// Current protocol version
+pub const BRIDGE_VERSION: u32 = 1;
+
+/// Default quiet period before a height change is reported, in milliseconds
+pub const DEFAULT_RESIZE_DEBOUNCE_MS: u32 = 100;
+
+/// Options for [`HostBridge::auto_resize`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoResizeConfig {
+    /// Wait this long after the last change before reporting (default: 100)
+    pub debounce_ms: u32,
+    /// Smallest height reported (default: 0)
+    pub min_height: u32,
+    /// Largest height reported; taller content scrolls inside the iframe
+    pub max_height: Option<u32>,
+}
+
+impl Default for AutoResizeConfig {
+    fn default() -> Self {
+        Self {
+            debounce_ms: DEFAULT_RESIZE_DEBOUNCE_MS,
+            min_height: 0,
+            max_height: None,
+        }
+    }
+}
+
+impl AutoResizeConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn debounce_ms(mut self, ms: u32) -> Self {
+        self.debounce_ms = ms;
+        self
+    }
+
+    pub fn min_height(mut self, height: u32) -> Self {
+        self.min_height = height;
+        self
+    }
+
+    pub fn max_height(mut self, height: u32) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Clamp a measured height to the configured bounds
+    ///
+    /// `min_height` wins if the bounds are inverted.
+    pub fn clamp(&self, height: u32) -> u32 {
+        let height = self.max_height.map_or(height, |max| height.min(max));
+        height.max(self.min_height)
+    }
+}
+
+/// Message sent from the widget to the host page
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WidgetMessage {
+    /// The widget has finished loading
+    Ready,
+    /// The widget's content size changed, in CSS pixels
+    Resize { height: u32, width: Option<u32> },
+    /// Ask the host to open a URL
+    Navigate { url: String, new_tab: bool },
+    /// Ask the host for a fresh auth token
+    AuthRefresh,
+    /// The user dismissed the widget
+    Close,
+}
+
+/// Message sent from the host page to the widget
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HostMessage {
+    /// A refreshed auth token
+    AuthToken { token: String },
+    /// The host could not refresh the token
+    AuthFailed { reason: String },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope<M> {
+    protocol: String,
+    version: u32,
+    message: M,
+}
+
+/// Encode a widget message into its JSON envelope
+pub fn encode_message(message: &WidgetMessage) -> Result<String, WidgetError> {
+    serde_json::to_string(&Envelope {
+        protocol: BRIDGE_PROTOCOL.to_string(),
+        version: BRIDGE_VERSION,
+        message,
+    })
+    .map_err(|e| WidgetError::Parse(format!("Failed to encode bridge message: {e}")))
+}
+
+/// Decode a host message from its JSON envelope
+///
+/// Returns `Ok(None)` for messages that belong to some other protocol, which
+/// are common on pages running several embeds.
+pub fn decode_host_message(json: &str) -> Result<Option<HostMessage>, WidgetError> {
+    #[derive(Deserialize)]
+    struct Header {
+        protocol: Option<String>,
+    }
+
+    let header: Header = match serde_json::from_str(json) {
+        Ok(header) => header,
+        Err(_) => return Ok(None),
+    };
+    if header.protocol.as_deref() != Some(BRIDGE_PROTOCOL) {
+        return Ok(None);
+    }
+
+    let envelope: Envelope<HostMessage> = serde_json::from_str(json)
+        .map_err(|e| WidgetError::Parse(format!("Invalid bridge message: {e}")))?;
+    if envelope.version != BRIDGE_VERSION {
+        return Err(WidgetError::Parse(format!(
+            "Unsupported bridge version {}",
+            envelope.version
+        )));
+    }
+    Ok(Some(envelope.message))
+}
+
+/// Whether `origin` matches one of the `allowed` patterns
+///
+/// Patterns are exact origins or `scheme://*.domain` subdomain wildcards.
+pub fn origin_allowed(origin: &str, allowed: &[String]) -> bool {
+    allowed
+        .iter()
+        .any(|pattern| match pattern.split_once("://*.") {
+            Some((scheme, domain)) => origin
+                .strip_prefix(scheme)
+                .and_then(|rest| rest.strip_prefix("://"))
+                .and_then(|host| host.strip_suffix(domain))
+                .is_some_and(|sub| sub.ends_with('.') && sub.len() > 1),
+            None => pattern == origin,
+        })
+}
+
+/// The `scheme://host[:port]` part of a URL
+fn origin_of(url: &str) -> Option<&str> {
+    let scheme_end = url.find("://")? + 3;
+    let host_end = url[scheme_end..]
+        .find(['/', '?', '#'])
+        .map_or(url.len(), |i| scheme_end + i);
+    (host_end > scheme_end).then(|| &url[..host_end])
+}
+
+struct Inner {
+    allowed: Vec<String>,
+    /// Concrete origin outgoing messages are posted to
+    target_origin: RefCell<Option<String>>,
+}
+
+/// Typed postMessage channel to the page embedding this widget
+///
+/// Cloning shares the same configuration.
+#[derive(Clone)]
+pub struct HostBridge {
+    inner: Rc<Inner>,
+}
+
+impl HostBridge {
+    /// Create a bridge accepting messages from `allowed_origins`
+    pub fn new<I, S>(allowed_origins: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let allowed: Vec<String> = allowed_origins.into_iter().map(Into::into).collect();
+        let target_origin = initial_target_origin(&allowed);
+        if target_origin.is_none() && is_embedded() {
+            tracing::warn!("HostBridge: host origin is not in the allowed list");
+        }
+        Self {
+            inner: Rc::new(Inner {
+                allowed,
+                target_origin: RefCell::new(target_origin),
+            }),
+        }
+    }
+
+    /// Whether the widget is running inside an iframe
+    pub fn is_embedded(&self) -> bool {
+        is_embedded()
+    }
+
+    /// Post a message to the host
+    ///
+    /// A no-op when the widget isn't embedded, so widgets can call this
+    /// unconditionally.
+    pub fn send(&self, message: WidgetMessage) -> Result<(), WidgetError> {
+        let Some(parent) = parent_window() else {
+            return Ok(());
+        };
+        let target = self.inner.target_origin.borrow().clone().ok_or_else(|| {
+            WidgetError::Other("HostBridge: host origin is not allowed".to_string())
+        })?;
+
+        let json = encode_message(&message)?;
+        let value = js_sys::JSON::parse(&json)
+            .map_err(|e| WidgetError::Parse(format!("Failed to encode bridge message: {e:?}")))?;
+        parent
+            .post_message(&value, &target)
+            .map_err(|e| WidgetError::Other(format!("postMessage failed: {e:?}")))
+    }
+
+    /// Tell the host the widget has loaded
+    pub fn ready(&self) -> Result<(), WidgetError> {
+        self.send(WidgetMessage::Ready)
+    }
+
+    /// Ask the host to resize the iframe to `height` pixels
+    pub fn request_resize(&self, height: u32) -> Result<(), WidgetError> {
+        self.send(WidgetMessage::Resize {
+            height,
+            width: None,
+        })
+    }
+
+    /// Ask the host to resize the iframe to fit the document
+    pub fn resize_to_content(&self) -> Result<(), WidgetError> {
+        self.request_resize(content_height())
+    }
+
+    /// Report the content height to the host whenever it changes
+    ///
+    /// Changes are debounced and clamped per `config`, and unchanged heights
+    /// aren't re-sent. Reporting stops when the returned [`AutoResize`] is
+    /// dropped.
+    pub fn auto_resize(&self, config: AutoResizeConfig) -> Result<AutoResize, WidgetError> {
+        let body = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.body())
+            .ok_or_else(|| WidgetError::Other("No document body to observe".to_string()))?;
+
+        let bridge = self.clone();
+        let debounce_ms = config.debounce_ms;
+        let last_height = Rc::new(RefCell::new(None::<u32>));
+        let report: Rc<dyn Fn()> = Rc::new(move || {
+            let height = config.clamp(content_height());
+            if last_height.replace(Some(height)) == Some(height) {
+                return;
+            }
+            if let Err(e) = bridge.request_resize(height) {
+                tracing::debug!("HostBridge: resize not sent: {e}");
+            }
+        });
+
+        let pending = {
+            let report = report.clone();
+            debounced(move |()| report(), debounce_ms)
+        };
+        let callback = {
+            let pending = pending.clone();
+            Closure::<dyn FnMut()>::new(move || pending.call(()))
+        };
+
+        let observer = web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref())
+            .map_err(|e| WidgetError::Other(format!("ResizeObserver unavailable: {e:?}")))?;
+        observer.observe(&body);
+        report();
+
+        Ok(AutoResize {
+            observer,
+            _callback: callback,
+            _pending: pending,
+        })
+    }
+
+    /// Ask the host to open `url`
+    pub fn navigate(&self, url: impl Into<String>, new_tab: bool) -> Result<(), WidgetError> {
+        self.send(WidgetMessage::Navigate {
+            url: url.into(),
+            new_tab,
+        })
+    }
+
+    /// Ask the host for a fresh auth token
+    pub fn request_auth_refresh(&self) -> Result<(), WidgetError> {
+        self.send(WidgetMessage::AuthRefresh)
+    }
+
+    /// Tell the host the user dismissed the widget
+    pub fn close(&self) -> Result<(), WidgetError> {
+        self.send(WidgetMessage::Close)
+    }
+
+    /// Call `handler` for every valid message from the host
+    ///
+    /// Keep the returned [`HostListener`] alive for as long as messages
+    /// should be handled.
+    pub fn on_message<F>(&self, handler: F) -> HostListener
+    where
+        F: Fn(HostMessage) + 'static,
+    {
+        let inner = self.inner.clone();
+        let closure = Closure::<dyn FnMut(web_sys::MessageEvent)>::new(
+            move |event: web_sys::MessageEvent| {
+                if !is_from_parent(&event) {
+                    return;
+                }
+                let origin = event.origin();
+                if !origin_allowed(&origin, &inner.allowed) {
+                    tracing::debug!("HostBridge: ignoring message from {origin}");
+                    return;
+                }
+                let Some(json) = js_sys::JSON::stringify(&event.data())
+                    .ok()
+                    .and_then(|s| s.as_string())
+                else {
+                    return;
+                };
+                match decode_host_message(&json) {
+                    Ok(Some(message)) => {
+                        // A verified host message pins down where replies go
+                        inner.target_origin.replace(Some(origin));
+                        handler(message);
+                    }
+                    Ok(None) => {}
+                    Err(e) => tracing::warn!("HostBridge: {e}"),
+                }
+            },
+        );
+
+        if let Some(window) = web_sys::window() {
+            let _ = window
+                .add_event_listener_with_callback("message", closure.as_ref().unchecked_ref());
+        }
+        HostListener { closure }
+    }
+}
+
+/// Handle for a [`HostBridge::on_message`] handler; removes it when dropped
+#[must_use = "the listener is removed when dropped"]
+pub struct HostListener {
+    closure: Closure<dyn FnMut(web_sys::MessageEvent)>,
+}
+
+impl Drop for HostListener {
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            let _ = window.remove_event_listener_with_callback(
+                "message",
+                self.closure.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
+/// Handle for [`HostBridge::auto_resize`]; stops observing when dropped
+#[must_use = "auto-resize stops when dropped"]
+pub struct AutoResize {
+    observer: web_sys::ResizeObserver,
+    _callback: Closure<dyn FnMut()>,
+    _pending: Debounced<()>,
+}
+
+impl Drop for AutoResize {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// Leptos signal of the latest host message
+///
+/// The listener lives as long as the calling component.
+///
+/// ```ignore
+/// let bridge = HostBridge::new(["https://*.defrag.au"]);
+/// let host_message = use_host_bridge(&bridge);
+///
+/// Effect::new(move || {
+///     if let Some(HostMessage::AuthToken { token }) = host_message.get() {
+///         auth.set_token(token);
+///     }
+/// });
+/// ```
+#[cfg(feature = "leptos")]
+pub fn use_host_bridge(bridge: &HostBridge) -> leptos::prelude::ReadSignal<Option<HostMessage>> {
+    use leptos::prelude::{Set, StoredValue};
+
+    let (message, set_message) = leptos::prelude::signal(None);
+    let listener = bridge.on_message(move |m| set_message.set(Some(m)));
+    // Disposed (and the listener removed) with the owning component
+    StoredValue::new_local(listener);
+    message
+}
+
+fn parent_window() -> Option<web_sys::Window> {
+    let window = web_sys::window()?;
+    let parent = window.parent().ok().flatten()?;
+    (JsValue::from(&parent) != JsValue::from(&window)).then_some(parent)
+}
+
+/// Height of the rendered content, independent of the iframe's current size
+fn content_height() -> u32 {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.body())
+        .map(|body| {
+            // The root element's scrollHeight never drops below the viewport,
+            // so measure the body to let the iframe shrink as well as grow
+            body.get_bounding_client_rect().height().ceil().max(0.0) as u32
+        })
+        .unwrap_or(0)
+}
+
+fn is_embedded() -> bool {
+    parent_window().is_some()
+}
+
+fn is_from_parent(event: &web_sys::MessageEvent) -> bool {
+    match (event.source(), parent_window()) {
+        (Some(source), Some(parent)) => JsValue::from(source) == JsValue::from(parent),
+        _ => false,
+    }
+}
+
+/// The host origin from the referrer, or the single exact allowed origin
+fn initial_target_origin(allowed: &[String]) -> Option<String> {
+    let referrer = web_sys::window()
+        .and_then(|w| w.document())
+        .map(|d| d.referrer())
+        .unwrap_or_default();
+    if let Some(origin) = origin_of(&referrer).filter(|o| origin_allowed(o, allowed)) {
+        return Some(origin.to_string());
+    }
+    match allowed {
+        [only] if !only.contains('*') => Some(only.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allowed(patterns: &[&str]) -> Vec<String> {
+        patterns.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn test_encode_message_envelope() {
+        let json = encode_message(&WidgetMessage::Resize {
+            height: 480,
+            width: None,
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            r#"{"protocol":"defrag-widget","version":1,"message":{"type":"resize","height":480,"width":null}}"#
+        );
+    }
+
+    #[test]
+    fn test_decode_host_message() {
+        let json = r#"{"protocol":"defrag-widget","version":1,"message":{"type":"auth_token","token":"abc"}}"#;
+        assert_eq!(
+            decode_host_message(json).unwrap(),
+            Some(HostMessage::AuthToken {
+                token: "abc".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_ignores_other_protocols() {
+        assert_eq!(
+            decode_host_message(r#"{"type":"webpackOk"}"#).unwrap(),
+            None
+        );
+        assert_eq!(decode_host_message(r#""hello""#).unwrap(), None);
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_messages() {
+        let unknown = r#"{"protocol":"defrag-widget","version":1,"message":{"type":"explode"}}"#;
+        assert!(decode_host_message(unknown).is_err());
+
+        let future = r#"{"protocol":"defrag-widget","version":2,"message":{"type":"auth_failed","reason":"x"}}"#;
+        assert!(decode_host_message(future).is_err());
+    }
+
+    #[test]
+    fn test_origin_allowed() {
+        let list = allowed(&["https://defrag.au", "https://*.example.com"]);
+        assert!(origin_allowed("https://defrag.au", &list));
+        assert!(origin_allowed("https://app.example.com", &list));
+        assert!(origin_allowed("https://a.b.example.com", &list));
+
+        assert!(!origin_allowed("http://defrag.au", &list));
+        assert!(!origin_allowed("https://example.com", &list));
+        assert!(!origin_allowed("https://evilexample.com", &list));
+        assert!(!origin_allowed("https://defrag.au.evil.com", &list));
+    }
+
+    #[test]
+    fn test_auto_resize_clamp() {
+        let config = AutoResizeConfig::new().min_height(200).max_height(800);
+        assert_eq!(config.clamp(50), 200);
+        assert_eq!(config.clamp(480), 480);
+        assert_eq!(config.clamp(2000), 800);
+        assert_eq!(AutoResizeConfig::new().clamp(5000), 5000);
+
+        let inverted = AutoResizeConfig::new().min_height(500).max_height(300);
+        assert_eq!(inverted.clamp(400), 500);
+    }
+
+    #[test]
+    fn test_origin_of() {
+        assert_eq!(
+            origin_of("https://defrag.au/play?x=1"),
+            Some("https://defrag.au")
+        );
+        assert_eq!(
+            origin_of("http://localhost:8080"),
+            Some("http://localhost:8080")
+        );
+        assert_eq!(origin_of(""), None);
+        assert_eq!(origin_of("https:///path"), None);
+    }
+}
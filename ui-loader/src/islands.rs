@@ -0,0 +1,77 @@
+//! Interactive islands in static widgets
+//!
+//! A `static_widget!` page is plain maud markup, but it can mark nodes as
+//! islands that a Leptos component is mounted into once the page has been
+//! rendered. Everything outside the islands stays static.
+//!
+//! ```ignore
+//! maud::html! {
+//!     h1 { "Sales" }
+//!     // Fallback content shows until the component replaces it
+//!     (ui_loader::island("chart", maud::html! { p { "Loading chart..." } }))
+//! }
+//! ```
+//!
+//! The `islands` field of `static_widget!` declares which component goes
+//! into which island; the generated code finds the nodes with
+//! [`take_island_nodes`].
+
+use maud::{html, Markup};
+use wasm_bindgen::JsCast;
+
+/// Attribute naming the island a node belongs to
+pub const ISLAND_ATTR: &str = "data-island";
+
+/// Island mount node with fallback content
+///
+/// Several nodes can share a name; each gets its own component instance.
+pub fn island(name: &str, fallback: Markup) -> Markup {
+    html! {
+        div data-island=(name) { (fallback) }
+    }
+}
+
+/// CSS selector matching every node of island `name`
+fn island_selector(name: &str) -> String {
+    format!("[{ISLAND_ATTR}=\"{name}\"]")
+}
+
+/// Find the nodes of island `name` under `root` and clear their fallback
+/// content, ready for mounting
+pub fn take_island_nodes(root: &web_sys::Element, name: &str) -> Vec<web_sys::HtmlElement> {
+    let Ok(nodes) = root.query_selector_all(&island_selector(name)) else {
+        tracing::warn!("Invalid island name: {name}");
+        return Vec::new();
+    };
+
+    let nodes: Vec<_> = (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect();
+    if nodes.is_empty() {
+        tracing::warn!("No nodes found for island `{name}`");
+    }
+    for node in &nodes {
+        node.set_inner_html("");
+    }
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_island_markup() {
+        let markup = island("chart", html! { p { "Loading..." } });
+        assert_eq!(
+            markup.into_string(),
+            r#"<div data-island="chart"><p>Loading...</p></div>"#
+        );
+    }
+
+    #[test]
+    fn test_island_selector() {
+        assert_eq!(island_selector("chart"), r#"[data-island="chart"]"#);
+    }
+}
@@ -32,6 +32,19 @@
 //! 4. Fetch initial data (with progress updates)
 //! 5. Handle errors (show error screen)
 //! 6. Hand off loaded data to the framework
+//!
+//! ## Host Bridge (web feature only)
+//!
+//! [`HostBridge`] is a typed postMessage channel between an embedded widget
+//! and the page hosting its iframe (resize, navigation, auth refresh, close).
+//! [`HostBridge::auto_resize`] opts in to reporting the content height.
+//! Enable the `leptos` feature for the `use_host_bridge` signal hook.
+//!
+//! ## Islands (web feature only)
+//!
+//! Static widgets can mark nodes with [`island`] for Leptos components to be
+//! mounted into after the page renders, so a mostly static page only pays
+//! for interactivity where it needs it.
 
 mod identity;
 
@@ -41,8 +54,23 @@ pub use identity::{Identity, IDENTITY_STORAGE_KEY};
 // Web feature - LoadingOrchestrator and related types
 // ============================================================================
 
+#[cfg(feature = "web")]
+mod host_bridge;
+#[cfg(feature = "web")]
+mod islands;
 #[cfg(feature = "web")]
 mod web;
 
+#[cfg(feature = "leptos")]
+pub use host_bridge::use_host_bridge;
+#[cfg(feature = "web")]
+pub use host_bridge::{
+    decode_host_message, encode_message, origin_allowed, AutoResize, AutoResizeConfig, HostBridge,
+    HostListener, HostMessage, WidgetMessage, BRIDGE_PROTOCOL, BRIDGE_VERSION,
+    DEFAULT_RESIZE_DEBOUNCE_MS,
+};
+#[cfg(feature = "web")]
+pub use islands::{island, take_island_nodes, ISLAND_ATTR};
+
 #[cfg(feature = "web")]
 pub use web::*;
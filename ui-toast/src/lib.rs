@@ -8,6 +8,8 @@
 //!
 //! - [`Toast`] - A single toast notification
 //! - [`ToastKind`] - The type/severity of the toast (Success, Warning, Error, Info)
+//! - [`ToastPosition`] - Screen corner or edge the toast container sits at
+//! - [`ToastStack`] - Which way new toasts push existing ones
 //! - [`ToastMsg`] - Messages for toast state management
 //! - [`HasToasts`] - Trait for types that contain toast state
 //!
@@ -32,6 +34,19 @@
 //! // Add a toast
 //! model.add_toast("Success!".to_string(), ToastKind::Success);
 //! ```
+//!
+//! ## Placement
+//!
+//! Containers default to the top-right corner. Renderers read a
+//! [`ToastPosition`] and [`ToastStack`] from their own configuration (props,
+//! attributes) and group toasts by [`Toast::position`], which overrides the
+//! container position for a single toast:
+//!
+//! ```ignore
+//! let position: ToastPosition = "bottom-center".parse()?;
+//! let toast = Toast::new(id, "Saved".into(), ToastKind::Success)
+//!     .at(ToastPosition::TopLeft);
+//! ```
 
 use std::collections::VecDeque;
 
@@ -46,6 +61,8 @@ pub struct Toast {
     pub kind: ToastKind,
     /// Custom icon override (if None, uses default for kind)
     pub icon: Option<String>,
+    /// Position override (if None, uses the container's position)
+    pub position: Option<ToastPosition>,
     /// Timestamp when the toast was created
     pub created_at: f64,
 }
@@ -59,6 +76,99 @@ pub enum ToastKind {
     Info,
 }
 
+/// Where on screen a toast container sits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ToastPosition {
+    #[default]
+    TopRight,
+    TopLeft,
+    TopCenter,
+    BottomRight,
+    BottomLeft,
+    BottomCenter,
+}
+
+impl ToastPosition {
+    /// All positions
+    pub const ALL: [ToastPosition; 6] = [
+        ToastPosition::TopRight,
+        ToastPosition::TopLeft,
+        ToastPosition::TopCenter,
+        ToastPosition::BottomRight,
+        ToastPosition::BottomLeft,
+        ToastPosition::BottomCenter,
+    ];
+
+    /// Kebab-case name, used for attribute values and CSS modifiers
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ToastPosition::TopRight => "top-right",
+            ToastPosition::TopLeft => "top-left",
+            ToastPosition::TopCenter => "top-center",
+            ToastPosition::BottomRight => "bottom-right",
+            ToastPosition::BottomLeft => "bottom-left",
+            ToastPosition::BottomCenter => "bottom-center",
+        }
+    }
+
+    /// Whether the container is anchored to the bottom edge
+    pub fn is_bottom(&self) -> bool {
+        matches!(
+            self,
+            ToastPosition::BottomRight | ToastPosition::BottomLeft | ToastPosition::BottomCenter
+        )
+    }
+}
+
+impl std::fmt::Display for ToastPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ToastPosition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.as_str() == s.trim())
+            .ok_or_else(|| format!("unknown toast position: {s}"))
+    }
+}
+
+/// Which way new toasts push the ones already showing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ToastStack {
+    /// Newest toast at the bottom of the stack
+    #[default]
+    Down,
+    /// Newest toast at the top of the stack
+    Up,
+}
+
+impl ToastStack {
+    /// Kebab-case name, used for attribute values and CSS modifiers
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ToastStack::Down => "down",
+            ToastStack::Up => "up",
+        }
+    }
+}
+
+impl std::str::FromStr for ToastStack {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "down" => Ok(ToastStack::Down),
+            "up" => Ok(ToastStack::Up),
+            _ => Err(format!("unknown toast stack direction: {s}")),
+        }
+    }
+}
+
 /// Toast message variants for state management
 #[derive(Debug, Clone)]
 pub enum ToastMsg {
@@ -83,6 +193,7 @@ impl Toast {
             message,
             kind,
             icon: None,
+            position: None,
             created_at: js_sys::Date::now(),
         }
     }
@@ -94,10 +205,17 @@ impl Toast {
             message,
             kind,
             icon: Some(icon),
+            position: None,
             created_at: js_sys::Date::now(),
         }
     }
 
+    /// Show this toast at `position` instead of the container's position
+    pub fn at(mut self, position: ToastPosition) -> Self {
+        self.position = Some(position);
+        self
+    }
+
     /// Check if this toast has expired
     pub fn is_expired(&self) -> bool {
         js_sys::Date::now() - self.created_at > Self::DURATION_MS as f64
@@ -198,3 +316,23 @@ pub fn info(message: impl Into<String>) -> ToastMsg {
 pub fn warning(message: impl Into<String>) -> ToastMsg {
     ToastMsg::Show(message.into(), ToastKind::Warning)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_round_trip() {
+        for position in ToastPosition::ALL {
+            assert_eq!(position.as_str().parse(), Ok(position));
+        }
+        assert!("middle".parse::<ToastPosition>().is_err());
+    }
+
+    #[test]
+    fn stack_parses() {
+        assert_eq!(" up ".parse(), Ok(ToastStack::Up));
+        assert_eq!(ToastStack::default(), ToastStack::Down);
+        assert!("sideways".parse::<ToastStack>().is_err());
+    }
+}
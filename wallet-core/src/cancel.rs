@@ -0,0 +1,107 @@
+//! Timeouts and cancellation for wallet calls
+//!
+//! Wallet extensions can leave a call pending forever (e.g. Eternl's enable
+//! popup being dismissed without rejecting), so every CIP-30 call on
+//! [`WalletApi`](crate::WalletApi) is raced against a timer, and any call can
+//! be wrapped with [`cancellable`] to abort it from the UI.
+
+#[cfg(not(feature = "native"))]
+use futures::future::{select, Either};
+use futures::future::{AbortHandle, Abortable};
+#[cfg(not(feature = "native"))]
+use gloo_timers::future::TimeoutFuture;
+use std::future::Future;
+#[cfg(not(feature = "native"))]
+use std::pin::pin;
+
+use crate::WalletError;
+
+/// Default timeout for wallet calls, in milliseconds
+///
+/// Generous enough for a user to read and approve a signing prompt.
+pub const DEFAULT_TIMEOUT_MS: u32 = 60_000;
+
+/// Handle for aborting a call started with [`cancellable`]
+///
+/// Cancelling resolves the wrapped future with [`WalletError::Cancelled`].
+/// The wallet's own prompt may stay open; its eventual result is ignored.
+#[derive(Debug, Clone)]
+pub struct CancelHandle(AbortHandle);
+
+impl CancelHandle {
+    /// Abort the call
+    pub fn cancel(&self) {
+        self.0.abort();
+    }
+
+    /// Whether `cancel` has been called
+    pub fn is_cancelled(&self) -> bool {
+        self.0.is_aborted()
+    }
+}
+
+/// Wrap a wallet call so it can be aborted
+///
+/// ```ignore
+/// let (connecting, handle) = cancellable(WalletApi::connect(provider));
+/// cancel_button.on_click(move || handle.cancel());
+/// match connecting.await {
+///     Err(WalletError::Cancelled) => { /* user gave up */ }
+///     result => { /* ... */ }
+/// }
+/// ```
+pub fn cancellable<T, F>(call: F) -> (impl Future<Output = Result<T, WalletError>>, CancelHandle)
+where
+    F: Future<Output = Result<T, WalletError>>,
+{
+    let (handle, registration) = AbortHandle::new_pair();
+    let call = Abortable::new(call, registration);
+    let wrapped = async move { call.await.unwrap_or(Err(WalletError::Cancelled)) };
+    (wrapped, CancelHandle(handle))
+}
+
+/// Race a CIP-30 call against `timeout_ms`
+///
+/// `operation` names the CIP-30 method for the [`WalletError::Timeout`]
+/// message. `None` waits indefinitely.
+#[cfg(not(feature = "native"))]
+pub(crate) async fn with_timeout<T, E, F>(
+    call: F,
+    timeout_ms: Option<u32>,
+    operation: &str,
+) -> Result<T, WalletError>
+where
+    F: Future<Output = Result<T, E>>,
+    E: Into<WalletError>,
+{
+    let Some(ms) = timeout_ms else {
+        return call.await.map_err(Into::into);
+    };
+
+    match select(pin!(call), pin!(TimeoutFuture::new(ms))).await {
+        Either::Left((result, _)) => result.map_err(Into::into),
+        Either::Right(_) => Err(WalletError::Timeout(operation.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use futures::future::pending;
+
+    #[test]
+    fn test_cancelled_call_resolves_with_cancelled() {
+        let (call, handle) = cancellable(pending::<Result<(), WalletError>>());
+        assert!(!handle.is_cancelled());
+        handle.cancel();
+        assert!(handle.is_cancelled());
+        assert!(matches!(block_on(call), Err(WalletError::Cancelled)));
+    }
+
+    #[test]
+    fn test_uncancelled_call_passes_result_through() {
+        let (call, _handle) = cancellable(async { Ok::<_, WalletError>(42) });
+        assert_eq!(block_on(call).unwrap(), 42);
+    }
+}
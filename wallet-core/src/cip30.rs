@@ -4,6 +4,7 @@
 
 use wasm_bindgen::prelude::*;
 
+use crate::cancel::{with_timeout, DEFAULT_TIMEOUT_MS};
 use crate::types::{DataSignature, WalletInfo, WalletProvider};
 use crate::WalletError;
 
@@ -190,17 +191,46 @@ pub fn get_wallet_info(provider: WalletProvider) -> Option<WalletInfo> {
 }
 
 /// Connected wallet API handle
+///
+/// Every call fails with [`WalletError::Timeout`] if the wallet doesn't answer
+/// within the handle's timeout ([`DEFAULT_TIMEOUT_MS`] unless configured).
 #[derive(Clone)]
 pub struct WalletApi {
     provider: WalletProvider,
     api: JsValue,
+    timeout_ms: Option<u32>,
 }
 
 impl WalletApi {
     /// Enable a wallet and get the API handle
     pub async fn connect(provider: WalletProvider) -> Result<Self, WalletError> {
-        let api = enable_wallet_js(provider.api_name()).await?;
-        Ok(Self { provider, api })
+        Self::connect_with_timeout(provider, Some(DEFAULT_TIMEOUT_MS)).await
+    }
+
+    /// Enable a wallet with a custom timeout (`None` waits indefinitely)
+    ///
+    /// The timeout covers the enable prompt and is kept for later calls.
+    pub async fn connect_with_timeout(
+        provider: WalletProvider,
+        timeout_ms: Option<u32>,
+    ) -> Result<Self, WalletError> {
+        let api = with_timeout(enable_wallet_js(provider.api_name()), timeout_ms, "enable").await?;
+        Ok(Self {
+            provider,
+            api,
+            timeout_ms,
+        })
+    }
+
+    /// Use a different timeout for subsequent calls (`None` waits indefinitely)
+    pub fn with_timeout(mut self, timeout_ms: Option<u32>) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Timeout applied to each call, in milliseconds
+    pub fn timeout_ms(&self) -> Option<u32> {
+        self.timeout_ms
     }
 
     /// Get the wallet provider
@@ -210,7 +240,12 @@ impl WalletApi {
 
     /// Get the network ID (0 = testnet, 1 = mainnet)
     pub async fn network_id(&self) -> Result<u8, WalletError> {
-        let result = get_network_id_js(&self.api).await?;
+        let result = with_timeout(
+            get_network_id_js(&self.api),
+            self.timeout_ms,
+            "getNetworkId",
+        )
+        .await?;
         result
             .as_f64()
             .map(|n| n as u8)
@@ -219,14 +254,24 @@ impl WalletApi {
 
     /// Get used addresses (hex-encoded)
     pub async fn used_addresses(&self) -> Result<Vec<String>, WalletError> {
-        let result = get_used_addresses_js(&self.api).await?;
+        let result = with_timeout(
+            get_used_addresses_js(&self.api),
+            self.timeout_ms,
+            "getUsedAddresses",
+        )
+        .await?;
         let array = js_sys::Array::from(&result);
         Ok(array.iter().filter_map(|v| v.as_string()).collect())
     }
 
     /// Get change address (hex-encoded)
     pub async fn change_address(&self) -> Result<String, WalletError> {
-        let result = get_change_address_js(&self.api).await?;
+        let result = with_timeout(
+            get_change_address_js(&self.api),
+            self.timeout_ms,
+            "getChangeAddress",
+        )
+        .await?;
         result
             .as_string()
             .ok_or_else(|| WalletError::ApiError("Invalid change address".into()))
@@ -237,7 +282,7 @@ impl WalletApi {
     /// Returns the total balance as CBOR-encoded Value.
     /// Use `wallet_pallas::decode_value` to parse the result.
     pub async fn balance(&self) -> Result<String, WalletError> {
-        let result = get_balance_js(&self.api).await?;
+        let result = with_timeout(get_balance_js(&self.api), self.timeout_ms, "getBalance").await?;
         result
             .as_string()
             .ok_or_else(|| WalletError::ApiError("Invalid balance".into()))
@@ -248,7 +293,12 @@ impl WalletApi {
     /// Returns a list of CBOR-encoded TransactionUnspentOutput values.
     /// Use `wallet_pallas::decode_utxo` to parse each result.
     pub async fn utxos(&self) -> Result<Vec<String>, WalletError> {
-        let result = get_utxos_js(&self.api, &JsValue::UNDEFINED, &JsValue::UNDEFINED).await?;
+        let result = with_timeout(
+            get_utxos_js(&self.api, &JsValue::UNDEFINED, &JsValue::UNDEFINED),
+            self.timeout_ms,
+            "getUtxos",
+        )
+        .await?;
 
         // Result can be null if wallet has no UTxOs
         if result.is_null() || result.is_undefined() {
@@ -261,7 +311,12 @@ impl WalletApi {
 
     /// Sign a transaction (returns witness set hex)
     pub async fn sign_tx(&self, tx_hex: &str, partial_sign: bool) -> Result<String, WalletError> {
-        let result = sign_tx_js(&self.api, tx_hex, partial_sign).await?;
+        let result = with_timeout(
+            sign_tx_js(&self.api, tx_hex, partial_sign),
+            self.timeout_ms,
+            "signTx",
+        )
+        .await?;
         result
             .as_string()
             .ok_or_else(|| WalletError::SigningFailed("Invalid signature response".into()))
@@ -274,7 +329,12 @@ impl WalletApi {
         address: &str,
         payload: &str,
     ) -> Result<DataSignature, WalletError> {
-        let result = sign_data_js(&self.api, address, payload).await?;
+        let result = with_timeout(
+            sign_data_js(&self.api, address, payload),
+            self.timeout_ms,
+            "signData",
+        )
+        .await?;
 
         // CIP-8 signData returns { signature: string, key: string }
         let signature = extract_signature_js(&result)
@@ -290,7 +350,8 @@ impl WalletApi {
 
     /// Submit a signed transaction
     pub async fn submit_tx(&self, tx_hex: &str) -> Result<String, WalletError> {
-        let result = submit_tx_js(&self.api, tx_hex).await?;
+        let result =
+            with_timeout(submit_tx_js(&self.api, tx_hex), self.timeout_ms, "submitTx").await?;
         result
             .as_string()
             .ok_or_else(|| WalletError::SubmitFailed("Invalid submit response".into()))
@@ -0,0 +1,221 @@
+//! Wallet detection that tolerates late-injecting extensions
+//!
+//! Extensions add themselves to `window.cardano` from a content script that
+//! can run after the page's own code, especially on slow devices. A single
+//! [`detect_wallets_with_info`](crate::detect_wallets_with_info) call at
+//! startup can therefore miss wallets the user has installed. These helpers
+//! poll `window.cardano` for a short window instead.
+//!
+//! With the `native` feature the backend's wallets are fixed, so detection
+//! returns immediately and watchers never fire.
+
+use futures::future::AbortHandle;
+#[cfg(not(feature = "native"))]
+use futures::future::Abortable;
+#[cfg(not(feature = "native"))]
+use gloo_timers::future::TimeoutFuture;
+
+use crate::detect_wallets_with_info;
+use crate::types::{WalletInfo, WalletProvider};
+
+/// Default time [`detect_wallets_async`] waits for extensions, in milliseconds
+pub const DEFAULT_DETECT_TIMEOUT_MS: u32 = 1_500;
+
+/// Default time [`watch_wallets`] keeps polling after page load, in milliseconds
+pub const DEFAULT_WATCH_MS: u32 = 10_000;
+
+/// How often `window.cardano` is polled
+#[cfg(not(feature = "native"))]
+const POLL_INTERVAL_MS: u32 = 100;
+
+/// How long the detected set must stay unchanged before detection settles
+#[cfg_attr(feature = "native", allow(dead_code))]
+const SETTLE_MS: u64 = 300;
+
+/// The browser clock, in unix ms
+#[cfg(not(feature = "native"))]
+fn local_now_ms() -> u64 {
+    js_sys::Date::now() as u64
+}
+
+/// Detect wallet extensions, waiting up to `timeout_ms` for late injections
+///
+/// Returns once at least one wallet was found and no new one appeared for a
+/// short moment, or when the timeout expires (possibly with nothing found).
+#[cfg(not(feature = "native"))]
+pub async fn detect_wallets_async(timeout_ms: u32) -> Vec<WalletInfo> {
+    let mut settle = Settle::new(detect_wallets_with_info(), local_now_ms(), timeout_ms);
+    while !settle.is_done(local_now_ms()) {
+        TimeoutFuture::new(POLL_INTERVAL_MS).await;
+        settle.observe(detect_wallets_with_info(), local_now_ms());
+    }
+    settle.last
+}
+
+/// Wait up to `timeout_ms` for `provider` to appear; returns whether it did
+///
+/// Use before auto-reconnecting to a saved wallet whose extension may not
+/// have injected yet.
+#[cfg(not(feature = "native"))]
+pub async fn wait_for_wallet(provider: WalletProvider, timeout_ms: u32) -> bool {
+    let deadline = local_now_ms() + u64::from(timeout_ms);
+    loop {
+        if is_injected(provider) {
+            return true;
+        }
+        if local_now_ms() >= deadline {
+            return false;
+        }
+        TimeoutFuture::new(POLL_INTERVAL_MS).await;
+    }
+}
+
+/// Handle for a [`watch_wallets`] poll; stops polling when dropped
+#[must_use = "the watcher stops when dropped"]
+#[derive(Debug)]
+pub struct WalletWatcher(AbortHandle);
+
+impl WalletWatcher {
+    /// Stop polling
+    pub fn stop(&self) {
+        self.0.abort();
+    }
+}
+
+impl Drop for WalletWatcher {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Call `on_change` with the full list whenever the detected wallets change
+///
+/// Polls for `duration_ms` (see [`DEFAULT_WATCH_MS`]), comparing against
+/// what was present when watching started.
+///
+/// ```ignore
+/// set_wallets(detect_wallets_with_info());
+/// let watcher = watch_wallets(DEFAULT_WATCH_MS, move |wallets| set_wallets(wallets));
+/// ```
+#[cfg(not(feature = "native"))]
+pub fn watch_wallets<F>(duration_ms: u32, on_change: F) -> WalletWatcher
+where
+    F: Fn(Vec<WalletInfo>) + 'static,
+{
+    let (handle, registration) = AbortHandle::new_pair();
+    let watching = async move {
+        let deadline = local_now_ms() + u64::from(duration_ms);
+        let mut last = detect_wallets_with_info();
+        while local_now_ms() < deadline {
+            TimeoutFuture::new(POLL_INTERVAL_MS).await;
+            let next = detect_wallets_with_info();
+            if next != last {
+                on_change(next.clone());
+                last = next;
+            }
+        }
+    };
+    wasm_bindgen_futures::spawn_local(async move {
+        let _ = Abortable::new(watching, registration).await;
+    });
+    WalletWatcher(handle)
+}
+
+fn is_injected(provider: WalletProvider) -> bool {
+    detect_wallets_with_info()
+        .iter()
+        .any(|wallet| wallet.api_name == provider.api_name())
+}
+
+/// Detect wallet extensions (native: the backend's wallets, immediately)
+#[cfg(feature = "native")]
+pub async fn detect_wallets_async(_timeout_ms: u32) -> Vec<WalletInfo> {
+    detect_wallets_with_info()
+}
+
+/// Whether `provider` is installed (native: checked once, no waiting)
+#[cfg(feature = "native")]
+pub async fn wait_for_wallet(provider: WalletProvider, _timeout_ms: u32) -> bool {
+    is_injected(provider)
+}
+
+/// Watch for wallet changes (native: the wallet list never changes)
+#[cfg(feature = "native")]
+pub fn watch_wallets<F>(_duration_ms: u32, _on_change: F) -> WalletWatcher
+where
+    F: Fn(Vec<WalletInfo>) + 'static,
+{
+    let (handle, _registration) = AbortHandle::new_pair();
+    WalletWatcher(handle)
+}
+
+/// Tracks polled readings and decides when detection can stop
+#[cfg_attr(feature = "native", allow(dead_code))]
+struct Settle {
+    last: Vec<WalletInfo>,
+    changed_at: u64,
+    deadline: u64,
+}
+
+#[cfg_attr(feature = "native", allow(dead_code))]
+impl Settle {
+    fn new(initial: Vec<WalletInfo>, now: u64, timeout_ms: u32) -> Self {
+        Self {
+            last: initial,
+            changed_at: now,
+            deadline: now + u64::from(timeout_ms),
+        }
+    }
+
+    fn observe(&mut self, next: Vec<WalletInfo>, now: u64) {
+        if next != self.last {
+            self.last = next;
+            self.changed_at = now;
+        }
+    }
+
+    fn is_done(&self, now: u64) -> bool {
+        now >= self.deadline
+            || (!self.last.is_empty() && now.saturating_sub(self.changed_at) >= SETTLE_MS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wallet(api_name: &str) -> WalletInfo {
+        WalletInfo {
+            api_name: api_name.to_string(),
+            name: api_name.to_string(),
+            icon: None,
+        }
+    }
+
+    #[test]
+    fn test_waits_until_timeout_when_nothing_found() {
+        let settle = Settle::new(vec![], 0, 1_000);
+        assert!(!settle.is_done(900));
+        assert!(settle.is_done(1_000));
+    }
+
+    #[test]
+    fn test_settles_once_wallets_stop_appearing() {
+        let mut settle = Settle::new(vec![], 0, 5_000);
+        settle.observe(vec![wallet("eternl")], 200);
+        assert!(!settle.is_done(400));
+
+        // A second extension injects late and restarts the settle period
+        settle.observe(vec![wallet("eternl"), wallet("lace")], 400);
+        assert!(!settle.is_done(600));
+        assert!(settle.is_done(700));
+        assert_eq!(settle.last.len(), 2);
+    }
+
+    #[test]
+    fn test_unchanged_reading_does_not_reset_settle() {
+        let mut settle = Settle::new(vec![wallet("nami")], 0, 5_000);
+        settle.observe(vec![wallet("nami")], 250);
+        assert!(settle.is_done(300));
+    }
+}
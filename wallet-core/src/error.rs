@@ -27,6 +27,15 @@ pub enum WalletError {
 
     #[error("JavaScript error: {0}")]
     JsError(String),
+
+    #[error("Wallet did not respond to {0} in time")]
+    Timeout(String),
+
+    #[error("Cancelled")]
+    Cancelled,
+
+    #[error("Protocol parameters unavailable: {0}")]
+    ProtocolParams(String),
 }
 
 impl From<wasm_bindgen::JsValue> for WalletError {
@@ -1,14 +1,59 @@
 //! Cardano wallet core functionality
 //!
-//! Provides CIP-30 wallet detection, connection, and signing capabilities.
+//! Provides CIP-30 wallet detection, connection, and signing capabilities,
+//! plus protocol parameter fetching for fee estimation.
 //! Framework-agnostic - can be used with any UI framework or web components.
+//!
+//! ## Native builds
+//!
+//! The `native` feature swaps the browser CIP-30 bindings for an injectable
+//! [`WalletBackend`] (a [`MockWallet`] by default) so dependent crates build
+//! and test off wasm32. Detection returns immediately and the last wallet is
+//! remembered in memory instead of localStorage.
+//!
+//! ## Mock wallet
+//!
+//! The `mock` feature adds [`MockWallet`]. In browser builds
+//! `install_mock_wallet` exposes it on `window.cardano`, so wallet UIs can be
+//! demoed without an extension.
 
+pub mod calendar;
+mod cancel;
+#[cfg(not(feature = "native"))]
 mod cip30;
+mod detect;
 mod error;
+#[cfg(feature = "mock")]
+mod mock;
+#[cfg(feature = "native")]
+mod native;
+mod protocol;
 mod storage;
 mod types;
 
+pub use cancel::{cancellable, CancelHandle, DEFAULT_TIMEOUT_MS};
+#[cfg(not(feature = "native"))]
 pub use cip30::*;
+pub use detect::{
+    detect_wallets_async, wait_for_wallet, watch_wallets, WalletWatcher, DEFAULT_DETECT_TIMEOUT_MS,
+    DEFAULT_WATCH_MS,
+};
 pub use error::*;
+#[cfg(all(feature = "mock", not(feature = "native")))]
+pub use mock::{install_mock_wallet, uninstall_mock_wallet};
+#[cfg(feature = "mock")]
+pub use mock::{MockWallet, WalletBackend};
+#[cfg(feature = "native")]
+pub use native::{
+    detect_wallets, detect_wallets_with_info, get_wallet_info, set_wallet_backend, wallet_backend,
+    WalletApi,
+};
+#[cfg(feature = "blockfrost")]
+pub use protocol::BlockfrostParams;
+#[cfg(feature = "koios")]
+pub use protocol::KoiosParams;
+#[cfg(feature = "worker")]
+pub use protocol::WorkerParams;
+pub use protocol::{ExUnitPrices, ExUnits, ProtocolParams, ProtocolParamsFetcher};
 pub use storage::*;
 pub use types::*;
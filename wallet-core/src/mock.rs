@@ -0,0 +1,440 @@
+//! Mock wallet
+//!
+//! [`MockWallet`] answers CIP-30 calls with canned responses. Native builds
+//! use it as the default [`WalletBackend`]; in the browser,
+//! [`install_mock_wallet`] exposes any backend as a `window.cardano` entry so
+//! wallet UIs can be demoed and tested without an extension.
+//!
+//! ```ignore
+//! install_mock_wallet(Rc::new(
+//!     MockWallet::new().with_balance(balance_hex).failing_every(5),
+//! ))?;
+//!
+//! // Detection and WalletApi::connect now find the mock
+//! let api = WalletApi::connect(WalletProvider::Eternl).await?;
+//!
+//! uninstall_mock_wallet();
+//! ```
+
+use std::cell::{Cell, RefCell};
+#[cfg(not(feature = "native"))]
+use std::rc::Rc;
+
+use crate::types::{DataSignature, WalletInfo, WalletProvider};
+use crate::WalletError;
+
+/// Wallet operations behind a mock wallet
+///
+/// Backs [`WalletApi`](crate::WalletApi) on native builds, and the
+/// `window.cardano` entries added by [`install_mock_wallet`] in the browser.
+///
+/// Mirrors the CIP-30 calls; return values use the same hex/CBOR encodings
+/// a browser wallet would.
+pub trait WalletBackend {
+    /// Installed wallets
+    fn wallets(&self) -> Vec<WalletInfo>;
+
+    /// Enable `provider` (the CIP-30 `enable()` prompt)
+    fn enable(&self, provider: WalletProvider) -> Result<(), WalletError>;
+
+    /// Network ID (0 = testnet, 1 = mainnet)
+    fn network_id(&self) -> Result<u8, WalletError>;
+
+    /// Used addresses (hex-encoded)
+    fn used_addresses(&self) -> Result<Vec<String>, WalletError>;
+
+    /// Change address (hex-encoded)
+    fn change_address(&self) -> Result<String, WalletError>;
+
+    /// Balance (CBOR-encoded Value hex)
+    fn balance(&self) -> Result<String, WalletError>;
+
+    /// UTxOs (CBOR-encoded TransactionUnspentOutput hex)
+    fn utxos(&self) -> Result<Vec<String>, WalletError>;
+
+    /// Sign a transaction, returning the witness set hex
+    fn sign_tx(&self, tx_hex: &str, partial_sign: bool) -> Result<String, WalletError>;
+
+    /// Sign arbitrary data (CIP-8)
+    fn sign_data(&self, address: &str, payload: &str) -> Result<DataSignature, WalletError>;
+
+    /// Submit a signed transaction, returning its hash
+    fn submit_tx(&self, tx_hex: &str) -> Result<String, WalletError>;
+}
+
+/// In-memory wallet with canned responses
+///
+/// Defaults to a single Eternl wallet on testnet with one enterprise address,
+/// zero balance and no UTxOs. Signing returns an empty witness set and
+/// submitting returns an all-zero hash; both are recorded for assertions.
+pub struct MockWallet {
+    wallets: Vec<WalletInfo>,
+    network_id: u8,
+    addresses: Vec<String>,
+    balance: String,
+    utxos: Vec<String>,
+    witness_set: String,
+    rejecting: bool,
+    fail_every: Option<u32>,
+    calls: Cell<u32>,
+    signed: RefCell<Vec<String>>,
+    submitted: RefCell<Vec<String>>,
+}
+
+impl Default for MockWallet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockWallet {
+    /// Mock with default responses
+    pub fn new() -> Self {
+        Self {
+            wallets: vec![WalletInfo {
+                api_name: WalletProvider::Eternl.api_name().to_string(),
+                name: WalletProvider::Eternl.display_name().to_string(),
+                icon: None,
+            }],
+            network_id: 0,
+            // Testnet enterprise address (header 0x60) with a zero key hash
+            addresses: vec![format!("60{}", "00".repeat(28))],
+            // CBOR uint 0
+            balance: "00".to_string(),
+            utxos: Vec::new(),
+            // CBOR empty map
+            witness_set: "a0".to_string(),
+            rejecting: false,
+            fail_every: None,
+            calls: Cell::new(0),
+            signed: RefCell::new(Vec::new()),
+            submitted: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Installed wallets reported by detection
+    pub fn with_wallets(mut self, wallets: Vec<WalletInfo>) -> Self {
+        self.wallets = wallets;
+        self
+    }
+
+    /// Network ID returned by `network_id`
+    pub fn with_network_id(mut self, network_id: u8) -> Self {
+        self.network_id = network_id;
+        self
+    }
+
+    /// Used addresses; the first is also the change address
+    pub fn with_addresses(mut self, addresses: Vec<String>) -> Self {
+        self.addresses = addresses;
+        self
+    }
+
+    /// Balance CBOR hex
+    pub fn with_balance(mut self, balance: impl Into<String>) -> Self {
+        self.balance = balance.into();
+        self
+    }
+
+    /// UTxO CBOR hex strings
+    pub fn with_utxos(mut self, utxos: Vec<String>) -> Self {
+        self.utxos = utxos;
+        self
+    }
+
+    /// Witness set hex returned by `sign_tx`
+    pub fn with_witness_set(mut self, witness_set: impl Into<String>) -> Self {
+        self.witness_set = witness_set.into();
+        self
+    }
+
+    /// Reject enable and signing requests, as if the user declined
+    pub fn rejecting(mut self, rejecting: bool) -> Self {
+        self.rejecting = rejecting;
+        self
+    }
+
+    /// Fail every `n`th balance, UTxO, signing or submit call
+    ///
+    /// Exercises error and retry paths with a wallet that otherwise works.
+    pub fn failing_every(mut self, n: u32) -> Self {
+        self.fail_every = (n > 0).then_some(n);
+        self
+    }
+
+    /// Count a call, failing it if it's one of the flaky ones
+    fn call(&self, operation: &str) -> Result<(), WalletError> {
+        let Some(n) = self.fail_every else {
+            return Ok(());
+        };
+        let calls = self.calls.get() + 1;
+        self.calls.set(calls);
+        if calls.is_multiple_of(n) {
+            return Err(WalletError::ApiError(format!("Mock {operation} failure")));
+        }
+        Ok(())
+    }
+
+    /// Transactions passed to `sign_tx`, oldest first
+    pub fn signed(&self) -> Vec<String> {
+        self.signed.borrow().clone()
+    }
+
+    /// Transactions passed to `submit_tx`, oldest first
+    pub fn submitted(&self) -> Vec<String> {
+        self.submitted.borrow().clone()
+    }
+}
+
+impl WalletBackend for MockWallet {
+    fn wallets(&self) -> Vec<WalletInfo> {
+        self.wallets.clone()
+    }
+
+    fn enable(&self, provider: WalletProvider) -> Result<(), WalletError> {
+        if !self
+            .wallets
+            .iter()
+            .any(|wallet| wallet.api_name == provider.api_name())
+        {
+            return Err(WalletError::NotEnabled(provider.display_name().to_string()));
+        }
+        if self.rejecting {
+            return Err(WalletError::UserRejected);
+        }
+        Ok(())
+    }
+
+    fn network_id(&self) -> Result<u8, WalletError> {
+        Ok(self.network_id)
+    }
+
+    fn used_addresses(&self) -> Result<Vec<String>, WalletError> {
+        Ok(self.addresses.clone())
+    }
+
+    fn change_address(&self) -> Result<String, WalletError> {
+        self.addresses
+            .first()
+            .cloned()
+            .ok_or_else(|| WalletError::ApiError("Invalid change address".into()))
+    }
+
+    fn balance(&self) -> Result<String, WalletError> {
+        self.call("getBalance")?;
+        Ok(self.balance.clone())
+    }
+
+    fn utxos(&self) -> Result<Vec<String>, WalletError> {
+        self.call("getUtxos")?;
+        Ok(self.utxos.clone())
+    }
+
+    fn sign_tx(&self, tx_hex: &str, _partial_sign: bool) -> Result<String, WalletError> {
+        self.call("signTx")?;
+        if self.rejecting {
+            return Err(WalletError::SigningFailed("User declined to sign".into()));
+        }
+        self.signed.borrow_mut().push(tx_hex.to_string());
+        Ok(self.witness_set.clone())
+    }
+
+    fn sign_data(&self, _address: &str, _payload: &str) -> Result<DataSignature, WalletError> {
+        self.call("signData")?;
+        if self.rejecting {
+            return Err(WalletError::SigningFailed("User declined to sign".into()));
+        }
+        Ok(DataSignature {
+            signature: "00".repeat(64),
+            key: "00".repeat(32),
+        })
+    }
+
+    fn submit_tx(&self, tx_hex: &str) -> Result<String, WalletError> {
+        self.call("submitTx")?;
+        self.submitted.borrow_mut().push(tx_hex.to_string());
+        Ok("00".repeat(32))
+    }
+}
+
+#[cfg(not(feature = "native"))]
+pub use browser::{install_mock_wallet, uninstall_mock_wallet};
+
+#[cfg(not(feature = "native"))]
+mod browser {
+    use super::*;
+    use js_sys::{Array, Object, Promise, Reflect};
+    use wasm_bindgen::prelude::*;
+
+    /// CIP-30 version reported by injected wallets
+    const API_VERSION: &str = "0.1.0";
+
+    thread_local! {
+        /// `window.cardano` entries replaced by the mock, restored on uninstall
+        static REPLACED: RefCell<Vec<(String, JsValue)>> = RefCell::default();
+    }
+
+    /// Expose `backend` as a CIP-30 wallet on `window.cardano`
+    ///
+    /// Adds an entry for each of the backend's wallets, replacing (and later
+    /// restoring) any real extension with the same API name. Calling it again
+    /// swaps the previous mock out.
+    pub fn install_mock_wallet(backend: Rc<dyn WalletBackend>) -> Result<(), WalletError> {
+        uninstall_mock_wallet();
+
+        let global = js_sys::global();
+        let mut cardano = Reflect::get(&global, &"cardano".into())?;
+        if !cardano.is_object() {
+            cardano = Object::new().into();
+            Reflect::set(&global, &"cardano".into(), &cardano)?;
+        }
+
+        for info in backend.wallets() {
+            let Some(provider) = WalletProvider::from_api_name(&info.api_name) else {
+                continue;
+            };
+            let key = JsValue::from_str(&info.api_name);
+            let previous = Reflect::get(&cardano, &key)?;
+            REPLACED.with(|replaced| {
+                replaced
+                    .borrow_mut()
+                    .push((info.api_name.clone(), previous))
+            });
+            let entry: JsValue = wallet_entry(&backend, provider, &info)?.into();
+            Reflect::set(&cardano, &key, &entry)?;
+        }
+        Ok(())
+    }
+
+    /// Remove the mock wallets, restoring any extensions they replaced
+    pub fn uninstall_mock_wallet() {
+        let replaced = REPLACED.with(|replaced| std::mem::take(&mut *replaced.borrow_mut()));
+        let Ok(cardano) = Reflect::get(&js_sys::global(), &"cardano".into()) else {
+            return;
+        };
+        for (api_name, previous) in replaced {
+            let key = JsValue::from_str(&api_name);
+            let _ = if previous.is_undefined() {
+                Reflect::delete_property(cardano.unchecked_ref::<Object>(), &key)
+            } else {
+                Reflect::set(&cardano, &key, &previous)
+            };
+        }
+    }
+
+    /// The `window.cardano.<name>` object: metadata plus `enable()`
+    fn wallet_entry(
+        backend: &Rc<dyn WalletBackend>,
+        provider: WalletProvider,
+        info: &WalletInfo,
+    ) -> Result<Object, WalletError> {
+        let entry = Object::new();
+        Reflect::set(&entry, &"name".into(), &info.name.as_str().into())?;
+        if let Some(icon) = &info.icon {
+            Reflect::set(&entry, &"icon".into(), &icon.as_str().into())?;
+        }
+        Reflect::set(&entry, &"apiVersion".into(), &API_VERSION.into())?;
+        Reflect::set(&entry, &"isMock".into(), &true.into())?;
+
+        let enabled = Rc::new(Cell::new(false));
+        set_method(&entry, "isEnabled", {
+            let enabled = enabled.clone();
+            move |_, _| Ok(enabled.get().into())
+        })?;
+        set_method(&entry, "enable", {
+            let backend = backend.clone();
+            move |_, _| {
+                backend.enable(provider)?;
+                enabled.set(true);
+                Ok(api_object(&backend)?.into())
+            }
+        })?;
+        Ok(entry)
+    }
+
+    /// The API object `enable()` resolves to
+    fn api_object(backend: &Rc<dyn WalletBackend>) -> Result<Object, WalletError> {
+        let api = Object::new();
+        let b = backend.clone();
+        set_method(&api, "getNetworkId", move |_, _| Ok(b.network_id()?.into()))?;
+        let b = backend.clone();
+        set_method(&api, "getUsedAddresses", move |_, _| {
+            Ok(strings(b.used_addresses()?))
+        })?;
+        set_method(&api, "getUnusedAddresses", |_, _| Ok(Array::new().into()))?;
+        let b = backend.clone();
+        set_method(&api, "getChangeAddress", move |_, _| {
+            Ok(b.change_address()?.into())
+        })?;
+        set_method(&api, "getRewardAddresses", |_, _| Ok(strings(Vec::new())))?;
+        let b = backend.clone();
+        set_method(&api, "getBalance", move |_, _| Ok(b.balance()?.into()))?;
+        let b = backend.clone();
+        set_method(&api, "getUtxos", move |_, _| Ok(strings(b.utxos()?)))?;
+        let b = backend.clone();
+        set_method(&api, "signTx", move |tx, partial| {
+            let tx = tx.as_string().unwrap_or_default();
+            Ok(b.sign_tx(&tx, partial.is_truthy())?.into())
+        })?;
+        let b = backend.clone();
+        set_method(&api, "signData", move |address, payload| {
+            let signature = b.sign_data(
+                &address.as_string().unwrap_or_default(),
+                &payload.as_string().unwrap_or_default(),
+            )?;
+            let result = Object::new();
+            Reflect::set(&result, &"signature".into(), &signature.signature.into())?;
+            Reflect::set(&result, &"key".into(), &signature.key.into())?;
+            Ok(result.into())
+        })?;
+        let b = backend.clone();
+        set_method(&api, "submitTx", move |tx, _| {
+            Ok(b.submit_tx(&tx.as_string().unwrap_or_default())?.into())
+        })?;
+        Ok(api)
+    }
+
+    /// Attach `f` as a promise-returning method, like an extension's
+    fn set_method<F>(target: &Object, name: &str, f: F) -> Result<(), WalletError>
+    where
+        F: Fn(JsValue, JsValue) -> Result<JsValue, WalletError> + 'static,
+    {
+        let method =
+            Closure::<dyn Fn(JsValue, JsValue) -> Promise>::new(move |a, b| match f(a, b) {
+                Ok(value) => Promise::resolve(&value),
+                Err(e) => Promise::reject(&JsValue::from_str(&e.to_string())),
+            });
+        Reflect::set(target, &name.into(), &method.into_js_value())?;
+        Ok(())
+    }
+
+    fn strings(values: Vec<String>) -> JsValue {
+        values
+            .into_iter()
+            .map(JsValue::from)
+            .collect::<Array>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failing_every() {
+        let wallet = MockWallet::new().failing_every(3);
+        assert!(wallet.balance().is_ok());
+        assert!(wallet.utxos().is_ok());
+        assert!(matches!(
+            wallet.sign_tx("84a0", false),
+            Err(WalletError::ApiError(_))
+        ));
+        assert!(wallet.balance().is_ok());
+        // Failed calls aren't recorded
+        assert!(wallet.signed().is_empty());
+
+        let wallet = MockWallet::new().failing_every(0);
+        assert!((0..10).all(|_| wallet.balance().is_ok()));
+    }
+}
@@ -0,0 +1,193 @@
+//! Native wallet backend
+//!
+//! With the `native` feature, [`WalletApi`] talks to an injectable
+//! [`WalletBackend`] instead of the browser's `window.cardano`, so crates
+//! depending on wallet-core build and test off wasm32 (CI, server-side
+//! validation). The backend defaults to a [`MockWallet`].
+//!
+//! ```ignore
+//! let wallet = Rc::new(MockWallet::new().with_network_id(1));
+//! set_wallet_backend(wallet.clone());
+//!
+//! let api = WalletApi::connect(WalletProvider::Eternl).await?;
+//! api.sign_tx(&tx_hex, false).await?;
+//! assert_eq!(wallet.signed(), vec![tx_hex]);
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::cancel::DEFAULT_TIMEOUT_MS;
+use crate::mock::{MockWallet, WalletBackend};
+use crate::types::{DataSignature, WalletInfo, WalletProvider};
+use crate::WalletError;
+
+thread_local! {
+    static BACKEND: RefCell<Rc<dyn WalletBackend>> = RefCell::new(Rc::new(MockWallet::new()));
+}
+
+/// Use `backend` for wallets connected on this thread from now on
+pub fn set_wallet_backend(backend: Rc<dyn WalletBackend>) {
+    BACKEND.with(|current| *current.borrow_mut() = backend);
+}
+
+/// The backend wallets on this thread connect to
+pub fn wallet_backend() -> Rc<dyn WalletBackend> {
+    BACKEND.with(|current| current.borrow().clone())
+}
+
+/// Detect available wallets from the backend
+pub fn detect_wallets() -> Vec<WalletProvider> {
+    detect_wallets_with_info()
+        .iter()
+        .filter_map(|wallet| WalletProvider::from_api_name(&wallet.api_name))
+        .collect()
+}
+
+/// Detect available wallets with full info (name, icon)
+pub fn detect_wallets_with_info() -> Vec<WalletInfo> {
+    wallet_backend().wallets()
+}
+
+/// Get info for a specific wallet
+pub fn get_wallet_info(provider: WalletProvider) -> Option<WalletInfo> {
+    detect_wallets_with_info()
+        .into_iter()
+        .find(|wallet| wallet.api_name == provider.api_name())
+}
+
+/// Connected wallet API handle
+///
+/// Calls go to the [`WalletBackend`] that was current when connecting.
+/// Timeouts are kept for API parity but not enforced.
+#[derive(Clone)]
+pub struct WalletApi {
+    provider: WalletProvider,
+    backend: Rc<dyn WalletBackend>,
+    timeout_ms: Option<u32>,
+}
+
+impl WalletApi {
+    /// Enable a wallet and get the API handle
+    pub async fn connect(provider: WalletProvider) -> Result<Self, WalletError> {
+        Self::connect_with_timeout(provider, Some(DEFAULT_TIMEOUT_MS)).await
+    }
+
+    /// Enable a wallet with a custom timeout (`None` waits indefinitely)
+    pub async fn connect_with_timeout(
+        provider: WalletProvider,
+        timeout_ms: Option<u32>,
+    ) -> Result<Self, WalletError> {
+        Ok(Self::connect_backend(provider, wallet_backend())?.with_timeout(timeout_ms))
+    }
+
+    /// Enable a wallet on a specific backend
+    pub fn connect_backend(
+        provider: WalletProvider,
+        backend: Rc<dyn WalletBackend>,
+    ) -> Result<Self, WalletError> {
+        backend.enable(provider)?;
+        Ok(Self {
+            provider,
+            backend,
+            timeout_ms: Some(DEFAULT_TIMEOUT_MS),
+        })
+    }
+
+    /// Use a different timeout for subsequent calls (`None` waits indefinitely)
+    pub fn with_timeout(mut self, timeout_ms: Option<u32>) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// Timeout applied to each call, in milliseconds
+    pub fn timeout_ms(&self) -> Option<u32> {
+        self.timeout_ms
+    }
+
+    /// Get the wallet provider
+    pub fn provider(&self) -> WalletProvider {
+        self.provider
+    }
+
+    /// Get the network ID (0 = testnet, 1 = mainnet)
+    pub async fn network_id(&self) -> Result<u8, WalletError> {
+        self.backend.network_id()
+    }
+
+    /// Get used addresses (hex-encoded)
+    pub async fn used_addresses(&self) -> Result<Vec<String>, WalletError> {
+        self.backend.used_addresses()
+    }
+
+    /// Get change address (hex-encoded)
+    pub async fn change_address(&self) -> Result<String, WalletError> {
+        self.backend.change_address()
+    }
+
+    /// Get wallet balance (CBOR-encoded hex string)
+    pub async fn balance(&self) -> Result<String, WalletError> {
+        self.backend.balance()
+    }
+
+    /// Get UTxOs from the wallet (CBOR-encoded hex strings)
+    pub async fn utxos(&self) -> Result<Vec<String>, WalletError> {
+        self.backend.utxos()
+    }
+
+    /// Sign a transaction (returns witness set hex)
+    pub async fn sign_tx(&self, tx_hex: &str, partial_sign: bool) -> Result<String, WalletError> {
+        self.backend.sign_tx(tx_hex, partial_sign)
+    }
+
+    /// Sign arbitrary data (CIP-8)
+    pub async fn sign_data(
+        &self,
+        address: &str,
+        payload: &str,
+    ) -> Result<DataSignature, WalletError> {
+        self.backend.sign_data(address, payload)
+    }
+
+    /// Submit a signed transaction
+    pub async fn submit_tx(&self, tx_hex: &str) -> Result<String, WalletError> {
+        self.backend.submit_tx(tx_hex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn test_connects_to_default_mock() {
+        let api = block_on(WalletApi::connect(WalletProvider::Eternl)).unwrap();
+        assert_eq!(block_on(api.network_id()).unwrap(), 0);
+        assert_eq!(detect_wallets(), vec![WalletProvider::Eternl]);
+        assert!(block_on(WalletApi::connect(WalletProvider::Nami)).is_err());
+    }
+
+    #[test]
+    fn test_injected_backend_records_calls() {
+        let wallet = Rc::new(MockWallet::new().with_network_id(1));
+        set_wallet_backend(wallet.clone());
+
+        let api = block_on(WalletApi::connect(WalletProvider::Eternl)).unwrap();
+        assert_eq!(block_on(api.network_id()).unwrap(), 1);
+        assert_eq!(block_on(api.sign_tx("84a0", false)).unwrap(), "a0");
+        block_on(api.submit_tx("84a0")).unwrap();
+
+        assert_eq!(wallet.signed(), vec!["84a0".to_string()]);
+        assert_eq!(wallet.submitted(), vec!["84a0".to_string()]);
+    }
+
+    #[test]
+    fn test_rejecting_wallet() {
+        let backend: Rc<dyn WalletBackend> = Rc::new(MockWallet::new().rejecting(true));
+        assert!(matches!(
+            WalletApi::connect_backend(WalletProvider::Eternl, backend),
+            Err(WalletError::UserRejected)
+        ));
+    }
+}
@@ -0,0 +1,493 @@
+//! Protocol parameters
+//!
+//! Fee estimation and output minimums depend on the live protocol parameters
+//! of the network. [`ProtocolParams`] holds the subset transaction building
+//! needs, and [`ProtocolParamsFetcher`] loads them from a chain index.
+//!
+//! Fetchers are behind features so apps only pull in an HTTP client when they
+//! need one:
+//!
+//! - `blockfrost` - [`BlockfrostParams`] (`/epochs/latest/parameters`)
+//! - `koios` - [`KoiosParams`] (`/cli_protocol_params`)
+//! - `worker` - [`WorkerParams`] for a custom endpoint serving [`ProtocolParams`] JSON
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! use wallet_core::{BlockfrostParams, Network, ProtocolParamsFetcher};
+//!
+//! let params = BlockfrostParams::new(Network::Mainnet, project_id).fetch().await?;
+//! let fee = wallet_pallas::estimate_fee(&tx_draft_hex, &params)?;
+//! ```
+
+#[cfg(any(feature = "blockfrost", feature = "koios"))]
+use crate::Network;
+use crate::WalletError;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+/// Execution units (memory and CPU steps) for Plutus scripts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExUnits {
+    pub mem: u64,
+    pub steps: u64,
+}
+
+impl ExUnits {
+    pub fn new(mem: u64, steps: u64) -> Self {
+        Self { mem, steps }
+    }
+}
+
+impl std::ops::Add for ExUnits {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            mem: self.mem.saturating_add(other.mem),
+            steps: self.steps.saturating_add(other.steps),
+        }
+    }
+}
+
+/// Lovelace price per execution unit
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ExUnitPrices {
+    pub mem: f64,
+    pub steps: f64,
+}
+
+/// Protocol parameters used for fee and deposit calculations
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProtocolParams {
+    /// Epoch these parameters apply to, if the source reports it
+    #[serde(default)]
+    pub epoch: Option<u64>,
+    /// Fee per transaction byte (the `a` in `a * size + b`)
+    pub min_fee_a: u64,
+    /// Constant fee per transaction (the `b` in `a * size + b`)
+    pub min_fee_b: u64,
+    /// Maximum transaction size in bytes
+    pub max_tx_size: u64,
+    /// Lovelace per byte of UTxO storage
+    pub coins_per_utxo_byte: u64,
+    /// Stake key registration deposit
+    pub key_deposit: u64,
+    /// Stake pool registration deposit
+    pub pool_deposit: u64,
+    /// Script execution prices
+    pub ex_unit_prices: ExUnitPrices,
+    /// Maximum execution units per transaction
+    pub max_tx_ex_units: ExUnits,
+    /// Collateral required as a percentage of the fee
+    pub collateral_percent: u64,
+    /// Maximum number of collateral inputs
+    pub max_collateral_inputs: u64,
+    /// Base fee per byte of reference scripts (tiered, see `wallet_pallas::ref_script_fee`)
+    #[serde(default)]
+    pub min_fee_ref_script_cost_per_byte: f64,
+}
+
+impl ProtocolParams {
+    /// Minimum collateral for a transaction paying `fee`
+    pub fn min_collateral(&self, fee: u64) -> u64 {
+        (fee * self.collateral_percent).div_ceil(100)
+    }
+}
+
+/// Source of live protocol parameters
+pub trait ProtocolParamsFetcher {
+    /// Fetch the current protocol parameters
+    fn fetch(&self) -> impl Future<Output = Result<ProtocolParams, WalletError>>;
+}
+
+// ============================================================================
+// Blockfrost
+// ============================================================================
+
+/// Blockfrost `/epochs/latest/parameters` fetcher
+#[cfg(feature = "blockfrost")]
+#[derive(Debug, Clone)]
+pub struct BlockfrostParams {
+    base_url: String,
+    project_id: String,
+}
+
+#[cfg(feature = "blockfrost")]
+impl BlockfrostParams {
+    /// Fetcher for the public Blockfrost endpoint of `network`
+    pub fn new(network: Network, project_id: impl Into<String>) -> Self {
+        let subdomain = match network {
+            Network::Mainnet => "cardano-mainnet",
+            Network::Preprod => "cardano-preprod",
+            Network::Preview => "cardano-preview",
+        };
+        Self::with_base_url(
+            format!("https://{subdomain}.blockfrost.io/api/v0"),
+            project_id,
+        )
+    }
+
+    /// Fetcher for a self-hosted or proxied Blockfrost-compatible API
+    pub fn with_base_url(base_url: impl Into<String>, project_id: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            project_id: project_id.into(),
+        }
+    }
+}
+
+#[cfg(feature = "blockfrost")]
+impl ProtocolParamsFetcher for BlockfrostParams {
+    async fn fetch(&self) -> Result<ProtocolParams, WalletError> {
+        let url = format!("{}/epochs/latest/parameters", self.base_url);
+        let request = gloo_net::http::Request::get(&url).header("project_id", &self.project_id);
+        let response: BlockfrostResponse = send_json(request).await?;
+        response.try_into()
+    }
+}
+
+/// Blockfrost reports large values as strings and prices as floats
+#[cfg(any(feature = "blockfrost", test))]
+#[derive(Debug, Deserialize)]
+struct BlockfrostResponse {
+    epoch: u64,
+    min_fee_a: u64,
+    min_fee_b: u64,
+    max_tx_size: u64,
+    coins_per_utxo_size: Option<String>,
+    key_deposit: String,
+    pool_deposit: String,
+    price_mem: Option<f64>,
+    price_step: Option<f64>,
+    max_tx_ex_mem: Option<String>,
+    max_tx_ex_steps: Option<String>,
+    collateral_percent: Option<u64>,
+    max_collateral_inputs: Option<u64>,
+    min_fee_ref_script_cost_per_byte: Option<f64>,
+}
+
+#[cfg(any(feature = "blockfrost", test))]
+impl TryFrom<BlockfrostResponse> for ProtocolParams {
+    type Error = WalletError;
+
+    fn try_from(r: BlockfrostResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            epoch: Some(r.epoch),
+            min_fee_a: r.min_fee_a,
+            min_fee_b: r.min_fee_b,
+            max_tx_size: r.max_tx_size,
+            coins_per_utxo_byte: parse_field("coins_per_utxo_size", r.coins_per_utxo_size)?,
+            key_deposit: parse_field("key_deposit", Some(r.key_deposit))?,
+            pool_deposit: parse_field("pool_deposit", Some(r.pool_deposit))?,
+            ex_unit_prices: ExUnitPrices {
+                mem: required("price_mem", r.price_mem)?,
+                steps: required("price_step", r.price_step)?,
+            },
+            max_tx_ex_units: ExUnits {
+                mem: parse_field("max_tx_ex_mem", r.max_tx_ex_mem)?,
+                steps: parse_field("max_tx_ex_steps", r.max_tx_ex_steps)?,
+            },
+            collateral_percent: required("collateral_percent", r.collateral_percent)?,
+            max_collateral_inputs: required("max_collateral_inputs", r.max_collateral_inputs)?,
+            min_fee_ref_script_cost_per_byte: r.min_fee_ref_script_cost_per_byte.unwrap_or(0.0),
+        })
+    }
+}
+
+// ============================================================================
+// Koios
+// ============================================================================
+
+/// Koios `/cli_protocol_params` fetcher
+#[cfg(feature = "koios")]
+#[derive(Debug, Clone)]
+pub struct KoiosParams {
+    base_url: String,
+    api_key: Option<String>,
+}
+
+#[cfg(feature = "koios")]
+impl KoiosParams {
+    /// Fetcher for the public Koios endpoint of `network`
+    pub fn new(network: Network) -> Self {
+        let host = match network {
+            Network::Mainnet => "api.koios.rest",
+            Network::Preprod => "preprod.koios.rest",
+            Network::Preview => "preview.koios.rest",
+        };
+        Self::with_base_url(format!("https://{host}/api/v1"))
+    }
+
+    /// Fetcher for a self-hosted Koios instance
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            api_key: None,
+        }
+    }
+
+    /// Send a bearer token for higher rate limits
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+}
+
+#[cfg(feature = "koios")]
+impl ProtocolParamsFetcher for KoiosParams {
+    async fn fetch(&self) -> Result<ProtocolParams, WalletError> {
+        let url = format!("{}/cli_protocol_params", self.base_url);
+        let mut request = gloo_net::http::Request::get(&url);
+        if let Some(key) = &self.api_key {
+            request = request.header("Authorization", &format!("Bearer {key}"));
+        }
+        let response: CliProtocolParams = send_json(request).await?;
+        Ok(response.into())
+    }
+}
+
+/// `cardano-cli query protocol-parameters` JSON, as served by Koios
+#[cfg(any(feature = "koios", test))]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CliProtocolParams {
+    tx_fee_per_byte: u64,
+    tx_fee_fixed: u64,
+    max_tx_size: u64,
+    utxo_cost_per_byte: u64,
+    stake_address_deposit: u64,
+    stake_pool_deposit: u64,
+    execution_unit_prices: CliExUnitPrices,
+    max_tx_execution_units: CliExUnits,
+    collateral_percentage: u64,
+    max_collateral_inputs: u64,
+    #[serde(default)]
+    min_fee_ref_script_cost_per_byte: f64,
+}
+
+#[cfg(any(feature = "koios", test))]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CliExUnitPrices {
+    price_memory: f64,
+    price_steps: f64,
+}
+
+#[cfg(any(feature = "koios", test))]
+#[derive(Debug, Deserialize)]
+struct CliExUnits {
+    memory: u64,
+    steps: u64,
+}
+
+#[cfg(any(feature = "koios", test))]
+impl From<CliProtocolParams> for ProtocolParams {
+    fn from(p: CliProtocolParams) -> Self {
+        Self {
+            epoch: None,
+            min_fee_a: p.tx_fee_per_byte,
+            min_fee_b: p.tx_fee_fixed,
+            max_tx_size: p.max_tx_size,
+            coins_per_utxo_byte: p.utxo_cost_per_byte,
+            key_deposit: p.stake_address_deposit,
+            pool_deposit: p.stake_pool_deposit,
+            ex_unit_prices: ExUnitPrices {
+                mem: p.execution_unit_prices.price_memory,
+                steps: p.execution_unit_prices.price_steps,
+            },
+            max_tx_ex_units: ExUnits {
+                mem: p.max_tx_execution_units.memory,
+                steps: p.max_tx_execution_units.steps,
+            },
+            collateral_percent: p.collateral_percentage,
+            max_collateral_inputs: p.max_collateral_inputs,
+            min_fee_ref_script_cost_per_byte: p.min_fee_ref_script_cost_per_byte,
+        }
+    }
+}
+
+// ============================================================================
+// Custom worker
+// ============================================================================
+
+/// Fetcher for an app's own endpoint returning [`ProtocolParams`] as JSON
+///
+/// Useful when a backend worker caches parameters and keeps API keys out of
+/// the browser.
+#[cfg(feature = "worker")]
+#[derive(Debug, Clone)]
+pub struct WorkerParams {
+    url: String,
+    token: Option<String>,
+}
+
+#[cfg(feature = "worker")]
+impl WorkerParams {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            token: None,
+        }
+    }
+
+    /// Send a bearer token with the request
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+}
+
+#[cfg(feature = "worker")]
+impl ProtocolParamsFetcher for WorkerParams {
+    async fn fetch(&self) -> Result<ProtocolParams, WalletError> {
+        let mut request = gloo_net::http::Request::get(&self.url);
+        if let Some(token) = &self.token {
+            request = request.header("Authorization", &format!("Bearer {token}"));
+        }
+        send_json(request).await
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+#[cfg(any(feature = "blockfrost", feature = "koios", feature = "worker"))]
+async fn send_json<T: serde::de::DeserializeOwned>(
+    request: gloo_net::http::RequestBuilder,
+) -> Result<T, WalletError> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| WalletError::ProtocolParams(format!("request failed: {e}")))?;
+
+    if !response.ok() {
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        return Err(WalletError::ProtocolParams(format!(
+            "HTTP {status}: {text}"
+        )));
+    }
+
+    response
+        .json::<T>()
+        .await
+        .map_err(|e| WalletError::ProtocolParams(format!("invalid response: {e}")))
+}
+
+#[cfg(any(feature = "blockfrost", test))]
+fn required<T>(field: &str, value: Option<T>) -> Result<T, WalletError> {
+    value.ok_or_else(|| WalletError::ProtocolParams(format!("missing {field}")))
+}
+
+#[cfg(any(feature = "blockfrost", test))]
+fn parse_field(field: &str, value: Option<String>) -> Result<u64, WalletError> {
+    required(field, value)?
+        .parse()
+        .map_err(|e| WalletError::ProtocolParams(format!("invalid {field}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blockfrost_response() {
+        let json = r#"{
+            "epoch": 520,
+            "min_fee_a": 44,
+            "min_fee_b": 155381,
+            "max_tx_size": 16384,
+            "coins_per_utxo_size": "4310",
+            "key_deposit": "2000000",
+            "pool_deposit": "500000000",
+            "price_mem": 0.0577,
+            "price_step": 0.0000721,
+            "max_tx_ex_mem": "14000000",
+            "max_tx_ex_steps": "10000000000",
+            "collateral_percent": 150,
+            "max_collateral_inputs": 3,
+            "min_fee_ref_script_cost_per_byte": 15
+        }"#;
+        let response: BlockfrostResponse = serde_json::from_str(json).unwrap();
+        let params = ProtocolParams::try_from(response).unwrap();
+
+        assert_eq!(params.epoch, Some(520));
+        assert_eq!(params.min_fee_a, 44);
+        assert_eq!(params.coins_per_utxo_byte, 4310);
+        assert_eq!(
+            params.max_tx_ex_units,
+            ExUnits::new(14_000_000, 10_000_000_000)
+        );
+        assert_eq!(params.min_fee_ref_script_cost_per_byte, 15.0);
+    }
+
+    #[test]
+    fn test_blockfrost_response_missing_field() {
+        let json = r#"{
+            "epoch": 200,
+            "min_fee_a": 44,
+            "min_fee_b": 155381,
+            "max_tx_size": 16384,
+            "coins_per_utxo_size": null,
+            "key_deposit": "2000000",
+            "pool_deposit": "500000000",
+            "price_mem": null,
+            "price_step": null,
+            "max_tx_ex_mem": null,
+            "max_tx_ex_steps": null,
+            "collateral_percent": null,
+            "max_collateral_inputs": null,
+            "min_fee_ref_script_cost_per_byte": null
+        }"#;
+        let response: BlockfrostResponse = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            ProtocolParams::try_from(response),
+            Err(WalletError::ProtocolParams(_))
+        ));
+    }
+
+    #[test]
+    fn test_cli_protocol_params() {
+        let json = r#"{
+            "txFeePerByte": 44,
+            "txFeeFixed": 155381,
+            "maxTxSize": 16384,
+            "utxoCostPerByte": 4310,
+            "stakeAddressDeposit": 2000000,
+            "stakePoolDeposit": 500000000,
+            "executionUnitPrices": { "priceMemory": 0.0577, "priceSteps": 0.0000721 },
+            "maxTxExecutionUnits": { "memory": 14000000, "steps": 10000000000 },
+            "collateralPercentage": 150,
+            "maxCollateralInputs": 3,
+            "minFeeRefScriptCostPerByte": 15
+        }"#;
+        let params: ProtocolParams = serde_json::from_str::<CliProtocolParams>(json)
+            .unwrap()
+            .into();
+
+        assert_eq!(params.epoch, None);
+        assert_eq!(params.min_fee_b, 155381);
+        assert_eq!(params.key_deposit, 2_000_000);
+        assert_eq!(params.ex_unit_prices.mem, 0.0577);
+    }
+
+    #[test]
+    fn test_min_collateral_rounds_up() {
+        let json = r#"{
+            "txFeePerByte": 44, "txFeeFixed": 155381, "maxTxSize": 16384,
+            "utxoCostPerByte": 4310, "stakeAddressDeposit": 2000000,
+            "stakePoolDeposit": 500000000,
+            "executionUnitPrices": { "priceMemory": 0.0577, "priceSteps": 0.0000721 },
+            "maxTxExecutionUnits": { "memory": 14000000, "steps": 10000000000 },
+            "collateralPercentage": 150, "maxCollateralInputs": 3
+        }"#;
+        let params: ProtocolParams = serde_json::from_str::<CliProtocolParams>(json)
+            .unwrap()
+            .into();
+
+        assert_eq!(params.min_collateral(200_001), 300_002);
+        assert_eq!(params.min_collateral(200_003), 300_005);
+    }
+}
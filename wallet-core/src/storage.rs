@@ -1,10 +1,37 @@
 //! Browser localStorage persistence for wallet state
+//!
+//! With the `native` feature the last wallet is kept in memory per thread.
 
 use crate::types::WalletProvider;
 
+#[cfg(not(feature = "native"))]
 const STORAGE_KEY: &str = "shared_ui_wallet";
 
+#[cfg(feature = "native")]
+thread_local! {
+    static LAST_WALLET: std::cell::Cell<Option<WalletProvider>> = const { std::cell::Cell::new(None) };
+}
+
+/// Save the last connected wallet provider
+#[cfg(feature = "native")]
+pub fn save_last_wallet(provider: WalletProvider) {
+    LAST_WALLET.set(Some(provider));
+}
+
+/// Load the last connected wallet provider
+#[cfg(feature = "native")]
+pub fn load_last_wallet() -> Option<WalletProvider> {
+    LAST_WALLET.get()
+}
+
+/// Clear the saved wallet
+#[cfg(feature = "native")]
+pub fn clear_last_wallet() {
+    LAST_WALLET.set(None);
+}
+
 /// Save the last connected wallet provider
+#[cfg(not(feature = "native"))]
 pub fn save_last_wallet(provider: WalletProvider) {
     if let Some(storage) = get_storage() {
         let _ = storage.set_item(STORAGE_KEY, provider.api_name());
@@ -12,6 +39,7 @@ pub fn save_last_wallet(provider: WalletProvider) {
 }
 
 /// Load the last connected wallet provider
+#[cfg(not(feature = "native"))]
 pub fn load_last_wallet() -> Option<WalletProvider> {
     let storage = get_storage()?;
     let name = storage.get_item(STORAGE_KEY).ok()??;
@@ -31,12 +59,14 @@ pub fn load_last_wallet() -> Option<WalletProvider> {
 }
 
 /// Clear the saved wallet
+#[cfg(not(feature = "native"))]
 pub fn clear_last_wallet() {
     if let Some(storage) = get_storage() {
         let _ = storage.remove_item(STORAGE_KEY);
     }
 }
 
+#[cfg(not(feature = "native"))]
 fn get_storage() -> Option<web_sys::Storage> {
     web_sys::window()?.local_storage().ok()?
 }
@@ -0,0 +1,99 @@
+//! A single wallet connection held by the context
+
+use crate::message::{stamp, SignedMessage};
+use send_wrapper::SendWrapper;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wallet_core::{DataSignature, Network, WalletApi, WalletError, WalletProvider};
+use wallet_pallas::SignMessage;
+
+/// Shared handle to a connected wallet's CIP-30 API
+pub(crate) type ApiHandle = SendWrapper<Rc<RefCell<WalletApi>>>;
+
+/// A wallet the context is connected to
+///
+/// The context can hold several of these at once (see
+/// [`WalletContext::connections`](crate::WalletContext::connections)), e.g. to
+/// prove ownership from a cold wallet while paying from a hot one. Each
+/// connection can sign independently of which one is active.
+#[derive(Clone)]
+pub struct ConnectedWallet {
+    /// Wallet extension
+    pub provider: WalletProvider,
+    /// Change address (hex-encoded)
+    pub address: String,
+    /// Network the wallet is on
+    pub network: Network,
+    api: ApiHandle,
+}
+
+impl PartialEq for ConnectedWallet {
+    fn eq(&self, other: &Self) -> bool {
+        self.provider == other.provider
+            && self.address == other.address
+            && self.network == other.network
+    }
+}
+
+impl std::fmt::Debug for ConnectedWallet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectedWallet")
+            .field("provider", &self.provider)
+            .field("address", &self.address)
+            .field("network", &self.network)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ConnectedWallet {
+    pub(crate) fn new(
+        provider: WalletProvider,
+        address: String,
+        network: Network,
+        api: WalletApi,
+    ) -> Self {
+        Self {
+            provider,
+            address,
+            network,
+            api: SendWrapper::new(Rc::new(RefCell::new(api))),
+        }
+    }
+
+    pub(crate) fn handle(&self) -> ApiHandle {
+        self.api.clone()
+    }
+
+    /// Clone of the underlying API for direct CIP-30 calls
+    pub fn api(&self) -> WalletApi {
+        self.api.borrow().clone()
+    }
+
+    /// Sign arbitrary data with this wallet's address using CIP-8
+    pub async fn sign_data(&self, payload_hex: &str) -> Result<DataSignature, WalletError> {
+        self.api().sign_data(&self.address, payload_hex).await
+    }
+
+    /// Sign a structured message with this wallet's address
+    ///
+    /// The message's address and `issued_at` are overwritten before signing.
+    pub async fn sign_message(&self, message: SignMessage) -> Result<SignedMessage, WalletError> {
+        let message = stamp(message, &self.address)?;
+        let signature = self.sign_data(&message.to_hex()).await?;
+        Ok(SignedMessage { message, signature })
+    }
+
+    /// Sign a transaction with this wallet
+    ///
+    /// Returns the witness set hex.
+    pub async fn sign_tx(&self, tx_hex: &str, partial_sign: bool) -> Result<String, WalletError> {
+        self.api().sign_tx(tx_hex, partial_sign).await
+    }
+
+    /// Submit a signed transaction through this wallet
+    ///
+    /// Returns the transaction hash.
+    pub async fn submit_tx(&self, tx_hex: &str) -> Result<String, WalletError> {
+        self.api().submit_tx(tx_hex).await
+    }
+}
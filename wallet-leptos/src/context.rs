@@ -1,24 +1,46 @@
 //! Reactive wallet context for Leptos applications
 
+use crate::connection::{ApiHandle, ConnectedWallet};
+use crate::idle::ExpiryReason;
+use crate::message::{stamp, SignedMessage};
+use crate::resource::WalletResource;
 use leptos::prelude::*;
-use send_wrapper::SendWrapper;
-use std::cell::RefCell;
-use std::rc::Rc;
-use wallet_core::{ConnectionState, Network, WalletApi, WalletError, WalletInfo, WalletProvider};
-use wallet_pallas::WalletBalance;
+use ui_core::FetchState;
+use wallet_core::{
+    CancelHandle, ConnectionState, Network, WalletApi, WalletError, WalletInfo, WalletProvider,
+    WalletWatcher, DEFAULT_DETECT_TIMEOUT_MS, DEFAULT_WATCH_MS,
+};
+use wallet_pallas::{SignMessage, Utxo, WalletBalance};
 use wasm_bindgen_futures::spawn_local;
 
+/// Whether browser APIs are available
+///
+/// False in `ssr` builds: the server always renders the wallet as
+/// Disconnected with no detected wallets, which is also the client's initial
+/// state, so hydration matches. Detection and reconnect run afterwards in a
+/// client-side Effect.
+pub(crate) const fn in_browser() -> bool {
+    !cfg!(feature = "ssr")
+}
+
 /// Reactive wallet context providing signals for wallet state
 ///
 /// Use with `WalletProvider` component and `use_wallet()` hook.
 #[derive(Clone)]
 pub struct WalletContext {
-    /// Current connection state
+    /// Current connection state (of the active wallet)
     pub connection_state: RwSignal<ConnectionState>,
 
     /// Available wallet extensions detected in browser
     pub available_wallets: RwSignal<Vec<WalletInfo>>,
 
+    /// All connected wallets, in connection order
+    pub connections: RwSignal<Vec<ConnectedWallet>>,
+
+    /// Provider of the active connection, which `address`, `network`,
+    /// `connection_state`, the resources and the signing methods refer to
+    pub active: RwSignal<Option<WalletProvider>>,
+
     /// Current connected address (hex-encoded)
     pub address: RwSignal<Option<String>>,
 
@@ -28,6 +50,12 @@ pub struct WalletContext {
     /// Wallet balance (opt-in, call fetch_balance to populate)
     pub balance: RwSignal<Option<WalletBalance>>,
 
+    /// Wallet UTxOs (lazy, fetched on first read)
+    pub utxos: WalletResource<Vec<Utxo>>,
+
+    /// Wallet assets summed from the UTxO set (lazy, fetched on first read)
+    pub assets: WalletResource<WalletBalance>,
+
     /// Derived stake address (bech32)
     pub stake_address: Memo<Option<String>>,
 
@@ -37,8 +65,20 @@ pub struct WalletContext {
     /// Last error message
     pub error: RwSignal<Option<String>>,
 
-    /// Internal: connected wallet API handle
-    api: RwSignal<Option<SendWrapper<Rc<RefCell<WalletApi>>>>>,
+    /// Why the last session was ended, until the next successful connect
+    pub session_expired: RwSignal<Option<ExpiryReason>>,
+
+    /// Internal: active wallet API handle
+    api: RwSignal<Option<ApiHandle>>,
+
+    /// Internal: cancels the in-flight `connect`, if any
+    pending_connect: StoredValue<Option<CancelHandle>>,
+
+    /// Internal: keeps `available_wallets` updated as extensions inject late
+    wallet_watcher: StoredValue<Option<WalletWatcher>>,
+
+    /// Internal: notified when a session expires
+    on_session_expired: StoredValue<Option<Callback<ExpiryReason>>>,
 }
 
 impl WalletContext {
@@ -56,78 +96,261 @@ impl WalletContext {
             })
         });
 
+        let api: RwSignal<Option<ApiHandle>> = RwSignal::new(None);
+
+        // UTxOs and assets come from the same wallet call, so they share a
+        // fetch: reading either one loads both
+        let requested = RwSignal::new(false);
+        let trigger = RwSignal::new(0u32);
+        let utxos = WalletResource::new(requested, trigger);
+        let assets = WalletResource::new(requested, trigger);
+        let generation = StoredValue::new(0u32);
+
+        Effect::new(move |_| {
+            trigger.track();
+            if !requested.get() || !in_browser() {
+                return;
+            }
+
+            let current = generation.get_value().wrapping_add(1);
+            generation.set_value(current);
+
+            let Some(api_wrapper) = api.get() else {
+                utxos.state.set(FetchState::Idle);
+                assets.state.set(FetchState::Idle);
+                return;
+            };
+
+            // Clone the api handle to avoid holding RefCell borrow across await
+            let api = api_wrapper.borrow().clone();
+            utxos.state.set(FetchState::Loading);
+            assets.state.set(FetchState::Loading);
+
+            spawn_local(async move {
+                let result = fetch_utxos(&api).await;
+
+                // A newer fetch or a disconnect superseded this one
+                if generation.get_value() != current {
+                    return;
+                }
+
+                match result {
+                    Ok(decoded) => {
+                        assets
+                            .state
+                            .set(FetchState::Loaded(wallet_pallas::sum_utxos(&decoded)));
+                        utxos.state.set(FetchState::Loaded(decoded));
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to fetch UTxOs: {e}");
+                        utxos.state.set(FetchState::Failed(e.to_string()));
+                        assets.state.set(FetchState::Failed(e.to_string()));
+                    }
+                }
+            });
+        });
+
         Self {
             connection_state: RwSignal::new(ConnectionState::Disconnected),
             available_wallets: RwSignal::new(vec![]),
+            connections: RwSignal::new(vec![]),
+            active: RwSignal::new(None),
             address,
             network: RwSignal::new(None),
             balance: RwSignal::new(None),
+            utxos,
+            assets,
             stake_address,
             loading: RwSignal::new(false),
             error: RwSignal::new(None),
-            api: RwSignal::new(None),
+            session_expired: RwSignal::new(None),
+            api,
+            pending_connect: StoredValue::new(None),
+            wallet_watcher: StoredValue::new(None),
+            on_session_expired: StoredValue::new(None),
         }
     }
 
     /// Connect to a wallet provider
+    ///
+    /// The new connection is added to `connections` and becomes active.
+    /// Existing connections stay open; reconnecting an already connected
+    /// provider replaces its entry.
     pub fn connect(&self, provider: WalletProvider) {
+        if !in_browser() {
+            return;
+        }
+        // Only one enable prompt at a time
+        self.cancel_connect();
+
         let ctx = self.clone();
-        ctx.connection_state.set(ConnectionState::Connecting);
+        // Keep showing the active wallet while another one connects
+        let has_active = ctx.active.get_untracked().is_some();
+        if !has_active {
+            ctx.connection_state.set(ConnectionState::Connecting);
+        }
         ctx.loading.set(true);
         ctx.error.set(None);
 
+        let (connecting, handle) = wallet_core::cancellable(connect_wallet(provider));
+        ctx.pending_connect.set_value(Some(handle));
+
         spawn_local(async move {
-            match WalletApi::connect(provider).await {
-                Ok(api) => {
-                    // Get network and address
-                    let network_id = api.network_id().await.unwrap_or(1);
-                    let network = match network_id {
-                        1 => Network::Mainnet,
-                        _ => Network::Preprod,
-                    };
-
-                    let address = api.change_address().await.ok();
-
-                    // Store API handle
-                    ctx.api
-                        .set(Some(SendWrapper::new(Rc::new(RefCell::new(api)))));
-
-                    // Update state
-                    ctx.network.set(Some(network));
-                    ctx.address.set(address.clone());
-                    ctx.connection_state.set(ConnectionState::Connected {
-                        provider,
-                        address: address.unwrap_or_default(),
-                        network,
-                    });
+            let result = connecting.await;
+            if matches!(result, Err(WalletError::Cancelled)) {
+                // cancel_connect already restored the state
+                return;
+            }
+            ctx.pending_connect.set_value(None);
 
-                    // Save to localStorage for auto-reconnect
-                    wallet_core::save_last_wallet(provider);
+            match result {
+                Ok(wallet) => {
+                    ctx.connections.update(|connections| {
+                        connections.retain(|w| w.provider != provider);
+                        connections.push(wallet);
+                    });
+                    // Activating also saves it to localStorage for auto-reconnect
+                    ctx.set_active(provider);
+                    ctx.session_expired.set(None);
                 }
                 Err(e) => {
                     ctx.error.set(Some(e.to_string()));
-                    ctx.connection_state
-                        .set(ConnectionState::Error(e.to_string()));
+                    if !has_active {
+                        ctx.connection_state
+                            .set(ConnectionState::Error(e.to_string()));
+                    }
                 }
             }
             ctx.loading.set(false);
         });
     }
 
-    /// Disconnect from current wallet
+    /// Abandon an in-flight `connect`
+    ///
+    /// For when the wallet's enable prompt was dismissed without answering.
+    /// Existing connections are unaffected.
+    pub fn cancel_connect(&self) {
+        let mut pending = None;
+        self.pending_connect.update_value(|p| pending = p.take());
+        let Some(handle) = pending else {
+            return;
+        };
+
+        handle.cancel();
+        self.loading.set(false);
+        if self.active.get_untracked().is_none() {
+            self.connection_state.set(ConnectionState::Disconnected);
+        }
+    }
+
+    /// Disconnect from all wallets
     pub fn disconnect(&self) {
-        self.api.set(None);
-        self.address.set(None);
-        self.network.set(None);
+        self.connections.set(vec![]);
+        self.active.set(None);
+        self.apply_active(None);
+        if in_browser() {
+            wallet_core::clear_last_wallet();
+        }
+    }
+
+    /// End the session: disconnect every wallet and notify `on_session_expired`
+    ///
+    /// Cached balance, UTxOs and assets are cleared and auto-reconnect is
+    /// forgotten, as with [`disconnect`](Self::disconnect). Called by the idle
+    /// policy; apps can also call it when their own session ends.
+    pub fn expire_session(&self, reason: ExpiryReason) {
+        self.cancel_connect();
+        self.disconnect();
+        self.session_expired.set(Some(reason));
+        if let Some(callback) = self.on_session_expired.get_value() {
+            callback.run(reason);
+        }
+    }
+
+    /// Set the callback run by [`expire_session`](Self::expire_session)
+    pub(crate) fn set_on_session_expired(&self, callback: Option<Callback<ExpiryReason>>) {
+        self.on_session_expired.set_value(callback);
+    }
+
+    /// Disconnect a single wallet
+    ///
+    /// If it was active, the earliest remaining connection becomes active.
+    pub fn disconnect_wallet(&self, provider: WalletProvider) {
+        self.connections
+            .update(|connections| connections.retain(|w| w.provider != provider));
+
+        if self.active.get_untracked() != Some(provider) {
+            return;
+        }
+        match self
+            .connections
+            .with_untracked(|c| c.first().map(|w| w.provider))
+        {
+            Some(next) => self.set_active(next),
+            None => self.disconnect(),
+        }
+    }
+
+    /// Make an existing connection the active one
+    ///
+    /// Does nothing if `provider` isn't connected.
+    pub fn set_active(&self, provider: WalletProvider) {
+        let Some(wallet) = self.connection(provider) else {
+            tracing::warn!("Cannot activate {provider:?}: not connected");
+            return;
+        };
+        self.active.set(Some(provider));
+        self.apply_active(Some(wallet));
+        if in_browser() {
+            wallet_core::save_last_wallet(provider);
+        }
+    }
+
+    /// Look up the connection for a provider
+    pub fn connection(&self, provider: WalletProvider) -> Option<ConnectedWallet> {
+        self.connections
+            .with_untracked(|c| c.iter().find(|w| w.provider == provider).cloned())
+    }
+
+    /// The active connection, if any (tracked)
+    pub fn active_connection(&self) -> Option<ConnectedWallet> {
+        let provider = self.active.get()?;
+        self.connections
+            .with(|c| c.iter().find(|w| w.provider == provider).cloned())
+    }
+
+    /// Point the single-wallet signals at `wallet`
+    fn apply_active(&self, wallet: Option<ConnectedWallet>) {
+        // Balance was fetched for the previous wallet
         self.balance.set(None);
-        self.connection_state.set(ConnectionState::Disconnected);
-        wallet_core::clear_last_wallet();
+        match wallet {
+            Some(w) => {
+                self.api.set(Some(w.handle()));
+                self.network.set(Some(w.network));
+                self.address
+                    .set(Some(w.address.clone()).filter(|a| !a.is_empty()));
+                self.connection_state.set(ConnectionState::Connected {
+                    provider: w.provider,
+                    address: w.address,
+                    network: w.network,
+                });
+            }
+            None => {
+                self.api.set(None);
+                self.address.set(None);
+                self.network.set(None);
+                self.connection_state.set(ConnectionState::Disconnected);
+            }
+        }
     }
 
     /// Fetch balance from connected wallet
     ///
     /// This is opt-in - call this method to populate the `balance` signal.
     pub fn fetch_balance(&self) {
+        if !in_browser() {
+            return;
+        }
         let ctx = self.clone();
 
         spawn_local(async move {
@@ -149,20 +372,41 @@ impl WalletContext {
     }
 
     /// Attempt auto-reconnect from localStorage
+    ///
+    /// Waits briefly for the saved wallet's extension, which may inject
+    /// after the page loads.
     pub fn try_reconnect(&self) {
+        if !in_browser() {
+            return;
+        }
         if let Some(provider) = wallet_core::load_last_wallet() {
-            // Check if wallet is still available
-            let available = wallet_core::detect_wallets();
-            if available.contains(&provider) {
-                self.connect(provider);
-            }
+            let ctx = self.clone();
+            spawn_local(async move {
+                // Check if wallet is still available
+                if wallet_core::wait_for_wallet(provider, DEFAULT_DETECT_TIMEOUT_MS).await {
+                    ctx.connect(provider);
+                }
+            });
         }
     }
 
     /// Refresh available wallets
+    ///
+    /// Keeps polling for [`DEFAULT_WATCH_MS`] afterwards so extensions that
+    /// inject late still show up without a page refresh.
     pub fn detect_wallets(&self) {
+        if !in_browser() {
+            return;
+        }
         let wallets = wallet_core::detect_wallets_with_info();
         self.available_wallets.set(wallets);
+
+        let available_wallets = self.available_wallets;
+        let watcher = wallet_core::watch_wallets(DEFAULT_WATCH_MS, move |wallets| {
+            available_wallets.try_set(wallets);
+        });
+        // Replacing an earlier watcher drops (and stops) it
+        self.wallet_watcher.set_value(Some(watcher));
     }
 
     /// Check if connected
@@ -203,6 +447,29 @@ impl WalletContext {
         api.sign_data(&address, payload_hex).await
     }
 
+    /// Sign a structured message (login, account link, vote) using CIP-8
+    ///
+    /// The message's address and `issued_at` are overwritten with the active
+    /// wallet's address and the current time before signing.
+    pub async fn sign_message(&self, message: SignMessage) -> Result<SignedMessage, WalletError> {
+        let api_wrapper = self
+            .api
+            .get()
+            .ok_or_else(|| WalletError::NotEnabled("Not connected".into()))?;
+
+        let address = self
+            .address
+            .get()
+            .ok_or_else(|| WalletError::NotEnabled("No address".into()))?;
+
+        let message = stamp(message, &address)?;
+
+        // Clone to avoid holding RefCell borrow across await
+        let api = api_wrapper.borrow().clone();
+        let signature = api.sign_data(&address, &message.to_hex()).await?;
+        Ok(SignedMessage { message, signature })
+    }
+
     /// Sign a transaction
     ///
     /// Returns the witness set hex.
@@ -232,8 +499,101 @@ impl WalletContext {
     }
 }
 
+/// Enable a wallet and read its network and change address
+async fn connect_wallet(provider: WalletProvider) -> Result<ConnectedWallet, WalletError> {
+    let api = WalletApi::connect(provider).await?;
+
+    let network = match api.network_id().await.unwrap_or(1) {
+        1 => Network::Mainnet,
+        _ => Network::Preprod,
+    };
+    let address = api.change_address().await.unwrap_or_default();
+
+    Ok(ConnectedWallet::new(provider, address, network, api))
+}
+
+/// Fetch and decode every UTxO held by the wallet
+async fn fetch_utxos(api: &WalletApi) -> Result<Vec<Utxo>, WalletError> {
+    api.utxos()
+        .await?
+        .iter()
+        .map(|hex| {
+            wallet_pallas::decode_utxo(hex)
+                .map_err(|e| WalletError::ApiError(format!("Invalid UTxO: {e}")))
+        })
+        .collect()
+}
+
 impl Default for WalletContext {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_server_render_stays_disconnected() {
+        Owner::new().with(|| {
+            let ctx = WalletContext::new();
+
+            // None of these may touch `window` on the server
+            ctx.detect_wallets();
+            ctx.try_reconnect();
+            ctx.connect(WalletProvider::Eternl);
+            ctx.fetch_balance();
+            ctx.cancel_connect();
+            ctx.set_active(WalletProvider::Eternl);
+            ctx.disconnect_wallet(WalletProvider::Eternl);
+            ctx.disconnect();
+            ctx.expire_session(ExpiryReason::Manual);
+
+            assert_eq!(
+                ctx.connection_state.get_untracked(),
+                ConnectionState::Disconnected
+            );
+            assert!(ctx.available_wallets.get_untracked().is_empty());
+            assert!(ctx.connections.get_untracked().is_empty());
+            assert!(ctx.active.get_untracked().is_none());
+            assert!(ctx.address.get_untracked().is_none());
+            assert!(!ctx.loading.get_untracked());
+            assert_eq!(
+                ctx.session_expired.get_untracked(),
+                Some(ExpiryReason::Manual)
+            );
+        });
+    }
+
+    #[test]
+    fn test_server_render_resources_stay_idle() {
+        Owner::new().with(|| {
+            let ctx = WalletContext::new();
+            assert!(ctx.utxos.state().is_idle());
+            assert!(ctx.assets.data().is_none());
+            ctx.utxos.refetch();
+            assert!(ctx.utxos.state.get_untracked().is_idle());
+        });
+    }
+
+    #[test]
+    fn test_server_render_signing_fails_cleanly() {
+        let ctx = Owner::new().with(WalletContext::new);
+        futures::executor::block_on(async {
+            assert!(matches!(
+                ctx.sign_data("48656c6c6f").await,
+                Err(WalletError::NotEnabled(_))
+            ));
+            assert!(matches!(
+                ctx.sign_message(SignMessage::login("example.com", "n"))
+                    .await,
+                Err(WalletError::NotEnabled(_))
+            ));
+            assert!(matches!(
+                ctx.sign_tx("84a0", false).await,
+                Err(WalletError::NotEnabled(_))
+            ));
+        });
+    }
+}
@@ -0,0 +1,219 @@
+//! Idle auto-disconnect
+//!
+//! For shared and kiosk-style screens, [`WalletProvider`](crate::WalletProvider)
+//! can disconnect the wallet after a period without input, so the next person
+//! at the screen doesn't inherit the previous session. Disconnecting clears
+//! the cached balance, UTxOs and assets and forgets the auto-reconnect wallet.
+//!
+//! Pointer, keyboard, wheel and touch input count as activity. While the tab
+//! is hidden an optional, usually shorter, timeout applies instead.
+//!
+//! ```ignore
+//! <WalletProvider
+//!     idle=IdlePolicy::after_ms(5 * 60_000).hidden_after_ms(60_000)
+//!     on_session_expired=move |reason| show_reconnect_prompt(reason)
+//! >
+//!     <App />
+//! </WalletProvider>
+//! ```
+
+use crate::context::WalletContext;
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// Events that count as user activity
+const ACTIVITY_EVENTS: [&str; 5] = [
+    "pointerdown",
+    "pointermove",
+    "keydown",
+    "wheel",
+    "touchstart",
+];
+
+/// Longest gap between idle checks
+const MAX_CHECK_INTERVAL_MS: u32 = 1_000;
+
+/// Why a session was ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiryReason {
+    /// No input for the idle timeout
+    Idle,
+    /// The tab stayed hidden for the hidden timeout
+    Hidden,
+    /// Ended by the app via [`WalletContext::expire_session`]
+    Manual,
+}
+
+/// When to disconnect an idle wallet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdlePolicy {
+    /// Disconnect after this long without input
+    pub timeout_ms: u32,
+    /// Disconnect after the tab has been hidden this long, if set
+    pub hidden_timeout_ms: Option<u32>,
+}
+
+impl IdlePolicy {
+    /// Disconnect after `timeout_ms` without input
+    pub fn after_ms(timeout_ms: u32) -> Self {
+        Self {
+            timeout_ms,
+            hidden_timeout_ms: None,
+        }
+    }
+
+    /// Also disconnect once the tab has been hidden for `timeout_ms`
+    pub fn hidden_after_ms(mut self, timeout_ms: u32) -> Self {
+        self.hidden_timeout_ms = Some(timeout_ms);
+        self
+    }
+
+    /// How often to check: a quarter of the shortest timeout, at most 1s
+    fn check_interval_ms(&self) -> u32 {
+        let shortest = self
+            .hidden_timeout_ms
+            .map_or(self.timeout_ms, |h| h.min(self.timeout_ms));
+        (shortest / 4).clamp(1, MAX_CHECK_INTERVAL_MS)
+    }
+}
+
+/// Activity timestamps (ms since epoch) checked against an [`IdlePolicy`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct IdleTracker {
+    last_activity: f64,
+    hidden_since: Option<f64>,
+}
+
+impl IdleTracker {
+    fn new(now: f64) -> Self {
+        Self {
+            last_activity: now,
+            hidden_since: None,
+        }
+    }
+
+    fn activity(&mut self, now: f64) {
+        self.last_activity = now;
+    }
+
+    fn visibility(&mut self, hidden: bool, now: f64) {
+        if hidden {
+            self.hidden_since.get_or_insert(now);
+        } else {
+            self.hidden_since = None;
+            // Coming back to the tab counts as activity
+            self.last_activity = now;
+        }
+    }
+
+    fn expired(&self, policy: &IdlePolicy, now: f64) -> Option<ExpiryReason> {
+        let hidden_expired = policy
+            .hidden_timeout_ms
+            .zip(self.hidden_since)
+            .is_some_and(|(timeout, since)| now - since >= f64::from(timeout));
+        if hidden_expired {
+            Some(ExpiryReason::Hidden)
+        } else if now - self.last_activity >= f64::from(policy.timeout_ms) {
+            Some(ExpiryReason::Idle)
+        } else {
+            None
+        }
+    }
+}
+
+/// Disconnect `ctx` when it goes idle under `policy`
+///
+/// Listeners and the check interval are removed when the current owner is
+/// cleaned up. Idle time only accrues while a wallet is connected.
+pub(crate) fn watch_idle(ctx: WalletContext, policy: IdlePolicy) {
+    let tracker = StoredValue::new(IdleTracker::new(js_sys::Date::now()));
+
+    for event in ACTIVITY_EVENTS {
+        let handle = window_event_listener_untyped(event, move |_| {
+            tracker.update_value(|t| t.activity(js_sys::Date::now()));
+        });
+        on_cleanup(move || handle.remove());
+    }
+
+    let visibility = window_event_listener_untyped("visibilitychange", move |_| {
+        let hidden = document().hidden();
+        tracker.update_value(|t| t.visibility(hidden, js_sys::Date::now()));
+    });
+    on_cleanup(move || visibility.remove());
+
+    if let Ok(handle) = set_interval_with_handle(
+        move || {
+            let now = js_sys::Date::now();
+            if ctx.active.get_untracked().is_none() {
+                // Nothing to expire; start counting from the next connect
+                tracker.update_value(|t| t.activity(now));
+                return;
+            }
+            if let Some(reason) = tracker.with_value(|t| t.expired(&policy, now)) {
+                tracing::info!("Wallet session expired: {reason:?}");
+                ctx.expire_session(reason);
+                tracker.update_value(|t| t.activity(now));
+            }
+        },
+        Duration::from_millis(u64::from(policy.check_interval_ms())),
+    ) {
+        on_cleanup(move || handle.clear());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idle_timeout() {
+        let policy = IdlePolicy::after_ms(1_000);
+        let mut tracker = IdleTracker::new(0.0);
+
+        assert_eq!(tracker.expired(&policy, 999.0), None);
+        assert_eq!(tracker.expired(&policy, 1_000.0), Some(ExpiryReason::Idle));
+
+        tracker.activity(800.0);
+        assert_eq!(tracker.expired(&policy, 1_500.0), None);
+        assert_eq!(tracker.expired(&policy, 1_800.0), Some(ExpiryReason::Idle));
+    }
+
+    #[test]
+    fn test_hidden_timeout() {
+        let policy = IdlePolicy::after_ms(10_000).hidden_after_ms(2_000);
+        let mut tracker = IdleTracker::new(0.0);
+
+        tracker.visibility(true, 1_000.0);
+        // A second hidden event keeps the original start
+        tracker.visibility(true, 2_000.0);
+        assert_eq!(tracker.expired(&policy, 2_999.0), None);
+        assert_eq!(
+            tracker.expired(&policy, 3_000.0),
+            Some(ExpiryReason::Hidden)
+        );
+
+        // Returning resets both clocks
+        tracker.visibility(false, 2_500.0);
+        assert_eq!(tracker.hidden_since, None);
+        assert_eq!(tracker.expired(&policy, 12_000.0), None);
+
+        // Without a hidden timeout, hidden time is just idle time
+        let policy = IdlePolicy::after_ms(10_000);
+        tracker.visibility(true, 3_000.0);
+        assert_eq!(tracker.expired(&policy, 12_000.0), None);
+        assert_eq!(tracker.expired(&policy, 12_500.0), Some(ExpiryReason::Idle));
+    }
+
+    #[test]
+    fn test_check_interval() {
+        assert_eq!(IdlePolicy::after_ms(300_000).check_interval_ms(), 1_000);
+        assert_eq!(IdlePolicy::after_ms(2_000).check_interval_ms(), 500);
+        assert_eq!(
+            IdlePolicy::after_ms(300_000)
+                .hidden_after_ms(400)
+                .check_interval_ms(),
+            100
+        );
+        assert_eq!(IdlePolicy::after_ms(0).check_interval_ms(), 1);
+    }
+}
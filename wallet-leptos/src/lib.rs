@@ -70,6 +70,11 @@
 //!                 <p>{format!("{:.6} ADA", b.ada())}</p>
 //!             })}
 //!
+//!             // Show asset count (fetched lazily on first read)
+//!             {move || wallet.assets.data().map(|a| view! {
+//!                 <p>{a.token_count()} " tokens"</p>
+//!             })}
+//!
 //!             // Show stake address (derived automatically)
 //!             {move || wallet.stake_address.get().map(|sa| view! {
 //!                 <p>"Stake: " {sa}</p>
@@ -82,19 +87,47 @@
 //! ## Features
 //!
 //! - **Reactive signals** for all wallet state (connection, address, network, balance)
+//! - **Multiple simultaneous connections** (`wallet.connections`) with an active selection,
+//!   e.g. prove ownership from a cold wallet and pay from a hot one
 //! - **Auto-detection** of installed wallet extensions
 //! - **Auto-reconnect** via localStorage persistence
+//! - **Connect timeout and cancellation**: a dismissed enable prompt fails with
+//!   `WalletError::Timeout`, or call `wallet.cancel_connect()` to give up sooner
 //! - **Derived stake address** computed from payment address
 //! - **Opt-in balance fetching** to avoid unnecessary API calls
+//! - **Lazy UTxO and asset resources** (`wallet.utxos`, `wallet.assets`) with
+//!   loading/error states, cached until `refetch()` or a reconnect
 //! - **Signing methods** for CIP-8 data signing and transaction signing
+//! - **Structured messages** (`wallet.sign_message`) for login, Discord linking and
+//!   votes, stamped with address and time and verifiable server-side
+//! - **SSR/hydration safe**: enable the `ssr` feature on the server and `hydrate` on the
+//!   client; the server renders Disconnected and never touches `window`
+//! - **Idle auto-disconnect** (`idle=IdlePolicy::after_ms(..)` on `WalletProvider`) for
+//!   kiosk-style screens, with `on_session_expired` for a reconnect prompt
+//! - **Wallet-gated content** (`RequireWallet`) with a connect prompt for the
+//!   detected wallets, or a custom fallback
+//! - **Signing buttons** (`SignDataButton`, `SignTxButton`) with pending/success/error
+//!   render props and error toasts
 
+mod connection;
 mod context;
 mod hooks;
+mod idle;
+mod message;
 mod provider;
+mod require_wallet;
+mod resource;
+mod sign_button;
 
+pub use connection::ConnectedWallet;
 pub use context::WalletContext;
 pub use hooks::{try_use_wallet, use_wallet};
+pub use idle::{ExpiryReason, IdlePolicy};
+pub use message::SignedMessage;
 pub use provider::WalletProvider;
+pub use require_wallet::RequireWallet;
+pub use resource::WalletResource;
+pub use sign_button::{SignDataButton, SignTxButton};
 
 // Re-export commonly used types from wallet-core
 pub use wallet_core::{
@@ -103,4 +136,12 @@ pub use wallet_core::{
 };
 
 // Re-export balance types from wallet-pallas
-pub use wallet_pallas::{decode_balance, NativeToken, PolicyGroup, WalletBalance};
+pub use wallet_pallas::{
+    decode_balance, decode_utxo, sum_utxos, NativeToken, PolicyGroup, Utxo, WalletBalance,
+};
+
+// Re-export message templates from wallet-pallas
+pub use wallet_pallas::{MessageAction, SignMessage};
+
+// Re-export fetch state for resource consumers
+pub use ui_core::FetchState;
@@ -0,0 +1,36 @@
+//! Signing structured messages
+//!
+//! [`WalletContext::sign_message`](crate::WalletContext::sign_message) and
+//! [`ConnectedWallet::sign_message`](crate::ConnectedWallet::sign_message)
+//! stamp a [`SignMessage`] with the wallet's address and the current time,
+//! then sign its canonical text with CIP-8. The backend verifies the result
+//! with `wallet_pallas::verify_signed_message`.
+//!
+//! ```ignore
+//! let nonce = api::login_challenge().await?;
+//! let signed = wallet
+//!     .sign_message(SignMessage::login("raffle.example", nonce).valid_for(300))
+//!     .await?;
+//! api::login(&signed.signature.signature, &signed.signature.key).await?;
+//! ```
+
+use wallet_core::{DataSignature, WalletError};
+use wallet_pallas::{Address, SignMessage};
+
+/// A message and the wallet's signature over it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedMessage {
+    /// The message as signed, with address and time filled in
+    pub message: SignMessage,
+    /// COSE signature and key from `signData`
+    pub signature: DataSignature,
+}
+
+/// Fill in the signer's address (from CIP-30 hex) and the current time
+pub(crate) fn stamp(message: SignMessage, address_hex: &str) -> Result<SignMessage, WalletError> {
+    let address = Address::from_hex(address_hex)
+        .and_then(|a| a.to_bech32())
+        .map_err(|e| WalletError::SigningFailed(e.to_string()))?;
+    let now_secs = (js_sys::Date::now() / 1000.0) as u64;
+    Ok(message.address(address).issued_at(now_secs))
+}
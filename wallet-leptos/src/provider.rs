@@ -1,6 +1,7 @@
 //! WalletProvider component for providing wallet context
 
-use crate::context::WalletContext;
+use crate::context::{in_browser, WalletContext};
+use crate::idle::{watch_idle, ExpiryReason, IdlePolicy};
 use leptos::prelude::*;
 
 /// Provides wallet context to child components
@@ -8,6 +9,14 @@ use leptos::prelude::*;
 /// Wrap your app (or a section of it) with this component to enable
 /// wallet functionality via `use_wallet()`.
 ///
+/// Safe to use in SSR apps: wallet detection and auto-reconnect run in a
+/// client-side Effect after hydration, and with the `ssr` feature the server
+/// always renders the Disconnected state.
+///
+/// Pass `idle` to disconnect automatically after a period without input
+/// (see [`IdlePolicy`]); `on_session_expired` then runs so the app can prompt
+/// the user to reconnect.
+///
 /// # Example
 ///
 /// ```ignore
@@ -32,10 +41,23 @@ pub fn WalletProvider(
     #[prop(optional, default = true)]
     auto_reconnect: bool,
 
+    /// Disconnect after a period without user input
+    #[prop(optional)]
+    idle: Option<IdlePolicy>,
+
+    /// Called when the session expires (idle, hidden tab, or `expire_session`)
+    #[prop(into, optional)]
+    on_session_expired: Option<Callback<ExpiryReason>>,
+
     children: Children,
 ) -> impl IntoView {
     let ctx = WalletContext::new();
     provide_context(ctx.clone());
+    ctx.set_on_session_expired(on_session_expired);
+
+    if let Some(policy) = idle.filter(|_| in_browser()) {
+        watch_idle(ctx.clone(), policy);
+    }
 
     // Auto-detect and reconnect on mount
     Effect::new(move |_| {
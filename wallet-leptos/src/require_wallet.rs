@@ -0,0 +1,148 @@
+//! Wallet-gated content
+//!
+//! [`RequireWallet`] renders its children only while a wallet is connected.
+//! Otherwise it shows a `fallback`, or an inline prompt with a connect button
+//! for each detected wallet extension, the connection progress and the last
+//! error.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! <RequireWallet>
+//!     <WalletGallery />
+//! </RequireWallet>
+//!
+//! <RequireWallet fallback=|| view! { <p>"Connect a wallet to vote"</p> }>
+//!     <VoteForm />
+//! </RequireWallet>
+//! ```
+
+use crate::hooks::use_wallet;
+use leptos::prelude::*;
+use ui_components::{use_locale, Button, ButtonSize, EmptyState, EmptyStateVariant};
+use wallet_core::{ConnectionState, WalletProvider};
+
+/// What to render for a connection state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Gate {
+    Open,
+    Connecting,
+    Closed,
+}
+
+impl Gate {
+    fn of(state: &ConnectionState) -> Self {
+        match state {
+            ConnectionState::Connected { .. } => Gate::Open,
+            ConnectionState::Connecting => Gate::Connecting,
+            ConnectionState::Disconnected | ConnectionState::Error(_) => Gate::Closed,
+        }
+    }
+}
+
+/// Content shown only while a wallet is connected
+///
+/// Must be used inside a `WalletProvider`.
+#[component]
+pub fn RequireWallet(
+    /// Rendered instead of children when no wallet is connected
+    #[prop(into, optional)]
+    fallback: Option<ViewFn>,
+    /// Show the connect prompt when there's no fallback
+    #[prop(optional, default = true)]
+    prompt: bool,
+    /// Gated content
+    children: ChildrenFn,
+) -> impl IntoView {
+    let wallet = use_wallet();
+    let locale = use_locale();
+    let connection_state = wallet.connection_state;
+    let gate = Memo::new(move |_| connection_state.with(Gate::of));
+
+    move || match (gate.get(), &fallback) {
+        (Gate::Open, _) => children().into_any(),
+        (_, Some(fallback)) => fallback.run(),
+        (_, None) if !prompt => ().into_any(),
+        (Gate::Connecting, None) => {
+            let message = locale.t_or(
+                "require_wallet.connecting",
+                "Approve the connection in your wallet...",
+            );
+            view! { <EmptyState variant=EmptyStateVariant::NoWallet message=message /> }.into_any()
+        }
+        (Gate::Closed, None) => view! { <ConnectPrompt /> }.into_any(),
+    }
+}
+
+/// Connect buttons for the detected wallets, with the last error
+#[component]
+fn ConnectPrompt() -> impl IntoView {
+    let wallet = use_wallet();
+    let locale = use_locale();
+    let available = wallet.available_wallets;
+    let error = wallet.error;
+
+    let message = move || match error.get() {
+        Some(e) => e,
+        None if available.with(Vec::is_empty) => locale.t_or(
+            "require_wallet.none_detected",
+            "Install a Cardano wallet extension to continue.",
+        ),
+        None => locale.t_or(
+            "empty_state.no_wallet.message",
+            EmptyStateVariant::NoWallet.default_message(),
+        ),
+    };
+
+    let buttons = move || {
+        let wallet = wallet.clone();
+        available
+            .get()
+            .into_iter()
+            .filter_map(move |info| {
+                let provider = WalletProvider::from_api_name(&info.api_name)?;
+                let wallet = wallet.clone();
+                Some(view! {
+                    <Button size=ButtonSize::Sm on_click=move |_| wallet.connect(provider)>
+                        {info.name}
+                    </Button>
+                })
+            })
+            .collect_view()
+    };
+
+    move || {
+        let buttons = buttons.clone();
+        view! {
+            <EmptyState
+                variant=EmptyStateVariant::NoWallet
+                message=message()
+                action=Box::new(move || {
+                    view! { <div class="ui-empty-state__actions">{buttons}</div> }.into_any()
+                })
+            />
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wallet_core::Network;
+
+    #[test]
+    fn test_gate_follows_connection_state() {
+        assert_eq!(Gate::of(&ConnectionState::Disconnected), Gate::Closed);
+        assert_eq!(
+            Gate::of(&ConnectionState::Error("denied".into())),
+            Gate::Closed
+        );
+        assert_eq!(Gate::of(&ConnectionState::Connecting), Gate::Connecting);
+        let connected = ConnectionState::Connected {
+            provider: WalletProvider::Eternl,
+            address: "addr1".into(),
+            network: Network::Mainnet,
+        };
+        assert_eq!(Gate::of(&connected), Gate::Open);
+    }
+}
@@ -0,0 +1,86 @@
+//! Lazy, cached wallet data resources
+
+use leptos::prelude::*;
+use ui_core::FetchState;
+
+/// A lazily-fetched piece of wallet data
+///
+/// Nothing is requested from the wallet until the resource is first read via
+/// [`state`](Self::state) or [`data`](Self::data) (or [`load`](Self::load) is
+/// called). The result is then cached until [`refetch`](Self::refetch) is
+/// called or the wallet connection changes.
+///
+/// ```ignore
+/// let wallet = use_wallet();
+///
+/// view! {
+///     {move || match wallet.utxos.state() {
+///         FetchState::Idle | FetchState::Loading => view! { <p>"Loading..."</p> }.into_any(),
+///         FetchState::Loaded(utxos) => view! { <p>{utxos.len()} " UTxOs"</p> }.into_any(),
+///         FetchState::Failed(e) => view! { <p>"Error: " {e}</p> }.into_any(),
+///     }}
+///     <button on:click=move |_| wallet.utxos.refetch()>"Refresh"</button>
+/// }
+/// ```
+pub struct WalletResource<T: Send + Sync + 'static> {
+    pub(crate) state: RwSignal<FetchState<T>>,
+    requested: RwSignal<bool>,
+    trigger: RwSignal<u32>,
+}
+
+impl<T: Send + Sync + 'static> Clone for WalletResource<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for WalletResource<T> {}
+
+impl<T: Clone + Send + Sync + 'static> WalletResource<T> {
+    /// Create a resource driven by shared `requested`/`trigger` signals
+    ///
+    /// Resources backed by the same wallet call share these so that reading
+    /// either one loads both.
+    pub(crate) fn new(requested: RwSignal<bool>, trigger: RwSignal<u32>) -> Self {
+        Self {
+            state: RwSignal::new(FetchState::Idle),
+            requested,
+            trigger,
+        }
+    }
+
+    /// Current fetch state, starting the fetch on first read
+    pub fn state(&self) -> FetchState<T> {
+        self.load();
+        self.state.get()
+    }
+
+    /// Loaded data, if any, starting the fetch on first read
+    pub fn data(&self) -> Option<T> {
+        self.load();
+        self.state.with(|s| s.data().cloned())
+    }
+
+    /// Whether a fetch is in flight (does not start one)
+    pub fn is_loading(&self) -> bool {
+        self.state.with(FetchState::is_loading)
+    }
+
+    /// Error from the last fetch (does not start one)
+    pub fn error(&self) -> Option<String> {
+        self.state.with(|s| s.error().map(str::to_string))
+    }
+
+    /// Start the fetch if it hasn't been requested yet
+    pub fn load(&self) {
+        if !self.requested.get_untracked() {
+            self.requested.set(true);
+        }
+    }
+
+    /// Discard the cached value and fetch again
+    pub fn refetch(&self) {
+        self.requested.set(true);
+        self.trigger.update(|n| *n = n.wrapping_add(1));
+    }
+}
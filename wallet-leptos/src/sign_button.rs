@@ -0,0 +1,212 @@
+//! Ready-made signing buttons
+//!
+//! [`SignDataButton`] and [`SignTxButton`] wrap `sign_data`/`sign_tx` on the
+//! wallet context: they disable themselves while disconnected or waiting on
+//! the wallet, show failures as error toasts (when a `ToastProvider` is in
+//! scope), and hand the signing state to their children as a render prop.
+//!
+//! The state is a [`FetchState`]: `Idle` before the first click, `Loading`
+//! while the wallet prompt is open, then `Loaded` with the result or `Failed`
+//! with the error message.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! <SignDataButton payload_hex=payload let:state>
+//!     {move || state.get().data().map(|sig| view! {
+//!         <code>{sig.signature.clone()}</code>
+//!     })}
+//! </SignDataButton>
+//!
+//! <SignTxButton
+//!     tx_hex=unsigned_tx
+//!     partial_sign=true
+//!     on_signed=Callback::new(move |witness_set: String| submit(witness_set))
+//!     let:state
+//! >
+//!     {move || state.get().error().map(|e| view! { <p class="error">{e.to_string()}</p> })}
+//! </SignTxButton>
+//! ```
+
+use crate::hooks::use_wallet;
+use leptos::prelude::*;
+use std::future::Future;
+use ui_components::{try_use_toasts, Button, ButtonVariant, Toast, ToastContext};
+use ui_core::FetchState;
+use wallet_core::{DataSignature, WalletError};
+use wasm_bindgen_futures::spawn_local;
+
+/// Button that signs a CIP-8 message with the connected wallet
+#[component]
+pub fn SignDataButton<F, V>(
+    /// Hex-encoded payload to sign
+    #[prop(into)]
+    payload_hex: Signal<String>,
+    /// Button label
+    #[prop(into, optional)]
+    label: Option<String>,
+    /// Visual variant
+    #[prop(optional)]
+    variant: ButtonVariant,
+    /// Called with the signature on success
+    #[prop(into, optional)]
+    on_signed: Option<Callback<DataSignature>>,
+    /// Called with the error message on failure
+    #[prop(into, optional)]
+    on_error: Option<Callback<String>>,
+    /// Show an error toast on failure
+    #[prop(optional, default = true)]
+    toast_errors: bool,
+    /// Render prop receiving the signing state
+    children: F,
+) -> impl IntoView
+where
+    F: Fn(Signal<FetchState<DataSignature>>) -> V,
+    V: IntoView + 'static,
+{
+    let wallet = use_wallet();
+    let toasts = try_use_toasts().filter(|_| toast_errors);
+    let state = RwSignal::new(FetchState::Idle);
+
+    let on_click = Callback::new(move |()| {
+        let wallet = wallet.clone();
+        let payload = payload_hex.get_untracked();
+        run_signing(state, toasts.clone(), on_signed, on_error, async move {
+            wallet.sign_data(&payload).await
+        });
+    });
+
+    sign_button_view(
+        state,
+        label.unwrap_or_else(|| "Sign Message".into()),
+        variant,
+        on_click,
+        children,
+    )
+}
+
+/// Button that signs a transaction with the connected wallet
+///
+/// Succeeds with the witness set hex; submitting is left to the caller.
+#[component]
+pub fn SignTxButton<F, V>(
+    /// CBOR-encoded transaction hex
+    #[prop(into)]
+    tx_hex: Signal<String>,
+    /// Only sign with the keys the wallet holds (for multi-party transactions)
+    #[prop(optional)]
+    partial_sign: bool,
+    /// Button label
+    #[prop(into, optional)]
+    label: Option<String>,
+    /// Visual variant
+    #[prop(optional)]
+    variant: ButtonVariant,
+    /// Called with the witness set hex on success
+    #[prop(into, optional)]
+    on_signed: Option<Callback<String>>,
+    /// Called with the error message on failure
+    #[prop(into, optional)]
+    on_error: Option<Callback<String>>,
+    /// Show an error toast on failure
+    #[prop(optional, default = true)]
+    toast_errors: bool,
+    /// Render prop receiving the signing state
+    children: F,
+) -> impl IntoView
+where
+    F: Fn(Signal<FetchState<String>>) -> V,
+    V: IntoView + 'static,
+{
+    let wallet = use_wallet();
+    let toasts = try_use_toasts().filter(|_| toast_errors);
+    let state = RwSignal::new(FetchState::Idle);
+
+    let on_click = Callback::new(move |()| {
+        let wallet = wallet.clone();
+        let tx = tx_hex.get_untracked();
+        run_signing(state, toasts.clone(), on_signed, on_error, async move {
+            wallet.sign_tx(&tx, partial_sign).await
+        });
+    });
+
+    sign_button_view(
+        state,
+        label.unwrap_or_else(|| "Sign Transaction".into()),
+        variant,
+        on_click,
+        children,
+    )
+}
+
+/// Shared markup: the button plus whatever the render prop returns
+fn sign_button_view<T, F, V>(
+    state: RwSignal<FetchState<T>>,
+    label: String,
+    variant: ButtonVariant,
+    on_click: Callback<()>,
+    children: F,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(Signal<FetchState<T>>) -> V,
+    V: IntoView + 'static,
+{
+    let wallet = use_wallet();
+    let pending = Signal::derive(move || state.with(FetchState::is_loading));
+    let disabled = Signal::derive(move || !wallet.is_connected());
+    let text = move || {
+        if pending.get() {
+            "Waiting for wallet...".to_string()
+        } else {
+            label.clone()
+        }
+    };
+
+    view! {
+        <Button variant=variant disabled=disabled loading=pending on_click=on_click>
+            {text}
+        </Button>
+        {children(state.into())}
+    }
+}
+
+/// Run a signing future, tracking it in `state`
+fn run_signing<T, Fut>(
+    state: RwSignal<FetchState<T>>,
+    toasts: Option<ToastContext>,
+    on_success: Option<Callback<T>>,
+    on_error: Option<Callback<String>>,
+    sign: Fut,
+) where
+    T: Clone + Send + Sync + 'static,
+    Fut: Future<Output = Result<T, WalletError>> + 'static,
+{
+    // Ignore clicks while the wallet prompt is already open
+    if state.with_untracked(FetchState::is_loading) {
+        return;
+    }
+    state.set(FetchState::Loading);
+
+    spawn_local(async move {
+        match sign.await {
+            Ok(value) => {
+                state.set(FetchState::Loaded(value.clone()));
+                if let Some(cb) = on_success {
+                    cb.run(value);
+                }
+            }
+            Err(e) => {
+                let message = e.to_string();
+                tracing::warn!("Wallet signing failed: {message}");
+                if let Some(toasts) = toasts {
+                    toasts.show(Toast::error(message.clone()));
+                }
+                state.set(FetchState::Failed(message.clone()));
+                if let Some(cb) = on_error {
+                    cb.run(message);
+                }
+            }
+        }
+    });
+}
@@ -21,6 +21,36 @@ impl Network {
             Network::Testnet => 0,
         }
     }
+
+    /// Lowercase name (`"mainnet"` / `"testnet"`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Network::Mainnet => "mainnet",
+            Network::Testnet => "testnet",
+        }
+    }
+}
+
+/// What an address's funds are locked by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AddressKind {
+    /// Payment key with a stake credential (base address)
+    PaymentKey,
+    /// Payment part is a script hash, whatever the delegation part
+    Script,
+    /// Payment key with no stake credential
+    Enterprise,
+    /// Payment key delegating via a chain pointer
+    Pointer,
+    /// Reward (stake) address
+    Reward,
+    /// Legacy Byron bootstrap address
+    Byron,
+}
+
+/// Classify a hex-encoded address
+pub fn classify_address(address_hex: &str) -> Result<AddressKind, PallasError> {
+    Ok(Address::from_hex(address_hex)?.kind())
 }
 
 /// A parsed Cardano address with utilities
@@ -58,6 +88,16 @@ impl Address {
         Ok(Self { inner, raw_bytes })
     }
 
+    /// Parse an address given as either hex bytes or bech32
+    pub fn parse(address: &str) -> Result<Self, PallasError> {
+        let address = address.trim();
+        if !address.is_empty() && address.bytes().all(|b| b.is_ascii_hexdigit()) {
+            Self::from_hex(address)
+        } else {
+            Self::from_bech32(address)
+        }
+    }
+
     /// Get the network this address belongs to
     pub fn network(&self) -> Network {
         match self.inner.network() {
@@ -119,6 +159,31 @@ impl Address {
         }
     }
 
+    /// Classify the address by what locks its funds
+    pub fn kind(&self) -> AddressKind {
+        use pallas_addresses::{ShelleyDelegationPart, ShelleyPaymentPart};
+
+        match &self.inner {
+            PallasAddress::Byron(_) => AddressKind::Byron,
+            PallasAddress::Stake(_) => AddressKind::Reward,
+            PallasAddress::Shelley(addr) => match (addr.payment(), addr.delegation()) {
+                (ShelleyPaymentPart::Script(_), _) => AddressKind::Script,
+                (_, ShelleyDelegationPart::Null) => AddressKind::Enterprise,
+                (_, ShelleyDelegationPart::Pointer(_)) => AddressKind::Pointer,
+                _ => AddressKind::PaymentKey,
+            },
+        }
+    }
+
+    /// Get the payment script hash (for script addresses)
+    pub fn script_hash(&self) -> Option<[u8; 28]> {
+        if self.is_script() {
+            self.payment_hash()
+        } else {
+            None
+        }
+    }
+
     /// Check if this is a script address
     pub fn is_script(&self) -> bool {
         match &self.inner {
@@ -177,3 +242,88 @@ impl Address {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shelley address hex: header byte followed by one or two 28-byte credentials
+    fn shelley(header: u8, parts: usize) -> String {
+        let mut bytes = vec![header];
+        bytes.extend(std::iter::repeat_n(0x11, 28 * parts));
+        hex::encode(bytes)
+    }
+
+    #[test]
+    fn test_classify_address() {
+        assert_eq!(
+            classify_address(&shelley(0x01, 2)).unwrap(),
+            AddressKind::PaymentKey
+        );
+        // Script payment part, key or no delegation
+        assert_eq!(
+            classify_address(&shelley(0x11, 2)).unwrap(),
+            AddressKind::Script
+        );
+        assert_eq!(
+            classify_address(&shelley(0x71, 1)).unwrap(),
+            AddressKind::Script
+        );
+        assert_eq!(
+            classify_address(&shelley(0x61, 1)).unwrap(),
+            AddressKind::Enterprise
+        );
+        assert_eq!(
+            classify_address(&shelley(0xe1, 1)).unwrap(),
+            AddressKind::Reward
+        );
+        // Pointer: slot, tx index, cert index as variable-length naturals
+        let pointer = format!("{}010203", shelley(0x41, 1));
+        assert_eq!(classify_address(&pointer).unwrap(), AddressKind::Pointer);
+    }
+
+    #[test]
+    fn test_parse_hex_or_bech32() {
+        let hex = shelley(0x01, 2);
+        let bech32 = Address::from_hex(&hex).unwrap().to_bech32().unwrap();
+        assert!(bech32.starts_with("addr1"));
+
+        assert_eq!(Address::parse(&hex).unwrap().to_bech32().unwrap(), bech32);
+        assert_eq!(Address::parse(&bech32).unwrap().to_hex(), hex);
+        assert_eq!(
+            Address::parse(&format!(" {bech32} ")).unwrap().to_hex(),
+            hex
+        );
+        assert!(Address::parse("").is_err());
+        assert!(Address::parse("addr1notanaddress").is_err());
+    }
+
+    #[test]
+    fn test_stake_address_and_network() {
+        let base = Address::from_hex(&shelley(0x01, 2)).unwrap();
+        assert_eq!(base.network(), Network::Mainnet);
+        let stake = base.stake_address_bech32().unwrap();
+        assert!(stake.starts_with("stake1"));
+        assert_eq!(Address::parse(&stake).unwrap().to_hex(), shelley(0xe1, 1));
+
+        let testnet = Address::from_hex(&shelley(0x00, 2)).unwrap();
+        assert_eq!(testnet.network().as_str(), "testnet");
+        assert!(testnet
+            .stake_address_bech32()
+            .unwrap()
+            .starts_with("stake_test1"));
+
+        // Enterprise addresses have no stake credential
+        let enterprise = Address::from_hex(&shelley(0x61, 1)).unwrap();
+        assert_eq!(enterprise.stake_address_bech32(), None);
+    }
+
+    #[test]
+    fn test_script_hash_only_for_script_addresses() {
+        let script = Address::from_hex(&shelley(0x71, 1)).unwrap();
+        assert_eq!(script.script_hash(), Some([0x11; 28]));
+
+        let key = Address::from_hex(&shelley(0x61, 1)).unwrap();
+        assert_eq!(key.script_hash(), None);
+    }
+}
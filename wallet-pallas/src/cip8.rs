@@ -90,7 +90,7 @@ pub fn verify_data_signature(
 ///   -1: 6,     // crv: Ed25519
 ///   -2: h'...' // x: public key bytes (32 bytes)
 /// }
-fn extract_ed25519_public_key(cose_key_bytes: &[u8]) -> Result<[u8; 32], PallasError> {
+pub(crate) fn extract_ed25519_public_key(cose_key_bytes: &[u8]) -> Result<[u8; 32], PallasError> {
     // Simple CBOR parsing for COSE_Key
     // This is a minimal parser that looks for the -2 key (0x21 in CBOR)
     // which contains the 32-byte Ed25519 public key
@@ -33,6 +33,33 @@ pub enum PallasError {
     #[error("Transaction parse error: {0}")]
     TransactionParse(String),
 
+    /// Invalid policy ID or asset name
+    #[error("Invalid asset ID: {0}")]
+    InvalidAssetId(String),
+
+    /// Failed to parse a CIP-14 asset fingerprint
+    #[error("Invalid asset fingerprint: {0}")]
+    InvalidFingerprint(String),
+
+    /// Transaction exceeds the protocol's maximum size
+    #[error("Transaction too large: {size} bytes exceeds maximum of {max}")]
+    TxTooLarge { size: u64, max: u64 },
+
+    /// Script budget exceeds the protocol's per-transaction limit
+    #[error("Execution units {ex_units:?} exceed maximum of {max:?}")]
+    ExUnitsExceeded {
+        ex_units: wallet_core::ExUnits,
+        max: wallet_core::ExUnits,
+    },
+
+    /// Structured sign message is malformed or fails its policy
+    #[error("Invalid message: {0}")]
+    InvalidMessage(String),
+
+    /// Malformed native script
+    #[error("Invalid script: {0}")]
+    InvalidScript(String),
+
     /// Unsupported address type
     #[error("Unsupported address type: {0}")]
     UnsupportedAddressType(String),
@@ -43,3 +70,9 @@ impl From<hex::FromHexError> for PallasError {
         PallasError::InvalidHex(e.to_string())
     }
 }
+
+impl From<pallas_codec::minicbor::decode::Error> for PallasError {
+    fn from(e: pallas_codec::minicbor::decode::Error) -> Self {
+        PallasError::CborDecode(e.to_string())
+    }
+}
@@ -0,0 +1,320 @@
+//! Fee estimation
+//!
+//! Estimates the minimum fee of a draft transaction from live
+//! [`ProtocolParams`], following the ledger's Conway rules:
+//!
+//! ```text
+//! fee = min_fee_a * size + min_fee_b
+//!     + ceil(price_mem * mem + price_steps * steps)
+//!     + tiered reference script fee
+//! ```
+//!
+//! Drafts are usually unsigned, so [`FeeOptions::signers`] pads the size with
+//! the VKey witnesses that will be added before submission. Execution units
+//! are read from the draft's redeemers unless overridden.
+
+use crate::PallasError;
+use pallas_codec::minicbor;
+use pallas_primitives::conway::{Redeemers, Tx};
+use wallet_core::{ExUnits, ProtocolParams};
+
+/// Encoded size of one VKey witness: `[bytes(32), bytes(64)]`
+const VKEY_WITNESS_BYTES: u64 = 101;
+
+/// Witness set key and array header added with the first VKey witness
+const VKEY_WITNESS_FIELD_BYTES: u64 = 2;
+
+/// Reference script bytes charged at each price tier
+const REF_SCRIPT_TIER_BYTES: u64 = 25_600;
+
+/// Price multiplier between reference script tiers
+const REF_SCRIPT_TIER_MULTIPLIER: f64 = 1.2;
+
+/// Inputs the draft transaction alone doesn't carry
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeeOptions {
+    /// VKey witnesses still to be added (one per required signer)
+    pub signers: usize,
+    /// Total size of reference scripts in spent and referenced inputs
+    pub ref_script_bytes: u64,
+    /// Script budget to use instead of the draft's redeemers
+    pub ex_units: Option<ExUnits>,
+}
+
+impl FeeOptions {
+    /// Pad for `signers` VKey witnesses still to be added
+    pub fn signers(mut self, signers: usize) -> Self {
+        self.signers = signers;
+        self
+    }
+
+    /// Charge for reference scripts totalling `bytes`
+    pub fn ref_script_bytes(mut self, bytes: u64) -> Self {
+        self.ref_script_bytes = bytes;
+        self
+    }
+
+    /// Use this script budget instead of the draft's redeemers
+    pub fn ex_units(mut self, ex_units: ExUnits) -> Self {
+        self.ex_units = Some(ex_units);
+        self
+    }
+}
+
+/// Breakdown of an estimated fee
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeEstimate {
+    /// Transaction size in bytes, including padded witnesses
+    pub tx_size: u64,
+    /// Total script execution budget
+    pub ex_units: ExUnits,
+    /// Size component (`min_fee_a * size + min_fee_b`)
+    pub size_fee: u64,
+    /// Script execution component
+    pub script_fee: u64,
+    /// Reference script component
+    pub ref_script_fee: u64,
+}
+
+impl FeeEstimate {
+    /// Total fee in lovelace
+    pub fn total(&self) -> u64 {
+        self.size_fee + self.script_fee + self.ref_script_fee
+    }
+}
+
+/// Estimate the fee of a hex-encoded draft transaction
+pub fn estimate_fee(
+    tx_draft_hex: &str,
+    params: &ProtocolParams,
+) -> Result<FeeEstimate, PallasError> {
+    estimate_fee_with(tx_draft_hex, params, &FeeOptions::default())
+}
+
+/// Estimate the fee of a hex-encoded draft transaction with extra inputs
+pub fn estimate_fee_with(
+    tx_draft_hex: &str,
+    params: &ProtocolParams,
+    options: &FeeOptions,
+) -> Result<FeeEstimate, PallasError> {
+    let tx_bytes = hex::decode(tx_draft_hex)?;
+
+    let tx: Tx =
+        minicbor::decode(&tx_bytes).map_err(|e| PallasError::TransactionParse(e.to_string()))?;
+    let witness = &tx.transaction_witness_set;
+
+    let mut tx_size = tx_bytes.len() as u64;
+    if options.signers > 0 {
+        tx_size += options.signers as u64 * VKEY_WITNESS_BYTES;
+        if witness.vkeywitness.is_none() {
+            tx_size += VKEY_WITNESS_FIELD_BYTES;
+        }
+    }
+    if tx_size > params.max_tx_size {
+        return Err(PallasError::TxTooLarge {
+            size: tx_size,
+            max: params.max_tx_size,
+        });
+    }
+
+    let ex_units = options.ex_units.unwrap_or_else(|| {
+        witness
+            .redeemer
+            .as_ref()
+            .map(|redeemers| redeemer_ex_units(redeemers))
+            .unwrap_or_default()
+    });
+    let max = params.max_tx_ex_units;
+    if ex_units.mem > max.mem || ex_units.steps > max.steps {
+        return Err(PallasError::ExUnitsExceeded { ex_units, max });
+    }
+
+    Ok(FeeEstimate {
+        tx_size,
+        ex_units,
+        size_fee: linear_fee(tx_size, params),
+        script_fee: script_fee(ex_units, params),
+        ref_script_fee: ref_script_fee(options.ref_script_bytes, params),
+    })
+}
+
+/// Total execution budget declared by a transaction's redeemers
+fn redeemer_ex_units(redeemers: &Redeemers) -> ExUnits {
+    let units: Vec<ExUnits> = match redeemers {
+        Redeemers::List(list) => list
+            .iter()
+            .map(|r| ExUnits::new(r.ex_units.mem, r.ex_units.steps))
+            .collect(),
+        Redeemers::Map(map) => map
+            .iter()
+            .map(|(_, r)| ExUnits::new(r.ex_units.mem, r.ex_units.steps))
+            .collect(),
+    };
+    units.into_iter().fold(ExUnits::default(), |acc, u| acc + u)
+}
+
+/// Size component of the fee: `min_fee_a * size + min_fee_b`
+pub fn linear_fee(tx_size: u64, params: &ProtocolParams) -> u64 {
+    params.min_fee_a * tx_size + params.min_fee_b
+}
+
+/// Script execution component of the fee, rounded up
+pub fn script_fee(ex_units: ExUnits, params: &ProtocolParams) -> u64 {
+    let prices = params.ex_unit_prices;
+    (prices.mem * ex_units.mem as f64 + prices.steps * ex_units.steps as f64).ceil() as u64
+}
+
+/// Reference script component of the fee
+///
+/// Each 25 KiB tier costs 1.2x the per-byte price of the previous one, and
+/// the total is rounded down.
+pub fn ref_script_fee(ref_script_bytes: u64, params: &ProtocolParams) -> u64 {
+    let mut remaining = ref_script_bytes;
+    let mut price = params.min_fee_ref_script_cost_per_byte;
+    let mut total = 0.0;
+
+    while remaining > 0 {
+        let tier = remaining.min(REF_SCRIPT_TIER_BYTES);
+        total += tier as f64 * price;
+        remaining -= tier;
+        price *= REF_SCRIPT_TIER_MULTIPLIER;
+    }
+
+    total.floor() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minicbor::Encoder;
+    use wallet_core::ExUnitPrices;
+
+    fn mainnet_params() -> ProtocolParams {
+        ProtocolParams {
+            epoch: Some(520),
+            min_fee_a: 44,
+            min_fee_b: 155_381,
+            max_tx_size: 16_384,
+            coins_per_utxo_byte: 4_310,
+            key_deposit: 2_000_000,
+            pool_deposit: 500_000_000,
+            ex_unit_prices: ExUnitPrices {
+                mem: 0.0577,
+                steps: 0.0000721,
+            },
+            max_tx_ex_units: ExUnits::new(14_000_000, 10_000_000_000),
+            collateral_percent: 150,
+            max_collateral_inputs: 3,
+            min_fee_ref_script_cost_per_byte: 15.0,
+        }
+    }
+
+    /// Unsigned one-input, one-output transaction with an empty witness set
+    fn draft_tx() -> Vec<u8> {
+        let mut buf = Vec::new();
+        let mut e = Encoder::new(&mut buf);
+        e.array(4).unwrap();
+
+        // Body: inputs, outputs, fee
+        e.map(3).unwrap();
+        e.u8(0).unwrap().array(1).unwrap();
+        e.array(2)
+            .unwrap()
+            .bytes(&[0x11; 32])
+            .unwrap()
+            .u8(0)
+            .unwrap();
+        e.u8(1).unwrap().array(1).unwrap();
+        let mut address = [0x22; 29];
+        address[0] = 0x61;
+        e.array(2)
+            .unwrap()
+            .bytes(&address)
+            .unwrap()
+            .u64(2_000_000)
+            .unwrap();
+        e.u8(2).unwrap().u64(0).unwrap();
+
+        // Witness set, validity, auxiliary data
+        e.map(0).unwrap();
+        e.bool(true).unwrap();
+        e.null().unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_linear_fee() {
+        assert_eq!(linear_fee(300, &mainnet_params()), 44 * 300 + 155_381);
+    }
+
+    #[test]
+    fn test_script_fee_rounds_up() {
+        let params = mainnet_params();
+        // 0.0577 * 1_000 + 0.0000721 * 1_000_000 = 57.7 + 72.1 = 129.8
+        assert_eq!(script_fee(ExUnits::new(1_000, 1_000_000), &params), 130);
+        assert_eq!(script_fee(ExUnits::default(), &params), 0);
+    }
+
+    #[test]
+    fn test_ref_script_fee_tiers() {
+        let params = mainnet_params();
+        assert_eq!(ref_script_fee(0, &params), 0);
+        assert_eq!(ref_script_fee(1_000, &params), 15_000);
+        // Full first tier at 15, then 100 bytes at 18
+        assert_eq!(ref_script_fee(25_700, &params), 25_600 * 15 + 100 * 18);
+    }
+
+    #[test]
+    fn test_estimate_unsigned_draft() {
+        let params = mainnet_params();
+        let tx = draft_tx();
+        let tx_hex = hex::encode(&tx);
+
+        let estimate = estimate_fee(&tx_hex, &params).unwrap();
+        assert_eq!(estimate.tx_size, tx.len() as u64);
+        assert_eq!(estimate.ex_units, ExUnits::default());
+        assert_eq!(estimate.total(), linear_fee(tx.len() as u64, &params));
+
+        let signed =
+            estimate_fee_with(&tx_hex, &params, &FeeOptions::default().signers(2)).unwrap();
+        assert_eq!(
+            signed.tx_size,
+            tx.len() as u64 + 2 * VKEY_WITNESS_BYTES + VKEY_WITNESS_FIELD_BYTES
+        );
+    }
+
+    #[test]
+    fn test_estimate_with_budget_override() {
+        let params = mainnet_params();
+        let tx_hex = hex::encode(draft_tx());
+        let options = FeeOptions::default()
+            .ex_units(ExUnits::new(1_000, 1_000_000))
+            .ref_script_bytes(1_000);
+
+        let estimate = estimate_fee_with(&tx_hex, &params, &options).unwrap();
+        assert_eq!(estimate.script_fee, 130);
+        assert_eq!(estimate.ref_script_fee, 15_000);
+        assert_eq!(
+            estimate.total(),
+            estimate.size_fee + estimate.script_fee + estimate.ref_script_fee
+        );
+    }
+
+    #[test]
+    fn test_estimate_rejects_over_budget() {
+        let params = mainnet_params();
+        let tx_hex = hex::encode(draft_tx());
+
+        let options = FeeOptions::default().ex_units(ExUnits::new(20_000_000, 1));
+        assert!(matches!(
+            estimate_fee_with(&tx_hex, &params, &options),
+            Err(PallasError::ExUnitsExceeded { .. })
+        ));
+
+        let options = FeeOptions::default().signers(200);
+        assert!(matches!(
+            estimate_fee_with(&tx_hex, &params, &options),
+            Err(PallasError::TxTooLarge { .. })
+        ));
+    }
+}
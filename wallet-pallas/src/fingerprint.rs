@@ -0,0 +1,194 @@
+//! CIP-14 asset fingerprints
+//!
+//! A fingerprint is the bech32 (`asset1...`) encoding of a blake2b-160 hash of
+//! `policy_id || asset_name`. Explorers such as pool.pm and cexplorer key
+//! their asset pages on it.
+//!
+//! The hash is one-way: a fingerprint can be parsed and compared against a
+//! known asset, but the policy ID and asset name cannot be recovered from it.
+
+use crate::PallasError;
+use bech32::{Bech32, Hrp};
+use cardano_assets::AssetId;
+use pallas_crypto::hash::Hasher;
+use std::fmt;
+use std::str::FromStr;
+
+/// Human-readable part of CIP-14 fingerprints
+const FINGERPRINT_HRP: Hrp = Hrp::parse_unchecked("asset");
+
+/// Policy ID length in bytes
+const POLICY_ID_LEN: usize = 28;
+
+/// Maximum asset name length in bytes
+const MAX_ASSET_NAME_LEN: usize = 32;
+
+/// A CIP-14 asset fingerprint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AssetFingerprint([u8; 20]);
+
+impl AssetFingerprint {
+    /// Compute the fingerprint of a policy ID and asset name (both hex)
+    pub fn from_parts(policy_id: &str, asset_name_hex: &str) -> Result<Self, PallasError> {
+        let mut bytes = hex::decode(policy_id)?;
+        if bytes.len() != POLICY_ID_LEN {
+            return Err(PallasError::InvalidAssetId(format!(
+                "policy ID must be {POLICY_ID_LEN} bytes, got {}",
+                bytes.len()
+            )));
+        }
+
+        let name = hex::decode(asset_name_hex)?;
+        if name.len() > MAX_ASSET_NAME_LEN {
+            return Err(PallasError::InvalidAssetId(format!(
+                "asset name must be at most {MAX_ASSET_NAME_LEN} bytes, got {}",
+                name.len()
+            )));
+        }
+        bytes.extend_from_slice(&name);
+
+        Ok(Self(*Hasher::<160>::hash(&bytes)))
+    }
+
+    /// Compute the fingerprint of an [`AssetId`]
+    pub fn from_asset_id(asset_id: &AssetId) -> Result<Self, PallasError> {
+        Self::from_parts(&asset_id.policy_id, &asset_id.asset_name_hex)
+    }
+
+    /// Parse an `asset1...` fingerprint
+    pub fn from_bech32(fingerprint: &str) -> Result<Self, PallasError> {
+        let (hrp, data) = bech32::decode(fingerprint)
+            .map_err(|e| PallasError::InvalidFingerprint(e.to_string()))?;
+        if hrp != FINGERPRINT_HRP {
+            return Err(PallasError::InvalidFingerprint(format!(
+                "expected prefix \"asset\", got \"{hrp}\""
+            )));
+        }
+        let hash = <[u8; 20]>::try_from(data.as_slice()).map_err(|_| {
+            PallasError::InvalidFingerprint(format!(
+                "expected 20-byte hash, got {} bytes",
+                data.len()
+            ))
+        })?;
+        Ok(Self(hash))
+    }
+
+    /// Encode as an `asset1...` string
+    pub fn to_bech32(&self) -> String {
+        bech32::encode::<Bech32>(FINGERPRINT_HRP, &self.0)
+            .expect("20-byte payload is within bech32 length limits")
+    }
+
+    /// The raw blake2b-160 hash
+    pub fn as_bytes(&self) -> &[u8; 20] {
+        &self.0
+    }
+
+    /// Whether this fingerprint identifies the given policy ID and asset name
+    pub fn matches(&self, policy_id: &str, asset_name_hex: &str) -> bool {
+        Self::from_parts(policy_id, asset_name_hex).is_ok_and(|other| other == *self)
+    }
+}
+
+impl fmt::Display for AssetFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_bech32())
+    }
+}
+
+impl FromStr for AssetFingerprint {
+    type Err = PallasError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bech32(s)
+    }
+}
+
+/// Compute the `asset1...` fingerprint of a policy ID and asset name (both hex)
+pub fn asset_fingerprint(policy_id: &str, asset_name_hex: &str) -> Result<String, PallasError> {
+    AssetFingerprint::from_parts(policy_id, asset_name_hex).map(|fp| fp.to_bech32())
+}
+
+/// Check whether a string is a well-formed `asset1...` fingerprint
+pub fn is_asset_fingerprint(s: &str) -> bool {
+    AssetFingerprint::from_bech32(s).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICY_A: &str = "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373";
+    const POLICY_B: &str = "1e349c9bdea19fd6c147626a5260bc44b71635f398b67c59881df209";
+
+    #[test]
+    fn test_cip14_vectors() {
+        let vectors = [
+            (POLICY_A, "", "asset1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3"),
+            (POLICY_B, "", "asset1uyuxku60yqe57nusqzjx38aan3f2wq6s93f6ea"),
+            (
+                POLICY_A,
+                "504154415445",
+                "asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92",
+            ),
+            (
+                POLICY_B,
+                "504154415445",
+                "asset1hv4p5tv2a837mzqrst04d0dcptdjmluqvdx9k3",
+            ),
+            (
+                POLICY_A,
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "asset1pkpwyknlvul7az0xx8czhl60pyel45rpje4z8w",
+            ),
+        ];
+        for (policy, name, expected) in vectors {
+            assert_eq!(asset_fingerprint(policy, name).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let fp: AssetFingerprint = "asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            fp.to_string(),
+            "asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92"
+        );
+        assert!(fp.matches(POLICY_A, "504154415445"));
+        assert!(!fp.matches(POLICY_B, "504154415445"));
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid() {
+        // Bad checksum
+        assert!(!is_asset_fingerprint(
+            "asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde93"
+        ));
+        // Wrong prefix
+        assert!(!is_asset_fingerprint(
+            "token13n25uv0yaf5kus35fm2k86cqy60z58d9d3yn67"
+        ));
+        // Policy-sized payload instead of a 20-byte hash
+        assert!(!is_asset_fingerprint(
+            "asset106hz3tezpzlg2mm6zxtx3tjj5jdhxuj7xfkuzetemnphxhlgjwd"
+        ));
+    }
+
+    #[test]
+    fn test_from_parts_validates_lengths() {
+        assert!(matches!(
+            AssetFingerprint::from_parts("abcd", ""),
+            Err(PallasError::InvalidAssetId(_))
+        ));
+        assert!(matches!(
+            AssetFingerprint::from_parts(POLICY_A, &"00".repeat(33)),
+            Err(PallasError::InvalidAssetId(_))
+        ));
+        assert!(matches!(
+            AssetFingerprint::from_parts(POLICY_A, "zz"),
+            Err(PallasError::InvalidHex(_))
+        ));
+    }
+}
@@ -0,0 +1,71 @@
+//! JavaScript bindings for address utilities
+//!
+//! Exported with the `wasm` feature so plain-JS host pages can convert and
+//! inspect addresses without shipping their own pallas build. Every helper
+//! accepts an address as hex bytes (CIP-30) or bech32, and throws a JS
+//! `Error` for input that isn't a valid address.
+//!
+//! ```js
+//! import init, { addressToBech32, stakeAddress, addressNetwork } from "./wallet_pallas.js";
+//!
+//! await init();
+//! const hex = await api.getChangeAddress();
+//! addressToBech32(hex);  // "addr1q..."
+//! stakeAddress(hex);     // "stake1u..." or undefined
+//! addressNetwork(hex);   // "mainnet"
+//! ```
+
+use wasm_bindgen::prelude::*;
+
+use crate::{Address, PallasError};
+
+fn parse(address: &str) -> Result<Address, JsError> {
+    Address::parse(address).map_err(to_js)
+}
+
+fn to_js(e: PallasError) -> JsError {
+    JsError::new(&e.to_string())
+}
+
+/// Bech32 form (`addr1...`, `stake1...`) of a hex or bech32 address
+#[wasm_bindgen(js_name = addressToBech32)]
+pub fn address_to_bech32(address: &str) -> Result<String, JsError> {
+    parse(address)?.to_bech32().map_err(to_js)
+}
+
+/// Hex bytes of a hex or bech32 address
+#[wasm_bindgen(js_name = addressToHex)]
+pub fn address_to_hex(address: &str) -> Result<String, JsError> {
+    Ok(parse(address)?.to_hex())
+}
+
+/// Bech32 stake (reward) address for an address's stake credential
+///
+/// `undefined` for addresses without one (enterprise, pointer, Byron). A
+/// stake address is returned as is.
+#[wasm_bindgen(js_name = stakeAddress)]
+pub fn stake_address(address: &str) -> Result<Option<String>, JsError> {
+    let parsed = parse(address)?;
+    if parsed.kind() == crate::AddressKind::Reward {
+        return parsed.to_bech32().map(Some).map_err(to_js);
+    }
+    Ok(parsed.stake_address_bech32())
+}
+
+/// `"mainnet"` or `"testnet"`
+#[wasm_bindgen(js_name = addressNetwork)]
+pub fn address_network(address: &str) -> Result<String, JsError> {
+    Ok(parse(address)?.network().as_str().to_string())
+}
+
+/// Network id as used by CIP-30 `getNetworkId` (1 = mainnet, 0 = testnet)
+#[wasm_bindgen(js_name = addressNetworkId)]
+pub fn address_network_id(address: &str) -> Result<u8, JsError> {
+    Ok(parse(address)?.network().id())
+}
+
+/// Whether `address` parses as a Cardano address (hex or bech32)
+#[wasm_bindgen(js_name = isValidAddress)]
+pub fn is_valid_address(address: &str) -> bool {
+    Address::parse(address).is_ok()
+}
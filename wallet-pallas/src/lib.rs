@@ -6,8 +6,19 @@
 //! ## Features
 //!
 //! - **Address utilities**: Bech32 encoding/decoding, network detection
+//! - **Fee estimation**: Linear, script and reference script fees from live protocol parameters
+//! - **Asset fingerprints**: CIP-14 `asset1...` generation and parsing
 //! - **CIP-8 verification**: Verify message signatures from `sign_data`
+//! - **Sign messages**: Canonical login/link/vote payloads with nonces, timestamps and
+//!   server-side verification
 //! - **Transaction inspection**: Parse transactions and witness sets
+//! - **Witness verification**: Check VKey witness signatures against the body hash and
+//!   find missing required signers
+//! - **UTxO decoding**: Decode `getUtxos` entries and sum them into a balance
+//! - **Script inspection**: Classify addresses, decode datums and reference scripts
+//! - **Native scripts**: Build multisig/timelock scripts, hash them and derive script addresses
+//! - **JS bindings** (`wasm` feature): Address conversion, stake address and network
+//!   helpers exported with `wasm_bindgen` for plain-JS host pages
 //!
 //! ## Example
 //!
@@ -33,13 +44,34 @@
 mod address;
 mod cip8;
 mod error;
+mod fee;
+mod fingerprint;
+#[cfg(feature = "wasm")]
+pub mod js;
+mod message;
+mod native_script;
+mod output;
 mod transaction;
+mod utxo;
 mod value;
 
-pub use address::Address;
+pub use address::{classify_address, Address, AddressKind, Network};
 pub use cip8::{compute_key_hash, verify_data_signature, DataSignatureInfo};
 pub use error::PallasError;
+pub use fee::{
+    estimate_fee, estimate_fee_with, linear_fee, ref_script_fee, script_fee, FeeEstimate,
+    FeeOptions,
+};
+pub use fingerprint::{asset_fingerprint, is_asset_fingerprint, AssetFingerprint};
+pub use message::{
+    verify_signed_message, MessageAction, MessagePolicy, SignMessage, VerifiedMessage,
+    DEFAULT_CLOCK_SKEW_SECS, DEFAULT_MAX_AGE_SECS,
+};
+pub use native_script::{native_script_witness_set, DecodedNativeScript, NativeScript};
+pub use output::{Datum, ScriptLanguage, ScriptRef, TxOutput};
 pub use transaction::{
-    extract_vkey_witnesses, parse_transaction, parse_witness_set, TransactionInfo, WitnessSetInfo,
+    extract_vkey_witnesses, parse_transaction, parse_witness_set, verify_tx_witnesses,
+    TransactionInfo, WitnessCheck, WitnessSetInfo, WitnessVerification,
 };
+pub use utxo::{decode_utxo, sum_utxos, Utxo};
 pub use value::{decode_balance, NativeToken, PolicyGroup, WalletBalance};
@@ -0,0 +1,664 @@
+//! Structured sign-in and consent messages for CIP-8 `signData`
+//!
+//! A [`SignMessage`] renders to a fixed, human-readable text so the wallet
+//! prompt shows the user what they are agreeing to, and so the backend can
+//! parse exactly what was signed:
+//!
+//! ```text
+//! raffle.example wants you to sign in with your Cardano wallet:
+//!
+//! Address: addr1...
+//! Action: login
+//! Nonce: 8f2c1a...
+//! Issued At: 2024-05-01T12:00:00Z
+//! Expires At: 2024-05-01T12:05:00Z
+//! ```
+//!
+//! The nonce should come from the backend (one per challenge) so a captured
+//! signature can't be replayed. [`verify_signed_message`] checks the COSE
+//! signature, that the signing key owns the message's address, and the
+//! domain, nonce and timestamps against a [`MessagePolicy`].
+//!
+//! ```ignore
+//! use wallet_pallas::{verify_signed_message, MessagePolicy};
+//!
+//! let policy = MessagePolicy::new("raffle.example", now_secs).nonce(issued_nonce);
+//! let verified = verify_signed_message(&signature_hex, &key_hex, &policy)?;
+//! sessions.create(&verified.message.address);
+//! ```
+
+use crate::cip8::{compute_key_hash, extract_ed25519_public_key};
+use crate::{Address, PallasError};
+use pallas_codec::minicbor::{data::Type, Decoder, Encoder};
+use pallas_crypto::key::ed25519::{PublicKey, Signature};
+use wallet_core::calendar::{civil_from_days, days_from_civil};
+
+/// Seconds a message stays acceptable after `issued_at` when it has no expiry
+pub const DEFAULT_MAX_AGE_SECS: u64 = 300;
+
+/// Allowed difference between the signer's and verifier's clocks
+pub const DEFAULT_CLOCK_SKEW_SECS: u64 = 60;
+
+/// What the user is agreeing to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageAction {
+    /// Sign in to the domain
+    Login,
+    /// Link the wallet to a Discord account
+    LinkDiscord { discord_id: String },
+    /// Cast a vote on a proposal
+    Vote { proposal: String, choice: String },
+}
+
+impl MessageAction {
+    fn tag(&self) -> &'static str {
+        match self {
+            Self::Login => "login",
+            Self::LinkDiscord { .. } => "link-discord",
+            Self::Vote { .. } => "vote",
+        }
+    }
+
+    fn statement(&self) -> &'static str {
+        match self {
+            Self::Login => "sign in with your Cardano wallet",
+            Self::LinkDiscord { .. } => "link your Cardano wallet to a Discord account",
+            Self::Vote { .. } => "cast a vote with your Cardano wallet",
+        }
+    }
+}
+
+/// A structured message for CIP-8 signing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignMessage {
+    /// Site or app requesting the signature
+    pub domain: String,
+    /// What the signature authorizes
+    pub action: MessageAction,
+    /// Bech32 address of the signer
+    pub address: String,
+    /// Single-use challenge, issued by the backend
+    pub nonce: String,
+    /// Unix seconds when the message was created
+    pub issued_at: u64,
+    /// Seconds after `issued_at` that the message expires, if any
+    pub valid_for: Option<u64>,
+}
+
+impl SignMessage {
+    /// Message for `action`; set the address and time before signing
+    pub fn new(domain: impl Into<String>, action: MessageAction, nonce: impl Into<String>) -> Self {
+        Self {
+            domain: domain.into(),
+            action,
+            address: String::new(),
+            nonce: nonce.into(),
+            issued_at: 0,
+            valid_for: None,
+        }
+    }
+
+    /// Sign-in message
+    pub fn login(domain: impl Into<String>, nonce: impl Into<String>) -> Self {
+        Self::new(domain, MessageAction::Login, nonce)
+    }
+
+    /// Discord account link message
+    pub fn link_discord(
+        domain: impl Into<String>,
+        nonce: impl Into<String>,
+        discord_id: impl Into<String>,
+    ) -> Self {
+        let discord_id = discord_id.into();
+        Self::new(domain, MessageAction::LinkDiscord { discord_id }, nonce)
+    }
+
+    /// Vote message
+    pub fn vote(
+        domain: impl Into<String>,
+        nonce: impl Into<String>,
+        proposal: impl Into<String>,
+        choice: impl Into<String>,
+    ) -> Self {
+        let action = MessageAction::Vote {
+            proposal: proposal.into(),
+            choice: choice.into(),
+        };
+        Self::new(domain, action, nonce)
+    }
+
+    /// Set the signer's bech32 address
+    pub fn address(mut self, address: impl Into<String>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Set the creation time (unix seconds)
+    pub fn issued_at(mut self, unix_secs: u64) -> Self {
+        self.issued_at = unix_secs;
+        self
+    }
+
+    /// Expire the message `secs` after it was issued
+    pub fn valid_for(mut self, secs: u64) -> Self {
+        self.valid_for = Some(secs);
+        self
+    }
+
+    /// Unix seconds when the message expires, if it does
+    pub fn expires_at(&self) -> Option<u64> {
+        self.valid_for
+            .map(|secs| self.issued_at.saturating_add(secs))
+    }
+
+    /// Canonical text shown in the wallet prompt
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![
+            format!("{} wants you to {}:", self.domain, self.action.statement()),
+            String::new(),
+            format!("Address: {}", self.address),
+            format!("Action: {}", self.action.tag()),
+        ];
+        match &self.action {
+            MessageAction::Login => {}
+            MessageAction::LinkDiscord { discord_id } => {
+                lines.push(format!("Discord ID: {discord_id}"));
+            }
+            MessageAction::Vote { proposal, choice } => {
+                lines.push(format!("Proposal: {proposal}"));
+                lines.push(format!("Choice: {choice}"));
+            }
+        }
+        lines.push(format!("Nonce: {}", self.nonce));
+        lines.push(format!("Issued At: {}", format_timestamp(self.issued_at)));
+        if let Some(expires_at) = self.expires_at() {
+            lines.push(format!("Expires At: {}", format_timestamp(expires_at)));
+        }
+        lines.join("\n")
+    }
+
+    /// Payload for `signData` (hex of the UTF-8 text)
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_text())
+    }
+
+    /// Parse canonical message text
+    ///
+    /// Anything that doesn't re-render to exactly the same text is rejected,
+    /// so a message can't carry extra or reordered lines.
+    pub fn parse(text: &str) -> Result<Self, PallasError> {
+        let mut lines = text.lines();
+        let header = lines.next().unwrap_or_default();
+        let (domain, _) = header
+            .split_once(" wants you to ")
+            .ok_or_else(|| invalid("missing header line"))?;
+
+        let fields = lines
+            .skip(1)
+            .map(|line| {
+                line.split_once(": ")
+                    .ok_or_else(|| invalid(format!("malformed line: {line}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| *value)
+                .ok_or_else(|| invalid(format!("missing {name}")))
+        };
+
+        let action = match field("Action")? {
+            "login" => MessageAction::Login,
+            "link-discord" => MessageAction::LinkDiscord {
+                discord_id: field("Discord ID")?.to_string(),
+            },
+            "vote" => MessageAction::Vote {
+                proposal: field("Proposal")?.to_string(),
+                choice: field("Choice")?.to_string(),
+            },
+            other => return Err(invalid(format!("unknown action: {other}"))),
+        };
+        let issued_at = parse_timestamp(field("Issued At")?)?;
+        let valid_for = match field("Expires At") {
+            Ok(value) => Some(
+                parse_timestamp(value)?
+                    .checked_sub(issued_at)
+                    .ok_or_else(|| invalid("expires before it was issued"))?,
+            ),
+            Err(_) => None,
+        };
+
+        let message = Self {
+            domain: domain.to_string(),
+            action,
+            address: field("Address")?.to_string(),
+            nonce: field("Nonce")?.to_string(),
+            issued_at,
+            valid_for,
+        };
+        if message.to_text() != text {
+            return Err(invalid("not in canonical form"));
+        }
+        Ok(message)
+    }
+
+    /// Parse a hex `signData` payload
+    pub fn from_hex(payload_hex: &str) -> Result<Self, PallasError> {
+        let bytes = hex::decode(payload_hex)?;
+        let text = String::from_utf8(bytes).map_err(|_| invalid("payload is not UTF-8"))?;
+        Self::parse(&text)
+    }
+}
+
+/// What a signed message must satisfy to be accepted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessagePolicy {
+    /// Domain the message must name
+    pub domain: String,
+    /// Nonce the message must carry, if the caller checks it here
+    pub nonce: Option<String>,
+    /// Current time (unix seconds)
+    pub now: u64,
+    /// Oldest acceptable `issued_at`, as seconds before `now`
+    pub max_age: u64,
+    /// Allowed clock difference between signer and verifier
+    pub clock_skew: u64,
+}
+
+impl MessagePolicy {
+    /// Policy for `domain` at `now`, with default age and skew limits
+    pub fn new(domain: impl Into<String>, now: u64) -> Self {
+        Self {
+            domain: domain.into(),
+            nonce: None,
+            now,
+            max_age: DEFAULT_MAX_AGE_SECS,
+            clock_skew: DEFAULT_CLOCK_SKEW_SECS,
+        }
+    }
+
+    /// Require this nonce
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = Some(nonce.into());
+        self
+    }
+
+    /// Override the maximum age in seconds
+    pub fn max_age(mut self, secs: u64) -> Self {
+        self.max_age = secs;
+        self
+    }
+
+    /// Check a parsed message's domain, nonce and timestamps
+    pub fn check(&self, message: &SignMessage) -> Result<(), PallasError> {
+        if message.domain != self.domain {
+            return Err(invalid(format!("signed for {}", message.domain)));
+        }
+        if self.nonce.as_ref().is_some_and(|n| *n != message.nonce) {
+            return Err(invalid("nonce mismatch"));
+        }
+        if message.issued_at > self.now.saturating_add(self.clock_skew) {
+            return Err(invalid("issued in the future"));
+        }
+        if self.now.saturating_sub(message.issued_at) > self.max_age.saturating_add(self.clock_skew)
+        {
+            return Err(invalid("too old"));
+        }
+        if message
+            .expires_at()
+            .is_some_and(|at| self.now > at.saturating_add(self.clock_skew))
+        {
+            return Err(invalid("expired"));
+        }
+        Ok(())
+    }
+}
+
+/// A message whose signature and policy checks passed
+#[derive(Debug, Clone)]
+pub struct VerifiedMessage {
+    /// The signed message
+    pub message: SignMessage,
+    /// The signer's public key (hex)
+    pub public_key_hex: String,
+    /// The signer's payment key hash
+    pub key_hash: [u8; 28],
+}
+
+/// Verify a `signData` result carrying a [`SignMessage`]
+///
+/// Checks the Ed25519 signature over the COSE_Sign1 payload, that the
+/// signing key is the payment key of the message's address, and the
+/// message against `policy`. The message is read from the signature itself,
+/// so the client only needs to send back `signature_hex` and `key_hex`.
+pub fn verify_signed_message(
+    signature_hex: &str,
+    key_hex: &str,
+    policy: &MessagePolicy,
+) -> Result<VerifiedMessage, PallasError> {
+    let signature_bytes = hex::decode(signature_hex)?;
+    let cose = CoseSign1::decode(&signature_bytes)?;
+    let public_key = extract_ed25519_public_key(&hex::decode(key_hex)?)?;
+
+    if !PublicKey::from(public_key).verify(cose.sig_structure(), &Signature::from(cose.signature)) {
+        return Err(PallasError::VerificationFailed);
+    }
+
+    let text = std::str::from_utf8(cose.payload).map_err(|_| invalid("payload is not UTF-8"))?;
+    let message = SignMessage::parse(text)?;
+
+    let key_hash = compute_key_hash(&public_key);
+    let address = Address::from_bech32(&message.address)?;
+    if address.payment_hash() != Some(key_hash) {
+        return Err(invalid("signing key does not own the address"));
+    }
+    policy.check(&message)?;
+
+    Ok(VerifiedMessage {
+        message,
+        public_key_hex: hex::encode(public_key),
+        key_hash,
+    })
+}
+
+/// The parts of a COSE_Sign1 needed to verify it
+///
+/// `COSE_Sign1 = [protected: bstr, unprotected: {* label => any}, payload: bstr / nil, signature: bstr]`
+struct CoseSign1<'a> {
+    protected: &'a [u8],
+    payload: &'a [u8],
+    signature: [u8; 64],
+}
+
+impl<'a> CoseSign1<'a> {
+    fn decode(bytes: &'a [u8]) -> Result<Self, PallasError> {
+        let mut d = Decoder::new(bytes);
+        if d.datatype()? == Type::Tag {
+            d.tag()?;
+        }
+        if d.array()? != Some(4) {
+            return Err(PallasError::InvalidSignature(
+                "COSE_Sign1 must be a 4-element array".into(),
+            ));
+        }
+        let protected = d.bytes()?;
+
+        // Hashed payloads only carry a digest of the message, which can't be parsed
+        let entries = d
+            .map()?
+            .ok_or_else(|| PallasError::InvalidSignature("indefinite unprotected header".into()))?;
+        for _ in 0..entries {
+            let is_hashed = if d.datatype()? == Type::String {
+                d.str()? == "hashed"
+            } else {
+                d.skip()?;
+                false
+            };
+            if !is_hashed {
+                d.skip()?;
+            } else if d.bool()? {
+                return Err(PallasError::InvalidSignature(
+                    "hashed payloads are not supported".into(),
+                ));
+            }
+        }
+
+        if d.datatype()? == Type::Null {
+            return Err(PallasError::InvalidSignature("detached payload".into()));
+        }
+        let payload = d.bytes()?;
+        let signature = d.bytes()?.try_into().map_err(|_| {
+            PallasError::InvalidSignature("Ed25519 signature must be 64 bytes".into())
+        })?;
+
+        Ok(Self {
+            protected,
+            payload,
+            signature,
+        })
+    }
+
+    /// `Sig_structure = ["Signature1", protected, external_aad: bstr, payload]`
+    fn sig_structure(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        let mut e = Encoder::new(&mut buf);
+        let _ = e
+            .array(4)
+            .and_then(|e| e.str("Signature1"))
+            .and_then(|e| e.bytes(self.protected))
+            .and_then(|e| e.bytes(&[]))
+            .and_then(|e| e.bytes(self.payload));
+        buf
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> PallasError {
+    PallasError::InvalidMessage(reason.into())
+}
+
+/// Unix seconds as `YYYY-MM-DDTHH:MM:SSZ`
+fn format_timestamp(unix_secs: u64) -> String {
+    let days = (unix_secs / 86_400) as i64;
+    let secs = unix_secs % 86_400;
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// Parse `YYYY-MM-DDTHH:MM:SSZ` into unix seconds
+fn parse_timestamp(value: &str) -> Result<u64, PallasError> {
+    let err = || invalid(format!("bad timestamp: {value}"));
+    let bytes = value.as_bytes();
+    if bytes.len() != 20
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || bytes[10] != b'T'
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+        || bytes[19] != b'Z'
+    {
+        return Err(err());
+    }
+    let num = |range: std::ops::Range<usize>| value[range].parse::<u64>().map_err(|_| err());
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return Err(err());
+    }
+    let days = days_from_civil(year as i64, month as u32, day as u32);
+    let days = u64::try_from(days).map_err(|_| err())?;
+    Ok(days * 86_400 + hour * 3600 + minute * 60 + second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pallas_crypto::key::ed25519::SecretKey;
+
+    const ISSUED: u64 = 1_714_564_800; // 2024-05-01T12:00:00Z
+
+    fn enterprise_address(key_hash: &[u8; 28]) -> String {
+        let mut bytes = vec![0x61];
+        bytes.extend_from_slice(key_hash);
+        Address::from_bytes(&bytes).unwrap().to_bech32().unwrap()
+    }
+
+    /// Sign `payload` the way a CIP-30 wallet does; returns (signature_hex, key_hex, address)
+    fn wallet_sign(seed: u8, payload: &[u8]) -> (String, String, String) {
+        let secret = SecretKey::from([seed; 32]);
+        let public: [u8; 32] = secret.public_key().as_ref().try_into().unwrap();
+
+        let mut protected = Vec::new();
+        Encoder::new(&mut protected)
+            .map(1)
+            .unwrap()
+            .u8(1)
+            .unwrap()
+            .i8(-8)
+            .unwrap();
+
+        let mut sig_structure = Vec::new();
+        Encoder::new(&mut sig_structure)
+            .array(4)
+            .and_then(|e| e.str("Signature1"))
+            .and_then(|e| e.bytes(&protected))
+            .and_then(|e| e.bytes(&[]))
+            .and_then(|e| e.bytes(payload))
+            .unwrap();
+        let signature = secret.sign(&sig_structure);
+
+        let mut cose = Vec::new();
+        Encoder::new(&mut cose)
+            .array(4)
+            .and_then(|e| e.bytes(&protected))
+            .and_then(|e| e.map(1))
+            .and_then(|e| e.str("hashed"))
+            .and_then(|e| e.bool(false))
+            .and_then(|e| e.bytes(payload))
+            .and_then(|e| e.bytes(signature.as_ref()))
+            .unwrap();
+
+        let mut key = Vec::new();
+        Encoder::new(&mut key)
+            .map(4)
+            .and_then(|e| e.u8(1))
+            .and_then(|e| e.u8(1))
+            .and_then(|e| e.u8(3))
+            .and_then(|e| e.i8(-8))
+            .and_then(|e| e.i8(-1))
+            .and_then(|e| e.u8(6))
+            .and_then(|e| e.i8(-2))
+            .and_then(|e| e.bytes(&public))
+            .unwrap();
+
+        let address = enterprise_address(&compute_key_hash(&public));
+        (hex::encode(cose), hex::encode(key), address)
+    }
+
+    fn signed_login(seed: u8) -> (String, String, SignMessage) {
+        let (_, _, address) = wallet_sign(seed, b"");
+        let message = SignMessage::login("raffle.example", "abc123")
+            .address(address)
+            .issued_at(ISSUED)
+            .valid_for(300);
+        let (signature, key, _) = wallet_sign(seed, message.to_text().as_bytes());
+        (signature, key, message)
+    }
+
+    #[test]
+    fn test_timestamps_roundtrip() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(ISSUED), "2024-05-01T12:00:00Z");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+        for secs in [0, ISSUED, 951_782_400, 4_102_444_799] {
+            assert_eq!(parse_timestamp(&format_timestamp(secs)).unwrap(), secs);
+        }
+        assert!(parse_timestamp("2024-13-01T00:00:00Z").is_err());
+        assert!(parse_timestamp("2024-05-01 12:00:00").is_err());
+    }
+
+    #[test]
+    fn test_canonical_text() {
+        let message = SignMessage::vote("dao.example", "n1", "CIP-1694 budget", "yes")
+            .address("addr1xyz")
+            .issued_at(ISSUED)
+            .valid_for(60);
+        assert_eq!(
+            message.to_text(),
+            "dao.example wants you to cast a vote with your Cardano wallet:\n\
+             \n\
+             Address: addr1xyz\n\
+             Action: vote\n\
+             Proposal: CIP-1694 budget\n\
+             Choice: yes\n\
+             Nonce: n1\n\
+             Issued At: 2024-05-01T12:00:00Z\n\
+             Expires At: 2024-05-01T12:01:00Z"
+        );
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let messages = [
+            SignMessage::login("a.example", "n").issued_at(ISSUED),
+            SignMessage::link_discord("a.example", "n", "1234").valid_for(10),
+            SignMessage::vote("a.example", "n", "p", "no").address("addr1q"),
+        ];
+        for message in messages {
+            assert_eq!(SignMessage::from_hex(&message.to_hex()).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_non_canonical() {
+        let text = SignMessage::login("a.example", "n").to_text();
+        assert!(SignMessage::parse(&format!("{text}\nExtra: line")).is_err());
+        assert!(SignMessage::parse(&text.replace("Action: login", "Action: logout")).is_err());
+        let reordered = text.replace("Address: \nAction: login", "Action: login\nAddress: ");
+        assert!(SignMessage::parse(&reordered).is_err());
+    }
+
+    #[test]
+    fn test_verify_signed_message() {
+        let (signature, key, message) = signed_login(7);
+        let policy = MessagePolicy::new("raffle.example", ISSUED + 10).nonce("abc123");
+
+        let verified = verify_signed_message(&signature, &key, &policy).unwrap();
+        assert_eq!(verified.message, message);
+    }
+
+    #[test]
+    fn test_verify_rejects_policy_failures() {
+        let (signature, key, _) = signed_login(7);
+        let ok = MessagePolicy::new("raffle.example", ISSUED);
+
+        for policy in [
+            MessagePolicy::new("other.example", ISSUED),
+            ok.clone().nonce("replayed"),
+            MessagePolicy::new("raffle.example", ISSUED + 300 + 61),
+            MessagePolicy::new("raffle.example", ISSUED - 61),
+        ] {
+            assert!(matches!(
+                verify_signed_message(&signature, &key, &policy),
+                Err(PallasError::InvalidMessage(_))
+            ));
+        }
+        assert!(verify_signed_message(&signature, &key, &ok).is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_key() {
+        let (signature, _, _) = signed_login(7);
+        let (_, other_key, _) = wallet_sign(9, b"");
+        let policy = MessagePolicy::new("raffle.example", ISSUED);
+        assert!(matches!(
+            verify_signed_message(&signature, &other_key, &policy),
+            Err(PallasError::VerificationFailed)
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_foreign_address() {
+        // Validly signed by key 9, but claims key 7's address
+        let (_, _, address) = wallet_sign(7, b"");
+        let message = SignMessage::login("raffle.example", "n")
+            .address(address)
+            .issued_at(ISSUED);
+        let (signature, key, _) = wallet_sign(9, message.to_text().as_bytes());
+        let policy = MessagePolicy::new("raffle.example", ISSUED);
+        assert!(matches!(
+            verify_signed_message(&signature, &key, &policy),
+            Err(PallasError::InvalidMessage(_))
+        ));
+    }
+}
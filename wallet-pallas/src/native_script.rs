@@ -0,0 +1,712 @@
+//! Native (multi-signature and timelock) scripts
+//!
+//! Builds native scripts, computes their hash and derives the script
+//! address that holds funds under them, so treasury-style multisig flows
+//! don't need `cardano-cli`. Serde uses the `cardano-cli` JSON format
+//! (`{"type": "atLeast", "required": 2, "scripts": [...]}`), so existing
+//! script files can be loaded as-is.
+//!
+//! ```ignore
+//! use wallet_pallas::{native_script_witness_set, NativeScript, Network};
+//!
+//! // 2-of-3 treasury, spendable until slot 150_000_000
+//! let script = NativeScript::all([
+//!     NativeScript::at_least(2, [
+//!         NativeScript::sig_hex(alice)?,
+//!         NativeScript::sig_hex(bob)?,
+//!         NativeScript::sig_hex(carol)?,
+//!     ]),
+//!     NativeScript::before(150_000_000),
+//! ]);
+//!
+//! let address = script.enterprise_address(Network::Mainnet)?;
+//! let script_hash = script.hash_hex();
+//!
+//! // Witness set fragment to include in the transaction before `signTx`
+//! let witnesses = native_script_witness_set(&[script]);
+//! ```
+
+use crate::address::{Address, Network};
+use crate::output::{script_hash, ScriptLanguage, ScriptRef};
+use crate::PallasError;
+use pallas_codec::minicbor::{self, Decoder, Encoder};
+use serde::{Deserialize, Serialize};
+
+/// Key hash length in bytes
+const KEY_HASH_LEN: usize = 28;
+
+/// Shelley address header types with a script payment credential
+const HEADER_BASE_SCRIPT_KEY: u8 = 0x10;
+const HEADER_BASE_SCRIPT_SCRIPT: u8 = 0x30;
+const HEADER_ENTERPRISE_SCRIPT: u8 = 0x70;
+
+/// Witness set key holding native scripts
+const WITNESS_NATIVE_SCRIPTS: u32 = 1;
+
+/// Deepest script nesting accepted when decoding untrusted CBOR
+const MAX_DEPTH: usize = 64;
+
+/// A native script
+///
+/// Timelocks use slots: `After(s)` is valid from slot `s` onwards
+/// (`invalid_before`), `Before(s)` only before slot `s` (`invalid_hereafter`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum NativeScript {
+    /// Requires a signature from the key with this hash
+    Sig {
+        #[serde(rename = "keyHash", with = "hex_key_hash")]
+        key_hash: [u8; KEY_HASH_LEN],
+    },
+    /// Requires every sub-script
+    All { scripts: Vec<NativeScript> },
+    /// Requires at least one sub-script
+    Any { scripts: Vec<NativeScript> },
+    /// Requires at least `required` sub-scripts
+    AtLeast {
+        required: u32,
+        scripts: Vec<NativeScript>,
+    },
+    /// Valid from this slot onwards
+    After { slot: u64 },
+    /// Valid before this slot
+    Before { slot: u64 },
+}
+
+impl NativeScript {
+    /// Require a signature from `key_hash`
+    pub fn sig(key_hash: [u8; KEY_HASH_LEN]) -> Self {
+        NativeScript::Sig { key_hash }
+    }
+
+    /// Require a signature from a hex-encoded key hash
+    pub fn sig_hex(key_hash: &str) -> Result<Self, PallasError> {
+        Ok(Self::sig(parse_key_hash(key_hash)?))
+    }
+
+    /// Require every script
+    pub fn all(scripts: impl IntoIterator<Item = NativeScript>) -> Self {
+        NativeScript::All {
+            scripts: scripts.into_iter().collect(),
+        }
+    }
+
+    /// Require any one script
+    pub fn any(scripts: impl IntoIterator<Item = NativeScript>) -> Self {
+        NativeScript::Any {
+            scripts: scripts.into_iter().collect(),
+        }
+    }
+
+    /// Require at least `required` scripts
+    pub fn at_least(required: u32, scripts: impl IntoIterator<Item = NativeScript>) -> Self {
+        NativeScript::AtLeast {
+            required,
+            scripts: scripts.into_iter().collect(),
+        }
+    }
+
+    /// Valid from `slot` onwards
+    pub fn after(slot: u64) -> Self {
+        NativeScript::After { slot }
+    }
+
+    /// Valid only before `slot`
+    pub fn before(slot: u64) -> Self {
+        NativeScript::Before { slot }
+    }
+
+    /// CBOR encoding, as hashed and placed in witness sets
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        // Writing to a Vec can't fail
+        let _ = self.encode(&mut Encoder::new(&mut buf));
+        buf
+    }
+
+    /// Hex-encoded [`to_cbor`](Self::to_cbor)
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_cbor())
+    }
+
+    /// Decode a CBOR-encoded script, keeping the original bytes
+    ///
+    /// See [`DecodedNativeScript`] for why the bytes are kept.
+    pub fn from_cbor(bytes: &[u8]) -> Result<DecodedNativeScript, PallasError> {
+        let mut d = Decoder::new(bytes);
+        let script = Self::decode(&mut d, 0)?;
+        if d.position() != bytes.len() {
+            return Err(PallasError::InvalidScript(
+                "trailing bytes after native script".to_string(),
+            ));
+        }
+        Ok(DecodedNativeScript {
+            script,
+            cbor: bytes.to_vec(),
+        })
+    }
+
+    /// Decode a hex CBOR-encoded script, keeping the original bytes
+    pub fn from_hex(hex_str: &str) -> Result<DecodedNativeScript, PallasError> {
+        Self::from_cbor(&hex::decode(hex_str)?)
+    }
+
+    /// Script hash, used as the address credential and as a policy ID
+    pub fn hash(&self) -> [u8; 28] {
+        script_hash(ScriptLanguage::Native, &self.to_cbor())
+    }
+
+    /// Hex-encoded [`hash`](Self::hash)
+    pub fn hash_hex(&self) -> String {
+        hex::encode(self.hash())
+    }
+
+    /// Address locked by this script, with no stake credential
+    pub fn enterprise_address(&self, network: Network) -> Result<Address, PallasError> {
+        self.address(HEADER_ENTERPRISE_SCRIPT, network, None)
+    }
+
+    /// Address locked by this script, delegating with a stake key hash
+    pub fn base_address(
+        &self,
+        network: Network,
+        stake_key_hash: [u8; KEY_HASH_LEN],
+    ) -> Result<Address, PallasError> {
+        self.address(HEADER_BASE_SCRIPT_KEY, network, Some(stake_key_hash))
+    }
+
+    /// Address locked by this script, delegating with a stake script
+    pub fn base_address_with_stake_script(
+        &self,
+        network: Network,
+        stake_script: &NativeScript,
+    ) -> Result<Address, PallasError> {
+        self.address(
+            HEADER_BASE_SCRIPT_SCRIPT,
+            network,
+            Some(stake_script.hash()),
+        )
+    }
+
+    fn address(
+        &self,
+        header: u8,
+        network: Network,
+        stake: Option<[u8; KEY_HASH_LEN]>,
+    ) -> Result<Address, PallasError> {
+        script_address(self.hash(), header, network, stake)
+    }
+
+    /// Reference script form, for outputs that carry the script
+    pub fn script_ref(&self) -> ScriptRef {
+        ScriptRef {
+            language: ScriptLanguage::Native,
+            hash: self.hash_hex(),
+            script: self.to_hex(),
+        }
+    }
+
+    /// Key hashes that appear anywhere in the script, in order, without duplicates
+    ///
+    /// The candidates for a transaction's `required_signers`.
+    pub fn key_hashes(&self) -> Vec<[u8; KEY_HASH_LEN]> {
+        let mut hashes = Vec::new();
+        self.collect_key_hashes(&mut hashes);
+        hashes
+    }
+
+    fn collect_key_hashes(&self, hashes: &mut Vec<[u8; KEY_HASH_LEN]>) {
+        match self {
+            NativeScript::Sig { key_hash } => {
+                if !hashes.contains(key_hash) {
+                    hashes.push(*key_hash);
+                }
+            }
+            NativeScript::All { scripts }
+            | NativeScript::Any { scripts }
+            | NativeScript::AtLeast { scripts, .. } => {
+                for script in scripts {
+                    script.collect_key_hashes(hashes);
+                }
+            }
+            NativeScript::After { .. } | NativeScript::Before { .. } => {}
+        }
+    }
+
+    /// Whether signatures from `signers` satisfy the script at `slot`
+    ///
+    /// Useful for checking collected signatures before submitting.
+    pub fn is_satisfied(&self, signers: &[[u8; KEY_HASH_LEN]], slot: u64) -> bool {
+        match self {
+            NativeScript::Sig { key_hash } => signers.contains(key_hash),
+            NativeScript::All { scripts } => scripts.iter().all(|s| s.is_satisfied(signers, slot)),
+            NativeScript::Any { scripts } => scripts.iter().any(|s| s.is_satisfied(signers, slot)),
+            NativeScript::AtLeast { required, scripts } => {
+                let met = scripts
+                    .iter()
+                    .filter(|s| s.is_satisfied(signers, slot))
+                    .count();
+                met >= *required as usize
+            }
+            NativeScript::After { slot: from } => slot >= *from,
+            NativeScript::Before { slot: until } => slot < *until,
+        }
+    }
+
+    /// `native_script = [0, addr_keyhash] / [1, [*script]] / [2, [*script]]
+    ///     / [3, n, [*script]] / [4, slot] / [5, slot]`
+    fn encode(
+        &self,
+        e: &mut Encoder<&mut Vec<u8>>,
+    ) -> Result<(), minicbor::encode::Error<std::convert::Infallible>> {
+        match self {
+            NativeScript::Sig { key_hash } => {
+                e.array(2)?.u8(0)?.bytes(key_hash)?;
+            }
+            NativeScript::All { scripts } => {
+                e.array(2)?.u8(1)?;
+                encode_scripts(e, scripts)?;
+            }
+            NativeScript::Any { scripts } => {
+                e.array(2)?.u8(2)?;
+                encode_scripts(e, scripts)?;
+            }
+            NativeScript::AtLeast { required, scripts } => {
+                e.array(3)?.u8(3)?.u32(*required)?;
+                encode_scripts(e, scripts)?;
+            }
+            NativeScript::After { slot } => {
+                e.array(2)?.u8(4)?.u64(*slot)?;
+            }
+            NativeScript::Before { slot } => {
+                e.array(2)?.u8(5)?.u64(*slot)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn decode(d: &mut Decoder<'_>, depth: usize) -> Result<Self, PallasError> {
+        if depth > MAX_DEPTH {
+            return Err(PallasError::InvalidScript(format!(
+                "native script nested deeper than {MAX_DEPTH}"
+            )));
+        }
+        d.array()?;
+        match d.u8()? {
+            0 => Ok(Self::sig(to_key_hash(d.bytes()?)?)),
+            1 => Ok(NativeScript::All {
+                scripts: decode_scripts(d, depth)?,
+            }),
+            2 => Ok(NativeScript::Any {
+                scripts: decode_scripts(d, depth)?,
+            }),
+            3 => {
+                let required = d.u32()?;
+                Ok(NativeScript::AtLeast {
+                    required,
+                    scripts: decode_scripts(d, depth)?,
+                })
+            }
+            4 => Ok(Self::after(d.u64()?)),
+            5 => Ok(Self::before(d.u64()?)),
+            other => Err(PallasError::InvalidScript(format!(
+                "Unknown native script type: {other}"
+            ))),
+        }
+    }
+}
+
+/// A native script decoded from CBOR, with the bytes it was decoded from
+///
+/// The ledger hashes scripts exactly as they were serialized. A script
+/// decoded from non-canonical CBOR (indefinite-length arrays, oversized
+/// integer heads) re-encodes to different bytes, so the hash, addresses and
+/// reference script form here all use the original bytes. Derefs to the
+/// parsed [`NativeScript`]; put [`cbor`](Self::cbor) in witness sets rather
+/// than a re-encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedNativeScript {
+    script: NativeScript,
+    cbor: Vec<u8>,
+}
+
+impl DecodedNativeScript {
+    /// The parsed script
+    pub fn script(&self) -> &NativeScript {
+        &self.script
+    }
+
+    /// Take the parsed script, dropping the original bytes
+    pub fn into_script(self) -> NativeScript {
+        self.script
+    }
+
+    /// The bytes the script was decoded from
+    pub fn cbor(&self) -> &[u8] {
+        &self.cbor
+    }
+
+    /// Hex-encoded [`cbor`](Self::cbor)
+    pub fn to_hex(&self) -> String {
+        hex::encode(&self.cbor)
+    }
+
+    /// Script hash of the original bytes
+    pub fn hash(&self) -> [u8; 28] {
+        script_hash(ScriptLanguage::Native, &self.cbor)
+    }
+
+    /// Hex-encoded [`hash`](Self::hash)
+    pub fn hash_hex(&self) -> String {
+        hex::encode(self.hash())
+    }
+
+    /// Address locked by this script, with no stake credential
+    pub fn enterprise_address(&self, network: Network) -> Result<Address, PallasError> {
+        script_address(self.hash(), HEADER_ENTERPRISE_SCRIPT, network, None)
+    }
+
+    /// Address locked by this script, delegating with a stake key hash
+    pub fn base_address(
+        &self,
+        network: Network,
+        stake_key_hash: [u8; KEY_HASH_LEN],
+    ) -> Result<Address, PallasError> {
+        script_address(
+            self.hash(),
+            HEADER_BASE_SCRIPT_KEY,
+            network,
+            Some(stake_key_hash),
+        )
+    }
+
+    /// Address locked by this script, delegating with the stake script
+    /// whose hash is `stake_script_hash`
+    pub fn base_address_with_stake_script(
+        &self,
+        network: Network,
+        stake_script_hash: [u8; KEY_HASH_LEN],
+    ) -> Result<Address, PallasError> {
+        script_address(
+            self.hash(),
+            HEADER_BASE_SCRIPT_SCRIPT,
+            network,
+            Some(stake_script_hash),
+        )
+    }
+
+    /// Reference script form, carrying the original bytes
+    pub fn script_ref(&self) -> ScriptRef {
+        ScriptRef {
+            language: ScriptLanguage::Native,
+            hash: self.hash_hex(),
+            script: self.to_hex(),
+        }
+    }
+}
+
+impl std::ops::Deref for DecodedNativeScript {
+    type Target = NativeScript;
+
+    fn deref(&self) -> &NativeScript {
+        &self.script
+    }
+}
+
+impl PartialEq<NativeScript> for DecodedNativeScript {
+    fn eq(&self, other: &NativeScript) -> bool {
+        self.script == *other
+    }
+}
+
+/// Witness set containing only `scripts` (hex CBOR: `{1: [*native_script]}`)
+///
+/// Place the scripts in the transaction's witness set before handing it to
+/// the wallet's `signTx`; each signer returns vkey witnesses to merge in.
+pub fn native_script_witness_set(scripts: &[NativeScript]) -> String {
+    let mut buf = Vec::new();
+    let mut e = Encoder::new(&mut buf);
+    let _ = e
+        .map(1)
+        .and_then(|e| e.u32(WITNESS_NATIVE_SCRIPTS))
+        .and_then(|e| encode_scripts(e, scripts));
+    hex::encode(buf)
+}
+
+fn encode_scripts<'a, 'b>(
+    e: &'a mut Encoder<&'b mut Vec<u8>>,
+    scripts: &[NativeScript],
+) -> Result<&'a mut Encoder<&'b mut Vec<u8>>, minicbor::encode::Error<std::convert::Infallible>> {
+    e.array(scripts.len() as u64)?;
+    for script in scripts {
+        script.encode(e)?;
+    }
+    Ok(e)
+}
+
+fn decode_scripts(d: &mut Decoder<'_>, depth: usize) -> Result<Vec<NativeScript>, PallasError> {
+    let len = d.array()?;
+    let mut scripts = Vec::new();
+    while len.is_none_or(|n| (scripts.len() as u64) < n) {
+        if len.is_none() && d.datatype()? == minicbor::data::Type::Break {
+            d.skip()?;
+            break;
+        }
+        scripts.push(NativeScript::decode(d, depth + 1)?);
+    }
+    Ok(scripts)
+}
+
+fn script_address(
+    hash: [u8; KEY_HASH_LEN],
+    header: u8,
+    network: Network,
+    stake: Option<[u8; KEY_HASH_LEN]>,
+) -> Result<Address, PallasError> {
+    let mut bytes = vec![header | network.id()];
+    bytes.extend_from_slice(&hash);
+    if let Some(stake) = stake {
+        bytes.extend_from_slice(&stake);
+    }
+    Address::from_bytes(&bytes)
+}
+
+fn to_key_hash(bytes: &[u8]) -> Result<[u8; KEY_HASH_LEN], PallasError> {
+    bytes.try_into().map_err(|_| {
+        PallasError::InvalidScript(format!(
+            "key hash must be {KEY_HASH_LEN} bytes, got {}",
+            bytes.len()
+        ))
+    })
+}
+
+fn parse_key_hash(hex_str: &str) -> Result<[u8; KEY_HASH_LEN], PallasError> {
+    to_key_hash(&hex::decode(hex_str)?)
+}
+
+/// Key hashes as hex strings, as `cardano-cli` writes them
+mod hex_key_hash {
+    use super::{parse_key_hash, KEY_HASH_LEN};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        hash: &[u8; KEY_HASH_LEN],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(hash))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; KEY_HASH_LEN], D::Error> {
+        let hex_str = String::deserialize(deserializer)?;
+        parse_key_hash(&hex_str).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: [u8; 28] = [0xaa; 28];
+    const BOB: [u8; 28] = [0xbb; 28];
+    const CAROL: [u8; 28] = [0xcc; 28];
+
+    fn two_of_three() -> NativeScript {
+        NativeScript::at_least(
+            2,
+            [
+                NativeScript::sig(ALICE),
+                NativeScript::sig(BOB),
+                NativeScript::sig(CAROL),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_cbor_encoding() {
+        let sig = NativeScript::sig(ALICE).to_cbor();
+        // [0, h'aa..'] with a 28-byte string header (0x58 0x1c)
+        assert_eq!(&sig[..4], &[0x82, 0x00, 0x58, 0x1c]);
+        assert_eq!(&sig[4..], &ALICE);
+
+        assert_eq!(NativeScript::after(10).to_cbor(), vec![0x82, 0x04, 0x0a]);
+        assert_eq!(NativeScript::before(10).to_cbor(), vec![0x82, 0x05, 0x0a]);
+
+        let all = NativeScript::all([NativeScript::after(1)]).to_cbor();
+        assert_eq!(all, vec![0x82, 0x01, 0x81, 0x82, 0x04, 0x01]);
+
+        let at_least = two_of_three().to_cbor();
+        assert_eq!(&at_least[..4], &[0x83, 0x03, 0x02, 0x83]);
+    }
+
+    #[test]
+    fn test_cbor_roundtrip() {
+        let script = NativeScript::all([two_of_three(), NativeScript::before(150_000_000)]);
+        assert_eq!(NativeScript::from_hex(&script.to_hex()).unwrap(), script);
+        assert!(NativeScript::from_cbor(&[0x82, 0x09, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_decode_keeps_original_bytes() {
+        // all [after 1] with an indefinite-length script list
+        let non_canonical = [0x82, 0x01, 0x9f, 0x82, 0x04, 0x01, 0xff];
+        let decoded = NativeScript::from_cbor(&non_canonical).unwrap();
+        let script = NativeScript::all([NativeScript::after(1)]);
+        assert_eq!(decoded, script);
+        assert_ne!(decoded.to_cbor(), non_canonical);
+        assert_eq!(decoded.cbor(), non_canonical);
+        assert_eq!(
+            decoded.hash(),
+            script_hash(ScriptLanguage::Native, &non_canonical)
+        );
+        assert_ne!(decoded.hash(), script.hash());
+        assert_eq!(
+            decoded
+                .enterprise_address(Network::Mainnet)
+                .unwrap()
+                .script_hash(),
+            Some(decoded.hash())
+        );
+        assert_eq!(decoded.script_ref().script, hex::encode(non_canonical));
+
+        assert!(NativeScript::from_cbor(&[0x82, 0x04, 0x01, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_decode_depth_limit() {
+        // all [all [... [after 1]]], `depth` levels of nesting
+        let nested = |depth: usize| {
+            let mut bytes = [0x82, 0x01, 0x81].repeat(depth);
+            bytes.extend_from_slice(&[0x82, 0x04, 0x01]);
+            bytes
+        };
+        assert!(NativeScript::from_cbor(&nested(MAX_DEPTH)).is_ok());
+        assert!(NativeScript::from_cbor(&nested(MAX_DEPTH + 1)).is_err());
+        assert!(NativeScript::from_cbor(&nested(100_000)).is_err());
+    }
+
+    #[test]
+    fn test_known_hash() {
+        // `{"type": "all", "scripts": []}`
+        let script = NativeScript::all([]);
+        assert_eq!(script.to_hex(), "820180");
+        assert_eq!(
+            script.hash_hex(),
+            "d441227553a0f1a965fee7d60a0f724b368dd1bddbc208730fccebcf"
+        );
+    }
+
+    #[test]
+    fn test_known_script_addresses() {
+        // CIP-19 test vectors
+        let hash: [u8; 28] =
+            hex::decode("c37b1b5dc0669f1d3c61a6fddb2e8fde96be87b881c60bce8e8d542f")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let stake: [u8; 28] =
+            hex::decode("337b62cfff6403a06a3acbc34f8c46003c69fe79a3628cefa9c47251")
+                .unwrap()
+                .try_into()
+                .unwrap();
+
+        let enterprise =
+            script_address(hash, HEADER_ENTERPRISE_SCRIPT, Network::Mainnet, None).unwrap();
+        assert_eq!(
+            enterprise.to_bech32().unwrap(),
+            "addr1w8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcyjy7wx"
+        );
+
+        let base =
+            script_address(hash, HEADER_BASE_SCRIPT_KEY, Network::Mainnet, Some(stake)).unwrap();
+        assert_eq!(
+            base.to_bech32().unwrap(),
+            "addr1z8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gten0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs9yc0hh"
+        );
+
+        let scripted = script_address(
+            hash,
+            HEADER_BASE_SCRIPT_SCRIPT,
+            Network::Mainnet,
+            Some(hash),
+        )
+        .unwrap();
+        assert_eq!(
+            scripted.to_bech32().unwrap(),
+            "addr1x8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gt7r0vd4msrxnuwnccdxlhdjar77j6lg0wypcc9uar5d2shskhj42g"
+        );
+    }
+
+    #[test]
+    fn test_hash_matches_reference_script_hash() {
+        let script = two_of_three();
+        assert_eq!(
+            script.hash(),
+            script_hash(ScriptLanguage::Native, &script.to_cbor())
+        );
+        assert_eq!(script.script_ref().hash, script.hash_hex());
+    }
+
+    #[test]
+    fn test_script_addresses() {
+        let script = two_of_three();
+
+        let enterprise = script.enterprise_address(Network::Mainnet).unwrap();
+        assert_eq!(enterprise.to_bytes()[0], 0x71);
+        assert!(enterprise.is_script());
+        assert_eq!(enterprise.script_hash(), Some(script.hash()));
+        assert!(enterprise.to_bech32().unwrap().starts_with("addr1"));
+
+        let base = script.base_address(Network::Testnet, ALICE).unwrap();
+        assert_eq!(base.to_bytes()[0], 0x10);
+        assert_eq!(base.stake_hash(), Some(ALICE));
+        assert!(base.to_bech32().unwrap().starts_with("addr_test1"));
+
+        let stake_script = NativeScript::sig(BOB);
+        let scripted = script
+            .base_address_with_stake_script(Network::Mainnet, &stake_script)
+            .unwrap();
+        assert_eq!(scripted.to_bytes()[0], 0x31);
+        assert_eq!(scripted.stake_hash(), Some(stake_script.hash()));
+    }
+
+    #[test]
+    fn test_is_satisfied() {
+        let script = NativeScript::all([two_of_three(), NativeScript::before(100)]);
+        assert!(script.is_satisfied(&[ALICE, CAROL], 50));
+        assert!(!script.is_satisfied(&[ALICE], 50));
+        assert!(!script.is_satisfied(&[ALICE, CAROL], 100));
+
+        let any = NativeScript::any([NativeScript::sig(BOB), NativeScript::after(10)]);
+        assert!(any.is_satisfied(&[], 10));
+        assert!(!any.is_satisfied(&[], 9));
+    }
+
+    #[test]
+    fn test_key_hashes_deduplicated() {
+        let script = NativeScript::any([two_of_three(), NativeScript::sig(ALICE)]);
+        assert_eq!(script.key_hashes(), vec![ALICE, BOB, CAROL]);
+    }
+
+    #[test]
+    fn test_cli_json_format() {
+        let json = format!(
+            r#"{{"type":"atLeast","required":1,"scripts":[{{"type":"sig","keyHash":"{}"}},{{"type":"after","slot":5}}]}}"#,
+            hex::encode(ALICE)
+        );
+        let script: NativeScript = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            script,
+            NativeScript::at_least(1, [NativeScript::sig(ALICE), NativeScript::after(5)])
+        );
+        assert_eq!(serde_json::to_string(&script).unwrap(), json);
+    }
+
+    #[test]
+    fn test_witness_set() {
+        let witnesses = native_script_witness_set(&[NativeScript::after(1)]);
+        // {1: [[4, 1]]}
+        assert_eq!(witnesses, "a10181820401");
+    }
+}
@@ -0,0 +1,357 @@
+//! Transaction output decoding
+//!
+//! Decodes outputs in both the legacy array form and the post-Alonzo map form,
+//! including datums and reference scripts, so dApps can recognise outputs at
+//! their own script addresses.
+
+use crate::address::{classify_address, AddressKind};
+use crate::value::value_to_balance;
+use crate::{PallasError, WalletBalance};
+use pallas_codec::minicbor::{data::Type, Decoder};
+use pallas_crypto::hash::Hasher;
+use pallas_primitives::conway::Value;
+use serde::{Deserialize, Serialize};
+
+/// CBOR tag wrapping embedded CBOR (inline datums, reference scripts)
+const CBOR_IN_CBOR_TAG: u64 = 24;
+
+/// Datum attached to an output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Datum {
+    /// Hash of a datum supplied by the spending transaction (hex)
+    Hash(String),
+    /// Datum stored in the output itself (CBOR-encoded PlutusData, hex)
+    Inline(String),
+}
+
+/// Script language of a reference script
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScriptLanguage {
+    Native,
+    PlutusV1,
+    PlutusV2,
+    PlutusV3,
+}
+
+impl ScriptLanguage {
+    fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(ScriptLanguage::Native),
+            1 => Some(ScriptLanguage::PlutusV1),
+            2 => Some(ScriptLanguage::PlutusV2),
+            3 => Some(ScriptLanguage::PlutusV3),
+            _ => None,
+        }
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            ScriptLanguage::Native => 0,
+            ScriptLanguage::PlutusV1 => 1,
+            ScriptLanguage::PlutusV2 => 2,
+            ScriptLanguage::PlutusV3 => 3,
+        }
+    }
+}
+
+/// Script stored in an output for use as a reference script
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptRef {
+    /// Script language
+    pub language: ScriptLanguage,
+    /// Script hash (hex), as it appears in script addresses and policy IDs
+    pub hash: String,
+    /// Script bytes (hex): the native script CBOR or the Plutus flat bytes
+    pub script: String,
+}
+
+/// A decoded transaction output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxOutput {
+    /// Address holding the output (hex)
+    pub address: String,
+    /// Lovelace and native assets held
+    pub value: WalletBalance,
+    /// Attached datum, if any
+    pub datum: Option<Datum>,
+    /// Attached reference script, if any
+    pub script_ref: Option<ScriptRef>,
+}
+
+impl TxOutput {
+    /// Kind of the output's address, `None` if it can't be parsed
+    pub fn address_kind(&self) -> Option<AddressKind> {
+        classify_address(&self.address).ok()
+    }
+
+    /// Whether the output is locked by a script
+    pub fn is_script(&self) -> bool {
+        self.address_kind() == Some(AddressKind::Script)
+    }
+}
+
+/// Decode a single transaction output at the decoder's position
+pub(crate) fn decode_output(d: &mut Decoder<'_>) -> Result<TxOutput, PallasError> {
+    match d.datatype()? {
+        // Legacy: [address, amount, ?datum_hash]
+        Type::Array | Type::ArrayIndef => {
+            let len = d.array()?;
+            let address = hex::encode(d.bytes()?);
+            let value: Value = d.decode()?;
+            let has_datum_hash = match len {
+                Some(n) => n > 2,
+                None => d.datatype()? != Type::Break,
+            };
+            let datum = if has_datum_hash {
+                Some(Datum::Hash(hex::encode(d.bytes()?)))
+            } else {
+                None
+            };
+            if len.is_none() {
+                d.skip()?;
+            }
+            Ok(TxOutput {
+                address,
+                value: value_to_balance(value),
+                datum,
+                script_ref: None,
+            })
+        }
+        // Post-Alonzo: { 0: address, 1: value, ?2: datum_option, ?3: script_ref }
+        Type::Map | Type::MapIndef => {
+            let len = d.map()?;
+            let mut address = None;
+            let mut value = None;
+            let mut datum = None;
+            let mut script_ref = None;
+            let mut seen = 0;
+            while len.is_none_or(|n| seen < n) {
+                if len.is_none() && d.datatype()? == Type::Break {
+                    d.skip()?;
+                    break;
+                }
+                match d.u32()? {
+                    0 => address = Some(hex::encode(d.bytes()?)),
+                    1 => value = Some(d.decode::<Value>()?),
+                    2 => datum = Some(decode_datum_option(d)?),
+                    3 => script_ref = Some(decode_script_ref(d)?),
+                    _ => d.skip()?,
+                }
+                seen += 1;
+            }
+            Ok(TxOutput {
+                address: address
+                    .ok_or_else(|| PallasError::CborDecode("Output missing address".into()))?,
+                value: value_to_balance(
+                    value.ok_or_else(|| PallasError::CborDecode("Output missing value".into()))?,
+                ),
+                datum,
+                script_ref,
+            })
+        }
+        other => Err(PallasError::CborDecode(format!(
+            "Unexpected output type: {other:?}"
+        ))),
+    }
+}
+
+/// `[0, datum_hash] / [1, #6.24(bytes .cbor plutus_data)]`
+fn decode_datum_option(d: &mut Decoder<'_>) -> Result<Datum, PallasError> {
+    d.array()?;
+    match d.u8()? {
+        0 => Ok(Datum::Hash(hex::encode(d.bytes()?))),
+        1 => Ok(Datum::Inline(hex::encode(decode_embedded_cbor(d)?))),
+        other => Err(PallasError::CborDecode(format!(
+            "Unknown datum option: {other}"
+        ))),
+    }
+}
+
+/// `#6.24(bytes .cbor [language, script])`
+fn decode_script_ref(d: &mut Decoder<'_>) -> Result<ScriptRef, PallasError> {
+    let embedded = decode_embedded_cbor(d)?;
+    let mut inner = Decoder::new(embedded);
+    inner.array()?;
+
+    let tag = inner.u8()?;
+    let language = ScriptLanguage::from_tag(tag)
+        .ok_or_else(|| PallasError::CborDecode(format!("Unknown script language: {tag}")))?;
+
+    let script: &[u8] = match language {
+        // Native scripts are inlined as CBOR rather than wrapped in bytes
+        ScriptLanguage::Native => {
+            let start = inner.position();
+            inner.skip()?;
+            &embedded[start..inner.position()]
+        }
+        _ => inner.bytes()?,
+    };
+
+    Ok(ScriptRef {
+        language,
+        hash: hex::encode(script_hash(language, script)),
+        script: hex::encode(script),
+    })
+}
+
+fn decode_embedded_cbor<'b>(d: &mut Decoder<'b>) -> Result<&'b [u8], PallasError> {
+    let tag = d.tag()?;
+    if tag.as_u64() != CBOR_IN_CBOR_TAG {
+        return Err(PallasError::CborDecode(format!(
+            "Expected embedded CBOR tag, got {}",
+            tag.as_u64()
+        )));
+    }
+    Ok(d.bytes()?)
+}
+
+/// Script hash: blake2b-224 of the language tag followed by the script bytes
+pub(crate) fn script_hash(language: ScriptLanguage, script: &[u8]) -> [u8; 28] {
+    let mut tagged = Vec::with_capacity(script.len() + 1);
+    tagged.push(language.tag());
+    tagged.extend_from_slice(script);
+
+    let hash = Hasher::<224>::hash(&tagged);
+    let mut result = [0u8; 28];
+    result.copy_from_slice(hash.as_ref());
+    result
+}
+
+/// Decode every output of a CBOR-encoded transaction
+///
+/// Only the outputs (body key 1) are decoded; everything else is skipped.
+pub(crate) fn decode_tx_outputs(tx_bytes: &[u8]) -> Result<Vec<TxOutput>, PallasError> {
+    let mut d = Decoder::new(tx_bytes);
+
+    // [body, witness_set, is_valid, auxiliary_data]
+    d.array()?;
+    let len = d.map()?;
+    let mut seen = 0;
+    while len.is_none_or(|n| seen < n) {
+        if len.is_none() && d.datatype()? == Type::Break {
+            break;
+        }
+        if d.u32()? == 1 {
+            let count = d.array()?;
+            let mut outputs = Vec::new();
+            while count.is_none_or(|n| (outputs.len() as u64) < n) {
+                if count.is_none() && d.datatype()? == Type::Break {
+                    break;
+                }
+                outputs.push(decode_output(&mut d)?);
+            }
+            return Ok(outputs);
+        }
+        d.skip()?;
+        seen += 1;
+    }
+
+    Err(PallasError::CborDecode(
+        "Transaction body has no outputs".into(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pallas_codec::minicbor::data::Tag;
+    use pallas_codec::minicbor::Encoder;
+
+    const SCRIPT_ADDRESS: [u8; 29] = {
+        // Enterprise script address (header 0x71: type 7, mainnet)
+        let mut a = [0x22; 29];
+        a[0] = 0x71;
+        a
+    };
+
+    fn embedded(inner: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        Encoder::new(&mut buf)
+            .tag(Tag::new(CBOR_IN_CBOR_TAG))
+            .unwrap()
+            .bytes(inner)
+            .unwrap();
+        buf
+    }
+
+    #[test]
+    fn test_decode_inline_datum_and_reference_script() {
+        let plutus_script = [0x49, 0x01, 0x00, 0x00, 0x22, 0x22, 0x20, 0x05, 0x01];
+
+        let mut datum = Vec::new();
+        Encoder::new(&mut datum).u64(42).unwrap();
+
+        let mut script = Vec::new();
+        Encoder::new(&mut script)
+            .array(2)
+            .unwrap()
+            .u8(2)
+            .unwrap()
+            .bytes(&plutus_script)
+            .unwrap();
+
+        let mut buf = Vec::new();
+        let mut e = Encoder::new(&mut buf);
+        e.map(4).unwrap();
+        e.u32(0).unwrap().bytes(&SCRIPT_ADDRESS).unwrap();
+        e.u32(1).unwrap().u64(5_000_000).unwrap();
+        e.u32(2).unwrap().array(2).unwrap().u8(1).unwrap();
+        e.writer_mut().extend_from_slice(&embedded(&datum));
+        e.u32(3).unwrap();
+        e.writer_mut().extend_from_slice(&embedded(&script));
+
+        let output = decode_output(&mut Decoder::new(&buf)).unwrap();
+        assert_eq!(output.value.lovelace, 5_000_000);
+        assert_eq!(output.datum, Some(Datum::Inline(hex::encode(&datum))));
+        assert!(output.is_script());
+
+        let script_ref = output.script_ref.unwrap();
+        assert_eq!(script_ref.language, ScriptLanguage::PlutusV2);
+        assert_eq!(script_ref.script, hex::encode(plutus_script));
+        assert_eq!(
+            script_ref.hash,
+            hex::encode(script_hash(ScriptLanguage::PlutusV2, &plutus_script))
+        );
+    }
+
+    #[test]
+    fn test_decode_legacy_datum_hash() {
+        let mut buf = Vec::new();
+        let mut e = Encoder::new(&mut buf);
+        e.array(3).unwrap();
+        e.bytes(&SCRIPT_ADDRESS).unwrap();
+        e.u64(2_000_000).unwrap();
+        e.bytes(&[0xdd; 32]).unwrap();
+
+        let output = decode_output(&mut Decoder::new(&buf)).unwrap();
+        assert_eq!(output.datum, Some(Datum::Hash(hex::encode([0xdd; 32]))));
+        assert!(output.script_ref.is_none());
+    }
+
+    #[test]
+    fn test_native_script_ref_hashes_inline_cbor() {
+        // Native script `sig <key hash>`: [0, h'..']
+        let mut native = Vec::new();
+        Encoder::new(&mut native)
+            .array(2)
+            .unwrap()
+            .u8(0)
+            .unwrap()
+            .bytes(&[0x33; 28])
+            .unwrap();
+
+        let mut script = Vec::new();
+        let mut e = Encoder::new(&mut script);
+        e.array(2).unwrap().u8(0).unwrap();
+        e.writer_mut().extend_from_slice(&native);
+
+        let buf = embedded(&script);
+        let script_ref = decode_script_ref(&mut Decoder::new(&buf)).unwrap();
+        assert_eq!(script_ref.language, ScriptLanguage::Native);
+        assert_eq!(script_ref.script, hex::encode(&native));
+        assert_eq!(
+            script_ref.hash,
+            hex::encode(script_hash(ScriptLanguage::Native, &native))
+        );
+    }
+}
@@ -1,11 +1,22 @@
 //! Transaction parsing and inspection utilities
 //!
-//! Parse Cardano transactions and witness sets to inspect their contents.
+//! Parse Cardano transactions and witness sets to inspect their contents, and
+//! check their VKey witness signatures.
 
+use crate::cip8::compute_key_hash;
+use crate::output::{decode_tx_outputs, TxOutput};
 use crate::PallasError;
-use pallas_codec::minicbor;
+use pallas_codec::minicbor::{self, data::Type, Decoder};
+use pallas_crypto::hash::Hasher;
+use pallas_crypto::key::ed25519::{PublicKey, Signature};
 use pallas_primitives::conway::{Tx, WitnessSet};
 
+/// Transaction body key holding the required signers
+const REQUIRED_SIGNERS_KEY: u32 = 14;
+
+/// CBOR tag for sets (Conway)
+const SET_TAG: u64 = 258;
+
 /// Information about a parsed transaction
 #[derive(Debug, Clone)]
 pub struct TransactionInfo {
@@ -23,6 +34,11 @@ pub struct TransactionInfo {
     pub has_scripts: bool,
     /// Number of VKey witnesses
     pub vkey_witness_count: usize,
+    /// Decoded outputs, including datums and reference scripts
+    ///
+    /// Best effort: `None` if any output has a shape the output decoder
+    /// doesn't understand, so the rest of the transaction still parses.
+    pub outputs: Option<Vec<TxOutput>>,
 }
 
 /// Information about a witness set
@@ -44,6 +60,48 @@ pub struct WitnessSetInfo {
     pub datums: usize,
 }
 
+/// Result of checking one VKey witness
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WitnessCheck {
+    /// The witness public key (hex)
+    pub vkey_hex: String,
+    /// blake2b-224 hash of the public key
+    pub key_hash: [u8; 28],
+    /// Whether the signature is valid for the transaction body
+    pub valid: bool,
+}
+
+/// Result of checking a transaction's VKey witnesses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WitnessVerification {
+    /// Transaction ID: blake2b-256 of the body as encoded
+    pub tx_hash: [u8; 32],
+    /// Every VKey witness, in witness set order
+    pub witnesses: Vec<WitnessCheck>,
+    /// Required signers (body key 14) without a valid witness
+    pub missing_signers: Vec<[u8; 28]>,
+}
+
+impl WitnessVerification {
+    /// Key hashes with a valid signature, e.g. for
+    /// [`NativeScript::is_satisfied`](crate::NativeScript::is_satisfied)
+    pub fn signers(&self) -> Vec<[u8; 28]> {
+        self.witnesses
+            .iter()
+            .filter(|w| w.valid)
+            .map(|w| w.key_hash)
+            .collect()
+    }
+
+    /// Whether every witness is valid and no required signer is missing
+    ///
+    /// Script witnesses aren't checked; a native script may still need more
+    /// signatures than the body lists.
+    pub fn is_complete(&self) -> bool {
+        self.missing_signers.is_empty() && self.witnesses.iter().all(|w| w.valid)
+    }
+}
+
 /// Parse a transaction from hex-encoded CBOR
 pub fn parse_transaction(tx_hex: &str) -> Result<TransactionInfo, PallasError> {
     let tx_bytes = hex::decode(tx_hex)?;
@@ -61,6 +119,8 @@ pub fn parse_transaction(tx_hex: &str) -> Result<TransactionInfo, PallasError> {
         || witness.plutus_v2_script.is_some()
         || witness.plutus_v3_script.is_some();
 
+    let outputs = decode_tx_outputs(&tx_bytes).ok();
+
     Ok(TransactionInfo {
         input_count: body.inputs.len(),
         output_count: body.outputs.len(),
@@ -69,6 +129,7 @@ pub fn parse_transaction(tx_hex: &str) -> Result<TransactionInfo, PallasError> {
         has_metadata: !matches!(tx.auxiliary_data, pallas_codec::utils::Nullable::Null),
         has_scripts,
         vkey_witness_count,
+        outputs,
     })
 }
 
@@ -129,3 +190,210 @@ pub fn extract_vkey_witnesses(witness_hex: &str) -> Result<Vec<(String, String)>
 
     Ok(result)
 }
+
+/// Check every VKey witness of a hex-encoded transaction
+///
+/// Each signature is verified against the hash of the body bytes as they
+/// appear in the transaction, so re-encoding can't change the result.
+/// Invalid witnesses are reported rather than returned as errors, which
+/// lets partially-signed multisig transactions be reviewed before
+/// submission.
+pub fn verify_tx_witnesses(tx_hex: &str) -> Result<WitnessVerification, PallasError> {
+    let tx_bytes = hex::decode(tx_hex)?;
+    let mut d = Decoder::new(&tx_bytes);
+
+    // [body, witness_set, is_valid, auxiliary_data]
+    d.array()?;
+    let body_start = d.position();
+    d.skip()?;
+    let body = &tx_bytes[body_start..d.position()];
+    let witness_start = d.position();
+    d.skip()?;
+    let witness_bytes = &tx_bytes[witness_start..d.position()];
+
+    let tx_hash = *Hasher::<256>::hash(body);
+    let witness: WitnessSet = minicbor::decode(witness_bytes)?;
+
+    let witnesses: Vec<WitnessCheck> = witness
+        .vkeywitness
+        .map(|witnesses| {
+            witnesses
+                .iter()
+                .map(|w| check_witness(w.vkey.as_ref(), w.signature.as_ref(), &tx_hash))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let missing_signers = required_signers(body)?
+        .into_iter()
+        .filter(|required| !witnesses.iter().any(|w| w.valid && w.key_hash == *required))
+        .collect();
+
+    Ok(WitnessVerification {
+        tx_hash,
+        witnesses,
+        missing_signers,
+    })
+}
+
+fn check_witness(vkey: &[u8], signature: &[u8], tx_hash: &[u8; 32]) -> WitnessCheck {
+    let key: Option<[u8; 32]> = vkey.try_into().ok();
+    let signature: Option<[u8; 64]> = signature.try_into().ok();
+    let valid = match (key, signature) {
+        (Some(key), Some(signature)) => {
+            PublicKey::from(key).verify(tx_hash, &Signature::from(signature))
+        }
+        _ => false,
+    };
+    WitnessCheck {
+        vkey_hex: hex::encode(vkey),
+        key_hash: key.map(|k| compute_key_hash(&k)).unwrap_or_default(),
+        valid,
+    }
+}
+
+/// Required signers (body key 14), with or without the set tag
+fn required_signers(body: &[u8]) -> Result<Vec<[u8; 28]>, PallasError> {
+    let mut d = Decoder::new(body);
+    let len = d.map()?;
+    let mut seen = 0;
+    while len.is_none_or(|n| seen < n) {
+        if len.is_none() && d.datatype()? == Type::Break {
+            break;
+        }
+        if d.u32()? != REQUIRED_SIGNERS_KEY {
+            d.skip()?;
+            seen += 1;
+            continue;
+        }
+
+        if d.datatype()? == Type::Tag {
+            let tag = d.tag()?;
+            if tag.as_u64() != SET_TAG {
+                return Err(PallasError::TransactionParse(format!(
+                    "unexpected tag {} on required signers",
+                    tag.as_u64()
+                )));
+            }
+        }
+        let count = d.array()?;
+        let mut signers = Vec::new();
+        while count.is_none_or(|n| (signers.len() as u64) < n) {
+            if count.is_none() && d.datatype()? == Type::Break {
+                break;
+            }
+            let hash = d.bytes()?;
+            signers.push(hash.try_into().map_err(|_| {
+                PallasError::TransactionParse(format!(
+                    "required signer is {} bytes, expected 28",
+                    hash.len()
+                ))
+            })?);
+        }
+        return Ok(signers);
+    }
+    Ok(Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minicbor::Encoder;
+    use pallas_crypto::key::ed25519::SecretKey;
+
+    fn keypair(seed: u8) -> (SecretKey, [u8; 32]) {
+        let secret = SecretKey::from([seed; 32]);
+        let public = secret.public_key().as_ref().try_into().unwrap();
+        (secret, public)
+    }
+
+    /// Body with one input, no outputs and the given required signers
+    fn body(required: &[[u8; 28]]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        let mut e = Encoder::new(&mut buf);
+        e.map(4).unwrap();
+        e.u8(0).unwrap().array(1).unwrap();
+        e.array(2)
+            .unwrap()
+            .bytes(&[0x11; 32])
+            .unwrap()
+            .u8(0)
+            .unwrap();
+        e.u8(1).unwrap().array(0).unwrap();
+        e.u8(2).unwrap().u64(180_000).unwrap();
+        e.u8(14)
+            .unwrap()
+            .tag(minicbor::data::Tag::new(SET_TAG))
+            .unwrap();
+        e.array(required.len() as u64).unwrap();
+        for hash in required {
+            e.bytes(hash).unwrap();
+        }
+        buf
+    }
+
+    fn tx(body: &[u8], witnesses: &[([u8; 32], Vec<u8>)]) -> String {
+        let mut buf = Vec::new();
+        let mut e = Encoder::new(&mut buf);
+        e.array(4).unwrap();
+        e.writer_mut().extend_from_slice(body);
+        e.map(1).unwrap().u8(0).unwrap();
+        e.array(witnesses.len() as u64).unwrap();
+        for (vkey, signature) in witnesses {
+            e.array(2)
+                .unwrap()
+                .bytes(vkey)
+                .unwrap()
+                .bytes(signature)
+                .unwrap();
+        }
+        e.bool(true).unwrap();
+        e.null().unwrap();
+        hex::encode(buf)
+    }
+
+    #[test]
+    fn test_verify_partially_signed_multisig() {
+        let (alice, alice_vkey) = keypair(1);
+        let (_, bob_vkey) = keypair(2);
+        let (carol, carol_vkey) = keypair(3);
+        let alice_hash = compute_key_hash(&alice_vkey);
+        let bob_hash = compute_key_hash(&bob_vkey);
+
+        let body = body(&[alice_hash, bob_hash]);
+        let tx_hash = *Hasher::<256>::hash(&body);
+        let tx_hex = tx(
+            &body,
+            &[
+                (alice_vkey, alice.sign(tx_hash).as_ref().to_vec()),
+                // Signed something else
+                (carol_vkey, carol.sign([0u8; 32]).as_ref().to_vec()),
+            ],
+        );
+
+        let result = verify_tx_witnesses(&tx_hex).unwrap();
+        assert_eq!(result.tx_hash, tx_hash);
+        assert_eq!(result.witnesses.len(), 2);
+        assert!(result.witnesses[0].valid);
+        assert_eq!(result.witnesses[0].key_hash, alice_hash);
+        assert!(!result.witnesses[1].valid);
+        assert_eq!(result.signers(), vec![alice_hash]);
+        assert_eq!(result.missing_signers, vec![bob_hash]);
+        assert!(!result.is_complete());
+    }
+
+    #[test]
+    fn test_verify_fully_signed() {
+        let (alice, alice_vkey) = keypair(1);
+        let body = body(&[compute_key_hash(&alice_vkey)]);
+        let tx_hash = *Hasher::<256>::hash(&body);
+        let tx_hex = tx(
+            &body,
+            &[(alice_vkey, alice.sign(tx_hash).as_ref().to_vec())],
+        );
+
+        let result = verify_tx_witnesses(&tx_hex).unwrap();
+        assert!(result.missing_signers.is_empty());
+        assert!(result.is_complete());
+    }
+}
@@ -0,0 +1,205 @@
+//! UTxO decoding utilities
+//!
+//! Decode CBOR-encoded `TransactionUnspentOutput` values returned by the CIP-30
+//! `getUtxos` call.
+
+use crate::address::{classify_address, AddressKind};
+use crate::output::{decode_output, Datum, ScriptRef};
+use crate::{PallasError, WalletBalance};
+use pallas_codec::minicbor::{self, Decoder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A decoded unspent transaction output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Utxo {
+    /// Hash of the transaction that created this output (hex)
+    pub tx_hash: String,
+    /// Output index within that transaction
+    pub index: u64,
+    /// Address holding the output (hex)
+    pub address: String,
+    /// Lovelace and native assets held
+    pub value: WalletBalance,
+    /// Attached datum, if any
+    pub datum: Option<Datum>,
+    /// Attached reference script, if any
+    pub script_ref: Option<ScriptRef>,
+}
+
+impl Utxo {
+    /// Output reference in `tx_hash#index` form
+    pub fn output_ref(&self) -> String {
+        format!("{}#{}", self.tx_hash, self.index)
+    }
+
+    /// Lovelace held by this output
+    pub fn lovelace(&self) -> u64 {
+        self.value.lovelace
+    }
+
+    /// Kind of the holding address, `None` if it can't be parsed
+    pub fn address_kind(&self) -> Option<AddressKind> {
+        classify_address(&self.address).ok()
+    }
+
+    /// Whether the output is locked by a script
+    pub fn is_script(&self) -> bool {
+        self.address_kind() == Some(AddressKind::Script)
+    }
+}
+
+/// Decode a CBOR-encoded TransactionUnspentOutput from the wallet's getUtxos response
+///
+/// The output may be in either the legacy array form or the post-Alonzo map
+/// form.
+pub fn decode_utxo(utxo_hex: &str) -> Result<Utxo, PallasError> {
+    let bytes = hex::decode(utxo_hex)?;
+    let mut d = Decoder::new(&bytes);
+    let err = |e: minicbor::decode::Error| PallasError::CborDecode(e.to_string());
+
+    // [input, output]
+    d.array().map_err(err)?;
+
+    // input = [tx_hash, index]
+    d.array().map_err(err)?;
+    let tx_hash = hex::encode(d.bytes().map_err(err)?);
+    let index = d.u64().map_err(err)?;
+
+    let output = decode_output(&mut d)?;
+
+    Ok(Utxo {
+        tx_hash,
+        index,
+        address: output.address,
+        value: output.value,
+        datum: output.datum,
+        script_ref: output.script_ref,
+    })
+}
+
+/// Sum the values of a set of UTxOs into a single balance
+pub fn sum_utxos(utxos: &[Utxo]) -> WalletBalance {
+    let mut lovelace = 0u64;
+    let mut assets: HashMap<String, HashMap<String, u64>> = HashMap::new();
+
+    for utxo in utxos {
+        lovelace = lovelace.saturating_add(utxo.value.lovelace);
+        for (policy_id, policy_assets) in &utxo.value.assets {
+            let entry = assets.entry(policy_id.clone()).or_default();
+            for (asset_name_hex, quantity) in policy_assets {
+                let total = entry.entry(asset_name_hex.clone()).or_default();
+                *total = total.saturating_add(*quantity);
+            }
+        }
+    }
+
+    WalletBalance { lovelace, assets }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minicbor::Encoder;
+
+    const POLICY: [u8; 28] = [0xab; 28];
+    const TX_HASH: [u8; 32] = [0x11; 32];
+    const ADDRESS: [u8; 29] = [0x61; 29];
+
+    fn encode_input(e: &mut Encoder<&mut Vec<u8>>, index: u64) {
+        e.array(2)
+            .unwrap()
+            .bytes(&TX_HASH)
+            .unwrap()
+            .u64(index)
+            .unwrap();
+    }
+
+    fn encode_multiasset_value(e: &mut Encoder<&mut Vec<u8>>, lovelace: u64, name: &[u8]) {
+        e.array(2).unwrap().u64(lovelace).unwrap();
+        e.map(1).unwrap().bytes(&POLICY).unwrap();
+        e.map(1).unwrap().bytes(name).unwrap().u64(1).unwrap();
+    }
+
+    #[test]
+    fn test_decode_legacy_utxo() {
+        let mut buf = Vec::new();
+        let mut e = Encoder::new(&mut buf);
+        e.array(2).unwrap();
+        encode_input(&mut e, 3);
+        e.array(2)
+            .unwrap()
+            .bytes(&ADDRESS)
+            .unwrap()
+            .u64(2_000_000)
+            .unwrap();
+
+        let utxo = decode_utxo(&hex::encode(&buf)).unwrap();
+        assert_eq!(utxo.tx_hash, hex::encode(TX_HASH));
+        assert_eq!(utxo.index, 3);
+        assert_eq!(utxo.address, hex::encode(ADDRESS));
+        assert_eq!(utxo.lovelace(), 2_000_000);
+        assert!(utxo.value.assets.is_empty());
+        assert_eq!(utxo.output_ref(), format!("{}#3", hex::encode(TX_HASH)));
+    }
+
+    #[test]
+    fn test_decode_post_alonzo_utxo_with_assets() {
+        let mut buf = Vec::new();
+        let mut e = Encoder::new(&mut buf);
+        e.array(2).unwrap();
+        encode_input(&mut e, 0);
+        // Datum (key 2) before the value to check unknown keys are skipped
+        e.map(3).unwrap();
+        e.u32(0).unwrap().bytes(&ADDRESS).unwrap();
+        e.u32(2)
+            .unwrap()
+            .array(2)
+            .unwrap()
+            .u8(0)
+            .unwrap()
+            .bytes(&[0; 32])
+            .unwrap();
+        e.u32(1).unwrap();
+        encode_multiasset_value(&mut e, 1_500_000, b"Pirate1");
+
+        let utxo = decode_utxo(&hex::encode(&buf)).unwrap();
+        assert_eq!(utxo.lovelace(), 1_500_000);
+        let policy = utxo.value.assets.get(&hex::encode(POLICY)).unwrap();
+        assert_eq!(policy.get(&hex::encode(b"Pirate1")), Some(&1));
+    }
+
+    #[test]
+    fn test_decode_utxo_rejects_garbage() {
+        assert!(decode_utxo("zz").is_err());
+        assert!(decode_utxo("01").is_err());
+    }
+
+    #[test]
+    fn test_sum_utxos() {
+        let utxo = |lovelace: u64, name: &str, qty: u64| Utxo {
+            tx_hash: String::new(),
+            index: 0,
+            address: String::new(),
+            datum: None,
+            script_ref: None,
+            value: WalletBalance {
+                lovelace,
+                assets: HashMap::from([(
+                    "policy".to_string(),
+                    HashMap::from([(name.to_string(), qty)]),
+                )]),
+            },
+        };
+
+        let total = sum_utxos(&[
+            utxo(1_000_000, "aa", 5),
+            utxo(2_000_000, "aa", 3),
+            utxo(0, "bb", 1),
+        ]);
+        assert_eq!(total.lovelace, 3_000_000);
+        let policy = total.assets.get("policy").unwrap();
+        assert_eq!(policy.get("aa"), Some(&8));
+        assert_eq!(policy.get("bb"), Some(&1));
+    }
+}
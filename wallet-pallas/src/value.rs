@@ -2,7 +2,7 @@
 //!
 //! Decode CBOR-encoded Cardano values (lovelace + multi-assets) from CIP-30 wallet API.
 
-use crate::PallasError;
+use crate::{asset_fingerprint, PallasError};
 use cardano_assets::AssetId;
 use pallas_codec::minicbor;
 use pallas_primitives::conway::Value;
@@ -51,7 +51,7 @@ pub fn decode_balance(balance_hex: &str) -> Result<WalletBalance, PallasError> {
 }
 
 /// Convert a pallas Value to our WalletBalance struct
-fn value_to_balance(value: Value) -> WalletBalance {
+pub(crate) fn value_to_balance(value: Value) -> WalletBalance {
     match value {
         Value::Coin(lovelace) => WalletBalance {
             lovelace,
@@ -97,6 +97,11 @@ impl NativeToken {
         format!("{}{}", self.policy_id, self.asset_name_hex)
     }
 
+    /// Get the CIP-14 fingerprint (`asset1...`) used by explorers
+    pub fn fingerprint(&self) -> Result<String, PallasError> {
+        asset_fingerprint(&self.policy_id, &self.asset_name_hex)
+    }
+
     /// Check if this token looks like an NFT (quantity = 1)
     pub fn is_likely_nft(&self) -> bool {
         self.quantity == 1
@@ -175,7 +180,7 @@ fn split_pascal_case(s: &str) -> String {
 }
 
 /// A policy group containing multiple assets
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PolicyGroup {
     /// Policy ID (56 hex chars)
     pub policy_id: String,
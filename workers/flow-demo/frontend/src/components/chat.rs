@@ -2,28 +2,172 @@
 
 use crate::ChatMessage;
 use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use std::collections::HashSet;
+use ui_components::{MentionInput, MentionMessage, MentionText, MentionUser};
+use ui_flow_protocol::OpId;
+
+/// How long a typing indicator lasts without another `UserTyping`
+pub const TYPING_TIMEOUT_MS: f64 = 4000.0;
+
+/// Someone else typing
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypingUser {
+    pub user_id: String,
+    pub user_name: String,
+    /// `Date.now()` the indicator expires at
+    pub expires_at: f64,
+}
+
+/// A message we sent that the server hasn't confirmed yet
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingMessage {
+    pub op_id: OpId,
+    pub text: String,
+    pub failed: bool,
+}
+
+/// Typing indicators and delivery state alongside the synced messages
+#[derive(Clone, Copy)]
+pub struct ChatActivity {
+    pub typing: RwSignal<Vec<TypingUser>>,
+    pub pending: RwSignal<Vec<PendingMessage>>,
+    /// Ids of our messages confirmed by `ActionOk`
+    pub delivered: RwSignal<HashSet<u64>>,
+}
+
+impl ChatActivity {
+    pub fn new() -> Self {
+        Self {
+            typing: RwSignal::new(Vec::new()),
+            pending: RwSignal::new(Vec::new()),
+            delivered: RwSignal::new(HashSet::new()),
+        }
+    }
+
+    /// `UserTyping` arrived; (re)start that user's indicator
+    pub fn user_typing(&self, user_id: String, user_name: String, now: f64) {
+        let expires_at = now + TYPING_TIMEOUT_MS;
+        self.typing.update(
+            |typing| match typing.iter_mut().find(|t| t.user_id == user_id) {
+                Some(existing) => existing.expires_at = expires_at,
+                None => typing.push(TypingUser {
+                    user_id,
+                    user_name,
+                    expires_at,
+                }),
+            },
+        );
+    }
+
+    /// Drop indicators that have run out
+    pub fn expire_typing(&self, now: f64) {
+        let any_expired = self
+            .typing
+            .with_untracked(|typing| typing.iter().any(|t| t.expires_at <= now));
+        if any_expired {
+            self.typing
+                .update(|typing| typing.retain(|t| t.expires_at > now));
+        }
+    }
+
+    /// A message landed, so its author has stopped typing
+    pub fn message_added(&self, message: &ChatMessage) {
+        let was_typing = self
+            .typing
+            .with_untracked(|typing| typing.iter().any(|t| t.user_id == message.user_id));
+        if was_typing {
+            self.typing
+                .update(|typing| typing.retain(|t| t.user_id != message.user_id));
+        }
+    }
+
+    /// We sent `text` as action `op_id`
+    pub fn sending(&self, op_id: OpId, text: String) {
+        self.pending.update(|pending| {
+            pending.push(PendingMessage {
+                op_id,
+                text,
+                failed: false,
+            })
+        });
+    }
+
+    /// `ActionOk` for `op_id`; other actions' acks are ignored
+    pub fn confirm(&self, op_id: OpId, message_id: Option<u64>) {
+        let is_message = self
+            .pending
+            .with_untracked(|pending| pending.iter().any(|p| p.op_id == op_id));
+        if !is_message {
+            return;
+        }
+        self.pending
+            .update(|pending| pending.retain(|p| p.op_id != op_id));
+        if let Some(id) = message_id {
+            self.delivered.update(|delivered| {
+                delivered.insert(id);
+            });
+        }
+    }
+
+    /// `ActionErr` for `op_id`; keeps the message around marked as failed
+    pub fn fail(&self, op_id: OpId) {
+        self.pending.update(|pending| {
+            if let Some(p) = pending.iter_mut().find(|p| p.op_id == op_id) {
+                p.failed = true;
+            }
+        });
+    }
+}
+
+impl Default for ChatActivity {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 /// Chat messages list with input
 #[component]
-pub fn Chat<F>(
+pub fn Chat<F, T>(
     /// List of chat messages
     messages: Signal<Vec<ChatMessage>>,
     /// Current user's ID to identify own messages
     current_user_id: ReadSignal<String>,
+    /// Who can be @-mentioned
+    users: Signal<Vec<MentionUser>>,
+    /// Typing indicators and delivery state
+    activity: ChatActivity,
     /// Called when a message is sent
     on_send: F,
+    /// Called as the user types (throttle before sending)
+    on_typing: T,
     /// Whether input is disabled
     disabled: Signal<bool>,
 ) -> impl IntoView
 where
-    F: Fn(String) + 'static,
+    F: Fn(MentionMessage) + 'static,
+    T: Fn() + 'static,
 {
-    let (input_value, set_input_value) = signal(String::new());
+    let (draft, set_draft) = signal(MentionMessage::default());
 
-    // Wrap on_send in Rc so we can clone it for multiple handlers
-    let on_send = std::rc::Rc::new(on_send);
-    let on_send_keypress = on_send.clone();
-    let on_send_click = on_send.clone();
+    // Callbacks below must be Send; the handlers only run on the main thread
+    let on_send = SendWrapper::new(on_send);
+    let on_typing = SendWrapper::new(on_typing);
+
+    // Send the draft unless it's blank, then clear the input
+    let send = Callback::new(move |message: MentionMessage| {
+        if !message.text.trim().is_empty() {
+            (*on_send)(message);
+            set_draft.set(MentionMessage::default());
+        }
+    });
+
+    let typing_text = move || {
+        let names: Vec<String> = activity
+            .typing
+            .with(|typing| typing.iter().map(|t| t.user_name.clone()).collect());
+        typing_label(&names)
+    };
 
     view! {
         <div class="card chat">
@@ -39,46 +183,73 @@ where
                         } else {
                             msg.user_name.clone()
                         };
-                        let message_class = if is_me { "message message--mine" } else { "message" };
+                        let mentions_me = msg.mentions.iter().any(|m| m.user_id == current_user_id.get());
+                        let message_class = match (is_me, mentions_me) {
+                            (true, _) => "message message--mine",
+                            (false, true) => "message message--mention",
+                            (false, false) => "message",
+                        };
+                        let id = msg.id;
+                        let delivered = move || is_me && activity.delivered.with(|d| d.contains(&id));
                         view! {
                             <div class=message_class>
                                 <div class="meta">
                                     <span class="author">{author_display}</span>
                                     <span class="time">{format_time(msg.timestamp)}</span>
+                                    <Show when=delivered>
+                                        <span class="receipt" title="Delivered">"✓"</span>
+                                    </Show>
+                                </div>
+                                <div class="content">
+                                    <MentionText
+                                        text=msg.text.clone()
+                                        mentions=msg.mentions.clone()
+                                        current_user_id=current_user_id
+                                    />
+                                </div>
+                            </div>
+                        }
+                    }
+                />
+                <For
+                    each=move || activity.pending.get()
+                    key=|p| (p.op_id, p.failed)
+                    children=move |p| {
+                        let (class, status) = if p.failed {
+                            ("message message--mine message--failed", "Failed to send")
+                        } else {
+                            ("message message--mine message--pending", "Sending…")
+                        };
+                        view! {
+                            <div class=class>
+                                <div class="meta">
+                                    <span class="receipt">{status}</span>
                                 </div>
-                                <div class="content">{msg.text.clone()}</div>
+                                <div class="content">{p.text}</div>
                             </div>
                         }
                     }
                 />
             </div>
+            <div class="typing" aria-live="polite">{typing_text}</div>
             <div class="input-row">
-                <input
-                    type="text"
-                    placeholder="Type a message..."
-                    prop:value=move || input_value.get()
-                    on:input=move |ev| set_input_value.set(event_target_value(&ev))
-                    on:keypress=move |ev| {
-                        if ev.key() == "Enter" {
-                            let text = input_value.get();
-                            if !text.trim().is_empty() {
-                                on_send_keypress(text);
-                                set_input_value.set(String::new());
-                            }
+                <MentionInput
+                    value=Signal::derive(move || draft.with(|d| d.text.clone()))
+                    on_change=move |message: MentionMessage| {
+                        if !message.text.trim().is_empty() {
+                            (*on_typing)();
                         }
+                        set_draft.set(message);
                     }
-                    disabled=move || disabled.get()
+                    users=users
+                    on_submit=send
+                    placeholder="Type a message... (@ to mention)"
+                    disabled=disabled
                 />
                 <button
                     class="primary"
-                    on:click=move |_| {
-                        let text = input_value.get();
-                        if !text.trim().is_empty() {
-                            on_send_click(text);
-                            set_input_value.set(String::new());
-                        }
-                    }
-                    disabled=move || disabled.get() || input_value.get().trim().is_empty()
+                    on:click=move |_| send.run(draft.get())
+                    disabled=move || disabled.get() || draft.with(|d| d.text.trim().is_empty())
                 >
                     "Send"
                 </button>
@@ -87,6 +258,16 @@ where
     }
 }
 
+/// "Alice is typing…", "Alice and Bob are typing…", "3 people are typing…"
+fn typing_label(names: &[String]) -> String {
+    match names {
+        [] => String::new(),
+        [one] => format!("{one} is typing…"),
+        [a, b] => format!("{a} and {b} are typing…"),
+        _ => format!("{} people are typing…", names.len()),
+    }
+}
+
 /// Format timestamp to human readable time
 fn format_time(timestamp: u64) -> String {
     let date = js_sys::Date::new(&(timestamp as f64).into());
@@ -18,6 +18,9 @@ pub fn GameResults(
     current_user_id: Signal<String>,
     /// Callback to request rematch
     on_rematch: impl Fn() + 'static,
+    /// Callback to watch the game's replay; no replay button without it
+    #[prop(into, optional)]
+    on_replay: Option<Callback<()>>,
 ) -> impl IntoView {
     use leptos::prelude::CollectView;
     let on_rematch = std::rc::Rc::new(on_rematch);
@@ -81,12 +84,19 @@ pub fn GameResults(
                 </ol>
             </div>
 
-            <button class="rematch-button" on:click={
-                let on_rematch = on_rematch.clone();
-                move |_| on_rematch()
-            }>
-                "Play Again"
-            </button>
+            <div class="results-actions">
+                {on_replay.map(|on_replay| view! {
+                    <button class="replay-button" on:click=move |_| on_replay.run(())>
+                        "Watch Replay"
+                    </button>
+                })}
+                <button class="rematch-button" on:click={
+                    let on_rematch = on_rematch.clone();
+                    move |_| on_rematch()
+                }>
+                    "Play Again"
+                </button>
+            </div>
         </div>
     }
 }
@@ -1,6 +1,7 @@
 //! Game Lobby Component
 //!
-//! Displays the pre-game lobby where players wait and anyone can configure settings.
+//! Displays the pre-game lobby where players wait and anyone can configure settings,
+//! including bot opponents for short-handed games.
 
 use leptos::prelude::*;
 
@@ -20,12 +21,30 @@ impl GameMode {
     }
 }
 
+/// How well bot opponents play
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BotDifficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl BotDifficulty {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Easy => "Easy",
+            Self::Normal => "Normal",
+            Self::Hard => "Hard",
+        }
+    }
+}
+
 /// Lobby component for pre-game setup
 #[component]
 pub fn Lobby(
     /// List of players in the lobby
     #[prop(into)]
-    players: Signal<Vec<(String, String)>>, // (user_id, user_name)
+    players: Signal<Vec<(String, String, bool)>>, // (user_id, user_name, bot)
     /// Current user's ID
     #[prop(into)]
     current_user_id: Signal<String>,
@@ -35,16 +54,25 @@ pub fn Lobby(
     /// Current grid size setting
     #[prop(into)]
     grid_size: Signal<(u8, u8)>,
+    /// Bots fill the lobby up to this many players (0 = off)
+    #[prop(into)]
+    bot_fill: Signal<u8>,
+    /// Current bot difficulty
+    #[prop(into)]
+    bot_difficulty: Signal<BotDifficulty>,
     /// Callback to change game mode
     on_mode_change: impl Fn(GameMode) + 'static,
     /// Callback to change grid size
     on_grid_change: impl Fn((u8, u8)) + 'static,
+    /// Callback to change bot seats and difficulty
+    on_bots_change: impl Fn(u8, BotDifficulty) + 'static,
     /// Callback to start the game
     on_start: impl Fn() + 'static,
 ) -> impl IntoView {
     use leptos::prelude::CollectView;
     let on_mode_change = std::rc::Rc::new(on_mode_change);
     let on_grid_change = std::rc::Rc::new(on_grid_change);
+    let on_bots_change = std::rc::Rc::new(on_bots_change);
     let on_start = std::rc::Rc::new(on_start);
 
     view! {
@@ -58,13 +86,14 @@ pub fn Lobby(
                         let players_vec = players.get();
                         let my_id = current_user_id.get();
 
-                        players_vec.into_iter().map(|(user_id, user_name)| {
+                        players_vec.into_iter().map(|(user_id, user_name, bot)| {
                             let is_me = user_id == my_id;
 
                             view! {
                                 <li>
                                     {user_name}
                                     {if is_me { " (you)" } else { "" }}
+                                    {bot.then(|| view! { <span class="bot-badge">"Bot"</span> })}
                                 </li>
                             }
                         }).collect_view()
@@ -75,6 +104,8 @@ pub fn Lobby(
             {
                 let on_mode = on_mode_change.clone();
                 let on_grid = on_grid_change.clone();
+                let on_bot_fill = on_bots_change.clone();
+                let on_bot_difficulty = on_bots_change.clone();
                 let on_start_click = on_start.clone();
 
                 view! {
@@ -121,6 +152,48 @@ pub fn Lobby(
                             </select>
                         </div>
 
+                        <div class="setting-row">
+                            <label>"Bots:"</label>
+                            <select on:change=move |ev| {
+                                let fill_to = event_target_value(&ev).parse().unwrap_or(0);
+                                on_bot_fill(fill_to, bot_difficulty.get_untracked());
+                            }>
+                                <option value="0" selected=move || bot_fill.get() == 0>
+                                    "Off"
+                                </option>
+                                {[2u8, 3, 4].into_iter().map(|n| view! {
+                                    <option value=n.to_string() selected=move || bot_fill.get() == n>
+                                        {format!("Fill to {n} players")}
+                                    </option>
+                                }).collect_view()}
+                            </select>
+                        </div>
+
+                        <div class="setting-row">
+                            <label>"Difficulty:"</label>
+                            <select
+                                disabled=move || bot_fill.get() == 0
+                                on:change=move |ev| {
+                                    let difficulty = match event_target_value(&ev).as_str() {
+                                        "easy" => BotDifficulty::Easy,
+                                        "hard" => BotDifficulty::Hard,
+                                        _ => BotDifficulty::Normal,
+                                    };
+                                    on_bot_difficulty(bot_fill.get_untracked(), difficulty);
+                                }
+                            >
+                                {[
+                                    ("easy", BotDifficulty::Easy),
+                                    ("normal", BotDifficulty::Normal),
+                                    ("hard", BotDifficulty::Hard),
+                                ].into_iter().map(|(value, difficulty)| view! {
+                                    <option value=value selected=move || bot_difficulty.get() == difficulty>
+                                        {difficulty.label()}
+                                    </option>
+                                }).collect_view()}
+                            </select>
+                        </div>
+
                         <button class="start-button" on:click=move |_| on_start_click()>
                             "Start Game"
                         </button>
@@ -1,19 +1,15 @@
 //! UI Components for the flow-demo frontend
 
-mod admin;
 mod chat;
 mod counter;
 mod game_board;
 mod game_results;
 mod lobby;
-mod player_list;
 mod presence;
 
-pub use admin::AdminPanel;
-pub use chat::Chat;
+pub use chat::{Chat, ChatActivity};
 pub use counter::Counter;
 pub use game_board::{CardView, GameBoard};
 pub use game_results::GameResults;
-pub use lobby::{GameMode, Lobby};
-pub use player_list::{PlayerInfo, PlayerList};
+pub use lobby::{BotDifficulty, GameMode, Lobby};
 pub use presence::Presence;
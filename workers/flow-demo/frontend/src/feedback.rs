@@ -0,0 +1,177 @@
+//! Sound and haptic feedback for game events
+//!
+//! Plays short synthesized tones through Web Audio and a vibration pattern
+//! through `navigator.vibrate` for a handful of generic game events. Nothing
+//! here knows about a particular game; callers map their own deltas to a
+//! [`FeedbackEvent`] and call [`FeedbackManager::play`].
+//!
+//! Feedback is gated by the `sound_enabled` setting, so a toggle bound with
+//! `use_setting(&settings, keys::SOUND_ENABLED, true)` turns it off and on
+//! without touching the manager.
+//!
+//! ```ignore
+//! let settings = Settings::new("memory-game");
+//! let feedback = FeedbackManager::new(settings.clone());
+//!
+//! feedback.play(FeedbackEvent::Match);
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use ui_core::settings::{keys, Settings};
+use web_sys::{AudioContext, AudioContextState, OscillatorType};
+
+/// Peak gain for tones; kept low so feedback never drowns out anything else
+const VOLUME: f32 = 0.15;
+
+/// A game event worth a sound and a buzz
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedbackEvent {
+    /// A card was turned over
+    Flip,
+    /// A pair was found
+    Match,
+    /// The local player won
+    Win,
+    /// The turn passed to the local player
+    YourTurn,
+}
+
+/// One note of a feedback sound
+#[derive(Debug, Clone, Copy)]
+struct Tone {
+    /// Pitch in Hz
+    frequency: f32,
+    /// Length in seconds
+    duration: f64,
+}
+
+const fn tone(frequency: f32, duration: f64) -> Tone {
+    Tone {
+        frequency,
+        duration,
+    }
+}
+
+impl FeedbackEvent {
+    /// Notes played one after another
+    fn tones(self) -> &'static [Tone] {
+        match self {
+            Self::Flip => &[tone(660.0, 0.05)],
+            Self::Match => &[tone(523.25, 0.08), tone(783.99, 0.14)],
+            Self::Win => &[
+                tone(523.25, 0.12),
+                tone(659.25, 0.12),
+                tone(783.99, 0.12),
+                tone(1046.5, 0.3),
+            ],
+            Self::YourTurn => &[tone(440.0, 0.1), tone(587.33, 0.1)],
+        }
+    }
+
+    fn waveform(self) -> OscillatorType {
+        match self {
+            Self::Flip => OscillatorType::Triangle,
+            _ => OscillatorType::Sine,
+        }
+    }
+
+    /// Alternating vibrate/pause durations in milliseconds
+    fn vibration(self) -> &'static [u32] {
+        match self {
+            Self::Flip => &[10],
+            Self::Match => &[30, 40, 30],
+            Self::Win => &[80, 60, 80, 60, 200],
+            Self::YourTurn => &[50, 50, 50],
+        }
+    }
+}
+
+/// Plays feedback for game events when the user has it enabled
+///
+/// Cloning shares the same audio context.
+#[derive(Clone)]
+pub struct FeedbackManager {
+    settings: Settings,
+    audio: Rc<RefCell<Option<AudioContext>>>,
+}
+
+impl FeedbackManager {
+    /// Create a manager reading its toggle from `settings`
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            settings,
+            audio: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Whether the user has feedback turned on (default: true)
+    pub fn is_enabled(&self) -> bool {
+        self.settings.get_or(keys::SOUND_ENABLED, true)
+    }
+
+    /// Play the sound and vibration for `event`, if enabled
+    pub fn play(&self, event: FeedbackEvent) {
+        if !self.is_enabled() {
+            return;
+        }
+        if let Err(e) = self.play_tones(event) {
+            tracing::debug!("Feedback sound failed: {:?}", e);
+        }
+        vibrate(event.vibration());
+    }
+
+    fn play_tones(&self, event: FeedbackEvent) -> Result<(), wasm_bindgen::JsValue> {
+        let mut audio = self.audio.borrow_mut();
+        let ctx = match &mut *audio {
+            Some(ctx) => ctx,
+            // Created lazily so the first play usually follows a user gesture
+            slot @ None => slot.insert(AudioContext::new()?),
+        };
+        if ctx.state() == AudioContextState::Suspended {
+            let _ = ctx.resume()?;
+        }
+
+        let mut start = ctx.current_time();
+        for tone in event.tones() {
+            let end = start + tone.duration;
+
+            let oscillator = ctx.create_oscillator()?;
+            oscillator.set_type(event.waveform());
+            oscillator
+                .frequency()
+                .set_value_at_time(tone.frequency, start)?;
+
+            // Short attack and exponential decay avoid clicks at the edges
+            let gain = ctx.create_gain()?;
+            let level = gain.gain();
+            level.set_value_at_time(0.0001, start)?;
+            level.exponential_ramp_to_value_at_time(VOLUME, start + 0.01)?;
+            level.exponential_ramp_to_value_at_time(0.0001, end)?;
+
+            oscillator.connect_with_audio_node(&gain)?;
+            gain.connect_with_audio_node(&ctx.destination())?;
+            oscillator.start_with_when(start)?;
+            oscillator.stop_with_when(end)?;
+
+            start = end;
+        }
+        Ok(())
+    }
+}
+
+/// Vibrate with `pattern`; a no-op where the Vibration API is unsupported
+fn vibrate(pattern: &[u32]) {
+    let Some(navigator) = web_sys::window().map(|w| w.navigator()) else {
+        return;
+    };
+    // Calling a missing `navigator.vibrate` (Safari) would throw
+    if !js_sys::Reflect::has(&navigator, &"vibrate".into()).unwrap_or(false) {
+        return;
+    }
+    let pattern: js_sys::Array = pattern
+        .iter()
+        .map(|ms| wasm_bindgen::JsValue::from(*ms))
+        .collect();
+    navigator.vibrate_with_pattern(&pattern);
+}
@@ -8,19 +8,33 @@
 //! - Snapshot + delta state synchronization
 //! - Presence tracking
 //! - Optimistic UI with action feedback
+//! - Chat typing indicators and delivery receipts
+//! - Chat @-mentions with autocomplete from presence
+//! - Sound and haptic feedback for game events
+//! - Post-game replays on a time slider
 
 mod components;
+pub mod feedback;
 pub mod memory_app;
+pub mod replay;
 
-use components::{Chat, Counter, Presence};
+use components::{Chat, ChatActivity, Counter, Presence};
 use leptos::prelude::*;
 pub use memory_app::MemoryApp;
 use send_wrapper::SendWrapper;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::rc::Rc;
-use ui_components::{ConnectionState, ConnectionStatus};
-use ui_flow_protocol::{ClientMessage, OpId, PresenceInfo, ServerMessage};
+use std::time::Duration;
+use ui_components::{
+    mention_users, ConnectionState, ConnectionStatus, Mention, MentionMessage, SystemAnnouncement,
+    SystemBanner,
+};
+use ui_core::timing::throttled;
+use ui_core::urls::{append_query, encode_component, endpoints};
+use ui_flow_protocol::{
+    ClientCommand, ClientMessage, OpId, PresenceInfo, ServerMessage, GOODBYE_CLOSED, GOODBYE_UNLOAD,
+};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::{MessageEvent, WebSocket};
@@ -102,6 +116,8 @@ pub struct ChatMessage {
     pub user_id: String,
     pub user_name: String,
     pub text: String,
+    #[serde(default)]
+    pub mentions: Vec<Mention>,
     pub timestamp: u64,
 }
 
@@ -129,10 +145,16 @@ pub enum DemoEvent {
 pub enum DemoAction {
     Increment,
     Decrement,
-    SendMessage { text: String },
+    SendMessage {
+        text: String,
+        mentions: Vec<Mention>,
+    },
     StartTyping,
 }
 
+/// Minimum gap between `StartTyping` actions while the user keeps typing
+const TYPING_THROTTLE_MS: u32 = 2500;
+
 /// Type aliases for protocol messages
 type ServerMsg = ServerMessage<DemoState, DemoDelta, DemoEvent>;
 type ClientMsg = ClientMessage<DemoAction>;
@@ -150,6 +172,9 @@ pub fn main() {
     // Initialize tracing (panic hooks + browser console logging)
     ui_core::runtime::init_widget_with_level(tracing::Level::DEBUG);
 
+    // Same-origin worker: API and WebSocket live on the page's host
+    ui_core::urls::Endpoints::from_location().install();
+
     // Mount the app
     mount_to_body(RootApp);
 }
@@ -221,12 +246,27 @@ fn App() -> impl IntoView {
     // Connection status
     let (status, set_status) = signal(ConnectionState::Disconnected);
 
+    // Server-issued ID of the current socket; authorizes the leave beacon
+    let (connection_id, set_connection_id) = signal(None::<String>);
+
     // Application state
     let (state, set_state) = signal(DemoState::default());
 
     // Presence list
     let (presence, set_presence) = signal(Vec::<PresenceInfo>::new());
 
+    // Operator maintenance banner
+    let (announcement, set_announcement) = signal(None::<SystemAnnouncement>);
+
+    // Typing indicators and delivery receipts
+    let chat = ChatActivity::new();
+    if let Ok(handle) = set_interval_with_handle(
+        move || chat.expire_typing(js_sys::Date::now()),
+        Duration::from_secs(1),
+    ) {
+        on_cleanup(move || handle.clear());
+    }
+
     // Current user ID signal for components
     let (current_user_id, _set_current_user_id) = signal(user_id);
 
@@ -238,11 +278,25 @@ fn App() -> impl IntoView {
     let ws_disconnect = ws.clone();
     let disconnect = SendWrapper::new(Rc::new(move || {
         if let Some(socket) = ws_disconnect.borrow_mut().take() {
-            let _ = socket.close();
+            say_goodbye(&socket, GOODBYE_CLOSED);
         }
         set_status.set(ConnectionState::Disconnected);
     }));
 
+    // Leaving the page drops us from the room at once instead of leaving a
+    // ghost until the socket times out. The beacon gets through even if the
+    // socket's Goodbye doesn't.
+    let ws_unload = ws.clone();
+    let unload = window_event_listener(leptos::ev::pagehide, move |_| {
+        if let Some(id) = connection_id.get_untracked() {
+            send_leave_beacon(&room_id.get_untracked(), &id);
+        }
+        if let Some(socket) = ws_unload.borrow_mut().take() {
+            say_goodbye(&socket, GOODBYE_UNLOAD);
+        }
+    });
+    on_cleanup(move || unload.remove());
+
     // Connect to WebSocket
     let ws_connect = ws.clone();
     let user_id_ws = user_id_for_ws.clone();
@@ -284,6 +338,8 @@ fn App() -> impl IntoView {
                 // Handle messages
                 let set_state_clone = set_state;
                 let set_presence_clone = set_presence;
+                let set_announcement_clone = set_announcement;
+                let my_user_id = user_id_ws.clone();
                 let on_message = Closure::wrap(Box::new(move |e: MessageEvent| {
                     if let Ok(buffer) = e.data().dyn_into::<js_sys::ArrayBuffer>() {
                         let array = js_sys::Uint8Array::new(&buffer);
@@ -291,7 +347,15 @@ fn App() -> impl IntoView {
 
                         match ui_flow_protocol::decode::<ServerMsg>(&bytes) {
                             Ok(msg) => {
-                                handle_server_message(msg, set_state_clone, set_presence_clone);
+                                handle_server_message(
+                                    msg,
+                                    set_state_clone,
+                                    set_presence_clone,
+                                    set_announcement_clone,
+                                    set_connection_id,
+                                    chat,
+                                    &my_user_id,
+                                );
                             }
                             Err(e) => {
                                 tracing::error!("Failed to decode message: {}", e);
@@ -311,18 +375,50 @@ fn App() -> impl IntoView {
         }
     }));
 
-    // Send action helper
+    // Send action helpers
     let ws_send = ws.clone();
-    let send_action = SendWrapper::new(Rc::new(move |action: DemoAction| {
-        if let Some(socket) = ws_send.borrow().as_ref() {
-            if socket.ready_state() == WebSocket::OPEN {
-                let msg: ClientMsg = ClientMessage::action(OpId::new(), action);
-                if let Ok(bytes) = ui_flow_protocol::encode(&msg) {
-                    let _ = socket.send_with_u8_array(&bytes);
-                }
-            }
+    let send_with_id = SendWrapper::new(Rc::new(move |op_id: OpId, action: DemoAction| {
+        let Some(socket) = ws_send.borrow().as_ref().cloned() else {
+            return false;
+        };
+        if socket.ready_state() != WebSocket::OPEN {
+            return false;
+        }
+        let msg: ClientMsg = ClientMessage::action(op_id, action);
+        match ui_flow_protocol::encode(&msg) {
+            Ok(bytes) => socket.send_with_u8_array(&bytes).is_ok(),
+            Err(_) => false,
         }
     }));
+    let send_action = {
+        let send = send_with_id.clone();
+        SendWrapper::new(Rc::new(move |action: DemoAction| {
+            send(OpId::new(), action);
+        }))
+    };
+
+    // Typing notifications, at most one per throttle window
+    let typing = {
+        let send = send_action.clone();
+        SendWrapper::new(throttled(
+            move |()| send(DemoAction::StartTyping),
+            TYPING_THROTTLE_MS,
+        ))
+    };
+
+    // Chat sends are tracked until ActionOk/ActionErr
+    let send_message = {
+        let send = send_with_id.clone();
+        let typing = typing.clone();
+        move |MentionMessage { text, mentions }| {
+            typing.cancel();
+            let op_id = OpId::new();
+            chat.sending(op_id, text.clone());
+            if !send(op_id, DemoAction::SendMessage { text, mentions }) {
+                chat.fail(op_id);
+            }
+        }
+    };
 
     // Auto-connect on mount
     let connect_effect = connect.clone();
@@ -336,6 +432,8 @@ fn App() -> impl IntoView {
     let connect_reconnect = connect.clone();
 
     view! {
+        <SystemBanner announcement=announcement />
+
         <div class="header">
             <h1>"Flow Demo"</h1>
             <p class="subtitle">"Unified Realtime Protocol with MessagePack"</p>
@@ -379,10 +477,12 @@ fn App() -> impl IntoView {
                 <Chat
                     messages=Signal::derive(move || state.get().messages)
                     current_user_id=current_user_id
-                    on_send={
-                        let send = send_action.clone();
-                        move |text: String| send(DemoAction::SendMessage { text })
-                    }
+                    users=Signal::derive(move || {
+                        presence.with(|p| mention_users(p, &current_user_id.get()))
+                    })
+                    activity=chat
+                    on_send=send_message
+                    on_typing=move || typing.call(())
                     disabled=Signal::derive(move || status.get() != ConnectionState::Connected)
                 />
             </div>
@@ -399,10 +499,15 @@ fn handle_server_message(
     msg: ServerMsg,
     set_state: WriteSignal<DemoState>,
     set_presence: WriteSignal<Vec<PresenceInfo>>,
+    set_announcement: WriteSignal<Option<SystemAnnouncement>>,
+    set_connection_id: WriteSignal<Option<String>>,
+    chat: ChatActivity,
+    my_user_id: &str,
 ) {
     match msg {
-        ServerMessage::Connected { .. } => {
+        ServerMessage::Connected { connection_id, .. } => {
             tracing::info!("Received Connected message");
+            set_connection_id.set(Some(connection_id));
         }
 
         ServerMessage::Snapshot { state, seq, .. } => {
@@ -412,11 +517,15 @@ fn handle_server_message(
 
         ServerMessage::Delta { delta, seq, .. } => {
             tracing::debug!("Received Delta at seq {}", seq);
+            note_chat_delta(chat, &delta);
             set_state.update(|s| apply_delta(s, delta));
         }
 
         ServerMessage::Deltas { deltas, seq, .. } => {
             tracing::debug!("Received {} Deltas, final seq {}", deltas.len(), seq);
+            for delta in &deltas {
+                note_chat_delta(chat, delta);
+            }
             set_state.update(|s| {
                 for delta in deltas {
                     apply_delta(s, delta);
@@ -424,6 +533,16 @@ fn handle_server_message(
             });
         }
 
+        ServerMessage::PrivateDelta { delta, seq, .. } => {
+            tracing::debug!("Received PrivateDelta at seq {}", seq);
+            set_state.update(|s| apply_delta(s, delta));
+        }
+
+        ServerMessage::DomainSnapshot { domain, .. } => {
+            // The demo room has no domain slices and never requests one
+            tracing::debug!("Ignoring DomainSnapshot for domain: {}", domain);
+        }
+
         ServerMessage::Presence { users } => {
             tracing::debug!("Received Presence: {} users", users.len());
             set_presence.set(users);
@@ -435,18 +554,26 @@ fn handle_server_message(
                 DemoEvent::Announcement { text } => {
                     tracing::info!("Announcement: {}", text);
                 }
-                DemoEvent::UserTyping { user_name, .. } => {
-                    tracing::debug!("{} is typing...", user_name);
+                DemoEvent::UserTyping { user_id, user_name } => {
+                    if user_id != my_user_id {
+                        chat.user_typing(user_id, user_name, js_sys::Date::now());
+                    }
                 }
             }
         }
 
-        ServerMessage::ActionOk { op_id, .. } => {
+        ServerMessage::ActionOk { op_id, result } => {
             tracing::debug!("Action {} completed successfully", op_id);
+            // SendMessage replies with the new message id
+            let message_id = ui_flow_protocol::decode_result::<Option<u64>>(result.as_deref())
+                .ok()
+                .flatten();
+            chat.confirm(op_id, message_id);
         }
 
         ServerMessage::ActionErr { op_id, message, .. } => {
             tracing::error!("Action {} failed: {}", op_id, message);
+            chat.fail(op_id);
         }
 
         ServerMessage::Progress {
@@ -468,6 +595,52 @@ fn handle_server_message(
         ServerMessage::Signal { .. } => {
             // WebRTC signalling not implemented
         }
+
+        ServerMessage::Command { command } => {
+            tracing::info!("Received server command: {:?}", command);
+            run_command(command, set_announcement);
+        }
+    }
+}
+
+/// Act on an operator command
+///
+/// This demo drives the socket directly rather than through ui-flow's
+/// `FlowConnection`, so it handles the page-level commands itself.
+fn run_command(command: ClientCommand, set_announcement: WriteSignal<Option<SystemAnnouncement>>) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    match command {
+        ClientCommand::Reload { max_delay_ms } => {
+            let delay = (f64::from(max_delay_ms) * js_sys::Math::random()) as i32;
+            let reload = Closure::once_into_js(move || {
+                if let Some(window) = web_sys::window() {
+                    let _ = window.location().reload();
+                }
+            });
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                reload.unchecked_ref(),
+                delay,
+            );
+        }
+        ClientCommand::RedirectTo { url } => {
+            let _ = window.location().set_href(&url);
+        }
+        ClientCommand::ShowMaintenanceBanner { message, until } => {
+            set_announcement.set(Some(
+                SystemAnnouncement::maintenance("flow-demo-maintenance", message).until(until),
+            ));
+        }
+        ClientCommand::HideMaintenanceBanner => set_announcement.set(None),
+        _ => {}
+    }
+}
+
+/// Clear the typing indicator of whoever just posted
+fn note_chat_delta(chat: ChatActivity, delta: &DemoDelta) {
+    if let DemoDelta::MessageAdded { message } = delta {
+        chat.message_added(message);
     }
 }
 
@@ -493,22 +666,52 @@ fn apply_delta(state: &mut DemoState, delta: DemoDelta) {
     }
 }
 
-/// Get WebSocket URL based on current location
-fn get_ws_url(room_id: &str, user_id: &str) -> String {
-    let window = web_sys::window().expect("no window");
-    let location = window.location();
-    let protocol = location.protocol().unwrap_or_else(|_| "http:".to_string());
-    let host = location
-        .host()
-        .unwrap_or_else(|_| "localhost:8787".to_string());
+/// Send Goodbye if the socket is open, then close it
+fn say_goodbye(socket: &WebSocket, reason: &str) {
+    if socket.ready_state() == WebSocket::OPEN {
+        let msg: ClientMsg = ClientMessage::goodbye(reason);
+        if let Ok(bytes) = ui_flow_protocol::encode(&msg) {
+            let _ = socket.send_with_u8_array(&bytes);
+        }
+    }
+    let _ = socket.close_with_code(1000);
+}
+
+/// POST a Goodbye to the room's leave endpoint with `navigator.sendBeacon`
+fn send_leave_beacon(room_id: &str, connection_id: &str) {
+    let msg: ClientMsg = ClientMessage::goodbye(GOODBYE_UNLOAD);
+    let (Some(window), Ok(bytes)) = (web_sys::window(), ui_flow_protocol::encode(&msg)) else {
+        return;
+    };
+    let body: js_sys::Object = js_sys::Uint8Array::from(bytes.as_slice()).into();
+    let url = get_leave_url(room_id, connection_id);
+    if !matches!(
+        window
+            .navigator()
+            .send_beacon_with_opt_buffer_source(&url, Some(&body)),
+        Ok(true)
+    ) {
+        tracing::warn!("Failed to queue leave beacon");
+    }
+}
 
-    let ws_protocol = if protocol == "https:" { "wss:" } else { "ws:" };
+/// Leave beacon URL for a room (`/ws/{room_id}/leave`)
+///
+/// The server only closes the socket the `connection_id` was issued to.
+fn get_leave_url(room_id: &str, connection_id: &str) -> String {
+    append_query(
+        &endpoints().api(&format!("/ws/{}/leave", encode_component(room_id))),
+        &[("connection_id", connection_id)],
+    )
+}
 
+/// Get WebSocket URL based on current location
+fn get_ws_url(room_id: &str, user_id: &str) -> String {
     // Use first 8 chars of ULID as display name for simplicity
     let display_name = &user_id[..8.min(user_id.len())];
 
-    format!(
-        "{}//{}/ws/{}?user_id={}&user_name={}",
-        ws_protocol, host, room_id, user_id, display_name
+    append_query(
+        &endpoints().ws_room(room_id),
+        &[("user_id", user_id), ("user_name", display_name)],
     )
 }
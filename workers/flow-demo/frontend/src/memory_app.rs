@@ -2,17 +2,22 @@
 //!
 //! The main entry point for the Black Flag memory game frontend.
 
-use crate::components::{
-    AdminPanel, CardView, GameBoard, GameMode, GameResults, Lobby, PlayerInfo, PlayerList,
-};
+use crate::components::{BotDifficulty, CardView, GameBoard, GameMode, GameResults, Lobby};
+use crate::feedback::{FeedbackEvent, FeedbackManager};
 use crate::get_or_create_user_id;
+use crate::replay::ReplayViewer;
 use leptos::prelude::*;
 use send_wrapper::SendWrapper;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
-use ui_components::{AssetCache, ConnectionState, ConnectionStatus, PreloadAsset};
+use ui_components::{
+    use_setting, AssetCache, ConnectionState, ConnectionStatus, PhaseOverlay, PhaseTransition,
+    PlayerList, PlayerListEntry, PreloadAsset,
+};
+use ui_core::settings::{keys, Settings};
+use ui_core::urls::{append_query, endpoints};
 use ui_flow_protocol::{ClientMessage, OpId, PresenceInfo, ServerMessage};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
@@ -58,6 +63,41 @@ impl From<GameMode> for ServerGameMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerBotDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl From<ServerBotDifficulty> for BotDifficulty {
+    fn from(d: ServerBotDifficulty) -> Self {
+        match d {
+            ServerBotDifficulty::Easy => BotDifficulty::Easy,
+            ServerBotDifficulty::Normal => BotDifficulty::Normal,
+            ServerBotDifficulty::Hard => BotDifficulty::Hard,
+        }
+    }
+}
+
+impl From<BotDifficulty> for ServerBotDifficulty {
+    fn from(d: BotDifficulty) -> Self {
+        match d {
+            BotDifficulty::Easy => ServerBotDifficulty::Easy,
+            BotDifficulty::Normal => ServerBotDifficulty::Normal,
+            BotDifficulty::Hard => ServerBotDifficulty::Hard,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct BotConfig {
+    pub fill_to: u8,
+    pub difficulty: ServerBotDifficulty,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GameConfig {
     pub grid_size: (u8, u8),
@@ -65,6 +105,8 @@ pub struct GameConfig {
     pub policy_id: String,
     pub flip_delay_ms: u64,
     pub shuffle_seed: u64,
+    #[serde(default)]
+    pub bots: BotConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -116,6 +158,8 @@ pub struct PlayerState {
     pub flipped: Vec<CardId>,
     pub spectating: bool,
     pub joined_at: u64,
+    #[serde(default)]
+    pub bot: bool,
 }
 
 /// Turn state machine - mirrors server's TurnState
@@ -164,6 +208,12 @@ pub struct MemoryGameState {
     pub current_turn: usize,
     pub turn_state: TurnState,
     pub host: Option<String>,
+    /// Consecutive matches per player; tracked client-side from deltas
+    #[serde(default)]
+    pub streaks: HashMap<String, u32>,
+    /// Current or last game, for fetching its replay (0 before the first)
+    #[serde(default)]
+    pub game_id: u64,
 }
 
 impl MemoryGameState {
@@ -196,6 +246,8 @@ pub enum MemoryDelta {
         user_id: String,
         user_name: String,
         spectating: bool,
+        #[serde(default)]
+        bot: bool,
     },
     PlayerLeft {
         user_id: String,
@@ -254,6 +306,8 @@ pub enum MemoryDelta {
     GameEnded {
         winner: Option<String>,
         rankings: Vec<(String, String, u32)>,
+        #[serde(default)]
+        game_id: u64,
     },
 }
 
@@ -290,6 +344,7 @@ pub enum MemoryAction {
     SetConfig {
         mode: Option<ServerGameMode>,
         grid_size: Option<(u8, u8)>,
+        bots: Option<BotConfig>,
     },
     StartGame,
     FlipCard {
@@ -300,7 +355,6 @@ pub enum MemoryAction {
     },
     Ready,
     RequestRematch,
-    ResetGame,
 }
 
 type ServerMsg = ServerMessage<MemoryGameState, MemoryDelta, MemoryEvent>;
@@ -319,8 +373,15 @@ pub fn MemoryApp() -> impl IntoView {
     let (room_id, _set_room_id) = signal("default".to_string());
     let (status, set_status) = signal(ConnectionState::Disconnected);
     let (game_state, set_game_state) = signal(MemoryGameState::default());
-    let (_presence, set_presence) = signal(Vec::<PresenceInfo>::new());
+    let (presence, set_presence) = signal(Vec::<PresenceInfo>::new());
     let (current_user_id, _) = signal(user_id);
+    // Finished game being replayed in place of the results
+    let replay_game = RwSignal::new(None::<u64>);
+    Effect::new(move |_| {
+        if !matches!(game_state.get().phase, GamePhase::Finished { .. }) {
+            replay_game.set(None);
+        }
+    });
 
     // Local UI state for flipped cards (before server confirms)
     let (local_flipped, set_local_flipped) = signal(Vec::<CardId>::new());
@@ -329,6 +390,11 @@ pub fn MemoryApp() -> impl IntoView {
     // Asset IDs for preloading images
     let (preload_assets, set_preload_assets) = signal(Vec::<AssetId>::new());
 
+    // Sound/haptics, toggled by the persisted sound setting
+    let settings = Settings::new("memory-game");
+    let feedback = FeedbackManager::new(settings.clone());
+    let sound_enabled = use_setting(&settings, keys::SOUND_ENABLED, true);
+
     // Use SendWrapper to make Rc<RefCell<...>> work with Leptos 0.8's Send+Sync requirements
     let ws: SendWrapper<Rc<RefCell<Option<WebSocket>>>> =
         SendWrapper::new(Rc::new(RefCell::new(None)));
@@ -382,6 +448,8 @@ pub fn MemoryApp() -> impl IntoView {
                 let set_revealed_clone = set_revealed_faces;
                 let set_local_flipped_clone = set_local_flipped;
                 let set_preload_assets_clone = set_preload_assets;
+                let feedback_clone = feedback.clone();
+                let user_id_clone = user_id_ws.clone();
                 let on_message = Closure::wrap(Box::new(move |e: MessageEvent| {
                     if let Ok(buffer) = e.data().dyn_into::<js_sys::ArrayBuffer>() {
                         let array = js_sys::Uint8Array::new(&buffer);
@@ -396,6 +464,8 @@ pub fn MemoryApp() -> impl IntoView {
                                     set_revealed_clone,
                                     set_local_flipped_clone,
                                     set_preload_assets_clone,
+                                    &feedback_clone,
+                                    &user_id_clone,
                                 );
                             }
                             Err(e) => {
@@ -428,7 +498,6 @@ pub fn MemoryApp() -> impl IntoView {
             }
         }
     }));
-    let send_action_for_admin = send_action.clone();
 
     // Auto-connect on mount
     let connect_effect = connect.clone();
@@ -455,13 +524,15 @@ pub fn MemoryApp() -> impl IntoView {
     // Derived signals
     let game_mode = Signal::derive(move || game_state.get().config.mode.into());
     let grid_size = Signal::derive(move || game_state.get().config.grid_size);
+    let bot_fill = Signal::derive(move || game_state.get().config.bots.fill_to);
+    let bot_difficulty = Signal::derive(move || game_state.get().config.bots.difficulty.into());
 
     let players_for_lobby = Signal::derive(move || {
         let state = game_state.get();
         state
             .players
             .values()
-            .map(|p| (p.user_id.clone(), p.user_name.clone()))
+            .map(|p| (p.user_id.clone(), p.user_name.clone(), p.bot))
             .collect::<Vec<_>>()
     });
 
@@ -470,11 +541,21 @@ pub fn MemoryApp() -> impl IntoView {
         state
             .players
             .values()
-            .map(|p| PlayerInfo {
+            .map(|p| PlayerListEntry {
                 user_id: p.user_id.clone(),
-                user_name: p.user_name.clone(),
+                name: p.user_name.clone(),
                 score: p.score,
+                streak: state.streaks.get(&p.user_id).copied().unwrap_or(0),
+                presence: presence.with(|users| {
+                    users
+                        .iter()
+                        .find(|u| u.user_id == p.user_id)
+                        .map(|u| u.status)
+                }),
                 spectating: p.spectating,
+                host: state.host.as_ref() == Some(&p.user_id),
+                bot: p.bot,
+                ..Default::default()
             })
             .collect::<Vec<_>>()
     });
@@ -501,57 +582,13 @@ pub fn MemoryApp() -> impl IntoView {
         state.turn_order.get(state.current_turn) == Some(&my_id)
     });
 
-    let cards_view = {
-        let _current_user_id = current_user_id;
-        Signal::derive(move || {
-            let state = game_state.get();
-            let revealed = revealed_faces.get();
-            let _my_id = _current_user_id.get();
-            let local = local_flipped.get();
-
-            state
-                .cards
-                .iter()
-                .map(|card| {
-                    let card_id = &card.card_id;
-                    // Card is visible if:
-                    // 1. It's matched
-                    // 2. It's in revealed_faces (server confirmed flip)
-                    // 3. In turn-taking mode and it's in turn_state flipped
-                    let is_matched = card.matched;
-                    let is_revealed = revealed.contains_key(card_id);
-                    let is_shared_flipped = state.config.mode == ServerGameMode::TurnTaking
-                        && state.flipped_card_ids().contains(card_id);
-                    let is_local_flipped = local.contains(card_id);
-
-                    let visible =
-                        is_matched || is_revealed || is_shared_flipped || is_local_flipped;
-
-                    // Get face data if visible - use asset_id for IIIF URL generation
-                    let (asset_id, name) = if visible {
-                        if let Some(face) = revealed.get(card_id) {
-                            (Some(face.asset_id.clone()), Some(face.name.clone()))
-                        } else if is_matched {
-                            (Some(card.asset_id.clone()), Some(card.name.clone()))
-                        } else {
-                            (None, None)
-                        }
-                    } else {
-                        (None, None)
-                    };
-
-                    CardView {
-                        card_id: card_id.clone(),
-                        visible,
-                        asset_id,
-                        name,
-                        matched: is_matched,
-                        matched_by: card.matched_by.clone(),
-                    }
-                })
-                .collect::<Vec<_>>()
-        })
-    };
+    let cards_view = Signal::derive(move || {
+        card_views(
+            &game_state.get(),
+            &revealed_faces.get(),
+            &local_flipped.get(),
+        )
+    });
 
     let flipped_card_ids = Signal::derive(move || {
         let state = game_state.get();
@@ -573,10 +610,7 @@ pub fn MemoryApp() -> impl IntoView {
         preload_assets
             .get()
             .into_iter()
-            .map(|asset| PreloadAsset {
-                policy_id: asset.policy_id,
-                asset_name_hex: asset.asset_name_hex,
-            })
+            .map(|asset| PreloadAsset::new(asset.policy_id, asset.asset_name_hex))
             .collect::<Vec<_>>()
     });
 
@@ -587,6 +621,13 @@ pub fn MemoryApp() -> impl IntoView {
         <div class="memory-app">
             <div class="header">
                 <h1>"Black Flag Memory"</h1>
+                <button
+                    class="sound-toggle"
+                    title=move || if sound_enabled.get() { "Mute sounds" } else { "Unmute sounds" }
+                    on:click=move |_| sound_enabled.set(!sound_enabled.get_untracked())
+                >
+                    {move || if sound_enabled.get() { "🔊" } else { "🔇" }}
+                </button>
                 <ConnectionStatus
                     status=status
                     on_reconnect=move |()| {
@@ -614,26 +655,19 @@ pub fn MemoryApp() -> impl IntoView {
             // Derive phase signals for Show conditions
             {
                 let is_lobby = Signal::derive(move || matches!(game_state.get().phase, GamePhase::Lobby { .. }));
-                let is_starting = Signal::derive(move || matches!(game_state.get().phase, GamePhase::Starting { .. }));
                 let is_loading = Signal::derive(move || matches!(game_state.get().phase, GamePhase::Loading { .. }));
                 let is_playing = Signal::derive(move || matches!(game_state.get().phase, GamePhase::Playing));
                 let is_finished = Signal::derive(move || matches!(game_state.get().phase, GamePhase::Finished { .. }));
 
                 let send_mode = send_action.clone();
                 let send_grid = send_action.clone();
+                let send_bots = send_action.clone();
                 let send_start = send_action.clone();
                 let send_flip = send_action.clone();
                 let send_ack = send_action.clone();
                 let send_rematch = send_action.clone();
 
                 // Derived signals for phase-specific data
-                let countdown = Signal::derive(move || {
-                    match game_state.get().phase {
-                        GamePhase::Starting { countdown } => countdown,
-                        _ => 0,
-                    }
-                });
-
                 let loading_info = Signal::derive(move || {
                     let state = game_state.get();
                     match &state.phase {
@@ -664,12 +698,15 @@ pub fn MemoryApp() -> impl IntoView {
                             current_user_id=current_user_id
                             game_mode=game_mode
                             grid_size=grid_size
+                            bot_fill=bot_fill
+                            bot_difficulty=bot_difficulty
                             on_mode_change={
                                 let send = send_mode.clone();
                                 move |mode: GameMode| {
                                     send(MemoryAction::SetConfig {
                                         mode: Some(mode.into()),
                                         grid_size: None,
+                                        bots: None,
                                     });
                                 }
                             }
@@ -679,6 +716,20 @@ pub fn MemoryApp() -> impl IntoView {
                                     send(MemoryAction::SetConfig {
                                         mode: None,
                                         grid_size: Some(size),
+                                        bots: None,
+                                    });
+                                }
+                            }
+                            on_bots_change={
+                                let send = send_bots.clone();
+                                move |fill_to: u8, difficulty: BotDifficulty| {
+                                    send(MemoryAction::SetConfig {
+                                        mode: None,
+                                        grid_size: None,
+                                        bots: Some(BotConfig {
+                                            fill_to,
+                                            difficulty: difficulty.into(),
+                                        }),
                                     });
                                 }
                             }
@@ -691,13 +742,10 @@ pub fn MemoryApp() -> impl IntoView {
                         />
                     </Show>
 
-                    // Starting countdown phase
-                    <Show when=move || is_starting.get() fallback=|| ()>
-                        <div class="countdown">
-                            <h2>"Game starting in..."</h2>
-                            <div class="countdown-number">{move || countdown.get()}</div>
-                        </div>
-                    </Show>
+                    // Countdown, "all ready" and winner overlays between phases
+                    <PhaseTransition overlay=Signal::derive(move || {
+                        phase_overlay(&game_state.get().phase, &current_user_id.get())
+                    }) />
 
                     // Loading phase
                     <Show when=move || is_loading.get() fallback=|| ()>
@@ -758,6 +806,9 @@ pub fn MemoryApp() -> impl IntoView {
                                     players=players_for_list
                                     current_turn=current_turn_user
                                     current_user_id=current_user_id
+                                    track_presence=true
+                                    crown_leader=true
+                                    title="Players"
                                 />
                             </div>
                         </div>
@@ -772,6 +823,17 @@ pub fn MemoryApp() -> impl IntoView {
                                 let (winner_sig, _) = signal(winner);
                                 let (rankings_sig, _) = signal(rankings);
                                 let send = send.clone();
+                                if let Some(game_id) = replay_game.get() {
+                                    return view! {
+                                        <ReplayViewer
+                                            room_id=room_id.get_untracked()
+                                            game_id=game_id
+                                            on_close=move |_| replay_game.set(None)
+                                        />
+                                    }
+                                    .into_any();
+                                }
+                                let game_id = game_state.with_untracked(|s| s.game_id);
                                 view! {
                                     <GameResults
                                         winner=winner_sig
@@ -780,24 +842,96 @@ pub fn MemoryApp() -> impl IntoView {
                                         on_rematch=move || {
                                             send(MemoryAction::RequestRematch);
                                         }
+                                        on_replay=move |_| replay_game.set(Some(game_id))
                                     />
                                 }
+                                .into_any()
                             }
                         }
                     </Show>
                 }
             }
+        </div>
+    }
+}
 
-            // Admin panel
-            {
-                let send_reset = send_action_for_admin.clone();
-                view! {
-                    <AdminPanel on_reset=move || {
-                        send_reset(MemoryAction::ResetGame);
-                    } />
+/// What each card shows, given the game state and this player's flips
+pub(crate) fn card_views(
+    state: &MemoryGameState,
+    revealed: &HashMap<CardId, CardFace>,
+    local: &[CardId],
+) -> Vec<CardView> {
+    state
+        .cards
+        .iter()
+        .map(|card| {
+            let card_id = &card.card_id;
+            // Card is visible if:
+            // 1. It's matched
+            // 2. It's in revealed_faces (server confirmed flip)
+            // 3. In turn-taking mode and it's in turn_state flipped
+            let is_matched = card.matched;
+            let is_revealed = revealed.contains_key(card_id);
+            let is_shared_flipped = state.config.mode == ServerGameMode::TurnTaking
+                && state.flipped_card_ids().contains(card_id);
+            let is_local_flipped = local.contains(card_id);
+
+            let visible = is_matched || is_revealed || is_shared_flipped || is_local_flipped;
+
+            // Get face data if visible - use asset_id for IIIF URL generation
+            let (asset_id, name) = if visible {
+                if let Some(face) = revealed.get(card_id) {
+                    (Some(face.asset_id.clone()), Some(face.name.clone()))
+                } else if is_matched {
+                    (Some(card.asset_id.clone()), Some(card.name.clone()))
+                } else {
+                    (None, None)
                 }
+            } else {
+                (None, None)
+            };
+
+            CardView {
+                card_id: card_id.clone(),
+                visible,
+                asset_id,
+                name,
+                matched: is_matched,
+                matched_by: card.matched_by.clone(),
             }
-        </div>
+        })
+        .collect()
+}
+
+/// Full-screen overlay announcing `phase`, if any
+fn phase_overlay(phase: &GamePhase, my_id: &str) -> Option<PhaseOverlay> {
+    match phase {
+        GamePhase::Starting { countdown } => Some(PhaseOverlay::Countdown {
+            seconds: *countdown,
+        }),
+        GamePhase::Playing => Some(PhaseOverlay::announcement("All players ready")),
+        GamePhase::Finished { winner, rankings } => {
+            let overlay = match winner.as_deref() {
+                Some(id) if id == my_id => PhaseOverlay::winner("You win!"),
+                Some(id) => {
+                    let name = rankings
+                        .iter()
+                        .find(|(user_id, _, _)| user_id == id)
+                        .map_or(id, |(_, name, _)| name.as_str());
+                    PhaseOverlay::winner(format!("{name} wins!"))
+                }
+                None => PhaseOverlay::announcement("It's a tie!"),
+            };
+            let score = winner
+                .as_ref()
+                .and_then(|id| rankings.iter().find(|(user_id, _, _)| user_id == id))
+                .map(|(_, _, score)| *score);
+            Some(match score {
+                Some(score) => overlay.with_subtitle(format!("{score} pairs")),
+                None => overlay,
+            })
+        }
+        GamePhase::Lobby { .. } | GamePhase::Loading { .. } => None,
     }
 }
 
@@ -808,6 +942,8 @@ fn handle_server_message(
     set_revealed_faces: WriteSignal<HashMap<CardId, CardFace>>,
     set_local_flipped: WriteSignal<Vec<CardId>>,
     set_preload_assets: WriteSignal<Vec<AssetId>>,
+    feedback: &FeedbackManager,
+    my_id: &str,
 ) {
     match msg {
         ServerMessage::Connected { .. } => {
@@ -824,6 +960,25 @@ fn handle_server_message(
 
         ServerMessage::Delta { delta, seq, .. } => {
             tracing::debug!("Received Delta at seq {}", seq);
+            if let Some(event) = feedback_event(&delta, my_id) {
+                feedback.play(event);
+            }
+            apply_delta(
+                delta,
+                set_game_state,
+                set_revealed_faces,
+                set_local_flipped,
+                set_preload_assets,
+            );
+        }
+
+        ServerMessage::PrivateDelta { delta, seq, .. } => {
+            tracing::debug!("Received PrivateDelta at seq {}", seq);
+            // Revealed faces and local flips are this player's private state;
+            // they live outside the shared game state and reset on snapshot
+            if let Some(event) = feedback_event(&delta, my_id) {
+                feedback.play(event);
+            }
             apply_delta(
                 delta,
                 set_game_state,
@@ -835,6 +990,7 @@ fn handle_server_message(
 
         ServerMessage::Deltas { deltas, seq, .. } => {
             tracing::debug!("Received {} Deltas, final seq {}", deltas.len(), seq);
+            // Replayed catch-up batch: apply silently rather than play a burst of sounds
             for delta in deltas {
                 apply_delta(
                     delta,
@@ -881,7 +1037,26 @@ fn handle_server_message(
     }
 }
 
-fn apply_delta(
+/// The feedback a live delta should trigger for the local player
+fn feedback_event(delta: &MemoryDelta, my_id: &str) -> Option<FeedbackEvent> {
+    match delta {
+        MemoryDelta::CardFlipped { .. } | MemoryDelta::OwnCardFlipped { .. } => {
+            Some(FeedbackEvent::Flip)
+        }
+        MemoryDelta::PairMatched { .. } => Some(FeedbackEvent::Match),
+        MemoryDelta::TurnChanged { user_id } if user_id == my_id => Some(FeedbackEvent::YourTurn),
+        MemoryDelta::GameEnded {
+            winner: Some(winner),
+            ..
+        } if winner == my_id => Some(FeedbackEvent::Win),
+        _ => None,
+    }
+}
+
+/// Apply a delta to the game state and this player's view of the board
+///
+/// Also used by the replay viewer to rebuild recorded games.
+pub(crate) fn apply_delta(
     delta: MemoryDelta,
     set_game_state: WriteSignal<MemoryGameState>,
     set_revealed_faces: WriteSignal<HashMap<CardId, CardFace>>,
@@ -893,6 +1068,7 @@ fn apply_delta(
             user_id,
             user_name,
             spectating,
+            bot,
         } => {
             set_game_state.update(|s| {
                 s.players.insert(
@@ -904,6 +1080,7 @@ fn apply_delta(
                         flipped: vec![],
                         spectating,
                         joined_at: 0,
+                        bot,
                     },
                 );
             });
@@ -938,6 +1115,7 @@ fn apply_delta(
                 s.turn_order = turn_order;
                 s.current_turn = 0;
                 s.phase = GamePhase::Playing;
+                s.streaks.clear();
             });
             set_revealed_faces.set(HashMap::new());
             set_local_flipped.set(Vec::new());
@@ -1068,6 +1246,7 @@ fn apply_delta(
                 if let Some(player) = s.players.get_mut(&by) {
                     player.score = new_score;
                 }
+                *s.streaks.entry(by.clone()).or_default() += 1;
                 // Reset turn state (player gets another turn)
                 s.turn_state = TurnState::AwaitingFirst;
             });
@@ -1082,7 +1261,7 @@ fn apply_delta(
 
         MemoryDelta::CardsReset {
             card_ids,
-            for_player: _,
+            for_player,
         } => {
             // Clear revealed faces
             set_revealed_faces.update(|m| {
@@ -1090,9 +1269,13 @@ fn apply_delta(
                     m.remove(card_id);
                 }
             });
-            // Reset turn state (next player's turn)
+            // Reset turn state (next player's turn) and end the missing player's streak
             set_game_state.update(|s| {
                 s.turn_state = TurnState::AwaitingFirst;
+                let missed = for_player.or_else(|| s.turn_order.get(s.current_turn).cloned());
+                if let Some(user_id) = missed {
+                    s.streaks.remove(&user_id);
+                }
             });
             set_local_flipped.set(Vec::new());
         }
@@ -1105,27 +1288,24 @@ fn apply_delta(
             });
         }
 
-        MemoryDelta::GameEnded { winner, rankings } => {
+        MemoryDelta::GameEnded {
+            winner,
+            rankings,
+            game_id,
+        } => {
             set_game_state.update(|s| {
                 s.phase = GamePhase::Finished { winner, rankings };
+                s.game_id = game_id;
             });
         }
     }
 }
 
 fn get_memory_ws_url(room_id: &str, user_id: &str) -> String {
-    let window = web_sys::window().expect("no window");
-    let location = window.location();
-    let protocol = location.protocol().unwrap_or_else(|_| "http:".to_string());
-    let host = location
-        .host()
-        .unwrap_or_else(|_| "localhost:8787".to_string());
-
-    let ws_protocol = if protocol == "https:" { "wss:" } else { "ws:" };
     let display_name = &user_id[..8.min(user_id.len())];
 
-    format!(
-        "{}//{}/memory/{}?user_id={}&user_name={}",
-        ws_protocol, host, room_id, user_id, display_name
+    append_query(
+        &endpoints().ws(&format!("/memory/{room_id}")),
+        &[("user_id", user_id), ("user_name", display_name)],
     )
 }
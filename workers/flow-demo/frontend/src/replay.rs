@@ -0,0 +1,257 @@
+//! Game Replay Viewer
+//!
+//! Plays back a finished memory game recorded by the server
+//! (`GET /memory/:room_id/replay/:game_id`) on a time slider. Each position
+//! is rebuilt by applying the recorded deltas up to it with the same
+//! [`apply_delta`] the live game uses, so the board looks as it did to
+//! every player then.
+
+use crate::components::GameBoard;
+use crate::memory_app::{
+    apply_delta, card_views, CardFace, CardId, GamePhase, MemoryDelta, MemoryGameState,
+};
+use leptos::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use ui_components::{use_fetch, PlayerList, PlayerListEntry};
+use ui_core::http::get_json;
+use ui_core::urls::{encode_component, endpoints};
+use ui_core::FetchState;
+
+/// Playback timer resolution
+const TICK: Duration = Duration::from_millis(100);
+
+/// Playback speeds to cycle through
+const SPEEDS: &[u64] = &[1, 2, 4];
+
+/// One recorded delta (mirrors the server's `ReplayFrame`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReplayFrame {
+    /// Milliseconds since the recording started
+    pub at_ms: u64,
+    pub delta: MemoryDelta,
+}
+
+/// A recorded game (mirrors the server's `GameReplay`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct GameReplay {
+    pub game_id: u64,
+    pub duration_ms: u64,
+    /// State before the first frame
+    pub initial: MemoryGameState,
+    pub frames: Vec<ReplayFrame>,
+    /// The recording hit its size limit before the game ended
+    pub truncated: bool,
+}
+
+/// `m:ss` for a position in the replay
+fn format_position(ms: u64) -> String {
+    let secs = ms / 1000;
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Replay of one finished game
+#[component]
+pub fn ReplayViewer(
+    /// Room the game was played in
+    #[prop(into)]
+    room_id: String,
+    /// Game to replay
+    game_id: u64,
+    /// Leave the replay
+    #[prop(into)]
+    on_close: Callback<()>,
+) -> impl IntoView {
+    let url = endpoints().api(&format!(
+        "/memory/{}/replay/{game_id}",
+        encode_component(&room_id)
+    ));
+    let replay = use_fetch(move || {
+        let url = url.clone();
+        async move { get_json::<GameReplay>(&url, None).await }
+    });
+
+    view! {
+        <div class="game-replay">
+            {move || match replay.state() {
+                FetchState::Loaded(replay) => view! {
+                    <ReplayPlayer replay=replay on_close=on_close />
+                }
+                .into_any(),
+                FetchState::Failed(error) => view! {
+                    <div class="replay-status">
+                        <p>{format!("Couldn't load the replay: {error}")}</p>
+                        <button on:click=move |_| on_close.run(())>"Back"</button>
+                    </div>
+                }
+                .into_any(),
+                _ => view! { <p class="replay-status">"Loading replay..."</p> }.into_any(),
+            }}
+        </div>
+    }
+}
+
+/// Board, scores and playback controls for a loaded replay
+#[component]
+fn ReplayPlayer(replay: GameReplay, on_close: Callback<()>) -> impl IntoView {
+    let duration = replay.duration_ms;
+    let truncated = replay.truncated;
+    let (game_state, set_game_state) = signal(replay.initial.clone());
+    let replay = StoredValue::new(replay);
+
+    let (revealed_faces, set_revealed_faces) = signal(HashMap::<CardId, CardFace>::new());
+    let (local_flipped, set_local_flipped) = signal(Vec::<CardId>::new());
+    // Live preloading doesn't apply to replays
+    let (_, set_preload_assets) = signal(Vec::new());
+
+    let position = RwSignal::new(0u64);
+    let playing = RwSignal::new(true);
+    let speed = RwSignal::new(SPEEDS[0]);
+    // Frames applied to the signals above
+    let applied = StoredValue::new(0usize);
+
+    // Rebuild the board at the slider position: forward moves apply only the
+    // new frames, backward ones start over from the initial state
+    Effect::new(move |_| {
+        let at = position.get();
+        replay.with_value(|replay| {
+            let due = replay.frames.partition_point(|f| f.at_ms <= at);
+            let mut from = applied.get_value();
+            if due < from {
+                set_game_state.set(replay.initial.clone());
+                set_revealed_faces.set(HashMap::new());
+                set_local_flipped.set(Vec::new());
+                from = 0;
+            }
+            for frame in &replay.frames[from..due] {
+                apply_delta(
+                    frame.delta.clone(),
+                    set_game_state,
+                    set_revealed_faces,
+                    set_local_flipped,
+                    set_preload_assets,
+                );
+            }
+            applied.set_value(due);
+        });
+    });
+
+    if let Ok(handle) = set_interval_with_handle(
+        move || {
+            if !playing.get_untracked() {
+                return;
+            }
+            let step = TICK.as_millis() as u64 * speed.get_untracked();
+            let next = (position.get_untracked() + step).min(duration);
+            position.set(next);
+            if next == duration {
+                playing.set(false);
+            }
+        },
+        TICK,
+    ) {
+        on_cleanup(move || handle.clear());
+    }
+
+    let cards = Signal::derive(move || {
+        card_views(
+            &game_state.get(),
+            &revealed_faces.get(),
+            &local_flipped.get(),
+        )
+    });
+    let flipped_card_ids = Signal::derive(move || game_state.get().flipped_card_ids());
+    let grid_size = Signal::derive(move || game_state.get().config.grid_size);
+    let current_turn = Signal::derive(move || {
+        let state = game_state.get();
+        match state.phase {
+            GamePhase::Playing => state.turn_order.get(state.current_turn).cloned(),
+            _ => None,
+        }
+    });
+    let players = Signal::derive(move || {
+        let state = game_state.get();
+        state
+            .players
+            .values()
+            .filter(|p| !p.spectating)
+            .map(|p| PlayerListEntry {
+                user_id: p.user_id.clone(),
+                name: p.user_name.clone(),
+                score: p.score,
+                streak: state.streaks.get(&p.user_id).copied().unwrap_or(0),
+                bot: p.bot,
+                ..Default::default()
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let toggle_playing = move |_| {
+        if !playing.get_untracked() && position.get_untracked() >= duration {
+            position.set(0);
+        }
+        playing.update(|p| *p = !*p);
+    };
+    let cycle_speed = move |_| {
+        speed.update(|s| {
+            let next = SPEEDS.iter().position(|x| x == s).map_or(0, |i| i + 1);
+            *s = SPEEDS[next % SPEEDS.len()];
+        });
+    };
+
+    view! {
+        <div class="game-playing">
+            <div class="game-main">
+                <GameBoard
+                    grid_size=grid_size
+                    cards=cards
+                    flipped_card_ids=flipped_card_ids
+                    is_my_turn=Signal::derive(|| false)
+                    on_flip=|_| {}
+                    on_card_loaded=|_| {}
+                    disabled=Signal::derive(|| true)
+                />
+                <div class="replay-controls">
+                    <button on:click=toggle_playing>
+                        {move || if playing.get() { "Pause" } else { "Play" }}
+                    </button>
+                    <input
+                        type="range"
+                        min="0"
+                        max=duration.to_string()
+                        step=TICK.as_millis().to_string()
+                        aria-label="Replay position"
+                        prop:value=move || position.get().to_string()
+                        on:input=move |ev| {
+                            if let Ok(at) = event_target_value(&ev).parse::<u64>() {
+                                playing.set(false);
+                                position.set(at.min(duration));
+                            }
+                        }
+                    />
+                    <span class="replay-time">
+                        {move || format!(
+                            "{} / {}",
+                            format_position(position.get()),
+                            format_position(duration)
+                        )}
+                    </span>
+                    <button on:click=cycle_speed>{move || format!("{}x", speed.get())}</button>
+                </div>
+                {truncated.then(|| view! {
+                    <p class="replay-time">"This recording ends before the game did."</p>
+                })}
+            </div>
+            <div class="game-sidebar">
+                <PlayerList
+                    players=players
+                    current_turn=current_turn
+                    crown_leader=true
+                    title="Replay"
+                />
+                <button on:click=move |_| on_close.run(())>"Back to Results"</button>
+            </div>
+        </div>
+    }
+}
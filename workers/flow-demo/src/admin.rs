@@ -0,0 +1,238 @@
+//! Admin HTTP API for room management.
+//!
+//! Every endpoint requires `Authorization: Bearer <token>` matching the
+//! `FLOW_ADMIN_TOKEN` secret, and returns 404 when the secret isn't set.
+//! Room IDs are `<kind>:<name>`, e.g. `memory:lobby` or `chat:default`.
+
+use crate::room_directory::{RoomEntry, DIRECTORY_NAME};
+use crate::types::RoomStats;
+use serde::Serialize;
+use ui_flow_protocol::ClientCommand;
+use wasm_bindgen::JsValue;
+use worker::*;
+
+/// Internal URL of a room's command endpoint
+pub const ROOM_COMMAND_URL: &str = "https://room/command";
+/// Internal URL of a room's stats endpoint
+pub const ROOM_STATS_URL: &str = "https://room/admin/stats";
+/// Internal URL of a room's reset endpoint
+pub const ROOM_RESET_URL: &str = "https://room/admin/reset";
+
+/// Whether a request reaching a room is the internal `url` built by this
+/// worker after [`check_auth`]
+///
+/// Client requests forwarded to a room keep their public URL, so they
+/// never match.
+pub fn is_internal(req: &Request, method: Method, url: &str) -> bool {
+    req.method() == method && req.url().is_ok_and(|u| u.as_str() == url)
+}
+
+/// Room types served by this worker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoomKind {
+    /// Counter/chat demo (`/ws/:room_id`)
+    Chat,
+    /// Memory game (`/memory/:room_id`)
+    Memory,
+}
+
+impl RoomKind {
+    fn prefix(&self) -> &'static str {
+        match self {
+            RoomKind::Chat => "chat",
+            RoomKind::Memory => "memory",
+        }
+    }
+
+    /// Durable Object binding for this room type
+    pub fn binding(&self) -> &'static str {
+        match self {
+            RoomKind::Chat => "FLOW_SESSIONS",
+            RoomKind::Memory => "MEMORY_SESSIONS",
+        }
+    }
+
+    /// Admin room ID for a room of this type
+    pub fn room_id(&self, name: &str) -> String {
+        format!("{}:{name}", self.prefix())
+    }
+
+    /// Split an admin room ID into its type and name
+    fn parse_room_id(id: &str) -> Option<(RoomKind, &str)> {
+        let (prefix, name) = id.split_once(':')?;
+        let kind = match prefix {
+            "chat" => RoomKind::Chat,
+            "memory" => RoomKind::Memory,
+            _ => return None,
+        };
+        (!name.is_empty()).then_some((kind, name))
+    }
+}
+
+/// A room as listed by `GET /admin/rooms`
+#[derive(Debug, Serialize)]
+struct RoomSummary {
+    id: String,
+    last_seen: u64,
+    /// Live stats, absent if the room didn't answer
+    #[serde(flatten)]
+    stats: Option<RoomStats>,
+}
+
+/// Reject requests without the admin bearer token
+///
+/// Returns the error response to send, or `None` if the request may proceed.
+pub fn check_auth(req: &Request, env: &Env) -> Result<Option<Response>> {
+    let Ok(admin_token) = env.secret("FLOW_ADMIN_TOKEN") else {
+        return Response::error("Admin API is not enabled", 404).map(Some);
+    };
+    let expected = format!("Bearer {admin_token}");
+    let provided = req.headers().get("Authorization")?.unwrap_or_default();
+    if !constant_time_eq(provided.as_bytes(), expected.as_bytes()) {
+        return Response::error("Unauthorized", 401).map(Some);
+    }
+    Ok(None)
+}
+
+/// Compare secrets without stopping at the first differing byte
+///
+/// Only the length can leak, which the fixed `Bearer` format already reveals.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Record that a room was connected to, for `GET /admin/rooms`
+pub async fn register_room(env: &Env, kind: RoomKind, name: &str) {
+    let entry = RoomEntry {
+        id: kind.room_id(name),
+        last_seen: js_sys::Date::now() as u64,
+    };
+    let result = async {
+        let body = serde_json::to_string(&entry).map_err(|e| Error::RustError(e.to_string()))?;
+        let mut init = RequestInit::new();
+        init.with_method(Method::Post)
+            .with_body(Some(JsValue::from_str(&body)));
+        let req = Request::new_with_init("https://directory/register", &init)?;
+        directory_stub(env)?.fetch_with_request(req).await
+    }
+    .await;
+
+    if let Err(e) = result {
+        tracing::warn!("Failed to register room {}: {e}", entry.id);
+    }
+}
+
+/// `GET /admin/rooms` - rooms seen in the last week with live stats
+pub async fn list_rooms(req: Request, ctx: RouteContext<()>) -> Result<Response> {
+    if let Some(denied) = check_auth(&req, &ctx.env)? {
+        return Ok(denied);
+    }
+
+    let mut resp = directory_stub(&ctx.env)?
+        .fetch_with_str("https://directory/rooms")
+        .await?;
+    let entries: Vec<RoomEntry> = resp.json().await?;
+
+    let mut rooms = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let stats = match RoomKind::parse_room_id(&entry.id) {
+            Some((kind, name)) => room_stats(&ctx.env, kind, name).await.ok(),
+            None => None,
+        };
+        rooms.push(RoomSummary {
+            id: entry.id,
+            last_seen: entry.last_seen,
+            stats,
+        });
+    }
+
+    Response::from_json(&rooms)
+}
+
+/// `POST /admin/rooms/:id/reset` - reset a room to its initial state
+pub async fn reset_room(req: Request, ctx: RouteContext<()>) -> Result<Response> {
+    if let Some(denied) = check_auth(&req, &ctx.env)? {
+        return Ok(denied);
+    }
+    let Some((kind, name)) = ctx.param("id").and_then(|id| RoomKind::parse_room_id(id)) else {
+        return Response::error("Unknown room", 404);
+    };
+
+    let mut init = RequestInit::new();
+    init.with_method(Method::Post);
+    let forward = Request::new_with_init(ROOM_RESET_URL, &init)?;
+    room_stub(&ctx.env, kind, name)?
+        .fetch_with_request(forward)
+        .await
+}
+
+/// `POST /admin/rooms/:id/broadcast` - send a JSON `ClientCommand` to a room
+pub async fn broadcast_room(req: Request, ctx: RouteContext<()>) -> Result<Response> {
+    if let Some(denied) = check_auth(&req, &ctx.env)? {
+        return Ok(denied);
+    }
+    let Some((kind, name)) = ctx.param("id").and_then(|id| RoomKind::parse_room_id(id)) else {
+        return Response::error("Unknown room", 404);
+    };
+    send_command(req, &ctx.env, kind, name).await
+}
+
+/// Validate a JSON `ClientCommand` and forward it to a room
+///
+/// Callers must have passed [`check_auth`].
+pub async fn send_command(
+    mut req: Request,
+    env: &Env,
+    kind: RoomKind,
+    name: &str,
+) -> Result<Response> {
+    // Reject bad commands here rather than in the room
+    let command: ClientCommand = match req.json().await {
+        Ok(command) => command,
+        Err(e) => return Response::error(format!("Invalid command: {e}"), 400),
+    };
+    if matches!(command, ClientCommand::Unknown) {
+        return Response::error("Unknown command kind", 400);
+    }
+    let body = serde_json::to_string(&command).map_err(|e| Error::RustError(e.to_string()))?;
+
+    let mut init = RequestInit::new();
+    init.with_method(Method::Post)
+        .with_body(Some(JsValue::from_str(&body)));
+    let forward = Request::new_with_init(ROOM_COMMAND_URL, &init)?;
+    room_stub(env, kind, name)?
+        .fetch_with_request(forward)
+        .await
+}
+
+async fn room_stats(env: &Env, kind: RoomKind, name: &str) -> Result<RoomStats> {
+    let mut resp = room_stub(env, kind, name)?
+        .fetch_with_str(ROOM_STATS_URL)
+        .await?;
+    resp.json().await
+}
+
+fn room_stub(env: &Env, kind: RoomKind, name: &str) -> Result<Stub> {
+    env.durable_object(kind.binding())?
+        .id_from_name(name)?
+        .get_stub()
+}
+
+fn directory_stub(env: &Env) -> Result<Stub> {
+    env.durable_object("ROOM_DIRECTORY")?
+        .id_from_name(DIRECTORY_NAME)?
+        .get_stub()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"Bearer secret", b"Bearer secret"));
+        assert!(!constant_time_eq(b"Bearer secret", b"Bearer secreT"));
+        assert!(!constant_time_eq(b"Bearer secret", b"Bearer secret2"));
+        assert!(!constant_time_eq(b"", b"Bearer secret"));
+    }
+}
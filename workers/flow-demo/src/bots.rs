@@ -0,0 +1,429 @@
+//! Server-side bot players for the memory game.
+//!
+//! Bots take empty seats in short-handed lobbies (see [`BotConfig`]) so a
+//! solo player still has someone to play against. They flip through the same
+//! paths as people, paced by the Durable Object alarm, and remember cards
+//! they've seen face up. Each remembered card is recalled with a chance set
+//! by [`BotDifficulty`], so easy bots forget most of the board and hard ones
+//! rarely do. Bots never look at cards they haven't seen.
+//!
+//! Everything here works on [`MemoryGameState`] alone; the session does the
+//! broadcasting and scheduling.
+
+use std::ops::Range;
+
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+use crate::types::{
+    BotConfig, BotDifficulty, Card, CardId, GameMode, GamePhase, MemoryGameState, PlayerState,
+    TurnState,
+};
+
+/// user_id prefix for bots
+pub const BOT_ID_PREFIX: &str = "bot_";
+
+/// Display names, one per bot seat (rooms hold at most 8 players)
+const BOT_NAMES: [&str; 7] = [
+    "Calico Jack",
+    "Anne Bonny",
+    "Mary Read",
+    "Blackbeard",
+    "Henry Every",
+    "Black Bart",
+    "Grace O'Malley",
+];
+
+/// Most bots in one room
+pub const MAX_BOTS: usize = BOT_NAMES.len();
+
+/// Cards each bot keeps in memory; older sightings are forgotten
+const MEMORY_CAPACITY: usize = 24;
+
+impl BotDifficulty {
+    /// Chance of recalling each remembered card when choosing a flip
+    pub fn recall(&self) -> f64 {
+        match self {
+            BotDifficulty::Easy => 0.25,
+            BotDifficulty::Normal => 0.6,
+            BotDifficulty::Hard => 0.9,
+        }
+    }
+
+    /// Thinking time before each flip, in ms
+    fn think_ms(&self) -> Range<u64> {
+        match self {
+            BotDifficulty::Easy => 1800..3200,
+            BotDifficulty::Normal => 1200..2400,
+            BotDifficulty::Hard => 800..1600,
+        }
+    }
+}
+
+/// Random thinking time before a bot's next flip
+pub fn think_delay_ms(difficulty: BotDifficulty, rng: &mut impl Rng) -> u64 {
+    rng.random_range(difficulty.think_ms())
+}
+
+/// Bots added and removed by [`sync_bots`]
+#[derive(Debug, Default)]
+pub struct BotChanges {
+    pub joined: Vec<PlayerState>,
+    pub left: Vec<String>,
+}
+
+/// Whether any people are seated
+pub fn has_humans(state: &MemoryGameState) -> bool {
+    state.players.values().any(|p| !p.bot)
+}
+
+/// Seat or remove bots so the room has `config.bots.fill_to` players
+///
+/// Rooms without people get no bots. Bots are renumbered from 1, so the same
+/// seat always has the same name.
+pub fn sync_bots(state: &mut MemoryGameState, now: u64) -> BotChanges {
+    let BotConfig { fill_to, .. } = state.config.bots;
+    let humans = state.players.values().filter(|p| !p.bot).count();
+    let wanted = if humans == 0 {
+        0
+    } else {
+        usize::from(fill_to).saturating_sub(humans).min(MAX_BOTS)
+    };
+
+    let mut changes = BotChanges::default();
+    for (i, name) in BOT_NAMES.iter().enumerate() {
+        let user_id = format!("{BOT_ID_PREFIX}{}", i + 1);
+        let seated = state.players.contains_key(&user_id);
+        if i < wanted && !seated {
+            let bot = PlayerState {
+                user_id: user_id.clone(),
+                user_name: name.to_string(),
+                score: 0,
+                flipped: vec![],
+                spectating: false,
+                joined_at: now,
+                bot: true,
+            };
+            state.players.insert(user_id, bot.clone());
+            changes.joined.push(bot);
+        } else if i >= wanted && seated {
+            remove_player(state, &user_id);
+            changes.left.push(user_id);
+        }
+    }
+    changes
+}
+
+/// Remove every bot (the last person left mid-game)
+pub fn remove_bots(state: &mut MemoryGameState) -> Vec<String> {
+    let bots: Vec<String> = state
+        .players
+        .values()
+        .filter(|p| p.bot)
+        .map(|p| p.user_id.clone())
+        .collect();
+    for user_id in &bots {
+        remove_player(state, user_id);
+    }
+    bots
+}
+
+fn remove_player(state: &mut MemoryGameState, user_id: &str) {
+    state.players.remove(user_id);
+    state.bot_memory.remove(user_id);
+    state.turn_order.retain(|id| id != user_id);
+    if !state.turn_order.is_empty() {
+        state.current_turn %= state.turn_order.len();
+    }
+}
+
+/// Every bot saw `card_id` (turn-taking flips are public)
+pub fn remember_all(state: &mut MemoryGameState, card_id: &CardId) {
+    let bots: Vec<String> = state
+        .players
+        .values()
+        .filter(|p| p.bot)
+        .map(|p| p.user_id.clone())
+        .collect();
+    for bot in bots {
+        remember(state, &bot, card_id);
+    }
+}
+
+/// `bot_id` saw `card_id` (its own race-mode flip)
+pub fn remember(state: &mut MemoryGameState, bot_id: &str, card_id: &CardId) {
+    let memory = state.bot_memory.entry(bot_id.to_string()).or_default();
+    memory.retain(|id| id != card_id);
+    memory.push(card_id.clone());
+    if memory.len() > MEMORY_CAPACITY {
+        memory.remove(0);
+    }
+}
+
+/// Bot whose turn it is in turn-taking mode, with the card it already
+/// flipped this turn
+pub fn turn_taking_bot(state: &MemoryGameState) -> Option<(String, Option<CardId>)> {
+    if !matches!(state.phase, GamePhase::Playing) || state.config.mode != GameMode::TurnTaking {
+        return None;
+    }
+    let user_id = state.turn_order.get(state.current_turn)?;
+    if !state.players.get(user_id)?.bot {
+        return None;
+    }
+    let first = match &state.turn_state {
+        TurnState::AwaitingFirst => None,
+        TurnState::FirstFlipped { card_id, .. } => Some(card_id.clone()),
+        // Waiting on card loads or the flip-back timer
+        _ => return None,
+    };
+    Some((user_id.clone(), first))
+}
+
+/// Bots playing a race-mode game
+pub fn racing_bots(state: &MemoryGameState) -> Vec<String> {
+    if !matches!(state.phase, GamePhase::Playing) || state.config.mode != GameMode::Race {
+        return Vec::new();
+    }
+    let mut bots: Vec<String> = state
+        .players
+        .values()
+        .filter(|p| p.bot && !p.spectating)
+        .map(|p| p.user_id.clone())
+        .collect();
+    bots.sort();
+    bots
+}
+
+/// Whether a bot has a move to make, so the alarm should be scheduled
+pub fn bots_pending(state: &MemoryGameState) -> bool {
+    has_humans(state) && (turn_taking_bot(state).is_some() || !racing_bots(state).is_empty())
+}
+
+/// Card a bot flips next, given what it remembers
+///
+/// `first` is the card it already has face up. Each remembered card is
+/// recalled with probability `recall`: a recalled partner of `first`, or a
+/// recalled pair, is taken; otherwise the bot tries a card it doesn't
+/// remember.
+pub fn choose_card(
+    cards: &[Card],
+    memory: &[CardId],
+    first: Option<&CardId>,
+    recall: f64,
+    rng: &mut impl Rng,
+) -> Option<CardId> {
+    let open = |card: &&Card| !card.matched && Some(&card.card_id) != first;
+    let recall = recall.clamp(0.0, 1.0);
+    let recalled: Vec<&Card> = memory
+        .iter()
+        .filter_map(|id| cards.iter().find(|c| &c.card_id == id))
+        .filter(open)
+        .filter(|_| rng.random_bool(recall))
+        .collect();
+
+    let known = match first.and_then(|id| cards.iter().find(|c| &c.card_id == id)) {
+        Some(first) => recalled.iter().find(|c| c.pair_id == first.pair_id),
+        None => recalled.iter().find(|a| {
+            recalled
+                .iter()
+                .any(|b| b.pair_id == a.pair_id && b.card_id != a.card_id)
+        }),
+    };
+    if let Some(card) = known {
+        return Some(card.card_id.clone());
+    }
+
+    let unknown: Vec<&Card> = cards
+        .iter()
+        .filter(open)
+        .filter(|c| !recalled.iter().any(|r| r.card_id == c.card_id))
+        .collect();
+    let pool = if unknown.is_empty() {
+        cards.iter().filter(open).collect()
+    } else {
+        unknown
+    };
+    pool.choose(rng).map(|c| c.card_id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    fn card(id: &str, pair_id: u8) -> Card {
+        Card {
+            card_id: CardId(id.to_string()),
+            asset_id: String::new(),
+            name: String::new(),
+            image_url: String::new(),
+            matched: false,
+            matched_by: None,
+            pair_id,
+        }
+    }
+
+    fn human(id: &str) -> PlayerState {
+        PlayerState {
+            user_id: id.to_string(),
+            user_name: id.to_string(),
+            score: 0,
+            flipped: vec![],
+            spectating: false,
+            joined_at: 0,
+            bot: false,
+        }
+    }
+
+    fn room(humans: &[&str], fill_to: u8) -> MemoryGameState {
+        let mut state = MemoryGameState::default();
+        state.config.bots.fill_to = fill_to;
+        for id in humans {
+            state.players.insert(id.to_string(), human(id));
+        }
+        state
+    }
+
+    fn ids(ids: &[&str]) -> Vec<CardId> {
+        ids.iter().map(|id| CardId(id.to_string())).collect()
+    }
+
+    #[test]
+    fn fills_empty_seats() {
+        let mut state = room(&["alice"], 3);
+        let changes = sync_bots(&mut state, 0);
+        let joined: Vec<_> = changes.joined.iter().map(|p| p.user_id.as_str()).collect();
+        assert_eq!(joined, ["bot_1", "bot_2"]);
+        assert!(changes.joined.iter().all(|p| p.bot));
+        assert_eq!(state.players.len(), 3);
+
+        // Already full: nothing changes
+        let changes = sync_bots(&mut state, 0);
+        assert!(changes.joined.is_empty() && changes.left.is_empty());
+    }
+
+    #[test]
+    fn bots_give_up_seats_to_people() {
+        let mut state = room(&["alice"], 3);
+        sync_bots(&mut state, 0);
+        state.players.insert("bob".into(), human("bob"));
+        let changes = sync_bots(&mut state, 0);
+        assert_eq!(changes.left, ["bot_2"]);
+        assert!(state.players.contains_key("bot_1"));
+    }
+
+    #[test]
+    fn no_bots_without_people() {
+        let mut state = room(&["alice"], 4);
+        sync_bots(&mut state, 0);
+        state.players.remove("alice");
+        let changes = sync_bots(&mut state, 0);
+        assert_eq!(changes.left.len(), 3);
+        assert!(state.players.is_empty());
+
+        let mut off = room(&["alice"], 0);
+        assert!(sync_bots(&mut off, 0).joined.is_empty());
+    }
+
+    #[test]
+    fn bot_count_is_capped() {
+        let mut state = room(&["alice"], u8::MAX);
+        sync_bots(&mut state, 0);
+        assert_eq!(state.players.len(), MAX_BOTS + 1);
+    }
+
+    #[test]
+    fn remove_bots_fixes_turn_order() {
+        let mut state = room(&["alice"], 3);
+        sync_bots(&mut state, 0);
+        state.turn_order = vec!["bot_1".into(), "alice".into(), "bot_2".into()];
+        state.current_turn = 2;
+        let mut removed = remove_bots(&mut state);
+        removed.sort();
+        assert_eq!(removed, ["bot_1", "bot_2"]);
+        assert_eq!(state.turn_order, ["alice"]);
+        assert_eq!(state.current_turn, 0);
+    }
+
+    #[test]
+    fn memory_is_deduplicated_and_capped() {
+        let mut state = room(&["alice"], 2);
+        sync_bots(&mut state, 0);
+        for i in 0..MEMORY_CAPACITY + 5 {
+            remember_all(&mut state, &CardId(i.to_string()));
+        }
+        remember_all(&mut state, &CardId("10".into()));
+        let memory = &state.bot_memory["bot_1"];
+        assert_eq!(memory.len(), MEMORY_CAPACITY);
+        assert_eq!(memory.last(), Some(&CardId("10".into())));
+        assert_eq!(memory.iter().filter(|id| id.0 == "10").count(), 1);
+    }
+
+    #[test]
+    fn perfect_recall_takes_known_partner() {
+        let cards = vec![card("a1", 1), card("b1", 2), card("a2", 1), card("b2", 2)];
+        let mut rng = SmallRng::seed_from_u64(7);
+        let first = CardId("a1".into());
+        let choice = choose_card(&cards, &ids(&["b1", "a2"]), Some(&first), 1.0, &mut rng);
+        assert_eq!(choice, Some(CardId("a2".into())));
+    }
+
+    #[test]
+    fn perfect_recall_starts_with_known_pair() {
+        let cards = vec![card("a1", 1), card("b1", 2), card("a2", 1), card("b2", 2)];
+        let mut rng = SmallRng::seed_from_u64(7);
+        let choice = choose_card(&cards, &ids(&["b1", "a1", "b2"]), None, 1.0, &mut rng);
+        assert_eq!(choice, Some(CardId("b1".into())));
+    }
+
+    #[test]
+    fn never_flips_matched_or_same_card() {
+        let mut cards = vec![card("a1", 1), card("a2", 1), card("b1", 2), card("b2", 2)];
+        cards[0].matched = true;
+        cards[1].matched = true;
+        let first = CardId("b1".into());
+        let mut rng = SmallRng::seed_from_u64(1);
+        for _ in 0..20 {
+            let choice = choose_card(&cards, &ids(&["a1"]), Some(&first), 0.0, &mut rng);
+            assert_eq!(choice, Some(CardId("b2".into())));
+        }
+        cards[2].matched = true;
+        cards[3].matched = true;
+        assert_eq!(choose_card(&cards, &[], None, 1.0, &mut rng), None);
+    }
+
+    #[test]
+    fn detects_bot_turns() {
+        let mut state = room(&["alice"], 2);
+        sync_bots(&mut state, 0);
+        state.phase = GamePhase::Playing;
+        state.turn_order = vec!["alice".into(), "bot_1".into()];
+        assert_eq!(turn_taking_bot(&state), None);
+
+        state.current_turn = 1;
+        assert_eq!(turn_taking_bot(&state), Some(("bot_1".into(), None)));
+        state.turn_state = TurnState::FirstFlipped {
+            card_id: CardId("a1".into()),
+            acked: false,
+        };
+        assert_eq!(
+            turn_taking_bot(&state),
+            Some(("bot_1".into(), Some(CardId("a1".into()))))
+        );
+        assert!(bots_pending(&state));
+
+        state.players.remove("alice");
+        assert!(!bots_pending(&state));
+    }
+
+    #[test]
+    fn race_bots_only_while_playing() {
+        let mut state = room(&["alice"], 3);
+        sync_bots(&mut state, 0);
+        state.config.mode = GameMode::Race;
+        assert!(racing_bots(&state).is_empty());
+        state.phase = GamePhase::Playing;
+        assert_eq!(racing_bots(&state), ["bot_1", "bot_2"]);
+    }
+}
@@ -0,0 +1,115 @@
+//! Bounded log of recent deltas for cheap resyncs.
+//!
+//! A client that reconnects after a short blip only missed a handful of
+//! deltas. The session keeps the last few broadcast deltas (persisted in DO
+//! storage so they survive hibernation) and replays them as a `Deltas`
+//! message when they cover the gap, falling back to a full snapshot when
+//! they don't.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Ring buffer of `(seq, delta)` pairs for broadcast deltas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeltaLog<D> {
+    capacity: usize,
+    /// Clients at or after this seq can be caught up from `entries`
+    floor: u64,
+    entries: VecDeque<(u64, D)>,
+}
+
+impl<D: Clone> DeltaLog<D> {
+    /// Create an empty log that can serve clients already at `current_seq`
+    pub fn new(capacity: usize, current_seq: u64) -> Self {
+        Self {
+            capacity,
+            floor: current_seq,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Record a delta broadcast to every client at `seq`
+    pub fn push(&mut self, seq: u64, delta: D) {
+        while self.entries.len() >= self.capacity.max(1) {
+            if let Some((evicted, _)) = self.entries.pop_front() {
+                self.floor = evicted;
+            }
+        }
+        self.entries.push_back((seq, delta));
+    }
+
+    /// Mark `seq` as unreplayable (an out-of-band snapshot)
+    ///
+    /// Clients from before `seq` get a snapshot on resync.
+    pub fn invalidate(&mut self, seq: u64) {
+        self.floor = self.floor.max(seq);
+        self.entries.retain(|(s, _)| *s > seq);
+    }
+
+    /// Deltas a client at `last_seq` needs to reach `current_seq`
+    ///
+    /// `None` when the log doesn't cover the gap and a snapshot is needed.
+    /// An empty list means the client is already up to date.
+    pub fn since(&self, last_seq: u64, current_seq: u64) -> Option<Vec<D>> {
+        if last_seq > current_seq || last_seq < self.floor {
+            return None;
+        }
+        Some(
+            self.entries
+                .iter()
+                .filter(|(seq, _)| *seq > last_seq)
+                .map(|(_, delta)| delta.clone())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log_with(seqs: std::ops::RangeInclusive<u64>, capacity: usize) -> DeltaLog<u64> {
+        let mut log = DeltaLog::new(capacity, *seqs.start() - 1);
+        for seq in seqs {
+            log.push(seq, seq);
+        }
+        log
+    }
+
+    #[test]
+    fn test_replays_missed_deltas() {
+        let log = log_with(1..=5, 10);
+        assert_eq!(log.since(2, 5), Some(vec![3, 4, 5]));
+        assert_eq!(log.since(0, 5), Some(vec![1, 2, 3, 4, 5]));
+        assert_eq!(log.since(5, 5), Some(vec![]));
+    }
+
+    #[test]
+    fn test_evicted_gap_needs_snapshot() {
+        let log = log_with(1..=5, 3);
+        assert_eq!(log.since(1, 5), None);
+        assert_eq!(log.since(2, 5), Some(vec![3, 4, 5]));
+    }
+
+    #[test]
+    fn test_client_ahead_of_server_needs_snapshot() {
+        let log = log_with(1..=5, 10);
+        assert_eq!(log.since(9, 5), None);
+    }
+
+    #[test]
+    fn test_new_log_only_serves_current_clients() {
+        let log: DeltaLog<u64> = DeltaLog::new(10, 7);
+        assert_eq!(log.since(3, 7), None);
+        assert_eq!(log.since(7, 7), Some(vec![]));
+    }
+
+    #[test]
+    fn test_invalidate() {
+        let mut log = log_with(1..=3, 10);
+        log.invalidate(4);
+        log.push(5, 5);
+        assert_eq!(log.since(3, 5), None);
+        assert_eq!(log.since(4, 5), Some(vec![5]));
+    }
+}
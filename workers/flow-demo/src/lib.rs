@@ -6,22 +6,57 @@
 //! - WebSocket connections via Durable Objects
 //! - MessagePack binary protocol
 //! - Snapshot + delta state synchronization
+//! - Delta replay on resync (`Resync { last_seq }` or `?last_seq=` on reconnect)
 //! - Presence tracking
 //! - Optimistic UI with action feedback
+//! - Operator commands (reload, resync, maintenance banner) to drain rooms
+//! - Bounded game recordings for post-game replay (`replay`)
+//! - Typed, cached DO storage with batched writes (`storage`)
 //!
 //! ## Endpoints
 //!
 //! - `GET /ws/:room_id` - Counter/chat demo WebSocket
 //! - `GET /memory/:room_id` - Memory game WebSocket
+//!
+//! Both accept `?last_seq=N` to receive missed deltas instead of a snapshot.
+//!
+//! - `GET /memory/:room_id/replay/:game_id` - JSON recording of one of the
+//!   room's last few finished games (the `game_id` comes with `GameEnded`)
+//!
+//! - `POST /ws/:room_id/command`, `POST /memory/:room_id/command` - Broadcast
+//!   a JSON `ClientCommand` (e.g. `{"kind": "reload", "max_delay_ms": 5000}`)
+//!   to every client in the room. Requires `Authorization: Bearer <token>`
+//!   matching the `FLOW_ADMIN_TOKEN` secret.
+//! - `POST /ws/:room_id/leave?user_id=` - Page-unload beacon carrying an
+//!   encoded `Goodbye`; drops the user from the room right away
+//!
+//! ## Admin API
+//!
+//! Same bearer token. Room IDs are `chat:<room_id>` or `memory:<room_id>`.
+//!
+//! - `GET /admin/rooms` - Rooms connected to in the last week, with live
+//!   connection counts and seq
+//! - `POST /admin/rooms/:id/reset` - Reset a room to its initial state
+//! - `POST /admin/rooms/:id/broadcast` - Send a JSON `ClientCommand` to a room
 
+mod admin;
 pub mod assets;
+mod bots;
+mod delta_log;
+mod liveness;
 mod memory_session;
+mod replay;
+mod room_directory;
 mod session;
+pub mod storage;
 mod types;
 
 pub use memory_session::MemoryGameSessionDO;
+pub use room_directory::RoomDirectoryDO;
 pub use session::FlowDemoSessionDO;
 
+use admin::RoomKind;
+
 use tracing::Level;
 use worker::*;
 
@@ -39,6 +74,17 @@ async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
         .get("/health", |_, _| Response::ok("OK"))
         .get_async("/ws/:room_id", handle_demo_websocket)
         .get_async("/memory/:room_id", handle_memory_websocket)
+        .get_async("/memory/:room_id/replay/:game_id", handle_memory_replay)
+        .post_async("/ws/:room_id/command", |req, ctx| {
+            handle_room_command(req, ctx, RoomKind::Chat)
+        })
+        .post_async("/memory/:room_id/command", |req, ctx| {
+            handle_room_command(req, ctx, RoomKind::Memory)
+        })
+        .post_async("/ws/:room_id/leave", handle_leave_beacon)
+        .get_async("/admin/rooms", admin::list_rooms)
+        .post_async("/admin/rooms/:id/reset", admin::reset_room)
+        .post_async("/admin/rooms/:id/broadcast", admin::broadcast_room)
         .run(req, env)
         .await
 }
@@ -50,6 +96,8 @@ async fn handle_demo_websocket(req: Request, ctx: RouteContext<()>) -> Result<Re
         .map(|s| s.as_str())
         .unwrap_or("default");
 
+    admin::register_room(&ctx.env, RoomKind::Chat, room_id).await;
+
     let namespace = ctx.env.durable_object("FLOW_SESSIONS")?;
     let stub = namespace.id_from_name(room_id)?.get_stub()?;
     stub.fetch_with_request(req).await
@@ -62,7 +110,51 @@ async fn handle_memory_websocket(req: Request, ctx: RouteContext<()>) -> Result<
         .map(|s| s.as_str())
         .unwrap_or("default");
 
+    admin::register_room(&ctx.env, RoomKind::Memory, room_id).await;
+
+    let namespace = ctx.env.durable_object("MEMORY_SESSIONS")?;
+    let stub = namespace.id_from_name(room_id)?.get_stub()?;
+    stub.fetch_with_request(req).await
+}
+
+/// Forward a replay request to the memory room's Durable Object
+async fn handle_memory_replay(req: Request, ctx: RouteContext<()>) -> Result<Response> {
+    let room_id = ctx
+        .param("room_id")
+        .map(|s| s.as_str())
+        .unwrap_or("default");
+
     let namespace = ctx.env.durable_object("MEMORY_SESSIONS")?;
     let stub = namespace.id_from_name(room_id)?.get_stub()?;
     stub.fetch_with_request(req).await
 }
+
+/// Forward a page-unload leave beacon to the demo room's Durable Object
+async fn handle_leave_beacon(req: Request, ctx: RouteContext<()>) -> Result<Response> {
+    let room_id = ctx
+        .param("room_id")
+        .map(|s| s.as_str())
+        .unwrap_or("default");
+
+    let namespace = ctx.env.durable_object("FLOW_SESSIONS")?;
+    let stub = namespace.id_from_name(room_id)?.get_stub()?;
+    stub.fetch_with_request(req).await
+}
+
+/// Forward an operator command to a room's Durable Object
+async fn handle_room_command(
+    req: Request,
+    ctx: RouteContext<()>,
+    kind: RoomKind,
+) -> Result<Response> {
+    if let Some(denied) = admin::check_auth(&req, &ctx.env)? {
+        return Ok(denied);
+    }
+
+    let room_id = ctx
+        .param("room_id")
+        .map(|s| s.as_str())
+        .unwrap_or("default");
+
+    admin::send_command(req, &ctx.env, kind, room_id).await
+}
@@ -0,0 +1,86 @@
+//! Connection liveness for presence.
+//!
+//! A socket whose network died without a close frame stays open on the
+//! Durable Object until the runtime notices, which can take hours. Clients
+//! ping every 30s, so each connection records when it was last heard from
+//! and an alarm-driven sweep marks quiet connections idle and closes the
+//! ones that have gone silent for good.
+
+use ui_flow_protocol::PresenceStatus;
+
+/// Quiet this long (a couple of missed pings) shows as idle
+pub const IDLE_AFTER_MS: u64 = 75_000;
+
+/// Quiet this long and the connection is treated as dead and closed
+pub const ZOMBIE_AFTER_MS: u64 = 120_000;
+
+/// How often the sweep runs while connections are open
+pub const SWEEP_INTERVAL_MS: u64 = 30_000;
+
+/// Minimum gap between activity writes to a socket's attachment
+pub const ACTIVITY_WRITE_MS: u64 = 5_000;
+
+/// How recently a connection was heard from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Liveness {
+    Active,
+    Idle,
+    /// Gone silent; close it and announce the leave
+    Zombie,
+}
+
+impl Liveness {
+    /// Classify a connection last heard from at `last_active`
+    pub fn at(last_active: u64, now: u64) -> Self {
+        let quiet = now.saturating_sub(last_active);
+        if quiet >= ZOMBIE_AFTER_MS {
+            Liveness::Zombie
+        } else if quiet >= IDLE_AFTER_MS {
+            Liveness::Idle
+        } else {
+            Liveness::Active
+        }
+    }
+
+    /// Status to report, or `None` if the connection shouldn't be listed
+    pub fn presence(self) -> Option<PresenceStatus> {
+        match self {
+            Liveness::Active => Some(PresenceStatus::Active),
+            Liveness::Idle => Some(PresenceStatus::Idle),
+            Liveness::Zombie => None,
+        }
+    }
+}
+
+/// Whether activity at `now` is worth persisting over `last_seen`
+pub fn should_record(last_seen: u64, now: u64) -> bool {
+    now.saturating_sub(last_seen) >= ACTIVITY_WRITE_MS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_liveness_thresholds() {
+        assert_eq!(Liveness::at(1_000, 1_000), Liveness::Active);
+        assert_eq!(Liveness::at(0, IDLE_AFTER_MS - 1), Liveness::Active);
+        assert_eq!(Liveness::at(0, IDLE_AFTER_MS), Liveness::Idle);
+        assert_eq!(Liveness::at(0, ZOMBIE_AFTER_MS), Liveness::Zombie);
+        // Clock skew never makes a connection look older
+        assert_eq!(Liveness::at(5_000, 1_000), Liveness::Active);
+    }
+
+    #[test]
+    fn test_presence_status() {
+        assert_eq!(Liveness::Active.presence(), Some(PresenceStatus::Active));
+        assert_eq!(Liveness::Idle.presence(), Some(PresenceStatus::Idle));
+        assert_eq!(Liveness::Zombie.presence(), None);
+    }
+
+    #[test]
+    fn test_activity_writes_are_throttled() {
+        assert!(!should_record(10_000, 10_000 + ACTIVITY_WRITE_MS - 1));
+        assert!(should_record(10_000, 10_000 + ACTIVITY_WRITE_MS));
+    }
+}
@@ -1,9 +1,15 @@
 //! Durable Object for managing Memory Game sessions.
 //!
 //! This implements the server-side game logic for the Black Flag memory game,
-//! supporting both turn-taking and race modes with 2-8 players.
+//! supporting both turn-taking and race modes with 2-8 players. Empty seats
+//! are filled by bots (see [`crate::bots`]), which move on the DO alarm.
+//! Each game is recorded for replay (see [`crate::replay`]).
 
 use crate::assets::{fetch_game_cards, AssetId};
+use crate::bots;
+use crate::delta_log::DeltaLog;
+use crate::replay::{self, GameReplay, Recording, ReplayFrame, ReplayIndex};
+use crate::storage::{SeqCounter, StorageBatch, TypedStorage};
 use crate::types::*;
 
 /// Helper trait for looking up cards by CardId
@@ -22,13 +28,24 @@ impl CardLookup for Vec<Card> {
     }
 }
 use serde::{Deserialize, Serialize};
-use std::cell::RefCell;
-use ui_flow_protocol::{encode, OpId, PresenceInfo, PresenceStatus, ServerMessage};
+use ui_flow_protocol::{
+    encode, Audience, ClientCommand, OpId, PresenceInfo, PresenceStatus, ServerMessage,
+};
 use worker::*;
 
-/// Storage keys for persisted state
-const STORAGE_KEY_GAME: &str = "game_state";
-const STORAGE_KEY_SEQ: &str = "game_seq";
+/// Storage keys for persisted state, all under `game_`
+const STORAGE_PREFIX: &str = "game_";
+const STORAGE_KEY_GAME: &str = "state";
+const STORAGE_KEY_SEQ: &str = "seq";
+const STORAGE_KEY_DELTA_LOG: &str = "delta_log";
+const STORAGE_KEY_RECORDING: &str = "recording";
+const STORAGE_KEY_REPLAYS: &str = "replays";
+
+/// Recent deltas kept for resyncing reconnecting players
+const DELTA_LOG_CAPACITY: usize = 128;
+
+type MemoryRecording = Recording<MemoryGameState, MemoryDelta>;
+type MemoryReplay = GameReplay<MemoryGameState, MemoryDelta>;
 
 /// Connection information stored as WebSocket attachment
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,19 +61,36 @@ pub struct MemoryGameSessionDO {
     state: State,
     #[allow(dead_code)]
     env: Env,
-    /// Current game state (cached from storage)
-    game_state: RefCell<Option<MemoryGameState>>,
-    /// Current sequence number (cached from storage)
-    seq: RefCell<Option<u64>>,
+    /// Current game state
+    game_state: TypedStorage<MemoryGameState>,
+    /// Current sequence number
+    seq: SeqCounter,
+    /// Recent broadcast deltas
+    delta_log: TypedStorage<DeltaLog<MemoryDelta>>,
+    /// Game being recorded, if any
+    recording: TypedStorage<Option<MemoryRecording>>,
+    /// Finished games with a stored replay
+    replays: TypedStorage<ReplayIndex>,
 }
 
 impl DurableObject for MemoryGameSessionDO {
     fn new(state: State, env: Env) -> Self {
         Self {
+            game_state: TypedStorage::prefixed(state.storage(), STORAGE_PREFIX, STORAGE_KEY_GAME),
+            seq: SeqCounter::prefixed(state.storage(), STORAGE_PREFIX, STORAGE_KEY_SEQ),
+            delta_log: TypedStorage::prefixed(
+                state.storage(),
+                STORAGE_PREFIX,
+                STORAGE_KEY_DELTA_LOG,
+            ),
+            recording: TypedStorage::prefixed(
+                state.storage(),
+                STORAGE_PREFIX,
+                STORAGE_KEY_RECORDING,
+            ),
+            replays: TypedStorage::prefixed(state.storage(), STORAGE_PREFIX, STORAGE_KEY_REPLAYS),
             state,
             env,
-            game_state: RefCell::new(None),
-            seq: RefCell::new(None),
         }
     }
 
@@ -64,6 +98,19 @@ impl DurableObject for MemoryGameSessionDO {
         if req.headers().get("Upgrade")?.as_deref() == Some("websocket") {
             return self.handle_websocket_upgrade(req).await;
         }
+        if admin::is_internal(&req, Method::Post, admin::ROOM_COMMAND_URL) {
+            return self.handle_command(req).await;
+        }
+        if req.method() == Method::Get && req.path().contains("/replay/") {
+            return self.handle_replay_request(&req.path()).await;
+        }
+        if admin::is_internal(&req, Method::Get, admin::ROOM_STATS_URL) {
+            return Response::from_json(&self.room_stats().await);
+        }
+        if admin::is_internal(&req, Method::Post, admin::ROOM_RESET_URL) {
+            self.reset_game().await?;
+            return Response::ok("Game reset");
+        }
         Response::error("Expected WebSocket upgrade", 400)
     }
 
@@ -77,7 +124,12 @@ impl DurableObject for MemoryGameSessionDO {
             WebSocketIncomingMessage::String(text) => text.into_bytes(),
         };
 
-        let client_msg: MemoryClientMsg = match ui_flow_protocol::decode(&bytes) {
+        // Decode and validate the client message (size and field caps keep
+        // oversized payloads out of storage)
+        let client_msg: MemoryClientMsg = match ui_flow_protocol::decode_validated(
+            &bytes,
+            ui_flow_protocol::DEFAULT_MAX_CLIENT_MESSAGE_BYTES,
+        ) {
             Ok(msg) => msg,
             Err(e) => {
                 let error_msg: MemoryServerMsg =
@@ -113,8 +165,16 @@ impl DurableObject for MemoryGameSessionDO {
         Ok(())
     }
 
+    /// The one alarm drives both the flip-back timer and bot moves. The flip
+    /// timer is only pending in `BothReady`, when no bot can move.
     async fn alarm(&self) -> Result<Response> {
-        self.handle_flip_timer_expired().await?;
+        let state = self.get_game_state().await;
+        if matches!(state.turn_state, TurnState::BothReady { .. }) {
+            self.handle_flip_timer_expired().await?;
+        } else {
+            self.run_bots().await?;
+        }
+        self.schedule_bots(&self.get_game_state().await).await;
         Response::ok("OK")
     }
 }
@@ -125,57 +185,90 @@ impl MemoryGameSessionDO {
     // =========================================================================
 
     async fn get_game_state(&self) -> MemoryGameState {
-        if let Some(state) = self.game_state.borrow().clone() {
-            return state;
-        }
-
-        let state: MemoryGameState = self
-            .state
-            .storage()
-            .get(STORAGE_KEY_GAME)
-            .await
-            .ok()
-            .unwrap_or_default();
-
-        *self.game_state.borrow_mut() = Some(state.clone());
-        state
+        self.game_state.get_or_default().await
     }
 
     async fn save_game_state(&self, state: &MemoryGameState) {
-        *self.game_state.borrow_mut() = Some(state.clone());
-        let _ = self.state.storage().put(STORAGE_KEY_GAME, state).await;
+        if let Err(e) = self.game_state.put(state).await {
+            tracing::error!("Failed to save game state: {e}");
+        }
     }
 
     async fn get_seq(&self) -> u64 {
-        if let Some(seq) = *self.seq.borrow() {
-            return seq;
-        }
+        self.seq.current().await
+    }
 
-        let seq: u64 = self
-            .state
-            .storage()
-            .get(STORAGE_KEY_SEQ)
-            .await
-            .ok()
-            .unwrap_or(0);
+    async fn get_delta_log(&self) -> DeltaLog<MemoryDelta> {
+        match self.delta_log.get().await {
+            Ok(Some(log)) => log,
+            Ok(None) => DeltaLog::new(DELTA_LOG_CAPACITY, self.get_seq().await),
+            Err(e) => {
+                tracing::warn!("Discarding unreadable delta log: {e}");
+                DeltaLog::new(DELTA_LOG_CAPACITY, self.get_seq().await)
+            }
+        }
+    }
 
-        *self.seq.borrow_mut() = Some(seq);
+    /// Increment the seq and record it in the delta log, saving both in one
+    /// write
+    async fn advance_seq(&self, record: impl FnOnce(&mut DeltaLog<MemoryDelta>, u64)) -> u64 {
+        let mut log = self.get_delta_log().await;
+        let mut batch = StorageBatch::new();
+        let seq = self.seq.next_in(&mut batch).await;
+        record(&mut log, seq);
+        self.delta_log.put_in(&mut batch, &log);
+        if let Err(e) = batch.commit(&self.state.storage()).await {
+            tracing::error!("Failed to save seq {seq}: {e}");
+        }
         seq
     }
 
-    async fn next_seq(&self) -> u64 {
-        let seq = self.get_seq().await + 1;
-        *self.seq.borrow_mut() = Some(seq);
-        let _ = self.state.storage().put(STORAGE_KEY_SEQ, seq).await;
-        seq
+    /// Bump the seq for a state change other clients can't replay (an
+    /// out-of-band snapshot)
+    async fn next_unreplayable_seq(&self) -> u64 {
+        self.advance_seq(|log, seq| log.invalidate(seq)).await
+    }
+
+    /// Catch a player at `last_seq` up: replayed deltas if the log covers the
+    /// gap, otherwise a full snapshot
+    async fn resync_message(&self, last_seq: Option<u64>) -> MemoryServerMsg {
+        let seq = self.get_seq().await;
+
+        if let Some(last_seq) = last_seq {
+            if let Some(deltas) = self.get_delta_log().await.since(last_seq, seq) {
+                tracing::debug!("Resync from seq {} with {} deltas", last_seq, deltas.len());
+                return ServerMessage::deltas(deltas, seq, now());
+            }
+        }
+
+        let game_state = self.get_game_state().await;
+        tracing::info!(
+            "Sending snapshot: phase={:?}, cards={}, seq={}",
+            match &game_state.phase {
+                GamePhase::Lobby { .. } => "Lobby",
+                GamePhase::Starting { .. } => "Starting",
+                GamePhase::Loading { .. } => "Loading",
+                GamePhase::Playing => "Playing",
+                GamePhase::Finished { .. } => "Finished",
+            },
+            game_state.cards.len(),
+            seq
+        );
+        ServerMessage::snapshot(game_state, seq, now())
     }
 
     // =========================================================================
     // WebSocket handling
     // =========================================================================
 
+    /// Reconnecting players can pass `last_seq` to receive the deltas they
+    /// missed instead of a full snapshot.
     async fn handle_websocket_upgrade(&self, req: Request) -> Result<Response> {
         let url = req.url()?;
+        let last_seq = url
+            .query_pairs()
+            .find(|(k, _)| k == "last_seq")
+            .and_then(|(_, v)| v.parse::<u64>().ok());
         let user_id = url
             .query_pairs()
             .find(|(k, _)| k == "user_id")
@@ -212,24 +305,10 @@ impl MemoryGameSessionDO {
             let _ = server.send_with_bytes(&bytes);
         }
 
-        // Send current state snapshot
-        let game_state = self.get_game_state().await;
-        let seq = self.get_seq().await;
-        tracing::info!(
-            "Sending initial snapshot: phase={:?}, cards={}, seq={}",
-            match &game_state.phase {
-                GamePhase::Lobby { .. } => "Lobby",
-                GamePhase::Starting { .. } => "Starting",
-                GamePhase::Loading { .. } => "Loading",
-                GamePhase::Playing => "Playing",
-                GamePhase::Finished { .. } => "Finished",
-            },
-            game_state.cards.len(),
-            seq
-        );
-        let snapshot_msg: MemoryServerMsg = ServerMessage::snapshot(game_state, seq, now());
-        if let Ok(bytes) = encode(&snapshot_msg) {
-            tracing::debug!("Sending Snapshot message ({} bytes)", bytes.len());
+        // Send current state (missed deltas or a snapshot)
+        let resync_msg = self.resync_message(last_seq).await;
+        if let Ok(bytes) = encode(&resync_msg) {
+            tracing::debug!("Sending initial state ({} bytes)", bytes.len());
             let _ = server.send_with_bytes(&bytes);
         }
 
@@ -255,11 +334,9 @@ impl MemoryGameSessionDO {
                 }
             }
 
-            ClientMessage::Resync { last_seq: _ } => {
-                let game_state = self.get_game_state().await;
-                let seq = self.get_seq().await;
-                let snapshot_msg: MemoryServerMsg = ServerMessage::snapshot(game_state, seq, now());
-                if let Ok(bytes) = encode(&snapshot_msg) {
+            ClientMessage::Resync { last_seq } => {
+                let resync_msg = self.resync_message(last_seq).await;
+                if let Ok(bytes) = encode(&resync_msg) {
                     let _ = ws.send_with_bytes(&bytes);
                 }
             }
@@ -268,6 +345,13 @@ impl MemoryGameSessionDO {
                 self.handle_action(ws, conn, op_id, action).await?;
             }
 
+            ClientMessage::Goodbye { reason } => {
+                // Free the seat now rather than when the socket times out
+                tracing::debug!("{} said goodbye ({reason})", conn.user_id);
+                self.handle_player_disconnect(&conn.user_id).await?;
+                let _ = ws.close(Some(1000), Some("goodbye"));
+            }
+
             _ => {}
         }
 
@@ -292,8 +376,12 @@ impl MemoryGameSessionDO {
             MemoryAction::LeaveGame => {
                 self.handle_leave_game(ws, conn, op_id).await?;
             }
-            MemoryAction::SetConfig { mode, grid_size } => {
-                self.handle_set_config(ws, conn, op_id, mode, grid_size)
+            MemoryAction::SetConfig {
+                mode,
+                grid_size,
+                bots: bot_config,
+            } => {
+                self.handle_set_config(ws, conn, op_id, mode, grid_size, bot_config)
                     .await?;
             }
             MemoryAction::StartGame => {
@@ -312,9 +400,6 @@ impl MemoryGameSessionDO {
             MemoryAction::RequestRematch => {
                 self.handle_request_rematch(ws, conn, op_id).await?;
             }
-            MemoryAction::ResetGame => {
-                self.handle_reset_game(ws, op_id).await?;
-            }
         }
         Ok(())
     }
@@ -346,6 +431,7 @@ impl MemoryGameSessionDO {
                 flipped: vec![],
                 spectating,
                 joined_at: now(),
+                bot: false,
             };
             state.players.insert(conn.user_id.clone(), player);
 
@@ -355,6 +441,13 @@ impl MemoryGameSessionDO {
             }
         }
 
+        // A new player takes a bot's seat
+        let bot_changes = if spectating {
+            bots::BotChanges::default()
+        } else {
+            bots::sync_bots(&mut state, now())
+        };
+
         self.save_game_state(&state).await;
 
         // Broadcast join
@@ -362,8 +455,10 @@ impl MemoryGameSessionDO {
             user_id: conn.user_id.clone(),
             user_name,
             spectating,
+            bot: false,
         };
         self.broadcast_delta(delta).await;
+        self.broadcast_bot_changes(bot_changes).await;
 
         // Send success
         self.send_action_ok(ws, op_id).await;
@@ -387,9 +482,22 @@ impl MemoryGameSessionDO {
         let mut state = self.get_game_state().await;
 
         if state.players.remove(user_id).is_some() {
-            // If host left, assign new host
+            // Bots don't play on in an empty room; in the lobby they fill
+            // the seat that was freed
+            let mut bot_changes = bots::BotChanges::default();
+            if !bots::has_humans(&state) {
+                bot_changes.left = bots::remove_bots(&mut state);
+            } else if matches!(state.phase, GamePhase::Lobby { .. }) {
+                bot_changes = bots::sync_bots(&mut state, now());
+            }
+
+            // If host left, assign new host (bots never host)
             if state.host.as_deref() == Some(user_id) {
-                state.host = state.players.keys().next().cloned();
+                state.host = state
+                    .players
+                    .values()
+                    .find(|p| !p.bot)
+                    .map(|p| p.user_id.clone());
                 if let Some(new_host) = &state.host {
                     let delta = MemoryDelta::HostChanged {
                         user_id: new_host.clone(),
@@ -412,6 +520,7 @@ impl MemoryGameSessionDO {
                 user_id: user_id.to_string(),
             };
             self.broadcast_delta(delta).await;
+            self.broadcast_bot_changes(bot_changes).await;
             self.broadcast_presence().await;
         }
 
@@ -425,6 +534,7 @@ impl MemoryGameSessionDO {
         op_id: OpId,
         mode: Option<GameMode>,
         grid_size: Option<(u8, u8)>,
+        bot_config: Option<BotConfig>,
     ) -> Result<()> {
         let mut state = self.get_game_state().await;
 
@@ -441,6 +551,13 @@ impl MemoryGameSessionDO {
         if let Some(gs) = grid_size {
             state.config.grid_size = gs;
         }
+        let bot_changes = match bot_config {
+            Some(config) => {
+                state.config.bots = config;
+                bots::sync_bots(&mut state, now())
+            }
+            None => bots::BotChanges::default(),
+        };
 
         self.save_game_state(&state).await;
 
@@ -448,6 +565,7 @@ impl MemoryGameSessionDO {
             config: state.config.clone(),
         };
         self.broadcast_delta(delta).await;
+        self.broadcast_bot_changes(bot_changes).await;
         self.send_action_ok(ws, op_id).await;
 
         Ok(())
@@ -521,14 +639,24 @@ impl MemoryGameSessionDO {
             player.score = 0;
             player.flipped.clear();
         }
+        state.bot_memory.clear();
 
-        // Enter Loading phase - wait for players to signal ready
+        // Enter Loading phase - wait for players to signal ready (bots have
+        // nothing to load)
+        let ready_players = state
+            .players
+            .values()
+            .filter(|p| p.bot && !p.spectating)
+            .map(|p| p.user_id.clone())
+            .collect();
         state.phase = GamePhase::Loading {
-            ready_players: Vec::new(),
+            ready_players,
             total_players,
         };
+        state.game_id = now();
 
         self.save_game_state(&state).await;
+        self.start_recording(&state).await;
 
         // Broadcast cards dealt (hidden card structure - no face data revealed)
         let hidden_cards: Vec<HiddenCard> = state.cards.iter().map(HiddenCard::from).collect();
@@ -614,6 +742,7 @@ impl MemoryGameSessionDO {
                 turn_order: state.turn_order.clone(),
             };
             self.broadcast_delta(started_delta).await;
+            self.schedule_bots(&state).await;
         } else {
             self.save_game_state(&state).await;
         }
@@ -655,75 +784,72 @@ impl MemoryGameSessionDO {
             return Ok(());
         }
 
-        match state.config.mode {
-            GameMode::TurnTaking => {
-                self.handle_flip_turn_taking(ws, conn, op_id, card_id, &mut state)
-                    .await?;
-            }
-            GameMode::Race => {
-                self.handle_flip_race(ws, conn, op_id, card_id, &mut state)
-                    .await?;
+        match self.flip(&conn.user_id, card_id, &mut state).await {
+            Ok(()) => {
+                self.save_game_state(&state).await;
+                self.send_action_ok(ws, op_id).await;
             }
+            Err(reason) => self.send_action_error(ws, op_id, reason).await,
         }
 
         Ok(())
     }
 
-    async fn handle_flip_turn_taking(
+    /// Flip `card_id` for `user_id` (people and bots)
+    ///
+    /// Returns the reason the flip was refused. The caller saves the state.
+    async fn flip(
         &self,
-        ws: &WebSocket,
-        conn: &ConnectionInfo,
-        op_id: OpId,
+        user_id: &str,
         card_id: CardId,
         state: &mut MemoryGameState,
-    ) -> Result<()> {
+    ) -> std::result::Result<(), &'static str> {
+        match state.config.mode {
+            GameMode::TurnTaking => self.flip_turn_taking(user_id, card_id, state).await,
+            GameMode::Race => self.flip_race(user_id, card_id, state).await,
+        }
+    }
+
+    /// Flip `card_id` for `user_id` in turn-taking mode
+    async fn flip_turn_taking(
+        &self,
+        user_id: &str,
+        card_id: CardId,
+        state: &mut MemoryGameState,
+    ) -> std::result::Result<(), &'static str> {
         // Must be player's turn
         let current_player = state.turn_order.get(state.current_turn);
-        if current_player != Some(&conn.user_id) {
-            self.send_action_error(ws, op_id, "Not your turn").await;
-            return Ok(());
+        if current_player.map(String::as_str) != Some(user_id) {
+            return Err("Not your turn");
         }
 
         // Use FSM to validate and transition
-        let new_turn_state = match state.turn_state.on_flip(card_id.clone()) {
-            Some(s) => s,
-            None => {
-                self.send_action_error(ws, op_id, "Invalid flip action")
-                    .await;
-                return Ok(());
-            }
-        };
+        let new_turn_state = state
+            .turn_state
+            .on_flip(card_id.clone())
+            .ok_or("Invalid flip action")?;
 
         // Card must not already be matched
-        let card = match state.cards.find_card(&card_id) {
-            Some(c) => c,
-            None => {
-                self.send_action_error(ws, op_id, "Card not found").await;
-                return Ok(());
-            }
-        };
-
+        let card = state.cards.find_card(&card_id).ok_or("Card not found")?;
         if card.matched {
-            self.send_action_error(ws, op_id, "Card already matched")
-                .await;
-            return Ok(());
+            return Err("Card already matched");
         }
 
         let face = CardFace::from(card);
 
         state.turn_state = new_turn_state;
 
+        // Everyone sees the card, bots included
+        bots::remember_all(state, &card_id);
+
         // Broadcast the flip
         let delta = MemoryDelta::CardFlipped {
             card_id,
-            by: conn.user_id.clone(),
+            by: user_id.to_string(),
             face,
         };
         self.broadcast_delta(delta).await;
 
-        self.save_game_state(state).await;
-        self.send_action_ok(ws, op_id).await;
-
         Ok(())
     }
 
@@ -798,6 +924,8 @@ impl MemoryGameSessionDO {
                     let current_player_id = state.turn_order.get(state.current_turn).cloned();
                     self.resolve_match(&mut state, &first, &second, current_player_id.as_deref())
                         .await;
+                    // Another go - for a bot, that means another move
+                    self.schedule_bots(&state).await;
                 } else {
                     // Schedule the flip-back after delay using Duration
                     let delay_ms = state.config.flip_delay_ms;
@@ -925,61 +1053,50 @@ impl MemoryGameSessionDO {
         }
     }
 
-    async fn handle_flip_race(
+    /// Flip `card_id` for `user_id` in race mode
+    async fn flip_race(
         &self,
-        ws: &WebSocket,
-        conn: &ConnectionInfo,
-        op_id: OpId,
+        user_id: &str,
         card_id: CardId,
         state: &mut MemoryGameState,
-    ) -> Result<()> {
+    ) -> std::result::Result<(), &'static str> {
         // Get player's flipped cards
-        let player = match state.players.get_mut(&conn.user_id) {
+        let player = match state.players.get_mut(user_id) {
             Some(p) if !p.spectating => p,
-            _ => {
-                self.send_action_error(ws, op_id, "You are not a player")
-                    .await;
-                return Ok(());
-            }
+            _ => return Err("You are not a player"),
         };
 
         // Can't flip same card twice
         if player.flipped.contains(&card_id) {
-            self.send_action_error(ws, op_id, "Card already flipped")
-                .await;
-            return Ok(());
+            return Err("Card already flipped");
         }
 
         // Can only have 2 cards flipped
         if player.flipped.len() >= 2 {
-            self.send_action_error(ws, op_id, "Two cards already flipped")
-                .await;
-            return Ok(());
+            return Err("Two cards already flipped");
         }
+        let is_bot = player.bot;
 
         // Get card data before mutating player state
-        let card = match state.cards.find_card(&card_id) {
-            Some(c) => c,
-            None => {
-                self.send_action_error(ws, op_id, "Card not found").await;
-                return Ok(());
-            }
-        };
+        let card = state.cards.find_card(&card_id).ok_or("Card not found")?;
         let face = CardFace::from(card);
 
         // Flip the card (only this player sees it initially)
-        let player = state.players.get_mut(&conn.user_id).unwrap();
+        let player = state.players.get_mut(user_id).unwrap();
         player.flipped.push(card_id.clone());
+        if is_bot {
+            bots::remember(state, user_id, &card_id);
+        }
 
         // Send only to this player
         let delta = MemoryDelta::OwnCardFlipped {
             card_id: card_id.clone(),
             face: face.clone(),
         };
-        self.send_delta_to(ws, delta).await;
+        self.send_private_delta(user_id, delta).await;
 
         // Check if player has two cards flipped
-        let player = state.players.get(&conn.user_id).unwrap();
+        let player = state.players.get(user_id).unwrap();
         if player.flipped.len() == 2 {
             let id1 = player.flipped[0].clone();
             let id2 = player.flipped[1].clone();
@@ -1002,26 +1119,26 @@ impl MemoryGameSessionDO {
                 // Check if cards are still available (race condition)
                 if card1_matched || card2_matched {
                     // Someone else got it first
-                    let player = state.players.get_mut(&conn.user_id).unwrap();
+                    let player = state.players.get_mut(user_id).unwrap();
                     player.flipped.clear();
 
                     let delta = MemoryDelta::CardsReset {
                         card_ids: [id1, id2],
-                        for_player: Some(conn.user_id.clone()),
+                        for_player: Some(user_id.to_string()),
                     };
-                    self.send_delta_to(ws, delta).await;
+                    self.send_private_delta(user_id, delta).await;
                 } else {
                     // Claim the match!
                     if let Some(card) = state.cards.find_card_mut(&id1) {
                         card.matched = true;
-                        card.matched_by = Some(conn.user_id.clone());
+                        card.matched_by = Some(user_id.to_string());
                     }
                     if let Some(card) = state.cards.find_card_mut(&id2) {
                         card.matched = true;
-                        card.matched_by = Some(conn.user_id.clone());
+                        card.matched_by = Some(user_id.to_string());
                     }
 
-                    let player = state.players.get_mut(&conn.user_id).unwrap();
+                    let player = state.players.get_mut(user_id).unwrap();
                     player.score += 1;
                     let new_score = player.score;
                     let user_name = player.user_name.clone();
@@ -1030,7 +1147,7 @@ impl MemoryGameSessionDO {
                     // Broadcast match to all players
                     let delta = MemoryDelta::PairMatched {
                         card_ids: [id1, id2],
-                        by: conn.user_id.clone(),
+                        by: user_id.to_string(),
                         by_name: user_name.clone(),
                         new_score,
                         face,
@@ -1050,20 +1167,17 @@ impl MemoryGameSessionDO {
                 }
             } else {
                 // No match - reset for this player only
-                let player = state.players.get_mut(&conn.user_id).unwrap();
+                let player = state.players.get_mut(user_id).unwrap();
                 player.flipped.clear();
 
                 let delta = MemoryDelta::CardsReset {
                     card_ids: [id1, id2],
-                    for_player: Some(conn.user_id.clone()),
+                    for_player: Some(user_id.to_string()),
                 };
-                self.send_delta_to(ws, delta).await;
+                self.send_private_delta(user_id, delta).await;
             }
         }
 
-        self.save_game_state(state).await;
-        self.send_action_ok(ws, op_id).await;
-
         Ok(())
     }
 
@@ -1085,25 +1199,35 @@ impl MemoryGameSessionDO {
             rankings: rankings.clone(),
         };
 
-        let delta = MemoryDelta::GameEnded { winner, rankings };
+        let delta = MemoryDelta::GameEnded {
+            winner,
+            rankings,
+            game_id: state.game_id,
+        };
         self.broadcast_delta(delta).await;
+        self.finish_recording().await;
     }
 
-    async fn handle_reset_game(&self, ws: &WebSocket, op_id: OpId) -> Result<()> {
+    /// Reset the room to a fresh game (admin API only)
+    async fn reset_game(&self) -> Result<()> {
         // Delete all storage and reset to fresh default state
         self.state.storage().delete_all().await?;
 
         let state = MemoryGameState::default();
-        self.save_game_state(&state).await;
-
-        // Send full snapshot to resync everyone
         let seq = 0u64;
-        self.state
-            .storage()
-            .put("seq", seq)
+        let mut batch = StorageBatch::new();
+        self.game_state.put_in(&mut batch, &state);
+        self.seq.set_in(&mut batch, seq);
+        self.delta_log
+            .put_in(&mut batch, &DeltaLog::new(DELTA_LOG_CAPACITY, seq));
+        self.recording.put_in(&mut batch, &None);
+        self.replays.put_in(&mut batch, &ReplayIndex::default());
+        batch
+            .commit(&self.state.storage())
             .await
-            .map_err(|e| Error::from(format!("Failed to save seq: {e}")))?;
+            .map_err(|e| Error::from(format!("Failed to save reset game: {e}")))?;
 
+        // Send full snapshot to resync everyone
         let snapshot_msg: MemoryServerMsg = ServerMessage::snapshot(state, seq, now());
         if let Ok(bytes) = encode(&snapshot_msg) {
             for ws in self.state.get_websockets() {
@@ -1111,7 +1235,6 @@ impl MemoryGameSessionDO {
             }
         }
 
-        self.send_action_ok(ws, op_id).await;
         tracing::info!("Game state reset by admin");
 
         Ok(())
@@ -1148,6 +1271,7 @@ impl MemoryGameSessionDO {
             player.flipped.clear();
             player.spectating = false;
         }
+        bots::sync_bots(&mut state, now());
 
         // Requester becomes host
         state.host = Some(conn.user_id.clone());
@@ -1155,7 +1279,7 @@ impl MemoryGameSessionDO {
         self.save_game_state(&state).await;
 
         // Send full snapshot to resync everyone
-        let seq = self.get_seq().await;
+        let seq = self.next_unreplayable_seq().await;
         let snapshot_msg: MemoryServerMsg = ServerMessage::snapshot(state, seq, now());
         if let Ok(bytes) = encode(&snapshot_msg) {
             for ws in self.state.get_websockets() {
@@ -1169,36 +1293,294 @@ impl MemoryGameSessionDO {
     }
 
     // =========================================================================
-    // Broadcast helpers
+    // Replays
     // =========================================================================
 
-    async fn broadcast_delta(&self, delta: MemoryDelta) {
-        let seq = self.next_seq().await;
-        let msg: MemoryServerMsg = ServerMessage::delta(delta, seq, now());
+    /// Start recording the game just dealt, from its state before the deal
+    ///
+    /// Drops the chunks of a recording whose game never finished.
+    async fn start_recording(&self, state: &MemoryGameState) {
+        if let Some(abandoned) = self.recording.get_or_default().await {
+            self.delete_chunks(abandoned.game_id, abandoned.chunks)
+                .await;
+        }
 
-        if let Ok(bytes) = encode(&msg) {
-            for ws in self.state.get_websockets() {
+        // Faces come with the deltas; bot memory is server-only
+        let mut initial = state.clone();
+        initial.cards.clear();
+        initial.bot_memory.clear();
+        let recording = Recording::new(state.game_id, initial, now());
+        if let Err(e) = self.recording.put(&Some(recording)).await {
+            tracing::error!("Failed to start recording game {}: {e}", state.game_id);
+        }
+    }
+
+    /// Add a broadcast delta to the recording, if a game is being recorded
+    async fn record_delta(&self, delta: &MemoryDelta) {
+        let Some(mut recording) = self.recording.get_or_default().await else {
+            return;
+        };
+        let sealed = recording.push(delta.clone(), now());
+
+        let chunk = sealed.as_ref().map(|(index, _)| {
+            TypedStorage::<Vec<ReplayFrame<MemoryDelta>>>::prefixed(
+                self.state.storage(),
+                STORAGE_PREFIX,
+                &replay::chunk_key(recording.game_id, *index),
+            )
+        });
+        let mut batch = StorageBatch::new();
+        if let (Some(chunk), Some((_, frames))) = (&chunk, &sealed) {
+            chunk.put_in(&mut batch, frames);
+        }
+        self.recording.put_in(&mut batch, &Some(recording));
+        if let Err(e) = batch.commit(&self.state.storage()).await {
+            tracing::error!("Failed to record delta: {e}");
+        }
+    }
+
+    /// Keep the finished game's recording and drop the oldest beyond
+    /// [`replay::MAX_STORED`]
+    async fn finish_recording(&self) {
+        let Some(mut recording) = self.recording.get_or_default().await else {
+            return;
+        };
+        recording.finish(now());
+        let game_id = recording.game_id;
+
+        let mut index = self.replays.get_or_default().await;
+        let evicted = index.add(game_id);
+
+        let stored = self.stored_recording(game_id);
+        let mut batch = StorageBatch::new();
+        stored.put_in(&mut batch, &recording);
+        self.replays.put_in(&mut batch, &index);
+        self.recording.put_in(&mut batch, &None);
+        if let Err(e) = batch.commit(&self.state.storage()).await {
+            tracing::error!("Failed to save replay of game {game_id}: {e}");
+            return;
+        }
+        tracing::info!(
+            "Recorded game {game_id}: {} frames",
+            recording.frame_count()
+        );
+
+        for old_id in evicted {
+            let old = self.stored_recording(old_id);
+            if let Ok(Some(old_recording)) = old.get().await {
+                self.delete_chunks(old_id, old_recording.chunks).await;
+            }
+            if let Err(e) = self.state.storage().delete(old.key()).await {
+                tracing::warn!("Failed to delete replay of game {old_id}: {e}");
+            }
+        }
+    }
+
+    fn stored_recording(&self, game_id: u64) -> TypedStorage<MemoryRecording> {
+        TypedStorage::prefixed(
+            self.state.storage(),
+            STORAGE_PREFIX,
+            &replay::recording_key(game_id),
+        )
+    }
+
+    async fn delete_chunks(&self, game_id: u64, chunks: usize) {
+        if chunks == 0 {
+            return;
+        }
+        let keys: Vec<String> = (0..chunks)
+            .map(|index| format!("{STORAGE_PREFIX}{}", replay::chunk_key(game_id, index)))
+            .collect();
+        if let Err(e) = self.state.storage().delete_multiple(keys).await {
+            tracing::warn!("Failed to delete replay chunks of game {game_id}: {e}");
+        }
+    }
+
+    /// A finished game's full replay, `None` if it isn't kept
+    async fn load_replay(&self, game_id: u64) -> Result<Option<MemoryReplay>> {
+        if !self.replays.get_or_default().await.contains(game_id) {
+            return Ok(None);
+        }
+        let Some(recording) = self.stored_recording(game_id).get().await? else {
+            return Ok(None);
+        };
+
+        let mut chunks = Vec::with_capacity(recording.chunks);
+        for index in 0..recording.chunks {
+            let chunk = TypedStorage::<Vec<ReplayFrame<MemoryDelta>>>::prefixed(
+                self.state.storage(),
+                STORAGE_PREFIX,
+                &replay::chunk_key(game_id, index),
+            );
+            chunks.push(chunk.get().await?.unwrap_or_default());
+        }
+        Ok(Some(recording.into_replay(chunks)))
+    }
+
+    /// `GET /memory/:room_id/replay/:game_id`
+    async fn handle_replay_request(&self, path: &str) -> Result<Response> {
+        let Some(game_id) = path
+            .rsplit('/')
+            .next()
+            .and_then(|id| id.parse::<u64>().ok())
+        else {
+            return Response::error("Invalid game ID", 400);
+        };
+        match self.load_replay(game_id).await? {
+            Some(replay) => Response::from_json(&replay),
+            None => Response::error("Replay not found", 404),
+        }
+    }
+
+    // =========================================================================
+    // Bots
+    // =========================================================================
+
+    /// Make one bot move: the current turn-taker's next flip, or one flip
+    /// for each bot in a race
+    async fn run_bots(&self) -> Result<()> {
+        let mut state = self.get_game_state().await;
+        if !bots::bots_pending(&state) {
+            return Ok(());
+        }
+
+        let mut rng = bot_rng();
+        let recall = state.config.bots.difficulty.recall();
+        let mut moves = Vec::new();
+        if let Some((bot_id, first)) = bots::turn_taking_bot(&state) {
+            let memory = state.bot_memory.get(&bot_id).cloned().unwrap_or_default();
+            if let Some(card_id) =
+                bots::choose_card(&state.cards, &memory, first.as_ref(), recall, &mut rng)
+            {
+                moves.push((bot_id, card_id));
+            }
+        }
+        for bot_id in bots::racing_bots(&state) {
+            let memory = state.bot_memory.get(&bot_id).cloned().unwrap_or_default();
+            let first = state
+                .players
+                .get(&bot_id)
+                .and_then(|p| p.flipped.first().cloned());
+            if let Some(card_id) =
+                bots::choose_card(&state.cards, &memory, first.as_ref(), recall, &mut rng)
+            {
+                moves.push((bot_id, card_id));
+            }
+        }
+
+        for (bot_id, card_id) in moves {
+            // An earlier bot may have ended the race
+            if !matches!(state.phase, GamePhase::Playing) {
+                break;
+            }
+            if let Err(reason) = self.flip(&bot_id, card_id, &mut state).await {
+                tracing::warn!(%bot_id, reason, "Bot flip refused");
+            }
+        }
+
+        self.save_game_state(&state).await;
+        Ok(())
+    }
+
+    /// Wake up for the next bot move, after a bit of thinking time
+    async fn schedule_bots(&self, state: &MemoryGameState) {
+        if !bots::bots_pending(state) {
+            return;
+        }
+        let delay_ms = bots::think_delay_ms(state.config.bots.difficulty, &mut bot_rng());
+        let duration = std::time::Duration::from_millis(delay_ms);
+        if let Err(e) = self.state.storage().set_alarm(duration).await {
+            tracing::error!("Failed to set bot alarm: {:?}", e);
+        }
+    }
+
+    /// Announce bots that took or gave up seats
+    async fn broadcast_bot_changes(&self, changes: bots::BotChanges) {
+        for bot in changes.joined {
+            let delta = MemoryDelta::PlayerJoined {
+                user_id: bot.user_id,
+                user_name: bot.user_name,
+                spectating: false,
+                bot: true,
+            };
+            self.broadcast_delta(delta).await;
+        }
+        for user_id in changes.left {
+            self.broadcast_delta(MemoryDelta::PlayerLeft { user_id })
+                .await;
+        }
+    }
+
+    // =========================================================================
+    // Broadcast helpers
+    // =========================================================================
+
+    /// Send a message to every connection whose user is in `audience`
+    fn send_to(&self, audience: &Audience, msg: &MemoryServerMsg) {
+        let Ok(bytes) = encode(msg) else {
+            return;
+        };
+        for ws in self.state.get_websockets() {
+            let included = *audience == Audience::All
+                || matches!(
+                    ws.deserialize_attachment::<ConnectionInfo>(),
+                    Ok(Some(conn)) if audience.includes(&conn.user_id)
+                );
+            if included {
                 let _ = ws.send_with_bytes(&bytes);
             }
         }
     }
 
-    async fn send_delta_to(&self, ws: &WebSocket, delta: MemoryDelta) {
-        let seq = self.next_seq().await;
+    async fn broadcast_delta(&self, delta: MemoryDelta) {
+        let seq = self
+            .advance_seq(|log, seq| log.push(seq, delta.clone()))
+            .await;
+        self.record_delta(&delta).await;
         let msg: MemoryServerMsg = ServerMessage::delta(delta, seq, now());
 
-        if let Ok(bytes) = encode(&msg) {
-            let _ = ws.send_with_bytes(&bytes);
-        }
+        self.send_to(&Audience::All, &msg);
+    }
+
+    /// Send a delta only `user_id` may see, to all of their connections
+    ///
+    /// Private deltas don't advance the shared seq, so other players can
+    /// still resync from the delta log.
+    async fn send_private_delta(&self, user_id: &str, delta: MemoryDelta) {
+        let seq = self.get_seq().await;
+        let msg: MemoryServerMsg = ServerMessage::private_delta(delta, seq, now());
+
+        self.send_to(&Audience::user(user_id), &msg);
     }
 
     async fn broadcast_event(&self, domain: &str, event: MemoryEvent) {
         let msg: MemoryServerMsg = ServerMessage::notify(domain, event, None);
 
-        if let Ok(bytes) = encode(&msg) {
-            for ws in self.state.get_websockets() {
-                let _ = ws.send_with_bytes(&bytes);
-            }
+        self.send_to(&Audience::All, &msg);
+    }
+
+    /// Broadcast an operator command (authorized by the worker) to all clients
+    async fn handle_command(&self, mut req: Request) -> Result<Response> {
+        let command: ClientCommand = match req.json().await {
+            Ok(command) => command,
+            Err(e) => return Response::error(format!("Invalid command: {e}"), 400),
+        };
+        tracing::info!("Broadcasting command {:?}", command);
+
+        let msg: MemoryServerMsg = ServerMessage::command(command);
+        let websockets = self.state.get_websockets();
+        let bytes = encode(&msg).map_err(|e| Error::RustError(e.to_string()))?;
+        for ws in &websockets {
+            let _ = ws.send_with_bytes(&bytes);
+        }
+
+        Response::ok(format!("Sent to {} clients", websockets.len()))
+    }
+
+    async fn room_stats(&self) -> RoomStats {
+        RoomStats {
+            connections: self.state.get_websockets().len(),
+            seq: self.get_seq().await,
         }
     }
 
@@ -1217,6 +1599,21 @@ impl MemoryGameSessionDO {
             }
         }
 
+        // Bots have no connection but are always here
+        let state = self.get_game_state().await;
+        users.extend(
+            state
+                .players
+                .into_values()
+                .filter(|p| p.bot)
+                .map(|p| PresenceInfo {
+                    user_id: p.user_id,
+                    name: Some(p.user_name),
+                    status: PresenceStatus::Active,
+                    connected_at: p.joined_at,
+                }),
+        );
+
         let msg: MemoryServerMsg = ServerMessage::presence(users);
 
         if let Ok(bytes) = encode(&msg) {
@@ -1248,3 +1645,8 @@ fn now() -> u64 {
 fn js_random_u32() -> u32 {
     (js_sys::Math::random() * (u32::MAX as f64)) as u32
 }
+
+fn bot_rng() -> rand::rngs::SmallRng {
+    use rand::SeedableRng;
+    rand::rngs::SmallRng::seed_from_u64(now() ^ (js_random_u32() as u64))
+}
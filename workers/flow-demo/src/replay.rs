@@ -0,0 +1,220 @@
+//! Bounded recordings of finished games for post-game review.
+//!
+//! While a game runs, the session records every broadcast delta with its
+//! time since the deal, on top of the state players started from. When the
+//! game ends the recording is kept under its game ID and served by
+//! `GET /memory/:room_id/replay/:game_id`; clients rebuild any moment by
+//! applying the frames up to it.
+//!
+//! Only broadcast deltas are recorded, so a replay shows what every player
+//! saw (race-mode private flips aren't in it). Recordings stop at
+//! [`MAX_FRAMES`] and only the last [`MAX_STORED`] games are kept.
+//!
+//! Frames are stored in chunks of [`CHUNK_FRAMES`] so each delta only
+//! rewrites the open chunk, and no single storage value outgrows the
+//! Durable Object value size limit.
+
+use serde::{Deserialize, Serialize};
+
+/// Most deltas recorded per game; later ones are dropped
+pub const MAX_FRAMES: usize = 512;
+
+/// Frames per stored chunk
+pub const CHUNK_FRAMES: usize = 32;
+
+/// Finished games kept per room
+pub const MAX_STORED: usize = 5;
+
+/// One recorded delta
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayFrame<D> {
+    /// Milliseconds since the recording started
+    pub at_ms: u64,
+    pub delta: D,
+}
+
+/// A game being recorded, or a finished one without its sealed chunks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recording<S, D> {
+    pub game_id: u64,
+    /// Unix ms when the recording started
+    pub started_at: u64,
+    /// Length of the game in ms, once it has ended
+    pub duration_ms: Option<u64>,
+    /// State before the first frame
+    pub initial: S,
+    /// Number of full chunks stored under [`chunk_key`]
+    pub chunks: usize,
+    /// Frames not yet sealed into a chunk
+    pub tail: Vec<ReplayFrame<D>>,
+    /// Frames were dropped after [`MAX_FRAMES`]
+    pub truncated: bool,
+}
+
+impl<S, D> Recording<S, D> {
+    /// Start recording game `game_id` from `initial` at `now`
+    pub fn new(game_id: u64, initial: S, now: u64) -> Self {
+        Self {
+            game_id,
+            started_at: now,
+            duration_ms: None,
+            initial,
+            chunks: 0,
+            tail: Vec::new(),
+            truncated: false,
+        }
+    }
+
+    /// Frames recorded so far
+    pub fn frame_count(&self) -> usize {
+        self.chunks * CHUNK_FRAMES + self.tail.len()
+    }
+
+    /// Record a delta broadcast at `now`
+    ///
+    /// Returns a chunk to store as `(index, frames)` when this frame fills
+    /// one. Ignored once the game has ended or the recording is full.
+    pub fn push(&mut self, delta: D, now: u64) -> Option<(usize, Vec<ReplayFrame<D>>)> {
+        if self.duration_ms.is_some() {
+            return None;
+        }
+        if self.frame_count() >= MAX_FRAMES {
+            self.truncated = true;
+            return None;
+        }
+        self.tail.push(ReplayFrame {
+            at_ms: now.saturating_sub(self.started_at),
+            delta,
+        });
+        if self.tail.len() < CHUNK_FRAMES {
+            return None;
+        }
+        let index = self.chunks;
+        self.chunks += 1;
+        Some((index, std::mem::take(&mut self.tail)))
+    }
+
+    /// Mark the game as ended at `now`
+    pub fn finish(&mut self, now: u64) {
+        self.duration_ms = Some(now.saturating_sub(self.started_at));
+    }
+
+    /// Reassemble the full replay from the stored chunks, in order
+    pub fn into_replay(self, chunks: Vec<Vec<ReplayFrame<D>>>) -> GameReplay<S, D> {
+        let mut frames: Vec<_> = chunks.into_iter().flatten().collect();
+        frames.extend(self.tail);
+        GameReplay {
+            game_id: self.game_id,
+            started_at: self.started_at,
+            duration_ms: self.duration_ms.unwrap_or_default(),
+            initial: self.initial,
+            frames,
+            truncated: self.truncated,
+        }
+    }
+}
+
+/// A recorded game as served to clients
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameReplay<S, D> {
+    pub game_id: u64,
+    /// Unix ms when the recording started
+    pub started_at: u64,
+    pub duration_ms: u64,
+    /// State before the first frame
+    pub initial: S,
+    pub frames: Vec<ReplayFrame<D>>,
+    /// Frames were dropped after [`MAX_FRAMES`]
+    pub truncated: bool,
+}
+
+/// IDs of the finished games kept, oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReplayIndex {
+    game_ids: Vec<u64>,
+}
+
+impl ReplayIndex {
+    /// Add a finished game, returning the IDs that no longer fit
+    pub fn add(&mut self, game_id: u64) -> Vec<u64> {
+        self.game_ids.retain(|id| *id != game_id);
+        self.game_ids.push(game_id);
+        let excess = self.game_ids.len().saturating_sub(MAX_STORED);
+        self.game_ids.drain(..excess).collect()
+    }
+
+    pub fn contains(&self, game_id: u64) -> bool {
+        self.game_ids.contains(&game_id)
+    }
+}
+
+/// Storage key (after the session prefix) of a finished game's recording
+pub fn recording_key(game_id: u64) -> String {
+    format!("replay_{game_id}")
+}
+
+/// Storage key (after the session prefix) of one chunk of frames
+pub fn chunk_key(game_id: u64, index: usize) -> String {
+    format!("replay_{game_id}_{index}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_are_timed_from_start() {
+        let mut recording = Recording::new(7, (), 1_000);
+        recording.push("dealt", 1_000);
+        recording.push("flipped", 3_500);
+        recording.finish(9_000);
+        recording.push("late", 9_500);
+
+        let replay = recording.into_replay(Vec::new());
+        let times: Vec<_> = replay.frames.iter().map(|f| f.at_ms).collect();
+        assert_eq!(times, vec![0, 2_500]);
+        assert_eq!(replay.duration_ms, 8_000);
+    }
+
+    #[test]
+    fn test_chunks_reassemble_in_order() {
+        let mut recording = Recording::new(1, (), 0);
+        let mut chunks = Vec::new();
+        for i in 0..(CHUNK_FRAMES * 2 + 3) as u64 {
+            if let Some((index, frames)) = recording.push(i, i) {
+                assert_eq!(index, chunks.len());
+                chunks.push(frames);
+            }
+        }
+        assert_eq!(recording.chunks, 2);
+        assert_eq!(recording.tail.len(), 3);
+
+        let replay = recording.into_replay(chunks);
+        let deltas: Vec<_> = replay.frames.iter().map(|f| f.delta).collect();
+        assert_eq!(
+            deltas,
+            (0..(CHUNK_FRAMES * 2 + 3) as u64).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_recording_is_bounded() {
+        let mut recording = Recording::new(1, (), 0);
+        for i in 0..=MAX_FRAMES as u64 {
+            recording.push(i, i);
+        }
+        assert_eq!(recording.frame_count(), MAX_FRAMES);
+        assert!(recording.truncated);
+    }
+
+    #[test]
+    fn test_index_evicts_oldest() {
+        let mut index = ReplayIndex::default();
+        for id in 1..=MAX_STORED as u64 {
+            assert!(index.add(id).is_empty());
+        }
+        assert_eq!(index.add(100), vec![1]);
+        assert!(!index.contains(1));
+        assert!(index.contains(2) && index.contains(100));
+    }
+}
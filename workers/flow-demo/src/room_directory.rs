@@ -0,0 +1,108 @@
+//! Room directory Durable Object.
+//!
+//! Durable Objects can't be enumerated, so the router registers every room
+//! it forwards a WebSocket to. A single instance keeps the list of rooms
+//! seen recently for the admin API.
+
+use crate::storage::TypedStorage;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use worker::*;
+
+/// Name of the single directory instance
+pub const DIRECTORY_NAME: &str = "global";
+
+/// Storage key for the room map
+const STORAGE_KEY_ROOMS: &str = "rooms";
+
+/// Rooms not seen for this long are dropped (7 days)
+const ROOM_TTL_MS: u64 = 7 * 24 * 60 * 60 * 1000;
+
+/// Most rooms kept; the least recently seen are dropped first
+const MAX_ROOMS: usize = 500;
+
+/// A room the router has seen
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomEntry {
+    /// Admin room ID (`<kind>:<name>`)
+    pub id: String,
+    /// Last WebSocket connection (unix ms)
+    pub last_seen: u64,
+}
+
+/// The Durable Object holding the room directory.
+#[durable_object]
+pub struct RoomDirectoryDO {
+    #[allow(dead_code)]
+    state: State,
+    #[allow(dead_code)]
+    env: Env,
+    /// Room ID -> last seen
+    rooms: TypedStorage<HashMap<String, u64>>,
+}
+
+impl DurableObject for RoomDirectoryDO {
+    fn new(state: State, env: Env) -> Self {
+        Self {
+            rooms: TypedStorage::new(state.storage(), STORAGE_KEY_ROOMS),
+            state,
+            env,
+        }
+    }
+
+    async fn fetch(&self, mut req: Request) -> Result<Response> {
+        match (req.method(), req.path().as_str()) {
+            (Method::Post, "/register") => {
+                let entry: RoomEntry = req.json().await?;
+                self.register(entry).await;
+                Response::ok("OK")
+            }
+            (Method::Get, "/rooms") => Response::from_json(&self.list().await),
+            _ => Response::error("Not found", 404),
+        }
+    }
+}
+
+impl RoomDirectoryDO {
+    async fn register(&self, entry: RoomEntry) {
+        let saved = self
+            .rooms
+            .update(|rooms| {
+                rooms.insert(entry.id, entry.last_seen);
+
+                let cutoff = now().saturating_sub(ROOM_TTL_MS);
+                rooms.retain(|_, last_seen| *last_seen >= cutoff);
+                if rooms.len() > MAX_ROOMS {
+                    let mut by_age: Vec<_> =
+                        rooms.iter().map(|(id, seen)| (*seen, id.clone())).collect();
+                    by_age.sort();
+                    for (_, id) in by_age.into_iter().take(rooms.len() - MAX_ROOMS) {
+                        rooms.remove(&id);
+                    }
+                }
+            })
+            .await;
+        if let Err(e) = saved {
+            tracing::error!("Failed to save room directory: {e}");
+        }
+    }
+
+    /// Rooms seen within the TTL, most recent first
+    async fn list(&self) -> Vec<RoomEntry> {
+        let cutoff = now().saturating_sub(ROOM_TTL_MS);
+        let mut entries: Vec<RoomEntry> = self
+            .rooms
+            .get_or_default()
+            .await
+            .into_iter()
+            .filter(|(_, last_seen)| *last_seen >= cutoff)
+            .map(|(id, last_seen)| RoomEntry { id, last_seen })
+            .collect();
+        entries.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+        entries
+    }
+}
+
+fn now() -> u64 {
+    js_sys::Date::now() as u64
+}
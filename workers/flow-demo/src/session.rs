@@ -3,16 +3,22 @@
 //! This implements the server-side of the unified realtime protocol,
 //! handling WebSocket connections, state management, and broadcasting.
 
+use crate::delta_log::DeltaLog;
+use crate::liveness::{self, Liveness, SWEEP_INTERVAL_MS};
+use crate::storage::{SeqCounter, StorageBatch, TypedStorage};
 use crate::types::*;
 use serde::{Deserialize, Serialize};
-use std::cell::RefCell;
-use ui_flow_protocol::{encode, OpId, PresenceInfo, PresenceStatus, ServerMessage};
+use ui_flow_protocol::{encode, ClientCommand, OpId, PresenceInfo, ServerMessage, UNKNOWN_DOMAIN};
 use worker::*;
 
 /// Storage keys for persisted state
 const STORAGE_KEY_STATE: &str = "room_state";
 const STORAGE_KEY_SEQ: &str = "seq";
 const STORAGE_KEY_MSG_ID: &str = "next_message_id";
+const STORAGE_KEY_DELTA_LOG: &str = "delta_log";
+
+/// Recent deltas kept for resyncing reconnecting clients
+const DELTA_LOG_CAPACITY: usize = 64;
 
 /// Connection information stored as WebSocket attachment
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +26,27 @@ struct ConnectionInfo {
     user_id: String,
     user_name: String,
     connected_at: u64,
+    /// Random ID sent only to this socket in `Connected`; the leave beacon
+    /// must present it
+    #[serde(default)]
+    connection_id: String,
+    /// Said Goodbye; already announced as left
+    #[serde(default)]
+    left: bool,
+    /// Last message from the client (unix ms), written at most every
+    /// [`liveness::ACTIVITY_WRITE_MS`]
+    #[serde(default)]
+    last_seen: u64,
+    /// Announced as idle by the last sweep
+    #[serde(default)]
+    idle: bool,
+}
+
+impl ConnectionInfo {
+    /// When the client was last heard from
+    fn last_active(&self) -> u64 {
+        self.last_seen.max(self.connected_at)
+    }
 }
 
 /// The Durable Object that manages a single room's state and connections.
@@ -28,23 +55,26 @@ pub struct FlowDemoSessionDO {
     state: State,
     #[allow(dead_code)]
     env: Env,
-    /// Current room state (cached from storage)
-    room_state: RefCell<Option<DemoState>>,
-    /// Current sequence number (cached from storage)
-    seq: RefCell<Option<u64>>,
-    /// Message ID counter (cached from storage)
-    next_message_id: RefCell<Option<u64>>,
+    /// Current room state
+    room_state: TypedStorage<DemoState>,
+    /// Current sequence number
+    seq: SeqCounter,
+    /// Next chat message ID
+    next_message_id: TypedStorage<u64>,
+    /// Recent broadcast deltas
+    delta_log: TypedStorage<DeltaLog<DemoDelta>>,
 }
 
 impl DurableObject for FlowDemoSessionDO {
     fn new(state: State, env: Env) -> Self {
+        // Values are loaded from storage lazily and cached
         Self {
+            room_state: TypedStorage::new(state.storage(), STORAGE_KEY_STATE),
+            seq: SeqCounter::new(state.storage(), STORAGE_KEY_SEQ),
+            next_message_id: TypedStorage::new(state.storage(), STORAGE_KEY_MSG_ID),
+            delta_log: TypedStorage::new(state.storage(), STORAGE_KEY_DELTA_LOG),
             state,
             env,
-            // Start as None - will be lazily loaded from storage
-            room_state: RefCell::new(None),
-            seq: RefCell::new(None),
-            next_message_id: RefCell::new(None),
         }
     }
 
@@ -54,6 +84,23 @@ impl DurableObject for FlowDemoSessionDO {
             return self.handle_websocket_upgrade(req).await;
         }
 
+        if admin::is_internal(&req, Method::Post, admin::ROOM_COMMAND_URL) {
+            return self.handle_command(req).await;
+        }
+
+        if req.method() == Method::Post && req.path().ends_with("/leave") {
+            return self.handle_leave_beacon(req).await;
+        }
+
+        if admin::is_internal(&req, Method::Get, admin::ROOM_STATS_URL) {
+            return Response::from_json(&self.room_stats().await);
+        }
+
+        if admin::is_internal(&req, Method::Post, admin::ROOM_RESET_URL) {
+            self.reset_room().await;
+            return Response::ok("Room reset");
+        }
+
         Response::error("Expected WebSocket upgrade", 400)
     }
 
@@ -67,8 +114,12 @@ impl DurableObject for FlowDemoSessionDO {
             WebSocketIncomingMessage::String(text) => text.into_bytes(),
         };
 
-        // Decode the client message
-        let client_msg: ClientMsg = match ui_flow_protocol::decode(&bytes) {
+        // Decode and validate the client message (size and field caps keep
+        // oversized payloads out of storage)
+        let client_msg: ClientMsg = match ui_flow_protocol::decode_validated(
+            &bytes,
+            ui_flow_protocol::DEFAULT_MAX_CLIENT_MESSAGE_BYTES,
+        ) {
             Ok(msg) => msg,
             Err(e) => {
                 let error_msg: ServerMsg =
@@ -86,7 +137,12 @@ impl DurableObject for FlowDemoSessionDO {
             user_id: "anonymous".to_string(),
             user_name: "Anonymous".to_string(),
             connected_at: 0,
+            connection_id: String::new(),
+            left: false,
+            last_seen: 0,
+            idle: false,
         });
+        let conn = self.record_activity(&ws, conn).await;
 
         // Handle the message
         self.handle_client_message(&ws, &conn, client_msg).await?;
@@ -101,8 +157,12 @@ impl DurableObject for FlowDemoSessionDO {
         _reason: String,
         _was_clean: bool,
     ) -> Result<()> {
-        // Get connection info
+        // Get connection info (a Goodbye already announced the leave)
         if let Ok(Some(conn)) = ws.deserialize_attachment::<ConnectionInfo>() {
+            if conn.left {
+                return Ok(());
+            }
+
             // Broadcast user left delta
             let delta = DemoDelta::UserLeft {
                 user_id: conn.user_id.clone(),
@@ -115,96 +175,96 @@ impl DurableObject for FlowDemoSessionDO {
 
         Ok(())
     }
+
+    /// Sweep for zombie connections while any are open
+    async fn alarm(&self) -> Result<Response> {
+        let open = self.sweep_connections().await;
+        if open > 0 {
+            self.schedule_sweep().await;
+        }
+        Response::ok("OK")
+    }
 }
 
 impl FlowDemoSessionDO {
     /// Load room state from storage, or return default if not found
     async fn get_room_state(&self) -> DemoState {
-        // Check cache first
-        if let Some(state) = self.room_state.borrow().clone() {
-            return state;
-        }
-
-        // Load from storage
-        let state: DemoState = self
-            .state
-            .storage()
-            .get(STORAGE_KEY_STATE)
-            .await
-            .ok()
-            .unwrap_or_default();
-
-        // Cache it
-        *self.room_state.borrow_mut() = Some(state.clone());
-        state
+        self.room_state.get_or_default().await
     }
 
     /// Save room state to storage
     async fn save_room_state(&self, state: &DemoState) {
-        // Update cache
-        *self.room_state.borrow_mut() = Some(state.clone());
-
-        // Persist to storage
-        let _ = self.state.storage().put(STORAGE_KEY_STATE, state).await;
+        if let Err(e) = self.room_state.put(state).await {
+            tracing::error!("Failed to save room state: {e}");
+        }
     }
 
     /// Get current sequence number
     async fn get_seq(&self) -> u64 {
-        if let Some(seq) = *self.seq.borrow() {
-            return seq;
-        }
-
-        let seq: u64 = self
-            .state
-            .storage()
-            .get(STORAGE_KEY_SEQ)
-            .await
-            .ok()
-            .unwrap_or(0);
+        self.seq.current().await
+    }
 
-        *self.seq.borrow_mut() = Some(seq);
-        seq
+    /// Load the delta log from storage, or start one at the current seq
+    async fn get_delta_log(&self) -> DeltaLog<DemoDelta> {
+        match self.delta_log.get().await {
+            Ok(Some(log)) => log,
+            Ok(None) => DeltaLog::new(DELTA_LOG_CAPACITY, self.get_seq().await),
+            Err(e) => {
+                tracing::warn!("Discarding unreadable delta log: {e}");
+                DeltaLog::new(DELTA_LOG_CAPACITY, self.get_seq().await)
+            }
+        }
     }
 
-    /// Increment and save sequence number
-    async fn next_seq(&self) -> u64 {
-        let seq = self.get_seq().await + 1;
-        *self.seq.borrow_mut() = Some(seq);
-        let _ = self.state.storage().put(STORAGE_KEY_SEQ, seq).await;
+    /// Increment the sequence number and record it in the delta log, saving
+    /// both in one write
+    async fn advance_seq(&self, record: impl FnOnce(&mut DeltaLog<DemoDelta>, u64)) -> u64 {
+        let mut log = self.get_delta_log().await;
+        let mut batch = StorageBatch::new();
+        let seq = self.seq.next_in(&mut batch).await;
+        record(&mut log, seq);
+        self.delta_log.put_in(&mut batch, &log);
+        if let Err(e) = batch.commit(&self.state.storage()).await {
+            tracing::error!("Failed to save seq {seq}: {e}");
+        }
         seq
     }
 
-    /// Get next message ID
-    async fn get_next_message_id(&self) -> u64 {
-        if let Some(id) = *self.next_message_id.borrow() {
-            return id;
-        }
+    /// Catch a client at `last_seq` up: replayed deltas if the log covers the
+    /// gap, otherwise a full snapshot
+    async fn resync_message(&self, last_seq: Option<u64>) -> ServerMsg {
+        let seq = self.get_seq().await;
 
-        let id: u64 = self
-            .state
-            .storage()
-            .get(STORAGE_KEY_MSG_ID)
-            .await
-            .ok()
-            .unwrap_or(1);
+        if let Some(last_seq) = last_seq {
+            if let Some(deltas) = self.get_delta_log().await.since(last_seq, seq) {
+                return ServerMessage::deltas(deltas, seq, now());
+            }
+        }
 
-        *self.next_message_id.borrow_mut() = Some(id);
-        id
+        let room_state = self.get_room_state().await;
+        ServerMessage::snapshot(room_state, seq, now())
     }
 
-    /// Increment and save message ID
+    /// Take the next message ID (IDs start at 1)
     async fn next_message_id(&self) -> u64 {
-        let id = self.get_next_message_id().await;
-        let next_id = id + 1;
-        *self.next_message_id.borrow_mut() = Some(next_id);
-        let _ = self.state.storage().put(STORAGE_KEY_MSG_ID, next_id).await;
+        let id = self.next_message_id.get_or_default().await.max(1);
+        if let Err(e) = self.next_message_id.put(&(id + 1)).await {
+            tracing::error!("Failed to save message ID: {e}");
+        }
         id
     }
 
     /// Handle WebSocket upgrade request
+    ///
+    /// Reconnecting clients can pass `last_seq` to receive the deltas they
+    /// missed instead of a full snapshot.
     async fn handle_websocket_upgrade(&self, req: Request) -> Result<Response> {
         // Extract user info from query params (in production, use JWT)
         let url = req.url()?;
+        let last_seq = url
+            .query_pairs()
+            .find(|(k, _)| k == "last_seq")
+            .and_then(|(_, v)| v.parse::<u64>().ok());
         let user_id = url
             .query_pairs()
             .find(|(k, _)| k == "user_id")
@@ -220,10 +280,15 @@ impl FlowDemoSessionDO {
         let WebSocketPair { client, server } = WebSocketPair::new()?;
 
         // Store connection info as attachment
+        let connection_id = new_connection_id()?;
         let conn_info = ConnectionInfo {
             user_id: user_id.clone(),
             user_name: user_name.clone(),
             connected_at: now(),
+            connection_id: connection_id.clone(),
+            left: false,
+            last_seen: now(),
+            idle: false,
         };
         server.serialize_attachment(&conn_info)?;
 
@@ -231,16 +296,14 @@ impl FlowDemoSessionDO {
         self.state.accept_web_socket(&server);
 
         // Send Connected message
-        let connected_msg: ServerMsg = ServerMessage::connected(1, self.state.id().to_string());
+        let connected_msg: ServerMsg = ServerMessage::connected(1, connection_id);
         if let Ok(bytes) = encode(&connected_msg) {
             let _ = server.send_with_bytes(&bytes);
         }
 
-        // Send current state snapshot (load from storage)
-        let room_state = self.get_room_state().await;
-        let seq = self.get_seq().await;
-        let snapshot_msg: ServerMsg = ServerMessage::snapshot(room_state, seq, now());
-        if let Ok(bytes) = encode(&snapshot_msg) {
+        // Send current state (missed deltas or a snapshot)
+        let resync_msg = self.resync_message(last_seq).await;
+        if let Ok(bytes) = encode(&resync_msg) {
             let _ = server.send_with_bytes(&bytes);
         }
 
@@ -251,6 +314,8 @@ impl FlowDemoSessionDO {
         // Broadcast updated presence
         self.broadcast_presence().await;
 
+        self.ensure_sweep_scheduled().await;
+
         Response::from_websocket(client)
     }
 
@@ -271,16 +336,30 @@ impl FlowDemoSessionDO {
                 }
             }
 
-            ClientMessage::Resync { last_seq: _ } => {
-                // Send full snapshot (load from storage)
-                let room_state = self.get_room_state().await;
-                let seq = self.get_seq().await;
-                let snapshot_msg: ServerMsg = ServerMessage::snapshot(room_state, seq, now());
-                if let Ok(bytes) = encode(&snapshot_msg) {
+            ClientMessage::Resync { last_seq } => {
+                let resync_msg = self.resync_message(last_seq).await;
+                if let Ok(bytes) = encode(&resync_msg) {
+                    let _ = ws.send_with_bytes(&bytes);
+                }
+            }
+
+            ClientMessage::SnapshotRequest { domain } => {
+                // The demo room sends its whole state in one snapshot
+                let reply: ServerMsg = ServerMessage::error_with_code(
+                    UNKNOWN_DOMAIN,
+                    format!("Unknown domain: {domain}"),
+                    false,
+                );
+                if let Ok(bytes) = encode(&reply) {
                     let _ = ws.send_with_bytes(&bytes);
                 }
             }
 
+            ClientMessage::Goodbye { reason } => {
+                tracing::debug!("{} said goodbye ({reason})", conn.user_id);
+                self.leave(ws, conn).await;
+            }
+
             ClientMessage::Action { op_id, action } => {
                 self.handle_action(ws, conn, op_id, action).await?;
             }
@@ -296,6 +375,10 @@ impl FlowDemoSessionDO {
             ClientMessage::Signal { .. } => {
                 // WebRTC signalling not implemented in demo
             }
+
+            ClientMessage::NotifyAck { .. } => {
+                // The demo sends no AckRequired notifications
+            }
         }
 
         Ok(())
@@ -342,15 +425,19 @@ impl FlowDemoSessionDO {
                 }
             }
 
-            DemoAction::SendMessage { text } => {
+            DemoAction::SendMessage { text, mut mentions } => {
                 let mut state = self.get_room_state().await;
                 let id = self.next_message_id().await;
 
+                // Keep only mentions that point into this text
+                mentions.retain(|m| m.fits(&text));
+
                 let message = ChatMessage {
                     id,
                     user_id: conn.user_id.clone(),
                     user_name: conn.user_name.clone(),
                     text,
+                    mentions,
                     timestamp: now(),
                 };
 
@@ -366,20 +453,22 @@ impl FlowDemoSessionDO {
                 let delta = DemoDelta::MessageAdded { message };
                 self.broadcast_delta(delta).await;
 
-                // Send success
-                let ok_msg: ServerMsg = ServerMessage::action_ok(op_id, None);
+                // Reply with the message id so the sender can mark it delivered
+                let ok_msg: ServerMsg = ServerMessage::action_ok_with(op_id, &id)
+                    .unwrap_or_else(|_| ServerMessage::action_ok(op_id, None));
                 if let Ok(bytes) = encode(&ok_msg) {
                     let _ = ws.send_with_bytes(&bytes);
                 }
             }
 
             DemoAction::StartTyping => {
-                // Broadcast typing event (not a state change)
+                // Broadcast typing event (not a state change); clients drop
+                // the indicator on their own after a few seconds
                 let event = DemoEvent::UserTyping {
                     user_id: conn.user_id.clone(),
                     user_name: conn.user_name.clone(),
                 };
-                self.broadcast_event("typing", event).await;
+                self.broadcast_event("events", event).await;
 
                 // Send success
                 let ok_msg: ServerMsg = ServerMessage::action_ok(op_id, None);
@@ -392,9 +481,163 @@ impl FlowDemoSessionDO {
         Ok(())
     }
 
+    /// Note that the client was heard from, announcing its return if the
+    /// last sweep marked it idle
+    async fn record_activity(&self, ws: &WebSocket, conn: ConnectionInfo) -> ConnectionInfo {
+        let now = now();
+        if !conn.idle && !liveness::should_record(conn.last_seen, now) {
+            return conn;
+        }
+        let was_idle = conn.idle;
+        let conn = ConnectionInfo {
+            last_seen: now,
+            idle: false,
+            ..conn
+        };
+        if let Err(e) = ws.serialize_attachment(&conn) {
+            tracing::warn!("Failed to record activity for {}: {e}", conn.user_id);
+        }
+        if was_idle {
+            self.broadcast_presence().await;
+        }
+        conn
+    }
+
+    /// Mark quiet connections idle and close dead ones, announcing any change
+    ///
+    /// Returns the number of connections still open.
+    async fn sweep_connections(&self) -> usize {
+        let now = now();
+        let mut open = 0;
+        let mut changed = false;
+
+        for ws in self.state.get_websockets() {
+            let Ok(Some(conn)) = ws.deserialize_attachment::<ConnectionInfo>() else {
+                continue;
+            };
+            if conn.left {
+                continue;
+            }
+            match Liveness::at(conn.last_active(), now) {
+                Liveness::Zombie => {
+                    tracing::info!(
+                        "Closing {}: silent for {}s",
+                        conn.user_id,
+                        (now - conn.last_active()) / 1000
+                    );
+                    let left = ConnectionInfo { left: true, ..conn };
+                    if let Err(e) = ws.serialize_attachment(&left) {
+                        tracing::warn!("Failed to mark {} as left: {e}", left.user_id);
+                    }
+                    self.broadcast_delta(DemoDelta::UserLeft {
+                        user_id: left.user_id,
+                    })
+                    .await;
+                    let _ = ws.close(Some(1001), Some("heartbeat timeout"));
+                    changed = true;
+                }
+                Liveness::Idle if !conn.idle => {
+                    let idle = ConnectionInfo { idle: true, ..conn };
+                    if let Err(e) = ws.serialize_attachment(&idle) {
+                        tracing::warn!("Failed to mark {} as idle: {e}", idle.user_id);
+                    }
+                    open += 1;
+                    changed = true;
+                }
+                Liveness::Idle | Liveness::Active => open += 1,
+            }
+        }
+
+        if changed {
+            self.broadcast_presence().await;
+        }
+        open
+    }
+
+    /// Start the sweep alarm unless one is already pending
+    async fn ensure_sweep_scheduled(&self) {
+        match self.state.storage().get_alarm().await {
+            Ok(Some(_)) => {}
+            Ok(None) => self.schedule_sweep().await,
+            Err(e) => tracing::error!("Failed to read alarm: {:?}", e),
+        }
+    }
+
+    async fn schedule_sweep(&self) {
+        let duration = std::time::Duration::from_millis(SWEEP_INTERVAL_MS);
+        if let Err(e) = self.state.storage().set_alarm(duration).await {
+            tracing::error!("Failed to set sweep alarm: {:?}", e);
+        }
+    }
+
+    /// Announce an intentional leave now and close the socket, instead of
+    /// waiting for it to time out
+    async fn leave(&self, ws: &WebSocket, conn: &ConnectionInfo) {
+        if conn.left {
+            return;
+        }
+        let left = ConnectionInfo {
+            left: true,
+            ..conn.clone()
+        };
+        if let Err(e) = ws.serialize_attachment(&left) {
+            tracing::warn!("Failed to mark {} as left: {e}", conn.user_id);
+        }
+
+        let delta = DemoDelta::UserLeft {
+            user_id: conn.user_id.clone(),
+        };
+        self.broadcast_delta(delta).await;
+        self.broadcast_presence().await;
+
+        let _ = ws.close(Some(1000), Some("goodbye"));
+    }
+
+    /// Goodbye sent with `navigator.sendBeacon` as the page unloaded
+    ///
+    /// The socket may never deliver its own Goodbye, so the beacon leaves
+    /// for it. Only the socket whose `connection_id` is in the query is
+    /// closed; that ID is sent to no one else, unlike the user IDs in presence.
+    async fn handle_leave_beacon(&self, mut req: Request) -> Result<Response> {
+        let connection_id = req
+            .url()?
+            .query_pairs()
+            .find(|(k, _)| k == "connection_id")
+            .map(|(_, v)| v.to_string())
+            .filter(|id| !id.is_empty());
+        let Some(connection_id) = connection_id else {
+            return Response::error("Missing connection_id", 400);
+        };
+
+        let bytes = req.bytes().await?;
+        let msg: ClientMsg = match ui_flow_protocol::decode_validated(
+            &bytes,
+            ui_flow_protocol::DEFAULT_MAX_CLIENT_MESSAGE_BYTES,
+        ) {
+            Ok(msg) => msg,
+            Err(e) => return Response::error(format!("Invalid message: {e}"), 400),
+        };
+        if !matches!(msg, ui_flow_protocol::ClientMessage::Goodbye { .. }) {
+            return Response::error("Expected Goodbye", 400);
+        }
+
+        for ws in self.state.get_websockets() {
+            if let Ok(Some(conn)) = ws.deserialize_attachment::<ConnectionInfo>() {
+                if conn.connection_id == connection_id {
+                    self.leave(&ws, &conn).await;
+                    return Response::ok("Left");
+                }
+            }
+        }
+
+        Response::error("Unknown connection", 404)
+    }
+
     /// Broadcast a delta to all connected clients
     async fn broadcast_delta(&self, delta: DemoDelta) {
-        let seq = self.next_seq().await;
+        let seq = self
+            .advance_seq(|log, seq| log.push(seq, delta.clone()))
+            .await;
 
         let msg: ServerMsg = ServerMessage::delta(delta, seq, now());
 
@@ -416,17 +659,71 @@ impl FlowDemoSessionDO {
         }
     }
 
+    /// Broadcast an operator command (authorized by the worker) to all clients
+    async fn handle_command(&self, mut req: Request) -> Result<Response> {
+        let command: ClientCommand = match req.json().await {
+            Ok(command) => command,
+            Err(e) => return Response::error(format!("Invalid command: {e}"), 400),
+        };
+        tracing::info!("Broadcasting command {:?}", command);
+
+        let msg: ServerMsg = ServerMessage::command(command);
+        let websockets = self.state.get_websockets();
+        let bytes = encode(&msg).map_err(|e| Error::RustError(e.to_string()))?;
+        for ws in &websockets {
+            let _ = ws.send_with_bytes(&bytes);
+        }
+
+        Response::ok(format!("Sent to {} clients", websockets.len()))
+    }
+
+    async fn room_stats(&self) -> RoomStats {
+        RoomStats {
+            connections: self.state.get_websockets().len(),
+            seq: self.get_seq().await,
+        }
+    }
+
+    /// Clear the counter and chat history (admin API only)
+    ///
+    /// The seq keeps counting so reconnecting clients can't mistake the
+    /// reset room for the one they last saw.
+    async fn reset_room(&self) {
+        let state = DemoState::default();
+        self.save_room_state(&state).await;
+
+        let seq = self.advance_seq(|log, seq| log.invalidate(seq)).await;
+
+        let snapshot_msg: ServerMsg = ServerMessage::snapshot(state, seq, now());
+        if let Ok(bytes) = encode(&snapshot_msg) {
+            for ws in self.state.get_websockets() {
+                let _ = ws.send_with_bytes(&bytes);
+            }
+        }
+        tracing::info!("Room reset by admin");
+    }
+
     /// Broadcast presence information to all connected clients
+    ///
+    /// Quiet connections are listed as idle; silent ones are left out even
+    /// before the sweep closes them.
     async fn broadcast_presence(&self) {
         let websockets = self.state.get_websockets();
+        let now = now();
         let mut users = Vec::new();
 
         for ws in &websockets {
             if let Ok(Some(conn)) = ws.deserialize_attachment::<ConnectionInfo>() {
+                if conn.left {
+                    continue;
+                }
+                let Some(status) = Liveness::at(conn.last_active(), now).presence() else {
+                    continue;
+                };
                 users.push(PresenceInfo {
                     user_id: conn.user_id,
                     name: Some(conn.user_name),
-                    status: PresenceStatus::Active,
+                    status,
                     connected_at: conn.connected_at,
                 });
             }
@@ -447,6 +744,13 @@ fn now() -> u64 {
     js_sys::Date::now() as u64
 }
 
+/// Unguessable ID for a new connection (it authorizes the leave beacon)
+fn new_connection_id() -> Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::fill(&mut bytes).map_err(|e| Error::RustError(e.to_string()))?;
+    Ok(format!("{:032x}", u128::from_le_bytes(bytes)))
+}
+
 /// Simple random number generation for user IDs
 mod rand {
     pub fn random<T>() -> T
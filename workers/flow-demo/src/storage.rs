@@ -0,0 +1,247 @@
+//! Typed, cached access to Durable Object storage.
+//!
+//! Each DO keeps a few values in storage (room state, seq, delta log) and
+//! caches them in memory between requests. [`TypedStorage`] does the load,
+//! cache and persist for one key; [`SeqCounter`] is the monotonic sequence
+//! number built on it; [`StorageBatch`] writes several values in one
+//! `put` so related changes (a new seq and the delta log entry for it) land
+//! together.
+//!
+//! A missing key reads as `None` (or the default); a value that exists but
+//! doesn't deserialize is an error, so a schema change can't silently reset
+//! a room. Writes update the cache first, so the DO keeps serving the new
+//! value for its lifetime even if persisting fails.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cell::RefCell;
+use worker::{Error, Result, Storage};
+
+/// Most keys Durable Object storage accepts in one `put`
+pub const MAX_BATCH_KEYS: usize = 128;
+
+/// One storage key holding a `T`, cached in memory
+pub struct TypedStorage<T> {
+    storage: Storage,
+    key: String,
+    cache: RefCell<Option<T>>,
+}
+
+impl<T> TypedStorage<T>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    /// Value stored under `key`
+    pub fn new(storage: Storage, key: impl Into<String>) -> Self {
+        Self {
+            storage,
+            key: key.into(),
+            cache: RefCell::new(None),
+        }
+    }
+
+    /// Value stored under `prefix` + `key` (e.g. `"game_"` + `"seq"`)
+    pub fn prefixed(storage: Storage, prefix: &str, key: &str) -> Self {
+        Self::new(storage, format!("{prefix}{key}"))
+    }
+
+    /// Storage key
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Load the value, `None` if the key has never been written
+    pub async fn get(&self) -> Result<Option<T>> {
+        if let Some(value) = self.cache.borrow().clone() {
+            return Ok(Some(value));
+        }
+
+        // `get_multiple` leaves missing keys out, which tells them apart from
+        // values that fail to deserialize
+        let found = self.storage.get_multiple(vec![self.key.as_str()]).await?;
+        let raw = found.get(&self.key.as_str().into());
+        if raw.is_undefined() {
+            return Ok(None);
+        }
+        let value: T = serde_wasm_bindgen::from_value(raw).map_err(|e| {
+            Error::RustError(format!("Failed to read storage key {}: {e}", self.key))
+        })?;
+
+        *self.cache.borrow_mut() = Some(value.clone());
+        Ok(Some(value))
+    }
+
+    /// Save the value
+    pub async fn put(&self, value: &T) -> Result<()> {
+        *self.cache.borrow_mut() = Some(value.clone());
+        self.storage.put(&self.key, value).await
+    }
+
+    /// Drop the cached value so the next read goes to storage
+    ///
+    /// Call after writing the key some other way (e.g. `delete_all`).
+    pub fn forget(&self) {
+        *self.cache.borrow_mut() = None;
+    }
+
+    /// Stage `value` for a batched write; the cache updates when it commits
+    pub fn put_in<'a>(&'a self, batch: &mut StorageBatch<'a>, value: &T) {
+        batch.stage(&self.key, value);
+        let value = value.clone();
+        batch
+            .on_commit
+            .push(Box::new(move || *self.cache.borrow_mut() = Some(value)));
+    }
+}
+
+impl<T> TypedStorage<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Default,
+{
+    /// Load the value, falling back to `T::default()`
+    ///
+    /// Read errors are logged and also fall back, for callers with no way to
+    /// report them.
+    pub async fn get_or_default(&self) -> T {
+        match self.get().await {
+            Ok(Some(value)) => value,
+            Ok(None) => T::default(),
+            Err(e) => {
+                tracing::warn!("Using default for storage key {}: {e}", self.key);
+                T::default()
+            }
+        }
+    }
+
+    /// Load, modify and save the value, returning what `f` returns
+    pub async fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R> {
+        let mut value = self.get().await?.unwrap_or_default();
+        let result = f(&mut value);
+        self.put(&value).await?;
+        Ok(result)
+    }
+}
+
+/// Monotonic sequence number persisted under one key, starting at 0
+pub struct SeqCounter {
+    value: TypedStorage<u64>,
+}
+
+impl SeqCounter {
+    /// Counter stored under `key`
+    pub fn new(storage: Storage, key: impl Into<String>) -> Self {
+        Self {
+            value: TypedStorage::new(storage, key),
+        }
+    }
+
+    /// Counter stored under `prefix` + `key`
+    pub fn prefixed(storage: Storage, prefix: &str, key: &str) -> Self {
+        Self {
+            value: TypedStorage::prefixed(storage, prefix, key),
+        }
+    }
+
+    /// Current value (0 if never advanced)
+    pub async fn current(&self) -> u64 {
+        self.value.get_or_default().await
+    }
+
+    /// Advance by one and save, returning the new value
+    pub async fn next(&self) -> Result<u64> {
+        let seq = self.current().await + 1;
+        self.value.put(&seq).await?;
+        Ok(seq)
+    }
+
+    /// Advance by one as part of `batch`, returning the new value
+    pub async fn next_in<'a>(&'a self, batch: &mut StorageBatch<'a>) -> u64 {
+        let seq = self.current().await + 1;
+        self.value.put_in(batch, &seq);
+        seq
+    }
+
+    /// Overwrite the value as part of `batch` (e.g. after clearing storage)
+    pub fn set_in<'a>(&'a self, batch: &mut StorageBatch<'a>, seq: u64) {
+        self.value.put_in(batch, &seq);
+    }
+}
+
+/// Several values written with one storage `put`
+///
+/// Stage values with [`TypedStorage::put_in`] / [`SeqCounter::next_in`], then
+/// [`commit`](Self::commit). A value that fails to encode is reported by
+/// `commit` and nothing is written; dropping an uncommitted batch also
+/// writes nothing.
+pub struct StorageBatch<'a> {
+    values: js_sys::Object,
+    len: usize,
+    error: Option<Error>,
+    on_commit: Vec<Box<dyn FnOnce() + 'a>>,
+}
+
+impl<'a> StorageBatch<'a> {
+    /// Empty batch
+    pub fn new() -> Self {
+        Self {
+            values: js_sys::Object::new(),
+            len: 0,
+            error: None,
+            on_commit: Vec::new(),
+        }
+    }
+
+    /// Number of staged values
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether nothing is staged
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn stage<T: Serialize>(&mut self, key: &str, value: &T) {
+        if self.error.is_none() {
+            if let Err(e) = self.try_stage(key, value) {
+                self.error = Some(e);
+            }
+        }
+    }
+
+    fn try_stage<T: Serialize>(&mut self, key: &str, value: &T) -> Result<()> {
+        let had_key = js_sys::Reflect::has(&self.values, &key.into())?;
+        if !had_key && self.len == MAX_BATCH_KEYS {
+            return Err(Error::RustError(format!(
+                "Storage batch is limited to {MAX_BATCH_KEYS} keys"
+            )));
+        }
+        let value = serde_wasm_bindgen::to_value(value)
+            .map_err(|e| Error::RustError(format!("Failed to encode storage key {key}: {e}")))?;
+        js_sys::Reflect::set(&self.values, &key.into(), &value)?;
+        if !had_key {
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    /// Write every staged value
+    pub async fn commit(self, storage: &Storage) -> Result<()> {
+        for update_cache in self.on_commit {
+            update_cache();
+        }
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        if self.len == 0 {
+            return Ok(());
+        }
+        storage.put_multiple_raw(self.values).await
+    }
+}
+
+impl Default for StorageBatch<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -6,6 +6,22 @@
 use cardano_assets::AssetId;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use ui_flow_protocol::{check_count, check_len, Validate, ValidationError};
+
+/// Longest chat message accepted from a client, in bytes
+pub const MAX_CHAT_MESSAGE_LEN: usize = 1000;
+
+/// Most mentions accepted in one chat message
+pub const MAX_CHAT_MENTIONS: usize = 20;
+
+/// Longest user ID accepted in a mention (IDs are ULIDs, 26 characters)
+pub const MAX_USER_ID_LEN: usize = 64;
+
+/// Longest display name accepted from a client, in bytes
+pub const MAX_USER_NAME_LEN: usize = 64;
+
+/// Longest card ID accepted from a client (ULIDs are 26 characters)
+pub const MAX_CARD_ID_LEN: usize = 64;
 
 // =============================================================================
 // CardId - Stable identifier for cards
@@ -61,10 +77,33 @@ pub struct ChatMessage {
     pub user_name: String,
     /// Message content
     pub text: String,
+    /// Users mentioned in `text`
+    #[serde(default)]
+    pub mentions: Vec<ChatMention>,
     /// Timestamp (unix ms)
     pub timestamp: u64,
 }
 
+/// A user mentioned in a chat message: `text[start..end]` is `@name`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatMention {
+    pub user_id: String,
+    /// Byte offset of the `@`
+    pub start: usize,
+    /// Byte offset just past the name
+    pub end: usize,
+}
+
+impl ChatMention {
+    /// Whether this mention points at an `@` mention within `text`
+    pub fn fits(&self, text: &str) -> bool {
+        self.start < self.end
+            && text
+                .get(self.start..self.end)
+                .is_some_and(|m| m.starts_with('@'))
+    }
+}
+
 /// Incremental state changes.
 ///
 /// Instead of sending the full state, we send only what changed.
@@ -103,11 +142,40 @@ pub enum DemoAction {
     /// Decrement the counter
     Decrement,
     /// Send a chat message
-    SendMessage { text: String },
+    SendMessage {
+        text: String,
+        #[serde(default)]
+        mentions: Vec<ChatMention>,
+    },
     /// Indicate that the user is typing
     StartTyping,
 }
 
+impl Validate for DemoAction {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::SendMessage { text, mentions } => {
+                check_len("text", text, MAX_CHAT_MESSAGE_LEN)?;
+                check_count("mentions", mentions.len(), MAX_CHAT_MENTIONS)?;
+                for mention in mentions {
+                    check_len("mentions.user_id", &mention.user_id, MAX_USER_ID_LEN)?;
+                }
+                Ok(())
+            }
+            Self::Increment | Self::Decrement | Self::StartTyping => Ok(()),
+        }
+    }
+}
+
+/// Live room details reported to the admin API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomStats {
+    /// Open WebSocket connections
+    pub connections: usize,
+    /// Current state sequence number
+    pub seq: u64,
+}
+
 /// Type aliases for the protocol types with our concrete types
 pub type ServerMsg = ui_flow_protocol::ServerMessage<DemoState, DemoDelta, DemoEvent>;
 pub type ClientMsg = ui_flow_protocol::ClientMessage<DemoAction>;
@@ -141,6 +209,9 @@ pub struct GameConfig {
     pub flip_delay_ms: u64,
     /// Shuffle seed for deterministic card layout
     pub shuffle_seed: u64,
+    /// Bots filling empty seats
+    #[serde(default)]
+    pub bots: BotConfig,
 }
 
 impl Default for GameConfig {
@@ -151,6 +222,35 @@ impl Default for GameConfig {
             policy_id: "b3dab69f7e6100849434fb1781e34bd12a916557f6231b8d2629b6f6".to_string(), // Black Flag
             flip_delay_ms: 1200,
             shuffle_seed: 0,
+            bots: BotConfig::default(),
+        }
+    }
+}
+
+/// How well bots remember cards they've seen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BotDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+/// Bots that join short-handed lobbies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BotConfig {
+    /// Bots join until this many players are seated (0 = no bots)
+    pub fill_to: u8,
+    pub difficulty: BotDifficulty,
+}
+
+impl Default for BotConfig {
+    /// One bot opponent for a solo player
+    fn default() -> Self {
+        Self {
+            fill_to: 2,
+            difficulty: BotDifficulty::Normal,
         }
     }
 }
@@ -368,6 +468,9 @@ pub struct PlayerState {
     pub spectating: bool,
     /// When the player joined
     pub joined_at: u64,
+    /// Server-side bot rather than a person
+    #[serde(default)]
+    pub bot: bool,
 }
 
 /// Complete authoritative game state (server-side)
@@ -389,6 +492,12 @@ pub struct MemoryGameState {
     pub turn_state: TurnState,
     /// Host user_id (can change settings)
     pub host: Option<String>,
+    /// Cards each bot has seen face up, oldest first
+    #[serde(default)]
+    pub bot_memory: HashMap<String, Vec<CardId>>,
+    /// Current or last game, for fetching its replay (0 before the first)
+    #[serde(default)]
+    pub game_id: u64,
 }
 
 /// Card face data sent to clients when a card is revealed
@@ -439,6 +548,8 @@ pub enum MemoryDelta {
         user_id: String,
         user_name: String,
         spectating: bool,
+        #[serde(default)]
+        bot: bool,
     },
     /// Player left the game
     PlayerLeft { user_id: String },
@@ -511,6 +622,9 @@ pub enum MemoryDelta {
     GameEnded {
         winner: Option<String>,
         rankings: Vec<(String, String, u32)>,
+        /// Replay ID (`GET /memory/:room_id/replay/:game_id`)
+        #[serde(default)]
+        game_id: u64,
     },
 }
 
@@ -548,6 +662,8 @@ pub enum MemoryAction {
     SetConfig {
         mode: Option<GameMode>,
         grid_size: Option<(u8, u8)>,
+        #[serde(default)]
+        bots: Option<BotConfig>,
     },
     /// Start the game (lobby phase only)
     StartGame,
@@ -559,8 +675,22 @@ pub enum MemoryAction {
     Ready,
     /// Request a rematch after game ends
     RequestRematch,
-    /// Reset entire game state (admin)
-    ResetGame,
+}
+
+impl Validate for MemoryAction {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Self::JoinGame { user_name } => check_len("user_name", user_name, MAX_USER_NAME_LEN),
+            Self::FlipCard { card_id } | Self::AckCardLoaded { card_id } => {
+                check_len("card_id", &card_id.0, MAX_CARD_ID_LEN)
+            }
+            Self::LeaveGame
+            | Self::SetConfig { .. }
+            | Self::StartGame
+            | Self::Ready
+            | Self::RequestRematch => Ok(()),
+        }
+    }
 }
 
 /// Type aliases for memory game protocol